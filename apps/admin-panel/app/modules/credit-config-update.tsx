@@ -44,6 +44,7 @@ const initialFormData: CreditModuleConfigureInput = {
   chartOfAccountCollateralParentCode: "",
   chartOfAccountInterestIncomeParentCode: "",
   chartOfAccountFeeIncomeParentCode: "",
+  chartOfAccountDeferredFeeIncomeParentCode: "",
   chartOfAccountShortTermIndividualDisbursedReceivableParentCode: "",
   chartOfAccountShortTermGovernmentEntityDisbursedReceivableParentCode: "",
   chartOfAccountShortTermPrivateCompanyDisbursedReceivableParentCode: "",
@@ -88,6 +89,7 @@ const creditModuleCodes = {
   chartOfAccountCollateralParentCode: "9220.08",
   chartOfAccountInterestIncomeParentCode: "6110.01.0100",
   chartOfAccountFeeIncomeParentCode: "6110.01.0300",
+  chartOfAccountDeferredFeeIncomeParentCode: "2190.01.0300",
   chartOfAccountShortTermIndividualInterestReceivableParentCode: "1141.04.9901",
   chartOfAccountShortTermGovernmentEntityInterestReceivableParentCode: "1141.02.9901",
   chartOfAccountShortTermPrivateCompanyInterestReceivableParentCode: "1141.03.9901",
@@ -33,6 +33,11 @@ gql`
         chartOfAccountBankDepositAccountsParentCode
         chartOfAccountFinancialInstitutionDepositAccountsParentCode
         chartOfAccountNonDomiciledCompanyDepositAccountsParentCode
+        chartOfAccountsTimeDepositAccountsParentCode
+        chartOfAccountsInterestExpenseParentCode
+        chartOfAccountsAccruedInterestParentCode
+        chartOfAccountsUnclaimedFundsParentCode
+        chartOfAccountsFeeIncomeParentCode
       }
     }
   }
@@ -52,6 +57,11 @@ const initialFormData: DepositModuleConfigureInput = {
   chartOfAccountBankDepositAccountsParentCode: "",
   chartOfAccountFinancialInstitutionDepositAccountsParentCode: "",
   chartOfAccountNonDomiciledIndividualDepositAccountsParentCode: "",
+  chartOfAccountsTimeDepositAccountsParentCode: "",
+  chartOfAccountsInterestExpenseParentCode: "",
+  chartOfAccountsAccruedInterestParentCode: "",
+  chartOfAccountsUnclaimedFundsParentCode: "",
+  chartOfAccountsFeeIncomeParentCode: "",
 }
 
 const depositModuleCodes = {
@@ -62,6 +72,11 @@ const depositModuleCodes = {
   chartOfAccountBankDepositAccountsParentCode: "2110.01.0501",
   chartOfAccountFinancialInstitutionDepositAccountsParentCode: "2110.01.0601",
   chartOfAccountNonDomiciledIndividualDepositAccountsParentCode: "2110.01.0901",
+  chartOfAccountsTimeDepositAccountsParentCode: "2120.01.0100",
+  chartOfAccountsInterestExpenseParentCode: "7110.01.0100",
+  chartOfAccountsAccruedInterestParentCode: "2190.01.0400",
+  chartOfAccountsUnclaimedFundsParentCode: "2190.01.0500",
+  chartOfAccountsFeeIncomeParentCode: "6110.01.0400",
 }
 
 export const DepositConfigUpdateDialog: React.FC<DepositConfigUpdateDialogProps> = ({
@@ -93,7 +108,12 @@ export const DepositConfigUpdateDialog: React.FC<DepositConfigUpdateDialogProps>
       depositModuleConfig.chartOfAccountPrivateCompanyDepositAccountsParentCode &&
       depositModuleConfig.chartOfAccountBankDepositAccountsParentCode &&
       depositModuleConfig.chartOfAccountFinancialInstitutionDepositAccountsParentCode &&
-      depositModuleConfig.chartOfAccountNonDomiciledCompanyDepositAccountsParentCode
+      depositModuleConfig.chartOfAccountNonDomiciledCompanyDepositAccountsParentCode &&
+      depositModuleConfig.chartOfAccountsTimeDepositAccountsParentCode &&
+      depositModuleConfig.chartOfAccountsInterestExpenseParentCode &&
+      depositModuleConfig.chartOfAccountsAccruedInterestParentCode &&
+      depositModuleConfig.chartOfAccountsUnclaimedFundsParentCode &&
+      depositModuleConfig.chartOfAccountsFeeIncomeParentCode
     ) {
       setFormData({
         chartOfAccountsOmnibusParentCode:
@@ -110,6 +130,16 @@ export const DepositConfigUpdateDialog: React.FC<DepositConfigUpdateDialogProps>
           depositModuleConfig.chartOfAccountFinancialInstitutionDepositAccountsParentCode,
         chartOfAccountNonDomiciledIndividualDepositAccountsParentCode:
           depositModuleConfig.chartOfAccountNonDomiciledCompanyDepositAccountsParentCode,
+        chartOfAccountsTimeDepositAccountsParentCode:
+          depositModuleConfig.chartOfAccountsTimeDepositAccountsParentCode,
+        chartOfAccountsInterestExpenseParentCode:
+          depositModuleConfig.chartOfAccountsInterestExpenseParentCode,
+        chartOfAccountsAccruedInterestParentCode:
+          depositModuleConfig.chartOfAccountsAccruedInterestParentCode,
+        chartOfAccountsUnclaimedFundsParentCode:
+          depositModuleConfig.chartOfAccountsUnclaimedFundsParentCode,
+        chartOfAccountsFeeIncomeParentCode:
+          depositModuleConfig.chartOfAccountsFeeIncomeParentCode,
       })
     }
   }, [depositModuleConfig])
@@ -41,6 +41,11 @@ gql`
       chartOfAccountBankDepositAccountsParentCode
       chartOfAccountFinancialInstitutionDepositAccountsParentCode
       chartOfAccountNonDomiciledCompanyDepositAccountsParentCode
+      chartOfAccountsTimeDepositAccountsParentCode
+      chartOfAccountsInterestExpenseParentCode
+      chartOfAccountsAccruedInterestParentCode
+      chartOfAccountsUnclaimedFundsParentCode
+      chartOfAccountsFeeIncomeParentCode
     }
   }
 
@@ -52,6 +57,7 @@ gql`
       chartOfAccountCollateralParentCode
       chartOfAccountInterestIncomeParentCode
       chartOfAccountFeeIncomeParentCode
+      chartOfAccountDeferredFeeIncomeParentCode
       chartOfAccountShortTermIndividualDisbursedReceivableParentCode
       chartOfAccountShortTermGovernmentEntityDisbursedReceivableParentCode
       chartOfAccountShortTermPrivateCompanyDisbursedReceivableParentCode
@@ -94,7 +94,7 @@ const WithdrawalDetailsCard: React.FC<WithdrawalDetailsProps> = ({ withdrawal })
           </Button>
         </>
       )}
-      {withdrawal?.approvalProcess.status === ApprovalProcessStatus.InProgress &&
+      {withdrawal?.approvalProcess?.status === ApprovalProcessStatus.InProgress &&
         withdrawal.approvalProcess.subjectCanSubmitDecision && (
           <>
             <Button
@@ -122,10 +122,10 @@ const WithdrawalDetailsCard: React.FC<WithdrawalDetailsProps> = ({ withdrawal })
         title={t("title")}
         details={details}
         footerContent={footerContent}
-        errorMessage={withdrawal.approvalProcess.deniedReason}
+        errorMessage={withdrawal.approvalProcess?.deniedReason}
         className="max-w-7xl m-auto"
       />
-      <VotersCard approvalProcess={withdrawal.approvalProcess} />
+      <VotersCard approvalProcess={withdrawal.approvalProcess ?? null} />
       {openWithdrawalConfirmDialog && (
         <WithdrawalConfirmDialog
           withdrawalData={openWithdrawalConfirmDialog}
@@ -140,16 +140,20 @@ const WithdrawalDetailsCard: React.FC<WithdrawalDetailsProps> = ({ withdrawal })
           setOpenWithdrawalCancelDialog={() => setOpenWithdrawalCancelDialog(null)}
         />
       )}
-      <ApprovalDialog
-        approvalProcess={withdrawal?.approvalProcess}
-        openApprovalDialog={openApprovalDialog}
-        setOpenApprovalDialog={() => setOpenApprovalDialog(false)}
-      />
-      <DenialDialog
-        approvalProcess={withdrawal?.approvalProcess}
-        openDenialDialog={openDenialDialog}
-        setOpenDenialDialog={() => setOpenDenialDialog(false)}
-      />
+      {withdrawal.approvalProcess && (
+        <>
+          <ApprovalDialog
+            approvalProcess={withdrawal.approvalProcess}
+            openApprovalDialog={openApprovalDialog}
+            setOpenApprovalDialog={() => setOpenApprovalDialog(false)}
+          />
+          <DenialDialog
+            approvalProcess={withdrawal.approvalProcess}
+            openDenialDialog={openDenialDialog}
+            setOpenDenialDialog={() => setOpenDenialDialog(false)}
+          />
+        </>
+      )}
     </>
   )
 }
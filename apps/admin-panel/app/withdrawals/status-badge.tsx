@@ -9,6 +9,8 @@ interface StatusBadgeProps extends BadgeProps {
 
 const getVariant = (status: WithdrawalStatus): BadgeProps["variant"] => {
   switch (status) {
+    case WithdrawalStatus.PendingSignatoryApproval:
+      return "default"
     case WithdrawalStatus.PendingApproval:
       return "default"
     case WithdrawalStatus.PendingConfirmation:
@@ -33,6 +35,10 @@ export const WithdrawalStatusBadge: React.FC<StatusBadgeProps> = ({
 
   const getTranslatedStatus = (status: WithdrawalStatus): string => {
     switch (status) {
+      case WithdrawalStatus.PendingSignatoryApproval:
+        return t("pendingSignatoryApproval", {
+          defaultMessage: "PENDING SIGNATORY APPROVAL",
+        }).toUpperCase()
       case WithdrawalStatus.PendingApproval:
         return t("pendingApproval", { defaultMessage: "PENDING APPROVAL" }).toUpperCase()
       case WithdrawalStatus.PendingConfirmation:
@@ -154,11 +154,12 @@ export enum ApprovalProcessStatus {
   InProgress = 'IN_PROGRESS'
 }
 
-export type ApprovalProcessTarget = CreditFacility | CreditFacilityDisbursal | Withdrawal;
+export type ApprovalProcessTarget = CreditFacility | CreditFacilityDisbursal | CreditFacilityForbearance | Withdrawal;
 
 export enum ApprovalProcessType {
   CreditFacilityApproval = 'CREDIT_FACILITY_APPROVAL',
   DisbursalApproval = 'DISBURSAL_APPROVAL',
+  ForbearanceApproval = 'FORBEARANCE_APPROVAL',
   WithdrawalApproval = 'WITHDRAWAL_APPROVAL'
 }
 
@@ -438,12 +439,14 @@ export type CreditFacility = {
   customer: Customer;
   disbursals: Array<CreditFacilityDisbursal>;
   facilityAmount: Scalars['UsdCents']['output'];
+  forbearances: Array<CreditFacilityForbearance>;
   id: Scalars['ID']['output'];
   maturesAt?: Maybe<Scalars['Timestamp']['output']>;
   repaymentPlan: Array<CreditFacilityRepaymentInPlan>;
   status: CreditFacilityStatus;
   subjectCanComplete: Scalars['Boolean']['output'];
   subjectCanInitiateDisbursal: Scalars['Boolean']['output'];
+  subjectCanInitiateForbearance: Scalars['Boolean']['output'];
   subjectCanRecordPayment: Scalars['Boolean']['output'];
   subjectCanUpdateCollateral: Scalars['Boolean']['output'];
   transactions: Array<CreditFacilityHistoryEntry>;
@@ -574,6 +577,31 @@ export type CreditFacilityEdge = {
   node: CreditFacility;
 };
 
+export type CreditFacilityForbearance = {
+  __typename?: 'CreditFacilityForbearance';
+  approvalProcess: ApprovalProcess;
+  capitalizeInterest: Scalars['Boolean']['output'];
+  createdAt: Scalars['Timestamp']['output'];
+  creditFacility: CreditFacility;
+  endsAt: Scalars['Timestamp']['output'];
+  forbearanceId: Scalars['UUID']['output'];
+  id: Scalars['ID']['output'];
+  startsAt: Scalars['Timestamp']['output'];
+  status: ForbearanceStatus;
+};
+
+export type CreditFacilityForbearanceInitiateInput = {
+  capitalizeInterest: Scalars['Boolean']['input'];
+  creditFacilityId: Scalars['UUID']['input'];
+  endsAt: Scalars['Timestamp']['input'];
+  startsAt: Scalars['Timestamp']['input'];
+};
+
+export type CreditFacilityForbearanceInitiatePayload = {
+  __typename?: 'CreditFacilityForbearanceInitiatePayload';
+  forbearance: CreditFacilityForbearance;
+};
+
 export type CreditFacilityHistoryEntry = CreditFacilityCollateralUpdated | CreditFacilityCollateralizationUpdated | CreditFacilityDisbursalExecuted | CreditFacilityIncrementalPayment | CreditFacilityInterestAccrued | CreditFacilityOrigination;
 
 export type CreditFacilityIncrementalPayment = {
@@ -652,6 +680,7 @@ export type CreditModuleConfig = {
   __typename?: 'CreditModuleConfig';
   chartOfAccountCollateralOmnibusParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountCollateralParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountDeferredFeeIncomeParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountFacilityOmnibusParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountFacilityParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountFeeIncomeParentCode?: Maybe<Scalars['String']['output']>;
@@ -697,6 +726,7 @@ export type CreditModuleConfig = {
 export type CreditModuleConfigureInput = {
   chartOfAccountCollateralOmnibusParentCode: Scalars['String']['input'];
   chartOfAccountCollateralParentCode: Scalars['String']['input'];
+  chartOfAccountDeferredFeeIncomeParentCode: Scalars['String']['input'];
   chartOfAccountFacilityOmnibusParentCode: Scalars['String']['input'];
   chartOfAccountFacilityParentCode: Scalars['String']['input'];
   chartOfAccountFeeIncomeParentCode: Scalars['String']['input'];
@@ -932,10 +962,15 @@ export type DepositModuleConfig = {
   chartOfAccountFinancialInstitutionDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountPrivateCompanyDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountsAccruedInterestParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountsFeeIncomeParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountsGovernmentEntityDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountsId?: Maybe<Scalars['UUID']['output']>;
   chartOfAccountsIndividualDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountsInterestExpenseParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountsOmnibusParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountsTimeDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountsUnclaimedFundsParentCode?: Maybe<Scalars['String']['output']>;
 };
 
 export type DepositModuleConfigureInput = {
@@ -943,9 +978,14 @@ export type DepositModuleConfigureInput = {
   chartOfAccountFinancialInstitutionDepositAccountsParentCode: Scalars['String']['input'];
   chartOfAccountNonDomiciledIndividualDepositAccountsParentCode: Scalars['String']['input'];
   chartOfAccountPrivateCompanyDepositAccountsParentCode: Scalars['String']['input'];
+  chartOfAccountsAccruedInterestParentCode: Scalars['String']['input'];
+  chartOfAccountsFeeIncomeParentCode: Scalars['String']['input'];
   chartOfAccountsGovernmentEntityDepositAccountsParentCode: Scalars['String']['input'];
   chartOfAccountsIndividualDepositAccountsParentCode: Scalars['String']['input'];
+  chartOfAccountsInterestExpenseParentCode: Scalars['String']['input'];
   chartOfAccountsOmnibusParentCode: Scalars['String']['input'];
+  chartOfAccountsTimeDepositAccountsParentCode: Scalars['String']['input'];
+  chartOfAccountsUnclaimedFundsParentCode: Scalars['String']['input'];
 };
 
 export type DepositModuleConfigurePayload = {
@@ -1058,6 +1098,12 @@ export type FacilityRemaining = {
   usdBalance: Scalars['UsdCents']['output'];
 };
 
+export enum ForbearanceStatus {
+  Approved = 'APPROVED',
+  Denied = 'DENIED',
+  New = 'NEW'
+}
+
 export type GeneralLedgerEntry = BtcGeneralLedgerEntry | UsdGeneralLedgerEntry;
 
 export type GeneralLedgerEntryConnection = {
@@ -1266,6 +1312,7 @@ export type Mutation = {
   creditFacilityComplete: CreditFacilityCompletePayload;
   creditFacilityCreate: CreditFacilityCreatePayload;
   creditFacilityDisbursalInitiate: CreditFacilityDisbursalInitiatePayload;
+  creditFacilityForbearanceInitiate: CreditFacilityForbearanceInitiatePayload;
   creditFacilityPartialPayment: CreditFacilityPartialPaymentPayload;
   creditModuleConfigure: CreditModuleConfigurePayload;
   customerCreate: CustomerCreatePayload;
@@ -1350,6 +1397,11 @@ export type MutationCreditFacilityDisbursalInitiateArgs = {
 };
 
 
+export type MutationCreditFacilityForbearanceInitiateArgs = {
+  input: CreditFacilityForbearanceInitiateInput;
+};
+
+
 export type MutationCreditFacilityPartialPaymentArgs = {
   input: CreditFacilityPartialPaymentInput;
 };
@@ -2125,7 +2177,7 @@ export type Withdrawal = {
   account: DepositAccount;
   accountId: Scalars['UUID']['output'];
   amount: Scalars['UsdCents']['output'];
-  approvalProcess: ApprovalProcess;
+  approvalProcess?: Maybe<ApprovalProcess>;
   approvalProcessId: Scalars['UUID']['output'];
   createdAt: Scalars['Timestamp']['output'];
   id: Scalars['ID']['output'];
@@ -2193,7 +2245,8 @@ export enum WithdrawalStatus {
   Confirmed = 'CONFIRMED',
   Denied = 'DENIED',
   PendingApproval = 'PENDING_APPROVAL',
-  PendingConfirmation = 'PENDING_CONFIRMATION'
+  PendingConfirmation = 'PENDING_CONFIRMATION',
+  PendingSignatoryApproval = 'PENDING_SIGNATORY_APPROVAL'
 }
 
 export type ApprovalProcessFieldsFragment = { __typename?: 'ApprovalProcess', id: string, approvalProcessId: string, deniedReason?: string | null, approvalProcessType: ApprovalProcessType, createdAt: any, subjectCanSubmitDecision: boolean, status: ApprovalProcessStatus, rules: { __typename?: 'CommitteeThreshold', threshold: number, committee: { __typename?: 'Committee', name: string, currentMembers: Array<{ __typename?: 'User', id: string, email: string, roles: Array<Role> }> } } | { __typename?: 'SystemApproval', autoApprove: boolean }, voters: Array<{ __typename?: 'ApprovalProcessVoter', stillEligible: boolean, didVote: boolean, didApprove: boolean, didDeny: boolean, user: { __typename?: 'User', id: string, userId: string, email: string, roles: Array<Role> } }> };
@@ -2576,17 +2629,17 @@ export type DepositModuleConfigureMutationVariables = Exact<{
 }>;
 
 
-export type DepositModuleConfigureMutation = { __typename?: 'Mutation', depositModuleConfigure: { __typename?: 'DepositModuleConfigurePayload', depositConfig: { __typename?: 'DepositModuleConfig', chartOfAccountsId?: string | null, chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null } } };
+export type DepositModuleConfigureMutation = { __typename?: 'Mutation', depositModuleConfigure: { __typename?: 'DepositModuleConfigurePayload', depositConfig: { __typename?: 'DepositModuleConfig', chartOfAccountsId?: string | null, chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null, chartOfAccountsTimeDepositAccountsParentCode?: string | null, chartOfAccountsInterestExpenseParentCode?: string | null, chartOfAccountsAccruedInterestParentCode?: string | null, chartOfAccountsUnclaimedFundsParentCode?: string | null, chartOfAccountsFeeIncomeParentCode?: string | null } } };
 
 export type DepositConfigQueryVariables = Exact<{ [key: string]: never; }>;
 
 
-export type DepositConfigQuery = { __typename?: 'Query', depositConfig?: { __typename?: 'DepositModuleConfig', chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null } | null };
+export type DepositConfigQuery = { __typename?: 'Query', depositConfig?: { __typename?: 'DepositModuleConfig', chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null, chartOfAccountsTimeDepositAccountsParentCode?: string | null, chartOfAccountsInterestExpenseParentCode?: string | null, chartOfAccountsAccruedInterestParentCode?: string | null, chartOfAccountsUnclaimedFundsParentCode?: string | null, chartOfAccountsFeeIncomeParentCode?: string | null } | null };
 
 export type CreditConfigQueryVariables = Exact<{ [key: string]: never; }>;
 
 
-export type CreditConfigQuery = { __typename?: 'Query', creditConfig?: { __typename?: 'CreditModuleConfig', chartOfAccountFacilityOmnibusParentCode?: string | null, chartOfAccountCollateralOmnibusParentCode?: string | null, chartOfAccountFacilityParentCode?: string | null, chartOfAccountCollateralParentCode?: string | null, chartOfAccountInterestIncomeParentCode?: string | null, chartOfAccountFeeIncomeParentCode?: string | null, chartOfAccountShortTermIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermPrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermBankDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermNonDomiciledCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermPrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermBankDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermNonDomiciledCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermIndividualInterestReceivableParentCode?: string | null, chartOfAccountShortTermGovernmentEntityInterestReceivableParentCode?: string | null, chartOfAccountShortTermPrivateCompanyInterestReceivableParentCode?: string | null, chartOfAccountShortTermBankInterestReceivableParentCode?: string | null, chartOfAccountShortTermFinancialInstitutionInterestReceivableParentCode?: string | null, chartOfAccountShortTermForeignAgencyOrSubsidiaryInterestReceivableParentCode?: string | null, chartOfAccountShortTermNonDomiciledCompanyInterestReceivableParentCode?: string | null, chartOfAccountLongTermIndividualInterestReceivableParentCode?: string | null, chartOfAccountLongTermGovernmentEntityInterestReceivableParentCode?: string | null, chartOfAccountLongTermPrivateCompanyInterestReceivableParentCode?: string | null, chartOfAccountLongTermBankInterestReceivableParentCode?: string | null, chartOfAccountLongTermFinancialInstitutionInterestReceivableParentCode?: string | null, chartOfAccountLongTermForeignAgencyOrSubsidiaryInterestReceivableParentCode?: string | null, chartOfAccountLongTermNonDomiciledCompanyInterestReceivableParentCode?: string | null, chartOfAccountOverdueIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountOverduePrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueBankDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueNonDomiciledCompanyDisbursedReceivableParentCode?: string | null } | null };
+export type CreditConfigQuery = { __typename?: 'Query', creditConfig?: { __typename?: 'CreditModuleConfig', chartOfAccountFacilityOmnibusParentCode?: string | null, chartOfAccountCollateralOmnibusParentCode?: string | null, chartOfAccountFacilityParentCode?: string | null, chartOfAccountCollateralParentCode?: string | null, chartOfAccountInterestIncomeParentCode?: string | null, chartOfAccountFeeIncomeParentCode?: string | null, chartOfAccountDeferredFeeIncomeParentCode?: string | null, chartOfAccountShortTermIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermPrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermBankDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermNonDomiciledCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermPrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermBankDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermNonDomiciledCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermIndividualInterestReceivableParentCode?: string | null, chartOfAccountShortTermGovernmentEntityInterestReceivableParentCode?: string | null, chartOfAccountShortTermPrivateCompanyInterestReceivableParentCode?: string | null, chartOfAccountShortTermBankInterestReceivableParentCode?: string | null, chartOfAccountShortTermFinancialInstitutionInterestReceivableParentCode?: string | null, chartOfAccountShortTermForeignAgencyOrSubsidiaryInterestReceivableParentCode?: string | null, chartOfAccountShortTermNonDomiciledCompanyInterestReceivableParentCode?: string | null, chartOfAccountLongTermIndividualInterestReceivableParentCode?: string | null, chartOfAccountLongTermGovernmentEntityInterestReceivableParentCode?: string | null, chartOfAccountLongTermPrivateCompanyInterestReceivableParentCode?: string | null, chartOfAccountLongTermBankInterestReceivableParentCode?: string | null, chartOfAccountLongTermFinancialInstitutionInterestReceivableParentCode?: string | null, chartOfAccountLongTermForeignAgencyOrSubsidiaryInterestReceivableParentCode?: string | null, chartOfAccountLongTermNonDomiciledCompanyInterestReceivableParentCode?: string | null, chartOfAccountOverdueIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountOverduePrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueBankDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueNonDomiciledCompanyDisbursedReceivableParentCode?: string | null } | null };
 
 export type BalanceSheetConfigQueryVariables = Exact<{ [key: string]: never; }>;
 
@@ -5405,6 +5458,11 @@ export const DepositModuleConfigureDocument = gql`
       chartOfAccountBankDepositAccountsParentCode
       chartOfAccountFinancialInstitutionDepositAccountsParentCode
       chartOfAccountNonDomiciledCompanyDepositAccountsParentCode
+      chartOfAccountsTimeDepositAccountsParentCode
+      chartOfAccountsInterestExpenseParentCode
+      chartOfAccountsAccruedInterestParentCode
+      chartOfAccountsUnclaimedFundsParentCode
+      chartOfAccountsFeeIncomeParentCode
     }
   }
 }
@@ -5445,6 +5503,11 @@ export const DepositConfigDocument = gql`
     chartOfAccountBankDepositAccountsParentCode
     chartOfAccountFinancialInstitutionDepositAccountsParentCode
     chartOfAccountNonDomiciledCompanyDepositAccountsParentCode
+    chartOfAccountsTimeDepositAccountsParentCode
+    chartOfAccountsInterestExpenseParentCode
+    chartOfAccountsAccruedInterestParentCode
+    chartOfAccountsUnclaimedFundsParentCode
+    chartOfAccountsFeeIncomeParentCode
   }
 }
     `;
@@ -5484,6 +5547,7 @@ export const CreditConfigDocument = gql`
     chartOfAccountCollateralParentCode
     chartOfAccountInterestIncomeParentCode
     chartOfAccountFeeIncomeParentCode
+    chartOfAccountDeferredFeeIncomeParentCode
     chartOfAccountShortTermIndividualDisbursedReceivableParentCode
     chartOfAccountShortTermGovernmentEntityDisbursedReceivableParentCode
     chartOfAccountShortTermPrivateCompanyDisbursedReceivableParentCode
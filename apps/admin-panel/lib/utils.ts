@@ -189,6 +189,8 @@ export const formatProcessType = (processType: ApprovalProcessType) => {
       return "Withdrawal"
     case ApprovalProcessType.DisbursalApproval:
       return "Disbursal"
+    case ApprovalProcessType.ForbearanceApproval:
+      return "Forbearance"
   }
 }
 
@@ -85,6 +85,27 @@ wait_for_accruals() {
   [[ "$num_accruals" == "$expected_num_accruals" ]] || exit 1
 }
 
+wait_for_auto_debit() {
+  credit_facility_id=$1
+
+  variables=$(
+    jq -n \
+      --arg creditFacilityId "$credit_facility_id" \
+    '{ id: $creditFacilityId }'
+  )
+  exec_admin_graphql 'find-credit-facility' "$variables"
+  echo "auto-debit | $i. $(graphql_output)" >> $RUN_LOG_FILE
+  num_collected=$(
+    graphql_output '[
+      .data.creditFacility.transactions[]
+      | select(.__typename == "CreditFacilityAutoDebitAttempted")
+      | select(.outcome == "COLLECTED")
+      ] | length'
+  )
+
+  [[ "$num_collected" -gt "0" ]] || exit 1
+}
+
 ymd() {
   local date_value
   read -r date_value
@@ -140,6 +161,56 @@ ymd() {
   [[ "$credit_facility_id" != "null" ]] || exit 1
 
   cache_value 'credit_facility_id' "$credit_facility_id"
+  cache_value 'deposit_account_id' "$deposit_account_id"
+}
+
+@test "credit-facility: rejects auto-debit from another customer's account" {
+  credit_facility_id=$(read_value 'credit_facility_id')
+  other_customer_id=$(create_customer)
+
+  variables=$(
+    jq -n \
+      --arg customerId "$other_customer_id" \
+    '{ id: $customerId }'
+  )
+  exec_admin_graphql 'customer' "$variables"
+  other_deposit_account_id=$(graphql_output '.data.customer.depositAccount.depositAccountId')
+  [[ "$other_deposit_account_id" != "null" ]] || exit 1
+
+  variables=$(
+    jq -n \
+      --arg creditFacilityId "$credit_facility_id" \
+      --arg depositAccountId "$other_deposit_account_id" \
+    '{
+      input: {
+        creditFacilityId: $creditFacilityId,
+        depositAccountId: $depositAccountId
+      }
+    }'
+  )
+  exec_admin_graphql 'credit-facility-auto-debit-enable' "$variables"
+  error=$(graphql_output '.errors[0].message')
+  [[ "$error" =~ "DepositAccountHolderMismatch" ]] || exit 1
+}
+
+@test "credit-facility: can enable auto-debit" {
+  credit_facility_id=$(read_value 'credit_facility_id')
+  deposit_account_id=$(read_value 'deposit_account_id')
+
+  variables=$(
+    jq -n \
+      --arg creditFacilityId "$credit_facility_id" \
+      --arg depositAccountId "$deposit_account_id" \
+    '{
+      input: {
+        creditFacilityId: $creditFacilityId,
+        depositAccountId: $depositAccountId
+      }
+    }'
+  )
+  exec_admin_graphql 'credit-facility-auto-debit-enable' "$variables"
+  id=$(graphql_output '.data.creditFacilityAutoDebitEnable.creditFacility.creditFacilityId')
+  [[ "$id" == "$credit_facility_id" ]] || exit 1
 }
 
 @test "credit-facility: can update collateral" {
@@ -213,3 +284,8 @@ ymd() {
 
   # assert_accounts_balanced
 }
+
+@test "credit-facility: auto-debits due interest from the linked account" {
+  credit_facility_id=$(read_value 'credit_facility_id')
+  retry 30 2 wait_for_auto_debit "$credit_facility_id"
+}
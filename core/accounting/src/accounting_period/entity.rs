@@ -0,0 +1,444 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::{
+    AccountingPeriodId, ApprovalProcessId, CalaAccountId, CalaCurrency, CalaTxId, DebitOrCredit,
+};
+
+use super::error::AccountingPeriodError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum AccountingPeriodStatus {
+    Open,
+    SoftClosed,
+    Closed,
+}
+
+/// One line of the transaction posted when a period is closed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClosingEntry {
+    pub account_id: CalaAccountId,
+    pub currency: CalaCurrency,
+    pub amount: Decimal,
+    pub direction: DebitOrCredit,
+}
+
+impl ClosingEntry {
+    fn reversed(&self) -> Self {
+        Self {
+            direction: match self.direction {
+                DebitOrCredit::Debit => DebitOrCredit::Credit,
+                DebitOrCredit::Credit => DebitOrCredit::Debit,
+            },
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "AccountingPeriodId")]
+pub enum AccountingPeriodEvent {
+    Initialized {
+        id: AccountingPeriodId,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    SoftClosed {
+        audit_info: AuditInfo,
+    },
+    Closed {
+        ledger_transaction_id: Option<CalaTxId>,
+        closing_entries: Vec<ClosingEntry>,
+        audit_info: AuditInfo,
+    },
+    ReopenRequested {
+        approval_process_id: ApprovalProcessId,
+        audit_info: AuditInfo,
+    },
+    ReopenApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    },
+    Reopened {
+        ledger_transaction_id: Option<CalaTxId>,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct AccountingPeriod {
+    pub id: AccountingPeriodId,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub status: AccountingPeriodStatus,
+    #[builder(default)]
+    pub closing_ledger_transaction_id: Option<CalaTxId>,
+
+    pub(super) events: EntityEvents<AccountingPeriodEvent>,
+}
+
+impl AccountingPeriod {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for accounting period")
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.status == AccountingPeriodStatus::Closed
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start_date <= date && date <= self.end_date
+    }
+
+    pub fn overlaps(&self, start_date: NaiveDate, end_date: NaiveDate) -> bool {
+        self.start_date <= end_date && start_date <= self.end_date
+    }
+
+    /// Balances over this range are rolled into retained earnings when the period closes.
+    pub fn range(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        let from = self
+            .start_date
+            .and_hms_opt(0, 0, 0)
+            .expect("valid time")
+            .and_utc();
+        let until = self
+            .end_date
+            .and_hms_opt(23, 59, 59)
+            .expect("valid time")
+            .and_utc();
+        (from, until)
+    }
+
+    pub fn reopen_approval_process_id(&self) -> Option<ApprovalProcessId> {
+        let mut pending = None;
+        for event in self.events.iter_all() {
+            match event {
+                AccountingPeriodEvent::ReopenRequested {
+                    approval_process_id,
+                    ..
+                } => pending = Some(*approval_process_id),
+                AccountingPeriodEvent::ReopenApprovalProcessConcluded { .. } => pending = None,
+                _ => (),
+            }
+        }
+        pending
+    }
+
+    pub fn soft_close(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, AccountingPeriodError> {
+        match self.status {
+            AccountingPeriodStatus::SoftClosed => return Ok(Idempotent::Ignored),
+            AccountingPeriodStatus::Closed => {
+                return Err(AccountingPeriodError::AlreadyClosed(self.id));
+            }
+            AccountingPeriodStatus::Open => (),
+        }
+
+        self.events
+            .push(AccountingPeriodEvent::SoftClosed { audit_info });
+        self.status = AccountingPeriodStatus::SoftClosed;
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Returns the id of the ledger transaction to post `closing_entries` under, if there
+    /// are any to post.
+    pub fn close(
+        &mut self,
+        today: NaiveDate,
+        closing_entries: Vec<ClosingEntry>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<Option<CalaTxId>>, AccountingPeriodError> {
+        if self.is_closed() {
+            return Ok(Idempotent::Ignored);
+        }
+        if today <= self.end_date {
+            return Err(AccountingPeriodError::PeriodNotEnded(self.id));
+        }
+
+        let ledger_transaction_id = (!closing_entries.is_empty()).then(CalaTxId::new);
+        self.events.push(AccountingPeriodEvent::Closed {
+            ledger_transaction_id,
+            closing_entries,
+            audit_info,
+        });
+        self.status = AccountingPeriodStatus::Closed;
+        self.closing_ledger_transaction_id = ledger_transaction_id;
+
+        Ok(Idempotent::Executed(ledger_transaction_id))
+    }
+
+    pub fn request_reopen(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, AccountingPeriodError> {
+        if !self.is_closed() {
+            return Err(AccountingPeriodError::NotClosed(self.id));
+        }
+        if self.reopen_approval_process_id().is_some() {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events.push(AccountingPeriodEvent::ReopenRequested {
+            approval_process_id,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn reopen_approval_process_concluded(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.reopen_approval_process_id() != Some(approval_process_id) {
+            return Idempotent::Ignored;
+        }
+
+        self.events
+            .push(AccountingPeriodEvent::ReopenApprovalProcessConcluded {
+                approval_process_id,
+                approved,
+                audit_info,
+            });
+
+        Idempotent::Executed(())
+    }
+
+    /// Reopens the period and returns the ledger transaction id and entries that reverse
+    /// its closing transaction, if one was posted.
+    pub fn reopen(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Option<(CalaTxId, Vec<ClosingEntry>)>, AccountingPeriodError> {
+        if !self.is_closed() {
+            return Err(AccountingPeriodError::NotClosed(self.id));
+        }
+        let reversal: Vec<_> = self
+            .events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                AccountingPeriodEvent::Closed {
+                    closing_entries, ..
+                } => Some(closing_entries.iter().map(ClosingEntry::reversed).collect()),
+                _ => None,
+            })
+            .expect("closed period without closing event");
+        let ledger_transaction_id = (!reversal.is_empty()).then(CalaTxId::new);
+
+        self.events.push(AccountingPeriodEvent::Reopened {
+            ledger_transaction_id,
+            audit_info,
+        });
+        self.status = AccountingPeriodStatus::Open;
+        self.closing_ledger_transaction_id = None;
+
+        Ok(ledger_transaction_id.map(|id| (id, reversal)))
+    }
+}
+
+impl TryFromEvents<AccountingPeriodEvent> for AccountingPeriod {
+    fn try_from_events(events: EntityEvents<AccountingPeriodEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = AccountingPeriodBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                AccountingPeriodEvent::Initialized {
+                    id,
+                    start_date,
+                    end_date,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .start_date(*start_date)
+                        .end_date(*end_date)
+                        .status(AccountingPeriodStatus::Open)
+                }
+                AccountingPeriodEvent::SoftClosed { .. } => {
+                    builder = builder.status(AccountingPeriodStatus::SoftClosed)
+                }
+                AccountingPeriodEvent::Closed {
+                    ledger_transaction_id,
+                    ..
+                } => {
+                    builder = builder
+                        .status(AccountingPeriodStatus::Closed)
+                        .closing_ledger_transaction_id(*ledger_transaction_id)
+                }
+                AccountingPeriodEvent::Reopened { .. } => {
+                    builder = builder
+                        .status(AccountingPeriodStatus::Open)
+                        .closing_ledger_transaction_id(None)
+                }
+                AccountingPeriodEvent::ReopenRequested { .. }
+                | AccountingPeriodEvent::ReopenApprovalProcessConcluded { .. } => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewAccountingPeriod {
+    #[builder(setter(into))]
+    pub(super) id: AccountingPeriodId,
+    pub(super) start_date: NaiveDate,
+    pub(super) end_date: NaiveDate,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewAccountingPeriod {
+    pub fn builder() -> NewAccountingPeriodBuilder {
+        NewAccountingPeriodBuilder::default()
+    }
+}
+
+impl IntoEvents<AccountingPeriodEvent> for NewAccountingPeriod {
+    fn into_events(self) -> EntityEvents<AccountingPeriodEvent> {
+        EntityEvents::init(
+            self.id,
+            [AccountingPeriodEvent::Initialized {
+                id: self.id,
+                start_date: self.start_date,
+                end_date: self.end_date,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn period() -> AccountingPeriod {
+        let new = NewAccountingPeriod::builder()
+            .id(AccountingPeriodId::new())
+            .start_date(date("2024-01-01"))
+            .end_date(date("2024-01-31"))
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        AccountingPeriod::try_from_events(new.into_events()).unwrap()
+    }
+
+    fn closing_entry() -> ClosingEntry {
+        ClosingEntry {
+            account_id: CalaAccountId::new(),
+            currency: CalaCurrency::USD,
+            amount: Decimal::ONE_HUNDRED,
+            direction: DebitOrCredit::Debit,
+        }
+    }
+
+    #[test]
+    fn cannot_close_before_end_date() {
+        let mut period = period();
+        let res = period.close(date("2024-01-31"), vec![], dummy_audit_info());
+        assert!(matches!(res, Err(AccountingPeriodError::PeriodNotEnded(_))));
+
+        let res = period
+            .close(date("2024-02-01"), vec![], dummy_audit_info())
+            .unwrap();
+        assert!(matches!(res, Idempotent::Executed(None)));
+        assert!(period.is_closed());
+    }
+
+    #[test]
+    fn closed_period_cannot_be_soft_closed() {
+        let mut period = period();
+        period
+            .close(date("2024-02-01"), vec![], dummy_audit_info())
+            .unwrap();
+        assert!(matches!(
+            period.soft_close(dummy_audit_info()),
+            Err(AccountingPeriodError::AlreadyClosed(_))
+        ));
+    }
+
+    #[test]
+    fn reopen_requires_closed_period() {
+        let mut period = period();
+        assert!(matches!(
+            period.request_reopen(ApprovalProcessId::new(), dummy_audit_info()),
+            Err(AccountingPeriodError::NotClosed(_))
+        ));
+    }
+
+    #[test]
+    fn reopen_reverses_closing_entries() {
+        let mut period = period();
+        let res = period
+            .close(
+                date("2024-02-01"),
+                vec![closing_entry()],
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert!(matches!(res, Idempotent::Executed(Some(_))));
+
+        let approval_process_id = ApprovalProcessId::new();
+        assert!(
+            period
+                .request_reopen(approval_process_id, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(
+            period.reopen_approval_process_id(),
+            Some(approval_process_id)
+        );
+        assert!(
+            period
+                .reopen_approval_process_concluded(
+                    ApprovalProcessId::new(),
+                    true,
+                    dummy_audit_info()
+                )
+                .was_ignored()
+        );
+        assert!(
+            period
+                .reopen_approval_process_concluded(approval_process_id, true, dummy_audit_info())
+                .did_execute()
+        );
+
+        let (_, reversal) = period.reopen(dummy_audit_info()).unwrap().unwrap();
+        assert_eq!(reversal.len(), 1);
+        assert_eq!(reversal[0].direction, DebitOrCredit::Credit);
+        assert_eq!(period.status, AccountingPeriodStatus::Open);
+        assert_eq!(period.closing_ledger_transaction_id, None);
+    }
+}
@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+use crate::primitives::AccountingPeriodId;
+
+#[derive(Error, Debug)]
+pub enum AccountingPeriodError {
+    #[error("AccountingPeriodError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("AccountingPeriodError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("AccountingPeriodError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("AccountingPeriodError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("AccountingPeriodError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("AccountingPeriodError - InvalidDateRange: start date must not be after end date")]
+    InvalidDateRange,
+    #[error("AccountingPeriodError - OverlappingPeriod: {0}")]
+    OverlappingPeriod(AccountingPeriodId),
+    #[error("AccountingPeriodError - PeriodNotEnded: {0}")]
+    PeriodNotEnded(AccountingPeriodId),
+    #[error("AccountingPeriodError - AlreadyClosed: {0}")]
+    AlreadyClosed(AccountingPeriodId),
+    #[error("AccountingPeriodError - NotClosed: {0}")]
+    NotClosed(AccountingPeriodId),
+}
+
+es_entity::from_es_entity_error!(AccountingPeriodError);
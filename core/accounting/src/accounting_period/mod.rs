@@ -0,0 +1,225 @@
+mod entity;
+pub mod error;
+mod posting_lock;
+mod repo;
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    primitives::{
+        AccountingPeriodId, ApprovalProcessId, CoreAccountingAction, CoreAccountingObject,
+    },
+    processes::approve_period_reopen::APPROVE_PERIOD_REOPEN_PROCESS,
+};
+
+pub(super) use entity::*;
+pub use entity::{AccountingPeriod, AccountingPeriodStatus, ClosingEntry};
+use error::*;
+pub use posting_lock::PeriodPostingLock;
+pub(crate) use repo::AccountingPeriodRepo;
+pub use repo::accounting_period_cursor::AccountingPeriodsByCreatedAtCursor;
+
+pub struct AccountingPeriods<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    repo: AccountingPeriodRepo,
+    authz: Perms,
+    governance: Governance<Perms, E>,
+}
+
+impl<Perms, E> Clone for AccountingPeriods<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            authz: self.authz.clone(),
+            governance: self.governance.clone(),
+        }
+    }
+}
+
+impl<Perms, E> AccountingPeriods<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub(crate) fn new(
+        repo: &AccountingPeriodRepo,
+        authz: &Perms,
+        governance: &Governance<Perms, E>,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            authz: authz.clone(),
+            governance: governance.clone(),
+        }
+    }
+
+    #[instrument(name = "accounting_period.create", skip(self), err)]
+    pub async fn create(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<AccountingPeriod, AccountingPeriodError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_accounting_periods(),
+                CoreAccountingAction::ACCOUNTING_PERIOD_CREATE,
+            )
+            .await?;
+
+        if start_date > end_date {
+            return Err(AccountingPeriodError::InvalidDateRange);
+        }
+        if let Some(existing) = self.repo.find_overlapping(start_date, end_date).await? {
+            return Err(AccountingPeriodError::OverlappingPeriod(existing.id));
+        }
+
+        let new_period = NewAccountingPeriod::builder()
+            .id(AccountingPeriodId::new())
+            .start_date(start_date)
+            .end_date(end_date)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new accounting period");
+
+        self.repo.create(new_period).await
+    }
+
+    #[instrument(name = "accounting_period.find_by_id", skip(self), err)]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountingPeriodId> + std::fmt::Debug,
+    ) -> Result<Option<AccountingPeriod>, AccountingPeriodError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(period) => Ok(Some(period)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[instrument(name = "accounting_period.list", skip(self), err)]
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<AccountingPeriodsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<AccountingPeriod, AccountingPeriodsByCreatedAtCursor>,
+        AccountingPeriodError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_accounting_periods(),
+                CoreAccountingAction::ACCOUNTING_PERIOD_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await
+    }
+
+    pub async fn find_all<T: From<AccountingPeriod>>(
+        &self,
+        ids: &[AccountingPeriodId],
+    ) -> Result<HashMap<AccountingPeriodId, T>, AccountingPeriodError> {
+        self.repo.find_all(ids).await
+    }
+
+    /// Soft-closed periods still accept manual adjustments but signal that the
+    /// period is being reviewed ahead of the final close.
+    #[instrument(name = "accounting_period.soft_close", skip(self), err)]
+    pub async fn soft_close(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountingPeriodId> + std::fmt::Debug,
+    ) -> Result<AccountingPeriod, AccountingPeriodError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_SOFT_CLOSE,
+            )
+            .await?;
+
+        let mut period = self.repo.find_by_id(id).await?;
+        if period.soft_close(audit_info)?.did_execute() {
+            self.repo.update(&mut period).await?;
+        }
+
+        Ok(period)
+    }
+
+    #[instrument(name = "accounting_period.request_reopen", skip(self), err)]
+    pub async fn request_reopen(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountingPeriodId> + std::fmt::Debug,
+    ) -> Result<AccountingPeriod, AccountingPeriodError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_REQUEST_REOPEN,
+            )
+            .await?;
+
+        let mut period = self.repo.find_by_id(id).await?;
+        let approval_process_id = ApprovalProcessId::new();
+        if period
+            .request_reopen(approval_process_id, audit_info)?
+            .was_ignored()
+        {
+            return Ok(period);
+        }
+
+        let mut db = self.repo.begin_op().await?;
+        self.governance
+            .start_process(
+                &mut db,
+                approval_process_id,
+                period.id.to_string(),
+                APPROVE_PERIOD_REOPEN_PROCESS,
+            )
+            .await?;
+        self.repo.update_in_op(&mut db, &mut period).await?;
+        db.commit().await?;
+
+        Ok(period)
+    }
+}
@@ -0,0 +1,62 @@
+use chrono::NaiveDate;
+
+use crate::primitives::AccountingPeriodId;
+
+use super::{error::AccountingPeriodError, repo::AccountingPeriodRepo};
+
+/// Closed accounting periods are locked for postings. Every posting that carries
+/// an effective date, whether entered manually or generated by another module,
+/// is checked here inside the operation that posts it. The check holds a share
+/// lock on the covering period until that operation commits, so closing the
+/// period waits for in-flight postings and postings wait for a running close.
+#[derive(Clone)]
+pub struct PeriodPostingLock {
+    repo: AccountingPeriodRepo,
+}
+
+impl PeriodPostingLock {
+    pub fn new(pool: &sqlx::PgPool) -> Self {
+        Self {
+            repo: AccountingPeriodRepo::new(pool),
+        }
+    }
+
+    pub async fn is_locked_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        effective: NaiveDate,
+    ) -> Result<bool, AccountingPeriodError> {
+        match self.repo.find_covering(effective).await? {
+            Some(period) => self.is_closed_in_op(db, period.id).await,
+            None => Ok(false),
+        }
+    }
+
+    /// Postings generated by background processes cannot be rejected, so instead of
+    /// landing in a closed period they are booked on the first day after it.
+    pub async fn first_open_date_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        effective: NaiveDate,
+    ) -> Result<NaiveDate, AccountingPeriodError> {
+        let mut date = effective;
+        while let Some(period) = self.repo.find_covering(date).await? {
+            if !self.is_closed_in_op(db, period.id).await? {
+                break;
+            }
+            date = period.end_date.succ_opt().expect("end date out of range");
+        }
+        Ok(date)
+    }
+
+    /// The status is re-read once the lock is held, as a close that committed while
+    /// waiting for it would not show in the period found before.
+    async fn is_closed_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: AccountingPeriodId,
+    ) -> Result<bool, AccountingPeriodError> {
+        self.repo.lock_for_posting_in_op(db, id).await?;
+        Ok(self.repo.find_by_id(id).await?.is_closed())
+    }
+}
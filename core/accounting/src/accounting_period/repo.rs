@@ -0,0 +1,105 @@
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::AccountingPeriodId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "AccountingPeriod",
+    err = "AccountingPeriodError",
+    tbl_prefix = "core"
+)]
+pub struct AccountingPeriodRepo {
+    pool: PgPool,
+}
+
+impl Clone for AccountingPeriodRepo {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+impl AccountingPeriodRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+
+    pub async fn find_covering(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Option<AccountingPeriod>, AccountingPeriodError> {
+        Ok(self
+            .list_all()
+            .await?
+            .into_iter()
+            .find(|period| period.contains(date)))
+    }
+
+    pub async fn find_overlapping(
+        &self,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Option<AccountingPeriod>, AccountingPeriodError> {
+        Ok(self
+            .list_all()
+            .await?
+            .into_iter()
+            .find(|period| period.overlaps(start_date, end_date)))
+    }
+
+    /// Postings take a share lock on the row of the period they land in while closing
+    /// takes an exclusive one, so a period cannot close between a posting's check and
+    /// its commit.
+    pub async fn lock_for_posting_in_op(
+        &self,
+        db: &mut DbOp<'_>,
+        id: AccountingPeriodId,
+    ) -> Result<(), AccountingPeriodError> {
+        sqlx::query!(
+            "SELECT id FROM core_accounting_periods WHERE id = $1 FOR SHARE",
+            id as AccountingPeriodId
+        )
+        .fetch_one(&mut **db.tx())
+        .await?;
+        Ok(())
+    }
+
+    pub async fn lock_for_close_in_op(
+        &self,
+        db: &mut DbOp<'_>,
+        id: AccountingPeriodId,
+    ) -> Result<(), AccountingPeriodError> {
+        sqlx::query!(
+            "SELECT id FROM core_accounting_periods WHERE id = $1 FOR UPDATE",
+            id as AccountingPeriodId
+        )
+        .fetch_one(&mut **db.tx())
+        .await?;
+        Ok(())
+    }
+
+    /// Periods typically span a month or a quarter, so the full set stays small.
+    async fn list_all(&self) -> Result<Vec<AccountingPeriod>, AccountingPeriodError> {
+        let mut periods = Vec::new();
+        let mut after = None;
+        loop {
+            let res = self
+                .list_by_created_at(
+                    es_entity::PaginatedQueryArgs { first: 100, after },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            periods.extend(res.entities);
+            if !res.has_next_page {
+                return Ok(periods);
+            }
+            after = res.end_cursor;
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::{CalaCurrency, DebitOrCredit};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AccountingConfig {
+    /// USD amount below which manual transactions are posted without going through
+    /// approval. It is compared against the sum of all debit entries of the
+    /// transaction, and only a transaction whose entries are all in USD can
+    /// auto-approve, so splitting an entry across currencies or lines cannot get
+    /// under it. When unset every manual transaction requires approval.
+    #[serde(default)]
+    pub manual_transaction_approval_threshold: Option<Decimal>,
+}
+
+impl AccountingConfig {
+    pub fn manual_transaction_requires_approval(
+        &self,
+        entries: impl IntoIterator<Item = (CalaCurrency, DebitOrCredit, Decimal)>,
+    ) -> bool {
+        let Some(threshold) = self.manual_transaction_approval_threshold else {
+            return true;
+        };
+        let mut debited = Decimal::ZERO;
+        for (currency, direction, amount) in entries {
+            if currency != CalaCurrency::USD {
+                return true;
+            }
+            if matches!(direction, DebitOrCredit::Debit) {
+                debited += amount;
+            }
+        }
+        debited >= threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn config() -> AccountingConfig {
+        AccountingConfig {
+            manual_transaction_approval_threshold: Some(dec!(1000)),
+        }
+    }
+
+    #[test]
+    fn threshold_applies_to_total_debits() {
+        let small = [
+            (CalaCurrency::USD, DebitOrCredit::Debit, dec!(600)),
+            (CalaCurrency::USD, DebitOrCredit::Credit, dec!(600)),
+        ];
+        assert!(!config().manual_transaction_requires_approval(small));
+
+        let split = [
+            (CalaCurrency::USD, DebitOrCredit::Debit, dec!(600)),
+            (CalaCurrency::USD, DebitOrCredit::Debit, dec!(600)),
+            (CalaCurrency::USD, DebitOrCredit::Credit, dec!(1200)),
+        ];
+        assert!(config().manual_transaction_requires_approval(split));
+    }
+
+    #[test]
+    fn any_non_usd_entry_requires_approval() {
+        let split = [
+            (CalaCurrency::USD, DebitOrCredit::Debit, dec!(600)),
+            (CalaCurrency::USD, DebitOrCredit::Credit, dec!(600)),
+            (CalaCurrency::BTC, DebitOrCredit::Debit, dec!(0.01)),
+            (CalaCurrency::BTC, DebitOrCredit::Credit, dec!(0.01)),
+        ];
+        assert!(config().manual_transaction_requires_approval(split));
+    }
+}
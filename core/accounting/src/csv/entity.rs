@@ -6,7 +6,7 @@ use audit::AuditInfo;
 use es_entity::*;
 
 use crate::csv::primitives::{
-    AccountingCsvLocationInCloud, AccountingCsvStatus, AccountingCsvType,
+    AccountingCsvFormat, AccountingCsvLocationInCloud, AccountingCsvStatus, AccountingCsvType,
 };
 use crate::primitives::{AccountingCsvId, LedgerAccountId};
 
@@ -19,7 +19,15 @@ pub enum AccountingCsvEvent {
     Initialized {
         id: AccountingCsvId,
         csv_type: AccountingCsvType,
+        #[serde(default)]
+        format: AccountingCsvFormat,
         ledger_account_id: Option<LedgerAccountId>,
+        #[serde(default)]
+        statement_reference: Option<String>,
+        #[serde(default)]
+        from: Option<DateTime<Utc>>,
+        #[serde(default)]
+        until: Option<DateTime<Utc>>,
         audit_info: AuditInfo,
     },
     FileUploaded {
@@ -46,8 +54,15 @@ pub enum AccountingCsvEvent {
 pub struct AccountingCsv {
     pub id: AccountingCsvId,
     pub csv_type: AccountingCsvType,
+    pub format: AccountingCsvFormat,
     #[builder(setter(strip_option), default)]
     pub ledger_account_id: Option<LedgerAccountId>,
+    #[builder(setter(strip_option), default)]
+    pub statement_reference: Option<String>,
+    #[builder(setter(strip_option), default)]
+    pub from: Option<DateTime<Utc>>,
+    #[builder(default)]
+    pub until: Option<DateTime<Utc>>,
     pub(super) events: EntityEvents<AccountingCsvEvent>,
 }
 
@@ -184,14 +199,28 @@ impl TryFromEvents<AccountingCsvEvent> for AccountingCsv {
             if let AccountingCsvEvent::Initialized {
                 id,
                 csv_type,
+                format,
                 ledger_account_id,
+                statement_reference,
+                from,
+                until,
                 ..
             } = event
             {
-                builder = builder.id(*id).csv_type(*csv_type);
+                builder = builder
+                    .id(*id)
+                    .csv_type(*csv_type)
+                    .format(*format)
+                    .until(*until);
                 if let Some(account_id) = ledger_account_id {
                     builder = builder.ledger_account_id(*account_id);
                 }
+                if let Some(reference) = statement_reference {
+                    builder = builder.statement_reference(reference.clone());
+                }
+                if let Some(from) = from {
+                    builder = builder.from(*from);
+                }
             }
         }
         builder.events(events).build()
@@ -204,8 +233,16 @@ pub struct NewAccountingCsv {
     pub(super) id: AccountingCsvId,
     #[builder(setter(into))]
     pub(super) csv_type: AccountingCsvType,
+    #[builder(default)]
+    pub(super) format: AccountingCsvFormat,
     #[builder(setter(strip_option), default)]
     pub(super) ledger_account_id: Option<LedgerAccountId>,
+    #[builder(setter(into, strip_option), default)]
+    pub(super) statement_reference: Option<String>,
+    #[builder(setter(strip_option), default)]
+    pub(super) from: Option<DateTime<Utc>>,
+    #[builder(default)]
+    pub(super) until: Option<DateTime<Utc>>,
     #[builder(setter(into))]
     pub(super) audit_info: AuditInfo,
 }
@@ -222,7 +259,11 @@ impl IntoEvents<AccountingCsvEvent> for NewAccountingCsv {
             [AccountingCsvEvent::Initialized {
                 id: self.id,
                 csv_type: self.csv_type,
+                format: self.format,
                 ledger_account_id: self.ledger_account_id,
+                statement_reference: self.statement_reference,
+                from: self.from,
+                until: self.until,
                 audit_info: self.audit_info,
             }],
         )
@@ -12,12 +12,24 @@ pub enum AccountingCsvError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("AccountingCsvError - LedgerAccountError: {0}")]
     LedgerAccountError(#[from] crate::ledger_account::error::LedgerAccountError),
+    #[error("AccountingCsvError - ProfitAndLossStatementError: {0}")]
+    ProfitAndLossStatementError(#[from] crate::profit_and_loss::error::ProfitAndLossStatementError),
+    #[error("AccountingCsvError - BalanceSheetError: {0}")]
+    BalanceSheetError(#[from] crate::balance_sheet::error::BalanceSheetError),
     #[error("AccountingCsvError - StorageError: {0}")]
     StorageError(#[from] cloud_storage::error::StorageError),
     #[error("AccountingCsvError - JobError: {0}")]
     JobError(#[from] job::error::JobError),
+    #[error("AccountingCsvError - JournalError: {0}")]
+    JournalError(#[from] crate::journal::error::JournalError),
+    #[error("AccountingCsvError - TrialBalanceError: {0}")]
+    TrialBalanceError(#[from] crate::trial_balance::error::TrialBalanceError),
     #[error("AccountingCsvError - CsvError: {0}")]
     CsvError(String),
+    #[error("AccountingCsvError - XlsxError: {0}")]
+    XlsxError(#[from] rust_xlsxwriter::XlsxError),
+    #[error("AccountingCsvError - IoError: {0}")]
+    IoError(#[from] std::io::Error),
     #[error("AccountingCsvError - UnsupportedCsvType")]
     UnsupportedCsvType,
     #[error("AccountingCsvError - CsvNotReady")]
@@ -26,6 +38,8 @@ pub enum AccountingCsvError {
     CsvFileNotFound,
     #[error("AccountingCsvError - MissingRequiredField: {0}")]
     MissingRequiredField(String),
+    #[error("AccountingCsvError - AmountNotRepresentable: {0} cannot be written exactly")]
+    AmountNotRepresentable(rust_decimal::Decimal),
 }
 
 es_entity::from_es_entity_error!(AccountingCsvError);
@@ -1,16 +1,29 @@
-use csv::Writer;
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
+use std::collections::HashMap;
+use tempfile::NamedTempFile;
 
 use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::DebitOrCredit;
 
 use crate::{
-    CoreAccountingAction, CoreAccountingObject, ledger_account::LedgerAccounts,
-    primitives::LedgerAccountId,
+    CoreAccountingAction, CoreAccountingObject,
+    balance_sheet::BalanceSheets,
+    journal::{Journal, JournalEntry, JournalEntryAmount},
+    ledger_account::{LedgerAccount, LedgerAccounts},
+    primitives::{BalanceRange, CalaAccountBalance, CalaAccountSetId, LedgerAccountId},
+    profit_and_loss::ProfitAndLossStatements,
+    trial_balance::TrialBalances,
 };
 
-use super::error::AccountingCsvError;
+use super::{
+    error::AccountingCsvError,
+    primitives::AccountingCsvFormat,
+    writer::{ReportCell, ReportWriter},
+};
+
+const PAGE_SIZE: usize = 100;
 
 pub struct GenerateCsv<Perms>
 where
@@ -19,6 +32,37 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
 {
     ledger_accounts: LedgerAccounts<Perms>,
+    profit_and_loss: ProfitAndLossStatements<Perms>,
+    balance_sheets: BalanceSheets<Perms>,
+    trial_balances: TrialBalances<Perms>,
+    journal: Journal<Perms>,
+}
+
+/// Which end of a statement line's balance range is reported.
+#[derive(Clone, Copy)]
+enum StatementBalance {
+    /// Movement over the range, as reported on the profit and loss statement.
+    Diff,
+    /// Balance at the end of the range, as reported on the balance sheet.
+    End,
+}
+
+struct StatementLine {
+    name: String,
+    code: Option<String>,
+    usd_balance_range: Option<BalanceRange>,
+    btc_balance_range: Option<BalanceRange>,
+}
+
+impl From<LedgerAccount> for StatementLine {
+    fn from(account: LedgerAccount) -> Self {
+        Self {
+            name: account.name,
+            code: account.code.map(|code| code.to_string()),
+            usd_balance_range: account.usd_balance_range,
+            btc_balance_range: account.btc_balance_range,
+        }
+    }
 }
 
 impl<Perms> GenerateCsv<Perms>
@@ -27,9 +71,19 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
 {
-    pub fn new(ledger_accounts: &LedgerAccounts<Perms>) -> Self {
+    pub fn new(
+        ledger_accounts: &LedgerAccounts<Perms>,
+        profit_and_loss: &ProfitAndLossStatements<Perms>,
+        balance_sheets: &BalanceSheets<Perms>,
+        trial_balances: &TrialBalances<Perms>,
+        journal: &Journal<Perms>,
+    ) -> Self {
         Self {
             ledger_accounts: ledger_accounts.clone(),
+            profit_and_loss: profit_and_loss.clone(),
+            balance_sheets: balance_sheets.clone(),
+            trial_balances: trial_balances.clone(),
+            journal: journal.clone(),
         }
     }
 
@@ -37,47 +91,465 @@ where
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         ledger_account_id: LedgerAccountId,
-    ) -> Result<Vec<u8>, AccountingCsvError> {
-        let history_result = self
-            .ledger_accounts
-            .history(sub, ledger_account_id, Default::default())
-            .await
-            .map_err(AccountingCsvError::LedgerAccountError)?;
-
-        let mut wtr = Writer::from_writer(vec![]);
-        wtr.write_record([
+        format: AccountingCsvFormat,
+    ) -> Result<NamedTempFile, AccountingCsvError> {
+        let mut wtr = ReportWriter::new(format)?;
+        wtr.write_header(&[
+            "Recorded At",
+            "Currency",
+            "Debit Amount",
+            "Credit Amount",
+            "Description",
+            "Entry Type",
+        ])?;
+
+        let mut after = None;
+        loop {
+            let history_result = self
+                .ledger_accounts
+                .history(
+                    sub,
+                    ledger_account_id,
+                    es_entity::PaginatedQueryArgs {
+                        first: PAGE_SIZE,
+                        after,
+                    },
+                )
+                .await
+                .map_err(AccountingCsvError::LedgerAccountError)?;
+
+            for entry in history_result.entities {
+                let (debit_amount, credit_amount) = debit_and_credit(&entry);
+                wtr.write_row([
+                    ReportCell::from(entry.created_at.to_rfc3339()),
+                    ReportCell::from(entry.amount.currency_code()),
+                    debit_amount,
+                    credit_amount,
+                    ReportCell::from(entry.description.unwrap_or_default()),
+                    ReportCell::from(entry.entry_type),
+                ])?;
+            }
+
+            if !history_result.has_next_page {
+                break;
+            }
+            after = history_result.end_cursor;
+        }
+
+        wtr.finish()
+    }
+
+    pub async fn generate_profit_and_loss_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        from: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+        format: AccountingCsvFormat,
+    ) -> Result<NamedTempFile, AccountingCsvError> {
+        let statement = self
+            .profit_and_loss
+            .pl_statement(sub, reference, from, until)
+            .await?;
+
+        let root = StatementLine {
+            name: statement.name,
+            code: None,
+            usd_balance_range: statement.usd_balance_range,
+            btc_balance_range: statement.btc_balance_range,
+        };
+        self.generate_statement_csv(
+            statement.id,
+            root,
+            from,
+            until,
+            StatementBalance::Diff,
+            format,
+        )
+        .await
+    }
+
+    pub async fn generate_balance_sheet_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        from: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+        format: AccountingCsvFormat,
+    ) -> Result<NamedTempFile, AccountingCsvError> {
+        let balance_sheet = self
+            .balance_sheets
+            .balance_sheet(sub, reference, from, until)
+            .await?;
+
+        let root = StatementLine {
+            name: balance_sheet.name,
+            code: None,
+            usd_balance_range: balance_sheet.usd_balance_range,
+            btc_balance_range: balance_sheet.btc_balance_range,
+        };
+        self.generate_statement_csv(
+            balance_sheet.id,
+            root,
+            from,
+            until,
+            StatementBalance::End,
+            format,
+        )
+        .await
+    }
+
+    pub async fn generate_trial_balance_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+        format: AccountingCsvFormat,
+    ) -> Result<NamedTempFile, AccountingCsvError> {
+        let trial_balance = self
+            .trial_balances
+            .trial_balance(sub, reference, from, until)
+            .await?;
+
+        let mut wtr = ReportWriter::new(format)?;
+        wtr.write_header(&[
+            "Account Code",
+            "Account Name",
+            "Currency",
+            "Opening Balance",
+            "Debits",
+            "Credits",
+            "Closing Balance",
+        ])?;
+
+        let mut after = None;
+        loop {
+            let res = self
+                .ledger_accounts
+                .list_children_in_range(
+                    trial_balance.id,
+                    es_entity::PaginatedQueryArgs {
+                        first: PAGE_SIZE,
+                        after,
+                    },
+                    from,
+                    Some(until),
+                )
+                .await?;
+
+            for account in res.entities {
+                let code = account
+                    .code
+                    .as_ref()
+                    .map(|code| code.to_string())
+                    .unwrap_or_default();
+                for (currency, range) in [
+                    ("USD", account.usd_balance_range.as_ref()),
+                    ("BTC", account.btc_balance_range.as_ref()),
+                ] {
+                    let Some(range) = range else {
+                        continue;
+                    };
+                    let (debits, credits) = range
+                        .diff
+                        .as_ref()
+                        .map(|diff| {
+                            (
+                                diff.details.settled.dr_balance,
+                                diff.details.settled.cr_balance,
+                            )
+                        })
+                        .unwrap_or_default();
+                    wtr.write_row([
+                        ReportCell::from(code.clone()),
+                        ReportCell::from(account.name.clone()),
+                        ReportCell::from(currency.to_string()),
+                        ReportCell::from(settled(range.start.as_ref())),
+                        ReportCell::from(debits),
+                        ReportCell::from(credits),
+                        ReportCell::from(settled(range.end.as_ref())),
+                    ])?;
+                }
+            }
+
+            if !res.has_next_page {
+                break;
+            }
+            after = res.end_cursor;
+        }
+
+        wtr.finish()
+    }
+
+    /// Entries are selected by the effective date of their transaction, as in the
+    /// trial balance. Backdated and forwarded postings mean the recording order says
+    /// nothing about the effective date, so the whole journal is walked, holding one
+    /// page in memory at a time.
+    pub async fn generate_general_ledger_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        from: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+        format: AccountingCsvFormat,
+    ) -> Result<NamedTempFile, AccountingCsvError> {
+        let mut wtr = ReportWriter::new(format)?;
+        wtr.write_header(&[
+            "Effective Date",
             "Recorded At",
+            "Transaction Id",
+            "Entry Id",
+            "Ledger Account Id",
             "Currency",
             "Debit Amount",
             "Credit Amount",
+            "Layer",
             "Description",
             "Entry Type",
-        ])
-        .map_err(|e| AccountingCsvError::CsvError(e.to_string()))?;
-
-        for entry in history_result.entities {
-            let formatted_amount = entry.amount.to_display_amount();
-            let currency = entry.amount.currency_code();
-
-            let (debit_amount, credit_amount) = match entry.direction {
-                DebitOrCredit::Debit => (formatted_amount, Decimal::from(0).to_string()),
-                DebitOrCredit::Credit => (Decimal::from(0).to_string(), formatted_amount),
-            };
-
-            wtr.write_record(&[
-                entry.created_at.to_rfc3339(),
-                currency,
-                debit_amount,
-                credit_amount,
-                entry.description.unwrap_or_default(),
-                entry.entry_type,
-            ])
-            .map_err(|e| AccountingCsvError::CsvError(e.to_string()))?;
+        ])?;
+
+        let from = from.date_naive();
+        let until = until.map(|until| until.date_naive());
+        let mut after = None;
+        loop {
+            let res = self
+                .journal
+                .entries(
+                    sub,
+                    es_entity::PaginatedQueryArgs {
+                        first: PAGE_SIZE,
+                        after,
+                    },
+                )
+                .await?;
+
+            let tx_ids: Vec<_> = res
+                .entities
+                .iter()
+                .map(|entry| entry.ledger_transaction_id)
+                .collect();
+            let effective_dates = self.journal.effective_dates(&tx_ids).await?;
+
+            for entry in res.entities {
+                let effective = effective_dates
+                    .get(&entry.ledger_transaction_id)
+                    .copied()
+                    .ok_or_else(|| {
+                        AccountingCsvError::MissingRequiredField(format!(
+                            "effective date of transaction {}",
+                            entry.ledger_transaction_id
+                        ))
+                    })?;
+                if effective < from || until.is_some_and(|until| effective > until) {
+                    continue;
+                }
+
+                let (debit_amount, credit_amount) = debit_and_credit(&entry);
+                wtr.write_row([
+                    ReportCell::from(effective.to_string()),
+                    ReportCell::from(entry.created_at.to_rfc3339()),
+                    ReportCell::from(entry.ledger_transaction_id.to_string()),
+                    ReportCell::from(entry.entry_id.to_string()),
+                    ReportCell::from(entry.ledger_account_id.to_string()),
+                    ReportCell::from(entry.amount.currency_code()),
+                    debit_amount,
+                    credit_amount,
+                    ReportCell::from(format!("{:?}", entry.layer)),
+                    ReportCell::from(entry.description.unwrap_or_default()),
+                    ReportCell::from(entry.entry_type),
+                ])?;
+            }
+
+            if !res.has_next_page {
+                break;
+            }
+            after = res.end_cursor;
+        }
+
+        wtr.finish()
+    }
+
+    async fn generate_statement_csv(
+        &self,
+        root_id: LedgerAccountId,
+        root: StatementLine,
+        from: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+        balance: StatementBalance,
+        format: AccountingCsvFormat,
+    ) -> Result<NamedTempFile, AccountingCsvError> {
+        let mut lines = HashMap::new();
+        let mut children_by_parent = HashMap::new();
+        let mut pending = vec![root_id];
+        while let Some(parent_id) = pending.pop() {
+            let children = self
+                .ledger_accounts
+                .find_all_children_in_range(CalaAccountSetId::from(parent_id), from, until)
+                .await?;
+            let mut child_ids = Vec::new();
+            for child in children.into_iter().filter(|child| !child.is_leaf()) {
+                child_ids.push(child.id);
+                pending.push(child.id);
+                lines.insert(child.id, StatementLine::from(child));
+            }
+            children_by_parent.insert(parent_id, child_ids);
+        }
+        lines.insert(root_id, root);
+
+        let mut wtr = ReportWriter::new(format)?;
+        wtr.write_header(&[
+            "Level",
+            "Account Code",
+            "Account Name",
+            "USD Balance",
+            "BTC Balance",
+        ])?;
+        write_statement_line(&mut wtr, root_id, 0, &lines, &children_by_parent, balance)?;
+
+        wtr.finish()
+    }
+}
+
+/// Writes `id` and its descendants depth first. Lines with children get a heading row
+/// before them and a subtotal row after them carrying the line's own balance.
+fn write_statement_line(
+    wtr: &mut ReportWriter,
+    id: LedgerAccountId,
+    level: usize,
+    lines: &HashMap<LedgerAccountId, StatementLine>,
+    children_by_parent: &HashMap<LedgerAccountId, Vec<LedgerAccountId>>,
+    balance: StatementBalance,
+) -> Result<(), AccountingCsvError> {
+    let line = &lines[&id];
+    let code = line.code.clone().unwrap_or_default();
+    let usd = balance_amount(line.usd_balance_range.as_ref(), balance);
+    let btc = balance_amount(line.btc_balance_range.as_ref(), balance);
+
+    let children = children_by_parent
+        .get(&id)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    if children.is_empty() {
+        wtr.write_row([
+            ReportCell::from(level.to_string()),
+            ReportCell::from(code),
+            ReportCell::from(line.name.clone()),
+            ReportCell::from(usd),
+            ReportCell::from(btc),
+        ])?;
+        return Ok(());
+    }
+
+    wtr.write_row([
+        ReportCell::from(level.to_string()),
+        ReportCell::from(code.clone()),
+        ReportCell::from(line.name.clone()),
+        ReportCell::Blank,
+        ReportCell::Blank,
+    ])?;
+    for child_id in children {
+        write_statement_line(
+            wtr,
+            *child_id,
+            level + 1,
+            lines,
+            children_by_parent,
+            balance,
+        )?;
+    }
+    wtr.write_row([
+        ReportCell::from(level.to_string()),
+        ReportCell::from(code),
+        ReportCell::from(format!("Total {}", line.name)),
+        ReportCell::from(usd),
+        ReportCell::from(btc),
+    ])?;
+
+    Ok(())
+}
+
+fn balance_amount(range: Option<&BalanceRange>, balance: StatementBalance) -> Decimal {
+    settled(range.and_then(|range| match balance {
+        StatementBalance::Diff => range.diff.as_ref(),
+        StatementBalance::End => range.end.as_ref(),
+    }))
+}
+
+fn settled(balance: Option<&CalaAccountBalance>) -> Decimal {
+    balance
+        .map(|balance| balance.settled())
+        .unwrap_or(Decimal::ZERO)
+}
+
+/// Amounts keep the scale of their currency, so both columns of an entry read
+/// e.g. `10.50` and `0.00` for USD.
+fn debit_and_credit(entry: &JournalEntry) -> (ReportCell, ReportCell) {
+    let (mut amount, scale) = match entry.amount {
+        JournalEntryAmount::Usd(cents) => (cents.to_usd(), 2),
+        JournalEntryAmount::Btc(sats) => (sats.to_btc(), 8),
+    };
+    amount.rescale(scale);
+    let zero = Decimal::new(0, scale);
+    match entry.direction {
+        DebitOrCredit::Debit => (ReportCell::from(amount), ReportCell::from(zero)),
+        DebitOrCredit::Credit => (ReportCell::from(zero), ReportCell::from(amount)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    fn line(name: &str, code: &str) -> StatementLine {
+        StatementLine {
+            name: name.to_string(),
+            code: Some(code.to_string()),
+            usd_balance_range: None,
+            btc_balance_range: None,
         }
-        let csv_data = wtr
-            .into_inner()
-            .map_err(|e| AccountingCsvError::CsvError(e.to_string()))?;
+    }
+
+    #[test]
+    fn statement_lines_are_nested_with_subtotals() {
+        let root = LedgerAccountId::new();
+        let cash = LedgerAccountId::new();
+        let loans = LedgerAccountId::new();
+        let lines = HashMap::from([
+            (root, line("Assets", "1")),
+            (cash, line("Cash", "11")),
+            (loans, line("Loans", "12")),
+        ]);
+        let children_by_parent = HashMap::from([(root, vec![cash, loans])]);
+
+        let mut wtr = ReportWriter::new(AccountingCsvFormat::Csv).unwrap();
+        write_statement_line(
+            &mut wtr,
+            root,
+            0,
+            &lines,
+            &children_by_parent,
+            StatementBalance::End,
+        )
+        .unwrap();
+
+        let mut csv = String::new();
+        wtr.finish()
+            .unwrap()
+            .reopen()
+            .unwrap()
+            .read_to_string(&mut csv)
+            .unwrap();
+        assert_eq!(
+            csv,
+            "0,1,Assets,,\n1,11,Cash,0,0\n1,12,Loans,0,0\n0,1,Total Assets,0,0\n"
+        );
+    }
 
-        Ok(csv_data)
+    #[test]
+    fn missing_balance_range_is_zero() {
+        assert_eq!(balance_amount(None, StatementBalance::Diff), Decimal::ZERO);
+        assert_eq!(balance_amount(None, StatementBalance::End), Decimal::ZERO);
     }
 }
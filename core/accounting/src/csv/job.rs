@@ -7,11 +7,15 @@ use cloud_storage::Storage;
 use job::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{ledger_account::LedgerAccounts, primitives::AccountingCsvId};
+use crate::{
+    balance_sheet::BalanceSheets, journal::Journal, ledger_account::LedgerAccounts,
+    primitives::AccountingCsvId, profit_and_loss::ProfitAndLossStatements,
+    trial_balance::TrialBalances,
+};
 
 use super::{
-    CoreAccountingAction, CoreAccountingObject, error::AccountingCsvError, generate::GenerateCsv,
-    primitives::AccountingCsvType, repo::AccountingCsvRepo,
+    AccountingCsv, CoreAccountingAction, CoreAccountingObject, error::AccountingCsvError,
+    generate::GenerateCsv, primitives::AccountingCsvType, repo::AccountingCsvRepo,
 };
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -38,6 +42,10 @@ where
     repo: AccountingCsvRepo,
     storage: Storage,
     ledger_accounts: LedgerAccounts<Perms>,
+    profit_and_loss: ProfitAndLossStatements<Perms>,
+    balance_sheets: BalanceSheets<Perms>,
+    trial_balances: TrialBalances<Perms>,
+    journal: Journal<Perms>,
     audit: Perms::Audit,
 }
 
@@ -47,16 +55,25 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         repo: &AccountingCsvRepo,
         storage: &Storage,
         ledger_accounts: &LedgerAccounts<Perms>,
+        profit_and_loss: &ProfitAndLossStatements<Perms>,
+        balance_sheets: &BalanceSheets<Perms>,
+        trial_balances: &TrialBalances<Perms>,
+        journal: &Journal<Perms>,
         audit: &Perms::Audit,
     ) -> Self {
         Self {
             repo: repo.clone(),
             storage: storage.clone(),
             ledger_accounts: ledger_accounts.clone(),
+            profit_and_loss: profit_and_loss.clone(),
+            balance_sheets: balance_sheets.clone(),
+            trial_balances: trial_balances.clone(),
+            journal: journal.clone(),
             audit: audit.clone(),
         }
     }
@@ -82,7 +99,13 @@ where
             config: job.config()?,
             repo: self.repo.clone(),
             storage: self.storage.clone(),
-            generator: GenerateCsv::new(&self.ledger_accounts),
+            generator: GenerateCsv::new(
+                &self.ledger_accounts,
+                &self.profit_and_loss,
+                &self.balance_sheets,
+                &self.trial_balances,
+                &self.journal,
+            ),
             audit: self.audit.clone(),
         }))
     }
@@ -134,19 +157,78 @@ where
                     .generate_ledger_account_csv(
                         &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(),
                         ledger_account_id,
+                        export.format,
+                    )
+                    .await
+            }
+            AccountingCsvType::ProfitAndLoss => {
+                let (reference, from) = statement_params(&export)?;
+
+                self.generator
+                    .generate_profit_and_loss_csv(
+                        &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(),
+                        reference,
+                        from,
+                        export.until,
+                        export.format,
+                    )
+                    .await
+            }
+            AccountingCsvType::BalanceSheet => {
+                let (reference, from) = statement_params(&export)?;
+
+                self.generator
+                    .generate_balance_sheet_csv(
+                        &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(),
+                        reference,
+                        from,
+                        export.until,
+                        export.format,
+                    )
+                    .await
+            }
+            AccountingCsvType::TrialBalance => {
+                let (reference, from) = statement_params(&export)?;
+                let until = export
+                    .until
+                    .ok_or_else(|| AccountingCsvError::MissingRequiredField("until".to_string()))?;
+
+                self.generator
+                    .generate_trial_balance_csv(
+                        &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(),
+                        reference,
+                        from,
+                        until,
+                        export.format,
+                    )
+                    .await
+            }
+            AccountingCsvType::GeneralLedger => {
+                let from = export
+                    .from
+                    .ok_or_else(|| AccountingCsvError::MissingRequiredField("from".to_string()))?;
+
+                self.generator
+                    .generate_general_ledger_csv(
+                        &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(),
+                        from,
+                        export.until,
+                        export.format,
                     )
                     .await
             }
-            AccountingCsvType::ProfitAndLoss => Err(AccountingCsvError::UnsupportedCsvType),
-            AccountingCsvType::BalanceSheet => Err(AccountingCsvError::UnsupportedCsvType),
         };
 
         match csv_result {
-            Ok(csv_data) => {
-                let path_in_bucket = format!("accounting_csvs/{}.csv", export.id);
+            Ok(file) => {
+                let path_in_bucket = format!(
+                    "accounting_csvs/{}.{}",
+                    export.id,
+                    export.format.file_extension()
+                );
                 match self
                     .storage
-                    .upload(csv_data, &path_in_bucket, "text/csv")
+                    .upload_file(file.path(), &path_in_bucket, export.format.content_type())
                     .await
                 {
                     Ok(_) => {
@@ -172,3 +254,15 @@ where
         Ok(JobCompletion::CompleteWithOp(db_static))
     }
 }
+
+fn statement_params(
+    export: &AccountingCsv,
+) -> Result<(String, chrono::DateTime<chrono::Utc>), AccountingCsvError> {
+    let reference = export.statement_reference.clone().ok_or_else(|| {
+        AccountingCsvError::MissingRequiredField("statement_reference".to_string())
+    })?;
+    let from = export
+        .from
+        .ok_or_else(|| AccountingCsvError::MissingRequiredField("from".to_string()))?;
+    Ok((reference, from))
+}
@@ -4,22 +4,29 @@ mod generate;
 mod job;
 mod primitives;
 mod repo;
+mod writer;
 
 use crate::Jobs;
 use crate::Storage;
+use chrono::{DateTime, Utc};
+
 use audit::AuditSvc;
 use authz::PermissionCheck;
 
 use super::{
     CoreAccountingAction, CoreAccountingObject,
+    balance_sheet::BalanceSheets,
+    journal::Journal,
     ledger_account::LedgerAccounts,
     primitives::{AccountingCsvId, LedgerAccountId},
+    profit_and_loss::ProfitAndLossStatements,
+    trial_balance::TrialBalances,
 };
 
 pub use entity::*;
 use error::*;
 use job::*;
-use primitives::*;
+pub use primitives::*;
 use repo::*;
 
 #[derive(Clone)]
@@ -39,12 +46,17 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         pool: &sqlx::PgPool,
         authz: &Perms,
         jobs: &Jobs,
         storage: &Storage,
         ledger_accounts: &LedgerAccounts<Perms>,
+        profit_and_loss: &ProfitAndLossStatements<Perms>,
+        balance_sheets: &BalanceSheets<Perms>,
+        trial_balances: &TrialBalances<Perms>,
+        journal: &Journal<Perms>,
     ) -> Self {
         let repo = AccountingCsvRepo::new(pool);
 
@@ -52,6 +64,10 @@ where
             &repo,
             storage,
             ledger_accounts,
+            profit_and_loss,
+            balance_sheets,
+            trial_balances,
+            journal,
             authz.audit(),
         ));
 
@@ -67,6 +83,7 @@ where
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         ledger_account_id: impl Into<LedgerAccountId> + std::fmt::Debug,
+        format: AccountingCsvFormat,
     ) -> Result<AccountingCsv, AccountingCsvError> {
         let ledger_account_id = ledger_account_id.into();
         let id = AccountingCsvId::new();
@@ -83,11 +100,129 @@ where
         let new_csv = NewAccountingCsv::builder()
             .id(id)
             .csv_type(AccountingCsvType::LedgerAccount)
+            .format(format)
             .ledger_account_id(ledger_account_id)
             .audit_info(audit_info)
             .build()
             .expect("Could not build new Accounting CSV");
 
+        self.create_and_spawn(new_csv).await
+    }
+
+    pub async fn create_profit_and_loss_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        from: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+        format: AccountingCsvFormat,
+    ) -> Result<AccountingCsv, AccountingCsvError> {
+        self.create_period_csv(
+            sub,
+            AccountingCsvType::ProfitAndLoss,
+            Some(reference),
+            from,
+            until,
+            format,
+        )
+        .await
+    }
+
+    pub async fn create_balance_sheet_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        from: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+        format: AccountingCsvFormat,
+    ) -> Result<AccountingCsv, AccountingCsvError> {
+        self.create_period_csv(
+            sub,
+            AccountingCsvType::BalanceSheet,
+            Some(reference),
+            from,
+            until,
+            format,
+        )
+        .await
+    }
+
+    pub async fn create_trial_balance_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+        format: AccountingCsvFormat,
+    ) -> Result<AccountingCsv, AccountingCsvError> {
+        self.create_period_csv(
+            sub,
+            AccountingCsvType::TrialBalance,
+            Some(reference),
+            from,
+            Some(until),
+            format,
+        )
+        .await
+    }
+
+    pub async fn create_general_ledger_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        from: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+        format: AccountingCsvFormat,
+    ) -> Result<AccountingCsv, AccountingCsvError> {
+        self.create_period_csv(
+            sub,
+            AccountingCsvType::GeneralLedger,
+            None,
+            from,
+            until,
+            format,
+        )
+        .await
+    }
+
+    async fn create_period_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        csv_type: AccountingCsvType,
+        statement_reference: Option<String>,
+        from: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+        format: AccountingCsvFormat,
+    ) -> Result<AccountingCsv, AccountingCsvError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_accounting_csvs(),
+                CoreAccountingAction::ACCOUNTING_CSV_CREATE,
+            )
+            .await?;
+
+        let mut builder = NewAccountingCsv::builder();
+        if let Some(reference) = statement_reference {
+            builder = builder.statement_reference(reference);
+        }
+        let new_csv = builder
+            .id(AccountingCsvId::new())
+            .csv_type(csv_type)
+            .format(format)
+            .from(from)
+            .until(until)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new Accounting CSV");
+
+        self.create_and_spawn(new_csv).await
+    }
+
+    async fn create_and_spawn(
+        &self,
+        new_csv: NewAccountingCsv,
+    ) -> Result<AccountingCsv, AccountingCsvError> {
         let mut db = self.repo.begin_op().await?;
         let csv = self.repo.create_in_op(&mut db, new_csv).await?;
         self.jobs
@@ -129,6 +264,7 @@ where
             accounting_csv_id,
             link: AccountingCsvDownloadLink {
                 csv_type: csv.csv_type,
+                format: csv.format,
                 url,
             },
         })
@@ -10,6 +10,45 @@ pub enum AccountingCsvType {
     LedgerAccount,
     ProfitAndLoss,
     BalanceSheet,
+    TrialBalance,
+    GeneralLedger,
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountingCsvFormat {
+    #[default]
+    Csv,
+    Xlsx,
+}
+
+impl AccountingCsvFormat {
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            AccountingCsvFormat::Csv => "csv",
+            AccountingCsvFormat::Xlsx => "xlsx",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            AccountingCsvFormat::Csv => "text/csv",
+            AccountingCsvFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -38,6 +77,7 @@ impl<'a> From<&AccountingCsvLocationInCloud<'a>> for cloud_storage::LocationInCl
 #[derive(Debug, Clone)]
 pub struct AccountingCsvDownloadLink {
     pub csv_type: AccountingCsvType,
+    pub format: AccountingCsvFormat,
     pub url: String,
 }
 
@@ -0,0 +1,221 @@
+use csv::Writer;
+use rust_decimal::{
+    Decimal,
+    prelude::{FromPrimitive, ToPrimitive},
+};
+use rust_xlsxwriter::{Format, Workbook};
+use tempfile::NamedTempFile;
+
+use super::{error::AccountingCsvError, primitives::AccountingCsvFormat};
+
+pub(super) enum ReportCell {
+    Text(String),
+    Amount(Decimal),
+    Blank,
+}
+
+impl From<String> for ReportCell {
+    fn from(value: String) -> Self {
+        ReportCell::Text(value)
+    }
+}
+
+impl From<Decimal> for ReportCell {
+    fn from(value: Decimal) -> Self {
+        ReportCell::Amount(value)
+    }
+}
+
+/// XLSX stores numbers as doubles. An amount is only written as a number when the
+/// double rounds back to exactly the same amount at the amount's own scale, which
+/// holds for up to 15 significant digits; anything larger is reported instead of
+/// being silently rounded.
+fn xlsx_number(value: Decimal) -> Result<f64, AccountingCsvError> {
+    value
+        .to_f64()
+        .filter(|number| {
+            Decimal::from_f64(*number).is_some_and(|back| back.round_dp(value.scale()) == value)
+        })
+        .ok_or(AccountingCsvError::AmountNotRepresentable(value))
+}
+
+/// Row oriented writer shared by all exports so that every report can be
+/// produced either as CSV or as a single sheet XLSX workbook. Rows are
+/// spooled to disk as they are written so exports of large ledgers do not
+/// have to fit in memory.
+pub(super) enum ReportWriter {
+    Csv(Writer<NamedTempFile>),
+    Xlsx { workbook: Workbook, row: u32 },
+}
+
+impl ReportWriter {
+    pub fn new(format: AccountingCsvFormat) -> Result<Self, AccountingCsvError> {
+        let wtr = match format {
+            AccountingCsvFormat::Csv => {
+                ReportWriter::Csv(Writer::from_writer(NamedTempFile::new()?))
+            }
+            AccountingCsvFormat::Xlsx => {
+                let mut workbook = Workbook::new();
+                workbook.add_worksheet_with_constant_memory();
+                ReportWriter::Xlsx { workbook, row: 0 }
+            }
+        };
+        Ok(wtr)
+    }
+
+    pub fn write_header(&mut self, header: &[&str]) -> Result<(), AccountingCsvError> {
+        match self {
+            ReportWriter::Csv(wtr) => wtr
+                .write_record(header)
+                .map_err(|e| AccountingCsvError::CsvError(e.to_string()))?,
+            ReportWriter::Xlsx { workbook, row } => {
+                let worksheet = workbook.worksheet_from_index(0)?;
+                let bold = Format::new().set_bold();
+                for (col, value) in header.iter().enumerate() {
+                    worksheet.write_string_with_format(*row, col as u16, *value, &bold)?;
+                }
+                *row += 1;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write_row(
+        &mut self,
+        cells: impl IntoIterator<Item = ReportCell>,
+    ) -> Result<(), AccountingCsvError> {
+        match self {
+            ReportWriter::Csv(wtr) => {
+                let record: Vec<String> = cells
+                    .into_iter()
+                    .map(|cell| match cell {
+                        ReportCell::Text(value) => value,
+                        ReportCell::Amount(value) => value.to_string(),
+                        ReportCell::Blank => String::new(),
+                    })
+                    .collect();
+                wtr.write_record(&record)
+                    .map_err(|e| AccountingCsvError::CsvError(e.to_string()))?;
+            }
+            ReportWriter::Xlsx { workbook, row } => {
+                let worksheet = workbook.worksheet_from_index(0)?;
+                for (col, cell) in cells.into_iter().enumerate() {
+                    let col = col as u16;
+                    match cell {
+                        ReportCell::Text(value) => worksheet.write_string(*row, col, value)?,
+                        ReportCell::Amount(value) => {
+                            let format = Format::new().set_num_format(amount_format(value));
+                            worksheet.write_number_with_format(
+                                *row,
+                                col,
+                                xlsx_number(value)?,
+                                &format,
+                            )?
+                        }
+                        ReportCell::Blank => worksheet.write_blank(*row, col, &Format::new())?,
+                    };
+                }
+                *row += 1;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> Result<NamedTempFile, AccountingCsvError> {
+        match self {
+            ReportWriter::Csv(wtr) => wtr
+                .into_inner()
+                .map_err(|e| AccountingCsvError::CsvError(e.to_string())),
+            ReportWriter::Xlsx { mut workbook, .. } => {
+                let file = NamedTempFile::new()?;
+                workbook.save(file.path())?;
+                Ok(file)
+            }
+        }
+    }
+}
+
+/// Shows the amount with as many decimals as it carries, e.g. `0.00` for USD.
+fn amount_format(value: Decimal) -> String {
+    match value.scale() {
+        0 => "0".to_string(),
+        scale => format!("0.{}", "0".repeat(scale as usize)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    fn contents(file: NamedTempFile) -> Vec<u8> {
+        let mut buf = Vec::new();
+        file.reopen().unwrap().read_to_end(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn csv_rows_are_written_in_order() {
+        let mut wtr = ReportWriter::new(AccountingCsvFormat::Csv).unwrap();
+        wtr.write_header(&["Name", "Amount"]).unwrap();
+        wtr.write_row([
+            ReportCell::from("Cash".to_string()),
+            ReportCell::from(Decimal::new(1050, 2)),
+        ])
+        .unwrap();
+        wtr.write_row([
+            ReportCell::from("Loans, net".to_string()),
+            ReportCell::Blank,
+        ])
+        .unwrap();
+
+        let csv = String::from_utf8(contents(wtr.finish().unwrap())).unwrap();
+        assert_eq!(csv, "Name,Amount\nCash,10.50\n\"Loans, net\",\n");
+    }
+
+    #[test]
+    fn xlsx_is_saved_as_workbook() {
+        let mut wtr = ReportWriter::new(AccountingCsvFormat::Xlsx).unwrap();
+        wtr.write_header(&["Name", "Amount"]).unwrap();
+        wtr.write_row([ReportCell::from("Cash".to_string()), ReportCell::Blank])
+            .unwrap();
+        wtr.write_row([
+            ReportCell::from("Loans".to_string()),
+            ReportCell::from(Decimal::new(1050, 2)),
+        ])
+        .unwrap();
+
+        let xlsx = contents(wtr.finish().unwrap());
+        assert!(xlsx.starts_with(b"PK"));
+    }
+
+    #[test]
+    fn xlsx_numbers_round_trip_at_their_scale() {
+        assert_eq!(xlsx_number(Decimal::new(1050, 2)).unwrap(), 10.5);
+        assert_eq!(
+            xlsx_number(Decimal::new(12_345_678, 8)).unwrap(),
+            0.12345678
+        );
+        assert_eq!(
+            xlsx_number(Decimal::new(999_999_999_999_999, 2)).unwrap(),
+            9_999_999_999_999.99
+        );
+        assert!(matches!(
+            xlsx_number(Decimal::new(123_456_789_012_345_678, 2)),
+            Err(AccountingCsvError::AmountNotRepresentable(_))
+        ));
+        assert_eq!(amount_format(Decimal::new(1050, 2)), "0.00");
+        assert_eq!(amount_format(Decimal::from(7)), "0");
+    }
+
+    #[test]
+    fn xlsx_rejects_amounts_it_would_round() {
+        let mut wtr = ReportWriter::new(AccountingCsvFormat::Xlsx).unwrap();
+        let res = wtr.write_row([ReportCell::from(Decimal::new(123_456_789_012_345_678, 2))]);
+        assert!(matches!(
+            res,
+            Err(AccountingCsvError::AmountNotRepresentable(_))
+        ));
+    }
+}
@@ -18,4 +18,12 @@ pub enum CoreAccountingError {
     AccountingCsvError(#[from] super::csv::error::AccountingCsvError),
     #[error("CoreAccountingError - TrialBalanceError: {0}")]
     TrialBalance(#[from] super::trial_balance::error::TrialBalanceError),
+    #[error("CoreAccountingError - AccountingPeriodError: {0}")]
+    AccountingPeriodError(#[from] super::accounting_period::error::AccountingPeriodError),
+    #[error("CoreAccountingError - ProcessError: {0}")]
+    ProcessError(#[from] super::processes::error::ProcessError),
+    #[error("CoreAccountingError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("CoreAccountingError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
 }
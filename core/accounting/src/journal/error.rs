@@ -8,6 +8,8 @@ pub enum JournalError {
     CalaLedger(#[from] cala_ledger::error::LedgerError),
     #[error("JournalError - CalaEntryError: {0}")]
     CalaEntry(#[from] cala_ledger::entry::error::EntryError),
+    #[error("JournalError - CalaTransactionError: {0}")]
+    CalaTransaction(#[from] cala_ledger::transaction::error::TransactionError),
     #[error("JournalError - AuthorizationError: {0}")]
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("JournalError - UnexpectedCurrency")]
@@ -1,6 +1,9 @@
 mod entry;
 pub mod error;
 
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
 pub use entry::*;
 use error::*;
 
@@ -9,7 +12,9 @@ use authz::PermissionCheck;
 
 use cala_ledger::CalaLedger;
 
-use crate::primitives::{CalaJournalId, CoreAccountingAction, CoreAccountingObject};
+use crate::primitives::{
+    CalaJournalId, CoreAccountingAction, CoreAccountingObject, LedgerTransactionId,
+};
 
 #[derive(Clone)]
 pub struct Journal<Perms>
@@ -77,4 +82,17 @@ where
             end_cursor: ret.end_cursor.map(JournalEntryCursor::from),
         })
     }
+    /// Effective dates of the given transactions, which date ranges in reports are
+    /// judged on rather than when the entries were recorded.
+    pub async fn effective_dates(
+        &self,
+        ids: &[LedgerTransactionId],
+    ) -> Result<HashMap<LedgerTransactionId, NaiveDate>, JournalError> {
+        let transactions: HashMap<_, cala_ledger::transaction::Transaction> =
+            self.cala.transactions().find_all(ids).await?;
+        Ok(transactions
+            .into_iter()
+            .map(|(id, tx)| (id, tx.values().effective))
+            .collect())
+    }
 }
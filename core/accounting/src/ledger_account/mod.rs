@@ -185,6 +185,48 @@ where
         }
     }
 
+    /// Lists one page of the direct members of the account set `id` with their balances
+    /// over the given range. Unlike `list_account_children` no chart lookups are made.
+    pub(crate) async fn list_children_in_range(
+        &self,
+        id: cala_ledger::AccountSetId,
+        args: es_entity::PaginatedQueryArgs<LedgerAccountChildrenCursor>,
+        from: chrono::DateTime<chrono::Utc>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<LedgerAccount, LedgerAccountChildrenCursor>,
+        LedgerAccountError,
+    > {
+        Ok(self.ledger.list_children(id, args, from, until).await?)
+    }
+
+    /// Loads every direct member of the account set `id` with its balances over the given
+    /// range, following pagination until all members have been returned.
+    pub(crate) async fn find_all_children_in_range(
+        &self,
+        id: cala_ledger::AccountSetId,
+        from: chrono::DateTime<chrono::Utc>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<LedgerAccount>, LedgerAccountError> {
+        let mut children = Vec::new();
+        let mut after = None;
+        loop {
+            let res = self
+                .list_children_in_range(
+                    id,
+                    es_entity::PaginatedQueryArgs { first: 100, after },
+                    from,
+                    until,
+                )
+                .await?;
+            children.extend(res.entities);
+            if !res.has_next_page {
+                return Ok(children);
+            }
+            after = res.end_cursor;
+        }
+    }
+
     /// Pushes into `account`'s `ancestor_ids` ancestors from the chart of account. The ancestors
     /// are pushed in ascending order, the root of the chart of accounts is pushed last. `account`
     /// itself is not pushed.
@@ -27,6 +27,10 @@ impl LedgerAccount {
         }
     }
 
+    pub(crate) fn is_leaf(&self) -> bool {
+        self.is_leaf
+    }
+
     pub(super) fn has_non_zero_balance(&self) -> bool {
         if let Some(usd) = self.usd_balance_range.as_ref() {
             usd.has_non_zero_balance()
@@ -90,7 +94,7 @@ impl
     )> for LedgerAccount
 {
     fn from(
-        (account_set, btc_balance_range, usd_balance_range): (
+        (account_set, usd_balance_range, btc_balance_range): (
             CalaAccountSet,
             Option<CalaBalanceRange>,
             Option<CalaBalanceRange>,
@@ -1,8 +1,10 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
 
+pub mod accounting_period;
 pub mod balance_sheet;
 pub mod chart_of_accounts;
+mod config;
 pub mod csv;
 pub mod error;
 pub mod journal;
@@ -10,6 +12,7 @@ pub mod ledger_account;
 pub mod ledger_transaction;
 pub mod manual_transaction;
 mod primitives;
+pub mod processes;
 pub mod profit_and_loss;
 pub mod transaction_templates;
 pub mod trial_balance;
@@ -20,43 +23,71 @@ use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
 use cloud_storage::Storage;
+use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
 use job::Jobs;
-use manual_transaction::ManualTransactions;
+use manual_transaction::{ManualTransactionLedger, ManualTransactions};
+use outbox::{Outbox, OutboxEventMarker};
+use processes::{
+    approve_manual_transaction::{
+        ApproveManualTransaction, ManualTransactionApprovalJobConfig,
+        ManualTransactionApprovalJobInitializer,
+    },
+    approve_period_reopen::{
+        ApprovePeriodReopen, PeriodReopenApprovalJobConfig, PeriodReopenApprovalJobInitializer,
+    },
+    close_accounting_period::CloseAccountingPeriod,
+};
 use tracing::instrument;
 
+pub use accounting_period::{
+    AccountingPeriod, AccountingPeriodStatus, AccountingPeriods,
+    AccountingPeriodsByCreatedAtCursor, PeriodPostingLock,
+};
 pub use balance_sheet::{BalanceSheet, BalanceSheets};
 pub use chart_of_accounts::{Chart, ChartOfAccounts, error as chart_of_accounts_error, tree};
+pub use config::AccountingConfig;
 pub use csv::AccountingCsvs;
 use error::CoreAccountingError;
 pub use journal::{Journal, error as journal_error};
 pub use ledger_account::{LedgerAccount, LedgerAccountChildrenCursor, LedgerAccounts};
 pub use ledger_transaction::{LedgerTransaction, LedgerTransactions};
-pub use manual_transaction::ManualEntryInput;
+pub use manual_transaction::{
+    AccountIdOrCode, ManualEntryInput, ManualTransaction, ManualTransactionStatus,
+    ManualTransactionsByCreatedAtCursor,
+};
 pub use primitives::*;
+pub use processes::{
+    approve_manual_transaction::APPROVE_MANUAL_TRANSACTION_PROCESS,
+    approve_period_reopen::APPROVE_PERIOD_REOPEN_PROCESS,
+};
 pub use profit_and_loss::{ProfitAndLossStatement, ProfitAndLossStatements};
 pub use transaction_templates::TransactionTemplates;
 pub use trial_balance::{TrialBalanceRoot, TrialBalances};
 
-pub struct CoreAccounting<Perms>
+pub struct CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     authz: Perms,
     chart_of_accounts: ChartOfAccounts<Perms>,
     journal: Journal<Perms>,
     ledger_accounts: LedgerAccounts<Perms>,
     ledger_transactions: LedgerTransactions<Perms>,
-    manual_transactions: ManualTransactions<Perms>,
+    manual_transactions: ManualTransactions<Perms, E>,
     profit_and_loss: ProfitAndLossStatements<Perms>,
     transaction_templates: TransactionTemplates<Perms>,
     balance_sheets: BalanceSheets<Perms>,
     csvs: AccountingCsvs<Perms>,
     trial_balances: TrialBalances<Perms>,
+    accounting_periods: AccountingPeriods<Perms, E>,
+    close_accounting_period: CloseAccountingPeriod<Perms>,
 }
 
-impl<Perms> Clone for CoreAccounting<Perms>
+impl<Perms, E> Clone for CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -71,35 +102,109 @@ where
             balance_sheets: self.balance_sheets.clone(),
             csvs: self.csvs.clone(),
             trial_balances: self.trial_balances.clone(),
+            accounting_periods: self.accounting_periods.clone(),
+            close_accounting_period: self.close_accounting_period.clone(),
         }
     }
 }
 
-impl<Perms> CoreAccounting<Perms>
+impl<Perms, E> CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
-    pub fn new(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn init(
         pool: &sqlx::PgPool,
+        config: AccountingConfig,
         authz: &Perms,
         cala: &CalaLedger,
         journal_id: CalaJournalId,
         storage: &Storage,
         jobs: &Jobs,
-    ) -> Self {
+        governance: &Governance<Perms, E>,
+        outbox: &Outbox<E>,
+    ) -> Result<Self, CoreAccountingError> {
         let chart_of_accounts = ChartOfAccounts::new(pool, authz, cala, journal_id);
         let journal = Journal::new(authz, cala, journal_id);
         let ledger_accounts = LedgerAccounts::new(authz, cala, journal_id);
-        let manual_transactions = ManualTransactions::new(pool, authz, cala, journal_id);
+        let manual_transactions =
+            ManualTransactions::new(pool, config, authz, cala, journal_id, governance);
         let ledger_transactions = LedgerTransactions::new(authz, cala);
         let profit_and_loss = ProfitAndLossStatements::new(pool, authz, cala, journal_id);
         let transaction_templates = TransactionTemplates::new(authz, cala);
         let balance_sheets = BalanceSheets::new(pool, authz, cala, journal_id);
-        let csvs = AccountingCsvs::new(pool, authz, jobs, storage, &ledger_accounts);
         let trial_balances = TrialBalances::new(pool, authz, cala, journal_id);
-        Self {
+        let csvs = AccountingCsvs::new(
+            pool,
+            authz,
+            jobs,
+            storage,
+            &ledger_accounts,
+            &profit_and_loss,
+            &balance_sheets,
+            &trial_balances,
+            &journal,
+        );
+
+        let period_repo = accounting_period::AccountingPeriodRepo::new(pool);
+        let period_ledger = ManualTransactionLedger::new(cala);
+        let accounting_periods = AccountingPeriods::new(&period_repo, authz, governance);
+        let close_accounting_period = CloseAccountingPeriod::new(
+            &period_repo,
+            &period_ledger,
+            &ledger_accounts,
+            &profit_and_loss,
+            authz,
+            journal_id,
+        );
+        let approve_period_reopen =
+            ApprovePeriodReopen::new(&period_repo, &period_ledger, authz.audit(), journal_id);
+
+        jobs.add_initializer_and_spawn_unique(
+            PeriodReopenApprovalJobInitializer::new(outbox, &approve_period_reopen),
+            PeriodReopenApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        let approve_manual_transaction = ApproveManualTransaction::new(
+            &manual_transaction::ManualTransactionRepo::new(pool),
+            &PeriodPostingLock::new(pool),
+            &period_ledger,
+            authz.audit(),
+            journal_id,
+        );
+
+        jobs.add_initializer_and_spawn_unique(
+            ManualTransactionApprovalJobInitializer::new(outbox, &approve_manual_transaction),
+            ManualTransactionApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        match governance.init_policy(APPROVE_PERIOD_REOPEN_PROCESS).await {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+
+        match governance
+            .init_policy(APPROVE_MANUAL_TRANSACTION_PROCESS)
+            .await
+        {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+
+        Ok(Self {
             authz: authz.clone(),
             chart_of_accounts,
             journal,
@@ -111,7 +216,9 @@ where
             balance_sheets,
             csvs,
             trial_balances,
-        }
+            accounting_periods,
+            close_accounting_period,
+        })
     }
 
     pub fn chart_of_accounts(&self) -> &ChartOfAccounts<Perms> {
@@ -130,7 +237,7 @@ where
         &self.ledger_transactions
     }
 
-    pub fn manual_transactions(&self) -> &ManualTransactions<Perms> {
+    pub fn manual_transactions(&self) -> &ManualTransactions<Perms, E> {
         &self.manual_transactions
     }
 
@@ -154,6 +261,10 @@ where
         &self.trial_balances
     }
 
+    pub fn accounting_periods(&self) -> &AccountingPeriods<Perms, E> {
+        &self.accounting_periods
+    }
+
     #[instrument(name = "core_accounting.find_ledger_account_by_code", skip(self))]
     pub async fn find_ledger_account_by_id(
         &self,
@@ -242,7 +353,7 @@ where
         description: String,
         effective: Option<chrono::NaiveDate>,
         entries: Vec<ManualEntryInput>,
-    ) -> Result<LedgerTransaction, CoreAccountingError> {
+    ) -> Result<ManualTransaction, CoreAccountingError> {
         let chart = self
             .chart_of_accounts
             .find_by_reference(chart_ref)
@@ -251,7 +362,7 @@ where
                 CoreAccountingError::ChartOfAccountsNotFoundByReference(chart_ref.to_string())
             })?;
 
-        let tx = self
+        Ok(self
             .manual_transactions
             .execute(
                 sub,
@@ -261,13 +372,30 @@ where
                 effective.unwrap_or_else(|| chrono::Utc::now().date_naive()),
                 entries,
             )
-            .await?;
+            .await?)
+    }
 
-        let ledger_tx_id = tx.ledger_transaction_id;
-        let mut txs = self.ledger_transactions.find_all(&[ledger_tx_id]).await?;
-        Ok(txs
-            .remove(&ledger_tx_id)
-            .expect("Could not find LedgerTransaction"))
+    #[instrument(name = "core_accounting.close_accounting_period", skip(self), err)]
+    pub async fn close_accounting_period(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        pl_statement_ref: String,
+        id: impl Into<AccountingPeriodId> + std::fmt::Debug,
+        retained_earnings: AccountIdOrCode,
+    ) -> Result<AccountingPeriod, CoreAccountingError> {
+        let chart = self
+            .chart_of_accounts
+            .find_by_reference(chart_ref)
+            .await?
+            .ok_or_else(move || {
+                CoreAccountingError::ChartOfAccountsNotFoundByReference(chart_ref.to_string())
+            })?;
+
+        Ok(self
+            .close_accounting_period
+            .execute(sub, &chart, id.into(), pl_statement_ref, retained_earnings)
+            .await?)
     }
 
     pub async fn import_csv(
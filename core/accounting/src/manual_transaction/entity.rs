@@ -1,10 +1,88 @@
+use chrono::NaiveDate;
 use derive_builder::Builder;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use audit::AuditInfo;
 use es_entity::*;
 
-use crate::primitives::{CalaTxId, ManualTransactionId};
+use crate::primitives::{
+    ApprovalProcessId, CalaAccountId, CalaCurrency, CalaJournalId, CalaTxId, DebitOrCredit,
+    ManualTransactionId,
+};
+
+use super::{
+    error::ManualTransactionError,
+    ledger::{EntryParams, ManualTransactionParams},
+};
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum ManualTransactionStatus {
+    PendingApproval,
+    Posted,
+    Denied,
+    Cancelled,
+    ReversalPending,
+    Reversed,
+}
+
+/// An entry of a manual transaction with its account already resolved, kept so the
+/// transaction can be posted once approved and mirrored when reversed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManualTransactionEntry {
+    pub account_id: CalaAccountId,
+    pub currency: CalaCurrency,
+    pub amount: Decimal,
+    pub description: String,
+    pub direction: DebitOrCredit,
+}
+
+impl ManualTransactionEntry {
+    fn reversed(&self) -> Self {
+        Self {
+            direction: match self.direction {
+                DebitOrCredit::Debit => DebitOrCredit::Credit,
+                DebitOrCredit::Credit => DebitOrCredit::Debit,
+            },
+            ..self.clone()
+        }
+    }
+}
+
+/// Debits and credits have to net to zero within each currency, otherwise the
+/// transaction could never be posted.
+pub(super) fn check_entries_balance(
+    entries: &[ManualTransactionEntry],
+) -> Result<(), ManualTransactionError> {
+    let mut nets: Vec<(CalaCurrency, Decimal)> = Vec::new();
+    for entry in entries {
+        let amount = match entry.direction {
+            DebitOrCredit::Debit => entry.amount,
+            DebitOrCredit::Credit => -entry.amount,
+        };
+        match nets.iter_mut().find(|(c, _)| *c == entry.currency) {
+            Some((_, net)) => *net += amount,
+            None => nets.push((entry.currency, amount)),
+        }
+    }
+    match nets.into_iter().find(|(_, net)| !net.is_zero()) {
+        Some((currency, _)) => Err(ManualTransactionError::UnbalancedEntries(currency)),
+        None => Ok(()),
+    }
+}
+
+impl From<&ManualTransactionEntry> for EntryParams {
+    fn from(entry: &ManualTransactionEntry) -> Self {
+        EntryParams {
+            account_id: entry.account_id,
+            currency: entry.currency,
+            amount: entry.amount,
+            description: entry.description.clone(),
+            direction: entry.direction,
+        }
+    }
+}
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -15,6 +93,34 @@ pub enum ManualTransactionEvent {
         ledger_transaction_id: CalaTxId,
         description: String,
         reference: String,
+        effective: NaiveDate,
+        entries: Vec<ManualTransactionEntry>,
+        approval_process_id: Option<ApprovalProcessId>,
+        reversal_of: Option<ManualTransactionId>,
+        initiated_by: String,
+        audit_info: AuditInfo,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    },
+    Posted {
+        audit_info: AuditInfo,
+    },
+    Cancelled {
+        audit_info: AuditInfo,
+    },
+    Reversed {
+        reversed_by: ManualTransactionId,
+        audit_info: AuditInfo,
+    },
+    ReversalAbandoned {
+        reversed_by: ManualTransactionId,
+        audit_info: AuditInfo,
+    },
+    ReversalPosted {
+        reversed_by: ManualTransactionId,
         audit_info: AuditInfo,
     },
 }
@@ -26,6 +132,17 @@ pub struct ManualTransaction {
     pub reference: String,
     pub description: String,
     pub ledger_transaction_id: CalaTxId,
+    pub effective: NaiveDate,
+    pub entries: Vec<ManualTransactionEntry>,
+    pub initiated_by: String,
+    #[builder(default)]
+    pub approval_process_id: Option<ApprovalProcessId>,
+    #[builder(default)]
+    pub reversal_of: Option<ManualTransactionId>,
+    #[builder(default)]
+    pub reversed_by: Option<ManualTransactionId>,
+    #[builder(default)]
+    reversal_posted: bool,
     pub(super) events: EntityEvents<ManualTransactionEvent>,
 }
 
@@ -35,6 +152,195 @@ impl ManualTransaction {
             .entity_first_persisted_at()
             .expect("No events for deposit")
     }
+
+    pub fn requires_approval(&self) -> bool {
+        self.approval_process_id.is_some()
+    }
+
+    pub fn is_approved_or_denied(&self) -> Option<bool> {
+        self.events.iter_all().find_map(|e| {
+            if let ManualTransactionEvent::ApprovalProcessConcluded { approved, .. } = e {
+                Some(*approved)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn is_posted(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, ManualTransactionEvent::Posted { .. }))
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, ManualTransactionEvent::Cancelled { .. }))
+    }
+
+    /// Only the posted reversal marks the transaction as reversed; until then the
+    /// reversal may still be denied or cancelled.
+    pub fn status(&self) -> ManualTransactionStatus {
+        if self.reversed_by.is_some() {
+            if self.reversal_posted {
+                ManualTransactionStatus::Reversed
+            } else {
+                ManualTransactionStatus::ReversalPending
+            }
+        } else if self.is_posted() {
+            ManualTransactionStatus::Posted
+        } else if self.is_cancelled() {
+            ManualTransactionStatus::Cancelled
+        } else {
+            match self.is_approved_or_denied() {
+                Some(false) => ManualTransactionStatus::Denied,
+                _ => ManualTransactionStatus::PendingApproval,
+            }
+        }
+    }
+
+    /// Maker-checker: whoever initiated the transaction cannot also approve it.
+    pub fn check_approver(&self, approver: &str) -> Result<(), ManualTransactionError> {
+        if self.initiated_by == approver {
+            return Err(ManualTransactionError::ApproverIsInitiator(self.id));
+        }
+        Ok(())
+    }
+
+    pub fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        let Some(approval_process_id) = self.approval_process_id else {
+            return Idempotent::Ignored;
+        };
+        idempotency_guard!(
+            self.events.iter_all(),
+            ManualTransactionEvent::ApprovalProcessConcluded { .. }
+        );
+        self.events
+            .push(ManualTransactionEvent::ApprovalProcessConcluded {
+                approval_process_id,
+                approved,
+                audit_info,
+            });
+        Idempotent::Executed(())
+    }
+
+    pub fn post(&mut self, audit_info: AuditInfo) -> Result<(), ManualTransactionError> {
+        if self.requires_approval() && self.is_approved_or_denied() != Some(true) {
+            return Err(ManualTransactionError::NotApproved(self.id));
+        }
+        if self.is_posted() {
+            return Err(ManualTransactionError::AlreadyPosted(self.id));
+        }
+        if self.is_cancelled() {
+            return Err(ManualTransactionError::AlreadyCancelled(self.id));
+        }
+
+        self.events
+            .push(ManualTransactionEvent::Posted { audit_info });
+
+        Ok(())
+    }
+
+    /// Cancels an approved transaction that can no longer be posted, e.g. because its
+    /// effective date now falls in a closed accounting period.
+    pub fn cancel(&mut self, audit_info: AuditInfo) -> Result<(), ManualTransactionError> {
+        if self.is_posted() {
+            return Err(ManualTransactionError::AlreadyPosted(self.id));
+        }
+        if self.is_cancelled() {
+            return Err(ManualTransactionError::AlreadyCancelled(self.id));
+        }
+
+        self.events
+            .push(ManualTransactionEvent::Cancelled { audit_info });
+
+        Ok(())
+    }
+
+    /// Marks the transaction as pending reversal by `reversed_by` and returns the
+    /// entries the reversing transaction must post.
+    pub fn reverse(
+        &mut self,
+        reversed_by: ManualTransactionId,
+        audit_info: AuditInfo,
+    ) -> Result<Vec<ManualTransactionEntry>, ManualTransactionError> {
+        if !self.is_posted() {
+            return Err(ManualTransactionError::NotPosted(self.id));
+        }
+        if self.reversal_of.is_some() {
+            return Err(ManualTransactionError::CannotReverseReversal(self.id));
+        }
+        if let Some(reversed_by) = self.reversed_by {
+            return Err(ManualTransactionError::AlreadyReversed(
+                self.id,
+                reversed_by,
+            ));
+        }
+
+        self.events.push(ManualTransactionEvent::Reversed {
+            reversed_by,
+            audit_info,
+        });
+        self.reversed_by = Some(reversed_by);
+
+        Ok(self
+            .entries
+            .iter()
+            .map(ManualTransactionEntry::reversed)
+            .collect())
+    }
+
+    /// Clears the reversal marker once the reversal awaiting approval has been denied
+    /// or cancelled, so the transaction can be reversed again.
+    pub fn abandon_reversal(
+        &mut self,
+        reversed_by: ManualTransactionId,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.reversed_by != Some(reversed_by) {
+            return Idempotent::Ignored;
+        }
+
+        self.events.push(ManualTransactionEvent::ReversalAbandoned {
+            reversed_by,
+            audit_info,
+        });
+        self.reversed_by = None;
+
+        Idempotent::Executed(())
+    }
+
+    pub fn reversal_posted(
+        &mut self,
+        reversed_by: ManualTransactionId,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.reversed_by != Some(reversed_by) || self.reversal_posted {
+            return Idempotent::Ignored;
+        }
+
+        self.events.push(ManualTransactionEvent::ReversalPosted {
+            reversed_by,
+            audit_info,
+        });
+        self.reversal_posted = true;
+
+        Idempotent::Executed(())
+    }
+
+    pub(crate) fn ledger_params(&self, journal_id: CalaJournalId) -> ManualTransactionParams {
+        ManualTransactionParams {
+            journal_id,
+            description: self.description.clone(),
+            entry_params: self.entries.iter().map(EntryParams::from).collect(),
+            effective: self.effective,
+        }
+    }
 }
 
 impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
@@ -49,6 +355,11 @@ impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
                     reference,
                     description,
                     ledger_transaction_id,
+                    effective,
+                    entries,
+                    approval_process_id,
+                    reversal_of,
+                    initiated_by,
                     ..
                 } => {
                     builder = builder
@@ -56,7 +367,22 @@ impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
                         .reference(reference.clone())
                         .description(description.clone())
                         .ledger_transaction_id(*ledger_transaction_id)
+                        .effective(*effective)
+                        .entries(entries.clone())
+                        .initiated_by(initiated_by.clone())
+                        .approval_process_id(*approval_process_id)
+                        .reversal_of(*reversal_of)
                 }
+                ManualTransactionEvent::Reversed { reversed_by, .. } => {
+                    builder = builder.reversed_by(Some(*reversed_by))
+                }
+                ManualTransactionEvent::ReversalAbandoned { .. } => {
+                    builder = builder.reversed_by(None)
+                }
+                ManualTransactionEvent::ReversalPosted { .. } => {
+                    builder = builder.reversal_posted(true)
+                }
+                _ => (),
             }
         }
         builder.events(events).build()
@@ -70,6 +396,14 @@ pub struct NewManualTransaction {
     reference: Option<String>,
     pub(super) ledger_transaction_id: CalaTxId,
     description: String,
+    effective: NaiveDate,
+    entries: Vec<ManualTransactionEntry>,
+    #[builder(setter(into, strip_option), default)]
+    approval_process_id: Option<ApprovalProcessId>,
+    #[builder(setter(strip_option), default)]
+    reversal_of: Option<ManualTransactionId>,
+    #[builder(setter(into))]
+    initiated_by: String,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
 }
@@ -97,8 +431,163 @@ impl IntoEvents<ManualTransactionEvent> for NewManualTransaction {
                 id: self.id,
                 ledger_transaction_id: self.ledger_transaction_id,
                 description: self.description,
+                effective: self.effective,
+                entries: self.entries,
+                approval_process_id: self.approval_process_id,
+                reversal_of: self.reversal_of,
+                initiated_by: self.initiated_by,
                 audit_info: self.audit_info,
             }],
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn entry(direction: DebitOrCredit) -> ManualTransactionEntry {
+        ManualTransactionEntry {
+            account_id: CalaAccountId::new(),
+            currency: CalaCurrency::USD,
+            amount: Decimal::ONE_HUNDRED,
+            description: "entry".to_string(),
+            direction,
+        }
+    }
+
+    fn manual_transaction(requires_approval: bool) -> ManualTransaction {
+        let id = ManualTransactionId::new();
+        let mut builder = NewManualTransaction::builder();
+        builder
+            .id(id)
+            .reference(None)
+            .ledger_transaction_id(CalaTxId::new())
+            .description("manual".to_string())
+            .effective("2024-01-15".parse().unwrap())
+            .entries(vec![
+                entry(DebitOrCredit::Debit),
+                entry(DebitOrCredit::Credit),
+            ])
+            .initiated_by("maker")
+            .audit_info(dummy_audit_info());
+        if requires_approval {
+            builder.approval_process_id(id);
+        }
+        ManualTransaction::try_from_events(builder.build().unwrap().into_events()).unwrap()
+    }
+
+    #[test]
+    fn cannot_post_before_approval() {
+        let mut tx = manual_transaction(true);
+        assert_eq!(tx.status(), ManualTransactionStatus::PendingApproval);
+        assert!(matches!(
+            tx.post(dummy_audit_info()),
+            Err(ManualTransactionError::NotApproved(_))
+        ));
+
+        assert!(
+            tx.approval_process_concluded(true, dummy_audit_info())
+                .did_execute()
+        );
+        assert!(tx.post(dummy_audit_info()).is_ok());
+        assert_eq!(tx.status(), ManualTransactionStatus::Posted);
+    }
+
+    #[test]
+    fn initiator_cannot_approve() {
+        let tx = manual_transaction(true);
+        assert!(matches!(
+            tx.check_approver("maker"),
+            Err(ManualTransactionError::ApproverIsInitiator(_))
+        ));
+        assert!(tx.check_approver("checker").is_ok());
+    }
+
+    #[test]
+    fn denied_transaction_cannot_be_posted() {
+        let mut tx = manual_transaction(true);
+        let _ = tx.approval_process_concluded(false, dummy_audit_info());
+        assert_eq!(tx.status(), ManualTransactionStatus::Denied);
+        assert!(tx.post(dummy_audit_info()).is_err());
+    }
+
+    #[test]
+    fn reversal_mirrors_entries() {
+        let mut tx = manual_transaction(false);
+        assert!(matches!(
+            tx.reverse(ManualTransactionId::new(), dummy_audit_info()),
+            Err(ManualTransactionError::NotPosted(_))
+        ));
+
+        tx.post(dummy_audit_info()).unwrap();
+        let reversal_id = ManualTransactionId::new();
+        let entries = tx.reverse(reversal_id, dummy_audit_info()).unwrap();
+        assert_eq!(entries[0].direction, DebitOrCredit::Credit);
+        assert_eq!(entries[1].direction, DebitOrCredit::Debit);
+        assert_eq!(tx.status(), ManualTransactionStatus::ReversalPending);
+        assert_eq!(tx.reversed_by, Some(reversal_id));
+
+        assert!(
+            tx.reversal_posted(ManualTransactionId::new(), dummy_audit_info())
+                .was_ignored()
+        );
+        assert!(
+            tx.reversal_posted(reversal_id, dummy_audit_info())
+                .did_execute()
+        );
+        assert_eq!(tx.status(), ManualTransactionStatus::Reversed);
+
+        assert!(matches!(
+            tx.reverse(ManualTransactionId::new(), dummy_audit_info()),
+            Err(ManualTransactionError::AlreadyReversed(_, _))
+        ));
+    }
+
+    #[test]
+    fn abandoned_reversal_can_be_retried() {
+        let mut tx = manual_transaction(false);
+        tx.post(dummy_audit_info()).unwrap();
+        let reversal_id = ManualTransactionId::new();
+        tx.reverse(reversal_id, dummy_audit_info()).unwrap();
+
+        assert!(
+            tx.abandon_reversal(ManualTransactionId::new(), dummy_audit_info())
+                .was_ignored()
+        );
+        assert!(
+            tx.abandon_reversal(reversal_id, dummy_audit_info())
+                .did_execute()
+        );
+        assert_eq!(tx.status(), ManualTransactionStatus::Posted);
+
+        let tx = ManualTransaction::try_from_events(tx.events).unwrap();
+        assert_eq!(tx.reversed_by, None);
+    }
+
+    #[test]
+    fn entries_must_balance_per_currency() {
+        let balanced = vec![entry(DebitOrCredit::Debit), entry(DebitOrCredit::Credit)];
+        assert!(check_entries_balance(&balanced).is_ok());
+
+        let mut unbalanced = balanced.clone();
+        unbalanced[1].amount = Decimal::ONE;
+        assert!(matches!(
+            check_entries_balance(&unbalanced),
+            Err(ManualTransactionError::UnbalancedEntries(currency)) if currency == CalaCurrency::USD
+        ));
+
+        let mut mixed = balanced;
+        mixed[1].currency = CalaCurrency::BTC;
+        assert!(check_entries_balance(&mixed).is_err());
+    }
+}
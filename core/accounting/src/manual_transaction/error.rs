@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::primitives::ManualTransactionId;
+
 #[derive(Error, Debug)]
 pub enum ManualTransactionError {
     #[error("ManualTransactionError - Sqlx: {0}")]
@@ -20,6 +22,28 @@ pub enum ManualTransactionError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("ManualTransactionError - Unknown account code: {0}")]
     UnknownAccountCode(String),
+    #[error("ManualTransactionError - UnbalancedEntries: debits and credits differ for {0}")]
+    UnbalancedEntries(crate::primitives::CalaCurrency),
+    #[error("ManualTransactionError - AccountingPeriodError: {0}")]
+    AccountingPeriodError(#[from] crate::accounting_period::error::AccountingPeriodError),
+    #[error("ManualTransactionError - Effective date {0} falls in a closed accounting period")]
+    PeriodClosed(chrono::NaiveDate),
+    #[error("ManualTransactionError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("ManualTransactionError - NotApproved: {0}")]
+    NotApproved(ManualTransactionId),
+    #[error("ManualTransactionError - AlreadyPosted: {0}")]
+    AlreadyPosted(ManualTransactionId),
+    #[error("ManualTransactionError - AlreadyCancelled: {0}")]
+    AlreadyCancelled(ManualTransactionId),
+    #[error("ManualTransactionError - NotPosted: {0}")]
+    NotPosted(ManualTransactionId),
+    #[error("ManualTransactionError - CannotReverseReversal: {0}")]
+    CannotReverseReversal(ManualTransactionId),
+    #[error("ManualTransactionError - AlreadyReversed: {0} by {1}")]
+    AlreadyReversed(ManualTransactionId, ManualTransactionId),
+    #[error("ManualTransactionError - ApproverIsInitiator: {0}")]
+    ApproverIsInitiator(ManualTransactionId),
 }
 
 es_entity::from_es_entity_error!(ManualTransactionError);
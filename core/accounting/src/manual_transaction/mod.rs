@@ -6,45 +6,82 @@ mod repo;
 
 use std::collections::HashMap;
 
+use tracing::instrument;
+
 use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::{CalaLedger, JournalId};
-use ledger::{EntryParams, ManualTransactionLedger, ManualTransactionParams};
+use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+pub(crate) use ledger::{EntryParams, ManualTransactionLedger, ManualTransactionParams};
 
 use crate::{
     Chart,
-    primitives::{CalaTxId, CoreAccountingAction, CoreAccountingObject, ManualTransactionId},
+    accounting_period::PeriodPostingLock,
+    config::AccountingConfig,
+    primitives::{
+        ApprovalProcessId, CalaTxId, CoreAccountingAction, CoreAccountingObject,
+        ManualTransactionId,
+    },
+    processes::approve_manual_transaction::APPROVE_MANUAL_TRANSACTION_PROCESS,
 };
 use error::*;
 
-pub use entity::ManualTransaction;
 pub(super) use entity::*;
+pub use entity::{ManualTransaction, ManualTransactionEntry, ManualTransactionStatus};
 pub use primitives::*;
+pub(crate) use repo::ManualTransactionRepo;
 pub use repo::manual_transaction_cursor::ManualTransactionsByCreatedAtCursor;
-use repo::*;
 
-#[derive(Clone)]
-pub struct ManualTransactions<Perms>
+pub struct ManualTransactions<Perms, E>
 where
     Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     ledger: ManualTransactionLedger,
     authz: Perms,
     journal_id: JournalId,
     repo: ManualTransactionRepo,
+    posting_lock: PeriodPostingLock,
+    governance: Governance<Perms, E>,
+    config: AccountingConfig,
+}
+
+impl<Perms, E> Clone for ManualTransactions<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            ledger: self.ledger.clone(),
+            authz: self.authz.clone(),
+            journal_id: self.journal_id,
+            repo: self.repo.clone(),
+            posting_lock: self.posting_lock.clone(),
+            governance: self.governance.clone(),
+            config: self.config.clone(),
+        }
+    }
 }
 
-impl<Perms> ManualTransactions<Perms>
+impl<Perms, E> ManualTransactions<Perms, E>
 where
     Perms: PermissionCheck,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     pub fn new(
         pool: &sqlx::PgPool,
+        config: AccountingConfig,
         authz: &Perms,
         cala: &CalaLedger,
         journal_id: JournalId,
+        governance: &Governance<Perms, E>,
     ) -> Self {
         let repo = ManualTransactionRepo::new(pool);
         Self {
@@ -52,6 +89,9 @@ where
             authz: authz.clone(),
             journal_id,
             repo,
+            posting_lock: PeriodPostingLock::new(pool),
+            governance: governance.clone(),
+            config,
         }
     }
 
@@ -104,6 +144,9 @@ where
         self.repo.find_all(ids).await
     }
 
+    /// Transactions that don't auto-approve under the configured threshold are only
+    /// posted once their approval process concludes.
+    #[instrument(name = "manual_transaction.execute", skip(self, chart, entries), err)]
     pub async fn execute(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -122,28 +165,13 @@ where
             )
             .await?;
 
-        let ledger_tx_id = CalaTxId::new();
-        let manual_tx_id = ManualTransactionId::new();
-
-        let new_tx = NewManualTransaction::builder()
-            .id(manual_tx_id)
-            .ledger_transaction_id(ledger_tx_id)
-            .description(description.clone())
-            .reference(reference)
-            .audit_info(audit_info)
-            .build()
-            .expect("Couldn't build new manual transaction");
-
-        let mut db = self.repo.begin_op().await?;
-        let manual_transaction = self.repo.create_in_op(&mut db, new_tx).await?;
-
-        let mut entry_params = vec![];
+        let mut resolved_entries = Vec::with_capacity(entries.len());
         for e in entries {
             let account_id = self
                 .ledger
                 .resolve_account_id(chart, &e.account_id_or_code)
                 .await?;
-            entry_params.push(EntryParams {
+            resolved_entries.push(ManualTransactionEntry {
                 account_id,
                 amount: e.amount,
                 currency: e.currency,
@@ -151,20 +179,165 @@ where
                 description: e.description,
             });
         }
+        check_entries_balance(&resolved_entries)?;
 
+        let manual_tx_id = ManualTransactionId::new();
+        let requires_approval = self.requires_approval(&resolved_entries);
+        let mut builder = NewManualTransaction::builder();
+        builder
+            .id(manual_tx_id)
+            .ledger_transaction_id(CalaTxId::new())
+            .description(description)
+            .reference(reference)
+            .effective(effective)
+            .entries(resolved_entries)
+            .initiated_by(sub.to_string())
+            .audit_info(audit_info.clone());
+        if requires_approval {
+            builder.approval_process_id(manual_tx_id);
+        }
+        let new_tx = builder
+            .build()
+            .expect("Couldn't build new manual transaction");
+
+        let mut db = self.repo.begin_op().await?;
+        self.ensure_period_open(&mut db, effective).await?;
+        if requires_approval {
+            self.governance
+                .start_process(
+                    &mut db,
+                    manual_tx_id,
+                    manual_tx_id.to_string(),
+                    APPROVE_MANUAL_TRANSACTION_PROCESS,
+                )
+                .await?;
+            let manual_transaction = self.repo.create_in_op(&mut db, new_tx).await?;
+            db.commit().await?;
+            return Ok(manual_transaction);
+        }
+
+        let mut manual_transaction = self.repo.create_in_op(&mut db, new_tx).await?;
+        manual_transaction.post(audit_info)?;
+        self.repo
+            .update_in_op(&mut db, &mut manual_transaction)
+            .await?;
         self.ledger
             .execute(
                 db,
-                ledger_tx_id,
-                ManualTransactionParams {
-                    journal_id: self.journal_id,
-                    description,
-                    entry_params,
-                    effective,
-                },
+                manual_transaction.ledger_transaction_id,
+                manual_transaction.ledger_params(self.journal_id),
             )
             .await?;
 
         Ok(manual_transaction)
     }
+
+    /// Reverses a posted transaction by posting its mirror image effective today. The
+    /// returned reversal links back to the original through `reversal_of` and goes
+    /// through the same approval threshold as any other manual transaction.
+    #[instrument(name = "manual_transaction.reverse", skip(self), err)]
+    pub async fn reverse(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ManualTransactionId> + std::fmt::Debug,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_REVERSE,
+            )
+            .await?;
+
+        let effective = chrono::Utc::now().date_naive();
+        let mut original = self.repo.find_by_id(id).await?;
+        let reversal_id = ManualTransactionId::new();
+        let entries = original.reverse(reversal_id, audit_info.clone())?;
+
+        let requires_approval = self.requires_approval(&entries);
+        let mut builder = NewManualTransaction::builder();
+        builder
+            .id(reversal_id)
+            .ledger_transaction_id(CalaTxId::new())
+            .description(format!("Reversal of {}", original.description))
+            .reference(Some(format!("{}-reversal", original.reference)))
+            .effective(effective)
+            .entries(entries)
+            .reversal_of(original.id)
+            .initiated_by(sub.to_string())
+            .audit_info(audit_info.clone());
+        if requires_approval {
+            builder.approval_process_id(reversal_id);
+        }
+        let new_reversal = builder
+            .build()
+            .expect("Couldn't build manual transaction reversal");
+
+        if !requires_approval {
+            let _ = original.reversal_posted(reversal_id, audit_info.clone());
+        }
+        let mut db = self.repo.begin_op().await?;
+        self.ensure_period_open(&mut db, effective).await?;
+        self.repo.update_in_op(&mut db, &mut original).await?;
+        if requires_approval {
+            self.governance
+                .start_process(
+                    &mut db,
+                    reversal_id,
+                    reversal_id.to_string(),
+                    APPROVE_MANUAL_TRANSACTION_PROCESS,
+                )
+                .await?;
+            let reversal = self.repo.create_in_op(&mut db, new_reversal).await?;
+            db.commit().await?;
+            return Ok(reversal);
+        }
+
+        let mut reversal = self.repo.create_in_op(&mut db, new_reversal).await?;
+        reversal.post(audit_info)?;
+        self.repo.update_in_op(&mut db, &mut reversal).await?;
+        self.ledger
+            .execute(
+                db,
+                reversal.ledger_transaction_id,
+                reversal.ledger_params(self.journal_id),
+            )
+            .await?;
+
+        Ok(reversal)
+    }
+
+    /// Rejects `sub` as approver of a manual transaction it initiated. Approval
+    /// processes that don't belong to a manual transaction are not affected.
+    pub async fn check_approver(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        process_id: impl Into<ApprovalProcessId> + std::fmt::Debug,
+    ) -> Result<(), ManualTransactionError> {
+        let id = ManualTransactionId::from(uuid::Uuid::from(process_id.into()));
+        match self.repo.find_by_id(id).await {
+            Ok(tx) => tx.check_approver(&sub.to_string()),
+            Err(e) if e.was_not_found() => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn ensure_period_open(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), ManualTransactionError> {
+        if self.posting_lock.is_locked_in_op(db, effective).await? {
+            return Err(ManualTransactionError::PeriodClosed(effective));
+        }
+        Ok(())
+    }
+
+    fn requires_approval(&self, entries: &[ManualTransactionEntry]) -> bool {
+        self.config.manual_transaction_requires_approval(
+            entries.iter().map(|e| (e.currency, e.direction, e.amount)),
+        )
+    }
 }
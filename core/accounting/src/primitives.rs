@@ -5,6 +5,8 @@ use thiserror::Error;
 
 use authz::AllOrOne;
 
+pub use governance::{ApprovalProcessId, GovernanceAction, GovernanceObject};
+
 pub use cala_ledger::{
     Currency as CalaCurrency, DebitOrCredit,
     account::Account as CalaAccount,
@@ -22,11 +24,13 @@ es_entity::entity_id! {
     ChartId,
     ManualTransactionId,
     LedgerAccountId,
-    AccountingCsvId;
+    AccountingCsvId,
+    AccountingPeriodId;
 
     LedgerAccountId => CalaAccountId,
     LedgerAccountId => CalaAccountSetId,
     AccountingCsvId => job::JobId,
+    ManualTransactionId => ApprovalProcessId,
 }
 
 impl From<cala_ledger::account_set::AccountSetMemberId> for LedgerAccountId {
@@ -276,6 +280,7 @@ pub type ProfitAndLossConfigurationAllOrOne = AllOrOne<LedgerAccountId>;
 pub type BalanceSheetAllOrOne = AllOrOne<LedgerAccountId>;
 pub type BalanceSheetConfigurationAllOrOne = AllOrOne<LedgerAccountId>;
 pub type AccountingCsvAllOrOne = AllOrOne<AccountingCsvId>;
+pub type AccountingPeriodAllOrOne = AllOrOne<AccountingPeriodId>;
 pub type TrialBalanceAllOrOne = AllOrOne<LedgerAccountId>; // what to do if there is only All
 // option
 
@@ -295,6 +300,7 @@ pub enum CoreAccountingAction {
     BalanceSheetConfigurationAction(BalanceSheetConfigurationAction),
     AccountingCsv(AccountingCsvAction),
     TrialBalanceAction(TrialBalanceAction),
+    AccountingPeriodAction(AccountingPeriodAction),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, strum::EnumDiscriminants)]
@@ -313,6 +319,7 @@ pub enum CoreAccountingObject {
     BalanceSheetConfiguration(BalanceSheetConfigurationAllOrOne),
     AccountingCsv(AccountingCsvAllOrOne),
     TrialBalance(TrialBalanceAllOrOne),
+    AccountingPeriod(AccountingPeriodAllOrOne),
 }
 
 impl CoreAccountingObject {
@@ -394,6 +401,14 @@ impl CoreAccountingObject {
     pub fn all_trial_balance() -> Self {
         CoreAccountingObject::TrialBalance(AllOrOne::All)
     }
+
+    pub fn accounting_period(id: AccountingPeriodId) -> Self {
+        CoreAccountingObject::AccountingPeriod(AllOrOne::ById(id))
+    }
+
+    pub fn all_accounting_periods() -> Self {
+        CoreAccountingObject::AccountingPeriod(AllOrOne::All)
+    }
 }
 
 impl Display for CoreAccountingObject {
@@ -413,6 +428,7 @@ impl Display for CoreAccountingObject {
             BalanceSheetConfiguration(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             AccountingCsv(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             TrialBalance(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            AccountingPeriod(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
         }
     }
 }
@@ -482,6 +498,10 @@ impl FromStr for CoreAccountingObject {
                 let obj_ref = id.parse().map_err(|_| "could not parse TrialBalance")?;
                 CoreAccountingObject::TrialBalance(obj_ref)
             }
+            AccountingPeriod => {
+                let obj_ref = id.parse().map_err(|_| "could not parse AccountingPeriod")?;
+                CoreAccountingObject::AccountingPeriod(obj_ref)
+            }
         };
         Ok(res)
     }
@@ -517,6 +537,12 @@ impl CoreAccountingAction {
         CoreAccountingAction::ManualTransactionAction(ManualTransactionAction::Create);
     pub const MANUAL_TRANSACTION_LIST: Self =
         CoreAccountingAction::ManualTransactionAction(ManualTransactionAction::List);
+    pub const MANUAL_TRANSACTION_REVERSE: Self =
+        CoreAccountingAction::ManualTransactionAction(ManualTransactionAction::Reverse);
+    pub const MANUAL_TRANSACTION_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreAccountingAction::ManualTransactionAction(
+            ManualTransactionAction::ConcludeApprovalProcess,
+        );
     pub const PROFIT_AND_LOSS_READ: Self =
         CoreAccountingAction::ProfitAndLossAction(ProfitAndLossAction::Read);
     pub const PROFIT_AND_LOSS_CREATE: Self =
@@ -562,6 +588,23 @@ impl CoreAccountingAction {
         CoreAccountingAction::TrialBalanceAction(TrialBalanceAction::Create);
     pub const TRIAL_BALANCE_UPDATE: Self =
         CoreAccountingAction::TrialBalanceAction(TrialBalanceAction::Update);
+
+    pub const ACCOUNTING_PERIOD_CREATE: Self =
+        CoreAccountingAction::AccountingPeriodAction(AccountingPeriodAction::Create);
+    pub const ACCOUNTING_PERIOD_READ: Self =
+        CoreAccountingAction::AccountingPeriodAction(AccountingPeriodAction::Read);
+    pub const ACCOUNTING_PERIOD_LIST: Self =
+        CoreAccountingAction::AccountingPeriodAction(AccountingPeriodAction::List);
+    pub const ACCOUNTING_PERIOD_SOFT_CLOSE: Self =
+        CoreAccountingAction::AccountingPeriodAction(AccountingPeriodAction::SoftClose);
+    pub const ACCOUNTING_PERIOD_CLOSE: Self =
+        CoreAccountingAction::AccountingPeriodAction(AccountingPeriodAction::Close);
+    pub const ACCOUNTING_PERIOD_REQUEST_REOPEN: Self =
+        CoreAccountingAction::AccountingPeriodAction(AccountingPeriodAction::RequestReopen);
+    pub const ACCOUNTING_PERIOD_CONCLUDE_REOPEN_APPROVAL_PROCESS: Self =
+        CoreAccountingAction::AccountingPeriodAction(
+            AccountingPeriodAction::ConcludeReopenApprovalProcess,
+        );
 }
 
 impl Display for CoreAccountingAction {
@@ -581,6 +624,7 @@ impl Display for CoreAccountingAction {
             BalanceSheetConfigurationAction(action) => action.fmt(f),
             AccountingCsv(action) => action.fmt(f),
             TrialBalanceAction(action) => action.fmt(f),
+            AccountingPeriodAction(action) => action.fmt(f),
         }
     }
 }
@@ -627,6 +671,9 @@ impl FromStr for CoreAccountingAction {
             CoreAccountingActionDiscriminants::TrialBalanceAction => {
                 CoreAccountingAction::from(action.parse::<TrialBalanceAction>()?)
             }
+            CoreAccountingActionDiscriminants::AccountingPeriodAction => {
+                CoreAccountingAction::from(action.parse::<AccountingPeriodAction>()?)
+            }
         };
         Ok(res)
     }
@@ -704,6 +751,8 @@ pub enum ManualTransactionAction {
     Read,
     Create,
     List,
+    Reverse,
+    ConcludeApprovalProcess,
 }
 
 impl From<ManualTransactionAction> for CoreAccountingAction {
@@ -795,6 +844,24 @@ impl From<TrialBalanceAction> for CoreAccountingAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum AccountingPeriodAction {
+    Create,
+    Read,
+    List,
+    SoftClose,
+    Close,
+    RequestReopen,
+    ConcludeReopenApprovalProcess,
+}
+
+impl From<AccountingPeriodAction> for CoreAccountingAction {
+    fn from(action: AccountingPeriodAction) -> Self {
+        CoreAccountingAction::AccountingPeriodAction(action)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BalanceRange {
     pub start: Option<CalaAccountBalance>,
@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use authz::PermissionCheck;
+use futures::StreamExt;
+
+use audit::AuditSvc;
+use governance::GovernanceEvent;
+use job::*;
+use outbox::{Outbox, OutboxEventMarker};
+
+use crate::{CoreAccountingAction, CoreAccountingObject, ManualTransactionId};
+
+use super::ApproveManualTransaction;
+
+#[derive(serde::Serialize)]
+pub struct ManualTransactionApprovalJobConfig<Perms, E> {
+    _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> ManualTransactionApprovalJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms, E> JobConfig for ManualTransactionApprovalJobConfig<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    type Initializer = ManualTransactionApprovalJobInitializer<Perms, E>;
+}
+
+pub struct ManualTransactionApprovalJobInitializer<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    outbox: Outbox<E>,
+    process: ApproveManualTransaction<Perms>,
+}
+
+impl<Perms, E> ManualTransactionApprovalJobInitializer<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(outbox: &Outbox<E>, process: &ApproveManualTransaction<Perms>) -> Self {
+        Self {
+            process: process.clone(),
+            outbox: outbox.clone(),
+        }
+    }
+}
+
+const MANUAL_TRANSACTION_APPROVE_JOB: JobType = JobType::new("manual-transaction-approval");
+impl<Perms, E> JobInitializer for ManualTransactionApprovalJobInitializer<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        MANUAL_TRANSACTION_APPROVE_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ManualTransactionApprovalJobRunner {
+            outbox: self.outbox.clone(),
+            process: self.process.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct ManualTransactionApprovalJobData {
+    sequence: outbox::EventSequence,
+}
+
+pub struct ManualTransactionApprovalJobRunner<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    outbox: Outbox<E>,
+    process: ApproveManualTransaction<Perms>,
+}
+#[async_trait]
+impl<Perms, E> JobRunner for ManualTransactionApprovalJobRunner<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    #[allow(clippy::single_match)]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<ManualTransactionApprovalJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            match message.as_ref().as_event() {
+                Some(GovernanceEvent::ApprovalProcessConcluded {
+                    approved,
+                    ref process_type,
+                    ref target_ref,
+                    ..
+                }) if process_type == &super::APPROVE_MANUAL_TRANSACTION_PROCESS => {
+                    let manual_transaction_id = target_ref.parse::<ManualTransactionId>()?;
+                    self.process
+                        .execute(manual_transaction_id, *approved)
+                        .await?;
+                    state.sequence = message.sequence;
+                    current_job.update_execution_state(state).await?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(JobCompletion::RescheduleAt(chrono::Utc::now()))
+    }
+}
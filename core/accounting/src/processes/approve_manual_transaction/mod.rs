@@ -0,0 +1,164 @@
+mod job;
+
+use audit::{AuditInfo, AuditSvc};
+use authz::PermissionCheck;
+use cala_ledger::JournalId;
+use governance::ApprovalProcessType;
+
+use crate::{
+    accounting_period::PeriodPostingLock,
+    manual_transaction::{ManualTransaction, ManualTransactionLedger, ManualTransactionRepo},
+    primitives::{CoreAccountingAction, CoreAccountingObject, ManualTransactionId},
+};
+
+use super::error::ProcessError;
+
+pub use job::*;
+
+pub const APPROVE_MANUAL_TRANSACTION_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("manual-transaction");
+
+pub struct ApproveManualTransaction<Perms>
+where
+    Perms: PermissionCheck,
+{
+    repo: ManualTransactionRepo,
+    posting_lock: PeriodPostingLock,
+    ledger: ManualTransactionLedger,
+    audit: Perms::Audit,
+    journal_id: JournalId,
+}
+
+impl<Perms> Clone for ApproveManualTransaction<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            posting_lock: self.posting_lock.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+            journal_id: self.journal_id,
+        }
+    }
+}
+
+impl<Perms> ApproveManualTransaction<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub(crate) fn new(
+        repo: &ManualTransactionRepo,
+        posting_lock: &PeriodPostingLock,
+        ledger: &ManualTransactionLedger,
+        audit: &Perms::Audit,
+        journal_id: JournalId,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            posting_lock: posting_lock.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+            journal_id,
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<ManualTransactionId>,
+        approved: bool,
+    ) -> Result<ManualTransaction, ProcessError> {
+        let id = id.into();
+        let mut manual_transaction = self.repo.find_by_id(id).await?;
+        if manual_transaction.is_approved_or_denied().is_some() {
+            return Ok(manual_transaction);
+        }
+        let mut db = self.repo.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await?;
+        if manual_transaction
+            .approval_process_concluded(approved, audit_info.clone())
+            .was_ignored()
+        {
+            return Ok(manual_transaction);
+        }
+
+        if !approved {
+            self.release_reversed(&mut db, &manual_transaction, audit_info)
+                .await?;
+            self.repo
+                .update_in_op(&mut db, &mut manual_transaction)
+                .await?;
+            db.commit().await?;
+            return Ok(manual_transaction);
+        }
+
+        if self
+            .posting_lock
+            .is_locked_in_op(&mut db, manual_transaction.effective)
+            .await?
+        {
+            manual_transaction.cancel(audit_info.clone())?;
+            self.release_reversed(&mut db, &manual_transaction, audit_info)
+                .await?;
+            self.repo
+                .update_in_op(&mut db, &mut manual_transaction)
+                .await?;
+            db.commit().await?;
+            return Ok(manual_transaction);
+        }
+
+        manual_transaction.post(audit_info.clone())?;
+        self.repo
+            .update_in_op(&mut db, &mut manual_transaction)
+            .await?;
+        if let Some(original_id) = manual_transaction.reversal_of {
+            let mut original = self.repo.find_by_id(original_id).await?;
+            if original
+                .reversal_posted(manual_transaction.id, audit_info)
+                .did_execute()
+            {
+                self.repo.update_in_op(&mut db, &mut original).await?;
+            }
+        }
+        self.ledger
+            .execute(
+                db,
+                manual_transaction.ledger_transaction_id,
+                manual_transaction.ledger_params(self.journal_id),
+            )
+            .await?;
+
+        Ok(manual_transaction)
+    }
+
+    /// A reversal that will never be posted must not leave the original marked as reversed.
+    async fn release_reversed(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        reversal: &ManualTransaction,
+        audit_info: AuditInfo,
+    ) -> Result<(), ProcessError> {
+        let Some(original_id) = reversal.reversal_of else {
+            return Ok(());
+        };
+        let mut original = self.repo.find_by_id(original_id).await?;
+        if original
+            .abandon_reversal(reversal.id, audit_info)
+            .did_execute()
+        {
+            self.repo.update_in_op(db, &mut original).await?;
+        }
+        Ok(())
+    }
+}
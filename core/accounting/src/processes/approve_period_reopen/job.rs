@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use authz::PermissionCheck;
+use futures::StreamExt;
+
+use audit::AuditSvc;
+use governance::GovernanceEvent;
+use job::*;
+use outbox::{Outbox, OutboxEventMarker};
+
+use crate::{AccountingPeriodId, CoreAccountingAction, CoreAccountingObject};
+
+use super::ApprovePeriodReopen;
+
+#[derive(serde::Serialize)]
+pub struct PeriodReopenApprovalJobConfig<Perms, E> {
+    _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> PeriodReopenApprovalJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms, E> JobConfig for PeriodReopenApprovalJobConfig<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    type Initializer = PeriodReopenApprovalJobInitializer<Perms, E>;
+}
+
+pub struct PeriodReopenApprovalJobInitializer<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    outbox: Outbox<E>,
+    process: ApprovePeriodReopen<Perms>,
+}
+
+impl<Perms, E> PeriodReopenApprovalJobInitializer<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(outbox: &Outbox<E>, process: &ApprovePeriodReopen<Perms>) -> Self {
+        Self {
+            process: process.clone(),
+            outbox: outbox.clone(),
+        }
+    }
+}
+
+const PERIOD_REOPEN_APPROVE_JOB: JobType = JobType::new("accounting-period-reopen-approval");
+impl<Perms, E> JobInitializer for PeriodReopenApprovalJobInitializer<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        PERIOD_REOPEN_APPROVE_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(PeriodReopenApprovalJobRunner {
+            outbox: self.outbox.clone(),
+            process: self.process.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct PeriodReopenApprovalJobData {
+    sequence: outbox::EventSequence,
+}
+
+pub struct PeriodReopenApprovalJobRunner<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    outbox: Outbox<E>,
+    process: ApprovePeriodReopen<Perms>,
+}
+#[async_trait]
+impl<Perms, E> JobRunner for PeriodReopenApprovalJobRunner<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    #[allow(clippy::single_match)]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<PeriodReopenApprovalJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            match message.as_ref().as_event() {
+                Some(GovernanceEvent::ApprovalProcessConcluded {
+                    id,
+                    approved,
+                    ref process_type,
+                    ref target_ref,
+                    ..
+                }) if process_type == &super::APPROVE_PERIOD_REOPEN_PROCESS => {
+                    let period_id = target_ref.parse::<AccountingPeriodId>()?;
+                    self.process.execute(period_id, *id, *approved).await?;
+                    state.sequence = message.sequence;
+                    current_job.update_execution_state(state).await?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(JobCompletion::RescheduleAt(chrono::Utc::now()))
+    }
+}
@@ -0,0 +1,124 @@
+mod job;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use cala_ledger::JournalId;
+use governance::ApprovalProcessType;
+
+use crate::{
+    accounting_period::{AccountingPeriod, AccountingPeriodRepo},
+    manual_transaction::{ManualTransactionLedger, ManualTransactionParams},
+    primitives::{
+        AccountingPeriodId, ApprovalProcessId, CoreAccountingAction, CoreAccountingObject,
+    },
+};
+
+use super::{close_accounting_period::entry_params, error::ProcessError};
+
+pub use job::*;
+
+pub const APPROVE_PERIOD_REOPEN_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("accounting-period-reopen");
+
+pub struct ApprovePeriodReopen<Perms>
+where
+    Perms: PermissionCheck,
+{
+    repo: AccountingPeriodRepo,
+    ledger: ManualTransactionLedger,
+    audit: Perms::Audit,
+    journal_id: JournalId,
+}
+
+impl<Perms> Clone for ApprovePeriodReopen<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+            journal_id: self.journal_id,
+        }
+    }
+}
+
+impl<Perms> ApprovePeriodReopen<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub(crate) fn new(
+        repo: &AccountingPeriodRepo,
+        ledger: &ManualTransactionLedger,
+        audit: &Perms::Audit,
+        journal_id: JournalId,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+            journal_id,
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<AccountingPeriodId>,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<AccountingPeriod, ProcessError> {
+        let id = id.into();
+        let mut period = self.repo.find_by_id(id).await?;
+        if period.reopen_approval_process_id() != Some(approval_process_id) {
+            return Ok(period);
+        }
+        let mut db = self.repo.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_CONCLUDE_REOPEN_APPROVAL_PROCESS,
+            )
+            .await?;
+        if period
+            .reopen_approval_process_concluded(approval_process_id, approved, audit_info.clone())
+            .was_ignored()
+        {
+            return Ok(period);
+        }
+
+        let reversal = if approved {
+            period.reopen(audit_info)?
+        } else {
+            None
+        };
+        self.repo.update_in_op(&mut db, &mut period).await?;
+        let Some((ledger_tx_id, entries)) = reversal else {
+            db.commit().await?;
+            return Ok(period);
+        };
+
+        self.ledger
+            .execute(
+                db,
+                ledger_tx_id,
+                ManualTransactionParams {
+                    journal_id: self.journal_id,
+                    description: format!(
+                        "Reversal of closing entries for accounting period {} to {}",
+                        period.start_date, period.end_date
+                    ),
+                    entry_params: entries.iter().map(entry_params).collect(),
+                    effective: period.end_date,
+                },
+            )
+            .await?;
+
+        Ok(period)
+    }
+}
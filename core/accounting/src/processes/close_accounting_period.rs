@@ -0,0 +1,239 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashSet;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use cala_ledger::JournalId;
+
+use crate::{
+    Chart,
+    accounting_period::{AccountingPeriod, AccountingPeriodRepo, ClosingEntry},
+    ledger_account::{LedgerAccount, LedgerAccounts},
+    manual_transaction::{
+        AccountIdOrCode, EntryParams, ManualTransactionLedger, ManualTransactionParams,
+    },
+    primitives::{
+        AccountingPeriodId, CalaAccountId, CalaAccountSetId, CalaCurrency, CoreAccountingAction,
+        CoreAccountingObject, DebitOrCredit, LedgerAccountId,
+    },
+    profit_and_loss::ProfitAndLossStatements,
+};
+
+use super::error::ProcessError;
+
+pub struct CloseAccountingPeriod<Perms>
+where
+    Perms: PermissionCheck,
+{
+    repo: AccountingPeriodRepo,
+    ledger: ManualTransactionLedger,
+    ledger_accounts: LedgerAccounts<Perms>,
+    profit_and_loss: ProfitAndLossStatements<Perms>,
+    authz: Perms,
+    journal_id: JournalId,
+}
+
+impl<Perms> Clone for CloseAccountingPeriod<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            ledger: self.ledger.clone(),
+            ledger_accounts: self.ledger_accounts.clone(),
+            profit_and_loss: self.profit_and_loss.clone(),
+            authz: self.authz.clone(),
+            journal_id: self.journal_id,
+        }
+    }
+}
+
+impl<Perms> CloseAccountingPeriod<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub(crate) fn new(
+        repo: &AccountingPeriodRepo,
+        ledger: &ManualTransactionLedger,
+        ledger_accounts: &LedgerAccounts<Perms>,
+        profit_and_loss: &ProfitAndLossStatements<Perms>,
+        authz: &Perms,
+        journal_id: JournalId,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            ledger: ledger.clone(),
+            ledger_accounts: ledger_accounts.clone(),
+            profit_and_loss: profit_and_loss.clone(),
+            authz: authz.clone(),
+            journal_id,
+        }
+    }
+
+    /// Closes the period, posting entries that zero the period's movement on every
+    /// account under the profit and loss statement against `retained_earnings`. The
+    /// period row stays locked for the whole close, so postings into the period
+    /// either commit before the closing entries are computed or see it closed.
+    pub async fn execute(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart: &Chart,
+        id: AccountingPeriodId,
+        pl_statement_ref: String,
+        retained_earnings: AccountIdOrCode,
+    ) -> Result<AccountingPeriod, ProcessError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_CLOSE,
+            )
+            .await?;
+
+        let mut db = self.repo.begin_op().await?;
+        self.repo.lock_for_close_in_op(&mut db, id).await?;
+        let mut period = self.repo.find_by_id(id).await?;
+        if period.is_closed() {
+            return Ok(period);
+        }
+
+        let (from, until) = period.range();
+        let statement = self
+            .profit_and_loss
+            .pl_statement(sub, pl_statement_ref, from, Some(until))
+            .await?;
+        let retained_earnings_account_id = self
+            .ledger
+            .resolve_account_id(chart, &retained_earnings)
+            .await?;
+        let closing_entries = self
+            .closing_entries(statement.id, from, until, retained_earnings_account_id)
+            .await?;
+
+        let ledger_tx_id = match period.close(
+            chrono::Utc::now().date_naive(),
+            closing_entries.clone(),
+            audit_info,
+        )? {
+            es_entity::Idempotent::Executed(ledger_tx_id) => ledger_tx_id,
+            es_entity::Idempotent::Ignored => return Ok(period),
+        };
+
+        self.repo.update_in_op(&mut db, &mut period).await?;
+        let Some(ledger_tx_id) = ledger_tx_id else {
+            db.commit().await?;
+            return Ok(period);
+        };
+
+        self.ledger
+            .execute(
+                db,
+                ledger_tx_id,
+                ManualTransactionParams {
+                    journal_id: self.journal_id,
+                    description: format!(
+                        "Closing entries for accounting period {} to {}",
+                        period.start_date, period.end_date
+                    ),
+                    entry_params: closing_entries.iter().map(entry_params).collect(),
+                    effective: period.end_date,
+                },
+            )
+            .await?;
+
+        Ok(period)
+    }
+
+    /// Walks the statement's account sets down to their leaf accounts and mirrors each
+    /// account's net movement over the range, offsetting the total per currency against
+    /// the retained earnings account.
+    async fn closing_entries(
+        &self,
+        statement_id: LedgerAccountId,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+        retained_earnings_account_id: CalaAccountId,
+    ) -> Result<Vec<ClosingEntry>, ProcessError> {
+        let mut entries = Vec::new();
+        let mut totals = [
+            (CalaCurrency::USD, Decimal::ZERO),
+            (CalaCurrency::BTC, Decimal::ZERO),
+        ];
+        let mut visited = HashSet::new();
+        let mut pending = vec![statement_id];
+        while let Some(parent_id) = pending.pop() {
+            let children = self
+                .ledger_accounts
+                .find_all_children_in_range(CalaAccountSetId::from(parent_id), from, Some(until))
+                .await?;
+            for child in children {
+                if !visited.insert(child.id) {
+                    continue;
+                }
+                if !child.is_leaf() {
+                    pending.push(child.id);
+                    continue;
+                }
+                for (currency, net) in net_movements(&child) {
+                    if let Some((_, total)) = totals.iter_mut().find(|(c, _)| *c == currency) {
+                        *total += net;
+                    }
+                    entries.push(closing_entry(CalaAccountId::from(child.id), currency, -net));
+                }
+            }
+        }
+
+        for (currency, total) in totals {
+            if !total.is_zero() {
+                entries.push(closing_entry(retained_earnings_account_id, currency, total));
+            }
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Net credit movement of `account` per currency; negative for a net debit movement.
+fn net_movements(account: &LedgerAccount) -> Vec<(CalaCurrency, Decimal)> {
+    [
+        (CalaCurrency::USD, account.usd_balance_range.as_ref()),
+        (CalaCurrency::BTC, account.btc_balance_range.as_ref()),
+    ]
+    .into_iter()
+    .filter_map(|(currency, range)| {
+        let diff = range.and_then(|range| range.diff.as_ref())?;
+        let net = diff.details.settled.cr_balance - diff.details.settled.dr_balance;
+        (!net.is_zero()).then_some((currency, net))
+    })
+    .collect()
+}
+
+/// Builds an entry moving `net` on the credit side: positive amounts are credited,
+/// negative amounts are debited.
+fn closing_entry(account_id: CalaAccountId, currency: CalaCurrency, net: Decimal) -> ClosingEntry {
+    ClosingEntry {
+        account_id,
+        currency,
+        amount: net.abs(),
+        direction: if net.is_sign_positive() {
+            DebitOrCredit::Credit
+        } else {
+            DebitOrCredit::Debit
+        },
+    }
+}
+
+pub(super) fn entry_params(entry: &ClosingEntry) -> EntryParams {
+    EntryParams {
+        account_id: entry.account_id,
+        currency: entry.currency,
+        amount: entry.amount,
+        description: "Accounting period close".to_string(),
+        direction: entry.direction,
+    }
+}
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProcessError {
+    #[error("ProcessError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("ProcessError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("ProcessError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("ProcessError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("ProcessError - AccountingPeriodError: {0}")]
+    AccountingPeriodError(#[from] crate::accounting_period::error::AccountingPeriodError),
+    #[error("ProcessError - ManualTransactionError: {0}")]
+    ManualTransactionError(#[from] crate::manual_transaction::error::ManualTransactionError),
+    #[error("ProcessError - LedgerAccountError: {0}")]
+    LedgerAccountError(#[from] crate::ledger_account::error::LedgerAccountError),
+    #[error("ProcessError - ProfitAndLossStatementError: {0}")]
+    ProfitAndLossStatementError(#[from] crate::profit_and_loss::error::ProfitAndLossStatementError),
+}
+
+es_entity::from_es_entity_error!(ProcessError);
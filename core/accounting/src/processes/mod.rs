@@ -0,0 +1,4 @@
+pub mod approve_manual_transaction;
+pub mod approve_period_reopen;
+pub mod close_accounting_period;
+pub mod error;
@@ -0,0 +1,97 @@
+mod helpers;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use chrono::{Days, NaiveDate};
+use cloud_storage::{Storage, config::StorageConfig};
+use job::{JobExecutorConfig, Jobs};
+
+use core_accounting::*;
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn close_waits_for_posting_in_flight() -> anyhow::Result<()> {
+    use rand::Rng;
+
+    let pool = helpers::init_pool().await?;
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let jobs = Jobs::new(&pool, JobExecutorConfig::default());
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+    let accounting = CoreAccounting::init(
+        &pool,
+        Default::default(),
+        &authz,
+        &cala,
+        journal_id,
+        &storage,
+        &jobs,
+        &governance,
+        &outbox,
+    )
+    .await?;
+    let chart_ref = format!("ref-{:08}", rand::thread_rng().gen_range(0..10000));
+    let chart = accounting
+        .chart_of_accounts()
+        .create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone())
+        .await?;
+    let import = r#"
+        1,,Assets
+        2,,Equity
+        "#;
+    let _ = accounting
+        .chart_of_accounts()
+        .import_from_csv(&DummySubject, chart.id, import)
+        .await?;
+    let pl_ref = format!("P&L #{:05}", rand::thread_rng().gen_range(0..100000));
+    accounting
+        .profit_and_loss()
+        .create_pl_statement(pl_ref.clone())
+        .await?;
+
+    // periods may not overlap, so each run closes a single day of its own
+    let day = NaiveDate::from_ymd_opt(2100, 1, 1).unwrap()
+        + Days::new(rand::thread_rng().gen_range(0..1_000_000));
+    let period = accounting
+        .accounting_periods()
+        .create(&DummySubject, day, day)
+        .await?;
+
+    let posting_lock = PeriodPostingLock::new(&pool);
+    let mut posting = es_entity::DbOp::init(&pool).await?;
+    assert!(!posting_lock.is_locked_in_op(&mut posting, day).await?);
+
+    let mut close = Box::pin(accounting.close_accounting_period(
+        &DummySubject,
+        &chart_ref,
+        pl_ref,
+        period.id,
+        "2".parse().unwrap(),
+    ));
+    let res = tokio::time::timeout(std::time::Duration::from_millis(500), &mut close).await;
+    assert!(res.is_err(), "close must wait for the posting to commit");
+
+    posting.commit().await?;
+    let closed = close.await?;
+    assert!(closed.is_closed());
+
+    let mut posting = es_entity::DbOp::init(&pool).await?;
+    assert!(posting_lock.is_locked_in_op(&mut posting, day).await?);
+    assert_eq!(
+        posting_lock
+            .first_open_date_in_op(&mut posting, day)
+            .await?,
+        day + Days::new(1)
+    );
+
+    Ok(())
+}
@@ -5,8 +5,11 @@ use cloud_storage::{Storage, config::StorageConfig};
 use job::{JobExecutorConfig, Jobs};
 
 use cala_ledger::{CalaLedger, CalaLedgerConfig, Currency, DebitOrCredit};
-use core_accounting::{CoreAccounting, ManualEntryInput, manual_transaction::AccountIdOrCode};
-use helpers::{action, object};
+use core_accounting::{
+    AccountingConfig, CoreAccounting, ManualEntryInput, ManualTransactionStatus,
+    manual_transaction::{AccountIdOrCode, error::ManualTransactionError},
+};
+use helpers::{action, event, object};
 use rust_decimal_macros::dec;
 
 #[tokio::test]
@@ -45,13 +48,101 @@ async fn ledger_transactions_by_template_code() -> anyhow::Result<()> {
     let manual_tx = accounting.execute_manual_transaction(&DummySubject, &chart_ref, None, "Test transaction 1".to_string(), None, entries).await?;
 
     let template_txs = accounting.ledger_transactions().list_for_template_code(&DummySubject, "MANUAL_TRANSACTION_2", Default::default()).await?.entities;
-    assert!(template_txs.iter().any(|tx| tx.id == manual_tx.id));
+    assert!(template_txs.iter().any(|tx| tx.id == manual_tx.ledger_transaction_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[rustfmt::skip]
+async fn manual_transaction_above_threshold_awaits_approval() -> anyhow::Result<()> {
+    let (accounting, chart_ref) = prepare_test().await?;
+
+    let to: AccountIdOrCode = "1".parse().unwrap();
+    let from: AccountIdOrCode = "2".parse().unwrap();
+
+    let entries = vec![
+        ManualEntryInput::builder().account_id_or_code(to.clone()).amount(dec!(5000)).currency(Currency::USD).direction(DebitOrCredit::Debit).description("test 1 debit").build().unwrap(),
+        ManualEntryInput::builder().account_id_or_code(from.clone()).amount(dec!(5000)).currency(Currency::USD).direction(DebitOrCredit::Credit).description("test 1 credit").build().unwrap(),
+    ];
+    let manual_tx = accounting.execute_manual_transaction(&DummySubject, &chart_ref, None, "Test transaction 1".to_string(), None, entries).await?;
+    assert_eq!(manual_tx.status(), ManualTransactionStatus::PendingApproval);
+
+    let posted = accounting.ledger_transactions().find_all::<core_accounting::LedgerTransaction>(&[manual_tx.ledger_transaction_id]).await?;
+    assert!(posted.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+#[rustfmt::skip]
+async fn initiator_cannot_approve_manual_transaction() -> anyhow::Result<()> {
+    let (accounting, chart_ref) = prepare_test().await?;
+
+    let to: AccountIdOrCode = "1".parse().unwrap();
+    let from: AccountIdOrCode = "2".parse().unwrap();
+
+    let entries = vec![
+        ManualEntryInput::builder().account_id_or_code(to.clone()).amount(dec!(5000)).currency(Currency::USD).direction(DebitOrCredit::Debit).description("test 1 debit").build().unwrap(),
+        ManualEntryInput::builder().account_id_or_code(from.clone()).amount(dec!(5000)).currency(Currency::USD).direction(DebitOrCredit::Credit).description("test 1 credit").build().unwrap(),
+    ];
+    let manual_tx = accounting.execute_manual_transaction(&DummySubject, &chart_ref, None, "Test transaction 1".to_string(), None, entries).await?;
+    assert_eq!(manual_tx.initiated_by, DummySubject.to_string());
+
+    let res = accounting.manual_transactions().check_approver(&DummySubject, manual_tx.id).await;
+    assert!(matches!(res, Err(ManualTransactionError::ApproverIsInitiator(id)) if id == manual_tx.id));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[rustfmt::skip]
+async fn unbalanced_manual_transaction_is_rejected() -> anyhow::Result<()> {
+    let (accounting, chart_ref) = prepare_test().await?;
+
+    let to: AccountIdOrCode = "1".parse().unwrap();
+    let from: AccountIdOrCode = "2".parse().unwrap();
+
+    let entries = vec![
+        ManualEntryInput::builder().account_id_or_code(to.clone()).amount(dec!(100)).currency(Currency::USD).direction(DebitOrCredit::Debit).description("test 1 debit").build().unwrap(),
+        ManualEntryInput::builder().account_id_or_code(from.clone()).amount(dec!(100)).currency(Currency::BTC).direction(DebitOrCredit::Credit).description("test 1 credit").build().unwrap(),
+    ];
+    let res = accounting.execute_manual_transaction(&DummySubject, &chart_ref, None, "Test transaction 1".to_string(), None, entries).await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+#[rustfmt::skip]
+async fn reverse_manual_transaction() -> anyhow::Result<()> {
+    let (accounting, chart_ref) = prepare_test().await?;
+
+    let to: AccountIdOrCode = "1".parse().unwrap();
+    let from: AccountIdOrCode = "2".parse().unwrap();
+
+    let entries = vec![
+        ManualEntryInput::builder().account_id_or_code(to.clone()).amount(dec!(100)).currency(Currency::USD).direction(DebitOrCredit::Debit).description("test 1 debit").build().unwrap(),
+        ManualEntryInput::builder().account_id_or_code(from.clone()).amount(dec!(100)).currency(Currency::USD).direction(DebitOrCredit::Credit).description("test 1 credit").build().unwrap(),
+    ];
+    let manual_tx = accounting.execute_manual_transaction(&DummySubject, &chart_ref, None, "Test transaction 1".to_string(), None, entries).await?;
+    assert_eq!(manual_tx.status(), ManualTransactionStatus::Posted);
+
+    let reversal = accounting.manual_transactions().reverse(&DummySubject, manual_tx.id).await?;
+    assert_eq!(reversal.reversal_of, Some(manual_tx.id));
+
+    let original = accounting.manual_transactions().find_manual_transaction_by_id(&DummySubject, manual_tx.id).await?.unwrap();
+    assert_eq!(original.status(), ManualTransactionStatus::Reversed);
+    assert_eq!(original.reversed_by, Some(reversal.id));
+
+    let account = accounting.find_ledger_account_by_code(&DummySubject, &chart_ref, "2".to_string()).await?.unwrap();
+    assert_eq!(account.usd_balance_range.expect("should have balance").end.expect("balance missing").settled(), dec!(0));
 
     Ok(())
 }
 
 async fn prepare_test() -> anyhow::Result<(
-    CoreAccounting<DummyPerms<action::DummyAction, object::DummyObject>>,
+    CoreAccounting<DummyPerms<action::DummyAction, object::DummyObject>, event::DummyEvent>,
     String,
 )> {
     use rand::Rng;
@@ -67,7 +158,23 @@ async fn prepare_test() -> anyhow::Result<(
     let storage = Storage::new(&StorageConfig::default());
     let jobs = Jobs::new(&pool, JobExecutorConfig::default());
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, &storage, &jobs);
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+    let config = AccountingConfig {
+        manual_transaction_approval_threshold: Some(dec!(1000)),
+    };
+    let accounting = CoreAccounting::init(
+        &pool,
+        config,
+        &authz,
+        &cala,
+        journal_id,
+        &storage,
+        &jobs,
+        &governance,
+        &outbox,
+    )
+    .await?;
     let chart_ref = format!("ref-{:08}", rand::thread_rng().gen_range(0..10000));
     let chart = accounting
         .chart_of_accounts()
@@ -7,7 +7,7 @@ use cloud_storage::{Storage, config::StorageConfig};
 use job::{JobExecutorConfig, Jobs};
 
 use core_accounting::*;
-use helpers::{action, object};
+use helpers::{action, event, object};
 
 #[tokio::test]
 async fn add_chart_to_trial_balance() -> anyhow::Result<()> {
@@ -25,7 +25,20 @@ async fn add_chart_to_trial_balance() -> anyhow::Result<()> {
     let storage = Storage::new(&StorageConfig::default());
     let jobs = Jobs::new(&pool, JobExecutorConfig::default());
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, &storage, &jobs);
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+    let accounting = CoreAccounting::init(
+        &pool,
+        Default::default(),
+        &authz,
+        &cala,
+        journal_id,
+        &storage,
+        &jobs,
+        &governance,
+        &outbox,
+    )
+    .await?;
     let chart_ref = format!("ref-{:08}", rand::thread_rng().gen_range(0..10000));
     let chart = accounting
         .chart_of_accounts()
@@ -13,6 +13,7 @@ pub struct ChartOfAccountsIntegrationConfig {
     pub chart_of_account_collateral_parent_code: AccountCode,
     pub chart_of_account_interest_income_parent_code: AccountCode,
     pub chart_of_account_fee_income_parent_code: AccountCode,
+    pub chart_of_account_deferred_fee_income_parent_code: AccountCode,
 
     pub chart_of_account_short_term_individual_disbursed_receivable_parent_code: AccountCode,
     pub chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: AccountCode,
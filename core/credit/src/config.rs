@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::terms::CVLPct;
+use crate::{exposure_limit::ExposureLimitsConfig, terms::CVLPct};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreditConfig {
@@ -8,6 +8,10 @@ pub struct CreditConfig {
     pub upgrade_buffer_cvl_pct: CVLPct,
     #[serde(default = "default_customer_active_check_enabled")]
     pub customer_active_check_enabled: bool,
+    /// Only seeds the exposure limit policy on first start; later changes are made at
+    /// runtime through `ExposureLimits::update_limits`.
+    #[serde(default)]
+    pub exposure_limits: ExposureLimitsConfig,
 }
 
 impl Default for CreditConfig {
@@ -15,6 +19,7 @@ impl Default for CreditConfig {
         CreditConfig {
             upgrade_buffer_cvl_pct: default_upgrade_buffer_cvl_pct(),
             customer_active_check_enabled: true,
+            exposure_limits: ExposureLimitsConfig::default(),
         }
     }
 }
@@ -23,6 +23,10 @@ impl CreditFacilityBalanceSummary {
         !self.disbursed.is_zero()
     }
 
+    pub fn disbursed_outstanding(&self) -> UsdCents {
+        self.not_yet_due_disbursed_outstanding + self.disbursed_outstanding_payable()
+    }
+
     pub fn disbursed_outstanding_payable(&self) -> UsdCents {
         self.due_disbursed_outstanding + self.overdue_disbursed_outstanding
     }
@@ -47,6 +51,10 @@ impl CreditFacilityBalanceSummary {
         self.disbursed_defaulted + self.interest_defaulted
     }
 
+    pub fn total_outstanding(&self) -> UsdCents {
+        self.total_not_yet_due() + self.total_outstanding_payable() + self.total_defaulted()
+    }
+
     pub fn any_outstanding_or_defaulted(&self) -> bool {
         !(self.total_not_yet_due().is_zero()
             && self.total_outstanding_payable().is_zero()
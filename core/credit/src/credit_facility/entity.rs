@@ -7,16 +7,21 @@ use audit::AuditInfo;
 use es_entity::*;
 
 use crate::{
+    forbearance::ForbearancePeriod,
     obligation::{NewObligation, ObligationsAmounts},
+    pricing_grid::PricingGrid,
     primitives::*,
-    terms::{CVLPct, CollateralizationState, InterestPeriod, TermValues},
+    terms::{AnnualRatePct, CVLPct, CollateralizationState, InterestPeriod, TermValues},
 };
 
 use crate::{interest_accrual_cycle::*, ledger::*};
 
 use super::{
-    balance::CreditFacilityBalanceSummary, cvl::*, error::CreditFacilityError, history,
-    repayment_plan,
+    balance::CreditFacilityBalanceSummary,
+    cvl::*,
+    error::CreditFacilityError,
+    history, repayment_plan,
+    structuring_fee::{self, StructuringFeeAmortizationPeriod},
 };
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -70,6 +75,25 @@ pub enum CreditFacilityEvent {
         obligation_id: ObligationId,
         audit_info: AuditInfo,
     },
+    InterestRateAdjusted {
+        interest_accrual_id: InterestAccrualCycleId,
+        idx: InterestAccrualCycleIdx,
+        annual_rate: AnnualRatePct,
+        previous_annual_rate: AnnualRatePct,
+        cvl: CVLPct,
+        adjusted_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    },
+    StructuringFeeAmortized {
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        amortized_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    },
+    ForbearanceApplied {
+        forbearance: ForbearancePeriod,
+        audit_info: AuditInfo,
+    },
     CollateralUpdated {
         tx_id: LedgerTxId,
         total_collateral: Satoshis,
@@ -90,6 +114,19 @@ pub enum CreditFacilityEvent {
         completed_at: DateTime<Utc>,
         audit_info: AuditInfo,
     },
+    AutoDebitMandateUpdated {
+        deposit_account_id: Option<CalaAccountId>,
+        audit_info: AuditInfo,
+    },
+    AutoDebitAttempted {
+        obligation_id: ObligationId,
+        payment_id: Option<PaymentId>,
+        requested: UsdCents,
+        collected: UsdCents,
+        failure_reason: Option<String>,
+        attempted_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -220,6 +257,131 @@ impl CreditFacility {
         self.terms.one_time_fee_rate.apply(self.amount)
     }
 
+    /// Amortization of the fee still deferred, re-estimated from the last recognised
+    /// period on the principal currently drawn and the rate currently in force.
+    pub fn structuring_fee_amortization_schedule(
+        &self,
+        drawn_principal: UsdCents,
+    ) -> Vec<StructuringFeeAmortizationPeriod> {
+        match (
+            self.terms.structuring_fee_recognition.is_deferred(),
+            self.activated_at,
+            self.matures_at,
+        ) {
+            (true, Some(activated_at), Some(matures_at)) => {
+                let amortized_until = self.structuring_fee_amortized_until();
+                let periods = structuring_fee::amortization_periods(activated_at, matures_at)
+                    .into_iter()
+                    .filter(|period| amortized_until.is_none_or(|until| period.end > until))
+                    .collect();
+                structuring_fee::effective_interest_schedule(
+                    drawn_principal,
+                    self.unamortized_structuring_fee(),
+                    self.current_annual_rate(),
+                    periods,
+                )
+            }
+            _ => vec![],
+        }
+    }
+
+    fn structuring_fee_amortized_until(&self) -> Option<DateTime<Utc>> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CreditFacilityEvent::StructuringFeeAmortized { amortized_at, .. } => {
+                Some(*amortized_at)
+            }
+            _ => None,
+        })
+    }
+
+    pub fn structuring_fee_amortized(&self) -> UsdCents {
+        self.events
+            .iter_all()
+            .fold(UsdCents::ZERO, |total, event| match event {
+                CreditFacilityEvent::StructuringFeeAmortized { amount, .. } => total + *amount,
+                _ => total,
+            })
+    }
+
+    /// Released in full when the facility completes. Facilities cannot be written off
+    /// yet; once they can, the write-off has to release this balance the same way.
+    pub fn unamortized_structuring_fee(&self) -> UsdCents {
+        if !self.terms.structuring_fee_recognition.is_deferred() || !self.is_activated() {
+            return UsdCents::ZERO;
+        }
+        self.structuring_fee() - self.structuring_fee_amortized()
+    }
+
+    pub(crate) fn next_structuring_fee_amortization_at(
+        &self,
+        after: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        if self.is_completed() || self.unamortized_structuring_fee().is_zero() {
+            return None;
+        }
+
+        match (self.activated_at, self.matures_at) {
+            (Some(activated_at), Some(matures_at)) => {
+                structuring_fee::amortization_periods(activated_at, matures_at)
+                    .into_iter()
+                    .map(|period| period.end)
+                    .find(|end| *end > after)
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn amortize_structuring_fee(
+        &mut self,
+        now: DateTime<Utc>,
+        drawn_principal: UsdCents,
+        audit_info: AuditInfo,
+    ) -> Idempotent<StructuringFeeAmortization> {
+        if self.is_completed() {
+            return Idempotent::Ignored;
+        }
+
+        let amount = self
+            .structuring_fee_amortization_schedule(drawn_principal)
+            .into_iter()
+            .filter(|period| period.period.end <= now)
+            .fold(UsdCents::ZERO, |total, period| total + period.amount);
+        if amount.is_zero() {
+            return Idempotent::Ignored;
+        }
+
+        Idempotent::Executed(self.record_structuring_fee_amortization(amount, now, audit_info))
+    }
+
+    fn record_structuring_fee_amortization(
+        &mut self,
+        amount: UsdCents,
+        amortized_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> StructuringFeeAmortization {
+        let idx = self
+            .events
+            .iter_all()
+            .filter(|event| matches!(event, CreditFacilityEvent::StructuringFeeAmortized { .. }))
+            .count();
+        let tx_id = LedgerTxId::new();
+        self.events
+            .push(CreditFacilityEvent::StructuringFeeAmortized {
+                ledger_tx_id: tx_id,
+                amount,
+                amortized_at,
+                audit_info,
+            });
+
+        StructuringFeeAmortization {
+            tx_id,
+            tx_ref: format!("{}-structuring-fee-amortization-{}", self.id, idx + 1),
+            amount,
+            effective: amortized_at.date_naive(),
+            credit_facility_account_ids: self.account_ids,
+        }
+    }
+
     fn facility_remaining(&self, amount_disbursed: UsdCents) -> UsdCents {
         self.amount - amount_disbursed
     }
@@ -306,6 +468,7 @@ impl CreditFacility {
         activated_at: DateTime<Utc>,
         price: PriceOfOneBTC,
         balances: CreditFacilityBalanceSummary,
+        pricing_grid: Option<&PricingGrid>,
         audit_info: AuditInfo,
     ) -> Result<Idempotent<(CreditFacilityActivation, InterestPeriod)>, CreditFacilityError> {
         if self.is_activated() {
@@ -346,7 +509,7 @@ impl CreditFacility {
         });
 
         let periods = self
-            .start_interest_accrual_cycle(audit_info)
+            .start_interest_accrual_cycle(price, balances, pricing_grid, audit_info)
             .expect("first accrual")
             .expect("first accrual");
         let activation = CreditFacilityActivation {
@@ -356,6 +519,7 @@ impl CreditFacility {
             debit_account_id: self.disbursal_credit_account_id,
             facility_amount: self.amount,
             structuring_fee_amount: self.structuring_fee(),
+            defer_structuring_fee: self.terms.structuring_fee_recognition.is_deferred(),
         };
 
         Ok(Idempotent::Executed((activation, periods.accrual)))
@@ -387,8 +551,31 @@ impl CreditFacility {
         Ok(full_period.truncate(self.matures_at.expect("Facility is already active")))
     }
 
+    pub fn current_annual_rate(&self) -> AnnualRatePct {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                CreditFacilityEvent::InterestRateAdjusted { annual_rate, .. } => Some(*annual_rate),
+                _ => None,
+            })
+            .unwrap_or(self.terms.annual_rate)
+    }
+
+    fn pricing_cvl(&self, price: PriceOfOneBTC, balances: CreditFacilityBalanceSummary) -> CVLPct {
+        let facility_cvl = self.facility_cvl_data(balances).cvl(price);
+        if balances.any_disbursed() {
+            facility_cvl.disbursed
+        } else {
+            facility_cvl.total
+        }
+    }
+
     pub(crate) fn start_interest_accrual_cycle(
         &mut self,
+        price: PriceOfOneBTC,
+        balances: CreditFacilityBalanceSummary,
+        pricing_grid: Option<&PricingGrid>,
         audit_info: AuditInfo,
     ) -> Result<Option<NewAccrualPeriods>, CreditFacilityError> {
         let accrual_cycle_period = match self.next_interest_accrual_cycle_period()? {
@@ -418,6 +605,23 @@ impl CreditFacility {
                 audit_info: audit_info.clone(),
             });
 
+        let previous_annual_rate = self.current_annual_rate();
+        let cvl = self.pricing_cvl(price, balances);
+        let annual_rate = pricing_grid
+            .map(|grid| grid.rate_for(self.terms.annual_rate, cvl))
+            .unwrap_or(self.terms.annual_rate);
+        if annual_rate != previous_annual_rate {
+            self.events.push(CreditFacilityEvent::InterestRateAdjusted {
+                interest_accrual_id: id,
+                idx,
+                annual_rate,
+                previous_annual_rate,
+                cvl,
+                adjusted_at: accrual_cycle_period.start,
+                audit_info: audit_info.clone(),
+            });
+        }
+
         let new_accrual = NewInterestAccrualCycle::builder()
             .id(id)
             .credit_facility_id(self.id)
@@ -425,7 +629,7 @@ impl CreditFacility {
             .idx(idx)
             .started_at(accrual_cycle_period.start)
             .facility_matures_at(self.matures_at.expect("Facility is already approved"))
-            .terms(self.terms)
+            .terms(self.terms.with_annual_rate(annual_rate))
             .audit_info(audit_info)
             .build()
             .expect("could not build new interest accrual");
@@ -448,13 +652,22 @@ impl CreditFacility {
             .accrual_cycle_data()
             .ok_or(CreditFacilityError::InterestAccrualNotCompletedYet)?;
 
-        let (idx, new_obligation) = {
+        let forbearance = self.forbearance_covering(accrual_cycle_data.posted_at);
+        let capitalize = self.terms.interest_capitalization.is_capitalized()
+            || forbearance.is_some_and(|forbearance| {
+                forbearance.capitalizes_interest_at(accrual_cycle_data.posted_at)
+            });
+        let (idx, mut new_obligation) = {
             let accrual = self
                 .interest_accrual_cycle_in_progress_mut()
                 .expect("accrual not found");
             (
                 accrual.idx,
-                match accrual.record_accrual_cycle(accrual_cycle_data.clone(), audit_info.clone()) {
+                match accrual.record_accrual_cycle(
+                    accrual_cycle_data.clone(),
+                    capitalize,
+                    audit_info.clone(),
+                ) {
                     Idempotent::Executed(new_obligation) => new_obligation,
                     Idempotent::Ignored => {
                         return Ok(Idempotent::Ignored);
@@ -462,6 +675,9 @@ impl CreditFacility {
                 },
             )
         };
+        if let Some(forbearance) = forbearance.filter(|_| !capitalize) {
+            new_obligation.reschedule(forbearance.defer(accrual_cycle_data.posted_at));
+        }
         self.events
             .push(CreditFacilityEvent::InterestAccrualCycleConcluded {
                 idx,
@@ -472,7 +688,11 @@ impl CreditFacility {
         self.events.push(CreditFacilityEvent::BalanceUpdated {
             ledger_tx_id: new_obligation.tx_id,
             source: BalanceUpdatedSource::Obligation(new_obligation.id),
-            balance_type: BalanceUpdatedType::InterestAccrual,
+            balance_type: if capitalize {
+                BalanceUpdatedType::InterestCapitalization
+            } else {
+                BalanceUpdatedType::InterestAccrual
+            },
             amount: accrual_cycle_data.interest,
             updated_at: accrual_cycle_data.posted_at,
             audit_info,
@@ -724,6 +944,178 @@ impl CreditFacility {
         self.maybe_update_collateralization(price, upgrade_buffer_cvl_pct, balances, &audit_info);
     }
 
+    pub fn forbearances(&self) -> Vec<ForbearancePeriod> {
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                CreditFacilityEvent::ForbearanceApplied { forbearance, .. } => Some(*forbearance),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn forbearance_covering(&self, date: DateTime<Utc>) -> Option<ForbearancePeriod> {
+        self.forbearances()
+            .into_iter()
+            .find(|forbearance| forbearance.covers(date))
+    }
+
+    /// Validates a requested forbearance against the ones already applied and the
+    /// `pending` requests still awaiting approval.
+    pub(crate) fn check_forbearance_period(
+        &self,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        pending: &[ForbearancePeriod],
+    ) -> Result<(), CreditFacilityError> {
+        let matures_at = self
+            .matures_at
+            .ok_or(CreditFacilityError::NotActivatedYet)?;
+        if self.is_completed() {
+            return Err(CreditFacilityError::AlreadyCompleted);
+        }
+        if ends_at > matures_at {
+            return Err(CreditFacilityError::ForbearancePastMaturityDate);
+        }
+        if self
+            .forbearances()
+            .iter()
+            .chain(pending)
+            .any(|forbearance| forbearance.overlaps(starts_at, ends_at))
+        {
+            return Err(CreditFacilityError::OverlappingForbearance);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn apply_forbearance(
+        &mut self,
+        forbearance: ForbearancePeriod,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::ForbearanceApplied {
+                forbearance: applied,
+                ..
+            } if applied.forbearance_id == forbearance.forbearance_id
+        );
+
+        if self.is_completed() {
+            return Ok(Idempotent::Ignored);
+        }
+        if self
+            .forbearances()
+            .iter()
+            .any(|applied| applied.overlaps(forbearance.starts_at, forbearance.ends_at))
+        {
+            return Err(CreditFacilityError::OverlappingForbearance);
+        }
+
+        self.events.push(CreditFacilityEvent::ForbearanceApplied {
+            forbearance,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn auto_debit_account_id(&self) -> Option<CalaAccountId> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CreditFacilityEvent::AutoDebitMandateUpdated {
+                deposit_account_id, ..
+            } => Some(*deposit_account_id),
+            _ => None,
+        })?
+    }
+
+    pub(crate) fn update_auto_debit_mandate(
+        &mut self,
+        deposit_account_id: Option<CalaAccountId>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CreditFacilityError> {
+        if self.is_completed() {
+            return Err(CreditFacilityError::AlreadyCompleted);
+        }
+        if self.auto_debit_account_id() == deposit_account_id {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events
+            .push(CreditFacilityEvent::AutoDebitMandateUpdated {
+                deposit_account_id,
+                audit_info,
+            });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn record_auto_debit_collected(
+        &mut self,
+        obligation_id: ObligationId,
+        payment_id: PaymentId,
+        requested: UsdCents,
+        collected: UsdCents,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        self.record_auto_debit_attempt(
+            obligation_id,
+            Some(payment_id),
+            requested,
+            collected,
+            None,
+            audit_info,
+        )
+    }
+
+    pub(crate) fn record_auto_debit_failed(
+        &mut self,
+        obligation_id: ObligationId,
+        requested: UsdCents,
+        failure_reason: String,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        self.record_auto_debit_attempt(
+            obligation_id,
+            None,
+            requested,
+            UsdCents::ZERO,
+            Some(failure_reason),
+            audit_info,
+        )
+    }
+
+    fn record_auto_debit_attempt(
+        &mut self,
+        obligation_id: ObligationId,
+        payment_id: Option<PaymentId>,
+        requested: UsdCents,
+        collected: UsdCents,
+        failure_reason: Option<String>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::AutoDebitAttempted {
+                obligation_id: attempted,
+                ..
+            } if *attempted == obligation_id
+        );
+
+        self.events.push(CreditFacilityEvent::AutoDebitAttempted {
+            obligation_id,
+            payment_id,
+            requested,
+            collected,
+            failure_reason,
+            attempted_at: crate::time::now(),
+            audit_info,
+        });
+
+        Idempotent::Executed(())
+    }
+
     pub(crate) fn is_completed(&self) -> bool {
         self.events
             .iter_all()
@@ -731,6 +1123,18 @@ impl CreditFacility {
             .any(|event| matches!(event, CreditFacilityEvent::Completed { .. }))
     }
 
+    pub(crate) fn exposure(&self, balances: &CreditFacilityBalanceSummary) -> UsdCents {
+        match self.status() {
+            CreditFacilityStatus::Closed => UsdCents::ZERO,
+            CreditFacilityStatus::Matured => balances.total_outstanding(),
+            CreditFacilityStatus::Active => {
+                balances.facility_remaining + balances.total_outstanding()
+            }
+            _ if matches!(self.is_approved(), Ok(false)) => UsdCents::ZERO,
+            _ => self.amount,
+        }
+    }
+
     pub(crate) fn complete(
         &mut self,
         audit_info: AuditInfo,
@@ -746,13 +1150,25 @@ impl CreditFacility {
             return Err(CreditFacilityError::OutstandingAmount);
         }
 
+        let completed_at = crate::time::now();
+        let unamortized_structuring_fee = self.unamortized_structuring_fee();
+        let structuring_fee_release = if unamortized_structuring_fee.is_zero() {
+            None
+        } else {
+            Some(self.record_structuring_fee_amortization(
+                unamortized_structuring_fee,
+                completed_at,
+                audit_info.clone(),
+            ))
+        };
+
         let res = CreditFacilityCompletion {
             tx_id: LedgerTxId::new(),
             collateral: self.collateral(),
             credit_facility_account_ids: self.account_ids,
+            structuring_fee_release,
         };
 
-        let completed_at = crate::time::now();
         self.confirm_collateral_update(
             CreditFacilityCollateralUpdate {
                 credit_facility_account_ids: self.account_ids,
@@ -837,10 +1253,12 @@ impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
                 }
                 CreditFacilityEvent::Activated { activated_at, .. } => {
                     let matures_at = terms
+                        .as_ref()
                         .expect("terms should be set")
                         .duration
                         .maturity_date(*activated_at);
                     let defaults_at = terms
+                        .as_ref()
                         .expect("terms should be set")
                         .interest_overdue_duration
                         .map(|d| d.end_date(matures_at));
@@ -853,9 +1271,14 @@ impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
                 CreditFacilityEvent::BalanceUpdated { .. } => (),
                 CreditFacilityEvent::InterestAccrualCycleStarted { .. } => (),
                 CreditFacilityEvent::InterestAccrualCycleConcluded { .. } => (),
+                CreditFacilityEvent::InterestRateAdjusted { .. } => (),
+                CreditFacilityEvent::StructuringFeeAmortized { .. } => (),
+                CreditFacilityEvent::ForbearanceApplied { .. } => (),
                 CreditFacilityEvent::CollateralUpdated { .. } => (),
                 CreditFacilityEvent::CollateralizationChanged { .. } => (),
                 CreditFacilityEvent::Completed { .. } => (),
+                CreditFacilityEvent::AutoDebitMandateUpdated { .. } => (),
+                CreditFacilityEvent::AutoDebitAttempted { .. } => (),
             }
         }
         builder.events(events).build()
@@ -912,7 +1335,8 @@ mod test {
     use rust_decimal_macros::dec;
 
     use crate::{
-        terms::{Duration, InterestInterval, OneTimeFeeRatePct},
+        pricing_grid::NewPricingGrid,
+        terms::{Duration, InterestInterval, OneTimeFeeRatePct, PricingGridBand},
         *,
     };
 
@@ -1226,7 +1650,12 @@ mod test {
         );
 
         credit_facility
-            .start_interest_accrual_cycle(dummy_audit_info())
+            .start_interest_accrual_cycle(
+                default_price(),
+                default_balances(credit_facility.amount),
+                None,
+                dummy_audit_info(),
+            )
             .unwrap()
             .unwrap();
 
@@ -1248,7 +1677,12 @@ mod test {
         let mut credit_facility = facility_from(events);
 
         credit_facility
-            .start_interest_accrual_cycle(dummy_audit_info())
+            .start_interest_accrual_cycle(
+                default_price(),
+                default_balances(credit_facility.amount),
+                None,
+                dummy_audit_info(),
+            )
             .unwrap()
             .unwrap();
         hydrate_accruals_in_facility(&mut credit_facility);
@@ -1356,6 +1790,101 @@ mod test {
         assert!(facility_cvl.is_disbursal_allowed(terms));
     }
 
+    #[test]
+    fn pricing_grid_adjusts_rate_on_activation() {
+        let new_grid = NewPricingGrid::builder()
+            .id(PricingGridId::new())
+            .bands([PricingGridBand {
+                min_cvl: CVLPct::new(200),
+                rate_adjustment: AnnualRatePct::from(dec!(-2)),
+            }])
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        let pricing_grid = PricingGrid::try_from_events(new_grid.into_events()).unwrap();
+        let terms = TermValues {
+            pricing_grid_id: Some(pricing_grid.id),
+            ..default_terms()
+        };
+        let mut credit_facility = facility_from(vec![CreditFacilityEvent::Initialized {
+            id: CreditFacilityId::new(),
+            audit_info: dummy_audit_info(),
+            customer_id: CustomerId::new(),
+            amount: default_facility(),
+            terms: Box::new(terms),
+            account_ids: CreditFacilityAccountIds::new(),
+            disbursal_credit_account_id: CalaAccountId::new(),
+            approval_process_id: ApprovalProcessId::new(),
+        }]);
+        credit_facility
+            .record_collateral_update(
+                default_full_collateral(),
+                dummy_audit_info(),
+                default_price(),
+                default_upgrade_buffer_cvl_pct(),
+                default_balances(credit_facility.amount),
+            )
+            .unwrap();
+        credit_facility
+            .approval_process_concluded(true, dummy_audit_info())
+            .unwrap();
+        assert!(credit_facility
+            .activate(
+                Utc::now(),
+                default_price(),
+                default_balances(credit_facility.amount),
+                Some(&pricing_grid),
+                dummy_audit_info(),
+            )
+            .unwrap()
+            .did_execute());
+
+        assert_eq!(
+            credit_facility.current_annual_rate(),
+            AnnualRatePct::from(dec!(10))
+        );
+        assert!(credit_facility
+            .events
+            .iter_all()
+            .any(|event| matches!(event, CreditFacilityEvent::InterestRateAdjusted { .. })));
+    }
+
+    #[test]
+    fn activation_without_pricing_grid_keeps_base_rate() {
+        let mut credit_facility = facility_from(initial_events());
+        credit_facility
+            .record_collateral_update(
+                default_full_collateral(),
+                dummy_audit_info(),
+                default_price(),
+                default_upgrade_buffer_cvl_pct(),
+                default_balances(credit_facility.amount),
+            )
+            .unwrap();
+        credit_facility
+            .approval_process_concluded(true, dummy_audit_info())
+            .unwrap();
+        assert!(credit_facility
+            .activate(
+                Utc::now(),
+                default_price(),
+                default_balances(credit_facility.amount),
+                None,
+                dummy_audit_info(),
+            )
+            .unwrap()
+            .did_execute());
+
+        assert_eq!(
+            credit_facility.current_annual_rate(),
+            credit_facility.terms.annual_rate
+        );
+        assert!(!credit_facility
+            .events
+            .iter_all()
+            .any(|event| matches!(event, CreditFacilityEvent::InterestRateAdjusted { .. })));
+    }
+
     #[test]
     fn check_activated_at() {
         let mut credit_facility = facility_from(initial_events());
@@ -1382,6 +1911,7 @@ mod test {
                 approval_time,
                 default_price(),
                 default_balances(credit_facility.amount),
+                None,
                 dummy_audit_info()
             )
             .unwrap()
@@ -1406,6 +1936,7 @@ mod test {
             approval_time,
             default_price(),
             default_balances(credit_facility.amount),
+            None,
             dummy_audit_info(),
         );
         assert!(matches!(res, Err(CreditFacilityError::NoCollateral)));
@@ -1433,6 +1964,7 @@ mod test {
             approval_time,
             default_price(),
             default_balances(credit_facility.amount),
+            None,
             dummy_audit_info(),
         );
         assert!(matches!(res, Err(CreditFacilityError::BelowMarginLimit)));
@@ -1467,6 +1999,7 @@ mod test {
                 Utc::now(),
                 default_price(),
                 default_balances(credit_facility.amount),
+                None,
                 dummy_audit_info()
             )
             .unwrap()
@@ -1492,6 +2025,7 @@ mod test {
                     Utc::now(),
                     default_price(),
                     default_balances(credit_facility.amount),
+                    None,
                     dummy_audit_info()
                 ),
                 Err(CreditFacilityError::ApprovalInProgress)
@@ -1513,6 +2047,7 @@ mod test {
                     Utc::now(),
                     default_price(),
                     default_balances(credit_facility.amount),
+                    None,
                     dummy_audit_info()
                 ),
                 Err(CreditFacilityError::Denied)
@@ -1534,6 +2069,7 @@ mod test {
                     Utc::now(),
                     default_price(),
                     default_balances(credit_facility.amount),
+                    None,
                     dummy_audit_info()
                 ),
                 Err(CreditFacilityError::NoCollateral)
@@ -1565,6 +2101,7 @@ mod test {
                     Utc::now(),
                     default_price(),
                     default_balances(credit_facility.amount),
+                    None,
                     dummy_audit_info()
                 ),
                 Err(CreditFacilityError::BelowMarginLimit)
@@ -1601,6 +2138,7 @@ mod test {
                     Utc::now(),
                     default_price(),
                     default_balances(credit_facility.amount),
+                    None,
                     dummy_audit_info()
                 ),
                 Ok(Idempotent::Ignored)
@@ -1633,12 +2171,186 @@ mod test {
                     Utc::now(),
                     default_price(),
                     default_balances(credit_facility.amount),
+                    None,
                     dummy_audit_info()
                 )
                 .is_ok());
         }
     }
 
+    mod structuring_fee_amortization {
+        use chrono::TimeZone;
+
+        use super::*;
+        use crate::terms::StructuringFeeRecognition;
+
+        fn activated_at() -> DateTime<Utc> {
+            Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap()
+        }
+
+        fn drawn() -> UsdCents {
+            default_facility()
+        }
+
+        fn activated_facility(recognition: StructuringFeeRecognition) -> CreditFacility {
+            let terms = TermValues {
+                structuring_fee_recognition: recognition,
+                ..default_terms()
+            };
+            let mut credit_facility = facility_from(vec![CreditFacilityEvent::Initialized {
+                id: CreditFacilityId::new(),
+                audit_info: dummy_audit_info(),
+                customer_id: CustomerId::new(),
+                amount: default_facility(),
+                terms: Box::new(terms),
+                account_ids: CreditFacilityAccountIds::new(),
+                disbursal_credit_account_id: CalaAccountId::new(),
+                approval_process_id: ApprovalProcessId::new(),
+            }]);
+
+            credit_facility
+                .record_collateral_update(
+                    default_full_collateral(),
+                    dummy_audit_info(),
+                    default_price(),
+                    default_upgrade_buffer_cvl_pct(),
+                    default_balances(credit_facility.amount),
+                )
+                .unwrap();
+            credit_facility
+                .approval_process_concluded(true, dummy_audit_info())
+                .unwrap();
+            let (activation, _) = credit_facility
+                .activate(
+                    activated_at(),
+                    default_price(),
+                    default_balances(credit_facility.amount),
+                    None,
+                    dummy_audit_info(),
+                )
+                .unwrap()
+                .unwrap();
+            assert_eq!(activation.defer_structuring_fee, recognition.is_deferred());
+
+            credit_facility
+        }
+
+        #[test]
+        fn upfront_fee_is_not_amortized() {
+            let mut credit_facility = activated_facility(StructuringFeeRecognition::Upfront);
+
+            assert!(credit_facility
+                .structuring_fee_amortization_schedule(drawn())
+                .is_empty());
+            assert_eq!(
+                credit_facility.next_structuring_fee_amortization_at(activated_at()),
+                None
+            );
+            assert!(credit_facility
+                .amortize_structuring_fee(
+                    credit_facility.matures_at.unwrap(),
+                    drawn(),
+                    dummy_audit_info()
+                )
+                .was_ignored());
+        }
+
+        #[test]
+        fn deferred_fee_is_amortized_per_schedule() {
+            let mut credit_facility = activated_facility(StructuringFeeRecognition::Deferred);
+
+            let schedule = credit_facility.structuring_fee_amortization_schedule(drawn());
+            let total = schedule
+                .iter()
+                .fold(UsdCents::ZERO, |total, period| total + period.amount);
+            assert_eq!(total, credit_facility.structuring_fee());
+
+            let first_end = schedule[0].period.end;
+            assert_eq!(
+                credit_facility.next_structuring_fee_amortization_at(activated_at()),
+                Some(first_end)
+            );
+            let amortization = credit_facility
+                .amortize_structuring_fee(first_end, drawn(), dummy_audit_info())
+                .unwrap();
+            assert_eq!(amortization.amount, schedule[0].amount);
+            assert!(credit_facility
+                .amortize_structuring_fee(first_end, drawn(), dummy_audit_info())
+                .was_ignored());
+            assert_eq!(
+                credit_facility.next_structuring_fee_amortization_at(first_end),
+                Some(schedule[1].period.end)
+            );
+
+            let remaining = credit_facility.structuring_fee_amortization_schedule(drawn());
+            assert_eq!(remaining[0].period, schedule[1].period);
+            let total = remaining
+                .iter()
+                .fold(UsdCents::ZERO, |total, period| total + period.amount);
+            assert_eq!(total, credit_facility.unamortized_structuring_fee());
+        }
+
+        #[test]
+        fn schedule_follows_drawn_principal_and_rate() {
+            let mut credit_facility = activated_facility(StructuringFeeRecognition::Deferred);
+
+            let undrawn = credit_facility.structuring_fee_amortization_schedule(UsdCents::ZERO);
+            let fully_drawn = credit_facility.structuring_fee_amortization_schedule(drawn());
+            assert_ne!(undrawn, fully_drawn);
+
+            credit_facility
+                .events
+                .push(CreditFacilityEvent::InterestRateAdjusted {
+                    interest_accrual_id: InterestAccrualCycleId::new(),
+                    idx: InterestAccrualCycleIdx::FIRST,
+                    annual_rate: AnnualRatePct::from(dec!(24)),
+                    previous_annual_rate: credit_facility.terms.annual_rate,
+                    cvl: CVLPct::new(150),
+                    adjusted_at: activated_at(),
+                    audit_info: dummy_audit_info(),
+                });
+            assert_ne!(
+                credit_facility.structuring_fee_amortization_schedule(drawn()),
+                fully_drawn
+            );
+        }
+
+        #[test]
+        fn completion_releases_unamortized_fee() {
+            let mut credit_facility = activated_facility(StructuringFeeRecognition::Deferred);
+            let first_period = credit_facility.structuring_fee_amortization_schedule(drawn())[0];
+            let _ = credit_facility
+                .amortize_structuring_fee(first_period.period.end, drawn(), dummy_audit_info())
+                .unwrap();
+
+            let completion = credit_facility
+                .complete(
+                    dummy_audit_info(),
+                    default_price(),
+                    default_upgrade_buffer_cvl_pct(),
+                    default_balances(credit_facility.amount),
+                )
+                .unwrap()
+                .unwrap();
+
+            let release = completion
+                .structuring_fee_release
+                .expect("unamortized fee should be released");
+            assert_eq!(
+                release.amount,
+                credit_facility.structuring_fee() - first_period.amount
+            );
+            assert_eq!(
+                credit_facility.unamortized_structuring_fee(),
+                UsdCents::ZERO
+            );
+            assert_eq!(
+                credit_facility.next_structuring_fee_amortization_at(first_period.period.end),
+                None
+            );
+        }
+    }
+
     mod completion {
         use super::*;
 
@@ -1909,4 +2621,287 @@ mod test {
             ));
         }
     }
+
+    mod forbearance {
+        use chrono::TimeZone;
+
+        use super::*;
+
+        fn activated_facility() -> CreditFacility {
+            let mut credit_facility = facility_from(initial_events());
+            credit_facility
+                .record_collateral_update(
+                    default_full_collateral(),
+                    dummy_audit_info(),
+                    default_price(),
+                    default_upgrade_buffer_cvl_pct(),
+                    default_balances(credit_facility.amount),
+                )
+                .unwrap();
+            credit_facility
+                .approval_process_concluded(true, dummy_audit_info())
+                .unwrap();
+            credit_facility
+                .activate(
+                    Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap(),
+                    default_price(),
+                    default_balances(credit_facility.amount),
+                    None,
+                    dummy_audit_info(),
+                )
+                .unwrap()
+                .unwrap();
+
+            credit_facility
+        }
+
+        fn forbearance(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> ForbearancePeriod {
+            ForbearancePeriod {
+                forbearance_id: ForbearanceId::new(),
+                starts_at,
+                ends_at,
+                capitalize_interest: false,
+            }
+        }
+
+        #[test]
+        fn errors_if_not_activated() {
+            let credit_facility = facility_from(initial_events());
+
+            assert!(matches!(
+                credit_facility.check_forbearance_period(
+                    Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap(),
+                    Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+                    &[],
+                ),
+                Err(CreditFacilityError::NotActivatedYet)
+            ));
+        }
+
+        #[test]
+        fn errors_if_past_maturity() {
+            let credit_facility = activated_facility();
+
+            assert!(matches!(
+                credit_facility.check_forbearance_period(
+                    Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+                    Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap(),
+                    &[],
+                ),
+                Err(CreditFacilityError::ForbearancePastMaturityDate)
+            ));
+        }
+
+        #[test]
+        fn errors_if_overlapping() {
+            let mut credit_facility = activated_facility();
+            let applied = forbearance(
+                Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+            );
+            assert!(credit_facility
+                .apply_forbearance(applied, dummy_audit_info())
+                .unwrap()
+                .did_execute());
+
+            assert!(matches!(
+                credit_facility.check_forbearance_period(
+                    Utc.with_ymd_and_hms(2025, 2, 15, 0, 0, 0).unwrap(),
+                    Utc.with_ymd_and_hms(2025, 3, 15, 0, 0, 0).unwrap(),
+                    &[],
+                ),
+                Err(CreditFacilityError::OverlappingForbearance)
+            ));
+            assert!(credit_facility
+                .check_forbearance_period(
+                    Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+                    Utc.with_ymd_and_hms(2025, 3, 15, 0, 0, 0).unwrap(),
+                    &[],
+                )
+                .is_ok());
+        }
+
+        #[test]
+        fn errors_if_overlapping_pending_request() {
+            let credit_facility = activated_facility();
+            let pending = forbearance(
+                Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+            );
+
+            assert!(matches!(
+                credit_facility.check_forbearance_period(
+                    Utc.with_ymd_and_hms(2025, 2, 15, 0, 0, 0).unwrap(),
+                    Utc.with_ymd_and_hms(2025, 3, 15, 0, 0, 0).unwrap(),
+                    &[pending],
+                ),
+                Err(CreditFacilityError::OverlappingForbearance)
+            ));
+        }
+
+        #[test]
+        fn apply_forbearance_rejects_overlap() {
+            let mut credit_facility = activated_facility();
+            let first = forbearance(
+                Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+            );
+            let second = forbearance(
+                Utc.with_ymd_and_hms(2025, 2, 15, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 3, 15, 0, 0, 0).unwrap(),
+            );
+
+            assert!(credit_facility
+                .apply_forbearance(first, dummy_audit_info())
+                .unwrap()
+                .did_execute());
+            assert!(matches!(
+                credit_facility.apply_forbearance(second, dummy_audit_info()),
+                Err(CreditFacilityError::OverlappingForbearance)
+            ));
+            assert_eq!(credit_facility.forbearances(), vec![first]);
+        }
+
+        #[test]
+        fn apply_forbearance_is_idempotent() {
+            let mut credit_facility = activated_facility();
+            let applied = forbearance(
+                Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+            );
+
+            assert!(credit_facility
+                .apply_forbearance(applied, dummy_audit_info())
+                .unwrap()
+                .did_execute());
+            assert!(credit_facility
+                .apply_forbearance(applied, dummy_audit_info())
+                .unwrap()
+                .was_ignored());
+            assert_eq!(credit_facility.forbearances(), vec![applied]);
+        }
+    }
+
+    mod exposure {
+        use super::*;
+
+        #[test]
+        fn pending_facility_exposes_full_amount() {
+            let credit_facility = facility_from(initial_events());
+
+            assert_eq!(
+                credit_facility.exposure(&default_balances(UsdCents::ZERO)),
+                default_facility()
+            );
+        }
+
+        #[test]
+        fn denied_facility_has_no_exposure() {
+            let mut credit_facility = facility_from(initial_events());
+            credit_facility
+                .approval_process_concluded(false, dummy_audit_info())
+                .unwrap();
+
+            assert_eq!(
+                credit_facility.exposure(&default_balances(UsdCents::ZERO)),
+                UsdCents::ZERO
+            );
+        }
+
+        #[test]
+        fn active_facility_exposes_undisbursed_and_outstanding() {
+            let mut credit_facility = facility_from(initial_events());
+            credit_facility
+                .record_collateral_update(
+                    default_full_collateral(),
+                    dummy_audit_info(),
+                    default_price(),
+                    default_upgrade_buffer_cvl_pct(),
+                    default_balances(credit_facility.amount),
+                )
+                .unwrap();
+            credit_facility
+                .approval_process_concluded(true, dummy_audit_info())
+                .unwrap();
+            credit_facility
+                .activate(
+                    Utc::now(),
+                    default_price(),
+                    default_balances(credit_facility.amount),
+                    None,
+                    dummy_audit_info(),
+                )
+                .unwrap()
+                .unwrap();
+
+            let balances = CreditFacilityBalanceSummary {
+                not_yet_due_disbursed_outstanding: UsdCents::from(4_00),
+                due_interest_outstanding: UsdCents::from(1),
+                ..default_balances(UsdCents::from(6_00))
+            };
+            assert_eq!(credit_facility.exposure(&balances), UsdCents::from(10_01));
+        }
+    }
+
+    mod auto_debit {
+        use super::*;
+
+        #[test]
+        fn mandate_update_is_idempotent() {
+            let mut credit_facility = facility_from(initial_events());
+            assert_eq!(credit_facility.auto_debit_account_id(), None);
+
+            let deposit_account_id = CalaAccountId::new();
+            assert!(credit_facility
+                .update_auto_debit_mandate(Some(deposit_account_id), dummy_audit_info())
+                .unwrap()
+                .did_execute());
+            assert!(credit_facility
+                .update_auto_debit_mandate(Some(deposit_account_id), dummy_audit_info())
+                .unwrap()
+                .was_ignored());
+            assert_eq!(
+                credit_facility.auto_debit_account_id(),
+                Some(deposit_account_id)
+            );
+
+            assert!(credit_facility
+                .update_auto_debit_mandate(None, dummy_audit_info())
+                .unwrap()
+                .did_execute());
+            assert_eq!(credit_facility.auto_debit_account_id(), None);
+        }
+
+        #[test]
+        fn attempt_is_recorded_once_per_obligation() {
+            let mut credit_facility = facility_from(initial_events());
+            let obligation_id = ObligationId::new();
+
+            assert!(credit_facility
+                .record_auto_debit_collected(
+                    obligation_id,
+                    PaymentId::new(),
+                    UsdCents::from(10_00),
+                    UsdCents::from(4_00),
+                    dummy_audit_info(),
+                )
+                .did_execute());
+            assert!(credit_facility
+                .record_auto_debit_failed(
+                    obligation_id,
+                    UsdCents::from(10_00),
+                    "insufficient available balance".to_string(),
+                    dummy_audit_info(),
+                )
+                .was_ignored());
+            assert!(credit_facility
+                .record_auto_debit_failed(
+                    ObligationId::new(),
+                    UsdCents::from(10_00),
+                    "insufficient available balance".to_string(),
+                    dummy_audit_info(),
+                )
+                .did_execute());
+        }
+    }
 }
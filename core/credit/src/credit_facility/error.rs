@@ -24,6 +24,12 @@ pub enum CreditFacilityError {
     DisbursalPastMaturityDate,
     #[error("CreditFacilityError - NotActivatedYet")]
     NotActivatedYet,
+    #[error("CreditFacilityError - AlreadyCompleted")]
+    AlreadyCompleted,
+    #[error("CreditFacilityError - ForbearancePastMaturityDate")]
+    ForbearancePastMaturityDate,
+    #[error("CreditFacilityError - OverlappingForbearance")]
+    OverlappingForbearance,
     #[error("CreditFacilityError - InterestAccrualNotCompletedYet")]
     InterestAccrualNotCompletedYet,
     #[error("CreditFacilityError - NoDisbursalInProgress")]
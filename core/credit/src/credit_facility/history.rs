@@ -1,6 +1,9 @@
 use chrono::{DateTime, Utc};
 
-use crate::{primitives::*, terms::CollateralizationState};
+use crate::{
+    primitives::*,
+    terms::{AnnualRatePct, CVLPct, CollateralizationState},
+};
 
 use super::{BalanceUpdatedSource, CreditFacilityEvent};
 
@@ -51,6 +54,24 @@ pub struct InterestAccrualsPosted {
     pub tx_id: LedgerTxId,
 }
 
+#[derive(Debug)]
+pub struct InterestRateAdjusted {
+    pub annual_rate: AnnualRatePct,
+    pub previous_annual_rate: AnnualRatePct,
+    pub cvl: CVLPct,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+pub struct AutoDebitAttempt {
+    pub obligation_id: ObligationId,
+    pub requested: UsdCents,
+    pub collected: UsdCents,
+    pub outcome: AutoDebitOutcome,
+    pub failure_reason: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
 #[derive(Debug)]
 pub enum CreditFacilityHistoryEntry {
     Payment(IncrementalPayment),
@@ -59,6 +80,8 @@ pub enum CreditFacilityHistoryEntry {
     Collateralization(CollateralizationUpdated),
     Disbursal(DisbursalExecuted),
     Interest(InterestAccrualsPosted),
+    InterestRate(InterestRateAdjusted),
+    AutoDebit(AutoDebitAttempt),
 }
 
 pub(super) fn project<'a>(
@@ -163,6 +186,22 @@ pub(super) fn project<'a>(
             } => {
                 interest_accruals_started_at.insert(*idx, *started_at);
             }
+            CreditFacilityEvent::InterestRateAdjusted {
+                annual_rate,
+                previous_annual_rate,
+                cvl,
+                adjusted_at,
+                ..
+            } => {
+                history.push(CreditFacilityHistoryEntry::InterestRate(
+                    InterestRateAdjusted {
+                        annual_rate: *annual_rate,
+                        previous_annual_rate: *previous_annual_rate,
+                        cvl: *cvl,
+                        recorded_at: *adjusted_at,
+                    },
+                ));
+            }
             CreditFacilityEvent::InterestAccrualCycleConcluded {
                 idx,
                 tx_id,
@@ -176,7 +215,8 @@ pub(super) fn project<'a>(
             }
             CreditFacilityEvent::BalanceUpdated {
                 source: BalanceUpdatedSource::Obligation(obligation_id),
-                balance_type: BalanceUpdatedType::InterestAccrual,
+                balance_type:
+                    BalanceUpdatedType::InterestAccrual | BalanceUpdatedType::InterestCapitalization,
                 amount,
                 updated_at: posted_at,
                 ..
@@ -194,6 +234,30 @@ pub(super) fn project<'a>(
                     },
                 ));
             }
+            CreditFacilityEvent::AutoDebitAttempted {
+                obligation_id,
+                requested,
+                collected,
+                failure_reason,
+                attempted_at,
+                ..
+            } => {
+                let outcome = if failure_reason.is_some() || collected.is_zero() {
+                    AutoDebitOutcome::Failed
+                } else if collected < requested {
+                    AutoDebitOutcome::PartiallyCollected
+                } else {
+                    AutoDebitOutcome::Collected
+                };
+                history.push(CreditFacilityHistoryEntry::AutoDebit(AutoDebitAttempt {
+                    obligation_id: *obligation_id,
+                    requested: *requested,
+                    collected: *collected,
+                    outcome,
+                    failure_reason: failure_reason.clone(),
+                    recorded_at: *attempted_at,
+                }));
+            }
 
             _ => {}
         }
@@ -229,4 +293,25 @@ mod test {
         let res = project(events.iter());
         assert_eq!(res.len(), 1)
     }
+
+    #[test]
+    fn can_project_partial_auto_debit() {
+        let events = vec![CreditFacilityEvent::AutoDebitAttempted {
+            obligation_id: ObligationId::new(),
+            payment_id: Some(PaymentId::new()),
+            requested: UsdCents::from(1_000_00),
+            collected: UsdCents::from(400_00),
+            failure_reason: None,
+            attempted_at: crate::time::now(),
+            audit_info: dummy_audit_info(),
+        }];
+        let res = project(events.iter());
+        match &res[..] {
+            [CreditFacilityHistoryEntry::AutoDebit(attempt)] => {
+                assert_eq!(attempt.outcome, AutoDebitOutcome::PartiallyCollected);
+                assert_eq!(attempt.collected, UsdCents::from(400_00));
+            }
+            _ => panic!("expected a single auto debit entry"),
+        }
+    }
 }
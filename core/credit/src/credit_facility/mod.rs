@@ -5,6 +5,7 @@ pub mod error;
 mod history;
 mod repayment_plan;
 mod repo;
+mod structuring_fee;
 
 pub use balance::CreditFacilityBalanceSummary;
 pub use cvl::FacilityCVL;
@@ -16,3 +17,4 @@ pub use repo::{
     credit_facility_cursor::*, CreditFacilitiesSortBy, CreditFacilityRepo,
     FindManyCreditFacilities, ListDirection, Sort,
 };
+pub use structuring_fee::StructuringFeeAmortizationPeriod;
@@ -1,6 +1,9 @@
 use chrono::{DateTime, Utc};
 
-use crate::primitives::{BalanceUpdatedType, UsdCents};
+use crate::{
+    forbearance::ForbearancePeriod,
+    primitives::{BalanceUpdatedType, UsdCents},
+};
 
 use super::{BalanceUpdatedSource, CreditFacilityEvent, CreditFacilityReceivable};
 
@@ -31,7 +34,9 @@ pub(super) fn project<'a>(
     events: impl DoubleEndedIterator<Item = &'a CreditFacilityEvent>,
 ) -> Vec<CreditFacilityRepaymentInPlan> {
     let mut terms = None;
+    let mut annual_rate = None;
     let mut activated_at = None;
+    let mut forbearances: Vec<ForbearancePeriod> = Vec::new();
 
     let mut total_disbursed = UsdCents::ZERO;
     let mut due_and_outstanding_disbursed = UsdCents::ZERO;
@@ -43,8 +48,14 @@ pub(super) fn project<'a>(
     for event in events {
         match event {
             CreditFacilityEvent::Initialized { terms: t, .. } => {
+                annual_rate = Some(t.annual_rate);
                 terms = Some(t);
             }
+            CreditFacilityEvent::InterestRateAdjusted {
+                annual_rate: rate, ..
+            } => {
+                annual_rate = Some(*rate);
+            }
             CreditFacilityEvent::Activated {
                 activated_at: recorded_at,
                 ..
@@ -60,6 +71,17 @@ pub(super) fn project<'a>(
                 total_disbursed += *amount;
                 due_and_outstanding_disbursed += *amount;
             }
+            CreditFacilityEvent::BalanceUpdated {
+                source: BalanceUpdatedSource::Obligation(_),
+                balance_type: BalanceUpdatedType::InterestCapitalization,
+                amount,
+                updated_at: posted_at,
+                ..
+            } => {
+                last_interest_accrual_at = Some(*posted_at);
+                total_disbursed += *amount;
+                due_and_outstanding_disbursed += *amount;
+            }
             CreditFacilityEvent::BalanceUpdated {
                 source: BalanceUpdatedSource::Obligation(_),
                 balance_type: BalanceUpdatedType::InterestAccrual,
@@ -68,7 +90,11 @@ pub(super) fn project<'a>(
                 ..
             } => {
                 last_interest_accrual_at = Some(*posted_at);
-                let due_at = *posted_at;
+                let due_at = forbearances
+                    .iter()
+                    .find(|f| f.covers(*posted_at))
+                    .map(|f| f.defer(*posted_at))
+                    .unwrap_or(*posted_at);
 
                 interest_accruals.push(RepaymentInPlan {
                     status: RepaymentStatus::Overdue,
@@ -78,13 +104,23 @@ pub(super) fn project<'a>(
                     due_at,
                 });
             }
+            CreditFacilityEvent::ForbearanceApplied { forbearance, .. } => {
+                for accrual in interest_accruals.iter_mut() {
+                    if forbearance.covers(accrual.due_at) {
+                        accrual.due_at = forbearance.defer(accrual.due_at);
+                    }
+                }
+                forbearances.push(*forbearance);
+            }
             CreditFacilityEvent::BalanceUpdated {
                 source: BalanceUpdatedSource::PaymentAllocation(_),
                 balance_type,
                 amount,
                 ..
             } => match balance_type {
-                BalanceUpdatedType::Disbursal => due_and_outstanding_disbursed -= *amount,
+                BalanceUpdatedType::Disbursal | BalanceUpdatedType::InterestCapitalization => {
+                    due_and_outstanding_disbursed -= *amount
+                }
                 BalanceUpdatedType::InterestAccrual => due_and_outstanding_interest += *amount,
             },
             _ => {}
@@ -114,6 +150,7 @@ pub(super) fn project<'a>(
         interest: due_and_outstanding_interest,
     };
     let terms = terms.expect("Initialized event not found");
+    let annual_rate = annual_rate.expect("Initialized event not found");
     let activated_at = match activated_at {
         Some(time) => time,
         None => return Vec::new(),
@@ -139,32 +176,51 @@ pub(super) fn project<'a>(
             .truncate(maturity_date)
     };
 
+    let mut capitalized_interest = UsdCents::ZERO;
     if !due_and_outstanding.is_zero() {
         while let Some(period) = next_interest_period {
-            let interest = terms
-                .annual_rate
-                .interest_for_time_period(due_and_outstanding.total(), period.days());
+            let interest = annual_rate.interest_for_time_period(
+                due_and_outstanding.total() + capitalized_interest,
+                period.days(),
+            );
+
+            let forbearance = forbearances
+                .iter()
+                .find(|forbearance| forbearance.covers(period.end));
+            if terms.interest_capitalization.is_capitalized()
+                || forbearance
+                    .is_some_and(|forbearance| forbearance.capitalizes_interest_at(period.end))
+            {
+                capitalized_interest += interest;
+                next_interest_period = period.next().truncate(maturity_date);
+                continue;
+            }
+
+            let due_at = forbearance
+                .map(|forbearance| forbearance.defer(period.end))
+                .unwrap_or(period.end);
 
             res.push(CreditFacilityRepaymentInPlan::Interest(RepaymentInPlan {
                 status: RepaymentStatus::Upcoming,
                 initial: interest,
                 outstanding: interest,
                 accrual_at: period.end,
-                due_at: period.end,
+                due_at,
             }));
 
             next_interest_period = period.next().truncate(maturity_date);
         }
     }
 
+    let outstanding_disbursed = due_and_outstanding_disbursed + capitalized_interest;
     res.push(CreditFacilityRepaymentInPlan::Disbursal(RepaymentInPlan {
-        status: if due_and_outstanding_disbursed == UsdCents::ZERO {
+        status: if outstanding_disbursed == UsdCents::ZERO {
             RepaymentStatus::Paid
         } else {
             RepaymentStatus::Upcoming
         },
-        initial: total_disbursed,
-        outstanding: due_and_outstanding_disbursed,
+        initial: total_disbursed + capitalized_interest,
+        outstanding: outstanding_disbursed,
         accrual_at: maturity_date,
         due_at: maturity_date,
     }));
@@ -405,6 +461,77 @@ mod tests {
         }
     }
 
+    fn forbearance_event(capitalize_interest: bool) -> CreditFacilityEvent {
+        CreditFacilityEvent::ForbearanceApplied {
+            forbearance: ForbearancePeriod {
+                forbearance_id: ForbearanceId::new(),
+                starts_at: "2020-04-15T00:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+                ends_at: "2020-05-10T00:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+                capitalize_interest,
+            },
+            audit_info: dummy_audit_info(),
+        }
+    }
+
+    #[test]
+    fn forbearance_defers_interest_due_in_period() {
+        let mut events = happy_credit_facility_events();
+        events.push(forbearance_event(false));
+        let repayment_plan = super::project(events.iter());
+
+        match &repayment_plan[1] {
+            CreditFacilityRepaymentInPlan::Interest(second) => {
+                assert_eq!(
+                    second.accrual_at,
+                    "2020-04-30T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+                );
+                assert_eq!(
+                    second.due_at,
+                    "2020-05-25T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+                );
+            }
+            _ => panic!("Expected second element to be Interest"),
+        }
+        match &repayment_plan[2] {
+            CreditFacilityRepaymentInPlan::Interest(third) => {
+                assert_eq!(third.due_at, third.accrual_at);
+            }
+            _ => panic!("Expected third element to be Interest"),
+        }
+    }
+
+    #[test]
+    fn forbearance_capitalizes_interest_into_principal() {
+        let mut events = happy_credit_facility_events();
+        events.push(forbearance_event(true));
+        let repayment_plan = super::project(events.iter());
+
+        let n_existing_interest_accruals = 1;
+        let n_future_interest_accruals = 1;
+        let n_principal_accruals = 1;
+        assert_eq!(
+            repayment_plan.len(),
+            n_existing_interest_accruals + n_future_interest_accruals + n_principal_accruals
+        );
+        match &repayment_plan[1] {
+            CreditFacilityRepaymentInPlan::Interest(second) => {
+                assert_eq!(
+                    second.accrual_at,
+                    "2020-05-14T14:20:00Z".parse::<DateTime<Utc>>().unwrap()
+                );
+                assert_eq!(second.due_at, second.accrual_at);
+            }
+            _ => panic!("Expected second element to be Interest"),
+        }
+        match &repayment_plan[2] {
+            CreditFacilityRepaymentInPlan::Disbursal(principal) => {
+                assert_eq!(principal.initial, UsdCents::from(1010));
+                assert_eq!(principal.outstanding, UsdCents::from(1010));
+            }
+            _ => panic!("Expected third element to be Disbursal"),
+        }
+    }
+
     #[test]
     fn overdue_payment() {
         let mut events = happy_credit_facility_events();
@@ -694,4 +821,76 @@ mod tests {
             _ => panic!("Expected fourth element to be Disbursal"),
         }
     }
+
+    #[test]
+    fn capitalized_interest_grows_principal() {
+        let activated_at = default_activated_at();
+        let disbursal_obligation_id = ObligationId::new();
+        let interest_obligation_id = ObligationId::new();
+        let first_interest_posted_at = end_of_month(activated_at);
+        let terms = TermValues {
+            interest_capitalization: InterestCapitalization::Capitalized,
+            ..terms()
+        };
+        let events = vec![
+            CreditFacilityEvent::Initialized {
+                id: CreditFacilityId::new(),
+                customer_id: CustomerId::new(),
+                account_ids: CreditFacilityAccountIds::new(),
+                amount: UsdCents::from(1_000_000),
+                terms: Box::new(terms),
+                audit_info: dummy_audit_info(),
+                disbursal_credit_account_id: CalaAccountId::new(),
+                approval_process_id: ApprovalProcessId::new(),
+            },
+            CreditFacilityEvent::Activated {
+                ledger_tx_id: LedgerTxId::new(),
+                activated_at,
+                audit_info: dummy_audit_info(),
+            },
+            CreditFacilityEvent::BalanceUpdated {
+                source: BalanceUpdatedSource::Obligation(disbursal_obligation_id),
+                ledger_tx_id: LedgerTxId::new(),
+                balance_type: BalanceUpdatedType::Disbursal,
+                amount: UsdCents::from(1_000_000),
+                updated_at: activated_at,
+                audit_info: dummy_audit_info(),
+            },
+            CreditFacilityEvent::InterestAccrualCycleConcluded {
+                idx: InterestAccrualCycleIdx::FIRST,
+                tx_id: LedgerTxId::new(),
+                obligation_id: interest_obligation_id,
+                audit_info: dummy_audit_info(),
+            },
+            CreditFacilityEvent::BalanceUpdated {
+                source: BalanceUpdatedSource::Obligation(interest_obligation_id),
+                ledger_tx_id: LedgerTxId::new(),
+                balance_type: BalanceUpdatedType::InterestCapitalization,
+                amount: UsdCents::from(5_589),
+                updated_at: first_interest_posted_at,
+                audit_info: dummy_audit_info(),
+            },
+        ];
+        let repayment_plan = super::project(events.iter());
+
+        assert_eq!(repayment_plan.len(), 1);
+        let principal_after_first_cycle = UsdCents::from(1_005_589);
+        let april_interest = terms
+            .annual_rate
+            .interest_for_time_period(principal_after_first_cycle, 30);
+        let may_interest = terms
+            .annual_rate
+            .interest_for_time_period(principal_after_first_cycle + april_interest, 14);
+        match &repayment_plan[0] {
+            CreditFacilityRepaymentInPlan::Disbursal(principal) => {
+                assert_eq!(principal.status, RepaymentStatus::Upcoming);
+                assert_eq!(
+                    principal.initial,
+                    principal_after_first_cycle + april_interest + may_interest
+                );
+                assert_eq!(principal.outstanding, principal.initial);
+            }
+            _ => panic!("Expected only element to be Disbursal"),
+        }
+    }
 }
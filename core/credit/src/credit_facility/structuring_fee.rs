@@ -0,0 +1,242 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::{prelude::*, Decimal};
+use rust_decimal_macros::dec;
+
+use crate::{
+    primitives::UsdCents,
+    terms::{AnnualRatePct, InterestInterval, InterestPeriod},
+};
+
+const MAX_RATE_SEARCH_ITERATIONS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StructuringFeeAmortizationPeriod {
+    pub period: InterestPeriod,
+    pub amount: UsdCents,
+}
+
+pub(super) fn amortization_periods(
+    activated_at: DateTime<Utc>,
+    matures_at: DateTime<Utc>,
+) -> Vec<InterestPeriod> {
+    let mut periods = vec![];
+    let mut next = InterestInterval::EndOfMonth
+        .period_from(activated_at)
+        .truncate(matures_at);
+    while let Some(period) = next {
+        periods.push(period);
+        next = period.next().truncate(matures_at);
+    }
+    periods
+}
+
+/// Spreads `fee` over `periods` using the effective interest method on the drawn
+/// `principal`. While too little is drawn for an effective rate to exist the fee is
+/// recognised straight-line, as a commitment fee would be.
+pub(super) fn effective_interest_schedule(
+    principal: UsdCents,
+    fee: UsdCents,
+    annual_rate: AnnualRatePct,
+    periods: Vec<InterestPeriod>,
+) -> Vec<StructuringFeeAmortizationPeriod> {
+    if fee.is_zero() || periods.is_empty() {
+        return vec![];
+    }
+    if principal.is_zero() || fee >= principal {
+        return straight_line_schedule(fee, periods);
+    }
+
+    let principal_usd = principal.to_usd();
+    let coupons = periods
+        .iter()
+        .map(|period| {
+            annual_rate
+                .interest_for_time_period(principal, period.days())
+                .to_usd()
+        })
+        .collect::<Vec<_>>();
+    let days = periods
+        .iter()
+        .map(|period| period.days())
+        .collect::<Vec<_>>();
+    let carrying_amount = principal_usd - fee.to_usd();
+    let daily_rate = daily_effective_rate(principal_usd, carrying_amount, &coupons, &days);
+
+    let mut carrying = carrying_amount;
+    let mut amortized = Decimal::ZERO;
+    let mut released = UsdCents::ZERO;
+    let last_idx = periods.len() - 1;
+    periods
+        .into_iter()
+        .zip(coupons)
+        .enumerate()
+        .map(|(idx, (period, coupon))| {
+            let effective_interest = carrying * (growth(daily_rate, period.days()) - Decimal::ONE);
+            let amortization = (effective_interest - coupon).max(Decimal::ZERO);
+            carrying += amortization;
+            amortized += amortization;
+
+            let cumulative = if idx == last_idx {
+                fee
+            } else {
+                UsdCents::try_from_usd(
+                    amortized
+                        .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero)
+                        .min(fee.to_usd()),
+                )
+                .expect("amortized amount should be positive")
+            };
+            let amount = cumulative - released;
+            released = cumulative;
+            StructuringFeeAmortizationPeriod { period, amount }
+        })
+        .collect()
+}
+
+fn straight_line_schedule(
+    fee: UsdCents,
+    periods: Vec<InterestPeriod>,
+) -> Vec<StructuringFeeAmortizationPeriod> {
+    let total_days = Decimal::from(periods.iter().map(|period| period.days()).sum::<u32>());
+    let mut elapsed_days = 0;
+    let mut released = UsdCents::ZERO;
+    periods
+        .into_iter()
+        .map(|period| {
+            elapsed_days += period.days();
+            let cumulative = UsdCents::try_from_usd(
+                (fee.to_usd() * Decimal::from(elapsed_days) / total_days)
+                    .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero),
+            )
+            .expect("amortized amount should be positive");
+            let amount = cumulative - released;
+            released = cumulative;
+            StructuringFeeAmortizationPeriod { period, amount }
+        })
+        .collect()
+}
+
+/// Compounds once per accrual period, with interest accruing simply on the days
+/// within it, matching how the coupons themselves are accrued.
+fn growth(daily_rate: Decimal, days: u32) -> Decimal {
+    Decimal::ONE + daily_rate * Decimal::from(days)
+}
+
+/// Solves for the daily rate that discounts the contractual cash flows (interest
+/// coupons plus the principal at maturity) back to the net carrying amount.
+fn daily_effective_rate(
+    principal: Decimal,
+    carrying_amount: Decimal,
+    coupons: &[Decimal],
+    days: &[u32],
+) -> Decimal {
+    let present_value = |rate: Decimal| {
+        let mut discount = Decimal::ONE;
+        let mut pv = Decimal::ZERO;
+        for (coupon, days) in coupons.iter().zip(days) {
+            discount /= growth(rate, *days);
+            pv += coupon * discount;
+        }
+        pv + principal * discount
+    };
+
+    let mut low = Decimal::ZERO;
+    let mut high = dec!(0.001);
+    while present_value(high) > carrying_amount {
+        low = high;
+        high *= dec!(2);
+    }
+
+    for _ in 0..MAX_RATE_SEARCH_ITERATIONS {
+        let mid = (low + high) / dec!(2);
+        if present_value(mid) > carrying_amount {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    (low + high) / dec!(2)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn activated_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap()
+    }
+
+    fn matures_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 15, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn schedule_releases_full_fee() {
+        let fee = UsdCents::from(1_000_00);
+        let schedule = effective_interest_schedule(
+            UsdCents::from(100_000_00),
+            fee,
+            AnnualRatePct::from(dec!(12)),
+            amortization_periods(activated_at(), matures_at()),
+        );
+
+        assert_eq!(schedule.len(), 13);
+        let total = schedule
+            .iter()
+            .fold(UsdCents::ZERO, |total, period| total + period.amount);
+        assert_eq!(total, fee);
+        assert_eq!(schedule.last().unwrap().period.end, matures_at());
+    }
+
+    #[test]
+    fn schedule_releases_more_as_carrying_amount_grows() {
+        let schedule = effective_interest_schedule(
+            UsdCents::from(100_000_00),
+            UsdCents::from(1_000_00),
+            AnnualRatePct::from(dec!(12)),
+            amortization_periods(activated_at(), matures_at()),
+        );
+
+        let full_months = &schedule[1..schedule.len() - 1];
+        assert!(full_months
+            .windows(2)
+            .filter(|pair| pair[0].period.days() == pair[1].period.days())
+            .all(|pair| pair[0].amount <= pair[1].amount));
+    }
+
+    #[test]
+    fn no_schedule_without_fee() {
+        let schedule = effective_interest_schedule(
+            UsdCents::from(100_000_00),
+            UsdCents::ZERO,
+            AnnualRatePct::from(dec!(12)),
+            amortization_periods(activated_at(), matures_at()),
+        );
+
+        assert!(schedule.is_empty());
+    }
+
+    #[test]
+    fn undrawn_facility_releases_fee_straight_line() {
+        let fee = UsdCents::from(1_000_00);
+        let schedule = effective_interest_schedule(
+            UsdCents::ZERO,
+            fee,
+            AnnualRatePct::from(dec!(12)),
+            amortization_periods(activated_at(), matures_at()),
+        );
+
+        assert_eq!(schedule.len(), 13);
+        let total = schedule
+            .iter()
+            .fold(UsdCents::ZERO, |total, period| total + period.amount);
+        assert_eq!(total, fee);
+        let (first, last) = (schedule[0], schedule[12]);
+        assert!(first.amount < schedule[1].amount);
+        assert!(last.amount < schedule[1].amount);
+    }
+}
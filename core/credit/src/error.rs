@@ -22,6 +22,12 @@ pub enum CoreCreditError {
     PaymentAllocationError(#[from] super::payment_allocation::error::PaymentAllocationError),
     #[error("CoreCreditError - DisbursalError: {0}")]
     DisbursalError(#[from] super::disbursal::error::DisbursalError),
+    #[error("CoreCreditError - ExposureLimitError: {0}")]
+    ExposureLimitError(#[from] super::exposure_limit::error::ExposureLimitError),
+    #[error("CoreCreditError - ForbearanceError: {0}")]
+    ForbearanceError(#[from] super::forbearance::error::ForbearanceError),
+    #[error("CoreCreditError - PricingGridError: {0}")]
+    PricingGridError(#[from] super::pricing_grid::error::PricingGridError),
     #[error("CoreCreditError - ObligationError: {0}")]
     ObligationError(#[from] super::obligation::error::ObligationError),
     #[error("CoreCreditError - InterestAccrualCycleError: {0}")]
@@ -48,6 +54,8 @@ pub enum CoreCreditError {
     ChartIdMismatch,
     #[error("CoreCreditError - CreditConfigAlreadyExists")]
     CreditConfigAlreadyExists,
+    #[error("CoreCreditError - NothingToCollectFromDeposit")]
+    NothingToCollectFromDeposit,
 }
 
 es_entity::from_es_entity_error!(CoreCreditError);
@@ -0,0 +1,155 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::*;
+
+use super::value::ExposureLimitsConfig;
+
+pub(super) const DEFAULT_EXPOSURE_LIMIT_POLICY_REFERENCE: &str = "default";
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "ExposureLimitPolicyId")]
+pub enum ExposureLimitPolicyEvent {
+    Initialized {
+        id: ExposureLimitPolicyId,
+        reference: String,
+        limits: ExposureLimitsConfig,
+        audit_info: AuditInfo,
+    },
+    LimitsUpdated {
+        limits: ExposureLimitsConfig,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct ExposureLimitPolicy {
+    pub id: ExposureLimitPolicyId,
+    pub reference: String,
+    pub limits: ExposureLimitsConfig,
+    pub(super) events: EntityEvents<ExposureLimitPolicyEvent>,
+}
+
+impl TryFromEvents<ExposureLimitPolicyEvent> for ExposureLimitPolicy {
+    fn try_from_events(
+        events: EntityEvents<ExposureLimitPolicyEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = ExposureLimitPolicyBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                ExposureLimitPolicyEvent::Initialized {
+                    id,
+                    reference,
+                    limits,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .reference(reference.clone())
+                        .limits(limits.clone())
+                }
+                ExposureLimitPolicyEvent::LimitsUpdated { limits, .. } => {
+                    builder = builder.limits(limits.clone())
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+impl ExposureLimitPolicy {
+    pub(super) fn update_limits(
+        &mut self,
+        limits: ExposureLimitsConfig,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.limits == limits {
+            return Idempotent::Ignored;
+        }
+        self.limits = limits.clone();
+        self.events
+            .push(ExposureLimitPolicyEvent::LimitsUpdated { limits, audit_info });
+        Idempotent::Executed(())
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewExposureLimitPolicy {
+    #[builder(setter(into))]
+    pub(super) id: ExposureLimitPolicyId,
+    #[builder(setter(into))]
+    pub(super) reference: String,
+    pub(super) limits: ExposureLimitsConfig,
+    #[builder(setter(into))]
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewExposureLimitPolicy {
+    pub fn builder() -> NewExposureLimitPolicyBuilder {
+        NewExposureLimitPolicyBuilder::default()
+    }
+}
+
+impl IntoEvents<ExposureLimitPolicyEvent> for NewExposureLimitPolicy {
+    fn into_events(self) -> EntityEvents<ExposureLimitPolicyEvent> {
+        EntityEvents::init(
+            self.id,
+            [ExposureLimitPolicyEvent::Initialized {
+                id: self.id,
+                reference: self.reference,
+                limits: self.limits,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn policy() -> ExposureLimitPolicy {
+        let id = ExposureLimitPolicyId::new();
+        let events = EntityEvents::init(
+            id,
+            [ExposureLimitPolicyEvent::Initialized {
+                id,
+                reference: DEFAULT_EXPOSURE_LIMIT_POLICY_REFERENCE.to_string(),
+                limits: ExposureLimitsConfig::default(),
+                audit_info: dummy_audit_info(),
+            }],
+        );
+        ExposureLimitPolicy::try_from_events(events).unwrap()
+    }
+
+    #[test]
+    fn update_limits_records_change() {
+        let mut policy = policy();
+        let limits = ExposureLimitsConfig {
+            customer: Some(UsdCents::from(100_00)),
+            ..Default::default()
+        };
+
+        assert!(policy
+            .update_limits(limits.clone(), dummy_audit_info())
+            .did_execute());
+        assert_eq!(policy.limits, limits);
+        assert!(policy
+            .update_limits(limits, dummy_audit_info())
+            .was_ignored());
+    }
+}
@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+use crate::primitives::{CustomerType, UsdCents};
+
+#[derive(Error, Debug)]
+pub enum ExposureLimitError {
+    #[error("ExposureLimitError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("ExposureLimitError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("ExposureLimitError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error(
+        "ExposureLimitError - CustomerLimitExceeded: exposure {exposure} exceeds limit {limit}"
+    )]
+    CustomerLimitExceeded { limit: UsdCents, exposure: UsdCents },
+    #[error("ExposureLimitError - CustomerTypeLimitExceeded: exposure {exposure} exceeds limit {limit} for {customer_type}")]
+    CustomerTypeLimitExceeded {
+        customer_type: CustomerType,
+        limit: UsdCents,
+        exposure: UsdCents,
+    },
+    #[error(
+        "ExposureLimitError - PortfolioLimitExceeded: exposure {exposure} exceeds limit {limit}"
+    )]
+    PortfolioLimitExceeded { limit: UsdCents, exposure: UsdCents },
+}
+
+es_entity::from_es_entity_error!(ExposureLimitError);
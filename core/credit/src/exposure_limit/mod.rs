@@ -0,0 +1,331 @@
+mod entity;
+pub mod error;
+mod repo;
+mod value;
+
+use std::collections::HashSet;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_customer::{CoreCustomerAction, CoreCustomerEvent, Customer, CustomerObject, Customers};
+use es_entity::DbOp;
+use outbox::OutboxEventMarker;
+use tracing::instrument;
+
+use crate::{
+    credit_facility::*, error::CoreCreditError, event::CoreCreditEvent, ledger::CreditLedger,
+    primitives::*,
+};
+
+pub use entity::ExposureLimitPolicy;
+use entity::*;
+use repo::*;
+pub use value::*;
+
+pub struct ExposureLimits<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCustomerEvent>,
+{
+    authz: Perms,
+    repo: ExposureLimitPolicyRepo,
+    credit_facility_repo: CreditFacilityRepo<E>,
+    customer: Customers<Perms, E>,
+    ledger: CreditLedger,
+}
+
+impl<Perms, E> Clone for ExposureLimits<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCustomerEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            repo: self.repo.clone(),
+            credit_facility_repo: self.credit_facility_repo.clone(),
+            customer: self.customer.clone(),
+            ledger: self.ledger.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ExposureLimits<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<CoreCustomerAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<CustomerObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCustomerEvent>,
+{
+    /// The configured limits only seed the policy the first time it is created; from then
+    /// on they are changed through `update_limits`.
+    pub async fn init(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        credit_facility_repo: &CreditFacilityRepo<E>,
+        customer: &Customers<Perms, E>,
+        ledger: &CreditLedger,
+        config: ExposureLimitsConfig,
+    ) -> Result<Self, CoreCreditError> {
+        let repo = ExposureLimitPolicyRepo::new(pool);
+        match repo
+            .find_by_reference(DEFAULT_EXPOSURE_LIMIT_POLICY_REFERENCE.to_string())
+            .await
+        {
+            Ok(_) => (),
+            Err(e) if e.was_not_found() => {
+                let audit_info = authz
+                    .audit()
+                    .record_system_entry(
+                        CoreCreditObject::exposure_limit_policy(),
+                        CoreCreditAction::EXPOSURE_LIMIT_POLICY_UPDATE,
+                    )
+                    .await?;
+                let new_policy = NewExposureLimitPolicy::builder()
+                    .id(ExposureLimitPolicyId::new())
+                    .reference(DEFAULT_EXPOSURE_LIMIT_POLICY_REFERENCE)
+                    .limits(config)
+                    .audit_info(audit_info)
+                    .build()
+                    .expect("could not build new exposure limit policy");
+                if repo.create(new_policy).await.is_err() {
+                    // another instance seeded the policy concurrently
+                    repo.find_by_reference(DEFAULT_EXPOSURE_LIMIT_POLICY_REFERENCE.to_string())
+                        .await?;
+                }
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        Ok(Self {
+            authz: authz.clone(),
+            repo,
+            credit_facility_repo: credit_facility_repo.clone(),
+            customer: customer.clone(),
+            ledger: ledger.clone(),
+        })
+    }
+
+    #[instrument(name = "credit.exposure_limits.find_policy", skip(self), err)]
+    pub async fn find_policy(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<ExposureLimitPolicy, CoreCreditError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::exposure_limit_policy(),
+                CoreCreditAction::EXPOSURE_LIMIT_POLICY_READ,
+            )
+            .await?;
+        self.default_policy().await
+    }
+
+    #[instrument(name = "credit.exposure_limits.update_limits", skip(self), err)]
+    pub async fn update_limits(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        limits: ExposureLimitsConfig,
+    ) -> Result<ExposureLimitPolicy, CoreCreditError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::exposure_limit_policy(),
+                CoreCreditAction::EXPOSURE_LIMIT_POLICY_UPDATE,
+            )
+            .await?;
+
+        let id = self.default_policy().await?.id;
+        let mut db = self.repo.begin_op().await?;
+        self.repo.lock_for_update_in_op(&mut db, id).await?;
+        let mut policy = self.repo.find_by_id_in_tx(db.tx(), id).await?;
+        if policy.update_limits(limits, audit_info).did_execute() {
+            self.repo.update_in_op(&mut db, &mut policy).await?;
+        }
+        db.commit().await?;
+
+        Ok(policy)
+    }
+
+    /// Must run in the op that records the new exposure: the locks taken here are held
+    /// until it commits, so concurrent checks cannot both pass against the same headroom.
+    pub async fn check_in_op(
+        &self,
+        db: &mut DbOp<'_>,
+        customer_id: CustomerId,
+        customer_type: CustomerType,
+        additional_exposure: UsdCents,
+    ) -> Result<(), CoreCreditError> {
+        let id = self.default_policy().await?.id;
+        self.repo.lock_for_check_in_op(db, id).await?;
+        let limits = self.repo.find_by_id_in_tx(db.tx(), id).await?.limits;
+
+        if limits.requires_portfolio_exposure() {
+            self.repo.lock_portfolio_exposure_in_op(db, id).await?;
+        } else if limits.customer.is_some() {
+            self.repo
+                .lock_customer_exposure_in_op(db, customer_id)
+                .await?;
+        } else {
+            return Ok(());
+        }
+
+        for utilization in self
+            .utilizations(&limits, customer_id, customer_type)
+            .await?
+        {
+            utilization.check(additional_exposure)?;
+        }
+        Ok(())
+    }
+
+    pub async fn customer_utilizations(
+        &self,
+        customer_id: CustomerId,
+        customer_type: CustomerType,
+    ) -> Result<Vec<ExposureLimitUtilization>, CoreCreditError> {
+        let limits = self.default_policy().await?.limits;
+        self.utilizations(&limits, customer_id, customer_type).await
+    }
+
+    async fn utilizations(
+        &self,
+        limits: &ExposureLimitsConfig,
+        customer_id: CustomerId,
+        customer_type: CustomerType,
+    ) -> Result<Vec<ExposureLimitUtilization>, CoreCreditError> {
+        let mut res = Vec::new();
+        if limits.customer.is_some() {
+            let exposure = self.customer_exposure(customer_id).await?;
+            res.extend(limits.customer_utilization(exposure));
+        }
+        if limits.requires_portfolio_exposure() {
+            let portfolio = self.portfolio_exposure().await?;
+            res.extend(limits.portfolio_utilizations(&portfolio, Some(customer_type)));
+        }
+        Ok(res)
+    }
+
+    pub async fn portfolio_utilizations(
+        &self,
+    ) -> Result<Vec<ExposureLimitUtilization>, CoreCreditError> {
+        let limits = self.default_policy().await?.limits;
+        if !limits.requires_portfolio_exposure() {
+            return Ok(Vec::new());
+        }
+        let portfolio = self.portfolio_exposure().await?;
+        Ok(limits.portfolio_utilizations(&portfolio, None))
+    }
+
+    async fn default_policy(&self) -> Result<ExposureLimitPolicy, CoreCreditError> {
+        Ok(self
+            .repo
+            .find_by_reference(DEFAULT_EXPOSURE_LIMIT_POLICY_REFERENCE.to_string())
+            .await?)
+    }
+
+    async fn facility_exposure(
+        &self,
+        credit_facility: &CreditFacility,
+    ) -> Result<UsdCents, CoreCreditError> {
+        let balances = if credit_facility.activated_at().is_some() {
+            self.ledger
+                .get_credit_facility_balance(credit_facility.account_ids)
+                .await?
+        } else {
+            CreditFacilityBalanceSummary::default()
+        };
+        Ok(credit_facility.exposure(&balances))
+    }
+
+    async fn customer_exposure(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<UsdCents, CoreCreditError> {
+        let mut exposure = UsdCents::ZERO;
+        let mut has_next_page = true;
+        let mut after: Option<CreditFacilitiesByCreatedAtCursor> = None;
+        while has_next_page {
+            let credit_facilities = self
+                .credit_facility_repo
+                .list_for_customer_id_by_created_at(
+                    customer_id,
+                    es_entity::PaginatedQueryArgs::<CreditFacilitiesByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (
+                credit_facilities.end_cursor,
+                credit_facilities.has_next_page,
+            );
+            for credit_facility in credit_facilities.entities.iter() {
+                exposure += self.facility_exposure(credit_facility).await?;
+            }
+        }
+        Ok(exposure)
+    }
+
+    /// Activated facilities are aggregated by the ledger per customer type; only facilities
+    /// still pending activation are read from the repo.
+    async fn portfolio_exposure(&self) -> Result<PortfolioExposure, CoreCreditError> {
+        let mut portfolio = PortfolioExposure::default();
+        for (customer_type, exposure) in self.ledger.get_customer_type_exposures().await? {
+            portfolio.add(customer_type, exposure);
+        }
+
+        let mut pending_exposures = Vec::new();
+        for status in [
+            CreditFacilityStatus::PendingCollateralization,
+            CreditFacilityStatus::PendingApproval,
+        ] {
+            let mut has_next_page = true;
+            let mut after: Option<CreditFacilitiesByCreatedAtCursor> = None;
+            while has_next_page {
+                let credit_facilities = self
+                    .credit_facility_repo
+                    .list_for_status_by_created_at(
+                        status,
+                        es_entity::PaginatedQueryArgs::<CreditFacilitiesByCreatedAtCursor> {
+                            first: 100,
+                            after,
+                        },
+                        es_entity::ListDirection::Ascending,
+                    )
+                    .await?;
+                (after, has_next_page) = (
+                    credit_facilities.end_cursor,
+                    credit_facilities.has_next_page,
+                );
+                for credit_facility in credit_facilities.entities.iter() {
+                    let exposure =
+                        credit_facility.exposure(&CreditFacilityBalanceSummary::default());
+                    if !exposure.is_zero() {
+                        pending_exposures.push((credit_facility.customer_id, exposure));
+                    }
+                }
+            }
+        }
+
+        let customer_ids: Vec<_> = pending_exposures
+            .iter()
+            .map(|(id, _)| *id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let customers = self.customer.find_all::<Customer>(&customer_ids).await?;
+
+        for (customer_id, exposure) in pending_exposures {
+            let customer = customers
+                .get(&customer_id)
+                .ok_or(CoreCreditError::CustomerNotFound)?;
+            portfolio.add(customer.customer_type, exposure);
+        }
+        Ok(portfolio)
+    }
+}
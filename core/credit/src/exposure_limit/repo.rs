@@ -0,0 +1,85 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::*;
+
+use super::{entity::*, error::ExposureLimitError};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "ExposureLimitPolicy",
+    err = "ExposureLimitError",
+    columns(reference(ty = "String")),
+    tbl_prefix = "core"
+)]
+pub struct ExposureLimitPolicyRepo {
+    pool: PgPool,
+}
+
+impl ExposureLimitPolicyRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+
+    /// Checks hold a share lock on the policy row so the limits they read stay in force
+    /// until the new exposure is committed.
+    pub async fn lock_for_check_in_op(
+        &self,
+        db: &mut DbOp<'_>,
+        id: ExposureLimitPolicyId,
+    ) -> Result<(), ExposureLimitError> {
+        sqlx::query!(
+            "SELECT id FROM core_exposure_limit_policies WHERE id = $1 FOR SHARE",
+            id as ExposureLimitPolicyId
+        )
+        .fetch_one(&mut **db.tx())
+        .await?;
+        Ok(())
+    }
+
+    pub async fn lock_for_update_in_op(
+        &self,
+        db: &mut DbOp<'_>,
+        id: ExposureLimitPolicyId,
+    ) -> Result<(), ExposureLimitError> {
+        sqlx::query!(
+            "SELECT id FROM core_exposure_limit_policies WHERE id = $1 FOR UPDATE",
+            id as ExposureLimitPolicyId
+        )
+        .fetch_one(&mut **db.tx())
+        .await?;
+        Ok(())
+    }
+
+    /// Portfolio and customer type limits are shared by every customer, so their checks
+    /// are serialized on the policy.
+    pub async fn lock_portfolio_exposure_in_op(
+        &self,
+        db: &mut DbOp<'_>,
+        id: ExposureLimitPolicyId,
+    ) -> Result<(), ExposureLimitError> {
+        sqlx::query!(
+            "SELECT pg_advisory_xact_lock(hashtextextended($1::uuid::text, 0))",
+            id as ExposureLimitPolicyId
+        )
+        .fetch_one(&mut **db.tx())
+        .await?;
+        Ok(())
+    }
+
+    /// A customer limit only needs checks for the same customer to be serialized.
+    pub async fn lock_customer_exposure_in_op(
+        &self,
+        db: &mut DbOp<'_>,
+        customer_id: CustomerId,
+    ) -> Result<(), ExposureLimitError> {
+        sqlx::query!(
+            "SELECT pg_advisory_xact_lock(hashtextextended($1::uuid::text, 0))",
+            customer_id as CustomerId
+        )
+        .fetch_one(&mut **db.tx())
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::{CustomerType, UsdCents};
+
+use super::error::ExposureLimitError;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ExposureLimitsConfig {
+    #[serde(default)]
+    pub customer: Option<UsdCents>,
+    #[serde(default)]
+    pub customer_types: Vec<CustomerTypeExposureLimit>,
+    #[serde(default)]
+    pub portfolio: Option<UsdCents>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CustomerTypeExposureLimit {
+    pub customer_type: CustomerType,
+    pub limit: UsdCents,
+}
+
+impl ExposureLimitsConfig {
+    pub(crate) fn requires_portfolio_exposure(&self) -> bool {
+        self.portfolio.is_some() || !self.customer_types.is_empty()
+    }
+
+    pub(crate) fn customer_utilization(
+        &self,
+        exposure: UsdCents,
+    ) -> Option<ExposureLimitUtilization> {
+        self.customer.map(|limit| ExposureLimitUtilization {
+            scope: ExposureLimitScope::Customer,
+            customer_type: None,
+            limit,
+            exposure,
+        })
+    }
+
+    pub(crate) fn portfolio_utilizations(
+        &self,
+        portfolio: &PortfolioExposure,
+        customer_type: Option<CustomerType>,
+    ) -> Vec<ExposureLimitUtilization> {
+        let mut res: Vec<_> = self
+            .customer_types
+            .iter()
+            .filter(|l| customer_type.is_none_or(|customer_type| l.customer_type == customer_type))
+            .map(|l| ExposureLimitUtilization {
+                scope: ExposureLimitScope::CustomerType,
+                customer_type: Some(l.customer_type),
+                limit: l.limit,
+                exposure: portfolio.for_customer_type(l.customer_type),
+            })
+            .collect();
+        if let Some(limit) = self.portfolio {
+            res.push(ExposureLimitUtilization {
+                scope: ExposureLimitScope::Portfolio,
+                customer_type: None,
+                limit,
+                exposure: portfolio.total,
+            });
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum ExposureLimitScope {
+    Customer,
+    CustomerType,
+    Portfolio,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExposureLimitUtilization {
+    pub scope: ExposureLimitScope,
+    pub customer_type: Option<CustomerType>,
+    pub limit: UsdCents,
+    pub exposure: UsdCents,
+}
+
+impl ExposureLimitUtilization {
+    pub fn remaining(&self) -> UsdCents {
+        if self.exposure >= self.limit {
+            UsdCents::ZERO
+        } else {
+            self.limit - self.exposure
+        }
+    }
+
+    pub(crate) fn check(&self, additional: UsdCents) -> Result<(), ExposureLimitError> {
+        let exposure = self.exposure + additional;
+        if exposure <= self.limit {
+            return Ok(());
+        }
+
+        let limit = self.limit;
+        Err(match self.scope {
+            ExposureLimitScope::Customer => {
+                ExposureLimitError::CustomerLimitExceeded { limit, exposure }
+            }
+            ExposureLimitScope::CustomerType => ExposureLimitError::CustomerTypeLimitExceeded {
+                customer_type: self
+                    .customer_type
+                    .expect("customer type missing for customer type limit"),
+                limit,
+                exposure,
+            },
+            ExposureLimitScope::Portfolio => {
+                ExposureLimitError::PortfolioLimitExceeded { limit, exposure }
+            }
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct PortfolioExposure {
+    customer_types: Vec<(CustomerType, UsdCents)>,
+    total: UsdCents,
+}
+
+impl PortfolioExposure {
+    pub(crate) fn add(&mut self, customer_type: CustomerType, exposure: UsdCents) {
+        match self
+            .customer_types
+            .iter_mut()
+            .find(|(t, _)| *t == customer_type)
+        {
+            Some((_, total)) => *total += exposure,
+            None => self.customer_types.push((customer_type, exposure)),
+        }
+        self.total += exposure;
+    }
+
+    fn for_customer_type(&self, customer_type: CustomerType) -> UsdCents {
+        self.customer_types
+            .iter()
+            .find(|(t, _)| *t == customer_type)
+            .map(|(_, exposure)| *exposure)
+            .unwrap_or(UsdCents::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn config() -> ExposureLimitsConfig {
+        ExposureLimitsConfig {
+            customer: Some(UsdCents::from(100_00)),
+            customer_types: vec![
+                CustomerTypeExposureLimit {
+                    customer_type: CustomerType::Individual,
+                    limit: UsdCents::from(500_00),
+                },
+                CustomerTypeExposureLimit {
+                    customer_type: CustomerType::Bank,
+                    limit: UsdCents::from(2_000_00),
+                },
+            ],
+            portfolio: Some(UsdCents::from(10_000_00)),
+        }
+    }
+
+    fn portfolio() -> PortfolioExposure {
+        let mut portfolio = PortfolioExposure::default();
+        portfolio.add(CustomerType::Individual, UsdCents::from(300_00));
+        portfolio.add(CustomerType::Bank, UsdCents::from(1_000_00));
+        portfolio.add(CustomerType::Individual, UsdCents::from(150_00));
+        portfolio
+    }
+
+    #[test]
+    fn portfolio_utilizations_for_customer_type() {
+        let utilizations =
+            config().portfolio_utilizations(&portfolio(), Some(CustomerType::Individual));
+
+        assert_eq!(utilizations.len(), 2);
+        assert_eq!(utilizations[0].scope, ExposureLimitScope::CustomerType);
+        assert_eq!(utilizations[0].exposure, UsdCents::from(450_00));
+        assert_eq!(utilizations[0].remaining(), UsdCents::from(50_00));
+        assert_eq!(utilizations[1].scope, ExposureLimitScope::Portfolio);
+        assert_eq!(utilizations[1].exposure, UsdCents::from(1_450_00));
+    }
+
+    #[test]
+    fn portfolio_utilizations_without_customer_type() {
+        let utilizations = config().portfolio_utilizations(&portfolio(), None);
+
+        assert_eq!(utilizations.len(), 3);
+        assert_eq!(utilizations[1].customer_type, Some(CustomerType::Bank));
+        assert_eq!(utilizations[1].exposure, UsdCents::from(1_000_00));
+    }
+
+    #[test]
+    fn check_allows_up_to_limit() {
+        let utilization = config()
+            .customer_utilization(UsdCents::from(60_00))
+            .unwrap();
+
+        assert!(utilization.check(UsdCents::from(40_00)).is_ok());
+        assert!(matches!(
+            utilization.check(UsdCents::from(40_01)),
+            Err(ExposureLimitError::CustomerLimitExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn check_reports_customer_type() {
+        let utilizations =
+            config().portfolio_utilizations(&portfolio(), Some(CustomerType::Individual));
+
+        assert!(matches!(
+            utilizations[0].check(UsdCents::from(50_01)),
+            Err(ExposureLimitError::CustomerTypeLimitExceeded {
+                customer_type: CustomerType::Individual,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn no_limits_configured() {
+        let config = ExposureLimitsConfig::default();
+
+        assert!(!config.requires_portfolio_exposure());
+        assert!(config.customer_utilization(UsdCents::from(1)).is_none());
+    }
+}
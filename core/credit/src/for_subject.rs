@@ -15,6 +15,7 @@ where
     credit_facilities: &'a CreditFacilityRepo<E>,
     disbursals: &'a DisbursalRepo,
     payments: &'a PaymentRepo,
+    pricing_grids: &'a PricingGridRepo,
     ledger: &'a CreditLedger,
 }
 
@@ -33,6 +34,7 @@ where
         credit_facilities: &'a CreditFacilityRepo<E>,
         disbursals: &'a DisbursalRepo,
         payments: &'a PaymentRepo,
+        pricing_grids: &'a PricingGridRepo,
         ledger: &'a CreditLedger,
     ) -> Self {
         Self {
@@ -42,6 +44,7 @@ where
             credit_facilities,
             disbursals,
             payments,
+            pricing_grids,
             ledger,
         }
     }
@@ -90,6 +93,26 @@ where
         Ok(balances)
     }
 
+    pub async fn pricing_grid(
+        &self,
+        id: impl Into<CreditFacilityId> + std::fmt::Debug,
+    ) -> Result<Option<PricingGrid>, CoreCreditError> {
+        let id = id.into();
+        let credit_facility = self.credit_facilities.find_by_id(id).await?;
+
+        self.ensure_credit_facility_access(
+            &credit_facility,
+            CoreCreditObject::credit_facility(id),
+            CoreCreditAction::CREDIT_FACILITY_READ,
+        )
+        .await?;
+
+        Ok(self
+            .pricing_grids
+            .find_for_terms(&credit_facility.terms)
+            .await?)
+    }
+
     pub async fn find_by_id(
         &self,
         id: impl Into<CreditFacilityId>,
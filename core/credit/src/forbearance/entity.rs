@@ -0,0 +1,204 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForbearancePeriod {
+    pub forbearance_id: ForbearanceId,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub capitalize_interest: bool,
+}
+
+impl ForbearancePeriod {
+    pub fn duration(&self) -> chrono::Duration {
+        self.ends_at - self.starts_at
+    }
+
+    pub fn overlaps(&self, starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> bool {
+        self.starts_at < ends_at && starts_at < self.ends_at
+    }
+
+    pub fn covers(&self, date: DateTime<Utc>) -> bool {
+        self.starts_at <= date && date < self.ends_at
+    }
+
+    pub fn defer(&self, date: DateTime<Utc>) -> DateTime<Utc> {
+        if date < self.starts_at {
+            date
+        } else {
+            date + self.duration()
+        }
+    }
+
+    /// Whether interest for a cycle ending at `date` is added to principal instead of
+    /// being billed.
+    pub fn capitalizes_interest_at(&self, date: DateTime<Utc>) -> bool {
+        self.capitalize_interest && self.covers(date)
+    }
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "ForbearanceId")]
+pub enum ForbearanceEvent {
+    Initialized {
+        id: ForbearanceId,
+        approval_process_id: ApprovalProcessId,
+        credit_facility_id: CreditFacilityId,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        capitalize_interest: bool,
+        audit_info: AuditInfo,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Forbearance {
+    pub id: ForbearanceId,
+    pub approval_process_id: ApprovalProcessId,
+    pub credit_facility_id: CreditFacilityId,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub capitalize_interest: bool,
+    pub(super) events: EntityEvents<ForbearanceEvent>,
+}
+
+impl TryFromEvents<ForbearanceEvent> for Forbearance {
+    fn try_from_events(events: EntityEvents<ForbearanceEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = ForbearanceBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                ForbearanceEvent::Initialized {
+                    id,
+                    approval_process_id,
+                    credit_facility_id,
+                    starts_at,
+                    ends_at,
+                    capitalize_interest,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .approval_process_id(*approval_process_id)
+                        .credit_facility_id(*credit_facility_id)
+                        .starts_at(*starts_at)
+                        .ends_at(*ends_at)
+                        .capitalize_interest(*capitalize_interest)
+                }
+                ForbearanceEvent::ApprovalProcessConcluded { .. } => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+impl Forbearance {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("entity_first_persisted_at not found")
+    }
+
+    pub fn period(&self) -> ForbearancePeriod {
+        ForbearancePeriod {
+            forbearance_id: self.id,
+            starts_at: self.starts_at,
+            ends_at: self.ends_at,
+            capitalize_interest: self.capitalize_interest,
+        }
+    }
+
+    pub fn is_approval_process_concluded(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, ForbearanceEvent::ApprovalProcessConcluded { .. }))
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.is_approved().is_none()
+    }
+
+    pub fn status(&self) -> ForbearanceStatus {
+        match self.is_approved() {
+            Some(true) => ForbearanceStatus::Approved,
+            Some(false) => ForbearanceStatus::Denied,
+            None => ForbearanceStatus::New,
+        }
+    }
+
+    pub(crate) fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<Option<ForbearancePeriod>> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ForbearanceEvent::ApprovalProcessConcluded { .. }
+        );
+        self.events
+            .push(ForbearanceEvent::ApprovalProcessConcluded {
+                approval_process_id: self.approval_process_id,
+                approved,
+                audit_info,
+            });
+
+        Idempotent::Executed(approved.then(|| self.period()))
+    }
+
+    fn is_approved(&self) -> Option<bool> {
+        self.events.iter_all().find_map(|event| match event {
+            ForbearanceEvent::ApprovalProcessConcluded { approved, .. } => Some(*approved),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewForbearance {
+    #[builder(setter(into))]
+    pub(super) id: ForbearanceId,
+    #[builder(setter(into))]
+    pub(crate) approval_process_id: ApprovalProcessId,
+    #[builder(setter(into))]
+    pub(super) credit_facility_id: CreditFacilityId,
+    pub(super) starts_at: DateTime<Utc>,
+    pub(super) ends_at: DateTime<Utc>,
+    pub(super) capitalize_interest: bool,
+    #[builder(setter(into))]
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewForbearance {
+    pub fn builder() -> NewForbearanceBuilder {
+        NewForbearanceBuilder::default()
+    }
+}
+
+impl IntoEvents<ForbearanceEvent> for NewForbearance {
+    fn into_events(self) -> EntityEvents<ForbearanceEvent> {
+        EntityEvents::init(
+            self.id,
+            [ForbearanceEvent::Initialized {
+                id: self.id,
+                approval_process_id: self.approval_process_id,
+                credit_facility_id: self.credit_facility_id,
+                starts_at: self.starts_at,
+                ends_at: self.ends_at,
+                capitalize_interest: self.capitalize_interest,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ForbearanceError {
+    #[error("ForbearanceError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("ForbearanceError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("ForbearanceError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("ForbearanceError - InvalidPeriod: starts at '{0}' is not before ends at '{1}'")]
+    InvalidPeriod(chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>),
+}
+
+es_entity::from_es_entity_error!(ForbearanceError);
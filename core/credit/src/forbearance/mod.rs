@@ -0,0 +1,8 @@
+mod entity;
+pub mod error;
+mod repo;
+
+pub(super) use entity::*;
+pub(super) use repo::*;
+
+pub use entity::{Forbearance, ForbearancePeriod};
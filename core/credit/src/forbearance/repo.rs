@@ -0,0 +1,27 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::*;
+
+use super::{entity::*, error::ForbearanceError};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "Forbearance",
+    err = "ForbearanceError",
+    columns(
+        credit_facility_id(ty = "CreditFacilityId", list_for, update(persist = false)),
+        approval_process_id(ty = "ApprovalProcessId", list_by, update(persist = "false")),
+    ),
+    tbl_prefix = "core"
+)]
+pub struct ForbearanceRepo {
+    pool: PgPool,
+}
+
+impl ForbearanceRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
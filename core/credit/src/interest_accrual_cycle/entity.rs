@@ -19,6 +19,9 @@ pub struct InterestAccrualCycleAccountIds {
     pub interest_receivable_overdue_account_id: CalaAccountId,
     pub interest_defaulted_account_id: CalaAccountId,
     pub interest_income_account_id: CalaAccountId,
+    pub disbursed_receivable_not_yet_due_account_id: CalaAccountId,
+    pub disbursed_receivable_due_account_id: CalaAccountId,
+    pub disbursed_receivable_overdue_account_id: CalaAccountId,
 }
 
 impl From<CreditFacilityAccountIds> for InterestAccrualCycleAccountIds {
@@ -33,6 +36,12 @@ impl From<CreditFacilityAccountIds> for InterestAccrualCycleAccountIds {
             interest_defaulted_account_id: credit_facility_account_ids
                 .interest_defaulted_account_id,
             interest_income_account_id: credit_facility_account_ids.interest_income_account_id,
+            disbursed_receivable_not_yet_due_account_id: credit_facility_account_ids
+                .disbursed_receivable_not_yet_due_account_id,
+            disbursed_receivable_due_account_id: credit_facility_account_ids
+                .disbursed_receivable_due_account_id,
+            disbursed_receivable_overdue_account_id: credit_facility_account_ids
+                .disbursed_receivable_overdue_account_id,
         }
     }
 }
@@ -262,6 +271,7 @@ impl InterestAccrualCycle {
             posted_at,
             ..
         }: InterestAccrualCycleData,
+        capitalize: bool,
         audit_info: AuditInfo,
     ) -> Idempotent<NewObligation> {
         idempotency_guard!(
@@ -269,6 +279,32 @@ impl InterestAccrualCycle {
             InterestAccrualCycleEvent::InterestAccrualsPosted { .. }
         );
         let obligation_id = ObligationId::new();
+        let (obligation_type, receivable_accounts) = if capitalize {
+            (
+                ObligationType::Disbursal,
+                [
+                    self.account_ids.disbursed_receivable_not_yet_due_account_id,
+                    self.account_ids.disbursed_receivable_due_account_id,
+                    self.account_ids.disbursed_receivable_overdue_account_id,
+                ],
+            )
+        } else {
+            (
+                ObligationType::Interest,
+                [
+                    self.account_ids.interest_receivable_not_yet_due_account_id,
+                    self.account_ids.interest_receivable_due_account_id,
+                    self.account_ids.interest_receivable_overdue_account_id,
+                ],
+            )
+        };
+        let [not_yet_due_account_id, due_account_id, overdue_account_id] = receivable_accounts;
+        // Capitalized interest becomes principal and is repaid with it at maturity.
+        let due_date = if capitalize {
+            self.facility_matures_at
+        } else {
+            self.accrual_cycle_ends_at()
+        };
         self.events
             .push(InterestAccrualCycleEvent::InterestAccrualsPosted {
                 tx_id,
@@ -283,28 +319,24 @@ impl InterestAccrualCycle {
             NewObligation::builder()
                 .id(obligation_id)
                 .credit_facility_id(self.credit_facility_id)
-                .obligation_type(ObligationType::Interest)
+                .obligation_type(obligation_type)
                 .reference(tx_ref.to_string())
                 .amount(interest)
                 .tx_id(tx_id)
                 .not_yet_due_accounts(ObligationAccounts {
-                    account_to_be_debited_id: self
-                        .account_ids
-                        .interest_receivable_not_yet_due_account_id,
+                    account_to_be_debited_id: not_yet_due_account_id,
                     account_to_be_credited_id: self.account_ids.interest_income_account_id,
                 })
                 .due_accounts(ObligationAccounts {
-                    account_to_be_debited_id: self.account_ids.interest_receivable_due_account_id,
+                    account_to_be_debited_id: due_account_id,
                     account_to_be_credited_id: self.account_ids.interest_income_account_id,
                 })
                 .overdue_accounts(ObligationAccounts {
-                    account_to_be_debited_id: self
-                        .account_ids
-                        .interest_receivable_overdue_account_id,
+                    account_to_be_debited_id: overdue_account_id,
                     account_to_be_credited_id: self.account_ids.interest_income_account_id,
                 })
-                .due_date(self.accrual_cycle_ends_at())
-                .overdue_date(self.accrual_cycle_ends_at())
+                .due_date(due_date)
+                .overdue_date(due_date)
                 .recorded_at(posted_at)
                 .audit_info(audit_info)
                 .build()
@@ -620,4 +652,48 @@ mod test {
             _ => panic!("Expected accrual to be returned"),
         }
     }
+
+    #[test]
+    fn capitalized_interest_is_recorded_as_principal_obligation() {
+        let terms = TermValues {
+            interest_capitalization: crate::terms::InterestCapitalization::Capitalized,
+            ..default_terms()
+        };
+        let account_ids = CreditFacilityAccountIds::new();
+        let started_at = default_started_at();
+        let mut accrual = accrual_from(vec![InterestAccrualCycleEvent::Initialized {
+            id: InterestAccrualCycleId::new(),
+            facility_id: CreditFacilityId::new(),
+            account_ids: account_ids.into(),
+            idx: InterestAccrualCycleIdx::FIRST,
+            started_at,
+            facility_matures_at: terms.duration.maturity_date(started_at),
+            terms,
+            audit_info: dummy_audit_info(),
+        }]);
+
+        let mut accrual_cycle_data = None;
+        while accrual_cycle_data.is_none() {
+            accrual.record_accrual(UsdCents::from(1_000_000_00), dummy_audit_info());
+            accrual_cycle_data = accrual.accrual_cycle_data();
+        }
+
+        let new_obligation = accrual
+            .record_accrual_cycle(accrual_cycle_data.unwrap(), true, dummy_audit_info())
+            .unwrap();
+        let obligation =
+            crate::obligation::Obligation::try_from_events(new_obligation.into_events()).unwrap();
+        assert_eq!(obligation.obligation_type(), ObligationType::Disbursal);
+        assert_eq!(obligation.due_at(), accrual.facility_matures_at);
+        assert_eq!(obligation.overdue_at(), accrual.facility_matures_at);
+        assert!(obligation.due_at() > accrual.accrual_cycle_ends_at());
+        assert_eq!(
+            obligation.not_yet_due_accounts().account_to_be_debited_id,
+            account_ids.disbursed_receivable_not_yet_due_account_id
+        );
+        assert_eq!(
+            obligation.not_yet_due_accounts().account_to_be_credited_id,
+            account_ids.interest_income_account_id
+        );
+    }
 }
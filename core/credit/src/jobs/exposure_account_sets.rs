@@ -0,0 +1,202 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_customer::{CoreCustomerAction, CoreCustomerEvent, Customer, CustomerObject, Customers};
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    credit_facility::*, error::CoreCreditError, event::CoreCreditEvent, ledger::*, primitives::*,
+};
+
+/// Adds the accounts of facilities created before `created_before` to the exposure
+/// account sets. Facilities created afterwards join the sets when their accounts are
+/// created.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ExposureAccountSetsJobConfig<Perms, E> {
+    pub created_before: DateTime<Utc>,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+
+impl<Perms, E> JobConfig for ExposureAccountSetsJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCustomerAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CustomerObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCustomerEvent>,
+{
+    type Initializer = ExposureAccountSetsJobInitializer<Perms, E>;
+}
+
+#[derive(Default, Clone, Deserialize, Serialize)]
+struct ExposureAccountSetsJobData {
+    after: Option<CreditFacilitiesByCreatedAtCursor>,
+}
+
+pub struct ExposureAccountSetsJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCustomerEvent>,
+{
+    ledger: CreditLedger,
+    credit_facility_repo: CreditFacilityRepo<E>,
+    customer: Customers<Perms, E>,
+}
+
+impl<Perms, E> ExposureAccountSetsJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCustomerAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CustomerObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCustomerEvent>,
+{
+    pub fn new(
+        ledger: &CreditLedger,
+        credit_facility_repo: &CreditFacilityRepo<E>,
+        customer: &Customers<Perms, E>,
+    ) -> Self {
+        Self {
+            ledger: ledger.clone(),
+            credit_facility_repo: credit_facility_repo.clone(),
+            customer: customer.clone(),
+        }
+    }
+}
+
+const CREDIT_FACILITY_EXPOSURE_ACCOUNT_SETS_JOB: JobType =
+    JobType::new("credit-facility-exposure-account-sets");
+impl<Perms, E> JobInitializer for ExposureAccountSetsJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCustomerAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CustomerObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCustomerEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        CREDIT_FACILITY_EXPOSURE_ACCOUNT_SETS_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ExposureAccountSetsJobRunner::<Perms, E> {
+            config: job.config()?,
+            credit_facility_repo: self.credit_facility_repo.clone(),
+            customer: self.customer.clone(),
+            ledger: self.ledger.clone(),
+        }))
+    }
+}
+
+pub struct ExposureAccountSetsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCustomerEvent>,
+{
+    config: ExposureAccountSetsJobConfig<Perms, E>,
+    credit_facility_repo: CreditFacilityRepo<E>,
+    customer: Customers<Perms, E>,
+    ledger: CreditLedger,
+}
+
+impl<Perms, E> ExposureAccountSetsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCustomerAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CustomerObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCustomerEvent>,
+{
+    async fn exposure_account_set_members(
+        &self,
+        credit_facilities: &[CreditFacility],
+    ) -> Result<Vec<(CreditFacilityAccountIds, CustomerType)>, CoreCreditError> {
+        let credit_facilities: Vec<_> = credit_facilities
+            .iter()
+            .filter(|credit_facility| {
+                credit_facility.created_at() < self.config.created_before
+                    && credit_facility.status() != CreditFacilityStatus::Closed
+            })
+            .collect();
+        let customer_ids: Vec<_> = credit_facilities
+            .iter()
+            .map(|credit_facility| credit_facility.customer_id)
+            .collect();
+        let customers = self.customer.find_all::<Customer>(&customer_ids).await?;
+
+        credit_facilities
+            .into_iter()
+            .map(|credit_facility| {
+                let customer = customers
+                    .get(&credit_facility.customer_id)
+                    .ok_or(CoreCreditError::CustomerNotFound)?;
+                Ok((credit_facility.account_ids, customer.customer_type))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for ExposureAccountSetsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCustomerAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CustomerObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<CoreCustomerEvent>,
+{
+    #[instrument(
+        name = "credit-facility.exposure-account-sets.job",
+        skip(self, current_job),
+        fields(attempt)
+    )]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let span = tracing::Span::current();
+        span.record("attempt", current_job.attempt());
+
+        let mut state = current_job
+            .execution_state::<ExposureAccountSetsJobData>()?
+            .unwrap_or_default();
+
+        loop {
+            let credit_facilities = self
+                .credit_facility_repo
+                .list_by_created_at(
+                    es_entity::PaginatedQueryArgs::<CreditFacilitiesByCreatedAtCursor> {
+                        first: 100,
+                        after: state.after.clone(),
+                    },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            let members = self
+                .exposure_account_set_members(&credit_facilities.entities)
+                .await?;
+            let done = !credit_facilities.has_next_page
+                || credit_facilities.entities.iter().any(|credit_facility| {
+                    credit_facility.created_at() >= self.config.created_before
+                });
+
+            if credit_facilities.end_cursor.is_some() {
+                state.after = credit_facilities.end_cursor;
+            }
+            let mut db = self.credit_facility_repo.begin_op().await?;
+            current_job
+                .update_execution_state_in_tx(db.tx(), &state)
+                .await?;
+            self.ledger
+                .add_credit_facilities_to_exposure_account_sets(db, members)
+                .await?;
+
+            if done {
+                return Ok(JobCompletion::Complete);
+            }
+        }
+    }
+}
@@ -5,6 +5,7 @@ use tracing::instrument;
 
 use audit::{AuditInfo, AuditSvc};
 use authz::PermissionCheck;
+use core_price::Price;
 use job::*;
 use outbox::OutboxEventMarker;
 
@@ -13,8 +14,9 @@ use crate::{
     interest_accruals,
     ledger::*,
     obligation::{Obligation, Obligations},
+    pricing_grid::PricingGridRepo,
     CoreCreditAction, CoreCreditError, CoreCreditEvent, CoreCreditObject, CreditFacilityId,
-    InterestAccrualCycleId,
+    InterestAccrualCycleId, ObligationType,
 };
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -40,6 +42,8 @@ where
     ledger: CreditLedger,
     obligations: Obligations<Perms, E>,
     credit_facility_repo: CreditFacilityRepo<E>,
+    pricing_grid_repo: PricingGridRepo,
+    price: Price,
     jobs: Jobs,
     audit: Perms::Audit,
 }
@@ -55,6 +59,8 @@ where
         ledger: &CreditLedger,
         obligations: &Obligations<Perms, E>,
         credit_facility_repo: &CreditFacilityRepo<E>,
+        pricing_grid_repo: &PricingGridRepo,
+        price: &Price,
         jobs: &Jobs,
         audit: &Perms::Audit,
     ) -> Self {
@@ -62,6 +68,8 @@ where
             ledger: ledger.clone(),
             obligations: obligations.clone(),
             credit_facility_repo: credit_facility_repo.clone(),
+            pricing_grid_repo: pricing_grid_repo.clone(),
+            price: price.clone(),
             jobs: jobs.clone(),
             audit: audit.clone(),
         }
@@ -89,7 +97,9 @@ where
             config: job.config()?,
             obligations: self.obligations.clone(),
             credit_facility_repo: self.credit_facility_repo.clone(),
+            pricing_grid_repo: self.pricing_grid_repo.clone(),
             ledger: self.ledger.clone(),
+            price: self.price.clone(),
             jobs: self.jobs.clone(),
             audit: self.audit.clone(),
         }))
@@ -104,7 +114,9 @@ where
     config: CreditFacilityJobConfig<Perms, E>,
     obligations: Obligations<Perms, E>,
     credit_facility_repo: CreditFacilityRepo<E>,
+    pricing_grid_repo: PricingGridRepo,
     ledger: CreditLedger,
+    price: Price,
     jobs: Jobs,
     audit: Perms::Audit,
 }
@@ -121,8 +133,14 @@ where
         &self,
         db: &mut es_entity::DbOp<'_>,
         audit_info: &AuditInfo,
-    ) -> Result<(Obligation, Option<(InterestAccrualCycleId, DateTime<Utc>)>), CoreCreditError>
-    {
+    ) -> Result<
+        (
+            Obligation,
+            CreditFacilityAccountIds,
+            Option<(InterestAccrualCycleId, DateTime<Utc>)>,
+        ),
+        CoreCreditError,
+    > {
         let mut credit_facility = self
             .credit_facility_repo
             .find_by_id(self.config.credit_facility_id)
@@ -141,7 +159,21 @@ where
             .create_with_jobs_in_op(db, new_obligation)
             .await?;
 
-        let res = credit_facility.start_interest_accrual_cycle(audit_info.clone())?;
+        let balances = self
+            .ledger
+            .get_credit_facility_balance(credit_facility.account_ids)
+            .await?;
+        let price = self.price.usd_cents_per_btc().await?;
+        let pricing_grid = self
+            .pricing_grid_repo
+            .find_for_terms(&credit_facility.terms)
+            .await?;
+        let res = credit_facility.start_interest_accrual_cycle(
+            price,
+            balances,
+            pricing_grid.as_ref(),
+            audit_info.clone(),
+        )?;
         self.credit_facility_repo
             .update_in_op(db, &mut credit_facility)
             .await?;
@@ -156,7 +188,7 @@ where
             (new_accrual_cycle_id, periods.accrual.end)
         });
 
-        Ok((obligation, new_cycle_data))
+        Ok((obligation, credit_facility.account_ids, new_cycle_data))
     }
 }
 
@@ -199,7 +231,7 @@ where
             )
             .await?;
 
-        let (obligation, new_cycle_data) = self
+        let (obligation, account_ids, new_cycle_data) = self
             .complete_interest_cycle_and_maybe_start_new_cycle(&mut db, &audit_info)
             .await?;
 
@@ -222,9 +254,18 @@ where
             );
         };
 
-        self.ledger
-            .record_interest_accrual_cycle(db, obligation)
-            .await?;
+        match obligation.obligation_type() {
+            ObligationType::Interest => {
+                self.ledger
+                    .record_interest_accrual_cycle(db, obligation)
+                    .await?
+            }
+            ObligationType::Disbursal => {
+                self.ledger
+                    .capitalize_interest_accrual_cycle(db, obligation, account_ids)
+                    .await?
+            }
+        }
 
         return Ok(JobCompletion::Complete);
     }
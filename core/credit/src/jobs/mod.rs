@@ -1,5 +1,8 @@
 pub mod cvl;
+pub mod exposure_account_sets;
 pub mod interest_accrual_cycles;
 pub mod interest_accruals;
+pub mod obligation_auto_debit;
 pub mod obligation_due;
 pub mod obligation_overdue;
+pub mod structuring_fee_amortization;
@@ -0,0 +1,248 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use audit::{AuditInfo, AuditSvc};
+use authz::PermissionCheck;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    credit_facility::CreditFacilityRepo,
+    event::CoreCreditEvent,
+    ledger::CreditLedger,
+    obligation::{ObligationStatus, Obligations},
+    payment::Payments,
+    primitives::*,
+};
+
+const INSUFFICIENT_AVAILABLE_BALANCE: &str = "insufficient available balance";
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CreditFacilityJobConfig<Perms, E> {
+    pub obligation_id: ObligationId,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for CreditFacilityJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    type Initializer = CreditFacilityProcessingJobInitializer<Perms, E>;
+}
+pub struct CreditFacilityProcessingJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    obligations: Obligations<Perms, E>,
+    credit_facility_repo: CreditFacilityRepo<E>,
+    payments: Payments<Perms, E>,
+    ledger: CreditLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> CreditFacilityProcessingJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    pub fn new(
+        ledger: &CreditLedger,
+        obligations: &Obligations<Perms, E>,
+        credit_facility_repo: &CreditFacilityRepo<E>,
+        payments: &Payments<Perms, E>,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            ledger: ledger.clone(),
+            obligations: obligations.clone(),
+            credit_facility_repo: credit_facility_repo.clone(),
+            payments: payments.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const CREDIT_FACILITY_OBLIGATION_AUTO_DEBIT_JOB: JobType =
+    JobType::new("credit-facility-obligation-auto-debit");
+impl<Perms, E> JobInitializer for CreditFacilityProcessingJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        CREDIT_FACILITY_OBLIGATION_AUTO_DEBIT_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(CreditFacilityProcessingJobRunner::<Perms, E> {
+            config: job.config()?,
+            obligations: self.obligations.clone(),
+            credit_facility_repo: self.credit_facility_repo.clone(),
+            payments: self.payments.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct CreditFacilityProcessingJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    config: CreditFacilityJobConfig<Perms, E>,
+    obligations: Obligations<Perms, E>,
+    credit_facility_repo: CreditFacilityRepo<E>,
+    payments: Payments<Perms, E>,
+    ledger: CreditLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> CreditFacilityProcessingJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    async fn record_failure(
+        &self,
+        credit_facility_id: CreditFacilityId,
+        requested: UsdCents,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut credit_facility = self
+            .credit_facility_repo
+            .find_by_id(credit_facility_id)
+            .await?;
+        if credit_facility
+            .record_auto_debit_failed(self.config.obligation_id, requested, reason, audit_info)
+            .did_execute()
+        {
+            let mut db = self.credit_facility_repo.begin_op().await?;
+            self.credit_facility_repo
+                .update_in_op(&mut db, &mut credit_facility)
+                .await?;
+            db.commit().await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for CreditFacilityProcessingJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    #[instrument(
+        name = "credit-facility.obligation-auto-debit.job",
+        skip(self, _current_job)
+    )]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let obligation = self
+            .obligations
+            .find_by_id(self.config.obligation_id)
+            .await?;
+        if obligation.status() == ObligationStatus::Paid {
+            return Ok(JobCompletion::Complete);
+        }
+
+        let mut credit_facility = self
+            .credit_facility_repo
+            .find_by_id(obligation.credit_facility_id)
+            .await?;
+        let deposit_account_id = match credit_facility.auto_debit_account_id() {
+            Some(deposit_account_id) => deposit_account_id,
+            None => return Ok(JobCompletion::Complete),
+        };
+
+        let requested = obligation.outstanding();
+        let available = self
+            .ledger
+            .deposit_account_available_balance(deposit_account_id)
+            .await?;
+        let amount = std::cmp::min(requested, available);
+
+        let mut db = self.credit_facility_repo.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::credit_facility(credit_facility.id),
+                CoreCreditAction::CREDIT_FACILITY_RECORD_AUTO_DEBIT,
+            )
+            .await?;
+
+        if amount == UsdCents::ZERO {
+            if credit_facility
+                .record_auto_debit_failed(
+                    obligation.id,
+                    requested,
+                    INSUFFICIENT_AVAILABLE_BALANCE.to_string(),
+                    audit_info,
+                )
+                .did_execute()
+            {
+                self.credit_facility_repo
+                    .update_in_op(&mut db, &mut credit_facility)
+                    .await?;
+                db.commit().await?;
+            }
+            return Ok(JobCompletion::Complete);
+        }
+
+        let payment_id = PaymentId::new();
+        if credit_facility
+            .record_auto_debit_collected(
+                obligation.id,
+                payment_id,
+                requested,
+                amount,
+                audit_info.clone(),
+            )
+            .was_ignored()
+        {
+            return Ok(JobCompletion::Complete);
+        }
+
+        let allocations = self
+            .payments
+            .record_in_op(
+                &mut db,
+                &mut credit_facility,
+                payment_id,
+                amount,
+                audit_info.clone(),
+            )
+            .await?;
+
+        if let Err(e) = self
+            .ledger
+            .record_obligation_repayments_from_deposit(db, allocations, deposit_account_id)
+            .await
+        {
+            self.record_failure(credit_facility.id, requested, e.to_string(), audit_info)
+                .await?;
+        }
+
+        Ok(JobCompletion::Complete)
+    }
+}
@@ -8,7 +8,7 @@ use outbox::OutboxEventMarker;
 
 use crate::{event::CoreCreditEvent, ledger::CreditLedger, obligation::Obligations, primitives::*};
 
-use super::obligation_overdue;
+use super::{obligation_auto_debit, obligation_overdue};
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct CreditFacilityJobConfig<Perms, E> {
@@ -112,6 +112,11 @@ where
             .find_by_id(self.config.obligation_id)
             .await?;
 
+        let due_at = obligation.due_at();
+        if due_at > crate::time::now() {
+            return Ok(JobCompletion::RescheduleAt(due_at));
+        }
+
         let mut db = self.obligations.begin_op().await?;
         let audit_info = self
             .audit
@@ -143,6 +148,16 @@ where
                 obligation.overdue_at(),
             )
             .await?;
+        self.jobs
+            .create_and_spawn_in_op(
+                &mut db,
+                JobId::new(),
+                obligation_auto_debit::CreditFacilityJobConfig::<Perms, E> {
+                    obligation_id: obligation.id,
+                    _phantom: std::marker::PhantomData,
+                },
+            )
+            .await?;
 
         self.ledger.record_obligation_due(db, due).await?;
 
@@ -106,6 +106,11 @@ where
             .find_by_id(self.config.obligation_id)
             .await?;
 
+        let overdue_at = obligation.overdue_at();
+        if overdue_at > crate::time::now() {
+            return Ok(JobCompletion::RescheduleAt(overdue_at));
+        }
+
         let mut db = self.obligations.begin_op().await?;
         let audit_info = self
             .audit
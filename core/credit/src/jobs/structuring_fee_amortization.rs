@@ -0,0 +1,334 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use audit::{AuditInfo, AuditSvc};
+use authz::PermissionCheck;
+use es_entity::Idempotent;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    credit_facility::CreditFacilityRepo, error::CoreCreditError, event::CoreCreditEvent, ledger::*,
+    primitives::*,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CreditFacilityJobConfig<Perms, E> {
+    pub credit_facility_id: CreditFacilityId,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+
+impl<Perms, E> JobConfig for CreditFacilityJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    type Initializer = CreditFacilityProcessingJobInitializer<Perms, E>;
+}
+
+pub struct CreditFacilityProcessingJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    ledger: CreditLedger,
+    credit_facility_repo: CreditFacilityRepo<E>,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> CreditFacilityProcessingJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    pub fn new(
+        ledger: &CreditLedger,
+        credit_facility_repo: &CreditFacilityRepo<E>,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            ledger: ledger.clone(),
+            credit_facility_repo: credit_facility_repo.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const CREDIT_FACILITY_STRUCTURING_FEE_AMORTIZATION_JOB: JobType =
+    JobType::new("credit-facility-structuring-fee-amortization");
+impl<Perms, E> JobInitializer for CreditFacilityProcessingJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        CREDIT_FACILITY_STRUCTURING_FEE_AMORTIZATION_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(CreditFacilityProcessingJobRunner::<Perms, E> {
+            config: job.config()?,
+            credit_facility_repo: self.credit_facility_repo.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct CreditFacilityProcessingJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    config: CreditFacilityJobConfig<Perms, E>,
+    credit_facility_repo: CreditFacilityRepo<E>,
+    ledger: CreditLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> CreditFacilityProcessingJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    #[es_entity::retry_on_concurrent_modification]
+    async fn record_amortization(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        audit_info: &AuditInfo,
+    ) -> Result<(Option<StructuringFeeAmortization>, Option<DateTime<Utc>>), CoreCreditError> {
+        let mut credit_facility = self
+            .credit_facility_repo
+            .find_by_id(self.config.credit_facility_id)
+            .await?;
+
+        let balances = self
+            .ledger
+            .get_credit_facility_balance(credit_facility.account_ids)
+            .await?;
+
+        let now = db.now();
+        let amortization = match credit_facility.amortize_structuring_fee(
+            now,
+            balances.disbursed_outstanding(),
+            audit_info.clone(),
+        ) {
+            Idempotent::Executed(amortization) => {
+                self.credit_facility_repo
+                    .update_in_op(db, &mut credit_facility)
+                    .await?;
+                Some(amortization)
+            }
+            Idempotent::Ignored => None,
+        };
+
+        Ok((
+            amortization,
+            credit_facility.next_structuring_fee_amortization_at(now),
+        ))
+    }
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for CreditFacilityProcessingJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    #[instrument(
+        name = "credit-facility.structuring-fee-amortization.job",
+        skip(self, current_job),
+        fields(attempt)
+    )]
+    async fn run(
+        &self,
+        current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let span = tracing::Span::current();
+        span.record("attempt", current_job.attempt());
+
+        let mut db = self.credit_facility_repo.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_AMORTIZE_STRUCTURING_FEE,
+            )
+            .await?;
+
+        let (amortization, next_amortization_at) =
+            self.record_amortization(&mut db, &audit_info).await?;
+
+        let db = if let Some(amortization) = amortization {
+            let (now, mut tx) = (db.now(), db.into_tx());
+            let sub_op = {
+                use sqlx::Acquire;
+                es_entity::DbOp::new(tx.begin().await?, now)
+            };
+            self.ledger
+                .amortize_structuring_fee(sub_op, amortization)
+                .await?;
+            es_entity::DbOp::new(tx, now)
+        } else {
+            db
+        };
+
+        match next_amortization_at {
+            Some(next_amortization_at) => {
+                Ok(JobCompletion::RescheduleAtWithOp(db, next_amortization_at))
+            }
+            None => Ok(JobCompletion::CompleteWithOp(db)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use chrono::TimeZone;
+    use es_entity::{EntityEvents, TryFromEvents};
+    use rust_decimal_macros::dec;
+
+    use crate::{
+        credit_facility::{CreditFacility, CreditFacilityBalanceSummary, CreditFacilityEvent},
+        terms::*,
+    };
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn activated_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap()
+    }
+
+    fn balances(facility_remaining: UsdCents) -> CreditFacilityBalanceSummary {
+        CreditFacilityBalanceSummary {
+            facility_remaining,
+            collateral: Satoshis::ZERO,
+            disbursed: UsdCents::ZERO,
+            not_yet_due_disbursed_outstanding: UsdCents::ZERO,
+            due_disbursed_outstanding: UsdCents::ZERO,
+            overdue_disbursed_outstanding: UsdCents::ZERO,
+            disbursed_defaulted: UsdCents::ZERO,
+            interest_posted: UsdCents::ZERO,
+            not_yet_due_interest_outstanding: UsdCents::ZERO,
+            due_interest_outstanding: UsdCents::ZERO,
+            overdue_interest_outstanding: UsdCents::ZERO,
+            interest_defaulted: UsdCents::ZERO,
+        }
+    }
+
+    fn activated_facility() -> CreditFacility {
+        let terms = TermValues::builder()
+            .annual_rate(dec!(12))
+            .duration(Duration::Months(12))
+            .interest_due_duration(InterestDuration::Days(0))
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .accrual_interval(InterestInterval::EndOfDay)
+            .one_time_fee_rate(OneTimeFeeRatePct::new(3))
+            .structuring_fee_recognition(StructuringFeeRecognition::Deferred)
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .build()
+            .expect("should build a valid term");
+        let amount = UsdCents::from(10_000_33);
+        let price = PriceOfOneBTC::new(UsdCents::from(50_000_00));
+        let id = CreditFacilityId::new();
+        let mut credit_facility = CreditFacility::try_from_events(EntityEvents::init(
+            id,
+            vec![CreditFacilityEvent::Initialized {
+                id,
+                audit_info: dummy_audit_info(),
+                customer_id: CustomerId::new(),
+                amount,
+                terms: Box::new(terms),
+                account_ids: CreditFacilityAccountIds::new(),
+                disbursal_credit_account_id: CalaAccountId::new(),
+                approval_process_id: ApprovalProcessId::new(),
+            }],
+        ))
+        .unwrap();
+
+        credit_facility
+            .record_collateral_update(
+                Satoshis::from(100_000_000),
+                dummy_audit_info(),
+                price,
+                CVLPct::new(5),
+                balances(amount),
+            )
+            .unwrap();
+        credit_facility
+            .approval_process_concluded(true, dummy_audit_info())
+            .unwrap();
+        credit_facility
+            .activate(
+                activated_at(),
+                price,
+                balances(amount),
+                None,
+                dummy_audit_info(),
+            )
+            .unwrap()
+            .unwrap();
+
+        credit_facility
+    }
+
+    #[test]
+    fn runs_release_exactly_the_fee() {
+        let mut credit_facility = activated_facility();
+        let fee = credit_facility.structuring_fee();
+
+        let mut drawn = UsdCents::from(2_500_00);
+        let mut now = activated_at();
+        let mut released = UsdCents::ZERO;
+        let mut runs = 0;
+        while let Some(next_amortization_at) =
+            credit_facility.next_structuring_fee_amortization_at(now)
+        {
+            now = next_amortization_at;
+            let remaining = credit_facility.structuring_fee_amortization_schedule(drawn);
+            if remaining.len() == 1 {
+                assert_eq!(
+                    remaining[0].amount,
+                    credit_facility.unamortized_structuring_fee()
+                );
+            }
+
+            let amortization = credit_facility
+                .amortize_structuring_fee(now, drawn, dummy_audit_info())
+                .unwrap();
+            released += amortization.amount;
+            drawn += UsdCents::from(500_00);
+            runs += 1;
+        }
+
+        assert_eq!(runs, 13);
+        assert_eq!(released, fee);
+        assert!(credit_facility.unamortized_structuring_fee().is_zero());
+    }
+}
@@ -176,6 +176,70 @@ pub const CREDIT_INTEREST_INCOME_ACCOUNT_SET_REF: &str = "credit-interest-income
 pub const CREDIT_FEE_INCOME_ACCOUNT_SET_NAME: &str = "Credit Fee Income Account Set";
 pub const CREDIT_FEE_INCOME_ACCOUNT_SET_REF: &str = "credit-fee-income-account-set";
 
+pub const CREDIT_DEFERRED_FEE_INCOME_ACCOUNT_SET_NAME: &str =
+    "Credit Deferred Fee Income Account Set";
+pub const CREDIT_DEFERRED_FEE_INCOME_ACCOUNT_SET_REF: &str =
+    "credit-deferred-fee-income-account-set";
+
+// Exposure Accounts
+pub const EXPOSURE_CREDIT_INDIVIDUAL_FACILITY_REMAINING_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Individual Facility Remaining Account Set";
+pub const EXPOSURE_CREDIT_INDIVIDUAL_FACILITY_REMAINING_ACCOUNT_SET_REF: &str =
+    "exposure-credit-individual-facility-remaining-account-set";
+pub const EXPOSURE_CREDIT_GOVERNMENT_ENTITY_FACILITY_REMAINING_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Government Entity Facility Remaining Account Set";
+pub const EXPOSURE_CREDIT_GOVERNMENT_ENTITY_FACILITY_REMAINING_ACCOUNT_SET_REF: &str =
+    "exposure-credit-government-entity-facility-remaining-account-set";
+pub const EXPOSURE_CREDIT_PRIVATE_COMPANY_FACILITY_REMAINING_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Private Company Facility Remaining Account Set";
+pub const EXPOSURE_CREDIT_PRIVATE_COMPANY_FACILITY_REMAINING_ACCOUNT_SET_REF: &str =
+    "exposure-credit-private-company-facility-remaining-account-set";
+pub const EXPOSURE_CREDIT_BANK_FACILITY_REMAINING_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Bank Facility Remaining Account Set";
+pub const EXPOSURE_CREDIT_BANK_FACILITY_REMAINING_ACCOUNT_SET_REF: &str =
+    "exposure-credit-bank-facility-remaining-account-set";
+pub const EXPOSURE_CREDIT_FINANCIAL_INSTITUTION_FACILITY_REMAINING_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Financial Institution Facility Remaining Account Set";
+pub const EXPOSURE_CREDIT_FINANCIAL_INSTITUTION_FACILITY_REMAINING_ACCOUNT_SET_REF: &str =
+    "exposure-credit-financial-institution-facility-remaining-account-set";
+pub const EXPOSURE_CREDIT_FOREIGN_AGENCY_OR_SUBSIDIARY_FACILITY_REMAINING_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Foreign Agency Or Subsidiary Facility Remaining Account Set";
+pub const EXPOSURE_CREDIT_FOREIGN_AGENCY_OR_SUBSIDIARY_FACILITY_REMAINING_ACCOUNT_SET_REF: &str =
+    "exposure-credit-foreign-agency-or-subsidiary-facility-remaining-account-set";
+pub const EXPOSURE_CREDIT_NON_DOMICILED_COMPANY_FACILITY_REMAINING_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Non-Domiciled Company Facility Remaining Account Set";
+pub const EXPOSURE_CREDIT_NON_DOMICILED_COMPANY_FACILITY_REMAINING_ACCOUNT_SET_REF: &str =
+    "exposure-credit-non-domiciled-company-facility-remaining-account-set";
+
+pub const EXPOSURE_CREDIT_INDIVIDUAL_DEFAULTED_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Individual Defaulted Account Set";
+pub const EXPOSURE_CREDIT_INDIVIDUAL_DEFAULTED_ACCOUNT_SET_REF: &str =
+    "exposure-credit-individual-defaulted-account-set";
+pub const EXPOSURE_CREDIT_GOVERNMENT_ENTITY_DEFAULTED_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Government Entity Defaulted Account Set";
+pub const EXPOSURE_CREDIT_GOVERNMENT_ENTITY_DEFAULTED_ACCOUNT_SET_REF: &str =
+    "exposure-credit-government-entity-defaulted-account-set";
+pub const EXPOSURE_CREDIT_PRIVATE_COMPANY_DEFAULTED_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Private Company Defaulted Account Set";
+pub const EXPOSURE_CREDIT_PRIVATE_COMPANY_DEFAULTED_ACCOUNT_SET_REF: &str =
+    "exposure-credit-private-company-defaulted-account-set";
+pub const EXPOSURE_CREDIT_BANK_DEFAULTED_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Bank Defaulted Account Set";
+pub const EXPOSURE_CREDIT_BANK_DEFAULTED_ACCOUNT_SET_REF: &str =
+    "exposure-credit-bank-defaulted-account-set";
+pub const EXPOSURE_CREDIT_FINANCIAL_INSTITUTION_DEFAULTED_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Financial Institution Defaulted Account Set";
+pub const EXPOSURE_CREDIT_FINANCIAL_INSTITUTION_DEFAULTED_ACCOUNT_SET_REF: &str =
+    "exposure-credit-financial-institution-defaulted-account-set";
+pub const EXPOSURE_CREDIT_FOREIGN_AGENCY_OR_SUBSIDIARY_DEFAULTED_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Foreign Agency Or Subsidiary Defaulted Account Set";
+pub const EXPOSURE_CREDIT_FOREIGN_AGENCY_OR_SUBSIDIARY_DEFAULTED_ACCOUNT_SET_REF: &str =
+    "exposure-credit-foreign-agency-or-subsidiary-defaulted-account-set";
+pub const EXPOSURE_CREDIT_NON_DOMICILED_COMPANY_DEFAULTED_ACCOUNT_SET_NAME: &str =
+    "Exposure Credit Non-Domiciled Company Defaulted Account Set";
+pub const EXPOSURE_CREDIT_NON_DOMICILED_COMPANY_DEFAULTED_ACCOUNT_SET_REF: &str =
+    "exposure-credit-non-domiciled-company-defaulted-account-set";
+
 // Velocity Controls
 pub(super) const CREDIT_FACILITY_VELOCITY_CONTROL_ID: uuid::Uuid =
     uuid::uuid!("00000000-0000-0000-0000-000000000002");
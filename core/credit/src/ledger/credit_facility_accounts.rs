@@ -21,6 +21,7 @@ pub struct CreditFacilityAccountIds {
     pub interest_defaulted_account_id: CalaAccountId,
     pub interest_income_account_id: CalaAccountId,
     pub fee_income_account_id: CalaAccountId,
+    pub deferred_fee_income_account_id: CalaAccountId,
 }
 
 impl CreditFacilityAccountIds {
@@ -39,6 +40,7 @@ impl CreditFacilityAccountIds {
             interest_defaulted_account_id: CalaAccountId::new(),
             interest_income_account_id: CalaAccountId::new(),
             fee_income_account_id: CalaAccountId::new(),
+            deferred_fee_income_account_id: CalaAccountId::new(),
         }
     }
 }
@@ -48,6 +50,7 @@ pub struct CreditFacilityCompletion {
     pub tx_id: LedgerTxId,
     pub collateral: Satoshis,
     pub credit_facility_account_ids: CreditFacilityAccountIds,
+    pub structuring_fee_release: Option<StructuringFeeAmortization>,
 }
 
 #[derive(Debug, Clone)]
@@ -58,6 +61,16 @@ pub struct CreditFacilityActivation {
     pub debit_account_id: CalaAccountId,
     pub facility_amount: UsdCents,
     pub structuring_fee_amount: UsdCents,
+    pub defer_structuring_fee: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct StructuringFeeAmortization {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+    pub credit_facility_account_ids: CreditFacilityAccountIds,
 }
 
 #[derive(Debug, Clone)]
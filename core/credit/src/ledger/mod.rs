@@ -17,6 +17,8 @@ use cala_ledger::{
     CalaLedger, Currency, DebitOrCredit, JournalId, LedgerOperation, TransactionId,
 };
 
+use core_accounting::PeriodPostingLock;
+
 use crate::{
     credit_facility::CreditFacilityBalanceSummary,
     payment_allocation::PaymentAllocation,
@@ -120,6 +122,7 @@ pub struct CreditFacilityInternalAccountSets {
     pub interest_defaulted: InternalAccountSetDetails,
     pub interest_income: InternalAccountSetDetails,
     pub fee_income: InternalAccountSetDetails,
+    pub deferred_fee_income: InternalAccountSetDetails,
 }
 
 impl CreditFacilityInternalAccountSets {
@@ -129,6 +132,7 @@ impl CreditFacilityInternalAccountSets {
             collateral,
             interest_income,
             fee_income,
+            deferred_fee_income,
 
             disbursed_receivable:
                 DisbursedReceivable {
@@ -150,6 +154,7 @@ impl CreditFacilityInternalAccountSets {
             collateral.id,
             interest_income.id,
             fee_income.id,
+            deferred_fee_income.id,
             disbursed_defaulted.id,
             interest_defaulted.id,
         ];
@@ -167,28 +172,80 @@ impl CreditFacilityInternalAccountSets {
     }
 }
 
+#[derive(Clone, Copy)]
+pub struct CustomerTypeExposureAccountSets {
+    facility_remaining: CalaAccountSetId,
+    defaulted: CalaAccountSetId,
+}
+
+#[derive(Clone, Copy)]
+pub struct ExposureAccountSets {
+    individual: CustomerTypeExposureAccountSets,
+    government_entity: CustomerTypeExposureAccountSets,
+    private_company: CustomerTypeExposureAccountSets,
+    bank: CustomerTypeExposureAccountSets,
+    financial_institution: CustomerTypeExposureAccountSets,
+    foreign_agency_or_subsidiary: CustomerTypeExposureAccountSets,
+    non_domiciled_company: CustomerTypeExposureAccountSets,
+}
+
+impl ExposureAccountSets {
+    fn for_customer_type(&self, customer_type: CustomerType) -> CustomerTypeExposureAccountSets {
+        match customer_type {
+            CustomerType::Individual => self.individual,
+            CustomerType::GovernmentEntity => self.government_entity,
+            CustomerType::PrivateCompany => self.private_company,
+            CustomerType::Bank => self.bank,
+            CustomerType::FinancialInstitution => self.financial_institution,
+            CustomerType::ForeignAgencyOrSubsidiary => self.foreign_agency_or_subsidiary,
+            CustomerType::NonDomiciledCompany => self.non_domiciled_company,
+        }
+    }
+
+    fn customer_types() -> [CustomerType; 7] {
+        [
+            CustomerType::Individual,
+            CustomerType::GovernmentEntity,
+            CustomerType::PrivateCompany,
+            CustomerType::Bank,
+            CustomerType::FinancialInstitution,
+            CustomerType::ForeignAgencyOrSubsidiary,
+            CustomerType::NonDomiciledCompany,
+        ]
+    }
+}
+
 #[derive(Clone)]
 pub struct CreditLedger {
     cala: CalaLedger,
     journal_id: JournalId,
+    posting_lock: PeriodPostingLock,
     facility_omnibus_account_ids: LedgerOmnibusAccountIds,
     collateral_omnibus_account_ids: LedgerOmnibusAccountIds,
     internal_account_sets: CreditFacilityInternalAccountSets,
+    exposure_account_sets: ExposureAccountSets,
     credit_facility_control_id: VelocityControlId,
     usd: Currency,
     btc: Currency,
 }
 
 impl CreditLedger {
-    pub async fn init(cala: &CalaLedger, journal_id: JournalId) -> Result<Self, CreditLedgerError> {
+    pub async fn init(
+        pool: &sqlx::PgPool,
+        cala: &CalaLedger,
+        journal_id: JournalId,
+    ) -> Result<Self, CreditLedgerError> {
         templates::AddCollateral::init(cala).await?;
         templates::ActivateCreditFacility::init(cala).await?;
         templates::RemoveCollateral::init(cala).await?;
         templates::RecordPaymentAllocation::init(cala).await?;
+        templates::RecordPaymentFromDeposit::init(cala).await?;
         templates::RecordObligationDueBalance::init(cala).await?;
         templates::RecordObligationOverdueBalance::init(cala).await?;
         templates::CreditFacilityAccrueInterest::init(cala).await?;
         templates::CreditFacilityPostAccruedInterest::init(cala).await?;
+        templates::CreditFacilityCapitalizeAccruedInterest::init(cala).await?;
+        templates::CreditFacilityAmortizeStructuringFee::init(cala).await?;
         templates::InitiateDisbursal::init(cala).await?;
         templates::CancelDisbursal::init(cala).await?;
         templates::ConfirmDisbursal::init(cala).await?;
@@ -583,6 +640,16 @@ impl CreditLedger {
         )
         .await?;
 
+        let deferred_fee_income_normal_balance_type = DebitOrCredit::Credit;
+        let deferred_fee_income_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_DEFERRED_FEE_INCOME_ACCOUNT_SET_REF}"),
+            CREDIT_DEFERRED_FEE_INCOME_ACCOUNT_SET_NAME.to_string(),
+            deferred_fee_income_normal_balance_type,
+        )
+        .await?;
+
         let disbursed_receivable = DisbursedReceivable {
             short_term: DisbursedReceivableAccountSets {
                 individual: InternalAccountSetDetails {
@@ -766,6 +833,104 @@ impl CreditLedger {
                 id: fee_income_account_set_id,
                 normal_balance_type: fee_income_normal_balance_type,
             },
+            deferred_fee_income: InternalAccountSetDetails {
+                id: deferred_fee_income_account_set_id,
+                normal_balance_type: deferred_fee_income_normal_balance_type,
+            },
+        };
+
+        let exposure_account_sets = ExposureAccountSets {
+            individual: Self::find_or_create_exposure_account_sets(
+                cala,
+                journal_id,
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_INDIVIDUAL_FACILITY_REMAINING_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_INDIVIDUAL_FACILITY_REMAINING_ACCOUNT_SET_NAME.to_string(),
+                ),
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_INDIVIDUAL_DEFAULTED_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_INDIVIDUAL_DEFAULTED_ACCOUNT_SET_NAME.to_string(),
+                ),
+            )
+            .await?,
+            government_entity: Self::find_or_create_exposure_account_sets(
+                cala,
+                journal_id,
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_GOVERNMENT_ENTITY_FACILITY_REMAINING_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_GOVERNMENT_ENTITY_FACILITY_REMAINING_ACCOUNT_SET_NAME.to_string(),
+                ),
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_GOVERNMENT_ENTITY_DEFAULTED_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_GOVERNMENT_ENTITY_DEFAULTED_ACCOUNT_SET_NAME.to_string(),
+                ),
+            )
+            .await?,
+            private_company: Self::find_or_create_exposure_account_sets(
+                cala,
+                journal_id,
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_PRIVATE_COMPANY_FACILITY_REMAINING_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_PRIVATE_COMPANY_FACILITY_REMAINING_ACCOUNT_SET_NAME.to_string(),
+                ),
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_PRIVATE_COMPANY_DEFAULTED_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_PRIVATE_COMPANY_DEFAULTED_ACCOUNT_SET_NAME.to_string(),
+                ),
+            )
+            .await?,
+            bank: Self::find_or_create_exposure_account_sets(
+                cala,
+                journal_id,
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_BANK_FACILITY_REMAINING_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_BANK_FACILITY_REMAINING_ACCOUNT_SET_NAME.to_string(),
+                ),
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_BANK_DEFAULTED_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_BANK_DEFAULTED_ACCOUNT_SET_NAME.to_string(),
+                ),
+            )
+            .await?,
+            financial_institution: Self::find_or_create_exposure_account_sets(
+                cala,
+                journal_id,
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_FINANCIAL_INSTITUTION_FACILITY_REMAINING_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_FINANCIAL_INSTITUTION_FACILITY_REMAINING_ACCOUNT_SET_NAME.to_string(),
+                ),
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_FINANCIAL_INSTITUTION_DEFAULTED_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_FINANCIAL_INSTITUTION_DEFAULTED_ACCOUNT_SET_NAME.to_string(),
+                ),
+            )
+            .await?,
+            foreign_agency_or_subsidiary: Self::find_or_create_exposure_account_sets(
+                cala,
+                journal_id,
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_FOREIGN_AGENCY_OR_SUBSIDIARY_FACILITY_REMAINING_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_FOREIGN_AGENCY_OR_SUBSIDIARY_FACILITY_REMAINING_ACCOUNT_SET_NAME.to_string(),
+                ),
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_FOREIGN_AGENCY_OR_SUBSIDIARY_DEFAULTED_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_FOREIGN_AGENCY_OR_SUBSIDIARY_DEFAULTED_ACCOUNT_SET_NAME.to_string(),
+                ),
+            )
+            .await?,
+            non_domiciled_company: Self::find_or_create_exposure_account_sets(
+                cala,
+                journal_id,
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_NON_DOMICILED_COMPANY_FACILITY_REMAINING_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_NON_DOMICILED_COMPANY_FACILITY_REMAINING_ACCOUNT_SET_NAME.to_string(),
+                ),
+                (
+                    format!("{journal_id}:{EXPOSURE_CREDIT_NON_DOMICILED_COMPANY_DEFAULTED_ACCOUNT_SET_REF}"),
+                    EXPOSURE_CREDIT_NON_DOMICILED_COMPANY_DEFAULTED_ACCOUNT_SET_NAME.to_string(),
+                ),
+            )
+            .await?,
         };
 
         let disbursal_limit_id = velocity::DisbursalLimit::init(cala).await?;
@@ -785,9 +950,11 @@ impl CreditLedger {
         Ok(Self {
             cala: cala.clone(),
             journal_id,
+            posting_lock: PeriodPostingLock::new(pool),
             facility_omnibus_account_ids,
             collateral_omnibus_account_ids,
             internal_account_sets,
+            exposure_account_sets,
             credit_facility_control_id,
             usd: Currency::USD,
             btc: Currency::BTC,
@@ -834,6 +1001,34 @@ impl CreditLedger {
         }
     }
 
+    async fn find_or_create_exposure_account_sets(
+        cala: &CalaLedger,
+        journal_id: JournalId,
+        (facility_remaining_reference, facility_remaining_name): (String, String),
+        (defaulted_reference, defaulted_name): (String, String),
+    ) -> Result<CustomerTypeExposureAccountSets, CreditLedgerError> {
+        let facility_remaining = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            facility_remaining_reference,
+            facility_remaining_name,
+            DebitOrCredit::Credit,
+        )
+        .await?;
+        let defaulted = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            defaulted_reference,
+            defaulted_name,
+            DebitOrCredit::Debit,
+        )
+        .await?;
+        Ok(CustomerTypeExposureAccountSets {
+            facility_remaining,
+            defaulted,
+        })
+    }
+
     async fn find_or_create_omnibus_account(
         cala: &CalaLedger,
         journal_id: JournalId,
@@ -925,6 +1120,7 @@ impl CreditLedger {
             interest_defaulted_account_id,
 
             fee_income_account_id: _,
+            deferred_fee_income_account_id: _,
             interest_income_account_id: _,
         }: CreditFacilityAccountIds,
     ) -> Result<CreditFacilityBalanceSummary, CreditLedgerError> {
@@ -1167,6 +1363,67 @@ impl CreditLedger {
         Ok(())
     }
 
+    pub async fn record_obligation_repayments_from_deposit(
+        &self,
+        op: es_entity::DbOp<'_>,
+        payments: Vec<PaymentAllocation>,
+        deposit_account_id: CalaAccountId,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        for PaymentAllocation {
+            id,
+            obligation_id: tx_ref,
+            amount,
+            account_to_be_debited_id,
+            ..
+        } in payments
+        {
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    id.into(),
+                    templates::RECORD_PAYMENT_FROM_DEPOSIT_CODE,
+                    templates::RecordPaymentFromDepositParams {
+                        journal_id: self.journal_id,
+                        currency: self.usd,
+                        amount: amount.to_usd(),
+                        deposit_account_id,
+                        receivable_account_id: account_to_be_debited_id,
+                        tx_ref: tx_ref.to_string(),
+                    },
+                )
+                .await?;
+        }
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn deposit_account_available_balance(
+        &self,
+        deposit_account_id: CalaAccountId,
+    ) -> Result<UsdCents, CreditLedgerError> {
+        match self
+            .cala
+            .balances()
+            .find(self.journal_id, deposit_account_id, self.usd)
+            .await
+        {
+            Ok(balances) => {
+                let settled = UsdCents::try_from_usd(balances.settled())?;
+                let encumbrance = UsdCents::try_from_usd(balances.encumbrance())?;
+                if settled > encumbrance {
+                    Ok(settled - encumbrance)
+                } else {
+                    Ok(UsdCents::ZERO)
+                }
+            }
+            Err(cala_ledger::balance::error::BalanceError::NotFound(..)) => Ok(UsdCents::ZERO),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     pub async fn record_obligation_due(
         &self,
         op: es_entity::DbOp<'_>,
@@ -1227,14 +1484,36 @@ impl CreditLedger {
 
     pub async fn complete_credit_facility(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         CreditFacilityCompletion {
             tx_id,
             collateral,
             credit_facility_account_ids,
+            structuring_fee_release,
         }: CreditFacilityCompletion,
+        customer_type: CustomerType,
     ) -> Result<(), CreditLedgerError> {
+        let structuring_fee_release = match structuring_fee_release {
+            Some(release) => Some(StructuringFeeAmortization {
+                effective: self
+                    .posting_lock
+                    .first_open_date_in_op(&mut op, release.effective)
+                    .await?,
+                ..release
+            }),
+            None => None,
+        };
         let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .account_sets()
+            .remove_member_in_op(
+                &mut op,
+                self.exposure_account_sets
+                    .for_customer_type(customer_type)
+                    .facility_remaining,
+                credit_facility_account_ids.facility_account_id,
+            )
+            .await?;
         self.cala
             .post_transaction_in_op(
                 &mut op,
@@ -1249,10 +1528,64 @@ impl CreditLedger {
                 },
             )
             .await?;
+        if let Some(release) = structuring_fee_release {
+            self.post_structuring_fee_amortization_in_op(&mut op, release)
+                .await?;
+        }
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn amortize_structuring_fee(
+        &self,
+        mut op: es_entity::DbOp<'_>,
+        amortization: StructuringFeeAmortization,
+    ) -> Result<(), CreditLedgerError> {
+        let amortization = StructuringFeeAmortization {
+            effective: self
+                .posting_lock
+                .first_open_date_in_op(&mut op, amortization.effective)
+                .await?,
+            ..amortization
+        };
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.post_structuring_fee_amortization_in_op(&mut op, amortization)
+            .await?;
         op.commit().await?;
         Ok(())
     }
 
+    async fn post_structuring_fee_amortization_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        StructuringFeeAmortization {
+            tx_id,
+            tx_ref,
+            amount,
+            effective,
+            credit_facility_account_ids,
+        }: StructuringFeeAmortization,
+    ) -> Result<(), CreditLedgerError> {
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::CREDIT_FACILITY_AMORTIZE_STRUCTURING_FEE_CODE,
+                templates::CreditFacilityAmortizeStructuringFeeParams {
+                    journal_id: self.journal_id,
+                    credit_facility_deferred_fee_income_account: credit_facility_account_ids
+                        .deferred_fee_income_account_id,
+                    credit_facility_fee_income_account: credit_facility_account_ids
+                        .fee_income_account_id,
+                    fee_amount: amount.to_usd(),
+                    external_id: tx_ref,
+                    effective,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
     pub async fn activate_credit_facility(
         &self,
         op: es_entity::DbOp<'_>,
@@ -1263,8 +1596,14 @@ impl CreditLedger {
             debit_account_id,
             facility_amount,
             structuring_fee_amount,
+            defer_structuring_fee,
         }: CreditFacilityActivation,
     ) -> Result<(), CreditLedgerError> {
+        let facility_fee_income_account = if defer_structuring_fee {
+            credit_facility_account_ids.deferred_fee_income_account_id
+        } else {
+            credit_facility_account_ids.fee_income_account_id
+        };
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1277,7 +1616,7 @@ impl CreditLedger {
                     credit_facility_account: credit_facility_account_ids.facility_account_id,
                     facility_disbursed_receivable_account: credit_facility_account_ids
                         .disbursed_receivable_not_yet_due_account_id,
-                    facility_fee_income_account: credit_facility_account_ids.fee_income_account_id,
+                    facility_fee_income_account,
                     debit_account_id,
                     facility_amount: facility_amount.to_usd(),
                     structuring_fee_amount: structuring_fee_amount.to_usd(),
@@ -1292,7 +1631,7 @@ impl CreditLedger {
 
     pub async fn record_interest_accrual(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         CreditFacilityInterestAccrual {
             tx_id,
             tx_ref,
@@ -1301,6 +1640,10 @@ impl CreditLedger {
             credit_facility_account_ids,
         }: CreditFacilityInterestAccrual,
     ) -> Result<(), CreditLedgerError> {
+        let effective = self
+            .posting_lock
+            .first_open_date_in_op(&mut op, period.end.date_naive())
+            .await?;
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
@@ -1316,7 +1659,7 @@ impl CreditLedger {
                         .interest_income_account_id,
                     interest_amount: interest.to_usd(),
                     external_id: tx_ref,
-                    effective: period.end.date_naive(),
+                    effective,
                 },
             )
             .await?;
@@ -1326,7 +1669,7 @@ impl CreditLedger {
 
     pub async fn record_interest_accrual_cycle(
         &self,
-        op: es_entity::DbOp<'_>,
+        mut op: es_entity::DbOp<'_>,
         obligation: Obligation,
     ) -> Result<(), CreditLedgerError> {
         let interest_receivable_account_id =
@@ -1340,6 +1683,10 @@ impl CreditLedger {
             recorded_at: posted_at,
             ..
         } = obligation;
+        let effective = self
+            .posting_lock
+            .first_open_date_in_op(&mut op, posted_at.date_naive())
+            .await?;
 
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
@@ -1354,7 +1701,52 @@ impl CreditLedger {
                     credit_facility_interest_income_account: interest_income_account_id,
                     interest_amount: interest.to_usd(),
                     external_id: tx_ref,
-                    effective: posted_at.date_naive(),
+                    effective,
+                },
+            )
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn capitalize_interest_accrual_cycle(
+        &self,
+        mut op: es_entity::DbOp<'_>,
+        obligation: Obligation,
+        credit_facility_account_ids: CreditFacilityAccountIds,
+    ) -> Result<(), CreditLedgerError> {
+        let disbursed_receivable_account_id =
+            obligation.not_yet_due_accounts().account_to_be_debited_id;
+        let interest_income_account_id =
+            obligation.not_yet_due_accounts().account_to_be_credited_id;
+        let Obligation {
+            tx_id,
+            reference: tx_ref,
+            initial_amount: interest,
+            recorded_at: posted_at,
+            ..
+        } = obligation;
+        let effective = self
+            .posting_lock
+            .first_open_date_in_op(&mut op, posted_at.date_naive())
+            .await?;
+
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::CREDIT_FACILITY_CAPITALIZE_ACCRUED_INTEREST_CODE,
+                templates::CreditFacilityCapitalizeAccruedInterestParams {
+                    journal_id: self.journal_id,
+
+                    credit_facility_interest_receivable_account: credit_facility_account_ids
+                        .interest_receivable_not_yet_due_account_id,
+                    credit_facility_interest_income_account: interest_income_account_id,
+                    credit_facility_disbursed_receivable_account: disbursed_receivable_account_id,
+                    interest_amount: interest.to_usd(),
+                    external_id: tx_ref,
+                    effective,
                 },
             )
             .await?;
@@ -1599,6 +1991,7 @@ impl CreditLedger {
             interest_defaulted_account_id,
             interest_income_account_id,
             fee_income_account_id,
+            deferred_fee_income_account_id,
         } = account_ids;
 
         let collateral_reference = &format!("credit-facility-collateral:{}", credit_facility_id);
@@ -1805,9 +2198,139 @@ impl CreditLedger {
         )
         .await?;
 
+        let deferred_fee_income_reference =
+            &format!("credit-facility-deferred-fee-income:{}", credit_facility_id);
+        let deferred_fee_income_name = &format!(
+            "Deferred Fee Income Account for Credit Facility {}",
+            credit_facility_id
+        );
+        self.create_account_in_op(
+            op,
+            deferred_fee_income_account_id,
+            self.internal_account_sets.deferred_fee_income,
+            deferred_fee_income_reference,
+            deferred_fee_income_name,
+            deferred_fee_income_name,
+        )
+        .await?;
+
+        self.add_credit_facility_to_exposure_account_sets_in_op(op, account_ids, customer_type)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn add_credit_facility_to_exposure_account_sets_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        account_ids: CreditFacilityAccountIds,
+        customer_type: CustomerType,
+    ) -> Result<(), CreditLedgerError> {
+        let exposure_account_sets = self.exposure_account_sets.for_customer_type(customer_type);
+        self.cala
+            .account_sets()
+            .add_member_in_op(
+                op,
+                exposure_account_sets.facility_remaining,
+                account_ids.facility_account_id,
+            )
+            .await?;
+        for defaulted_account_id in [
+            account_ids.disbursed_defaulted_account_id,
+            account_ids.interest_defaulted_account_id,
+        ] {
+            self.cala
+                .account_sets()
+                .add_member_in_op(op, exposure_account_sets.defaulted, defaulted_account_id)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn add_credit_facilities_to_exposure_account_sets(
+        &self,
+        op: es_entity::DbOp<'_>,
+        credit_facilities: Vec<(CreditFacilityAccountIds, CustomerType)>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        for (account_ids, customer_type) in credit_facilities {
+            self.add_credit_facility_to_exposure_account_sets_in_op(
+                &mut op,
+                account_ids,
+                customer_type,
+            )
+            .await?;
+        }
+        op.commit().await?;
         Ok(())
     }
 
+    /// Remaining commitment plus outstanding balances per customer type, read from the
+    /// exposure and receivable account sets in a single balance lookup. Facilities keep
+    /// their undrawn commitment in the aggregate until they are completed.
+    pub async fn get_customer_type_exposures(
+        &self,
+    ) -> Result<Vec<(CustomerType, UsdCents)>, CreditLedgerError> {
+        let customer_type_balance_ids: Vec<_> = ExposureAccountSets::customer_types()
+            .into_iter()
+            .map(|customer_type| {
+                let exposure_account_sets =
+                    self.exposure_account_sets.for_customer_type(customer_type);
+                let account_set_ids = [
+                    exposure_account_sets.facility_remaining,
+                    exposure_account_sets.defaulted,
+                    self.disbursed_internal_account_set_from_type(
+                        customer_type,
+                        DisbursedReceivableAccountCategory::ShortTerm,
+                    )
+                    .id,
+                    self.disbursed_internal_account_set_from_type(
+                        customer_type,
+                        DisbursedReceivableAccountCategory::LongTerm,
+                    )
+                    .id,
+                    self.disbursed_internal_account_set_from_type(
+                        customer_type,
+                        DisbursedReceivableAccountCategory::Overdue,
+                    )
+                    .id,
+                    self.interest_internal_account_set_from_type(
+                        customer_type,
+                        DurationType::ShortTerm,
+                    )
+                    .id,
+                    self.interest_internal_account_set_from_type(
+                        customer_type,
+                        DurationType::LongTerm,
+                    )
+                    .id,
+                ];
+                (
+                    customer_type,
+                    account_set_ids.map(|id| (self.journal_id, CalaAccountId::from(id), self.usd)),
+                )
+            })
+            .collect();
+
+        let all_balance_ids: Vec<_> = customer_type_balance_ids
+            .iter()
+            .flat_map(|(_, balance_ids)| balance_ids.iter().copied())
+            .collect();
+        let balances = self.cala.balances().find_all(&all_balance_ids).await?;
+
+        let mut res = Vec::new();
+        for (customer_type, balance_ids) in customer_type_balance_ids {
+            let mut exposure = UsdCents::ZERO;
+            for balance_id in balance_ids.iter() {
+                if let Some(b) = balances.get(balance_id) {
+                    exposure += UsdCents::try_from_usd(b.settled())?;
+                }
+            }
+            res.push((customer_type, exposure));
+        }
+        Ok(res)
+    }
+
     pub async fn get_chart_of_accounts_integration_config(
         &self,
     ) -> Result<Option<ChartOfAccountsIntegrationConfig>, CreditLedgerError> {
@@ -1898,6 +2421,7 @@ impl CreditLedger {
             collateral_parent_account_set_id,
             interest_income_parent_account_set_id,
             fee_income_parent_account_set_id,
+            deferred_fee_income_parent_account_set_id,
             short_term_disbursed_integration_meta,
             long_term_disbursed_integration_meta,
             short_term_interest_integration_meta,
@@ -1961,6 +2485,15 @@ impl CreditLedger {
             |meta| meta.fee_income_parent_account_set_id,
         )
         .await?;
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.internal_account_sets.deferred_fee_income.id,
+            *deferred_fee_income_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.deferred_fee_income_parent_account_set_id,
+        )
+        .await?;
 
         self.attach_short_term_disbursed_receivable_account_sets(
             &mut op,
@@ -2642,6 +3175,7 @@ pub struct ChartOfAccountsIntegrationMeta {
     pub collateral_parent_account_set_id: CalaAccountSetId,
     pub interest_income_parent_account_set_id: CalaAccountSetId,
     pub fee_income_parent_account_set_id: CalaAccountSetId,
+    pub deferred_fee_income_parent_account_set_id: CalaAccountSetId,
 
     pub short_term_disbursed_integration_meta: ShortTermDisbursedIntegrationMeta,
     pub long_term_disbursed_integration_meta: LongTermDisbursedIntegrationMeta,
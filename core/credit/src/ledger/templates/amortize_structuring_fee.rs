@@ -0,0 +1,142 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{error::TxTemplateError, Params, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const CREDIT_FACILITY_AMORTIZE_STRUCTURING_FEE_CODE: &str =
+    "CREDIT_FACILITY_AMORTIZE_STRUCTURING_FEE";
+
+#[derive(Debug)]
+pub struct CreditFacilityAmortizeStructuringFeeParams {
+    pub journal_id: JournalId,
+    pub credit_facility_deferred_fee_income_account: CalaAccountId,
+    pub credit_facility_fee_income_account: CalaAccountId,
+    pub fee_amount: Decimal,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+}
+
+impl CreditFacilityAmortizeStructuringFeeParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("credit_facility_deferred_fee_income_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("credit_facility_fee_income_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("fee_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<CreditFacilityAmortizeStructuringFeeParams> for Params {
+    fn from(
+        CreditFacilityAmortizeStructuringFeeParams {
+            journal_id,
+            credit_facility_deferred_fee_income_account,
+            credit_facility_fee_income_account,
+            fee_amount,
+            external_id,
+            effective,
+        }: CreditFacilityAmortizeStructuringFeeParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert(
+            "credit_facility_deferred_fee_income_account",
+            credit_facility_deferred_fee_income_account,
+        );
+        params.insert(
+            "credit_facility_fee_income_account",
+            credit_facility_fee_income_account,
+        );
+        params.insert("fee_amount", fee_amount);
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+        params
+    }
+}
+
+pub struct CreditFacilityAmortizeStructuringFee;
+
+impl CreditFacilityAmortizeStructuringFee {
+    #[instrument(
+        name = "ledger.credit_facility_amortize_structuring_fee.init",
+        skip_all
+    )]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description("'Amortize deferred structuring fee for credit facility'")
+            .build()
+            .expect("Couldn't build TxInput");
+
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_facility_deferred_fee_income_account")
+                .units("params.fee_amount")
+                .currency("'USD'")
+                .entry_type("'AMORTIZE_STRUCTURING_FEE_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_facility_fee_income_account")
+                .units("params.fee_amount")
+                .currency("'USD'")
+                .entry_type("'AMORTIZE_STRUCTURING_FEE_CR'")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = CreditFacilityAmortizeStructuringFeeParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(CREDIT_FACILITY_AMORTIZE_STRUCTURING_FEE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
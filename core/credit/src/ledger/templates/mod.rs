@@ -1,23 +1,29 @@
 mod accrue_interest;
 mod activate_credit_facility;
 mod add_collateral;
+mod amortize_structuring_fee;
 mod cancel_disbursal;
+mod capitalize_accrued_interest;
 mod confirm_disbursal;
 mod initiate_disbursal;
 mod obligation_due_balance;
 mod obligation_overdue_balance;
 mod payment_allocation;
 mod post_accrued_interest;
+mod record_payment_from_deposit;
 mod remove_collateral;
 
 pub use accrue_interest::*;
 pub use activate_credit_facility::*;
 pub use add_collateral::*;
+pub use amortize_structuring_fee::*;
 pub use cancel_disbursal::*;
+pub use capitalize_accrued_interest::*;
 pub use confirm_disbursal::*;
 pub use initiate_disbursal::*;
 pub use obligation_due_balance::*;
 pub use obligation_overdue_balance::*;
 pub use payment_allocation::*;
 pub use post_accrued_interest::*;
+pub use record_payment_from_deposit::*;
 pub use remove_collateral::*;
@@ -0,0 +1,137 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{error::TxTemplateError, Params, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const RECORD_PAYMENT_FROM_DEPOSIT_CODE: &str = "RECORD_PAYMENT_FROM_DEPOSIT";
+
+#[derive(Debug)]
+pub struct RecordPaymentFromDepositParams {
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub amount: Decimal,
+    pub deposit_account_id: CalaAccountId,
+    pub receivable_account_id: CalaAccountId,
+    pub tx_ref: String,
+}
+
+impl RecordPaymentFromDepositParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("deposit_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("receivable_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<RecordPaymentFromDepositParams> for Params {
+    fn from(
+        RecordPaymentFromDepositParams {
+            journal_id,
+            currency,
+            amount,
+            deposit_account_id,
+            receivable_account_id,
+            tx_ref,
+        }: RecordPaymentFromDepositParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("external_id", tx_ref);
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("amount", amount);
+        params.insert("deposit_account_id", deposit_account_id);
+        params.insert("receivable_account_id", receivable_account_id);
+        params.insert("effective", chrono::Utc::now().date_naive());
+
+        params
+    }
+}
+
+pub struct RecordPaymentFromDeposit;
+
+impl RecordPaymentFromDeposit {
+    #[instrument(name = "ledger.record_payment_from_deposit.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description("'Repay an obligation from a deposit account'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_PAYMENT_FROM_DEPOSIT_DR'")
+                .currency("params.currency")
+                .account_id("params.deposit_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_PAYMENT_FROM_DEPOSIT_CR'")
+                .currency("params.currency")
+                .account_id("params.receivable_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = RecordPaymentFromDepositParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RECORD_PAYMENT_FROM_DEPOSIT_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -4,13 +4,16 @@ mod credit_facility;
 mod disbursal;
 pub mod error;
 mod event;
+mod exposure_limit;
 mod for_subject;
+mod forbearance;
 mod interest_accrual_cycle;
 mod jobs;
 pub mod ledger;
 mod obligation;
 mod payment;
 mod payment_allocation;
+mod pricing_grid;
 mod primitives;
 mod processes;
 mod publisher;
@@ -23,7 +26,7 @@ use audit::{AuditInfo, AuditSvc};
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
 use core_accounting::Chart;
-use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject, Customers};
+use core_customer::{CoreCustomerAction, CoreCustomerEvent, Customer, CustomerObject, Customers};
 use core_price::Price;
 use es_entity::Idempotent;
 use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
@@ -38,17 +41,25 @@ pub use credit_facility::*;
 pub use disbursal::{disbursal_cursor::*, *};
 use error::*;
 pub use event::*;
+pub use exposure_limit::{
+    error::ExposureLimitError, CustomerTypeExposureLimit, ExposureLimitPolicy, ExposureLimitScope,
+    ExposureLimitUtilization, ExposureLimits, ExposureLimitsConfig,
+};
 use for_subject::CreditFacilitiesForSubject;
+pub use forbearance::*;
 pub use interest_accrual_cycle::*;
 use jobs::*;
 pub use ledger::*;
 pub use obligation::{obligation_cursor::*, *};
 pub use payment::*;
 pub use payment_allocation::*;
+use pricing_grid::PricingGridRepo;
+pub use pricing_grid::{error::PricingGridError, PricingGrid, PricingGrids};
 pub use primitives::*;
 use processes::activate_credit_facility::*;
 pub use processes::approve_credit_facility::*;
 pub use processes::approve_disbursal::*;
+pub use processes::approve_forbearance::*;
 use publisher::CreditFacilityPublisher;
 pub use terms::*;
 
@@ -62,8 +73,11 @@ where
     authz: Perms,
     credit_facility_repo: CreditFacilityRepo<E>,
     disbursal_repo: DisbursalRepo,
+    forbearance_repo: ForbearanceRepo,
     payment_repo: PaymentRepo,
-    payment_allocation_repo: PaymentAllocationRepo,
+    pricing_grid_repo: PricingGridRepo,
+    pricing_grids: PricingGrids<Perms>,
+    payments: Payments<Perms, E>,
     governance: Governance<Perms, E>,
     customer: Customers<Perms, E>,
     ledger: CreditLedger,
@@ -72,7 +86,9 @@ where
     approve_disbursal: ApproveDisbursal<Perms, E>,
     cala: CalaLedger,
     approve_credit_facility: ApproveCreditFacility<Perms, E>,
+    approve_forbearance: ApproveForbearance<Perms, E>,
     obligations: Obligations<Perms, E>,
+    exposure_limits: ExposureLimits<Perms, E>,
 }
 
 impl<Perms, E> Clone for CoreCredit<Perms, E>
@@ -88,8 +104,11 @@ where
             credit_facility_repo: self.credit_facility_repo.clone(),
             obligations: self.obligations.clone(),
             disbursal_repo: self.disbursal_repo.clone(),
+            forbearance_repo: self.forbearance_repo.clone(),
             payment_repo: self.payment_repo.clone(),
-            payment_allocation_repo: self.payment_allocation_repo.clone(),
+            pricing_grid_repo: self.pricing_grid_repo.clone(),
+            pricing_grids: self.pricing_grids.clone(),
+            payments: self.payments.clone(),
             governance: self.governance.clone(),
             customer: self.customer.clone(),
             ledger: self.ledger.clone(),
@@ -98,6 +117,8 @@ where
             cala: self.cala.clone(),
             approve_disbursal: self.approve_disbursal.clone(),
             approve_credit_facility: self.approve_credit_facility.clone(),
+            approve_forbearance: self.approve_forbearance.clone(),
+            exposure_limits: self.exposure_limits.clone(),
         }
     }
 }
@@ -129,10 +150,28 @@ where
         let publisher = CreditFacilityPublisher::new(outbox);
         let credit_facility_repo = CreditFacilityRepo::new(pool, &publisher);
         let disbursal_repo = DisbursalRepo::new(pool);
+        let forbearance_repo = ForbearanceRepo::new(pool);
         let obligations = Obligations::new(pool, authz, cala, jobs, &publisher);
         let payment_repo = PaymentRepo::new(pool);
         let payment_allocation_repo = PaymentAllocationRepo::new(pool);
-        let ledger = CreditLedger::init(cala, journal_id).await?;
+        let pricing_grid_repo = PricingGridRepo::new(pool);
+        let pricing_grids = PricingGrids::new(authz, &pricing_grid_repo);
+        let payments = Payments::new(
+            &payment_repo,
+            &payment_allocation_repo,
+            &credit_facility_repo,
+            &obligations,
+        );
+        let ledger = CreditLedger::init(pool, cala, journal_id).await?;
+        let exposure_limits = ExposureLimits::init(
+            pool,
+            authz,
+            &credit_facility_repo,
+            customer,
+            &ledger,
+            config.exposure_limits.clone(),
+        )
+        .await?;
         let approve_disbursal = ApproveDisbursal::new(
             &disbursal_repo,
             &obligations,
@@ -145,10 +184,18 @@ where
 
         let approve_credit_facility =
             ApproveCreditFacility::new(&credit_facility_repo, authz.audit(), governance);
+        let approve_forbearance = ApproveForbearance::new(
+            &forbearance_repo,
+            &obligations,
+            &credit_facility_repo,
+            authz.audit(),
+            governance,
+        );
         let activate_credit_facility = ActivateCreditFacility::new(
             &obligations,
             &credit_facility_repo,
             &disbursal_repo,
+            &pricing_grid_repo,
             &ledger,
             price,
             jobs,
@@ -180,6 +227,8 @@ where
                 &ledger,
                 &obligations,
                 &credit_facility_repo,
+                &pricing_grid_repo,
+                price,
                 jobs,
                 authz.audit(),
             ),
@@ -195,6 +244,34 @@ where
                 authz.audit(),
             ),
         );
+        jobs.add_initializer(
+            obligation_auto_debit::CreditFacilityProcessingJobInitializer::<Perms, E>::new(
+                &ledger,
+                &obligations,
+                &credit_facility_repo,
+                &payments,
+                authz.audit(),
+            ),
+        );
+        jobs.add_initializer(
+            structuring_fee_amortization::CreditFacilityProcessingJobInitializer::<Perms, E>::new(
+                &ledger,
+                &credit_facility_repo,
+                authz.audit(),
+            ),
+        );
+        jobs.add_initializer_and_spawn_unique(
+            exposure_account_sets::ExposureAccountSetsJobInitializer::<Perms, E>::new(
+                &ledger,
+                &credit_facility_repo,
+                customer,
+            ),
+            exposure_account_sets::ExposureAccountSetsJobConfig::<Perms, E> {
+                created_before: crate::time::now(),
+                _phantom: std::marker::PhantomData,
+            },
+        )
+        .await?;
         jobs.add_initializer_and_spawn_unique(
             CreditFacilityApprovalJobInitializer::new(outbox, &approve_credit_facility),
             CreditFacilityApprovalJobConfig::<Perms, E>::new(),
@@ -205,6 +282,11 @@ where
             DisbursalApprovalJobConfig::<Perms, E>::new(),
         )
         .await?;
+        jobs.add_initializer_and_spawn_unique(
+            ForbearanceApprovalJobInitializer::new(outbox, &approve_forbearance),
+            ForbearanceApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
         jobs.add_initializer_and_spawn_unique(
             CreditFacilityActivationJobInitializer::new(outbox, &activate_credit_facility),
             CreditFacilityActivationJobConfig::<Perms, E>::new(),
@@ -214,6 +296,7 @@ where
             .init_policy(APPROVE_CREDIT_FACILITY_PROCESS)
             .await;
         let _ = governance.init_policy(APPROVE_DISBURSAL_PROCESS).await;
+        let _ = governance.init_policy(APPROVE_FORBEARANCE_PROCESS).await;
 
         Ok(Self {
             authz: authz.clone(),
@@ -221,8 +304,11 @@ where
             credit_facility_repo,
             obligations,
             disbursal_repo,
+            forbearance_repo,
             payment_repo,
-            payment_allocation_repo,
+            pricing_grid_repo,
+            pricing_grids,
+            payments,
             governance: governance.clone(),
             ledger,
             price: price.clone(),
@@ -230,6 +316,8 @@ where
             cala: cala.clone(),
             approve_disbursal,
             approve_credit_facility,
+            approve_forbearance,
+            exposure_limits,
         })
     }
 
@@ -237,6 +325,14 @@ where
         &self.obligations
     }
 
+    pub fn exposure_limits(&self) -> &ExposureLimits<Perms, E> {
+        &self.exposure_limits
+    }
+
+    pub fn pricing_grids(&self) -> &PricingGrids<Perms> {
+        &self.pricing_grids
+    }
+
     pub async fn subject_can_create(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -269,6 +365,7 @@ where
             &self.credit_facility_repo,
             &self.disbursal_repo,
             &self.payment_repo,
+            &self.pricing_grid_repo,
             &self.ledger,
         ))
     }
@@ -297,6 +394,8 @@ where
             return Err(CoreCreditError::CustomerNotActive);
         }
 
+        self.pricing_grid_repo.find_for_terms(&terms).await?;
+
         let id = CreditFacilityId::new();
         let new_credit_facility = NewCreditFacility::builder()
             .id(id)
@@ -311,6 +410,9 @@ where
             .expect("could not build new credit facility");
 
         let mut db = self.credit_facility_repo.begin_op().await?;
+        self.exposure_limits
+            .check_in_op(&mut db, customer.id, customer.customer_type, amount)
+            .await?;
         self.governance
             .start_process(&mut db, id, id.to_string(), APPROVE_CREDIT_FACILITY_PROCESS)
             .await?;
@@ -326,7 +428,7 @@ where
                 credit_facility.id,
                 credit_facility.account_ids,
                 customer.customer_type,
-                terms.duration.duration_type(),
+                credit_facility.terms.duration.duration_type(),
             )
             .await?;
 
@@ -389,6 +491,47 @@ where
         Ok(balances)
     }
 
+    #[instrument(name = "credit_facility.customer_exposure_limits", skip(self), err)]
+    pub async fn customer_exposure_limits(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        customer_id: impl Into<CustomerId> + std::fmt::Debug + Copy,
+    ) -> Result<Vec<ExposureLimitUtilization>, CoreCreditError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_LIST,
+            )
+            .await?;
+
+        let customer = self
+            .customer
+            .find_by_id(sub, customer_id)
+            .await?
+            .ok_or(CoreCreditError::CustomerNotFound)?;
+
+        self.exposure_limits
+            .customer_utilizations(customer.id, customer.customer_type)
+            .await
+    }
+
+    #[instrument(name = "credit_facility.portfolio_exposure_limits", skip(self), err)]
+    pub async fn portfolio_exposure_limits(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<Vec<ExposureLimitUtilization>, CoreCreditError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_LIST,
+            )
+            .await?;
+
+        self.exposure_limits.portfolio_utilizations().await
+    }
+
     pub async fn subject_can_initiate_disbursal(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -458,6 +601,9 @@ where
         }
 
         let mut db = self.credit_facility_repo.begin_op().await?;
+        self.exposure_limits
+            .check_in_op(&mut db, customer.id, customer.customer_type, amount)
+            .await?;
         let disbursal_id = DisbursalId::new();
         let new_disbursal = NewDisbursal::builder()
             .id(disbursal_id)
@@ -555,6 +701,124 @@ where
             .await
     }
 
+    pub async fn subject_can_initiate_forbearance(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::FORBEARANCE_INITIATE,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "credit_facility.initiate_forbearance", skip(self), err)]
+    pub async fn initiate_forbearance(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+        starts_at: chrono::DateTime<chrono::Utc>,
+        ends_at: chrono::DateTime<chrono::Utc>,
+        capitalize_interest: bool,
+    ) -> Result<Forbearance, CoreCreditError> {
+        let audit_info = self
+            .subject_can_initiate_forbearance(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        if starts_at >= ends_at {
+            return Err(
+                forbearance::error::ForbearanceError::InvalidPeriod(starts_at, ends_at).into(),
+            );
+        }
+
+        let facility = self
+            .credit_facility_repo
+            .find_by_id(credit_facility_id.into())
+            .await?;
+        let pending = self
+            .forbearance_repo
+            .list_for_credit_facility_id_by_created_at(
+                facility.id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities
+            .into_iter()
+            .filter(|forbearance| forbearance.is_pending())
+            .map(|forbearance| forbearance.period())
+            .collect::<Vec<_>>();
+        facility.check_forbearance_period(starts_at, ends_at, &pending)?;
+
+        let mut db = self.forbearance_repo.begin_op().await?;
+        let forbearance_id = ForbearanceId::new();
+        let new_forbearance = NewForbearance::builder()
+            .id(forbearance_id)
+            .approval_process_id(ApprovalProcessId::from(forbearance_id))
+            .credit_facility_id(facility.id)
+            .starts_at(starts_at)
+            .ends_at(ends_at)
+            .capitalize_interest(capitalize_interest)
+            .audit_info(audit_info)
+            .build()
+            .expect("could not build new forbearance");
+
+        self.governance
+            .start_process(
+                &mut db,
+                new_forbearance.approval_process_id,
+                new_forbearance.approval_process_id.to_string(),
+                APPROVE_FORBEARANCE_PROCESS,
+            )
+            .await?;
+        let forbearance = self
+            .forbearance_repo
+            .create_in_op(&mut db, new_forbearance)
+            .await?;
+
+        db.commit().await?;
+
+        Ok(forbearance)
+    }
+
+    #[instrument(name = "credit_facility.list_forbearances", skip(self), err)]
+    pub async fn list_forbearances_for_credit_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+    ) -> Result<Vec<Forbearance>, CoreCreditError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::FORBEARANCE_LIST,
+            )
+            .await?;
+
+        Ok(self
+            .forbearance_repo
+            .list_for_credit_facility_id_by_created_at(
+                credit_facility_id.into(),
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    pub async fn ensure_up_to_date_forbearance_status(
+        &self,
+        forbearance: &Forbearance,
+    ) -> Result<Option<Forbearance>, CoreCreditError> {
+        self.approve_forbearance.execute_from_svc(forbearance).await
+    }
+
     pub async fn subject_can_update_collateral(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -614,6 +878,115 @@ where
         Ok(credit_facility)
     }
 
+    #[es_entity::retry_on_concurrent_modification]
+    #[instrument(name = "credit_facility.add_collateral", skip(self), err)]
+    pub async fn add_collateral(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+        amount: Satoshis,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let audit_info = self
+            .subject_can_update_collateral(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let price = self.price.usd_cents_per_btc().await?;
+
+        let mut credit_facility = self
+            .credit_facility_repo
+            .find_by_id(credit_facility_id)
+            .await?;
+
+        let balances = self
+            .ledger
+            .get_credit_facility_balance(credit_facility.account_ids)
+            .await?;
+        let mut db = self.credit_facility_repo.begin_op().await?;
+        let credit_facility_collateral_update = credit_facility.record_collateral_update(
+            credit_facility.collateral() + amount,
+            audit_info,
+            price,
+            self.config.upgrade_buffer_cvl_pct,
+            balances,
+        )?;
+        self.credit_facility_repo
+            .update_in_op(&mut db, &mut credit_facility)
+            .await?;
+
+        self.ledger
+            .update_credit_facility_collateral(db, credit_facility_collateral_update)
+            .await?;
+
+        Ok(credit_facility)
+    }
+
+    pub async fn subject_can_update_auto_debit_mandate(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_UPDATE_AUTO_DEBIT_MANDATE,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "credit_facility.enable_auto_debit", skip(self), err)]
+    pub async fn enable_auto_debit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+        deposit_account_id: impl Into<CalaAccountId> + std::fmt::Debug,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        self.update_auto_debit_mandate(sub, credit_facility_id, Some(deposit_account_id.into()))
+            .await
+    }
+
+    #[instrument(name = "credit_facility.disable_auto_debit", skip(self), err)]
+    pub async fn disable_auto_debit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        self.update_auto_debit_mandate(sub, credit_facility_id, None)
+            .await
+    }
+
+    #[es_entity::retry_on_concurrent_modification]
+    async fn update_auto_debit_mandate(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+        deposit_account_id: Option<CalaAccountId>,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let audit_info = self
+            .subject_can_update_auto_debit_mandate(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut credit_facility = self
+            .credit_facility_repo
+            .find_by_id(credit_facility_id)
+            .await?;
+
+        if credit_facility
+            .update_auto_debit_mandate(deposit_account_id, audit_info)?
+            .did_execute()
+        {
+            self.credit_facility_repo
+                .update(&mut credit_facility)
+                .await?;
+        }
+
+        Ok(credit_facility)
+    }
+
     pub async fn subject_can_record_payment(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -648,60 +1021,70 @@ where
             .await?
             .expect("audit info missing");
 
-        let new_payment = NewPayment::builder()
-            .id(PaymentId::new())
-            .amount(amount)
-            .credit_facility_id(credit_facility_id)
-            .build()
-            .expect("could not build new payment");
-        let mut payment = self.payment_repo.create_in_op(&mut db, new_payment).await?;
-
-        let res = self
-            .obligations
-            .allocate_payment_in_op(
+        let allocations = self
+            .payments
+            .record_in_op(
                 &mut db,
-                credit_facility_id,
-                payment.id,
+                &mut credit_facility,
+                PaymentId::new(),
                 amount,
-                audit_info.clone(),
+                audit_info,
             )
             .await?;
 
-        payment
-            .record_allocated(
-                res.disbursed_amount(),
-                res.interest_amount(),
-                audit_info.clone(),
-            )
-            .did_execute();
-        self.payment_repo
-            .update_in_op(&mut db, &mut payment)
+        self.ledger
+            .record_obligation_repayments(db, allocations)
             .await?;
 
-        let allocations = self
-            .payment_allocation_repo
-            .create_all_in_op(&mut db, res.allocations)
+        Ok(credit_facility)
+    }
+
+    /// Repays the facility out of a deposit account. The amount is capped at what the
+    /// deposit account has available and at what is outstanding on the facility, and the
+    /// call fails if that leaves nothing to collect.
+    #[es_entity::retry_on_concurrent_modification]
+    #[instrument(name = "credit_facility.record_payment_from_deposit", skip(self), err)]
+    pub async fn record_payment_from_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+        deposit_account_id: impl Into<CalaAccountId> + std::fmt::Debug + Copy,
+        amount: UsdCents,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let audit_info = self
+            .subject_can_record_payment(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut credit_facility = self
+            .credit_facility_repo
+            .find_by_id(credit_facility_id)
             .await?;
 
-        let now = crate::time::now();
-        for allocation in &allocations {
-            credit_facility
-                .update_balance_from_payment(
-                    allocation.id,
-                    allocation.ledger_tx_id,
-                    allocation.obligation_type,
-                    allocation.amount,
-                    now,
-                    audit_info.clone(),
-                )
-                .did_execute();
+        let available = self
+            .ledger
+            .deposit_account_available_balance(deposit_account_id.into())
+            .await?;
+        let outstanding = self.outstanding(&credit_facility).await?;
+        let amount = std::cmp::min(amount, std::cmp::min(available, outstanding));
+        if amount == UsdCents::ZERO {
+            return Err(CoreCreditError::NothingToCollectFromDeposit);
         }
-        self.credit_facility_repo
-            .update_in_op(&mut db, &mut credit_facility)
+
+        let mut db = self.credit_facility_repo.begin_op().await?;
+        let allocations = self
+            .payments
+            .record_in_op(
+                &mut db,
+                &mut credit_facility,
+                PaymentId::new(),
+                amount,
+                audit_info,
+            )
             .await?;
 
         self.ledger
-            .record_obligation_repayments(db, allocations)
+            .record_obligation_repayments_from_deposit(db, allocations, deposit_account_id.into())
             .await?;
 
         Ok(credit_facility)
@@ -936,12 +1319,22 @@ where
             return Ok(credit_facility);
         };
 
+        let customer_type = self
+            .customer
+            .find_all::<Customer>(&[credit_facility.customer_id])
+            .await?
+            .remove(&credit_facility.customer_id)
+            .ok_or(CoreCreditError::CustomerNotFound)?
+            .customer_type;
+
         let mut db = self.credit_facility_repo.begin_op().await?;
         self.credit_facility_repo
             .update_in_op(&mut db, &mut credit_facility)
             .await?;
 
-        self.ledger.complete_credit_facility(db, completion).await?;
+        self.ledger
+            .complete_credit_facility(db, completion, customer_type)
+            .await?;
 
         Ok(credit_facility)
     }
@@ -1000,6 +1393,13 @@ where
         Ok(self.disbursal_repo.find_all(ids).await?)
     }
 
+    pub async fn find_all_forbearances<T: From<Forbearance>>(
+        &self,
+        ids: &[ForbearanceId],
+    ) -> Result<HashMap<ForbearanceId, T>, CoreCreditError> {
+        Ok(self.forbearance_repo.find_all(ids).await?)
+    }
+
     pub async fn can_be_completed(&self, entity: &CreditFacility) -> Result<bool, CoreCreditError> {
         Ok(self.outstanding(entity).await?.is_zero())
     }
@@ -1072,6 +1472,8 @@ where
             chart.account_set_id_from_code(&config.chart_of_account_interest_income_parent_code)?;
         let fee_income_parent_account_set_id =
             chart.account_set_id_from_code(&config.chart_of_account_fee_income_parent_code)?;
+        let deferred_fee_income_parent_account_set_id = chart
+            .account_set_id_from_code(&config.chart_of_account_deferred_fee_income_parent_code)?;
 
         let short_term_individual_disbursed_receivable_parent_account_set_id = chart
             .account_set_id_from_code(
@@ -1255,6 +1657,7 @@ where
             collateral_parent_account_set_id,
             interest_income_parent_account_set_id,
             fee_income_parent_account_set_id,
+            deferred_fee_income_parent_account_set_id,
 
             short_term_disbursed_integration_meta: ShortTermDisbursedIntegrationMeta {
                 short_term_individual_disbursed_receivable_parent_account_set_id,
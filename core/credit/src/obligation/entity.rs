@@ -6,8 +6,10 @@ use audit::AuditInfo;
 use es_entity::*;
 
 use crate::{
+    forbearance::ForbearancePeriod,
     primitives::{
-        CalaAccountId, LedgerTxId, ObligationId, ObligationType, PaymentAllocationId, UsdCents,
+        CalaAccountId, ForbearanceId, LedgerTxId, ObligationId, ObligationType,
+        PaymentAllocationId, UsdCents,
     },
     CreditFacilityId,
 };
@@ -103,6 +105,13 @@ pub enum ObligationEvent {
         tx_id: LedgerTxId,
         audit_info: AuditInfo,
     },
+    Deferred {
+        forbearance_id: ForbearanceId,
+        due_date: DateTime<Utc>,
+        overdue_date: DateTime<Utc>,
+        defaulted_date: Option<DateTime<Utc>>,
+        audit_info: AuditInfo,
+    },
     // TODO: Remove and find from PaymentAllocation entities
     PaymentRecorded {
         payment_allocation_id: PaymentAllocationId,
@@ -148,20 +157,32 @@ impl Obligation {
     }
 
     pub fn due_at(&self) -> DateTime<Utc> {
-        self.events
-            .iter_all()
-            .find_map(|e| match e {
-                ObligationEvent::Initialized { due_date, .. } => Some(*due_date),
-                _ => None,
-            })
-            .expect("Entity was not Initialized")
+        let (due_date, _, _) = self.dates();
+        due_date
     }
 
     pub fn overdue_at(&self) -> DateTime<Utc> {
+        let (_, overdue_date, _) = self.dates();
+        overdue_date
+    }
+
+    fn dates(&self) -> (DateTime<Utc>, DateTime<Utc>, Option<DateTime<Utc>>) {
         self.events
             .iter_all()
+            .rev()
             .find_map(|e| match e {
-                ObligationEvent::Initialized { overdue_date, .. } => Some(*overdue_date),
+                ObligationEvent::Initialized {
+                    due_date,
+                    overdue_date,
+                    defaulted_date,
+                    ..
+                }
+                | ObligationEvent::Deferred {
+                    due_date,
+                    overdue_date,
+                    defaulted_date,
+                    ..
+                } => Some((*due_date, *overdue_date, *defaulted_date)),
                 _ => None,
             })
             .expect("Entity was not Initialized")
@@ -255,19 +276,7 @@ impl Obligation {
     }
 
     pub fn expected_status(&self) -> ObligationStatus {
-        let (due_date, overdue_date, defaulted_date) = self
-            .events
-            .iter_all()
-            .find_map(|e| match e {
-                ObligationEvent::Initialized {
-                    due_date,
-                    overdue_date,
-                    defaulted_date,
-                    ..
-                } => Some((*due_date, *overdue_date, *defaulted_date)),
-                _ => None,
-            })
-            .expect("Entity was not Initialized");
+        let (due_date, overdue_date, defaulted_date) = self.dates();
 
         let now = crate::time::now();
 
@@ -370,6 +379,54 @@ impl Obligation {
         Ok(Idempotent::Executed(res))
     }
 
+    pub(crate) fn defer(
+        &mut self,
+        forbearance: &ForbearancePeriod,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ObligationEvent::Deferred {
+                forbearance_id,
+                ..
+            } if forbearance.forbearance_id == *forbearance_id
+        );
+
+        let status = self.status();
+        if status == ObligationStatus::Paid {
+            return Idempotent::Ignored;
+        }
+
+        let (due_date, overdue_date, defaulted_date) = self.dates();
+        let due_pending = status == ObligationStatus::NotYetDue;
+        let overdue_pending = status != ObligationStatus::Overdue;
+
+        let affected = (due_pending && forbearance.covers(due_date))
+            || (overdue_pending && forbearance.covers(overdue_date))
+            || defaulted_date.is_some_and(|date| forbearance.covers(date));
+        if !affected {
+            return Idempotent::Ignored;
+        }
+
+        self.events.push(ObligationEvent::Deferred {
+            forbearance_id: forbearance.forbearance_id,
+            due_date: if due_pending {
+                forbearance.defer(due_date)
+            } else {
+                due_date
+            },
+            overdue_date: if overdue_pending {
+                forbearance.defer(overdue_date)
+            } else {
+                overdue_date
+            },
+            defaulted_date: defaulted_date.map(|date| forbearance.defer(date)),
+            audit_info,
+        });
+
+        Idempotent::Executed(())
+    }
+
     pub(crate) fn record_payment(
         &mut self,
         payment_allocation_id: PaymentAllocationId,
@@ -424,6 +481,7 @@ impl TryFromEvents<ObligationEvent> for Obligation {
                 }
                 ObligationEvent::DueRecorded { .. } => (),
                 ObligationEvent::OverdueRecorded { .. } => (),
+                ObligationEvent::Deferred { .. } => (),
                 ObligationEvent::PaymentRecorded { .. } => (),
                 ObligationEvent::Completed { .. } => (),
             }
@@ -462,6 +520,13 @@ impl NewObligation {
         NewObligationBuilder::default()
     }
 
+    pub(crate) fn reschedule(&mut self, due_date: DateTime<Utc>) {
+        let shift = due_date - self.due_date;
+        self.due_date = due_date;
+        self.overdue_date += shift;
+        self.defaulted_date = self.defaulted_date.map(|date| date + shift);
+    }
+
     pub(super) fn reference(&self) -> String {
         match self.reference.as_deref() {
             None => self.id.to_string(),
@@ -560,4 +625,63 @@ mod test {
             Err(ObligationError::InvalidStatusTransitionToOverdue)
         ));
     }
+
+    fn forbearance(starts_at: DateTime<Utc>, days: i64) -> ForbearancePeriod {
+        ForbearancePeriod {
+            forbearance_id: ForbearanceId::new(),
+            starts_at,
+            ends_at: starts_at + chrono::Duration::days(days),
+            capitalize_interest: false,
+        }
+    }
+
+    #[test]
+    fn defer_shifts_pending_dates() {
+        let mut obligation = obligation_from(initial_events());
+        let due_at = obligation.due_at();
+        let overdue_at = obligation.overdue_at();
+        let forbearance = forbearance(due_at - chrono::Duration::days(1), 30);
+
+        assert!(obligation
+            .defer(&forbearance, dummy_audit_info())
+            .did_execute());
+        assert_eq!(obligation.due_at(), due_at + chrono::Duration::days(30));
+        assert_eq!(
+            obligation.overdue_at(),
+            overdue_at + chrono::Duration::days(30)
+        );
+        assert_eq!(obligation.expected_status(), ObligationStatus::NotYetDue);
+
+        assert!(obligation
+            .defer(&forbearance, dummy_audit_info())
+            .was_ignored());
+    }
+
+    #[test]
+    fn defer_keeps_recorded_due_date() {
+        let mut obligation = obligation_from(initial_events());
+        obligation.record_due(dummy_audit_info()).did_execute();
+        let due_at = obligation.due_at();
+        let overdue_at = obligation.overdue_at();
+        let forbearance = forbearance(overdue_at, 30);
+
+        assert!(obligation
+            .defer(&forbearance, dummy_audit_info())
+            .did_execute());
+        assert_eq!(obligation.due_at(), due_at);
+        assert_eq!(
+            obligation.overdue_at(),
+            overdue_at + chrono::Duration::days(30)
+        );
+    }
+
+    #[test]
+    fn defer_ignored_outside_forbearance_period() {
+        let mut obligation = obligation_from(initial_events());
+        let forbearance = forbearance(obligation.overdue_at() + chrono::Duration::days(1), 30);
+
+        assert!(obligation
+            .defer(&forbearance, dummy_audit_info())
+            .was_ignored());
+    }
 }
@@ -13,6 +13,7 @@ use std::collections::HashMap;
 
 use crate::{
     event::CoreCreditEvent,
+    forbearance::ForbearancePeriod,
     jobs::obligation_due,
     payment_allocation::NewPaymentAllocation,
     primitives::{
@@ -141,6 +142,26 @@ where
         Ok(PaymentAllocationResult::new(new_allocations))
     }
 
+    pub(crate) async fn defer_facility_obligations_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        forbearance: &ForbearancePeriod,
+        audit_info: AuditInfo,
+    ) -> Result<(), ObligationError> {
+        let mut obligations = self.facility_obligations(credit_facility_id).await?;
+        for obligation in obligations.values_mut() {
+            if obligation
+                .defer(forbearance, audit_info.clone())
+                .did_execute()
+            {
+                self.repo.update_in_op(db, obligation).await?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn check_facility_obligations_status_updated(
         &self,
         credit_facility_id: CreditFacilityId,
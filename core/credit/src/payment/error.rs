@@ -8,6 +8,12 @@ pub enum PaymentError {
     EsEntityError(es_entity::EsEntityError),
     #[error("PaymentError - CursorDestructureError: {0}")]
     CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("PaymentError - ObligationError: {0}")]
+    ObligationError(#[from] crate::obligation::error::ObligationError),
+    #[error("PaymentError - PaymentAllocationError: {0}")]
+    PaymentAllocationError(#[from] crate::payment_allocation::error::PaymentAllocationError),
+    #[error("PaymentError - CreditFacilityError: {0}")]
+    CreditFacilityError(#[from] crate::credit_facility::error::CreditFacilityError),
 }
 
 es_entity::from_es_entity_error!(PaymentError);
@@ -2,7 +2,131 @@ mod entity;
 pub mod error;
 mod repo;
 
+use audit::{AuditInfo, AuditSvc};
+use authz::PermissionCheck;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    credit_facility::{CreditFacility, CreditFacilityRepo},
+    event::CoreCreditEvent,
+    obligation::Obligations,
+    payment_allocation::{PaymentAllocation, PaymentAllocationRepo},
+    primitives::{CoreCreditAction, CoreCreditObject, PaymentId, UsdCents},
+};
+
 pub(super) use entity::*;
+use error::PaymentError;
 pub(super) use repo::*;
 
 pub use entity::Payment;
+
+pub struct Payments<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    repo: PaymentRepo,
+    payment_allocation_repo: PaymentAllocationRepo,
+    credit_facility_repo: CreditFacilityRepo<E>,
+    obligations: Obligations<Perms, E>,
+}
+
+impl<Perms, E> Clone for Payments<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            payment_allocation_repo: self.payment_allocation_repo.clone(),
+            credit_facility_repo: self.credit_facility_repo.clone(),
+            obligations: self.obligations.clone(),
+        }
+    }
+}
+
+impl<Perms, E> Payments<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    pub(crate) fn new(
+        repo: &PaymentRepo,
+        payment_allocation_repo: &PaymentAllocationRepo,
+        credit_facility_repo: &CreditFacilityRepo<E>,
+        obligations: &Obligations<Perms, E>,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            payment_allocation_repo: payment_allocation_repo.clone(),
+            credit_facility_repo: credit_facility_repo.clone(),
+            obligations: obligations.clone(),
+        }
+    }
+
+    /// Allocates a payment to the facility's outstanding obligations and updates the
+    /// facility balances. The returned allocations still have to be posted to the ledger.
+    pub(crate) async fn record_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        credit_facility: &mut CreditFacility,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        audit_info: AuditInfo,
+    ) -> Result<Vec<PaymentAllocation>, PaymentError> {
+        let new_payment = NewPayment::builder()
+            .id(payment_id)
+            .amount(amount)
+            .credit_facility_id(credit_facility.id)
+            .build()
+            .expect("could not build new payment");
+        let mut payment = self.repo.create_in_op(db, new_payment).await?;
+
+        let res = self
+            .obligations
+            .allocate_payment_in_op(
+                db,
+                credit_facility.id,
+                payment.id,
+                amount,
+                audit_info.clone(),
+            )
+            .await?;
+
+        payment
+            .record_allocated(
+                res.disbursed_amount(),
+                res.interest_amount(),
+                audit_info.clone(),
+            )
+            .did_execute();
+        self.repo.update_in_op(db, &mut payment).await?;
+
+        let allocations = self
+            .payment_allocation_repo
+            .create_all_in_op(db, res.allocations)
+            .await?;
+
+        let now = crate::time::now();
+        for allocation in &allocations {
+            credit_facility
+                .update_balance_from_payment(
+                    allocation.id,
+                    allocation.ledger_tx_id,
+                    allocation.obligation_type,
+                    allocation.amount,
+                    now,
+                    audit_info.clone(),
+                )
+                .did_execute();
+        }
+        self.credit_facility_repo
+            .update_in_op(db, credit_facility)
+            .await?;
+
+        Ok(allocations)
+    }
+}
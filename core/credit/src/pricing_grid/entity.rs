@@ -0,0 +1,165 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::{
+    primitives::*,
+    terms::{AnnualRatePct, CVLPct, PricingGridBand},
+};
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "PricingGridId")]
+pub enum PricingGridEvent {
+    Initialized {
+        id: PricingGridId,
+        bands: Vec<PricingGridBand>,
+        audit_info: AuditInfo,
+    },
+}
+
+/// Grids are immutable so that terms referencing one keep pricing the same way.
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct PricingGrid {
+    pub id: PricingGridId,
+    bands: Vec<PricingGridBand>,
+    pub(super) events: EntityEvents<PricingGridEvent>,
+}
+
+impl TryFromEvents<PricingGridEvent> for PricingGrid {
+    fn try_from_events(events: EntityEvents<PricingGridEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = PricingGridBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                PricingGridEvent::Initialized { id, bands, .. } => {
+                    builder = builder.id(*id).bands(bands.clone())
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+impl PricingGrid {
+    pub fn bands(&self) -> &[PricingGridBand] {
+        &self.bands
+    }
+
+    pub fn band_for(&self, cvl: CVLPct) -> Option<&PricingGridBand> {
+        self.bands.iter().rev().find(|band| cvl >= band.min_cvl)
+    }
+
+    pub fn rate_for(&self, base_rate: AnnualRatePct, cvl: CVLPct) -> AnnualRatePct {
+        match self.band_for(cvl) {
+            Some(band) => base_rate.adjusted_by(band.rate_adjustment),
+            None => base_rate,
+        }
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewPricingGrid {
+    #[builder(setter(into))]
+    pub(super) id: PricingGridId,
+    #[builder(setter(custom))]
+    pub(super) bands: Vec<PricingGridBand>,
+    #[builder(setter(into))]
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewPricingGrid {
+    pub fn builder() -> NewPricingGridBuilder {
+        NewPricingGridBuilder::default()
+    }
+}
+
+impl NewPricingGridBuilder {
+    pub fn bands(mut self, bands: impl IntoIterator<Item = PricingGridBand>) -> Self {
+        let mut bands: Vec<_> = bands.into_iter().collect();
+        bands.sort_by(|a, b| a.min_cvl.cmp(&b.min_cvl));
+        self.bands = Some(bands);
+        self
+    }
+}
+
+impl IntoEvents<PricingGridEvent> for NewPricingGrid {
+    fn into_events(self) -> EntityEvents<PricingGridEvent> {
+        EntityEvents::init(
+            self.id,
+            [PricingGridEvent::Initialized {
+                id: self.id,
+                bands: self.bands,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use audit::AuditEntryId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn pricing_grid() -> PricingGrid {
+        let new_grid = NewPricingGrid::builder()
+            .id(PricingGridId::new())
+            .bands([
+                PricingGridBand {
+                    min_cvl: CVLPct::new(200),
+                    rate_adjustment: AnnualRatePct::from(dec!(-2)),
+                },
+                PricingGridBand {
+                    min_cvl: CVLPct::new(160),
+                    rate_adjustment: AnnualRatePct::from(dec!(-1)),
+                },
+            ])
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        PricingGrid::try_from_events(new_grid.into_events()).unwrap()
+    }
+
+    #[test]
+    fn picks_highest_matching_band() {
+        let grid = pricing_grid();
+        let base_rate = AnnualRatePct::from(dec!(12));
+
+        assert_eq!(grid.rate_for(base_rate, CVLPct::new(150)), base_rate);
+        assert_eq!(
+            grid.rate_for(base_rate, CVLPct::new(160)),
+            AnnualRatePct::from(dec!(11))
+        );
+        assert_eq!(
+            grid.rate_for(base_rate, CVLPct::new(199)),
+            AnnualRatePct::from(dec!(11))
+        );
+        assert_eq!(
+            grid.rate_for(base_rate, CVLPct::new(250)),
+            AnnualRatePct::from(dec!(10))
+        );
+    }
+
+    #[test]
+    fn rate_does_not_go_below_zero() {
+        let grid = pricing_grid();
+        let base_rate = AnnualRatePct::from(dec!(1));
+
+        assert_eq!(
+            grid.rate_for(base_rate, CVLPct::new(250)),
+            AnnualRatePct::from(dec!(0))
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PricingGridError {
+    #[error("PricingGridError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("PricingGridError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("PricingGridError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("PricingGridError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+}
+
+es_entity::from_es_entity_error!(PricingGridError);
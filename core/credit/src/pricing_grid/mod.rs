@@ -0,0 +1,94 @@
+mod entity;
+pub mod error;
+mod repo;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use tracing::instrument;
+
+use crate::{primitives::*, terms::PricingGridBand};
+
+pub use entity::PricingGrid;
+pub(super) use entity::*;
+use error::*;
+pub(super) use repo::*;
+
+pub struct PricingGrids<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Perms,
+    repo: PricingGridRepo,
+}
+
+impl<Perms> Clone for PricingGrids<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+}
+
+impl<Perms> PricingGrids<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    pub(super) fn new(authz: &Perms, repo: &PricingGridRepo) -> Self {
+        Self {
+            authz: authz.clone(),
+            repo: repo.clone(),
+        }
+    }
+
+    #[instrument(name = "credit.pricing_grids.create", skip(self), err)]
+    pub async fn create(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        bands: impl IntoIterator<Item = PricingGridBand> + std::fmt::Debug,
+    ) -> Result<PricingGrid, PricingGridError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_pricing_grids(),
+                CoreCreditAction::PRICING_GRID_CREATE,
+            )
+            .await?;
+
+        let new_grid = NewPricingGrid::builder()
+            .id(PricingGridId::new())
+            .bands(bands)
+            .audit_info(audit_info)
+            .build()
+            .expect("could not build new pricing grid");
+        self.repo.create(new_grid).await
+    }
+
+    #[instrument(name = "credit.pricing_grids.find_by_id", skip(self), err)]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<PricingGridId> + std::fmt::Debug,
+    ) -> Result<Option<PricingGrid>, PricingGridError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::pricing_grid(id),
+                CoreCreditAction::PRICING_GRID_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(grid) => Ok(Some(grid)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::{primitives::*, terms::TermValues};
+
+use super::{entity::*, error::PricingGridError};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(entity = "PricingGrid", err = "PricingGridError", tbl_prefix = "core")]
+pub struct PricingGridRepo {
+    pool: PgPool,
+}
+
+impl PricingGridRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+
+    pub async fn find_for_terms(
+        &self,
+        terms: &TermValues,
+    ) -> Result<Option<PricingGrid>, PricingGridError> {
+        match terms.pricing_grid_id {
+            Some(id) => Ok(Some(self.find_by_id(id).await?)),
+            None => Ok(None),
+        }
+    }
+}
@@ -21,10 +21,14 @@ es_entity::entity_id! {
     PaymentAllocationId,
     ChartOfAccountsIntegrationConfigId,
     ObligationId,
-    InterestAccrualCycleId;
+    InterestAccrualCycleId,
+    ForbearanceId,
+    ExposureLimitPolicyId,
+    PricingGridId;
 
     CreditFacilityId => governance::ApprovalProcessId,
     DisbursalId => governance::ApprovalProcessId,
+    ForbearanceId => governance::ApprovalProcessId,
 
     CreditFacilityId => job::JobId,
     InterestAccrualCycleId => job::JobId,
@@ -44,6 +48,7 @@ pub enum ObligationType {
 pub enum BalanceUpdatedType {
     Disbursal,
     InterestAccrual,
+    InterestCapitalization,
 }
 
 impl From<ObligationType> for BalanceUpdatedType {
@@ -65,6 +70,8 @@ pub type CreditFacilityAllOrOne = AllOrOne<CreditFacilityId>;
 pub type ChartOfAccountsIntegrationConfigAllOrOne = AllOrOne<ChartOfAccountsIntegrationConfigId>;
 pub type DisbursalAllOrOne = AllOrOne<DisbursalId>;
 pub type ObligationAllOrOne = AllOrOne<ObligationId>;
+pub type ExposureLimitPolicyAllOrOne = AllOrOne<ExposureLimitPolicyId>;
+pub type PricingGridAllOrOne = AllOrOne<PricingGridId>;
 
 #[derive(Clone, Copy, Debug, PartialEq, strum::EnumDiscriminants)]
 #[strum_discriminants(derive(strum::Display, strum::EnumString))]
@@ -74,6 +81,8 @@ pub enum CoreCreditObject {
     ChartOfAccountsIntegration(ChartOfAccountsIntegrationConfigAllOrOne),
     Disbursal(DisbursalAllOrOne),
     Obligation(ObligationAllOrOne),
+    ExposureLimitPolicy(ExposureLimitPolicyAllOrOne),
+    PricingGrid(PricingGridAllOrOne),
 }
 
 impl CoreCreditObject {
@@ -104,6 +113,18 @@ impl CoreCreditObject {
     pub fn all_obligations() -> Self {
         CoreCreditObject::Obligation(AllOrOne::All)
     }
+
+    pub fn exposure_limit_policy() -> Self {
+        CoreCreditObject::ExposureLimitPolicy(AllOrOne::All)
+    }
+
+    pub fn pricing_grid(id: PricingGridId) -> Self {
+        CoreCreditObject::PricingGrid(AllOrOne::ById(id))
+    }
+
+    pub fn all_pricing_grids() -> Self {
+        CoreCreditObject::PricingGrid(AllOrOne::All)
+    }
 }
 
 impl std::fmt::Display for CoreCreditObject {
@@ -115,6 +136,8 @@ impl std::fmt::Display for CoreCreditObject {
             ChartOfAccountsIntegration(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             Disbursal(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             Obligation(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            ExposureLimitPolicy(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            PricingGrid(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
         }
     }
 }
@@ -142,6 +165,14 @@ impl FromStr for CoreCreditObject {
                 let obj_ref = id.parse().map_err(|_| "could not parse CoreCreditObject")?;
                 CoreCreditObject::Disbursal(obj_ref)
             }
+            ExposureLimitPolicy => {
+                let obj_ref = id.parse().map_err(|_| "could not parse CoreCreditObject")?;
+                CoreCreditObject::ExposureLimitPolicy(obj_ref)
+            }
+            PricingGrid => {
+                let obj_ref = id.parse().map_err(|_| "could not parse CoreCreditObject")?;
+                CoreCreditObject::PricingGrid(obj_ref)
+            }
         };
         Ok(res)
     }
@@ -154,7 +185,10 @@ pub enum CoreCreditAction {
     CreditFacility(CreditFacilityAction),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAction),
     Disbursal(DisbursalAction),
+    Forbearance(ForbearanceAction),
     Obligation(ObligationAction),
+    ExposureLimitPolicy(ExposureLimitPolicyAction),
+    PricingGrid(PricingGridAction),
 }
 
 impl CoreCreditAction {
@@ -170,12 +204,18 @@ impl CoreCreditAction {
         CoreCreditAction::CreditFacility(CreditFacilityAction::Activate);
     pub const CREDIT_FACILITY_RECORD_INTEREST: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::RecordInterest);
+    pub const CREDIT_FACILITY_AMORTIZE_STRUCTURING_FEE: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::AmortizeStructuringFee);
     pub const CREDIT_FACILITY_COMPLETE: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::Complete);
     pub const CREDIT_FACILITY_UPDATE_COLLATERAL: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateCollateral);
     pub const CREDIT_FACILITY_UPDATE_COLLATERALIZATION_STATE: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateCollateralizationState);
+    pub const CREDIT_FACILITY_UPDATE_AUTO_DEBIT_MANDATE: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateAutoDebitMandate);
+    pub const CREDIT_FACILITY_RECORD_AUTO_DEBIT: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::RecordAutoDebit);
 
     pub const CHART_OF_ACCOUNTS_INTEGRATION_CONFIG_READ: Self =
         CoreCreditAction::ChartOfAccountsIntegrationConfig(
@@ -190,11 +230,25 @@ impl CoreCreditAction {
     pub const DISBURSAL_SETTLE: Self = CoreCreditAction::Disbursal(DisbursalAction::Settle);
     pub const DISBURSAL_LIST: Self = CoreCreditAction::Disbursal(DisbursalAction::List);
 
+    pub const FORBEARANCE_INITIATE: Self =
+        CoreCreditAction::Forbearance(ForbearanceAction::Initiate);
+    pub const FORBEARANCE_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreCreditAction::Forbearance(ForbearanceAction::ConcludeApprovalProcess);
+    pub const FORBEARANCE_LIST: Self = CoreCreditAction::Forbearance(ForbearanceAction::List);
+
     pub const OBLIGATION_READ: Self = CoreCreditAction::Obligation(ObligationAction::Read);
     pub const OBLIGATION_UPDATE_STATUS: Self =
         CoreCreditAction::Obligation(ObligationAction::UpdateStatus);
     pub const OBLIGATION_RECORD_PAYMENT: Self =
         CoreCreditAction::Obligation(ObligationAction::RecordPaymentAllocation);
+
+    pub const EXPOSURE_LIMIT_POLICY_READ: Self =
+        CoreCreditAction::ExposureLimitPolicy(ExposureLimitPolicyAction::Read);
+    pub const EXPOSURE_LIMIT_POLICY_UPDATE: Self =
+        CoreCreditAction::ExposureLimitPolicy(ExposureLimitPolicyAction::Update);
+
+    pub const PRICING_GRID_CREATE: Self = CoreCreditAction::PricingGrid(PricingGridAction::Create);
+    pub const PRICING_GRID_READ: Self = CoreCreditAction::PricingGrid(PricingGridAction::Read);
 }
 
 impl std::fmt::Display for CoreCreditAction {
@@ -205,7 +259,10 @@ impl std::fmt::Display for CoreCreditAction {
             CreditFacility(action) => action.fmt(f),
             ChartOfAccountsIntegrationConfig(action) => action.fmt(f),
             Disbursal(action) => action.fmt(f),
+            Forbearance(action) => action.fmt(f),
             Obligation(action) => action.fmt(f),
+            ExposureLimitPolicy(action) => action.fmt(f),
+            PricingGrid(action) => action.fmt(f),
         }
     }
 }
@@ -224,7 +281,12 @@ impl FromStr for CoreCreditAction {
                 CoreCreditAction::from(action.parse::<ChartOfAccountsIntegrationConfigAction>()?)
             }
             Disbursal => CoreCreditAction::from(action.parse::<DisbursalAction>()?),
+            Forbearance => CoreCreditAction::from(action.parse::<ForbearanceAction>()?),
             Obligation => CoreCreditAction::from(action.parse::<ObligationAction>()?),
+            ExposureLimitPolicy => {
+                CoreCreditAction::from(action.parse::<ExposureLimitPolicyAction>()?)
+            }
+            PricingGrid => CoreCreditAction::from(action.parse::<PricingGridAction>()?),
         };
         Ok(res)
     }
@@ -240,8 +302,11 @@ pub enum CreditFacilityAction {
     Activate,
     UpdateCollateral,
     RecordInterest,
+    AmortizeStructuringFee,
     Complete,
     UpdateCollateralizationState,
+    UpdateAutoDebitMandate,
+    RecordAutoDebit,
 }
 impl From<CreditFacilityAction> for CoreCreditAction {
     fn from(action: CreditFacilityAction) -> Self {
@@ -262,6 +327,19 @@ impl From<DisbursalAction> for CoreCreditAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ForbearanceAction {
+    Initiate,
+    ConcludeApprovalProcess,
+    List,
+}
+impl From<ForbearanceAction> for CoreCreditAction {
+    fn from(action: ForbearanceAction) -> Self {
+        Self::Forbearance(action)
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "kebab-case")]
 pub enum ChartOfAccountsIntegrationConfigAction {
@@ -288,6 +366,30 @@ impl From<ObligationAction> for CoreCreditAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ExposureLimitPolicyAction {
+    Read,
+    Update,
+}
+impl From<ExposureLimitPolicyAction> for CoreCreditAction {
+    fn from(action: ExposureLimitPolicyAction) -> Self {
+        Self::ExposureLimitPolicy(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PricingGridAction {
+    Create,
+    Read,
+}
+impl From<PricingGridAction> for CoreCreditAction {
+    fn from(action: PricingGridAction) -> Self {
+        Self::PricingGrid(action)
+    }
+}
+
 #[derive(
     Debug,
     Default,
@@ -319,6 +421,14 @@ pub enum DisbursalStatus {
     Confirmed,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum ForbearanceStatus {
+    New,
+    Approved,
+    Denied,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Hash, Deserialize, sqlx::Type)]
 #[serde(transparent)]
 #[sqlx(transparent)]
@@ -342,6 +452,14 @@ pub enum CollateralAction {
     Remove,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum AutoDebitOutcome {
+    Collected,
+    PartiallyCollected,
+    Failed,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
 pub enum DisbursedReceivableAccountType {
     Individual,
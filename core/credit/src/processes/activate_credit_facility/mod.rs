@@ -12,9 +12,10 @@ use crate::{
     disbursal::{DisbursalRepo, NewDisbursal},
     error::CoreCreditError,
     event::CoreCreditEvent,
-    jobs::interest_accruals,
+    jobs::{interest_accruals, structuring_fee_amortization},
     ledger::CreditLedger,
     obligation::Obligations,
+    pricing_grid::PricingGridRepo,
     primitives::{CoreCreditAction, CoreCreditObject, CreditFacilityId, DisbursalId, LedgerTxId},
     Jobs,
 };
@@ -29,6 +30,7 @@ where
     obligations: Obligations<Perms, E>,
     credit_facility_repo: CreditFacilityRepo<E>,
     disbursal_repo: DisbursalRepo,
+    pricing_grid_repo: PricingGridRepo,
     ledger: CreditLedger,
     price: Price,
     jobs: Jobs,
@@ -45,6 +47,7 @@ where
             obligations: self.obligations.clone(),
             credit_facility_repo: self.credit_facility_repo.clone(),
             disbursal_repo: self.disbursal_repo.clone(),
+            pricing_grid_repo: self.pricing_grid_repo.clone(),
             ledger: self.ledger.clone(),
             price: self.price.clone(),
             jobs: self.jobs.clone(),
@@ -63,6 +66,7 @@ where
         obligations: &Obligations<Perms, E>,
         credit_facility_repo: &CreditFacilityRepo<E>,
         disbursal_repo: &DisbursalRepo,
+        pricing_grid_repo: &PricingGridRepo,
         ledger: &CreditLedger,
         price: &Price,
         jobs: &Jobs,
@@ -72,6 +76,7 @@ where
             obligations: obligations.clone(),
             credit_facility_repo: credit_facility_repo.clone(),
             disbursal_repo: disbursal_repo.clone(),
+            pricing_grid_repo: pricing_grid_repo.clone(),
             ledger: ledger.clone(),
             price: price.clone(),
             jobs: jobs.clone(),
@@ -106,8 +111,18 @@ where
             .ledger
             .get_credit_facility_balance(credit_facility.account_ids)
             .await?;
+        let pricing_grid = self
+            .pricing_grid_repo
+            .find_for_terms(&credit_facility.terms)
+            .await?;
         let Ok(es_entity::Idempotent::Executed((credit_facility_activation, next_accrual_period))) =
-            credit_facility.activate(now, price, balances, audit_info.clone())
+            credit_facility.activate(
+                now,
+                price,
+                balances,
+                pricing_grid.as_ref(),
+                audit_info.clone(),
+            )
         else {
             return Ok(credit_facility);
         };
@@ -163,6 +178,21 @@ where
                 next_accrual_period.end,
             )
             .await?;
+        if let Some(first_amortization_at) =
+            credit_facility.next_structuring_fee_amortization_at(now)
+        {
+            self.jobs
+                .create_and_spawn_at_in_op(
+                    &mut db,
+                    uuid::Uuid::new_v4(),
+                    structuring_fee_amortization::CreditFacilityJobConfig::<Perms, E> {
+                        credit_facility_id: id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                    first_amortization_at,
+                )
+                .await?;
+        }
 
         self.ledger
             .activate_credit_facility(db, credit_facility_activation)
@@ -0,0 +1,176 @@
+mod job;
+
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use es_entity::Idempotent;
+use governance::{
+    ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, Governance, GovernanceAction,
+    GovernanceEvent, GovernanceObject,
+};
+
+use outbox::OutboxEventMarker;
+
+use crate::{
+    credit_facility::CreditFacilityRepo, forbearance::ForbearanceRepo, obligation::Obligations,
+    primitives::ForbearanceId, CoreCreditAction, CoreCreditError, CoreCreditEvent,
+    CoreCreditObject, Forbearance,
+};
+
+pub use job::*;
+pub const APPROVE_FORBEARANCE_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("forbearance");
+
+pub struct ApproveForbearance<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    forbearance_repo: ForbearanceRepo,
+    obligations: Obligations<Perms, E>,
+    credit_facility_repo: CreditFacilityRepo<E>,
+    audit: Perms::Audit,
+    governance: Governance<Perms, E>,
+}
+
+impl<Perms, E> Clone for ApproveForbearance<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            forbearance_repo: self.forbearance_repo.clone(),
+            obligations: self.obligations.clone(),
+            credit_facility_repo: self.credit_facility_repo.clone(),
+            audit: self.audit.clone(),
+            governance: self.governance.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveForbearance<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    pub fn new(
+        forbearance_repo: &ForbearanceRepo,
+        obligations: &Obligations<Perms, E>,
+        credit_facility_repo: &CreditFacilityRepo<E>,
+        audit: &Perms::Audit,
+        governance: &Governance<Perms, E>,
+    ) -> Self {
+        Self {
+            forbearance_repo: forbearance_repo.clone(),
+            obligations: obligations.clone(),
+            credit_facility_repo: credit_facility_repo.clone(),
+            audit: audit.clone(),
+            governance: governance.clone(),
+        }
+    }
+
+    pub async fn execute_from_svc(
+        &self,
+        forbearance: &Forbearance,
+    ) -> Result<Option<Forbearance>, CoreCreditError> {
+        if forbearance.is_approval_process_concluded() {
+            return Ok(None);
+        }
+
+        let process: ApprovalProcess = self
+            .governance
+            .find_all_approval_processes(&[forbearance.approval_process_id])
+            .await?
+            .remove(&forbearance.approval_process_id)
+            .expect("approval process not found");
+
+        let res = match process.status() {
+            ApprovalProcessStatus::Approved => Some(self.execute(forbearance.id, true).await?),
+            ApprovalProcessStatus::Denied => Some(self.execute(forbearance.id, false).await?),
+            _ => None,
+        };
+        Ok(res)
+    }
+
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    #[instrument(
+        name = "credit_facility.approve_forbearance",
+        skip(self),
+        fields(already_applied, overlapping)
+    )]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<ForbearanceId>,
+        approved: bool,
+    ) -> Result<Forbearance, CoreCreditError> {
+        let mut forbearance = self.forbearance_repo.find_by_id(id.into()).await?;
+
+        let mut db = self.forbearance_repo.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::credit_facility(forbearance.credit_facility_id),
+                CoreCreditAction::FORBEARANCE_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await?;
+
+        let mut credit_facility = self
+            .credit_facility_repo
+            .find_by_id(forbearance.credit_facility_id)
+            .await?;
+        let period = forbearance.period();
+        // Another forbearance covering the same dates may have been applied since this
+        // one was requested; it can then no longer be granted.
+        let overlapping = approved
+            && credit_facility
+                .forbearances()
+                .iter()
+                .any(|applied| applied.overlaps(period.starts_at, period.ends_at));
+
+        let span = tracing::Span::current();
+        span.record("overlapping", overlapping);
+        let period = if let Idempotent::Executed(period) =
+            forbearance.approval_process_concluded(approved && !overlapping, audit_info.clone())
+        {
+            period
+        } else {
+            span.record("already_applied", true);
+            return Ok(forbearance);
+        };
+        span.record("already_applied", false);
+
+        self.forbearance_repo
+            .update_in_op(&mut db, &mut forbearance)
+            .await?;
+
+        if let Some(period) = period {
+            if credit_facility
+                .apply_forbearance(period, audit_info.clone())?
+                .did_execute()
+            {
+                self.credit_facility_repo
+                    .update_in_op(&mut db, &mut credit_facility)
+                    .await?;
+                self.obligations
+                    .defer_facility_obligations_in_op(
+                        &mut db,
+                        credit_facility.id,
+                        &period,
+                        audit_info,
+                    )
+                    .await?;
+            }
+        }
+
+        db.commit().await?;
+
+        Ok(forbearance)
+    }
+}
@@ -75,7 +75,8 @@ where
                             recorded_at: *recorded_in_ledger_at,
                         })
                     }
-                    BalanceUpdatedType::InterestAccrual => {
+                    BalanceUpdatedType::InterestAccrual
+                    | BalanceUpdatedType::InterestCapitalization => {
                         Some(CoreCreditEvent::FacilityRepaymentRecorded {
                             id: entity.id,
                             disbursal_amount: UsdCents::ZERO,
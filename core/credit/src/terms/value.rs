@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use super::error::TermsError;
-use crate::primitives::{DisbursedReceivableAccountCategory, PriceOfOneBTC, Satoshis, UsdCents};
+use crate::primitives::{
+    DisbursedReceivableAccountCategory, PriceOfOneBTC, PricingGridId, Satoshis, UsdCents,
+};
 
 const NUMBER_OF_DAYS_IN_YEAR: u64 = 365;
 const SHORT_TERM_DURATION_MONTHS_THRESHOLD: u32 = 12;
@@ -50,6 +52,10 @@ impl AnnualRatePct {
                 .expect("should return a valid integer"),
         )
     }
+
+    pub fn adjusted_by(&self, adjustment: AnnualRatePct) -> Self {
+        Self((self.0 + adjustment.0).max(Decimal::ZERO))
+    }
 }
 
 impl From<Decimal> for AnnualRatePct {
@@ -85,6 +91,36 @@ impl From<Decimal> for OneTimeFeeRatePct {
     }
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[serde(rename_all = "snake_case")]
+pub enum StructuringFeeRecognition {
+    #[default]
+    Upfront,
+    Deferred,
+}
+
+impl StructuringFeeRecognition {
+    pub fn is_deferred(&self) -> bool {
+        matches!(self, StructuringFeeRecognition::Deferred)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[serde(rename_all = "snake_case")]
+pub enum InterestCapitalization {
+    #[default]
+    Payable,
+    Capitalized,
+}
+
+impl InterestCapitalization {
+    pub fn is_capitalized(&self) -> bool {
+        matches!(self, InterestCapitalization::Capitalized)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct CVLPct(Decimal);
@@ -247,6 +283,12 @@ impl From<Decimal> for CVLPct {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PricingGridBand {
+    pub min_cvl: CVLPct,
+    pub rate_adjustment: AnnualRatePct,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(tag = "type", content = "value", rename_all = "snake_case")]
 pub enum Duration {
@@ -410,6 +452,12 @@ pub struct TermValues {
     pub accrual_interval: InterestInterval,
     #[builder(setter(into))]
     pub one_time_fee_rate: OneTimeFeeRatePct,
+    #[builder(default)]
+    #[serde(default)]
+    pub structuring_fee_recognition: StructuringFeeRecognition,
+    #[builder(default)]
+    #[serde(default)]
+    pub interest_capitalization: InterestCapitalization,
     // overdue_penalty_rate: LoanAnnualRate,
     #[builder(setter(into))]
     pub liquidation_cvl: CVLPct,
@@ -417,6 +465,9 @@ pub struct TermValues {
     pub margin_call_cvl: CVLPct,
     #[builder(setter(into))]
     pub initial_cvl: CVLPct,
+    #[builder(default, setter(into))]
+    #[serde(default)]
+    pub pricing_grid_id: Option<PricingGridId>,
 }
 
 impl TermValues {
@@ -432,6 +483,13 @@ impl TermValues {
         let collateral_value = self.initial_cvl.scale(desired_principal);
         price.cents_to_sats_round_up(collateral_value)
     }
+
+    pub fn with_annual_rate(&self, annual_rate: AnnualRatePct) -> Self {
+        Self {
+            annual_rate,
+            ..*self
+        }
+    }
 }
 
 impl TermValuesBuilder {
@@ -673,6 +731,26 @@ mod test {
         assert_eq!(interest, UsdCents::from(757));
     }
 
+    #[test]
+    fn adjusted_rate_does_not_go_below_zero() {
+        let base_rate = AnnualRatePct(dec!(1));
+
+        assert_eq!(
+            base_rate.adjusted_by(AnnualRatePct(dec!(-0.5))),
+            AnnualRatePct(dec!(0.5))
+        );
+        assert_eq!(
+            base_rate.adjusted_by(AnnualRatePct(dec!(-2))),
+            AnnualRatePct::from(Decimal::ZERO)
+        );
+        assert_eq!(
+            base_rate
+                .adjusted_by(AnnualRatePct(dec!(-2)))
+                .interest_for_time_period(UsdCents::from(100_000), 30),
+            UsdCents::ZERO
+        );
+    }
+
     #[test]
     fn maturity_date() {
         let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
@@ -45,7 +45,18 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
     .await?;
 
     let storage = Storage::new(&StorageConfig::default());
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, &storage, &jobs);
+    let accounting = CoreAccounting::init(
+        &pool,
+        Default::default(),
+        &authz,
+        &cala,
+        journal_id,
+        &storage,
+        &jobs,
+        &governance,
+        &outbox,
+    )
+    .await?;
     let chart_ref = format!("ref-{:08}", rand::thread_rng().gen_range(0..10000));
     let chart = accounting
         .chart_of_accounts()
@@ -87,6 +98,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
                 .chart_of_account_collateral_parent_code("4".parse().unwrap())
                 .chart_of_account_interest_income_parent_code("7".parse().unwrap())
                 .chart_of_account_fee_income_parent_code("8".parse().unwrap())
+                .chart_of_account_deferred_fee_income_parent_code("8".parse().unwrap())
                 .chart_of_account_short_term_individual_disbursed_receivable_parent_code("1".parse().unwrap())
                 .chart_of_account_short_term_government_entity_disbursed_receivable_parent_code(
                     "2".parse().unwrap(),
@@ -220,6 +232,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
                 .chart_of_account_collateral_parent_code("4".parse().unwrap())
                 .chart_of_account_interest_income_parent_code("7".parse().unwrap())
                 .chart_of_account_fee_income_parent_code("8".parse().unwrap())
+                .chart_of_account_deferred_fee_income_parent_code("8".parse().unwrap())
                 .chart_of_account_short_term_individual_disbursed_receivable_parent_code("1".parse().unwrap())
                 .chart_of_account_short_term_government_entity_disbursed_receivable_parent_code(
                     "2".parse().unwrap(),
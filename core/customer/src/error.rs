@@ -16,6 +16,12 @@ pub enum CustomerError {
     AuditError(#[from] audit::error::AuditError),
     #[error("CustomerError - SubjectIsNotCustomer")]
     SubjectIsNotCustomer,
+    #[error("CustomerError - SignatoryError: {0}")]
+    SignatoryError(#[from] crate::signatory::error::SignatoryError),
+    #[error("CustomerError - SignatoriesNotSupported: {0}")]
+    SignatoriesNotSupported(crate::primitives::CustomerType),
+    #[error("CustomerError - SignatoryNotFoundForSubject")]
+    SignatoryNotFoundForSubject,
 }
 
 es_entity::from_es_entity_error!(CustomerError);
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::primitives::{AccountStatus, CustomerId, CustomerType};
+use crate::primitives::{AccountStatus, CustomerId, CustomerType, KycLevel, SignatoryId};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -14,4 +14,13 @@ pub enum CoreCustomerEvent {
         id: CustomerId,
         status: AccountStatus,
     },
+    CustomerKycLevelUpdated {
+        id: CustomerId,
+        level: KycLevel,
+    },
+    SignatoryCreated {
+        id: SignatoryId,
+        customer_id: CustomerId,
+        email: String,
+    },
 }
@@ -7,6 +7,7 @@ mod event;
 mod primitives;
 mod publisher;
 mod repo;
+mod signatory;
 
 use std::collections::HashMap;
 use tracing::instrument;
@@ -21,6 +22,8 @@ use error::*;
 pub use event::*;
 pub use primitives::*;
 pub use repo::{customer_cursor::*, CustomerRepo, CustomersSortBy, FindManyCustomers, Sort};
+use signatory::*;
+pub use signatory::{error::SignatoryError, Signatory};
 
 use publisher::*;
 
@@ -32,6 +35,7 @@ where
     authz: Perms,
     outbox: Outbox<E>,
     repo: CustomerRepo<E>,
+    signatories: SignatoryRepo<E>,
 }
 
 impl<Perms, E> Clone for Customers<Perms, E>
@@ -44,6 +48,7 @@ where
             authz: self.authz.clone(),
             outbox: self.outbox.clone(),
             repo: self.repo.clone(),
+            signatories: self.signatories.clone(),
         }
     }
 }
@@ -58,8 +63,10 @@ where
     pub fn new(pool: &sqlx::PgPool, authz: &Perms, outbox: &Outbox<E>) -> Self {
         let publisher = CustomerPublisher::new(outbox);
         let repo = CustomerRepo::new(pool, &publisher);
+        let signatories = SignatoryRepo::new(pool, &publisher);
         Self {
             repo,
+            signatories,
             authz: authz.clone(),
             outbox: outbox.clone(),
         }
@@ -342,4 +349,204 @@ where
 
         Ok(customer)
     }
+
+    #[instrument(name = "customer.add_signatory", skip(self), err)]
+    pub async fn add_signatory(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        customer_id: impl Into<CustomerId> + std::fmt::Debug,
+        email: impl Into<String> + std::fmt::Debug,
+        role: SignatoryRole,
+    ) -> Result<Signatory, CustomerError> {
+        let customer_id = customer_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CustomerObject::all_signatories(),
+                CoreCustomerAction::SIGNATORY_CREATE,
+            )
+            .await?;
+
+        let customer = self.repo.find_by_id(customer_id).await?;
+        if customer.customer_type.is_individual() {
+            return Err(CustomerError::SignatoriesNotSupported(
+                customer.customer_type,
+            ));
+        }
+
+        let new_signatory = NewSignatory::builder()
+            .id(SignatoryId::new())
+            .customer_id(customer_id)
+            .email(email)
+            .role(role)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build signatory");
+
+        Ok(self.signatories.create(new_signatory).await?)
+    }
+
+    #[instrument(name = "customer.update_signatory_role", skip(self), err)]
+    pub async fn update_signatory_role(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        signatory_id: impl Into<SignatoryId> + std::fmt::Debug,
+        role: SignatoryRole,
+    ) -> Result<Signatory, CustomerError> {
+        let signatory_id = signatory_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CustomerObject::signatory(signatory_id),
+                CoreCustomerAction::SIGNATORY_UPDATE_ROLE,
+            )
+            .await?;
+
+        let mut signatory = self.signatories.find_by_id(signatory_id).await?;
+        if signatory.update_role(role, audit_info)?.did_execute() {
+            self.signatories.update(&mut signatory).await?;
+        }
+
+        Ok(signatory)
+    }
+
+    #[instrument(name = "customer.remove_signatory", skip(self), err)]
+    pub async fn remove_signatory(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        signatory_id: impl Into<SignatoryId> + std::fmt::Debug,
+    ) -> Result<Signatory, CustomerError> {
+        let signatory_id = signatory_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CustomerObject::signatory(signatory_id),
+                CoreCustomerAction::SIGNATORY_REMOVE,
+            )
+            .await?;
+
+        let mut signatory = self.signatories.find_by_id(signatory_id).await?;
+        if signatory.remove(audit_info).did_execute() {
+            self.signatories.update(&mut signatory).await?;
+        }
+
+        Ok(signatory)
+    }
+
+    #[instrument(name = "customer.find_signatory_by_id", skip(self), err)]
+    pub async fn find_signatory_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        signatory_id: impl Into<SignatoryId> + std::fmt::Debug,
+    ) -> Result<Option<Signatory>, CustomerError> {
+        let signatory_id = signatory_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CustomerObject::signatory(signatory_id),
+                CoreCustomerAction::SIGNATORY_READ,
+            )
+            .await?;
+
+        match self.signatories.find_by_id(signatory_id).await {
+            Ok(signatory) => Ok(Some(signatory)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "customer.list_signatories_for_customer", skip(self), err)]
+    pub async fn list_signatories_for_customer(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        customer_id: impl Into<CustomerId> + std::fmt::Debug,
+    ) -> Result<Vec<Signatory>, CustomerError> {
+        let customer_id = customer_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CustomerObject::all_signatories(),
+                CoreCustomerAction::SIGNATORY_LIST,
+            )
+            .await?;
+
+        Ok(self
+            .signatories
+            .list_for_customer_id_by_created_at(
+                customer_id,
+                Default::default(),
+                es_entity::ListDirection::Ascending,
+            )
+            .await?
+            .entities)
+    }
+
+    /// Looks up the signatory a customer-facing session is acting as.
+    /// Removed signatories are treated as unknown so that their sessions lose access immediately.
+    #[instrument(name = "customer.find_signatory_for_subject", skip(self))]
+    pub async fn find_signatory_for_subject(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        signatory_id: SignatoryId,
+    ) -> Result<Signatory, CustomerError>
+    where
+        CustomerId: for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
+    {
+        let customer_id =
+            CustomerId::try_from(sub).map_err(|_| CustomerError::SubjectIsNotCustomer)?;
+        let signatory = self.signatories.find_by_id(signatory_id).await?;
+        if signatory.customer_id != customer_id || !signatory.is_active() {
+            return Err(CustomerError::SignatoryNotFoundForSubject);
+        }
+        Ok(signatory)
+    }
+
+    #[instrument(
+        name = "customer.update_authentication_id_for_signatory",
+        skip(self, authentication_id)
+    )]
+    pub async fn update_authentication_id_for_signatory(
+        &self,
+        signatory_id: SignatoryId,
+        authentication_id: AuthenticationId,
+    ) -> Result<Signatory, CustomerError> {
+        self.authz
+            .audit()
+            .record_system_entry(
+                CustomerObject::signatory(signatory_id),
+                CoreCustomerAction::SIGNATORY_UPDATE_AUTHENTICATION_ID,
+            )
+            .await?;
+
+        let mut signatory = self.signatories.find_by_id(signatory_id).await?;
+        if signatory
+            .update_authentication_id(authentication_id)
+            .did_execute()
+        {
+            self.signatories.update(&mut signatory).await?;
+        }
+        Ok(signatory)
+    }
+
+    #[instrument(
+        name = "customer.find_signatory_by_authentication_id",
+        skip(self, authentication_id)
+    )]
+    pub async fn find_signatory_by_authentication_id(
+        &self,
+        authentication_id: AuthenticationId,
+    ) -> Result<Option<Signatory>, CustomerError> {
+        match self
+            .signatories
+            .find_by_authentication_id(Some(authentication_id))
+            .await
+        {
+            Ok(signatory) => Ok(Some(signatory)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
 }
@@ -5,7 +5,8 @@ pub use audit::AuditInfo;
 pub use authz::AllOrOne;
 
 es_entity::entity_id! {
-    CustomerId;
+    CustomerId,
+    SignatoryId;
 }
 
 es_entity::entity_id! { AuthenticationId }
@@ -64,12 +65,61 @@ impl Display for CustomerType {
     }
 }
 
+/// What an authorized person may do on behalf of a corporate customer.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum SignatoryRole {
+    Viewer,
+    Initiator,
+    Approver,
+}
+
+impl SignatoryRole {
+    pub fn can_initiate_withdrawals(&self) -> bool {
+        matches!(self, SignatoryRole::Initiator)
+    }
+
+    pub fn can_approve_withdrawals(&self) -> bool {
+        matches!(self, SignatoryRole::Approver)
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[serde(rename_all = "kebab-case")]
+pub enum SignatoryStatus {
+    #[default]
+    Active,
+    Removed,
+}
+
 impl AccountStatus {
     pub fn is_inactive(&self) -> bool {
         matches!(self, AccountStatus::Inactive)
     }
 }
 
+impl CustomerType {
+    pub fn is_individual(&self) -> bool {
+        matches!(self, CustomerType::Individual)
+    }
+}
+
 impl From<CustomerType> for String {
     fn from(customer_type: CustomerType) -> Self {
         match customer_type {
@@ -85,12 +135,14 @@ impl From<CustomerType> for String {
 }
 
 pub type CustomerAllOrOne = AllOrOne<CustomerId>;
+pub type SignatoryAllOrOne = AllOrOne<SignatoryId>;
 
 #[derive(Clone, Copy, Debug, PartialEq, strum::EnumDiscriminants)]
 #[strum_discriminants(derive(strum::Display, strum::EnumString))]
 #[strum_discriminants(strum(serialize_all = "kebab-case"))]
 pub enum CustomerObject {
     Customer(CustomerAllOrOne),
+    Signatory(SignatoryAllOrOne),
 }
 
 impl CustomerObject {
@@ -103,6 +155,15 @@ impl CustomerObject {
             None => CustomerObject::all_customers(),
         }
     }
+    pub fn all_signatories() -> CustomerObject {
+        CustomerObject::Signatory(AllOrOne::All)
+    }
+    pub fn signatory(id: impl Into<Option<SignatoryId>>) -> CustomerObject {
+        match id.into() {
+            Some(id) => CustomerObject::Signatory(AllOrOne::ById(id)),
+            None => CustomerObject::all_signatories(),
+        }
+    }
 }
 
 impl Display for CustomerObject {
@@ -111,6 +172,7 @@ impl Display for CustomerObject {
         use CustomerObject::*;
         match self {
             Customer(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            Signatory(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
         }
     }
 }
@@ -126,6 +188,10 @@ impl FromStr for CustomerObject {
                 let obj_ref = id.parse().map_err(|_| "could not parse CustomerObject")?;
                 CustomerObject::Customer(obj_ref)
             }
+            Signatory => {
+                let obj_ref = id.parse().map_err(|_| "could not parse CustomerObject")?;
+                CustomerObject::Signatory(obj_ref)
+            }
         };
         Ok(res)
     }
@@ -136,6 +202,7 @@ impl FromStr for CustomerObject {
 #[strum_discriminants(strum(serialize_all = "kebab-case"))]
 pub enum CoreCustomerAction {
     Customer(CustomerEntityAction),
+    Signatory(SignatoryEntityAction),
 }
 
 impl CoreCustomerAction {
@@ -151,6 +218,15 @@ impl CoreCustomerAction {
         CoreCustomerAction::Customer(CustomerEntityAction::ApproveKyc);
     pub const CUSTOMER_DECLINE_KYC: Self =
         CoreCustomerAction::Customer(CustomerEntityAction::DeclineKyc);
+
+    pub const SIGNATORY_CREATE: Self = CoreCustomerAction::Signatory(SignatoryEntityAction::Create);
+    pub const SIGNATORY_READ: Self = CoreCustomerAction::Signatory(SignatoryEntityAction::Read);
+    pub const SIGNATORY_LIST: Self = CoreCustomerAction::Signatory(SignatoryEntityAction::List);
+    pub const SIGNATORY_UPDATE_ROLE: Self =
+        CoreCustomerAction::Signatory(SignatoryEntityAction::UpdateRole);
+    pub const SIGNATORY_REMOVE: Self = CoreCustomerAction::Signatory(SignatoryEntityAction::Remove);
+    pub const SIGNATORY_UPDATE_AUTHENTICATION_ID: Self =
+        CoreCustomerAction::Signatory(SignatoryEntityAction::UpdateAuthenticationId);
 }
 
 #[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
@@ -166,12 +242,24 @@ pub enum CustomerEntityAction {
     DeclineKyc,
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum SignatoryEntityAction {
+    Read,
+    Create,
+    List,
+    UpdateRole,
+    Remove,
+    UpdateAuthenticationId,
+}
+
 impl Display for CoreCustomerAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}:", CoreCustomerActionDiscriminants::from(self))?;
         use CoreCustomerAction::*;
         match self {
             Customer(action) => action.fmt(f),
+            Signatory(action) => action.fmt(f),
         }
     }
 }
@@ -184,6 +272,7 @@ impl FromStr for CoreCustomerAction {
         use CoreCustomerActionDiscriminants::*;
         let res = match entity.parse()? {
             Customer => CoreCustomerAction::from(action.parse::<CustomerEntityAction>()?),
+            Signatory => CoreCustomerAction::from(action.parse::<SignatoryEntityAction>()?),
         };
         Ok(res)
     }
@@ -194,3 +283,28 @@ impl From<CustomerEntityAction> for CoreCustomerAction {
         CoreCustomerAction::Customer(action)
     }
 }
+
+impl From<SignatoryEntityAction> for CoreCustomerAction {
+    fn from(action: SignatoryEntityAction) -> Self {
+        CoreCustomerAction::Signatory(action)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn only_initiators_can_initiate_withdrawals() {
+        assert!(SignatoryRole::Initiator.can_initiate_withdrawals());
+        assert!(!SignatoryRole::Approver.can_initiate_withdrawals());
+        assert!(!SignatoryRole::Viewer.can_initiate_withdrawals());
+    }
+
+    #[test]
+    fn only_approvers_can_approve_withdrawals() {
+        assert!(SignatoryRole::Approver.can_approve_withdrawals());
+        assert!(!SignatoryRole::Initiator.can_approve_withdrawals());
+        assert!(!SignatoryRole::Viewer.can_approve_withdrawals());
+    }
+}
@@ -1,6 +1,11 @@
 use outbox::{Outbox, OutboxEventMarker};
 
-use super::{entity::*, error::*, event::*};
+use super::{
+    entity::*,
+    error::*,
+    event::*,
+    signatory::{error::SignatoryError, Signatory, SignatoryEvent},
+};
 
 pub struct CustomerPublisher<E>
 where
@@ -50,6 +55,37 @@ where
                         status: *status,
                     })
                 }
+                KycApproved { level, .. } => Some(CoreCustomerEvent::CustomerKycLevelUpdated {
+                    id: entity.id,
+                    level: *level,
+                }),
+                KycDeclined { .. } => Some(CoreCustomerEvent::CustomerKycLevelUpdated {
+                    id: entity.id,
+                    level: entity.level,
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(db.tx(), publish_events)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn publish_signatory(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        entity: &Signatory,
+        new_events: es_entity::LastPersisted<'_, SignatoryEvent>,
+    ) -> Result<(), SignatoryError> {
+        use SignatoryEvent::*;
+        let publish_events = new_events
+            .filter_map(|event| match &event.event {
+                Initialized { .. } => Some(CoreCustomerEvent::SignatoryCreated {
+                    id: entity.id,
+                    customer_id: entity.customer_id,
+                    email: entity.email.clone(),
+                }),
                 _ => None,
             })
             .collect::<Vec<_>>();
@@ -0,0 +1,168 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::*;
+
+use super::error::SignatoryError;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "SignatoryId")]
+pub enum SignatoryEvent {
+    Initialized {
+        id: SignatoryId,
+        customer_id: CustomerId,
+        email: String,
+        role: SignatoryRole,
+        audit_info: AuditInfo,
+    },
+    AuthenticationIdUpdated {
+        authentication_id: AuthenticationId,
+    },
+    RoleUpdated {
+        role: SignatoryRole,
+        audit_info: AuditInfo,
+    },
+    Removed {
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Signatory {
+    pub id: SignatoryId,
+    pub customer_id: CustomerId,
+    pub email: String,
+    pub role: SignatoryRole,
+    #[builder(default)]
+    pub status: SignatoryStatus,
+    #[builder(setter(strip_option), default)]
+    pub authentication_id: Option<AuthenticationId>,
+    pub(super) events: EntityEvents<SignatoryEvent>,
+}
+
+impl Signatory {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("entity_first_persisted_at not found")
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == SignatoryStatus::Active
+    }
+
+    pub fn update_authentication_id(
+        &mut self,
+        authentication_id: AuthenticationId,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            SignatoryEvent::AuthenticationIdUpdated { authentication_id: existing_id } if existing_id == &authentication_id
+        );
+        self.authentication_id = Some(authentication_id);
+        self.events
+            .push(SignatoryEvent::AuthenticationIdUpdated { authentication_id });
+        Idempotent::Executed(())
+    }
+
+    pub fn update_role(
+        &mut self,
+        role: SignatoryRole,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, SignatoryError> {
+        if !self.is_active() {
+            return Err(SignatoryError::AlreadyRemoved(self.id));
+        }
+        if self.role == role {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events
+            .push(SignatoryEvent::RoleUpdated { role, audit_info });
+        self.role = role;
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn remove(&mut self, audit_info: AuditInfo) -> Idempotent<()> {
+        idempotency_guard!(self.events.iter_all(), SignatoryEvent::Removed { .. });
+
+        self.events.push(SignatoryEvent::Removed { audit_info });
+        self.status = SignatoryStatus::Removed;
+
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<SignatoryEvent> for Signatory {
+    fn try_from_events(events: EntityEvents<SignatoryEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = SignatoryBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                SignatoryEvent::Initialized {
+                    id,
+                    customer_id,
+                    email,
+                    role,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .customer_id(*customer_id)
+                        .email(email.clone())
+                        .role(*role);
+                }
+                SignatoryEvent::AuthenticationIdUpdated { authentication_id } => {
+                    builder = builder.authentication_id(*authentication_id);
+                }
+                SignatoryEvent::RoleUpdated { role, .. } => {
+                    builder = builder.role(*role);
+                }
+                SignatoryEvent::Removed { .. } => {
+                    builder = builder.status(SignatoryStatus::Removed);
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewSignatory {
+    #[builder(setter(into))]
+    pub(super) id: SignatoryId,
+    #[builder(setter(into))]
+    pub(super) customer_id: CustomerId,
+    #[builder(setter(into))]
+    pub(super) email: String,
+    pub(super) role: SignatoryRole,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewSignatory {
+    pub fn builder() -> NewSignatoryBuilder {
+        NewSignatoryBuilder::default()
+    }
+}
+
+impl IntoEvents<SignatoryEvent> for NewSignatory {
+    fn into_events(self) -> EntityEvents<SignatoryEvent> {
+        EntityEvents::init(
+            self.id,
+            [SignatoryEvent::Initialized {
+                id: self.id,
+                customer_id: self.customer_id,
+                email: self.email,
+                role: self.role,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+use crate::primitives::SignatoryId;
+
+#[derive(Error, Debug)]
+pub enum SignatoryError {
+    #[error("SignatoryError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("SignatoryError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("SignatoryError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("SignatoryError - AlreadyRemoved: {0}")]
+    AlreadyRemoved(SignatoryId),
+}
+
+es_entity::from_es_entity_error!(SignatoryError);
@@ -0,0 +1,7 @@
+mod entity;
+pub mod error;
+mod repo;
+
+pub use entity::Signatory;
+pub(crate) use entity::*;
+pub(crate) use repo::*;
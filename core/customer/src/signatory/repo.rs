@@ -0,0 +1,62 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+use outbox::OutboxEventMarker;
+
+use crate::{event::CoreCustomerEvent, primitives::*, publisher::*};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "Signatory",
+    err = "SignatoryError",
+    columns(
+        customer_id(ty = "CustomerId", list_for, update(persist = false)),
+        email(ty = "String", update(persist = false)),
+        authentication_id(ty = "Option<AuthenticationId>", create(persist = false))
+    ),
+    post_persist_hook = "publish"
+)]
+pub struct SignatoryRepo<E>
+where
+    E: OutboxEventMarker<CoreCustomerEvent>,
+{
+    pool: PgPool,
+    publisher: CustomerPublisher<E>,
+}
+
+impl<E> Clone for SignatoryRepo<E>
+where
+    E: OutboxEventMarker<CoreCustomerEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            publisher: self.publisher.clone(),
+        }
+    }
+}
+
+impl<E> SignatoryRepo<E>
+where
+    E: OutboxEventMarker<CoreCustomerEvent>,
+{
+    pub(crate) fn new(pool: &PgPool, publisher: &CustomerPublisher<E>) -> Self {
+        Self {
+            pool: pool.clone(),
+            publisher: publisher.clone(),
+        }
+    }
+
+    async fn publish(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        entity: &Signatory,
+        new_events: es_entity::LastPersisted<'_, SignatoryEvent>,
+    ) -> Result<(), SignatoryError> {
+        self.publisher
+            .publish_signatory(db, entity, new_events)
+            .await
+    }
+}
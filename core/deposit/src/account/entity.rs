@@ -1,11 +1,15 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use derive_builder::Builder;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use es_entity::*;
 
 use audit::AuditInfo;
 
-use crate::primitives::*;
+use crate::{history::AccountStatusChangeEntry, primitives::*};
+
+use super::error::DepositAccountError;
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -18,13 +22,113 @@ pub enum DepositAccountEvent {
         reference: String,
         name: String,
         description: String,
-        status: AccountStatus,
+        status: DepositAccountStatus,
+        currency: DepositAccountCurrency,
         audit_info: AuditInfo,
     },
     AccountStatusUpdated {
-        status: AccountStatus,
+        status: DepositAccountStatus,
+        audit_info: AuditInfo,
+    },
+    Frozen {
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    Unfrozen {
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    Closed {
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    MarkedDormant {
+        last_activity_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    },
+    Reactivated {
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    UnclaimedFundsTransferred {
+        tx_id: CalaTransactionId,
+        amount: UsdCents,
+        audit_info: AuditInfo,
+    },
+    DepositProductAssigned {
+        deposit_product_id: DepositProductId,
+        accrue_interest_from: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    InterestAccrued {
+        tx_id: CalaTransactionId,
+        accrual_date: NaiveDate,
+        balance: UsdCents,
+        annual_rate: InterestRatePct,
+        accrued_cents: Decimal,
+        amount: UsdCents,
+        audit_info: AuditInfo,
+    },
+    InterestCapitalized {
+        tx_id: CalaTransactionId,
+        amount: UsdCents,
         audit_info: AuditInfo,
     },
+    TimeDepositOpened {
+        terms: TimeDepositTerms,
+        audit_info: AuditInfo,
+    },
+    TimeDepositMatured {
+        interest_tx_id: CalaTransactionId,
+        interest: UsdCents,
+        audit_info: AuditInfo,
+    },
+    TimeDepositBroken {
+        forfeit_tx_id: CalaTransactionId,
+        forfeited_interest: UsdCents,
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    WithdrawalLimitAssigned {
+        withdrawal_limit_id: Option<WithdrawalLimitId>,
+        source: WithdrawalLimitSource,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TimeDepositTerms {
+    pub principal: UsdCents,
+    pub annual_rate: InterestRatePct,
+    pub start_date: NaiveDate,
+    pub maturity_date: NaiveDate,
+    pub payout_account_id: DepositAccountId,
+    #[serde(default)]
+    pub early_break_penalty: EarlyBreakPenaltyPct,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TimeDepositSettlement {
+    pub interest_tx_id: CalaTransactionId,
+    pub interest: UsdCents,
+    pub forfeit_tx_id: CalaTransactionId,
+    pub forfeited_interest: UsdCents,
+    pub payout_amount: UsdCents,
+    pub payout_account_id: DepositAccountId,
+}
+
+pub struct DormantAccountReportEntry {
+    pub account: DepositAccount,
+    pub dormant_since: DateTime<Utc>,
+    pub last_activity_at: DateTime<Utc>,
+    pub balance: UsdCents,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DepositInterestPosting {
+    pub tx_id: CalaTransactionId,
+    pub amount: UsdCents,
+    pub effective: NaiveDate,
 }
 
 #[derive(EsEntity, Builder)]
@@ -35,7 +139,14 @@ pub struct DepositAccount {
     pub reference: String,
     pub name: String,
     pub description: String,
-    pub status: AccountStatus,
+    pub status: DepositAccountStatus,
+    pub currency: DepositAccountCurrency,
+    #[builder(setter(strip_option), default)]
+    pub deposit_product_id: Option<DepositProductId>,
+    #[builder(setter(strip_option), default)]
+    pub time_deposit_terms: Option<TimeDepositTerms>,
+    #[builder(default)]
+    pub withdrawal_limit_id: Option<WithdrawalLimitId>,
     pub(super) events: EntityEvents<DepositAccountEvent>,
 }
 
@@ -51,20 +162,573 @@ impl DepositAccount {
         status: AccountStatus,
         audit_info: AuditInfo,
     ) -> Idempotent<()> {
+        let status = DepositAccountStatus::from(status);
+        if self.status.is_closed() {
+            return Idempotent::Ignored;
+        }
         idempotency_guard!(
             self.events.iter_all().rev(),
             DepositAccountEvent::AccountStatusUpdated { status: existing_status, .. } if existing_status == &status
         );
         self.events
             .push(DepositAccountEvent::AccountStatusUpdated { status, audit_info });
-        self.status = status;
+        // A frozen or dormant account keeps blocking debits until it is explicitly released
+        if !self.status.is_frozen() && !self.status.is_dormant() {
+            self.status = status;
+        }
+        Idempotent::Executed(())
+    }
+
+    fn holder_status(&self) -> DepositAccountStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                DepositAccountEvent::AccountStatusUpdated { status, .. } => Some(*status),
+                DepositAccountEvent::Initialized { status, .. } => Some(*status),
+                _ => None,
+            })
+            .expect("No status for deposit account")
+    }
+
+    pub fn freeze(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, DepositAccountError> {
+        if self.status.is_closed() {
+            return Err(DepositAccountError::AccountClosed(self.id));
+        }
+        if self.status.is_dormant() {
+            return Err(DepositAccountError::AccountDormant(self.id));
+        }
+        if self.status.is_frozen() {
+            return Ok(Idempotent::Ignored);
+        }
+        self.events
+            .push(DepositAccountEvent::Frozen { reason, audit_info });
+        self.status = DepositAccountStatus::Frozen;
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn unfreeze(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<(), DepositAccountError> {
+        if self.status.is_closed() {
+            return Err(DepositAccountError::AccountClosed(self.id));
+        }
+        if !self.status.is_frozen() {
+            return Err(DepositAccountError::AccountNotFrozen(self.id));
+        }
+        self.events
+            .push(DepositAccountEvent::Unfrozen { reason, audit_info });
+        self.status = self.holder_status();
+        Ok(())
+    }
+
+    pub fn close(&mut self, reason: String, audit_info: AuditInfo) -> Idempotent<()> {
+        if self.status.is_closed() {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(DepositAccountEvent::Closed { reason, audit_info });
+        self.status = DepositAccountStatus::Closed;
+        Idempotent::Executed(())
+    }
+
+    /// Only active demand accounts become dormant; time deposits are locked by their terms.
+    pub(crate) fn mark_dormant(
+        &mut self,
+        last_activity_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.status != DepositAccountStatus::Active || self.is_time_deposit() {
+            return Idempotent::Ignored;
+        }
+        self.events.push(DepositAccountEvent::MarkedDormant {
+            last_activity_at,
+            audit_info,
+        });
+        self.status = DepositAccountStatus::Dormant;
         Idempotent::Executed(())
     }
+
+    fn last_marked_dormant(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        if !self.status.is_dormant() {
+            return None;
+        }
+        self.events
+            .iter_persisted()
+            .rev()
+            .find_map(|event| match &event.event {
+                DepositAccountEvent::MarkedDormant {
+                    last_activity_at, ..
+                } => Some((event.recorded_at, *last_activity_at)),
+                _ => None,
+            })
+    }
+
+    pub fn dormant_since(&self) -> Option<DateTime<Utc>> {
+        self.last_marked_dormant().map(|(since, _)| since)
+    }
+
+    pub fn last_activity_at(&self) -> Option<DateTime<Utc>> {
+        self.last_marked_dormant()
+            .map(|(_, last_activity_at)| last_activity_at)
+    }
+
+    pub(crate) fn last_reactivated_at(&self) -> Option<DateTime<Utc>> {
+        self.events
+            .iter_persisted()
+            .rev()
+            .find_map(|event| match &event.event {
+                DepositAccountEvent::Reactivated { .. } => Some(event.recorded_at),
+                _ => None,
+            })
+    }
+
+    pub fn reactivate(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<(), DepositAccountError> {
+        if self.status.is_closed() {
+            return Err(DepositAccountError::AccountClosed(self.id));
+        }
+        if !self.status.is_dormant() {
+            return Err(DepositAccountError::AccountNotDormant(self.id));
+        }
+        self.events
+            .push(DepositAccountEvent::Reactivated { reason, audit_info });
+        self.status = self.holder_status();
+        Ok(())
+    }
+
+    /// Balances may only be escheated once the account has been dormant for `escheatment_period`.
+    pub(crate) fn transfer_unclaimed_funds(
+        &mut self,
+        amount: UsdCents,
+        now: DateTime<Utc>,
+        escheatment_period: chrono::Duration,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<CalaTransactionId>, DepositAccountError> {
+        let dormant_since = self
+            .dormant_since()
+            .ok_or(DepositAccountError::AccountNotDormant(self.id))?;
+        if dormant_since + escheatment_period > now {
+            return Err(DepositAccountError::EscheatmentPeriodNotElapsed(self.id));
+        }
+        if amount == UsdCents::ZERO {
+            return Ok(Idempotent::Ignored);
+        }
+        let tx_id = CalaTransactionId::new();
+        self.events
+            .push(DepositAccountEvent::UnclaimedFundsTransferred {
+                tx_id,
+                amount,
+                audit_info,
+            });
+        Ok(Idempotent::Executed(tx_id))
+    }
+
+    pub(crate) fn status_changes(&self) -> Vec<AccountStatusChangeEntry> {
+        let mut holder_status = DepositAccountStatus::Inactive;
+        let mut changes = Vec::new();
+        for event in self.events.iter_persisted() {
+            let (status, reason) = match &event.event {
+                DepositAccountEvent::Initialized { status, .. }
+                | DepositAccountEvent::AccountStatusUpdated { status, .. } => {
+                    holder_status = *status;
+                    continue;
+                }
+                DepositAccountEvent::Frozen { reason, .. } => {
+                    (DepositAccountStatus::Frozen, reason.clone())
+                }
+                DepositAccountEvent::Unfrozen { reason, .. }
+                | DepositAccountEvent::Reactivated { reason, .. } => {
+                    (holder_status, reason.clone())
+                }
+                DepositAccountEvent::Closed { reason, .. } => {
+                    (DepositAccountStatus::Closed, reason.clone())
+                }
+                DepositAccountEvent::MarkedDormant {
+                    last_activity_at, ..
+                } => (
+                    DepositAccountStatus::Dormant,
+                    format!("No activity since {}", last_activity_at.date_naive()),
+                ),
+                _ => continue,
+            };
+            changes.push(AccountStatusChangeEntry {
+                status,
+                reason,
+                recorded_at: event.recorded_at,
+            });
+        }
+        changes
+    }
+
+    pub fn assign_deposit_product(
+        &mut self,
+        deposit_product_id: DepositProductId,
+        accrue_interest_from: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.deposit_product_id == Some(deposit_product_id) {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(DepositAccountEvent::DepositProductAssigned {
+                deposit_product_id,
+                accrue_interest_from,
+                audit_info,
+            });
+        self.deposit_product_id = Some(deposit_product_id);
+        Idempotent::Executed(())
+    }
+
+    pub fn withdrawal_limit_source(&self) -> Option<WithdrawalLimitSource> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            DepositAccountEvent::WithdrawalLimitAssigned { source, .. } => Some(*source),
+            _ => None,
+        })
+    }
+
+    /// Returns the previously assigned limit so its ledger membership can be moved.
+    /// A limit assigned directly to the account takes precedence over segment defaults.
+    pub fn assign_withdrawal_limit(
+        &mut self,
+        withdrawal_limit_id: Option<WithdrawalLimitId>,
+        source: WithdrawalLimitSource,
+        audit_info: AuditInfo,
+    ) -> Idempotent<Option<WithdrawalLimitId>> {
+        let current_source = self.withdrawal_limit_source();
+        if source == WithdrawalLimitSource::Segment
+            && current_source == Some(WithdrawalLimitSource::Account)
+        {
+            return Idempotent::Ignored;
+        }
+        if self.withdrawal_limit_id == withdrawal_limit_id && current_source == Some(source) {
+            return Idempotent::Ignored;
+        }
+        let previous = self.withdrawal_limit_id;
+        self.events
+            .push(DepositAccountEvent::WithdrawalLimitAssigned {
+                withdrawal_limit_id,
+                source,
+                audit_info,
+            });
+        self.withdrawal_limit_id = withdrawal_limit_id;
+        Idempotent::Executed(previous)
+    }
+
+    pub fn is_time_deposit(&self) -> bool {
+        self.time_deposit_terms.is_some()
+    }
+
+    pub(crate) fn accepts_debits(&self, currency: DepositAccountCurrency) -> bool {
+        self.currency == currency
+            && !self.is_time_deposit()
+            && self.status == DepositAccountStatus::Active
+    }
+
+    pub(crate) fn accepts_credits(&self, currency: DepositAccountCurrency) -> bool {
+        self.currency == currency
+            && !self.is_time_deposit()
+            && matches!(
+                self.status,
+                DepositAccountStatus::Active
+                    | DepositAccountStatus::Frozen
+                    | DepositAccountStatus::Dormant
+            )
+    }
+
+    pub(crate) fn next_interest_accrual_date(&self) -> Option<NaiveDate> {
+        if self.status.is_closed() {
+            return None;
+        }
+        if self.deposit_product_id.is_none() && !self.is_time_deposit() {
+            return None;
+        }
+        let next = self
+            .events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                DepositAccountEvent::InterestAccrued { accrual_date, .. } => {
+                    accrual_date.succ_opt()
+                }
+                _ => None,
+            })
+            .or_else(|| {
+                self.events.iter_all().find_map(|event| match event {
+                    DepositAccountEvent::DepositProductAssigned {
+                        accrue_interest_from,
+                        ..
+                    } => Some(*accrue_interest_from),
+                    DepositAccountEvent::TimeDepositOpened { terms, .. } => Some(terms.start_date),
+                    _ => None,
+                })
+            })?;
+
+        // Time deposits stop accruing on their maturity date
+        match self.time_deposit_terms {
+            Some(terms) if next >= terms.maturity_date => None,
+            _ => Some(next),
+        }
+    }
+
+    fn uncapitalized_accruals(&self) -> impl Iterator<Item = &DepositAccountEvent> {
+        self.events
+            .iter_all()
+            .rev()
+            .take_while(|event| !matches!(event, DepositAccountEvent::InterestCapitalized { .. }))
+            .filter(|event| matches!(event, DepositAccountEvent::InterestAccrued { .. }))
+    }
+
+    pub(crate) fn accrue_interest(
+        &mut self,
+        accrual_date: NaiveDate,
+        balance: UsdCents,
+        annual_rate: InterestRatePct,
+        audit_info: AuditInfo,
+    ) -> Idempotent<DepositInterestPosting> {
+        match self.next_interest_accrual_date() {
+            Some(next) if next == accrual_date => (),
+            _ => return Idempotent::Ignored,
+        }
+        if self.is_interest_capitalization_due(accrual_date) {
+            return Idempotent::Ignored;
+        }
+
+        let (accrued_so_far, posted_so_far) = self.uncapitalized_accruals().fold(
+            (Decimal::ZERO, UsdCents::ZERO),
+            |(accrued, posted), event| match event {
+                DepositAccountEvent::InterestAccrued {
+                    accrued_cents,
+                    amount,
+                    ..
+                } => (accrued + accrued_cents, posted + *amount),
+                _ => (accrued, posted),
+            },
+        );
+        let accrued_cents = annual_rate.daily_interest_cents(balance);
+        let amount = whole_cents(accrued_so_far + accrued_cents) - posted_so_far;
+
+        let tx_id = CalaTransactionId::new();
+        self.events.push(DepositAccountEvent::InterestAccrued {
+            tx_id,
+            accrual_date,
+            balance,
+            annual_rate,
+            accrued_cents,
+            amount,
+            audit_info,
+        });
+
+        Idempotent::Executed(DepositInterestPosting {
+            tx_id,
+            amount,
+            effective: accrual_date,
+        })
+    }
+
+    pub(crate) fn is_interest_capitalization_due(&self, date: NaiveDate) -> bool {
+        if self.is_time_deposit() {
+            return false;
+        }
+        self.uncapitalized_accruals()
+            .last()
+            .map(|event| match event {
+                DepositAccountEvent::InterestAccrued { accrual_date, .. } => {
+                    (accrual_date.year(), accrual_date.month()) != (date.year(), date.month())
+                }
+                _ => false,
+            })
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn capitalize_interest(
+        &mut self,
+        date: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<DepositInterestPosting> {
+        if !self.is_interest_capitalization_due(date) {
+            return Idempotent::Ignored;
+        }
+
+        let (amount, effective) = self.uncapitalized_accruals().fold(
+            (UsdCents::ZERO, None),
+            |(total, effective), event| match event {
+                DepositAccountEvent::InterestAccrued {
+                    amount,
+                    accrual_date,
+                    ..
+                } => (total + *amount, effective.max(Some(*accrual_date))),
+                _ => (total, effective),
+            },
+        );
+
+        let tx_id = CalaTransactionId::new();
+        self.events.push(DepositAccountEvent::InterestCapitalized {
+            tx_id,
+            amount,
+            audit_info,
+        });
+
+        Idempotent::Executed(DepositInterestPosting {
+            tx_id,
+            amount,
+            effective: effective.expect("no interest accrued"),
+        })
+    }
+
+    /// Capitalizes the interest accrued since the last capitalization, whatever
+    /// the month, so a closing account pays it out with its balance.
+    pub(crate) fn capitalize_interest_on_close(
+        &mut self,
+        date: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Option<DepositInterestPosting> {
+        let amount = self.uncapitalized_interest();
+        if amount.is_zero() {
+            return None;
+        }
+        let tx_id = CalaTransactionId::new();
+        self.events.push(DepositAccountEvent::InterestCapitalized {
+            tx_id,
+            amount,
+            audit_info,
+        });
+        Some(DepositInterestPosting {
+            tx_id,
+            amount,
+            effective: date,
+        })
+    }
+
+    fn uncapitalized_interest(&self) -> UsdCents {
+        self.uncapitalized_accruals()
+            .fold(UsdCents::ZERO, |total, event| match event {
+                DepositAccountEvent::InterestAccrued { amount, .. } => total + *amount,
+                _ => total,
+            })
+    }
+
+    pub(crate) fn is_time_deposit_due_for_maturity(&self, today: NaiveDate) -> bool {
+        match self.time_deposit_terms {
+            Some(terms) => {
+                terms.maturity_date <= today && self.next_interest_accrual_date().is_none()
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn mature_time_deposit(
+        &mut self,
+        today: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<TimeDepositSettlement>, DepositAccountError> {
+        let terms = self
+            .time_deposit_terms
+            .ok_or(DepositAccountError::NotTimeDeposit(self.id))?;
+        if self.status.is_closed() {
+            return Ok(Idempotent::Ignored);
+        }
+        if self.status.is_frozen() {
+            return Err(DepositAccountError::AccountFrozen(self.id));
+        }
+        if !self.is_time_deposit_due_for_maturity(today) {
+            return Err(DepositAccountError::TimeDepositNotMatured(self.id));
+        }
+
+        let interest = self.uncapitalized_interest();
+        let interest_tx_id = CalaTransactionId::new();
+        self.events.push(DepositAccountEvent::TimeDepositMatured {
+            interest_tx_id,
+            interest,
+            audit_info: audit_info.clone(),
+        });
+        self.events.push(DepositAccountEvent::Closed {
+            reason: "Time deposit matured".to_string(),
+            audit_info,
+        });
+        self.status = DepositAccountStatus::Closed;
+
+        Ok(Idempotent::Executed(TimeDepositSettlement {
+            interest_tx_id,
+            interest,
+            forfeit_tx_id: CalaTransactionId::new(),
+            forfeited_interest: UsdCents::ZERO,
+            payout_amount: terms.principal + interest,
+            payout_account_id: terms.payout_account_id,
+        }))
+    }
+
+    pub fn break_time_deposit(
+        &mut self,
+        today: NaiveDate,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<TimeDepositSettlement, DepositAccountError> {
+        let terms = self
+            .time_deposit_terms
+            .ok_or(DepositAccountError::NotTimeDeposit(self.id))?;
+        if self.status.is_closed() {
+            return Err(DepositAccountError::AccountClosed(self.id));
+        }
+        if self.status.is_frozen() {
+            return Err(DepositAccountError::AccountFrozen(self.id));
+        }
+        if terms.maturity_date <= today {
+            return Err(DepositAccountError::TimeDepositAlreadyMatured(self.id));
+        }
+
+        // Breaking the term early forfeits the product's penalty share of the
+        // interest accrued so far, the rest is capitalized and paid out
+        let accrued_interest = self.uncapitalized_interest();
+        let forfeited_interest = terms
+            .early_break_penalty
+            .forfeited_interest(accrued_interest);
+        let interest = accrued_interest - forfeited_interest;
+        let interest_tx_id = CalaTransactionId::new();
+        if !interest.is_zero() {
+            self.events.push(DepositAccountEvent::InterestCapitalized {
+                tx_id: interest_tx_id,
+                amount: interest,
+                audit_info: audit_info.clone(),
+            });
+        }
+        let forfeit_tx_id = CalaTransactionId::new();
+        self.events.push(DepositAccountEvent::TimeDepositBroken {
+            forfeit_tx_id,
+            forfeited_interest,
+            reason: reason.clone(),
+            audit_info: audit_info.clone(),
+        });
+        self.events
+            .push(DepositAccountEvent::Closed { reason, audit_info });
+        self.status = DepositAccountStatus::Closed;
+
+        Ok(TimeDepositSettlement {
+            interest_tx_id,
+            interest,
+            forfeit_tx_id,
+            forfeited_interest,
+            payout_amount: terms.principal + interest,
+            payout_account_id: terms.payout_account_id,
+        })
+    }
 }
 
 impl TryFromEvents<DepositAccountEvent> for DepositAccount {
     fn try_from_events(events: EntityEvents<DepositAccountEvent>) -> Result<Self, EsEntityError> {
         let mut builder = DepositAccountBuilder::default();
+        let mut holder_status = DepositAccountStatus::Inactive;
+        let mut frozen = false;
+        let mut dormant = false;
         for event in events.iter_all() {
             match event {
                 DepositAccountEvent::Initialized {
@@ -74,8 +738,10 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                     name,
                     description,
                     status,
+                    currency,
                     ..
                 } => {
+                    holder_status = *status;
                     builder = builder
                         .id(*id)
                         .account_holder_id(*account_holder_id)
@@ -83,9 +749,51 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                         .name(name.to_string())
                         .description(description.to_string())
                         .status(*status)
+                        .currency(*currency)
                 }
                 DepositAccountEvent::AccountStatusUpdated { status, .. } => {
-                    builder = builder.status(*status);
+                    holder_status = *status;
+                    if !frozen && !dormant {
+                        builder = builder.status(*status);
+                    }
+                }
+                DepositAccountEvent::Frozen { .. } => {
+                    frozen = true;
+                    builder = builder.status(DepositAccountStatus::Frozen);
+                }
+                DepositAccountEvent::Unfrozen { .. } => {
+                    frozen = false;
+                    builder = builder.status(holder_status);
+                }
+                DepositAccountEvent::Closed { .. } => {
+                    builder = builder.status(DepositAccountStatus::Closed);
+                }
+                DepositAccountEvent::MarkedDormant { .. } => {
+                    dormant = true;
+                    builder = builder.status(DepositAccountStatus::Dormant);
+                }
+                DepositAccountEvent::Reactivated { .. } => {
+                    dormant = false;
+                    builder = builder.status(holder_status);
+                }
+                DepositAccountEvent::UnclaimedFundsTransferred { .. } => (),
+                DepositAccountEvent::DepositProductAssigned {
+                    deposit_product_id, ..
+                } => {
+                    builder = builder.deposit_product_id(*deposit_product_id);
+                }
+                DepositAccountEvent::InterestAccrued { .. } => (),
+                DepositAccountEvent::InterestCapitalized { .. } => (),
+                DepositAccountEvent::TimeDepositOpened { terms, .. } => {
+                    builder = builder.time_deposit_terms(*terms);
+                }
+                DepositAccountEvent::TimeDepositMatured { .. } => (),
+                DepositAccountEvent::TimeDepositBroken { .. } => (),
+                DepositAccountEvent::WithdrawalLimitAssigned {
+                    withdrawal_limit_id,
+                    ..
+                } => {
+                    builder = builder.withdrawal_limit_id(*withdrawal_limit_id);
                 }
             }
         }
@@ -103,6 +811,10 @@ pub struct NewDepositAccount {
     pub(super) name: String,
     pub(super) description: String,
     pub(super) active: bool,
+    #[builder(default)]
+    pub(super) currency: DepositAccountCurrency,
+    #[builder(setter(strip_option), default)]
+    pub(super) time_deposit_terms: Option<TimeDepositTerms>,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
 }
@@ -115,22 +827,99 @@ impl NewDepositAccount {
 
 impl IntoEvents<DepositAccountEvent> for NewDepositAccount {
     fn into_events(self) -> EntityEvents<DepositAccountEvent> {
-        EntityEvents::init(
-            self.id,
-            [DepositAccountEvent::Initialized {
-                id: self.id,
-                account_holder_id: self.account_holder_id,
-                ledger_account_id: self.id.into(),
-                reference: self.reference,
-                name: self.name,
-                description: self.description,
-                status: if self.active {
-                    AccountStatus::Active
-                } else {
-                    AccountStatus::Inactive
-                },
+        let mut events = vec![DepositAccountEvent::Initialized {
+            id: self.id,
+            account_holder_id: self.account_holder_id,
+            ledger_account_id: self.id.into(),
+            reference: self.reference,
+            name: self.name,
+            description: self.description,
+            status: if self.active {
+                DepositAccountStatus::Active
+            } else {
+                DepositAccountStatus::Inactive
+            },
+            currency: self.currency,
+            audit_info: self.audit_info.clone(),
+        }];
+        if let Some(terms) = self.time_deposit_terms {
+            events.push(DepositAccountEvent::TimeDepositOpened {
+                terms,
                 audit_info: self.audit_info,
-            }],
-        )
+            });
+        }
+        EntityEvents::init(self.id, events)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn account_with_product(accrue_interest_from: NaiveDate) -> DepositAccount {
+        let new_account = NewDepositAccount::builder()
+            .id(DepositAccountId::new())
+            .account_holder_id(DepositAccountHolderId::new())
+            .reference("ref".to_string())
+            .name("name".to_string())
+            .description("description".to_string())
+            .active(true)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        let mut account = DepositAccount::try_from_events(new_account.into_events()).unwrap();
+        let _ = account.assign_deposit_product(
+            DepositProductId::new(),
+            accrue_interest_from,
+            dummy_audit_info(),
+        );
+        account
+    }
+
+    #[test]
+    fn active_account_accrues_from_product_assignment() {
+        let from = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let account = account_with_product(from);
+        assert_eq!(account.next_interest_accrual_date(), Some(from));
+    }
+
+    #[test]
+    fn closing_capitalizes_interest_accrued_this_month() {
+        let from = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let mut account = account_with_product(from);
+        let rate = InterestRatePct::from(rust_decimal_macros::dec!(3.65));
+        let balance = UsdCents::from(1_000_000);
+        for day in 0..3 {
+            let date = from + chrono::Days::new(day);
+            let _ = account.accrue_interest(date, balance, rate, dummy_audit_info());
+        }
+        assert!(!account.is_interest_capitalization_due(from + chrono::Days::new(3)));
+
+        let closing = from + chrono::Days::new(3);
+        let posting = account
+            .capitalize_interest_on_close(closing, dummy_audit_info())
+            .expect("interest accrued");
+        assert_eq!(posting.amount, UsdCents::from(300));
+        assert_eq!(posting.effective, closing);
+        assert!(account
+            .capitalize_interest_on_close(closing, dummy_audit_info())
+            .is_none());
+    }
+
+    #[test]
+    fn closed_account_stops_accruing() {
+        let from = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let mut account = account_with_product(from);
+        let _ = account.close("closed".to_string(), dummy_audit_info());
+        assert_eq!(account.next_interest_accrual_date(), None);
     }
 }
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::primitives::DepositAccountId;
+
 #[derive(Error, Debug)]
 pub enum DepositAccountError {
     #[error("CommitteeError - Sqlx: {0}")]
@@ -8,6 +10,24 @@ pub enum DepositAccountError {
     EsEntityError(es_entity::EsEntityError),
     #[error("CommitteeError - CursorDestructureError: {0}")]
     CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("DepositAccountError - AccountClosed: {0}")]
+    AccountClosed(DepositAccountId),
+    #[error("DepositAccountError - AccountNotFrozen: {0}")]
+    AccountNotFrozen(DepositAccountId),
+    #[error("DepositAccountError - AccountFrozen: {0}")]
+    AccountFrozen(DepositAccountId),
+    #[error("DepositAccountError - AccountDormant: {0}")]
+    AccountDormant(DepositAccountId),
+    #[error("DepositAccountError - AccountNotDormant: {0}")]
+    AccountNotDormant(DepositAccountId),
+    #[error("DepositAccountError - EscheatmentPeriodNotElapsed: {0}")]
+    EscheatmentPeriodNotElapsed(DepositAccountId),
+    #[error("DepositAccountError - NotTimeDeposit: {0}")]
+    NotTimeDeposit(DepositAccountId),
+    #[error("DepositAccountError - TimeDepositNotMatured: {0}")]
+    TimeDepositNotMatured(DepositAccountId),
+    #[error("DepositAccountError - TimeDepositAlreadyMatured: {0}")]
+    TimeDepositAlreadyMatured(DepositAccountId),
 }
 
 es_entity::from_es_entity_error!(DepositAccountError);
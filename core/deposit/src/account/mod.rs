@@ -1,7 +1,9 @@
 mod entity;
 pub mod error;
 mod repo;
+mod time_deposit;
 
-pub use entity::DepositAccount;
 pub(crate) use entity::*;
+pub use entity::{DepositAccount, DormantAccountReportEntry, TimeDepositTerms};
 pub(crate) use repo::*;
+pub use time_deposit::TimeDeposits;
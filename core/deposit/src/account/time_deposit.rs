@@ -0,0 +1,249 @@
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::Jobs;
+use outbox::OutboxEventMarker;
+use tracing::instrument;
+
+use crate::{
+    checks::*,
+    error::CoreDepositError,
+    event::CoreDepositEvent,
+    jobs::time_deposit_maturity::TimeDepositMaturityJobConfig,
+    ledger::{DepositAccountSweep, DepositLedger},
+    primitives::*,
+    product::DepositProductRepo,
+    transfer::{NewTransfer, TransferRepo},
+};
+
+use super::{DepositAccount, DepositAccountRepo, NewDepositAccount, TimeDepositTerms};
+
+pub struct TimeDeposits<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    authz: Perms,
+    accounts: DepositAccountRepo,
+    products: DepositProductRepo,
+    transfers: TransferRepo<E>,
+    ledger: DepositLedger,
+    jobs: Jobs,
+}
+
+impl<Perms, E> Clone for TimeDeposits<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            accounts: self.accounts.clone(),
+            products: self.products.clone(),
+            transfers: self.transfers.clone(),
+            ledger: self.ledger.clone(),
+            jobs: self.jobs.clone(),
+        }
+    }
+}
+
+impl<Perms, E> TimeDeposits<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub(crate) fn new(
+        authz: &Perms,
+        accounts: &DepositAccountRepo,
+        products: &DepositProductRepo,
+        transfers: &TransferRepo<E>,
+        ledger: &DepositLedger,
+        jobs: &Jobs,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            accounts: accounts.clone(),
+            products: products.clone(),
+            transfers: transfers.clone(),
+            ledger: ledger.clone(),
+            jobs: jobs.clone(),
+        }
+    }
+
+    #[instrument(name = "deposit.open_time_deposit", skip(self), err)]
+    pub async fn open_time_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        funding_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        principal: UsdCents,
+        annual_rate: InterestRatePct,
+        maturity_date: chrono::NaiveDate,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let funding_account_id = funding_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_accounts(),
+                CoreDepositAction::DEPOSIT_ACCOUNT_OPEN_TIME_DEPOSIT,
+            )
+            .await?;
+        let start_date = chrono::Utc::now().date_naive();
+        if maturity_date <= start_date {
+            return Err(CoreDepositError::TimeDepositMaturityNotInFuture);
+        }
+        check_account_active(
+            &self.accounts,
+            funding_account_id,
+            DepositAccountCurrency::Usd,
+        )
+        .await?;
+        check_available_balance(&self.ledger, funding_account_id, principal.into()).await?;
+        let funding_account = self.accounts.find_by_id(funding_account_id).await?;
+        let early_break_penalty = match funding_account.deposit_product_id {
+            Some(deposit_product_id) => {
+                self.products
+                    .find_by_id(deposit_product_id)
+                    .await?
+                    .early_break_penalty
+            }
+            None => EarlyBreakPenaltyPct::default(),
+        };
+
+        let account_id = DepositAccountId::new();
+        let name = format!("Time Deposit {}", funding_account.account_holder_id);
+        let reference = format!("deposit-time-deposit:{}", account_id);
+        let new_account = NewDepositAccount::builder()
+            .id(account_id)
+            .account_holder_id(funding_account.account_holder_id)
+            .reference(reference.clone())
+            .name(name.clone())
+            .description(name.clone())
+            .active(true)
+            .time_deposit_terms(TimeDepositTerms {
+                principal,
+                annual_rate,
+                start_date,
+                maturity_date,
+                payout_account_id: funding_account_id,
+                early_break_penalty,
+            })
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("Could not build new account");
+
+        let mut op = self.accounts.begin_op().await?;
+        let account = self.accounts.create_in_op(&mut op, new_account).await?;
+
+        let new_transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_deposit_account_id(funding_account_id)
+            .to_deposit_account_id(account_id)
+            .amount(principal)
+            .reference(Some(format!("time-deposit-funding-{account_id}")))
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("Could not build new transfer");
+        let mut transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+        let tx_id = transfer.execute(audit_info)?;
+        self.transfers.update_in_op(&mut op, &mut transfer).await?;
+
+        self.jobs
+            .create_and_spawn_at_in_op(
+                &mut op,
+                account_id,
+                TimeDepositMaturityJobConfig::<Perms, E> {
+                    deposit_account_id: account_id,
+                    _phantom: std::marker::PhantomData,
+                },
+                maturity_date
+                    .and_hms_opt(0, 0, 0)
+                    .expect("valid time")
+                    .and_utc(),
+            )
+            .await?;
+
+        self.ledger
+            .create_time_deposit_account(
+                op,
+                account_id,
+                reference,
+                name,
+                tx_id,
+                principal,
+                funding_account_id,
+            )
+            .await?;
+        Ok(account)
+    }
+
+    #[instrument(name = "deposit.break_time_deposit", skip(self), err)]
+    pub async fn break_time_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_BREAK_TIME_DEPOSIT,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        let balance = self.ledger.balance(account_id).await?;
+        if !balance.encumbrance.is_zero() {
+            return Err(CoreDepositError::DepositAccountHasActiveHolds);
+        }
+        let settlement = account.break_time_deposit(
+            chrono::Utc::now().date_naive(),
+            reason,
+            audit_info.clone(),
+        )?;
+        check_account_accepts_credits(
+            &self.accounts,
+            settlement.payout_account_id,
+            DepositAccountCurrency::Usd,
+        )
+        .await?;
+
+        let mut op = self.accounts.begin_op().await?;
+        self.accounts.update_in_op(&mut op, &mut account).await?;
+
+        let new_transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_deposit_account_id(account_id)
+            .to_deposit_account_id(settlement.payout_account_id)
+            .amount(settlement.payout_amount)
+            .reference(Some(format!("time-deposit-break-{account_id}")))
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("Could not build new transfer");
+        let mut transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+        let tx_id = transfer.execute(audit_info)?;
+        self.transfers.update_in_op(&mut op, &mut transfer).await?;
+
+        self.ledger
+            .break_time_deposit(
+                op,
+                account_id,
+                settlement.interest_tx_id,
+                settlement.interest,
+                settlement.forfeit_tx_id,
+                settlement.forfeited_interest,
+                DepositAccountSweep {
+                    tx_id,
+                    amount: settlement.payout_amount,
+                    to_account_id: settlement.payout_account_id.into(),
+                },
+            )
+            .await?;
+        Ok(account)
+    }
+}
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+use crate::primitives::BankStatementLineId;
+
+#[derive(Error, Debug)]
+pub enum BankStatementError {
+    #[error("BankStatementError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("BankStatementError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("BankStatementError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("BankStatementError - CsvError: {0}")]
+    CsvError(#[from] csv::Error),
+    #[error("BankStatementError - MalformedStatement: {0}")]
+    MalformedStatement(String),
+    #[error("BankStatementError - InvalidAmount: {0}")]
+    InvalidAmount(String),
+    #[error("BankStatementError - LineAlreadyProcessed: {0}")]
+    LineAlreadyProcessed(BankStatementLineId),
+}
+
+es_entity::from_es_entity_error!(BankStatementError);
@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::BankStatementImportId;
+use audit::AuditInfo;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum BankStatementFormat {
+    Camt053,
+    Csv,
+}
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub struct BankStatementImportSummary {
+    pub total_lines: u32,
+    pub matched_lines: u32,
+    pub unmatched_lines: u32,
+    pub duplicate_lines: u32,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "BankStatementImportId")]
+pub enum BankStatementImportEvent {
+    Initialized {
+        id: BankStatementImportId,
+        file_name: String,
+        format: BankStatementFormat,
+        audit_info: AuditInfo,
+    },
+    Processed {
+        summary: BankStatementImportSummary,
+        audit_info: AuditInfo,
+        recorded_at: DateTime<Utc>,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct BankStatementImport {
+    pub id: BankStatementImportId,
+    pub file_name: String,
+    pub format: BankStatementFormat,
+    #[builder(setter(strip_option), default)]
+    pub summary: Option<BankStatementImportSummary>,
+
+    pub(super) events: EntityEvents<BankStatementImportEvent>,
+}
+
+impl BankStatementImport {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for bank statement import")
+    }
+
+    pub fn processed(
+        &mut self,
+        summary: BankStatementImportSummary,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            BankStatementImportEvent::Processed { .. }
+        );
+        self.events.push(BankStatementImportEvent::Processed {
+            summary,
+            audit_info,
+            recorded_at: Utc::now(),
+        });
+        self.summary = Some(summary);
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<BankStatementImportEvent> for BankStatementImport {
+    fn try_from_events(
+        events: EntityEvents<BankStatementImportEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = BankStatementImportBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                BankStatementImportEvent::Initialized {
+                    id,
+                    file_name,
+                    format,
+                    ..
+                } => {
+                    builder = builder.id(*id).file_name(file_name.clone()).format(*format);
+                }
+                BankStatementImportEvent::Processed { summary, .. } => {
+                    builder = builder.summary(*summary);
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewBankStatementImport {
+    #[builder(setter(into))]
+    pub(super) id: BankStatementImportId,
+    #[builder(setter(into))]
+    pub(super) file_name: String,
+    pub(super) format: BankStatementFormat,
+    #[builder(setter(into))]
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewBankStatementImport {
+    pub fn builder() -> NewBankStatementImportBuilder {
+        NewBankStatementImportBuilder::default()
+    }
+}
+
+impl IntoEvents<BankStatementImportEvent> for NewBankStatementImport {
+    fn into_events(self) -> EntityEvents<BankStatementImportEvent> {
+        EntityEvents::init(
+            self.id,
+            [BankStatementImportEvent::Initialized {
+                id: self.id,
+                file_name: self.file_name,
+                format: self.format,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
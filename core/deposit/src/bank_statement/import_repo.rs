@@ -0,0 +1,24 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::BankStatementImportId;
+
+use super::{error::*, import_entity::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "BankStatementImport",
+    err = "BankStatementError",
+    tbl_prefix = "core"
+)]
+pub struct BankStatementImportRepo {
+    #[allow(dead_code)]
+    pool: PgPool,
+}
+
+impl BankStatementImportRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
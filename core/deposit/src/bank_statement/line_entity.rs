@@ -0,0 +1,268 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{
+    BankStatementImportId, BankStatementLineId, DepositAccountId, DepositId, UsdCents,
+};
+use audit::AuditInfo;
+
+use super::error::BankStatementError;
+
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum BankStatementLineStatus {
+    Unmatched,
+    Matched,
+    Resolved,
+    Dismissed,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "BankStatementLineId")]
+pub enum BankStatementLineEvent {
+    Initialized {
+        id: BankStatementLineId,
+        import_id: BankStatementImportId,
+        bank_transaction_id: String,
+        amount: UsdCents,
+        booking_date: NaiveDate,
+        remittance_information: String,
+        counterparty_name: Option<String>,
+        counterparty_account: Option<String>,
+        audit_info: AuditInfo,
+    },
+    Matched {
+        deposit_account_id: DepositAccountId,
+        deposit_id: DepositId,
+        audit_info: AuditInfo,
+    },
+    Resolved {
+        deposit_account_id: DepositAccountId,
+        deposit_id: DepositId,
+        audit_info: AuditInfo,
+        recorded_at: DateTime<Utc>,
+    },
+    Dismissed {
+        reason: String,
+        audit_info: AuditInfo,
+        recorded_at: DateTime<Utc>,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct BankStatementLine {
+    pub id: BankStatementLineId,
+    pub import_id: BankStatementImportId,
+    pub bank_transaction_id: String,
+    pub amount: UsdCents,
+    pub booking_date: NaiveDate,
+    pub remittance_information: String,
+    pub counterparty_name: Option<String>,
+    pub counterparty_account: Option<String>,
+    #[builder(setter(strip_option), default)]
+    pub deposit_account_id: Option<DepositAccountId>,
+    #[builder(setter(strip_option), default)]
+    pub deposit_id: Option<DepositId>,
+
+    pub(super) events: EntityEvents<BankStatementLineEvent>,
+}
+
+impl BankStatementLine {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for bank statement line")
+    }
+
+    pub fn status(&self) -> BankStatementLineStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                BankStatementLineEvent::Matched { .. } => Some(BankStatementLineStatus::Matched),
+                BankStatementLineEvent::Resolved { .. } => Some(BankStatementLineStatus::Resolved),
+                BankStatementLineEvent::Dismissed { .. } => {
+                    Some(BankStatementLineStatus::Dismissed)
+                }
+                _ => None,
+            })
+            .unwrap_or(BankStatementLineStatus::Unmatched)
+    }
+
+    pub fn dismissal_reason(&self) -> Option<&str> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            BankStatementLineEvent::Dismissed { reason, .. } => Some(reason.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Reference used for the deposit so that a bank transaction is only ever credited once.
+    pub fn deposit_reference(&self) -> String {
+        format!("bank-statement:{}", self.bank_transaction_id)
+    }
+
+    pub fn matched(
+        &mut self,
+        deposit_account_id: DepositAccountId,
+        deposit_id: DepositId,
+        audit_info: AuditInfo,
+    ) -> Result<(), BankStatementError> {
+        self.ensure_unmatched()?;
+
+        self.events.push(BankStatementLineEvent::Matched {
+            deposit_account_id,
+            deposit_id,
+            audit_info,
+        });
+        self.deposit_account_id = Some(deposit_account_id);
+        self.deposit_id = Some(deposit_id);
+
+        Ok(())
+    }
+
+    pub fn resolve(
+        &mut self,
+        deposit_account_id: DepositAccountId,
+        deposit_id: DepositId,
+        audit_info: AuditInfo,
+    ) -> Result<(), BankStatementError> {
+        self.ensure_unmatched()?;
+
+        self.events.push(BankStatementLineEvent::Resolved {
+            deposit_account_id,
+            deposit_id,
+            audit_info,
+            recorded_at: Utc::now(),
+        });
+        self.deposit_account_id = Some(deposit_account_id);
+        self.deposit_id = Some(deposit_id);
+
+        Ok(())
+    }
+
+    pub fn dismiss(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<(), BankStatementError> {
+        self.ensure_unmatched()?;
+
+        self.events.push(BankStatementLineEvent::Dismissed {
+            reason,
+            audit_info,
+            recorded_at: Utc::now(),
+        });
+
+        Ok(())
+    }
+
+    fn ensure_unmatched(&self) -> Result<(), BankStatementError> {
+        if self.status() != BankStatementLineStatus::Unmatched {
+            return Err(BankStatementError::LineAlreadyProcessed(self.id));
+        }
+        Ok(())
+    }
+}
+
+impl TryFromEvents<BankStatementLineEvent> for BankStatementLine {
+    fn try_from_events(
+        events: EntityEvents<BankStatementLineEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = BankStatementLineBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                BankStatementLineEvent::Initialized {
+                    id,
+                    import_id,
+                    bank_transaction_id,
+                    amount,
+                    booking_date,
+                    remittance_information,
+                    counterparty_name,
+                    counterparty_account,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .import_id(*import_id)
+                        .bank_transaction_id(bank_transaction_id.clone())
+                        .amount(*amount)
+                        .booking_date(*booking_date)
+                        .remittance_information(remittance_information.clone())
+                        .counterparty_name(counterparty_name.clone())
+                        .counterparty_account(counterparty_account.clone());
+                }
+                BankStatementLineEvent::Matched {
+                    deposit_account_id,
+                    deposit_id,
+                    ..
+                }
+                | BankStatementLineEvent::Resolved {
+                    deposit_account_id,
+                    deposit_id,
+                    ..
+                } => {
+                    builder = builder
+                        .deposit_account_id(*deposit_account_id)
+                        .deposit_id(*deposit_id);
+                }
+                BankStatementLineEvent::Dismissed { .. } => {}
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewBankStatementLine {
+    #[builder(setter(into))]
+    pub(super) id: BankStatementLineId,
+    #[builder(setter(into))]
+    pub(super) import_id: BankStatementImportId,
+    #[builder(setter(into))]
+    pub(super) bank_transaction_id: String,
+    pub(super) amount: UsdCents,
+    pub(super) booking_date: NaiveDate,
+    #[builder(setter(into))]
+    pub(super) remittance_information: String,
+    pub(super) counterparty_name: Option<String>,
+    pub(super) counterparty_account: Option<String>,
+    #[builder(setter(into))]
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewBankStatementLine {
+    pub fn builder() -> NewBankStatementLineBuilder {
+        NewBankStatementLineBuilder::default()
+    }
+
+    pub(super) fn status(&self) -> BankStatementLineStatus {
+        BankStatementLineStatus::Unmatched
+    }
+}
+
+impl IntoEvents<BankStatementLineEvent> for NewBankStatementLine {
+    fn into_events(self) -> EntityEvents<BankStatementLineEvent> {
+        EntityEvents::init(
+            self.id,
+            [BankStatementLineEvent::Initialized {
+                id: self.id,
+                import_id: self.import_id,
+                bank_transaction_id: self.bank_transaction_id,
+                amount: self.amount,
+                booking_date: self.booking_date,
+                remittance_information: self.remittance_information,
+                counterparty_name: self.counterparty_name,
+                counterparty_account: self.counterparty_account,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
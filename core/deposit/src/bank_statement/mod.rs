@@ -0,0 +1,440 @@
+pub mod error;
+mod import_entity;
+mod import_repo;
+mod line_entity;
+mod line_repo;
+mod parse;
+
+pub(super) use import_entity::*;
+pub use import_entity::{BankStatementFormat, BankStatementImport, BankStatementImportSummary};
+pub use import_repo::bank_statement_import_cursor::BankStatementImportsByCreatedAtCursor;
+pub(super) use import_repo::*;
+pub(super) use line_entity::*;
+pub use line_entity::{BankStatementLine, BankStatementLineStatus};
+pub use line_repo::bank_statement_line_cursor::BankStatementLinesByCreatedAtCursor;
+pub(super) use line_repo::*;
+pub(super) use parse::*;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use outbox::OutboxEventMarker;
+use tracing::instrument;
+
+use crate::{
+    account::{DepositAccount, DepositAccountRepo},
+    checks::check_account_accepts_credits,
+    deposit::{Deposit, DepositRepo, NewDeposit},
+    error::CoreDepositError,
+    event::CoreDepositEvent,
+    ledger::DepositLedger,
+    primitives::*,
+};
+use error::BankStatementError;
+
+pub struct BankStatementImports<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    authz: Perms,
+    imports: BankStatementImportRepo,
+    lines: BankStatementLineRepo,
+    accounts: DepositAccountRepo,
+    deposits: DepositRepo<E>,
+    ledger: DepositLedger,
+}
+
+impl<Perms, E> Clone for BankStatementImports<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            imports: self.imports.clone(),
+            lines: self.lines.clone(),
+            accounts: self.accounts.clone(),
+            deposits: self.deposits.clone(),
+            ledger: self.ledger.clone(),
+        }
+    }
+}
+
+impl<Perms, E> BankStatementImports<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub(crate) fn new(
+        authz: &Perms,
+        imports: &BankStatementImportRepo,
+        lines: &BankStatementLineRepo,
+        accounts: &DepositAccountRepo,
+        deposits: &DepositRepo<E>,
+        ledger: &DepositLedger,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            imports: imports.clone(),
+            lines: lines.clone(),
+            accounts: accounts.clone(),
+            deposits: deposits.clone(),
+            ledger: ledger.clone(),
+        }
+    }
+
+    #[instrument(name = "deposit.import_bank_statement", skip(self, data), err)]
+    pub async fn import_bank_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        file_name: String,
+        format: BankStatementFormat,
+        data: String,
+    ) -> Result<BankStatementImport, CoreDepositError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_bank_statements(),
+                CoreDepositAction::BANK_STATEMENT_IMPORT,
+            )
+            .await?;
+
+        let parsed = parse(format, &data)?;
+
+        let new_import = NewBankStatementImport::builder()
+            .id(BankStatementImportId::new())
+            .file_name(file_name)
+            .format(format)
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("Could not build new bank statement import");
+        let mut import = self.imports.create(new_import).await?;
+
+        let mut summary = BankStatementImportSummary::default();
+        for line in parsed {
+            // Only USD credits can be booked against deposit accounts.
+            if line.currency != "USD" {
+                continue;
+            }
+            summary.total_lines += 1;
+
+            match self
+                .lines
+                .find_by_bank_transaction_id(&line.bank_transaction_id)
+                .await
+            {
+                Ok(_) => {
+                    summary.duplicate_lines += 1;
+                    continue;
+                }
+                Err(e) if e.was_not_found() => (),
+                Err(e) => return Err(e.into()),
+            }
+
+            let amount = UsdCents::try_from_usd(line.amount)
+                .map_err(|_| BankStatementError::InvalidAmount(line.amount.to_string()))?;
+            let deposit_account_id = self
+                .match_bank_statement_line(&line.reference_candidates())
+                .await?;
+            let new_line = NewBankStatementLine::builder()
+                .id(BankStatementLineId::new())
+                .import_id(import.id)
+                .bank_transaction_id(line.bank_transaction_id)
+                .amount(amount)
+                .booking_date(line.booking_date)
+                .remittance_information(line.remittance_information)
+                .counterparty_name(line.counterparty_name)
+                .counterparty_account(line.counterparty_account)
+                .audit_info(audit_info.clone())
+                .build()
+                .expect("Could not build new bank statement line");
+
+            let mut op = self.lines.begin_op().await?;
+            let mut statement_line = self.lines.create_in_op(&mut op, new_line).await?;
+            match deposit_account_id {
+                Some(deposit_account_id) => {
+                    let deposit = self
+                        .create_bank_statement_deposit_in_op(
+                            &mut op,
+                            &statement_line,
+                            deposit_account_id,
+                            audit_info.clone(),
+                        )
+                        .await?;
+                    statement_line.matched(deposit_account_id, deposit.id, audit_info.clone())?;
+                    self.lines
+                        .update_in_op(&mut op, &mut statement_line)
+                        .await?;
+                    self.ledger
+                        .record_deposit(
+                            op,
+                            deposit.id,
+                            DepositAmount::Usd(amount),
+                            deposit_account_id,
+                        )
+                        .await?;
+                    summary.matched_lines += 1;
+                }
+                None => {
+                    op.commit().await?;
+                    summary.unmatched_lines += 1;
+                }
+            }
+        }
+
+        if import.processed(summary, audit_info).did_execute() {
+            self.imports.update(&mut import).await?;
+        }
+
+        Ok(import)
+    }
+
+    #[instrument(name = "deposit.find_bank_statement_import_by_id", skip(self), err)]
+    pub async fn find_bank_statement_import_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<BankStatementImportId> + std::fmt::Debug,
+    ) -> Result<Option<BankStatementImport>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::bank_statement(id),
+                CoreDepositAction::BANK_STATEMENT_READ,
+            )
+            .await?;
+
+        match self.imports.find_by_id(id).await {
+            Ok(import) => Ok(Some(import)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_bank_statement_imports", skip(self), err)]
+    pub async fn list_bank_statement_imports(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<BankStatementImportsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<BankStatementImport, BankStatementImportsByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_bank_statements(),
+                CoreDepositAction::BANK_STATEMENT_LIST,
+            )
+            .await?;
+        Ok(self
+            .imports
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.list_bank_statement_lines_for_import", skip(self), err)]
+    pub async fn list_bank_statement_lines_for_import(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        import_id: impl Into<BankStatementImportId> + std::fmt::Debug,
+        query: es_entity::PaginatedQueryArgs<BankStatementLinesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<BankStatementLine, BankStatementLinesByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        let import_id = import_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::bank_statement(import_id),
+                CoreDepositAction::BANK_STATEMENT_READ,
+            )
+            .await?;
+        Ok(self
+            .lines
+            .list_for_import_id_by_created_at(import_id, query, es_entity::ListDirection::Ascending)
+            .await?)
+    }
+
+    /// The review queue: statement lines that could not be matched to a deposit account.
+    #[instrument(name = "deposit.list_unmatched_bank_statement_lines", skip(self), err)]
+    pub async fn list_unmatched_bank_statement_lines(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<BankStatementLinesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<BankStatementLine, BankStatementLinesByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_bank_statements(),
+                CoreDepositAction::BANK_STATEMENT_LIST,
+            )
+            .await?;
+        Ok(self
+            .lines
+            .list_for_status_by_created_at(
+                BankStatementLineStatus::Unmatched,
+                query,
+                es_entity::ListDirection::Ascending,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "deposit.resolve_bank_statement_line", skip(self), err)]
+    pub async fn resolve_bank_statement_line(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        line_id: impl Into<BankStatementLineId> + std::fmt::Debug,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<BankStatementLine, CoreDepositError> {
+        let line_id = line_id.into();
+        let deposit_account_id = deposit_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_bank_statements(),
+                CoreDepositAction::BANK_STATEMENT_RESOLVE_LINE,
+            )
+            .await?;
+
+        let mut line = self.lines.find_by_id(line_id).await?;
+        check_account_accepts_credits(
+            &self.accounts,
+            deposit_account_id,
+            DepositAccountCurrency::Usd,
+        )
+        .await?;
+
+        let mut op = self.lines.begin_op().await?;
+        let deposit = self
+            .create_bank_statement_deposit_in_op(
+                &mut op,
+                &line,
+                deposit_account_id,
+                audit_info.clone(),
+            )
+            .await?;
+        line.resolve(deposit_account_id, deposit.id, audit_info)?;
+        self.lines.update_in_op(&mut op, &mut line).await?;
+        self.ledger
+            .record_deposit(
+                op,
+                deposit.id,
+                DepositAmount::Usd(line.amount),
+                deposit_account_id,
+            )
+            .await?;
+
+        Ok(line)
+    }
+
+    #[instrument(name = "deposit.dismiss_bank_statement_line", skip(self), err)]
+    pub async fn dismiss_bank_statement_line(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        line_id: impl Into<BankStatementLineId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<BankStatementLine, CoreDepositError> {
+        let line_id = line_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_bank_statements(),
+                CoreDepositAction::BANK_STATEMENT_DISMISS_LINE,
+            )
+            .await?;
+
+        let mut line = self.lines.find_by_id(line_id).await?;
+        line.dismiss(reason, audit_info)?;
+        self.lines.update(&mut line).await?;
+
+        Ok(line)
+    }
+
+    #[instrument(name = "deposit.find_all_bank_statement_imports", skip(self), err)]
+    pub async fn find_all_bank_statement_imports<T: From<BankStatementImport>>(
+        &self,
+        ids: &[BankStatementImportId],
+    ) -> Result<std::collections::HashMap<BankStatementImportId, T>, CoreDepositError> {
+        Ok(self.imports.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.find_all_bank_statement_lines", skip(self), err)]
+    pub async fn find_all_bank_statement_lines<T: From<BankStatementLine>>(
+        &self,
+        ids: &[BankStatementLineId],
+    ) -> Result<std::collections::HashMap<BankStatementLineId, T>, CoreDepositError> {
+        Ok(self.lines.find_all(ids).await?)
+    }
+
+    /// Resolves the references found on a statement line to a single USD deposit
+    /// account, either directly by account id or via the id of its holder.
+    async fn match_bank_statement_line(
+        &self,
+        candidates: &[uuid::Uuid],
+    ) -> Result<Option<DepositAccountId>, CoreDepositError> {
+        let accepts_credits =
+            |account: &DepositAccount| account.accepts_credits(DepositAccountCurrency::Usd);
+
+        for candidate in candidates {
+            match self
+                .accounts
+                .find_by_id(DepositAccountId::from(*candidate))
+                .await
+            {
+                Ok(account) if accepts_credits(&account) => return Ok(Some(account.id)),
+                Ok(_) => continue,
+                Err(e) if e.was_not_found() => (),
+                Err(e) => return Err(e.into()),
+            }
+
+            let mut holder_accounts = self
+                .accounts
+                .list_for_account_holder_id_by_created_at(
+                    DepositAccountHolderId::from(*candidate),
+                    Default::default(),
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?
+                .entities
+                .into_iter()
+                .filter(accepts_credits);
+            if let (Some(account), None) = (holder_accounts.next(), holder_accounts.next()) {
+                return Ok(Some(account.id));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn create_bank_statement_deposit_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        line: &BankStatementLine,
+        deposit_account_id: DepositAccountId,
+        audit_info: audit::AuditInfo,
+    ) -> Result<Deposit, CoreDepositError> {
+        let deposit_id = DepositId::new();
+        let new_deposit = NewDeposit::builder()
+            .id(deposit_id)
+            .ledger_transaction_id(deposit_id)
+            .deposit_account_id(deposit_account_id)
+            .amount(DepositAmount::Usd(line.amount))
+            .reference(Some(line.deposit_reference()))
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new deposit");
+
+        Ok(self.deposits.create_in_op(op, new_deposit).await?)
+    }
+}
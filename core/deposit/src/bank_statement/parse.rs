@@ -0,0 +1,337 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::{error::BankStatementError, import_entity::BankStatementFormat};
+
+/// A single credit line read from an uploaded bank statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedStatementLine {
+    pub bank_transaction_id: String,
+    pub booking_date: NaiveDate,
+    pub amount: Decimal,
+    pub currency: String,
+    pub remittance_information: String,
+    pub counterparty_name: Option<String>,
+    pub counterparty_account: Option<String>,
+}
+
+impl ParsedStatementLine {
+    /// Every UUID mentioned in the remittance information or the counterparty
+    /// account. These are the references we try to resolve to a deposit account.
+    pub fn reference_candidates(&self) -> Vec<Uuid> {
+        let mut candidates = Vec::new();
+        let sources = std::iter::once(self.remittance_information.as_str())
+            .chain(self.counterparty_account.as_deref());
+        for source in sources {
+            for token in source.split(|c: char| !(c.is_ascii_hexdigit() || c == '-')) {
+                if token.len() != 36 && token.len() != 32 {
+                    continue;
+                }
+                if let Ok(id) = Uuid::parse_str(token) {
+                    if !candidates.contains(&id) {
+                        candidates.push(id);
+                    }
+                }
+            }
+        }
+        candidates
+    }
+}
+
+pub fn parse(
+    format: BankStatementFormat,
+    data: &str,
+) -> Result<Vec<ParsedStatementLine>, BankStatementError> {
+    match format {
+        BankStatementFormat::Camt053 => parse_camt053(data),
+        BankStatementFormat::Csv => parse_csv(data),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CsvRow {
+    transaction_id: String,
+    booking_date: NaiveDate,
+    amount: String,
+    currency: String,
+    #[serde(default)]
+    remittance_information: String,
+    #[serde(default)]
+    counterparty_name: Option<String>,
+    #[serde(default)]
+    counterparty_account: Option<String>,
+}
+
+/// Expects a header row with the columns
+/// `transaction_id,booking_date,amount,currency,remittance_information,counterparty_name,counterparty_account`.
+/// Rows with a non-positive amount are debits and are skipped.
+fn parse_csv(data: &str) -> Result<Vec<ParsedStatementLine>, BankStatementError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(data.as_bytes());
+
+    let mut lines = Vec::new();
+    for row in reader.deserialize() {
+        let row: CsvRow = row?;
+        let amount = parse_amount(&row.amount)?;
+        if amount <= Decimal::ZERO {
+            continue;
+        }
+        if row.transaction_id.is_empty() {
+            return Err(BankStatementError::MalformedStatement(
+                "missing transaction_id".to_string(),
+            ));
+        }
+        lines.push(ParsedStatementLine {
+            bank_transaction_id: row.transaction_id,
+            booking_date: row.booking_date,
+            amount,
+            currency: row.currency.to_uppercase(),
+            remittance_information: row.remittance_information,
+            counterparty_name: row.counterparty_name.filter(|s| !s.is_empty()),
+            counterparty_account: row.counterparty_account.filter(|s| !s.is_empty()),
+        });
+    }
+    Ok(lines)
+}
+
+/// Reads the booked credit entries (`<Ntry>` with `CdtDbtInd` = `CRDT`) of a
+/// camt.053 document. Batched entries are imported as a single line.
+fn parse_camt053(data: &str) -> Result<Vec<ParsedStatementLine>, BankStatementError> {
+    let entries = elements(data, "Ntry");
+    if entries.is_empty() && first_element(data, "BkToCstmrStmt").is_none() {
+        return Err(BankStatementError::MalformedStatement(
+            "not a camt.053 document".to_string(),
+        ));
+    }
+
+    let mut lines = Vec::new();
+    for (_, entry) in entries {
+        if text(entry, "CdtDbtInd").as_deref() != Some("CRDT") {
+            continue;
+        }
+        if text(entry, "RvslInd").as_deref() == Some("true") {
+            continue;
+        }
+
+        let (amount_tag, amount_text) = first_element(entry, "Amt").ok_or_else(|| {
+            BankStatementError::MalformedStatement("entry without Amt".to_string())
+        })?;
+        let amount = parse_amount(&unescape(amount_text.trim()))?;
+        if amount <= Decimal::ZERO {
+            continue;
+        }
+        let currency = attribute(amount_tag, "Ccy")
+            .ok_or_else(|| BankStatementError::MalformedStatement("Amt without Ccy".to_string()))?
+            .to_uppercase();
+
+        let booking_date = first_element(entry, "BookgDt")
+            .or_else(|| first_element(entry, "ValDt"))
+            .and_then(|(_, date)| {
+                text(date, "Dt").or_else(|| {
+                    text(date, "DtTm").map(|dt| dt.chars().take(10).collect::<String>())
+                })
+            })
+            .ok_or_else(|| {
+                BankStatementError::MalformedStatement("entry without booking date".to_string())
+            })?;
+        let booking_date = NaiveDate::parse_from_str(&booking_date, "%Y-%m-%d").map_err(|_| {
+            BankStatementError::MalformedStatement(format!("invalid date '{booking_date}'"))
+        })?;
+
+        let bank_transaction_id = ["AcctSvcrRef", "NtryRef", "TxId", "EndToEndId"]
+            .iter()
+            .filter_map(|tag| text(entry, tag))
+            .find(|id| !id.is_empty() && id != "NOTPROVIDED")
+            .ok_or_else(|| {
+                BankStatementError::MalformedStatement(
+                    "entry without transaction reference".to_string(),
+                )
+            })?;
+
+        let mut remittance: Vec<String> = elements(entry, "Ustrd")
+            .into_iter()
+            .map(|(_, s)| unescape(s.trim()))
+            .collect();
+        if let Some((_, creditor_ref)) = first_element(entry, "CdtrRefInf") {
+            remittance.extend(text(creditor_ref, "Ref"));
+        }
+
+        let counterparty_name =
+            first_element(entry, "Dbtr").and_then(|(_, debtor)| text(debtor, "Nm"));
+        let counterparty_account = first_element(entry, "DbtrAcct").and_then(|(_, account)| {
+            text(account, "IBAN")
+                .or_else(|| first_element(account, "Othr").and_then(|(_, other)| text(other, "Id")))
+        });
+
+        lines.push(ParsedStatementLine {
+            bank_transaction_id,
+            booking_date,
+            amount,
+            currency,
+            remittance_information: remittance.join(" "),
+            counterparty_name,
+            counterparty_account,
+        });
+    }
+    Ok(lines)
+}
+
+fn parse_amount(raw: &str) -> Result<Decimal, BankStatementError> {
+    let amount: Decimal = raw
+        .parse()
+        .map_err(|_| BankStatementError::InvalidAmount(raw.to_string()))?;
+    if amount.normalize().scale() > 2 {
+        return Err(BankStatementError::InvalidAmount(raw.to_string()));
+    }
+    Ok(amount)
+}
+
+/// Returns the opening tag and inner content of every `<tag>` element in `xml`.
+fn elements<'a>(xml: &'a str, tag: &str) -> Vec<(&'a str, &'a str)> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+    let mut found = Vec::new();
+    let mut pos = 0;
+    while let Some(idx) = xml[pos..].find(&open) {
+        let start = pos + idx;
+        let after_name = start + open.len();
+        if !matches!(
+            xml[after_name..].chars().next(),
+            Some('>' | '/' | ' ' | '\t' | '\r' | '\n')
+        ) {
+            pos = after_name;
+            continue;
+        }
+        let Some(tag_end) = xml[after_name..].find('>').map(|i| after_name + i + 1) else {
+            break;
+        };
+        let opening_tag = &xml[start..tag_end];
+        if opening_tag.ends_with("/>") {
+            found.push((opening_tag, ""));
+            pos = tag_end;
+            continue;
+        }
+        let Some(close_start) = xml[tag_end..].find(&close).map(|i| tag_end + i) else {
+            break;
+        };
+        found.push((opening_tag, &xml[tag_end..close_start]));
+        pos = close_start + close.len();
+    }
+    found
+}
+
+fn first_element<'a>(xml: &'a str, tag: &str) -> Option<(&'a str, &'a str)> {
+    elements(xml, tag).into_iter().next()
+}
+
+fn text(xml: &str, tag: &str) -> Option<String> {
+    first_element(xml, tag).map(|(_, inner)| unescape(inner.trim()))
+}
+
+fn attribute(opening_tag: &str, name: &str) -> Option<String> {
+    let pattern = format!("{name}=\"");
+    let start = opening_tag.find(&pattern)? + pattern.len();
+    let end = opening_tag[start..].find('"')? + start;
+    Some(unescape(&opening_tag[start..end]))
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    const CAMT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.053.001.02">
+  <BkToCstmrStmt>
+    <Stmt>
+      <Ntry>
+        <Amt Ccy="USD">1250.50</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <BookgDt><Dt>2026-03-02</Dt></BookgDt>
+        <AcctSvcrRef>BANKREF-1</AcctSvcrRef>
+        <NtryDtls>
+          <TxDtls>
+            <AmtDtls><InstdAmt><Amt Ccy="USD">1250.50</Amt></InstdAmt></AmtDtls>
+            <RltdPties>
+              <Dbtr><Nm>Smith &amp; Sons</Nm></Dbtr>
+              <DbtrAcct><Id><IBAN>DE89370400440532013000</IBAN></Id></DbtrAcct>
+            </RltdPties>
+            <RmtInf>
+              <Ustrd>Deposit 0195a3b0-7c4e-7d60-9f1a-2b3c4d5e6f70</Ustrd>
+            </RmtInf>
+          </TxDtls>
+        </NtryDtls>
+      </Ntry>
+      <Ntry>
+        <Amt Ccy="USD">10.00</Amt>
+        <CdtDbtInd>DBIT</CdtDbtInd>
+        <BookgDt><Dt>2026-03-02</Dt></BookgDt>
+        <AcctSvcrRef>BANKREF-2</AcctSvcrRef>
+      </Ntry>
+    </Stmt>
+  </BkToCstmrStmt>
+</Document>"#;
+
+    #[test]
+    fn camt053_reads_credit_entries_only() {
+        let lines = parse_camt053(CAMT).unwrap();
+        assert_eq!(lines.len(), 1);
+        let line = &lines[0];
+        assert_eq!(line.bank_transaction_id, "BANKREF-1");
+        assert_eq!(line.amount, dec!(1250.50));
+        assert_eq!(line.currency, "USD");
+        assert_eq!(
+            line.booking_date,
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()
+        );
+        assert_eq!(line.counterparty_name.as_deref(), Some("Smith & Sons"));
+        assert_eq!(
+            line.counterparty_account.as_deref(),
+            Some("DE89370400440532013000")
+        );
+        assert_eq!(
+            line.reference_candidates(),
+            vec![Uuid::parse_str("0195a3b0-7c4e-7d60-9f1a-2b3c4d5e6f70").unwrap()]
+        );
+    }
+
+    #[test]
+    fn camt053_rejects_other_documents() {
+        assert!(parse_camt053("<Document><Foo/></Document>").is_err());
+    }
+
+    #[test]
+    fn csv_skips_debits_and_reads_optional_columns() {
+        let data = "transaction_id,booking_date,amount,currency,remittance_information,counterparty_name,counterparty_account
+tx-1,2026-03-02,100.00,usd,ref 0195a3b0-7c4e-7d60-9f1a-2b3c4d5e6f70,,
+tx-2,2026-03-02,-50.00,USD,,,
+";
+        let lines = parse_csv(data).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].bank_transaction_id, "tx-1");
+        assert_eq!(lines[0].amount, dec!(100));
+        assert_eq!(lines[0].currency, "USD");
+        assert_eq!(lines[0].counterparty_name, None);
+    }
+
+    #[test]
+    fn sub_cent_amounts_are_rejected() {
+        assert!(matches!(
+            parse_amount("1.005"),
+            Err(BankStatementError::InvalidAmount(_))
+        ));
+        assert_eq!(parse_amount("1.50").unwrap(), dec!(1.5));
+    }
+}
@@ -14,6 +14,11 @@ pub struct ChartOfAccountsIntegrationConfig {
     pub chart_of_account_bank_deposit_accounts_parent_code: AccountCode,
     pub chart_of_account_financial_institution_deposit_accounts_parent_code: AccountCode,
     pub chart_of_account_non_domiciled_individual_deposit_accounts_parent_code: AccountCode,
+    pub chart_of_accounts_time_deposit_accounts_parent_code: AccountCode,
+    pub chart_of_accounts_interest_expense_parent_code: AccountCode,
+    pub chart_of_accounts_accrued_interest_parent_code: AccountCode,
+    pub chart_of_accounts_unclaimed_funds_parent_code: AccountCode,
+    pub chart_of_accounts_fee_income_parent_code: AccountCode,
 }
 
 impl ChartOfAccountsIntegrationConfig {
@@ -0,0 +1,70 @@
+use crate::{
+    account::DepositAccountRepo, error::CoreDepositError, ledger::DepositLedger, primitives::*,
+};
+
+pub(crate) async fn check_account_active(
+    accounts: &DepositAccountRepo,
+    deposit_account_id: DepositAccountId,
+    currency: DepositAccountCurrency,
+) -> Result<(), CoreDepositError> {
+    let account = accounts.find_by_id(deposit_account_id).await?;
+    if account.is_time_deposit() {
+        return Err(CoreDepositError::TimeDepositLocked);
+    }
+    if account.currency != currency {
+        return Err(CoreDepositError::DepositAccountCurrencyMismatch(
+            account.currency,
+            currency,
+        ));
+    }
+    match account.status {
+        DepositAccountStatus::Active => Ok(()),
+        DepositAccountStatus::Inactive => Err(CoreDepositError::DepositAccountNotActive),
+        DepositAccountStatus::Frozen => Err(CoreDepositError::DepositAccountFrozen),
+        DepositAccountStatus::Dormant => Err(CoreDepositError::DepositAccountDormant),
+        DepositAccountStatus::Closed => Err(CoreDepositError::DepositAccountClosed),
+    }
+}
+
+pub(crate) async fn check_account_accepts_credits(
+    accounts: &DepositAccountRepo,
+    deposit_account_id: DepositAccountId,
+    currency: DepositAccountCurrency,
+) -> Result<(), CoreDepositError> {
+    let account = accounts.find_by_id(deposit_account_id).await?;
+    if account.is_time_deposit() {
+        return Err(CoreDepositError::TimeDepositLocked);
+    }
+    if account.currency != currency {
+        return Err(CoreDepositError::DepositAccountCurrencyMismatch(
+            account.currency,
+            currency,
+        ));
+    }
+    match account.status {
+        DepositAccountStatus::Active
+        | DepositAccountStatus::Frozen
+        | DepositAccountStatus::Dormant => Ok(()),
+        DepositAccountStatus::Inactive => Err(CoreDepositError::DepositAccountNotActive),
+        DepositAccountStatus::Closed => Err(CoreDepositError::DepositAccountClosed),
+    }
+}
+
+pub(crate) async fn check_available_balance(
+    ledger: &DepositLedger,
+    deposit_account_id: DepositAccountId,
+    amount: DepositAmount,
+) -> Result<(), CoreDepositError> {
+    let sufficient = match amount {
+        DepositAmount::Usd(amount) => {
+            ledger.balance(deposit_account_id).await?.available() >= amount
+        }
+        DepositAmount::Btc(amount) => {
+            ledger.btc_balance(deposit_account_id).await?.available() >= amount
+        }
+    };
+    if !sufficient {
+        return Err(CoreDepositError::InsufficientAvailableBalance);
+    }
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::UsdCents;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DepositConfig {
+    #[serde(default)]
+    pub transfer_approval_threshold: Option<UsdCents>,
+    #[serde(default = "default_basic_kyc_daily_withdrawal_limit")]
+    pub basic_kyc_daily_withdrawal_limit: UsdCents,
+    #[serde(default = "default_basic_kyc_monthly_withdrawal_limit")]
+    pub basic_kyc_monthly_withdrawal_limit: UsdCents,
+    #[serde(default)]
+    pub withdrawal_payment_debtor: WithdrawalPaymentDebtorConfig,
+    #[serde(default = "default_dormancy_period_days")]
+    pub dormancy_period_days: u32,
+    #[serde(default = "default_escheatment_period_days")]
+    pub escheatment_period_days: u32,
+    #[serde(default)]
+    pub standing_order_retry: StandingOrderRetryConfig,
+}
+
+impl Default for DepositConfig {
+    fn default() -> Self {
+        Self {
+            transfer_approval_threshold: None,
+            basic_kyc_daily_withdrawal_limit: default_basic_kyc_daily_withdrawal_limit(),
+            basic_kyc_monthly_withdrawal_limit: default_basic_kyc_monthly_withdrawal_limit(),
+            withdrawal_payment_debtor: WithdrawalPaymentDebtorConfig::default(),
+            dormancy_period_days: default_dormancy_period_days(),
+            escheatment_period_days: default_escheatment_period_days(),
+            standing_order_retry: StandingOrderRetryConfig::default(),
+        }
+    }
+}
+
+/// The bank account debited in exported withdrawal payment files.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WithdrawalPaymentDebtorConfig {
+    #[serde(default = "default_withdrawal_payment_debtor_name")]
+    pub name: String,
+    #[serde(default)]
+    pub account_number: String,
+    #[serde(default)]
+    pub bic: Option<String>,
+}
+
+impl Default for WithdrawalPaymentDebtorConfig {
+    fn default() -> Self {
+        Self {
+            name: default_withdrawal_payment_debtor_name(),
+            account_number: String::new(),
+            bic: None,
+        }
+    }
+}
+
+/// How a standing order execution that failed for lack of funds is retried before
+/// the occurrence is skipped.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StandingOrderRetryConfig {
+    #[serde(default = "default_standing_order_max_attempts")]
+    pub max_attempts: u32,
+    #[serde(default = "default_standing_order_retry_interval_hours")]
+    pub retry_interval_hours: u32,
+}
+
+impl Default for StandingOrderRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: default_standing_order_max_attempts(),
+            retry_interval_hours: default_standing_order_retry_interval_hours(),
+        }
+    }
+}
+
+impl StandingOrderRetryConfig {
+    pub fn retry_interval(&self) -> chrono::Duration {
+        chrono::Duration::hours(self.retry_interval_hours.into())
+    }
+}
+
+impl DepositConfig {
+    pub fn transfer_requires_approval(&self, amount: UsdCents) -> bool {
+        self.transfer_approval_threshold
+            .is_some_and(|threshold| amount > threshold)
+    }
+
+    pub fn dormancy_period(&self) -> chrono::Duration {
+        chrono::Duration::days(self.dormancy_period_days.into())
+    }
+
+    /// How long an account must have been dormant before its balance can be escheated.
+    pub fn escheatment_period(&self) -> chrono::Duration {
+        chrono::Duration::days(self.escheatment_period_days.into())
+    }
+}
+
+fn default_basic_kyc_daily_withdrawal_limit() -> UsdCents {
+    UsdCents::from(100_000)
+}
+
+fn default_basic_kyc_monthly_withdrawal_limit() -> UsdCents {
+    UsdCents::from(1_000_000)
+}
+
+fn default_dormancy_period_days() -> u32 {
+    365
+}
+
+fn default_escheatment_period_days() -> u32 {
+    1095
+}
+
+fn default_standing_order_max_attempts() -> u32 {
+    3
+}
+
+fn default_standing_order_retry_interval_hours() -> u32 {
+    24
+}
+
+fn default_withdrawal_payment_debtor_name() -> String {
+    "Lana Bank".to_string()
+}
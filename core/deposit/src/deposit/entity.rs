@@ -2,10 +2,27 @@ use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 use audit::AuditInfo;
-use core_money::UsdCents;
 use es_entity::*;
 
-use crate::primitives::{CalaTransactionId, DepositAccountId, DepositId};
+use crate::primitives::{CalaTransactionId, DepositAccountId, DepositAmount, DepositId};
+
+use super::error::DepositError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum DepositStatus {
+    Confirmed,
+    Reverted,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum DepositReversalReason {
+    WrongAccount,
+    WrongAmount,
+    Duplicate,
+    Other,
+}
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -15,10 +32,15 @@ pub enum DepositEvent {
         id: DepositId,
         ledger_transaction_id: CalaTransactionId,
         deposit_account_id: DepositAccountId,
-        amount: UsdCents,
+        amount: DepositAmount,
         reference: String,
         audit_info: AuditInfo,
     },
+    Reverted {
+        ledger_tx_id: CalaTransactionId,
+        reason: DepositReversalReason,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -26,8 +48,10 @@ pub enum DepositEvent {
 pub struct Deposit {
     pub id: DepositId,
     pub deposit_account_id: DepositAccountId,
-    pub amount: UsdCents,
+    pub amount: DepositAmount,
     pub reference: String,
+    #[builder(setter(strip_option), default)]
+    pub reverted_tx_id: Option<CalaTransactionId>,
     pub(super) events: EntityEvents<DepositEvent>,
 }
 
@@ -37,6 +61,47 @@ impl Deposit {
             .entity_first_persisted_at()
             .expect("No events for deposit")
     }
+
+    pub fn revert(
+        &mut self,
+        reason: DepositReversalReason,
+        audit_info: AuditInfo,
+    ) -> Result<CalaTransactionId, DepositError> {
+        if self.is_reverted() {
+            return Err(DepositError::AlreadyReverted(self.id));
+        }
+
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(DepositEvent::Reverted {
+            ledger_tx_id,
+            reason,
+            audit_info,
+        });
+        self.reverted_tx_id = Some(ledger_tx_id);
+
+        Ok(ledger_tx_id)
+    }
+
+    fn is_reverted(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, DepositEvent::Reverted { .. }))
+    }
+
+    pub fn reversal_reason(&self) -> Option<DepositReversalReason> {
+        self.events.iter_all().find_map(|e| match e {
+            DepositEvent::Reverted { reason, .. } => Some(*reason),
+            _ => None,
+        })
+    }
+
+    pub fn status(&self) -> DepositStatus {
+        if self.is_reverted() {
+            DepositStatus::Reverted
+        } else {
+            DepositStatus::Confirmed
+        }
+    }
 }
 
 impl TryFromEvents<DepositEvent> for Deposit {
@@ -57,6 +122,9 @@ impl TryFromEvents<DepositEvent> for Deposit {
                         .amount(*amount)
                         .reference(reference.clone());
                 }
+                DepositEvent::Reverted { ledger_tx_id, .. } => {
+                    builder = builder.reverted_tx_id(*ledger_tx_id);
+                }
             }
         }
         builder.events(events).build()
@@ -72,7 +140,7 @@ pub struct NewDeposit {
     #[builder(setter(into))]
     pub(super) deposit_account_id: DepositAccountId,
     #[builder(setter(into))]
-    pub(super) amount: UsdCents,
+    pub(super) amount: DepositAmount,
     reference: Option<String>,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
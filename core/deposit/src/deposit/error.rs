@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::primitives::DepositId;
+
 #[derive(Error, Debug)]
 pub enum DepositError {
     #[error("DepositError - Sqlx: {0}")]
@@ -8,6 +10,8 @@ pub enum DepositError {
     EsEntityError(es_entity::EsEntityError),
     #[error("DepositError - CursorDestructureError: {0}")]
     CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("DepositError - AlreadyReverted: {0}")]
+    AlreadyReverted(DepositId),
 }
 
 es_entity::from_es_entity_error!(DepositError);
@@ -2,7 +2,7 @@ mod entity;
 pub mod error;
 mod repo;
 
-pub use entity::Deposit;
 pub(crate) use entity::*;
+pub use entity::{Deposit, DepositReversalReason, DepositStatus};
 pub use repo::deposit_cursor::DepositsByCreatedAtCursor;
 pub(crate) use repo::*;
@@ -5,7 +5,7 @@ use outbox::OutboxEventMarker;
 
 use crate::{
     event::CoreDepositEvent,
-    primitives::{DepositAccountId, DepositId},
+    primitives::{CalaTransactionId, DepositAccountId, DepositId},
     publisher::DepositPublisher,
 };
 
@@ -17,6 +17,7 @@ use super::{entity::*, error::*};
     err = "DepositError",
     columns(
         deposit_account_id(ty = "DepositAccountId", list_for, update(persist = false)),
+        reverted_tx_id(ty = "Option<CalaTransactionId>", create(persist = false)),
         reference(ty = "String", create(accessor = "reference()"))
     ),
     tbl_prefix = "core",
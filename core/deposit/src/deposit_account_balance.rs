@@ -1,13 +1,45 @@
-use core_money::UsdCents;
+use core_money::{Satoshis, UsdCents};
 
 pub struct DepositAccountBalance {
     pub settled: UsdCents,
     pub pending: UsdCents,
+    pub encumbrance: UsdCents,
 }
 
 impl DepositAccountBalance {
     pub const ZERO: Self = DepositAccountBalance {
         settled: UsdCents::ZERO,
         pending: UsdCents::ZERO,
+        encumbrance: UsdCents::ZERO,
     };
+
+    pub fn available(&self) -> UsdCents {
+        if self.settled > self.encumbrance {
+            self.settled - self.encumbrance
+        } else {
+            UsdCents::ZERO
+        }
+    }
+}
+
+pub struct BtcDepositAccountBalance {
+    pub settled: Satoshis,
+    pub pending: Satoshis,
+    pub encumbrance: Satoshis,
+}
+
+impl BtcDepositAccountBalance {
+    pub const ZERO: Self = BtcDepositAccountBalance {
+        settled: Satoshis::ZERO,
+        pending: Satoshis::ZERO,
+        encumbrance: Satoshis::ZERO,
+    };
+
+    pub fn available(&self) -> Satoshis {
+        if self.settled > self.encumbrance {
+            self.settled - self.encumbrance
+        } else {
+            Satoshis::ZERO
+        }
+    }
 }
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::primitives::{DepositAccountCurrency, UsdCents};
+
 #[derive(Error, Debug)]
 pub enum CoreDepositError {
     #[error("CoreDepositError - Sqlx: {0}")]
@@ -10,10 +12,34 @@ pub enum CoreDepositError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("CoreDepositError - DepositAccountError: {0}")]
     DepositAccountError(#[from] crate::account::error::DepositAccountError),
+    #[error("CoreDepositError - DepositProductError: {0}")]
+    DepositProductError(#[from] crate::product::error::DepositProductError),
     #[error("CoreDepositError - DepositError: {0}")]
     DepositError(#[from] crate::deposit::error::DepositError),
     #[error("CoreDepositError - WithdrawalError: {0}")]
     WithdrawalError(#[from] crate::withdrawal::error::WithdrawalError),
+    #[error("CoreDepositError - TransferError: {0}")]
+    TransferError(#[from] crate::transfer::error::TransferError),
+    #[error("CoreDepositError - HoldError: {0}")]
+    HoldError(#[from] crate::hold::error::HoldError),
+    #[error("CoreDepositError - StandingOrderError: {0}")]
+    StandingOrderError(#[from] crate::standing_order::error::StandingOrderError),
+    #[error("CoreDepositError - WithdrawalLimitError: {0}")]
+    WithdrawalLimitError(#[from] crate::withdrawal_limit::error::WithdrawalLimitError),
+    #[error("CoreDepositError - WithdrawalLimitOverrideError: {0}")]
+    WithdrawalLimitOverrideError(
+        #[from] crate::withdrawal_limit_override::error::WithdrawalLimitOverrideError,
+    ),
+    #[error("CoreDepositError - DepositAccountStatementError: {0}")]
+    DepositAccountStatementError(#[from] crate::statement::error::DepositAccountStatementError),
+    #[error("CoreDepositError - BankStatementError: {0}")]
+    BankStatementError(#[from] crate::bank_statement::error::BankStatementError),
+    #[error("CoreDepositError - DepositFeeError: {0}")]
+    DepositFeeError(#[from] crate::fee::error::DepositFeeError),
+    #[error("CoreDepositError - PaymentBatchError: {0}")]
+    PaymentBatchError(#[from] crate::payment_batch::error::PaymentBatchError),
+    #[error("CoreDepositError - StorageError: {0}")]
+    StorageError(#[from] cloud_storage::error::StorageError),
     #[error("CoreDepositError - DepositLedgerError: {0}")]
     DepositLedgerError(#[from] crate::ledger::error::DepositLedgerError),
     #[error("CoreDepositError - GovernanceError: {0}")]
@@ -30,12 +56,50 @@ pub enum CoreDepositError {
     SubjectIsNotDepositAccountHolder,
     #[error("CoreDepositError - DepositAccountNotFound")]
     DepositAccountNotFound,
+    #[error("CoreDepositError - DepositAccountHolderMismatch")]
+    DepositAccountHolderMismatch,
+    #[error("CoreDepositError - SignatoryNotAuthorized")]
+    SignatoryNotAuthorized,
     #[error("CoreDepositError - ChartIdMismatch")]
     ChartIdMismatch,
     #[error("CoreDepositError - DepositConfigAlreadyExists")]
     DepositConfigAlreadyExists,
     #[error("CoreDepositError - DepositAccountNotActive")]
     DepositAccountNotActive,
+    #[error("CoreDepositError - DepositAccountFrozen")]
+    DepositAccountFrozen,
+    #[error("CoreDepositError - DepositAccountClosed")]
+    DepositAccountClosed,
+    #[error("CoreDepositError - DepositAccountDormant")]
+    DepositAccountDormant,
+    #[error("CoreDepositError - DepositAccountBalanceNotZero")]
+    DepositAccountBalanceNotZero,
+    #[error("CoreDepositError - InsufficientBalanceForReversal")]
+    InsufficientBalanceForReversal,
+    #[error("CoreDepositError - InsufficientAvailableBalance")]
+    InsufficientAvailableBalance,
+    #[error("CoreDepositError - DepositAccountHasActiveHolds")]
+    DepositAccountHasActiveHolds,
+    #[error("CoreDepositError - TimeDepositLocked")]
+    TimeDepositLocked,
+    #[error("CoreDepositError - TimeDepositMaturityNotInFuture")]
+    TimeDepositMaturityNotInFuture,
+    #[error("CoreDepositError - DepositAccountCurrencyMismatch: expected {0:?}, got {1:?}")]
+    DepositAccountCurrencyMismatch(DepositAccountCurrency, DepositAccountCurrency),
+    #[error(
+        "CoreDepositError - DailyWithdrawalLimitExceeded: limit {limit}, already withdrawn {withdrawn}"
+    )]
+    DailyWithdrawalLimitExceeded {
+        limit: UsdCents,
+        withdrawn: UsdCents,
+    },
+    #[error(
+        "CoreDepositError - MonthlyWithdrawalLimitExceeded: limit {limit}, already withdrawn {withdrawn}"
+    )]
+    MonthlyWithdrawalLimitExceeded {
+        limit: UsdCents,
+        withdrawn: UsdCents,
+    },
 }
 
 impl CoreDepositError {
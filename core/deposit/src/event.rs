@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use super::{DepositAccountId, DepositId, WithdrawalId};
+use super::{
+    DepositAccountId, DepositAmount, DepositId, StandingOrderId, TransferId, WithdrawalId,
+};
 use core_money::UsdCents;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -9,11 +11,29 @@ pub enum CoreDepositEvent {
     DepositInitialized {
         id: DepositId,
         deposit_account_id: DepositAccountId,
-        amount: UsdCents,
+        amount: DepositAmount,
+    },
+    DepositReverted {
+        id: DepositId,
+        deposit_account_id: DepositAccountId,
+        amount: DepositAmount,
     },
     WithdrawalConfirmed {
         id: WithdrawalId,
         deposit_account_id: DepositAccountId,
+        amount: DepositAmount,
+    },
+    TransferExecuted {
+        id: TransferId,
+        from_deposit_account_id: DepositAccountId,
+        to_deposit_account_id: DepositAccountId,
+        amount: UsdCents,
+    },
+    StandingOrderRepaymentDue {
+        id: StandingOrderId,
+        deposit_account_id: DepositAccountId,
+        credit_facility_id: uuid::Uuid,
         amount: UsdCents,
+        execution_date: chrono::NaiveDate,
     },
 }
@@ -0,0 +1,173 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::{
+    CalaTransactionId, DepositAccountId, DepositFeeId, DepositFeeType, UsdCents,
+};
+
+use super::error::DepositFeeError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum DepositFeeStatus {
+    Charged,
+    Waived,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "DepositFeeId")]
+pub enum DepositFeeEvent {
+    Initialized {
+        id: DepositFeeId,
+        ledger_tx_id: CalaTransactionId,
+        deposit_account_id: DepositAccountId,
+        fee_type: DepositFeeType,
+        amount: UsdCents,
+        reference: String,
+        audit_info: AuditInfo,
+    },
+    Waived {
+        ledger_tx_id: CalaTransactionId,
+        reason: String,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct DepositFee {
+    pub id: DepositFeeId,
+    pub deposit_account_id: DepositAccountId,
+    pub fee_type: DepositFeeType,
+    pub amount: UsdCents,
+    pub reference: String,
+    pub ledger_tx_id: CalaTransactionId,
+    #[builder(setter(strip_option), default)]
+    pub waived_tx_id: Option<CalaTransactionId>,
+    pub(super) events: EntityEvents<DepositFeeEvent>,
+}
+
+impl DepositFee {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for deposit fee")
+    }
+
+    pub fn status(&self) -> DepositFeeStatus {
+        if self.waived_tx_id.is_some() {
+            DepositFeeStatus::Waived
+        } else {
+            DepositFeeStatus::Charged
+        }
+    }
+
+    pub fn waiver_reason(&self) -> Option<&str> {
+        self.events.iter_all().find_map(|e| match e {
+            DepositFeeEvent::Waived { reason, .. } => Some(reason.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn waive(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<CalaTransactionId, DepositFeeError> {
+        if self.waived_tx_id.is_some() {
+            return Err(DepositFeeError::AlreadyWaived(self.id));
+        }
+
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(DepositFeeEvent::Waived {
+            ledger_tx_id,
+            reason,
+            audit_info,
+        });
+        self.waived_tx_id = Some(ledger_tx_id);
+
+        Ok(ledger_tx_id)
+    }
+}
+
+impl TryFromEvents<DepositFeeEvent> for DepositFee {
+    fn try_from_events(events: EntityEvents<DepositFeeEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = DepositFeeBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                DepositFeeEvent::Initialized {
+                    id,
+                    ledger_tx_id,
+                    deposit_account_id,
+                    fee_type,
+                    amount,
+                    reference,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .ledger_tx_id(*ledger_tx_id)
+                        .deposit_account_id(*deposit_account_id)
+                        .fee_type(*fee_type)
+                        .amount(*amount)
+                        .reference(reference.clone());
+                }
+                DepositFeeEvent::Waived { ledger_tx_id, .. } => {
+                    builder = builder.waived_tx_id(*ledger_tx_id);
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewDepositFee {
+    #[builder(setter(into))]
+    pub(super) id: DepositFeeId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    pub(super) fee_type: DepositFeeType,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    #[builder(setter(into))]
+    pub(super) reference: String,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewDepositFee {
+    pub fn builder() -> NewDepositFeeBuilder {
+        NewDepositFeeBuilder::default()
+    }
+}
+
+pub(crate) fn monthly_fee_reference(
+    deposit_account_id: DepositAccountId,
+    fee_type: DepositFeeType,
+    period_start: NaiveDate,
+) -> String {
+    format!("{deposit_account_id}:{fee_type}:{period_start}")
+}
+
+impl IntoEvents<DepositFeeEvent> for NewDepositFee {
+    fn into_events(self) -> EntityEvents<DepositFeeEvent> {
+        EntityEvents::init(
+            self.id,
+            [DepositFeeEvent::Initialized {
+                id: self.id,
+                ledger_tx_id: CalaTransactionId::from(self.id),
+                deposit_account_id: self.deposit_account_id,
+                fee_type: self.fee_type,
+                amount: self.amount,
+                reference: self.reference,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+use crate::primitives::DepositFeeId;
+
+#[derive(Error, Debug)]
+pub enum DepositFeeError {
+    #[error("DepositFeeError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("DepositFeeError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("DepositFeeError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("DepositFeeError - AlreadyWaived: {0}")]
+    AlreadyWaived(DepositFeeId),
+}
+
+es_entity::from_es_entity_error!(DepositFeeError);
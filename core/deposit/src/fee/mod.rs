@@ -0,0 +1,215 @@
+mod entity;
+pub mod error;
+mod repo;
+
+pub(crate) use entity::*;
+pub use entity::{DepositFee, DepositFeeStatus};
+pub use repo::deposit_fee_cursor::DepositFeesByCreatedAtCursor;
+pub(crate) use repo::*;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use tracing::instrument;
+
+use crate::{
+    account::DepositAccountRepo,
+    checks::check_account_accepts_credits,
+    error::CoreDepositError,
+    ledger::{DepositFeeCharge, DepositLedger},
+    primitives::*,
+};
+
+pub struct DepositFees<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Perms,
+    repo: DepositFeeRepo,
+    accounts: DepositAccountRepo,
+    ledger: DepositLedger,
+}
+
+impl<Perms> Clone for DepositFees<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            repo: self.repo.clone(),
+            accounts: self.accounts.clone(),
+            ledger: self.ledger.clone(),
+        }
+    }
+}
+
+impl<Perms> DepositFees<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub(crate) fn new(
+        authz: &Perms,
+        repo: &DepositFeeRepo,
+        accounts: &DepositAccountRepo,
+        ledger: &DepositLedger,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            repo: repo.clone(),
+            accounts: accounts.clone(),
+            ledger: ledger.clone(),
+        }
+    }
+
+    #[instrument(name = "deposit.waive_fee", skip(self), err)]
+    pub async fn waive_fee(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        fee_id: impl Into<DepositFeeId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<DepositFee, CoreDepositError> {
+        let id = fee_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_fee(id),
+                CoreDepositAction::DEPOSIT_FEE_WAIVE,
+            )
+            .await?;
+        let mut fee = self.repo.find_by_id(id).await?;
+        check_account_accepts_credits(
+            &self.accounts,
+            fee.deposit_account_id,
+            DepositAccountCurrency::Usd,
+        )
+        .await?;
+
+        let mut op = self.repo.begin_op().await?;
+        let tx_id = fee.waive(reason, audit_info)?;
+        self.repo.update_in_op(&mut op, &mut fee).await?;
+        self.ledger
+            .waive_fee(op, tx_id, fee.amount, fee.deposit_account_id)
+            .await?;
+        Ok(fee)
+    }
+
+    #[instrument(name = "deposit.find_deposit_fee_by_id", skip(self), err)]
+    pub async fn find_deposit_fee_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositFeeId> + std::fmt::Debug,
+    ) -> Result<Option<DepositFee>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_fee(id),
+                CoreDepositAction::DEPOSIT_FEE_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(fee) => Ok(Some(fee)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub async fn find_deposit_fee_by_waived_tx_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        waived_tx_id: impl Into<CalaTransactionId> + std::fmt::Debug,
+    ) -> Result<DepositFee, CoreDepositError> {
+        let waived_tx_id = waived_tx_id.into();
+        let fee = self.repo.find_by_waived_tx_id(Some(waived_tx_id)).await?;
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_fee(fee.id),
+                CoreDepositAction::DEPOSIT_FEE_READ,
+            )
+            .await?;
+
+        Ok(fee)
+    }
+
+    #[instrument(name = "deposit.list_fees_for_account", skip(self), err)]
+    pub async fn list_fees_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<DepositFee>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_fees(),
+                CoreDepositAction::DEPOSIT_FEE_LIST,
+            )
+            .await?;
+        Ok(self
+            .repo
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[instrument(name = "deposit.find_all_deposit_fees", skip(self), err)]
+    pub async fn find_all_deposit_fees<T: From<DepositFee>>(
+        &self,
+        ids: &[DepositFeeId],
+    ) -> Result<std::collections::HashMap<DepositFeeId, T>, CoreDepositError> {
+        Ok(self.repo.find_all(ids).await?)
+    }
+
+    pub(crate) async fn waive_withdrawal_fee_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        withdrawal_id: WithdrawalId,
+        reason: &str,
+        audit_info: audit::AuditInfo,
+    ) -> Result<Option<DepositFeeCharge>, CoreDepositError> {
+        Ok(self
+            .repo
+            .waive_withdrawal_fee_in_op(op, withdrawal_id, reason, audit_info)
+            .await?
+            .map(|fee| DepositFeeCharge {
+                tx_id: fee.waived_tx_id.expect("fee was just waived").into(),
+                amount: fee.amount,
+            }))
+    }
+    pub(crate) async fn charge_withdrawal_fee_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        withdrawal_id: WithdrawalId,
+        deposit_account_id: DepositAccountId,
+        amount: UsdCents,
+        audit_info: audit::AuditInfo,
+    ) -> Result<Option<DepositFeeCharge>, CoreDepositError> {
+        if amount.is_zero() {
+            return Ok(None);
+        }
+        let fee_id = DepositFeeId::new();
+        let new_fee = NewDepositFee::builder()
+            .id(fee_id)
+            .deposit_account_id(deposit_account_id)
+            .fee_type(DepositFeeType::Withdrawal)
+            .amount(amount)
+            .reference(withdrawal_id.to_string())
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new deposit fee");
+        self.repo.create_in_op(op, new_fee).await?;
+        Ok(Some(DepositFeeCharge {
+            tx_id: fee_id.into(),
+            amount,
+        }))
+    }
+}
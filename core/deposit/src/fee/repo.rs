@@ -0,0 +1,53 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use audit::AuditInfo;
+
+use crate::primitives::{CalaTransactionId, DepositAccountId, DepositFeeId, WithdrawalId};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "DepositFee",
+    err = "DepositFeeError",
+    columns(
+        deposit_account_id(ty = "DepositAccountId", list_for, update(persist = false)),
+        waived_tx_id(ty = "Option<CalaTransactionId>", create(persist = false)),
+        reference(ty = "String", update(persist = false))
+    ),
+    tbl_prefix = "core"
+)]
+pub struct DepositFeeRepo {
+    #[allow(dead_code)]
+    pool: PgPool,
+}
+
+impl DepositFeeRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+
+    /// Waives the fee charged for initiating the withdrawal unless it has already been
+    /// waived. Returns the waived fee so the caller can credit it back on the ledger.
+    pub async fn waive_withdrawal_fee_in_op(
+        &self,
+        op: &mut DbOp<'_>,
+        withdrawal_id: WithdrawalId,
+        reason: &str,
+        audit_info: AuditInfo,
+    ) -> Result<Option<DepositFee>, DepositFeeError> {
+        let mut fee = match self.find_by_reference(withdrawal_id.to_string()).await {
+            Ok(fee) => fee,
+            Err(e) if e.was_not_found() => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        if fee.status() == DepositFeeStatus::Waived {
+            return Ok(None);
+        }
+        fee.waive(reason.to_string(), audit_info)?;
+        self.update_in_op(op, &mut fee).await?;
+        Ok(Some(fee))
+    }
+}
@@ -1,5 +1,6 @@
-use audit::AuditSvc;
+use audit::{AuditInfo, AuditSvc};
 use authz::PermissionCheck;
+use cloud_storage::Storage;
 use outbox::OutboxEventMarker;
 use tracing::instrument;
 
@@ -10,9 +11,10 @@ use crate::{
     deposit_account_cursor::DepositAccountsByCreatedAtCursor,
     error::*,
     event::*,
-    history::{DepositAccountHistoryCursor, DepositAccountHistoryEntry},
+    history::{self, DepositAccountHistoryCursor, DepositAccountHistoryEntry},
     ledger::*,
     primitives::*,
+    statement::*,
     withdrawal::*,
 };
 
@@ -26,7 +28,9 @@ where
     accounts: &'a DepositAccountRepo,
     deposits: &'a DepositRepo<E>,
     withdrawals: &'a WithdrawalRepo<E>,
+    statements: &'a DepositAccountStatementRepo,
     ledger: &'a DepositLedger,
+    storage: &'a Storage,
     authz: &'a Perms,
 }
 
@@ -39,13 +43,16 @@ where
         From<CoreDepositObject> + From<GovernanceObject>,
     E: OutboxEventMarker<CoreDepositEvent>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn new(
         subject: &'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         account_holder_id: DepositAccountHolderId,
         accounts: &'a DepositAccountRepo,
         deposits: &'a DepositRepo<E>,
         withdrawals: &'a WithdrawalRepo<E>,
+        statements: &'a DepositAccountStatementRepo,
         ledger: &'a DepositLedger,
+        storage: &'a Storage,
         authz: &'a Perms,
     ) -> Self {
         Self {
@@ -54,7 +61,9 @@ where
             accounts,
             deposits,
             withdrawals,
+            statements,
             ledger,
+            storage,
             authz,
         }
     }
@@ -123,13 +132,19 @@ where
         )
         .await?;
 
+        let account = self.accounts.find_by_id(account_id).await?;
+        let after = query.after.clone();
         let history = self
             .ledger
             .account_history::<DepositAccountHistoryEntry, DepositAccountHistoryCursor>(
                 account_id, query,
             )
             .await?;
-        Ok(history)
+        Ok(history::with_status_changes(
+            history,
+            after.as_ref(),
+            account.status_changes(),
+        ))
     }
 
     pub async fn list_deposits_for_account(
@@ -173,6 +188,26 @@ where
         Ok(deposit)
     }
 
+    pub async fn find_deposit_by_reverted_tx_id(
+        &self,
+        reverted_tx_id: impl Into<CalaTransactionId> + std::fmt::Debug,
+    ) -> Result<Deposit, CoreDepositError> {
+        let reverted_tx_id = reverted_tx_id.into();
+        let deposit = self
+            .deposits
+            .find_by_reverted_tx_id(Some(reverted_tx_id))
+            .await?;
+
+        self.ensure_account_access(
+            deposit.deposit_account_id,
+            CoreDepositObject::deposit(deposit.id),
+            CoreDepositAction::DEPOSIT_READ,
+        )
+        .await?;
+
+        Ok(deposit)
+    }
+
     pub async fn list_withdrawals_for_account(
         &self,
         account_id: impl Into<DepositAccountId> + std::fmt::Debug,
@@ -234,12 +269,61 @@ where
         Ok(withdrawal)
     }
 
+    pub async fn list_statements_for_account(
+        &self,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<DepositAccountStatement>, CoreDepositError> {
+        let account_id = account_id.into();
+
+        self.ensure_account_access(
+            account_id,
+            CoreDepositObject::all_statements(),
+            CoreDepositAction::STATEMENT_LIST,
+        )
+        .await?;
+
+        Ok(self
+            .statements
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[instrument(
+        name = "deposit.for_subject.generate_statement_download_links",
+        skip(self),
+        err
+    )]
+    pub async fn generate_statement_download_links(
+        &self,
+        statement_id: impl Into<DepositAccountStatementId> + std::fmt::Debug,
+    ) -> Result<DepositAccountStatementDownloadLinks, CoreDepositError> {
+        let statement_id = statement_id.into();
+        let mut statement = self.statements.find_by_id(statement_id).await?;
+
+        let audit_info = self
+            .ensure_account_access(
+                statement.deposit_account_id,
+                CoreDepositObject::statement(statement_id),
+                CoreDepositAction::STATEMENT_GENERATE_DOWNLOAD_LINK,
+            )
+            .await?;
+
+        let links = statement_download_links(self.storage, &mut statement, audit_info).await?;
+        self.statements.update(&mut statement).await?;
+        Ok(links)
+    }
+
     async fn ensure_account_access(
         &self,
         account_id: DepositAccountId,
         object: CoreDepositObject,
         action: CoreDepositAction,
-    ) -> Result<(), CoreDepositError> {
+    ) -> Result<AuditInfo, CoreDepositError> {
         let account = self.accounts.find_by_id(account_id).await?;
 
         if account.account_holder_id != self.account_holder_id {
@@ -249,11 +333,12 @@ where
                 .await?;
             return Err(CoreDepositError::DepositAccountNotFound);
         }
-        self.authz
+        let audit_info = self
+            .authz
             .audit()
             .record_entry(self.sub, object, action, true)
             .await?;
 
-        Ok(())
+        Ok(audit_info)
     }
 }
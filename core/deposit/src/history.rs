@@ -1,17 +1,41 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::primitives::{CalaEntryId, CalaTransactionId as CalaTxId};
+use crate::primitives::{CalaEntryId, CalaTransactionId as CalaTxId, DepositAccountStatus};
 
 pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
+    RevertedDeposit(DepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(WithdrawalEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
+    TransferIn(TransferEntry),
+    TransferOut(TransferEntry),
+    UnclaimedFundsTransfer(UnclaimedFundsTransferEntry),
+    Fee(FeeEntry),
+    WaivedFee(FeeEntry),
+    StatusChanged(AccountStatusChangeEntry),
     Unknown(UnknownEntry),
     Ignored,
 }
+
+impl DepositAccountHistoryEntry {
+    fn recorded_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Deposit(entry) | Self::RevertedDeposit(entry) => Some(entry.recorded_at),
+            Self::Withdrawal(entry) | Self::CancelledWithdrawal(entry) => Some(entry.recorded_at),
+            Self::Disbursal(entry) => Some(entry.recorded_at),
+            Self::Payment(entry) => Some(entry.recorded_at),
+            Self::TransferIn(entry) | Self::TransferOut(entry) => Some(entry.recorded_at),
+            Self::UnclaimedFundsTransfer(entry) => Some(entry.recorded_at),
+            Self::Fee(entry) | Self::WaivedFee(entry) => Some(entry.recorded_at),
+            Self::StatusChanged(entry) => Some(entry.recorded_at),
+            Self::Unknown(entry) => Some(entry.recorded_at),
+            Self::Ignored => None,
+        }
+    }
+}
 pub struct DepositEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
@@ -36,6 +60,30 @@ pub struct PaymentEntry {
     pub recorded_at: DateTime<Utc>,
 }
 
+pub struct TransferEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+}
+
+pub struct UnclaimedFundsTransferEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+}
+
+pub struct FeeEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+}
+
+pub struct AccountStatusChangeEntry {
+    pub status: DepositAccountStatus,
+    pub reason: String,
+    pub recorded_at: DateTime<Utc>,
+}
+
 pub struct UnknownEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
@@ -43,14 +91,23 @@ pub struct UnknownEntry {
 }
 
 const RECORD_DEPOSIT: &str = "RECORD_DEPOSIT_CR";
+const REVERT_DEPOSIT: &str = "REVERT_DEPOSIT_DR";
 const INITIATE_WITHDRAW: &str = "INITIATE_WITHDRAW_SETTLED_DR";
 const CANCEL_WITHDRAW: &str = "CANCEL_WITHDRAW_SETTLED_CR";
 const CONFIRM_DISBURSAL: &str = "CONFIRM_DISBURSAL_PENDING_CR";
 const RECORD_PAYMENT_ALLOCATION: &str = "RECORD_PAYMENT_ALLOCATION_DR";
+const RECORD_PAYMENT_FROM_DEPOSIT: &str = "RECORD_PAYMENT_FROM_DEPOSIT_DR";
+const TRANSFER_IN: &str = "TRANSFER_SETTLED_CR";
+const TRANSFER_OUT: &str = "TRANSFER_SETTLED_DR";
+const TRANSFER_UNCLAIMED_FUNDS: &str = "TRANSFER_UNCLAIMED_FUNDS_DR";
+const CHARGE_DEPOSIT_FEE: &str = "CHARGE_DEPOSIT_FEE_DR";
+const WAIVE_DEPOSIT_FEE: &str = "WAIVE_DEPOSIT_FEE_CR";
 
 const IGNORE_INITIATE_WITHDRAW_PENDING: &str = "INITIATE_WITHDRAW_PENDING_CR";
 const IGNORE_CONFIRM_WITHDRAWAL_PENDING: &str = "CONFIRM_WITHDRAW_PENDING_DR";
 const IGNORE_CANCEL_WITHDRAW_PENDING: &str = "CANCEL_WITHDRAW_PENDING_DR";
+const IGNORE_PLACE_HOLD: &str = "PLACE_HOLD_CR";
+const IGNORE_RELEASE_HOLD: &str = "RELEASE_HOLD_DR";
 
 impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
     fn from(entry: cala_ledger::entry::Entry) -> Self {
@@ -60,6 +117,11 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
             }),
+            REVERT_DEPOSIT => DepositAccountHistoryEntry::RevertedDeposit(DepositEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                recorded_at: entry.created_at(),
+            }),
             INITIATE_WITHDRAW => DepositAccountHistoryEntry::Withdrawal(WithdrawalEntry {
                 tx_id: entry.values().transaction_id,
                 entry_id: entry.id,
@@ -75,7 +137,36 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
             }),
-            RECORD_PAYMENT_ALLOCATION => DepositAccountHistoryEntry::Payment(PaymentEntry {
+            RECORD_PAYMENT_ALLOCATION | RECORD_PAYMENT_FROM_DEPOSIT => {
+                DepositAccountHistoryEntry::Payment(PaymentEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                })
+            }
+            TRANSFER_IN => DepositAccountHistoryEntry::TransferIn(TransferEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                recorded_at: entry.created_at(),
+            }),
+            TRANSFER_OUT => DepositAccountHistoryEntry::TransferOut(TransferEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                recorded_at: entry.created_at(),
+            }),
+            TRANSFER_UNCLAIMED_FUNDS => {
+                DepositAccountHistoryEntry::UnclaimedFundsTransfer(UnclaimedFundsTransferEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    recorded_at: entry.created_at(),
+                })
+            }
+            CHARGE_DEPOSIT_FEE => DepositAccountHistoryEntry::Fee(FeeEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                recorded_at: entry.created_at(),
+            }),
+            WAIVE_DEPOSIT_FEE => DepositAccountHistoryEntry::WaivedFee(FeeEntry {
                 tx_id: entry.values().transaction_id,
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
@@ -84,6 +175,8 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
             IGNORE_CONFIRM_WITHDRAWAL_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_INITIATE_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_CANCEL_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
+            IGNORE_PLACE_HOLD => DepositAccountHistoryEntry::Ignored,
+            IGNORE_RELEASE_HOLD => DepositAccountHistoryEntry::Ignored,
 
             _ => DepositAccountHistoryEntry::Unknown(UnknownEntry {
                 tx_id: entry.values().transaction_id,
@@ -107,6 +200,10 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
             },
+            DepositAccountHistoryEntry::RevertedDeposit(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
             DepositAccountHistoryEntry::Withdrawal(entry) => Self {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
@@ -123,6 +220,30 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
             },
+            DepositAccountHistoryEntry::TransferIn(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::TransferOut(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::UnclaimedFundsTransfer(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::Fee(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::WaivedFee(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::StatusChanged(entry) => Self {
+                entry_id: CalaEntryId::from(uuid::Uuid::nil()),
+                created_at: entry.recorded_at,
+            },
             DepositAccountHistoryEntry::Unknown(entry) => Self {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
@@ -134,6 +255,39 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
     }
 }
 
+/// Interleaves account status changes with the ledger entries of a history page,
+/// keeping only the changes that fall within the time range covered by the page.
+pub(crate) fn with_status_changes(
+    mut history: es_entity::PaginatedQueryRet<
+        DepositAccountHistoryEntry,
+        DepositAccountHistoryCursor,
+    >,
+    after: Option<&DepositAccountHistoryCursor>,
+    changes: Vec<AccountStatusChangeEntry>,
+) -> es_entity::PaginatedQueryRet<DepositAccountHistoryEntry, DepositAccountHistoryCursor> {
+    let newest = after.map(|cursor| cursor.created_at);
+    let oldest = if history.has_next_page {
+        history
+            .entities
+            .iter()
+            .rev()
+            .find_map(DepositAccountHistoryEntry::recorded_at)
+    } else {
+        None
+    };
+    history.entities.extend(
+        changes
+            .into_iter()
+            .filter(|change| newest.is_none_or(|newest| change.recorded_at < newest))
+            .filter(|change| oldest.is_none_or(|oldest| change.recorded_at > oldest))
+            .map(DepositAccountHistoryEntry::StatusChanged),
+    );
+    history
+        .entities
+        .sort_by_key(|entry| std::cmp::Reverse(entry.recorded_at()));
+    history
+}
+
 impl From<cala_ledger::entry::EntriesByCreatedAtCursor> for DepositAccountHistoryCursor {
     fn from(cursor: cala_ledger::entry::EntriesByCreatedAtCursor) -> Self {
         Self {
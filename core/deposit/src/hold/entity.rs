@@ -0,0 +1,180 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{CalaTransactionId, DepositAccountId, HoldId, UsdCents};
+use audit::AuditInfo;
+
+use super::error::HoldError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum HoldStatus {
+    Active,
+    Released,
+    Expired,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "HoldId")]
+pub enum HoldEvent {
+    Initialized {
+        id: HoldId,
+        deposit_account_id: DepositAccountId,
+        amount: UsdCents,
+        reason: String,
+        expires_at: Option<DateTime<Utc>>,
+        ledger_tx_id: CalaTransactionId,
+        audit_info: AuditInfo,
+    },
+    Released {
+        ledger_tx_id: CalaTransactionId,
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    Expired {
+        ledger_tx_id: CalaTransactionId,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Hold {
+    pub id: HoldId,
+    pub deposit_account_id: DepositAccountId,
+    pub amount: UsdCents,
+    pub reason: String,
+    #[builder(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+    pub ledger_tx_id: CalaTransactionId,
+
+    pub(super) events: EntityEvents<HoldEvent>,
+}
+
+impl Hold {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for hold")
+    }
+
+    pub fn status(&self) -> HoldStatus {
+        self.events
+            .iter_all()
+            .find_map(|e| match e {
+                HoldEvent::Released { .. } => Some(HoldStatus::Released),
+                HoldEvent::Expired { .. } => Some(HoldStatus::Expired),
+                _ => None,
+            })
+            .unwrap_or(HoldStatus::Active)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status() == HoldStatus::Active
+    }
+
+    pub fn release(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<CalaTransactionId, HoldError> {
+        match self.status() {
+            HoldStatus::Released => return Err(HoldError::AlreadyReleased(self.id)),
+            HoldStatus::Expired => return Err(HoldError::AlreadyExpired(self.id)),
+            HoldStatus::Active => (),
+        }
+
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(HoldEvent::Released {
+            ledger_tx_id,
+            reason,
+            audit_info,
+        });
+
+        Ok(ledger_tx_id)
+    }
+
+    pub fn expire(&mut self, audit_info: AuditInfo) -> Idempotent<CalaTransactionId> {
+        if !self.is_active() {
+            return Idempotent::Ignored;
+        }
+
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(HoldEvent::Expired {
+            ledger_tx_id,
+            audit_info,
+        });
+
+        Idempotent::Executed(ledger_tx_id)
+    }
+}
+
+impl TryFromEvents<HoldEvent> for Hold {
+    fn try_from_events(events: EntityEvents<HoldEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = HoldBuilder::default();
+        for event in events.iter_all() {
+            if let HoldEvent::Initialized {
+                id,
+                deposit_account_id,
+                amount,
+                reason,
+                expires_at,
+                ledger_tx_id,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .deposit_account_id(*deposit_account_id)
+                    .amount(*amount)
+                    .reason(reason.clone())
+                    .expires_at(*expires_at)
+                    .ledger_tx_id(*ledger_tx_id)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewHold {
+    #[builder(setter(into))]
+    pub(super) id: HoldId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    #[builder(setter(into))]
+    pub(super) reason: String,
+    #[builder(default)]
+    pub(super) expires_at: Option<DateTime<Utc>>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewHold {
+    pub fn builder() -> NewHoldBuilder {
+        NewHoldBuilder::default()
+    }
+}
+
+impl IntoEvents<HoldEvent> for NewHold {
+    fn into_events(self) -> EntityEvents<HoldEvent> {
+        EntityEvents::init(
+            self.id,
+            [HoldEvent::Initialized {
+                id: self.id,
+                ledger_tx_id: CalaTransactionId::from(self.id),
+                deposit_account_id: self.deposit_account_id,
+                amount: self.amount,
+                reason: self.reason,
+                expires_at: self.expires_at,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
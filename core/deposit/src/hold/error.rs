@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::primitives::HoldId;
+
+#[derive(Error, Debug)]
+pub enum HoldError {
+    #[error("HoldError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("HoldError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("HoldError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("HoldError - AlreadyReleased: {0}")]
+    AlreadyReleased(HoldId),
+    #[error("HoldError - AlreadyExpired: {0}")]
+    AlreadyExpired(HoldId),
+    #[error("HoldError - ExpiryInPast")]
+    ExpiryInPast,
+}
+
+es_entity::from_es_entity_error!(HoldError);
@@ -0,0 +1,213 @@
+mod entity;
+pub mod error;
+pub mod repo;
+
+pub(super) use entity::*;
+pub use entity::{Hold, HoldStatus};
+pub use repo::hold_cursor::HoldsByCreatedAtCursor;
+pub(super) use repo::*;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::Jobs;
+use tracing::instrument;
+
+use crate::{
+    account::DepositAccountRepo, error::CoreDepositError, jobs::hold_expiry::HoldExpiryJobConfig,
+    ledger::DepositLedger, primitives::*,
+};
+
+pub struct Holds<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Perms,
+    repo: HoldRepo,
+    accounts: DepositAccountRepo,
+    ledger: DepositLedger,
+    jobs: Jobs,
+}
+
+impl<Perms> Clone for Holds<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            repo: self.repo.clone(),
+            accounts: self.accounts.clone(),
+            ledger: self.ledger.clone(),
+            jobs: self.jobs.clone(),
+        }
+    }
+}
+
+impl<Perms> Holds<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub(crate) fn new(
+        authz: &Perms,
+        repo: &HoldRepo,
+        accounts: &DepositAccountRepo,
+        ledger: &DepositLedger,
+        jobs: &Jobs,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            repo: repo.clone(),
+            accounts: accounts.clone(),
+            ledger: ledger.clone(),
+            jobs: jobs.clone(),
+        }
+    }
+
+    #[instrument(name = "deposit.place_hold", skip(self), err)]
+    pub async fn place_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        reason: String,
+        expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Hold, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_holds(),
+                CoreDepositAction::HOLD_PLACE,
+            )
+            .await?;
+        if expires_at.is_some_and(|expires_at| expires_at <= chrono::Utc::now()) {
+            return Err(error::HoldError::ExpiryInPast.into());
+        }
+        let account = self.accounts.find_by_id(deposit_account_id).await?;
+        if account.status.is_closed() {
+            return Err(CoreDepositError::DepositAccountClosed);
+        }
+        if account.currency != DepositAccountCurrency::Usd {
+            return Err(CoreDepositError::DepositAccountCurrencyMismatch(
+                account.currency,
+                DepositAccountCurrency::Usd,
+            ));
+        }
+
+        let new_hold = NewHold::builder()
+            .id(HoldId::new())
+            .deposit_account_id(deposit_account_id)
+            .amount(amount)
+            .reason(reason)
+            .expires_at(expires_at)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new hold");
+
+        let mut op = self.repo.begin_op().await?;
+        let hold = self.repo.create_in_op(&mut op, new_hold).await?;
+        if let Some(expires_at) = hold.expires_at {
+            self.jobs
+                .create_and_spawn_at_in_op(
+                    &mut op,
+                    hold.id,
+                    HoldExpiryJobConfig::<Perms> {
+                        hold_id: hold.id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                    expires_at,
+                )
+                .await?;
+        }
+        self.ledger
+            .place_hold(op, hold.ledger_tx_id, hold.amount, deposit_account_id)
+            .await?;
+        Ok(hold)
+    }
+
+    #[instrument(name = "deposit.release_hold", skip(self), err)]
+    pub async fn release_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        hold_id: impl Into<HoldId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<Hold, CoreDepositError> {
+        let hold_id = hold_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(hold_id),
+                CoreDepositAction::HOLD_RELEASE,
+            )
+            .await?;
+
+        let mut hold = self.repo.find_by_id(hold_id).await?;
+        let ledger_tx_id = hold.release(reason, audit_info)?;
+
+        let mut op = self.repo.begin_op().await?;
+        self.repo.update_in_op(&mut op, &mut hold).await?;
+        self.ledger
+            .release_hold(op, ledger_tx_id, hold.amount, hold.deposit_account_id)
+            .await?;
+        Ok(hold)
+    }
+
+    #[instrument(name = "deposit.find_hold_by_id", skip(self), err)]
+    pub async fn find_hold_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<HoldId> + std::fmt::Debug,
+    ) -> Result<Option<Hold>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(id),
+                CoreDepositAction::HOLD_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(hold) => Ok(Some(hold)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_holds_for_account", skip(self), err)]
+    pub async fn list_holds_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<Hold>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_holds(),
+                CoreDepositAction::HOLD_LIST,
+            )
+            .await?;
+        Ok(self
+            .repo
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[instrument(name = "deposit.find_all_holds", skip(self), err)]
+    pub async fn find_all_holds<T: From<Hold>>(
+        &self,
+        ids: &[HoldId],
+    ) -> Result<std::collections::HashMap<HoldId, T>, CoreDepositError> {
+        Ok(self.repo.find_all(ids).await?)
+    }
+}
@@ -0,0 +1,281 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    account::*, deposit::DepositRepo, error::CoreDepositError, event::CoreDepositEvent,
+    ledger::DepositLedger, primitives::*, transfer::TransferRepo, withdrawal::WithdrawalRepo,
+    DepositAccountsByCreatedAtCursor,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DormantAccountsJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> DormantAccountsJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms, E> JobConfig for DormantAccountsJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Initializer = DormantAccountsJobInitializer<Perms, E>;
+}
+
+pub struct DormantAccountsJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    accounts: DepositAccountRepo,
+    deposits: DepositRepo<E>,
+    withdrawals: WithdrawalRepo<E>,
+    transfers: TransferRepo<E>,
+    ledger: DepositLedger,
+    dormancy_period: Duration,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> DormantAccountsJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        accounts: &DepositAccountRepo,
+        deposits: &DepositRepo<E>,
+        withdrawals: &WithdrawalRepo<E>,
+        transfers: &TransferRepo<E>,
+        ledger: &DepositLedger,
+        dormancy_period: Duration,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            accounts: accounts.clone(),
+            deposits: deposits.clone(),
+            withdrawals: withdrawals.clone(),
+            transfers: transfers.clone(),
+            ledger: ledger.clone(),
+            dormancy_period,
+            audit: audit.clone(),
+        }
+    }
+}
+
+const DEPOSIT_DORMANT_ACCOUNTS_JOB: JobType = JobType::new("deposit-dormant-accounts");
+impl<Perms, E> JobInitializer for DormantAccountsJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPOSIT_DORMANT_ACCOUNTS_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(DormantAccountsJobRunner::<Perms, E> {
+            accounts: self.accounts.clone(),
+            deposits: self.deposits.clone(),
+            withdrawals: self.withdrawals.clone(),
+            transfers: self.transfers.clone(),
+            ledger: self.ledger.clone(),
+            dormancy_period: self.dormancy_period,
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct DormantAccountsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    accounts: DepositAccountRepo,
+    deposits: DepositRepo<E>,
+    withdrawals: WithdrawalRepo<E>,
+    transfers: TransferRepo<E>,
+    ledger: DepositLedger,
+    dormancy_period: Duration,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> DormantAccountsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    /// Only customer-initiated movements and reactivations count as activity; interest
+    /// postings do not.
+    async fn last_activity_at(
+        &self,
+        account: &DepositAccount,
+    ) -> Result<DateTime<Utc>, CoreDepositError> {
+        let last_deposit = self
+            .deposits
+            .list_for_deposit_account_id_by_created_at(
+                account.id,
+                es_entity::PaginatedQueryArgs {
+                    first: 1,
+                    after: None,
+                },
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities
+            .first()
+            .map(|deposit| deposit.created_at());
+        let last_withdrawal = self
+            .withdrawals
+            .list_for_deposit_account_id_by_created_at(
+                account.id,
+                es_entity::PaginatedQueryArgs {
+                    first: 1,
+                    after: None,
+                },
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities
+            .first()
+            .map(|withdrawal| withdrawal.created_at());
+        let last_outgoing_transfer = self
+            .transfers
+            .list_for_from_deposit_account_id_by_created_at(
+                account.id,
+                es_entity::PaginatedQueryArgs {
+                    first: 1,
+                    after: None,
+                },
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities
+            .first()
+            .map(|transfer| transfer.created_at());
+        let last_incoming_transfer = self
+            .transfers
+            .list_for_to_deposit_account_id_by_created_at(
+                account.id,
+                es_entity::PaginatedQueryArgs {
+                    first: 1,
+                    after: None,
+                },
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities
+            .first()
+            .map(|transfer| transfer.created_at());
+
+        Ok([
+            last_deposit,
+            last_withdrawal,
+            last_outgoing_transfer,
+            last_incoming_transfer,
+            account.last_reactivated_at(),
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or_else(|| account.created_at()))
+    }
+
+    async fn mark_dormant(
+        &self,
+        account: &mut DepositAccount,
+        last_activity_at: DateTime<Utc>,
+    ) -> Result<(), CoreDepositError> {
+        let mut op = self.accounts.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::deposit_account(account.id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_MARK_DORMANT,
+            )
+            .await?;
+        if account
+            .mark_dormant(last_activity_at, audit_info)
+            .was_ignored()
+        {
+            return Ok(());
+        }
+        self.accounts.update_in_op(&mut op, account).await?;
+        self.ledger.mark_account_dormant(op, account.id).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for DormantAccountsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let now = Utc::now();
+        let cutoff = now - self.dormancy_period;
+
+        let mut has_next_page = true;
+        let mut after: Option<DepositAccountsByCreatedAtCursor> = None;
+        while has_next_page {
+            let mut accounts = self
+                .accounts
+                .list_by_created_at(
+                    es_entity::PaginatedQueryArgs::<DepositAccountsByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (accounts.end_cursor, accounts.has_next_page);
+
+            for account in accounts.entities.iter_mut() {
+                if account.status != DepositAccountStatus::Active
+                    || account.is_time_deposit()
+                    || account.created_at() > cutoff
+                {
+                    continue;
+                }
+                let last_activity_at = self.last_activity_at(account).await?;
+                if last_activity_at > cutoff {
+                    continue;
+                }
+                self.mark_dormant(account, last_activity_at).await?;
+            }
+        }
+
+        let tomorrow = now.date_naive().succ_opt().expect("date out of range");
+        Ok(JobCompletion::RescheduleAt(
+            tomorrow.and_hms_opt(0, 0, 0).expect("valid time").and_utc(),
+        ))
+    }
+}
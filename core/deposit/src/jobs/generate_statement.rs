@@ -0,0 +1,222 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use cloud_storage::Storage;
+use job::*;
+
+use crate::{
+    account::DepositAccountRepo,
+    ledger::DepositLedger,
+    primitives::*,
+    statement::{
+        error::DepositAccountStatementError, DepositAccountStatement, DepositAccountStatementRepo,
+        DepositAccountStatementStatus, StatementContent, StatementEntry,
+    },
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GenerateStatementJobConfig<Perms> {
+    pub deposit_account_statement_id: DepositAccountStatementId,
+    pub _phantom: std::marker::PhantomData<Perms>,
+}
+impl<Perms> JobConfig for GenerateStatementJobConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    type Initializer = GenerateStatementJobInitializer<Perms>;
+}
+
+pub struct GenerateStatementJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    statements: DepositAccountStatementRepo,
+    accounts: DepositAccountRepo,
+    ledger: DepositLedger,
+    storage: Storage,
+    audit: Perms::Audit,
+}
+
+impl<Perms> GenerateStatementJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub fn new(
+        statements: &DepositAccountStatementRepo,
+        accounts: &DepositAccountRepo,
+        ledger: &DepositLedger,
+        storage: &Storage,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            statements: statements.clone(),
+            accounts: accounts.clone(),
+            ledger: ledger.clone(),
+            storage: storage.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const GENERATE_DEPOSIT_ACCOUNT_STATEMENT_JOB: JobType =
+    JobType::new("generate-deposit-account-statement");
+impl<Perms> JobInitializer for GenerateStatementJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        GENERATE_DEPOSIT_ACCOUNT_STATEMENT_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(GenerateStatementJobRunner::<Perms> {
+            config: job.config()?,
+            statements: self.statements.clone(),
+            accounts: self.accounts.clone(),
+            ledger: self.ledger.clone(),
+            storage: self.storage.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct GenerateStatementJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    config: GenerateStatementJobConfig<Perms>,
+    statements: DepositAccountStatementRepo,
+    accounts: DepositAccountRepo,
+    ledger: DepositLedger,
+    storage: Storage,
+    audit: Perms::Audit,
+}
+
+impl<Perms> GenerateStatementJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    async fn generate(
+        &self,
+        statement: &DepositAccountStatement,
+    ) -> Result<StatementContent, DepositAccountStatementError> {
+        let account = self
+            .accounts
+            .find_by_id(statement.deposit_account_id)
+            .await?;
+        if account.currency != DepositAccountCurrency::Usd {
+            return Err(DepositAccountStatementError::UnsupportedCurrency(
+                account.currency,
+            ));
+        }
+
+        let since = statement
+            .period_start
+            .and_hms_opt(0, 0, 0)
+            .expect("valid time")
+            .and_utc();
+        let balance = self.ledger.balance(account.id).await?;
+        let entries = self
+            .ledger
+            .entries_since(account.id, since)
+            .await?
+            .iter()
+            .filter_map(StatementEntry::from_ledger_entry)
+            .collect();
+
+        StatementContent::build(
+            account.reference,
+            account.name,
+            statement.period_start,
+            statement.period_end,
+            balance.settled,
+            entries,
+        )
+    }
+
+    async fn upload(
+        &self,
+        statement: &DepositAccountStatement,
+        content: &StatementContent,
+    ) -> Result<(String, String), DepositAccountStatementError> {
+        let pdf_path_in_bucket = format!("deposit_account_statements/{}.pdf", statement.id);
+        let csv_path_in_bucket = format!("deposit_account_statements/{}.csv", statement.id);
+        self.storage
+            .upload(content.to_pdf(), &pdf_path_in_bucket, "application/pdf")
+            .await?;
+        self.storage
+            .upload(content.to_csv()?, &csv_path_in_bucket, "text/csv")
+            .await?;
+        Ok((pdf_path_in_bucket, csv_path_in_bucket))
+    }
+}
+
+#[async_trait]
+impl<Perms> JobRunner for GenerateStatementJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut statement = self
+            .statements
+            .find_by_id(self.config.deposit_account_statement_id)
+            .await?;
+        if statement.status() == DepositAccountStatementStatus::Completed {
+            return Ok(JobCompletion::Complete);
+        }
+
+        let result = match self.generate(&statement).await {
+            Ok(content) => self
+                .upload(&statement, &content)
+                .await
+                .map(|paths| (content, paths)),
+            Err(e) => Err(e),
+        };
+
+        let mut op = self.statements.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::statement(statement.id),
+                CoreDepositAction::STATEMENT_GENERATE,
+            )
+            .await?;
+
+        match result {
+            Ok((content, (pdf_path_in_bucket, csv_path_in_bucket))) => {
+                let _ = statement.files_uploaded(
+                    self.storage.bucket_name().to_string(),
+                    pdf_path_in_bucket,
+                    csv_path_in_bucket,
+                    content.opening_balance,
+                    content.closing_balance,
+                    audit_info,
+                );
+            }
+            Err(e) => statement.generation_failed(e.to_string(), audit_info),
+        }
+
+        self.statements
+            .update_in_op(&mut op, &mut statement)
+            .await?;
+        let (now, tx) = (op.now(), op.into_tx());
+        Ok(JobCompletion::CompleteWithOp(es_entity::DbOp::new(tx, now)))
+    }
+}
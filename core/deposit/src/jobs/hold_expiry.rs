@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use es_entity::Idempotent;
+use job::*;
+
+use crate::{hold::HoldRepo, ledger::DepositLedger, primitives::*};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct HoldExpiryJobConfig<Perms> {
+    pub hold_id: HoldId,
+    pub _phantom: std::marker::PhantomData<Perms>,
+}
+impl<Perms> JobConfig for HoldExpiryJobConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    type Initializer = HoldExpiryJobInitializer<Perms>;
+}
+
+pub struct HoldExpiryJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    holds: HoldRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms> HoldExpiryJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub fn new(holds: &HoldRepo, ledger: &DepositLedger, audit: &Perms::Audit) -> Self {
+        Self {
+            holds: holds.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const HOLD_EXPIRY_JOB: JobType = JobType::new("deposit-hold-expiry");
+impl<Perms> JobInitializer for HoldExpiryJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        HOLD_EXPIRY_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(HoldExpiryJobRunner::<Perms> {
+            config: job.config()?,
+            holds: self.holds.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct HoldExpiryJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    config: HoldExpiryJobConfig<Perms>,
+    holds: HoldRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for HoldExpiryJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut hold = self.holds.find_by_id(self.config.hold_id).await?;
+
+        let Some(expires_at) = hold.expires_at else {
+            return Ok(JobCompletion::Complete);
+        };
+        if expires_at > chrono::Utc::now() {
+            return Ok(JobCompletion::RescheduleAt(expires_at));
+        }
+
+        let mut op = self.holds.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::hold(hold.id),
+                CoreDepositAction::HOLD_EXPIRE,
+            )
+            .await?;
+
+        let ledger_tx_id = match hold.expire(audit_info) {
+            Idempotent::Executed(ledger_tx_id) => ledger_tx_id,
+            Idempotent::Ignored => return Ok(JobCompletion::Complete),
+        };
+        self.holds.update_in_op(&mut op, &mut hold).await?;
+
+        self.ledger
+            .release_hold(op, ledger_tx_id, hold.amount, hold.deposit_account_id)
+            .await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
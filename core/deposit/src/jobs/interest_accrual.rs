@@ -0,0 +1,277 @@
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use es_entity::Idempotent;
+use job::*;
+
+use crate::{
+    account::*, error::CoreDepositError, ledger::DepositLedger, primitives::*, product::*,
+    DepositAccountsByCreatedAtCursor,
+};
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+struct DepositInterestAccrualJobData {
+    accrual_date: Option<NaiveDate>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DepositInterestAccrualJobConfig<Perms> {
+    pub _phantom: std::marker::PhantomData<Perms>,
+}
+impl<Perms> DepositInterestAccrualJobConfig<Perms> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms> JobConfig for DepositInterestAccrualJobConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    type Initializer = DepositInterestAccrualJobInitializer<Perms>;
+}
+
+pub struct DepositInterestAccrualJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    accounts: DepositAccountRepo,
+    products: DepositProductRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms> DepositInterestAccrualJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub fn new(
+        accounts: &DepositAccountRepo,
+        products: &DepositProductRepo,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            accounts: accounts.clone(),
+            products: products.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const DEPOSIT_INTEREST_ACCRUAL_JOB: JobType = JobType::new("deposit-interest-accrual");
+impl<Perms> JobInitializer for DepositInterestAccrualJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPOSIT_INTEREST_ACCRUAL_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(DepositInterestAccrualJobRunner::<Perms> {
+            accounts: self.accounts.clone(),
+            products: self.products.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct DepositInterestAccrualJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    accounts: DepositAccountRepo,
+    products: DepositProductRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms> DepositInterestAccrualJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    async fn capitalize_interest(
+        &self,
+        account: &mut DepositAccount,
+        date: NaiveDate,
+    ) -> Result<(), CoreDepositError> {
+        let mut op = self.accounts.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::deposit_account(account.id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_ACCRUE_INTEREST,
+            )
+            .await?;
+
+        let posting = match account.capitalize_interest(date, audit_info) {
+            Idempotent::Executed(posting) => posting,
+            Idempotent::Ignored => return Ok(()),
+        };
+        self.accounts.update_in_op(&mut op, account).await?;
+
+        if posting.amount.is_zero() {
+            op.commit().await?;
+        } else {
+            self.ledger
+                .capitalize_interest(
+                    op,
+                    posting.tx_id,
+                    posting.amount,
+                    account.id,
+                    posting.effective,
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn accrue_account_interest(
+        &self,
+        account: &mut DepositAccount,
+        products: &mut HashMap<DepositProductId, DepositProduct>,
+        today: NaiveDate,
+    ) -> Result<(), CoreDepositError> {
+        if let Some(terms) = account.time_deposit_terms {
+            return self
+                .accrue_interest(account, |_| terms.annual_rate, today)
+                .await;
+        }
+        let Some(product_id) = account.deposit_product_id else {
+            return Ok(());
+        };
+        if !products.contains_key(&product_id) {
+            let product = self.products.find_by_id(product_id).await?;
+            products.insert(product_id, product);
+        }
+        let product = products.get(&product_id).expect("product not loaded");
+        self.accrue_interest(account, |date| product.rate_at(date), today)
+            .await
+    }
+
+    async fn accrue_interest(
+        &self,
+        account: &mut DepositAccount,
+        rate_at: impl Fn(NaiveDate) -> InterestRatePct,
+        today: NaiveDate,
+    ) -> Result<(), CoreDepositError> {
+        while let Some(date) = account.next_interest_accrual_date() {
+            if account.is_interest_capitalization_due(date) {
+                self.capitalize_interest(account, date).await?;
+            }
+            if date >= today {
+                break;
+            }
+
+            let balance = self.ledger.balance_as_of(account.id, date).await?;
+            let mut op = self.accounts.begin_op().await?;
+            let audit_info = self
+                .audit
+                .record_system_entry_in_tx(
+                    op.tx(),
+                    CoreDepositObject::deposit_account(account.id),
+                    CoreDepositAction::DEPOSIT_ACCOUNT_ACCRUE_INTEREST,
+                )
+                .await?;
+
+            let posting =
+                match account.accrue_interest(date, balance.settled, rate_at(date), audit_info) {
+                    Idempotent::Executed(posting) => posting,
+                    Idempotent::Ignored => break,
+                };
+            self.accounts.update_in_op(&mut op, account).await?;
+
+            if posting.amount.is_zero() {
+                op.commit().await?;
+            } else {
+                self.ledger
+                    .accrue_interest(op, posting.tx_id, posting.amount, posting.effective)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Perms> JobRunner for DepositInterestAccrualJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<DepositInterestAccrualJobData>()?
+            .unwrap_or_default();
+        // Accrue up to the day the run was scheduled for, so a delayed run
+        // catches up one day at a time instead of skipping to the wall clock
+        let today = state
+            .accrual_date
+            .unwrap_or_else(|| Utc::now().date_naive());
+        let mut products: HashMap<DepositProductId, DepositProduct> = HashMap::new();
+
+        let mut has_next_page = true;
+        let mut after: Option<DepositAccountsByCreatedAtCursor> = None;
+        while has_next_page {
+            let mut accounts = self
+                .accounts
+                .list_by_created_at(
+                    es_entity::PaginatedQueryArgs::<DepositAccountsByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (accounts.end_cursor, accounts.has_next_page);
+
+            for account in accounts.entities.iter_mut() {
+                if account.status != DepositAccountStatus::Active {
+                    continue;
+                }
+                if let Err(e) = self
+                    .accrue_account_interest(account, &mut products, today)
+                    .await
+                {
+                    tracing::error!(
+                        deposit_account_id = %account.id,
+                        error = %e,
+                        "could not accrue deposit interest"
+                    );
+                }
+            }
+        }
+
+        let tomorrow = today.succ_opt().expect("date out of range");
+        state.accrual_date = Some(tomorrow);
+        current_job.update_execution_state(&state).await?;
+        Ok(JobCompletion::RescheduleAt(
+            tomorrow.and_hms_opt(0, 0, 0).expect("valid time").and_utc(),
+        ))
+    }
+}
@@ -0,0 +1,10 @@
+pub mod dormant_accounts;
+pub mod generate_statement;
+pub mod hold_expiry;
+pub mod interest_accrual;
+pub mod monthly_fees;
+pub mod monthly_statements;
+pub mod standing_order_execution;
+pub mod time_deposit_maturity;
+pub mod withdrawal_limit_override_expiry;
+pub mod withdrawal_payment_batch;
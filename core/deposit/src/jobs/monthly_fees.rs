@@ -0,0 +1,269 @@
+use async_trait::async_trait;
+use chrono::{Datelike, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+
+use crate::{
+    account::*,
+    error::CoreDepositError,
+    fee::{monthly_fee_reference, DepositFeeRepo, NewDepositFee},
+    ledger::{DepositFeeCharge, DepositLedger},
+    primitives::*,
+    product::{DepositFeeSchedule, DepositProductRepo},
+    DepositAccountsByCreatedAtCursor,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MonthlyFeesJobConfig<Perms> {
+    pub _phantom: std::marker::PhantomData<Perms>,
+}
+impl<Perms> MonthlyFeesJobConfig<Perms> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms> JobConfig for MonthlyFeesJobConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    type Initializer = MonthlyFeesJobInitializer<Perms>;
+}
+
+pub struct MonthlyFeesJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    accounts: DepositAccountRepo,
+    products: DepositProductRepo,
+    fees: DepositFeeRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms> MonthlyFeesJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub fn new(
+        accounts: &DepositAccountRepo,
+        products: &DepositProductRepo,
+        fees: &DepositFeeRepo,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            accounts: accounts.clone(),
+            products: products.clone(),
+            fees: fees.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const DEPOSIT_MONTHLY_FEES_JOB: JobType = JobType::new("deposit-monthly-fees");
+impl<Perms> JobInitializer for MonthlyFeesJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPOSIT_MONTHLY_FEES_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(MonthlyFeesJobRunner::<Perms> {
+            accounts: self.accounts.clone(),
+            products: self.products.clone(),
+            fees: self.fees.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct MonthlyFeesJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    accounts: DepositAccountRepo,
+    products: DepositProductRepo,
+    fees: DepositFeeRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms> MonthlyFeesJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    /// Lowest end-of-day settled balance held since the later of the period start and
+    /// the account opening, so a top-up just before the run does not avoid the fee.
+    async fn minimum_daily_balance(
+        &self,
+        account: &DepositAccount,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Result<UsdCents, CoreDepositError> {
+        let mut date = std::cmp::max(period_start, account.created_at().date_naive());
+        let mut minimum = None;
+        while date <= period_end {
+            let settled = self.ledger.balance_as_of(account.id, date).await?.settled;
+            minimum = Some(match minimum {
+                Some(minimum) => std::cmp::min(minimum, settled),
+                None => settled,
+            });
+            date = date.succ_opt().expect("date out of range");
+        }
+        Ok(minimum.unwrap_or(UsdCents::ZERO))
+    }
+
+    /// Fees are capped at the available balance so that charging them never overdraws the account.
+    async fn charge_fees(
+        &self,
+        account: &DepositAccount,
+        fee_schedule: &DepositFeeSchedule,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Result<(), CoreDepositError> {
+        let balance = self.ledger.balance(account.id).await?;
+        let mut available = balance.available();
+        let minimum_daily_balance = self
+            .minimum_daily_balance(account, period_start, period_end)
+            .await?;
+
+        for (fee_type, amount) in fee_schedule.monthly_fees(minimum_daily_balance) {
+            let reference = monthly_fee_reference(account.id, fee_type, period_start);
+            match self.fees.find_by_reference(reference.clone()).await {
+                Ok(_) => continue,
+                Err(e) if e.was_not_found() => (),
+                Err(e) => return Err(e.into()),
+            }
+
+            let amount = std::cmp::min(amount, available);
+            if amount.is_zero() {
+                continue;
+            }
+
+            let mut op = self.fees.begin_op().await?;
+            let audit_info = self
+                .audit
+                .record_system_entry_in_tx(
+                    op.tx(),
+                    CoreDepositObject::all_deposit_fees(),
+                    CoreDepositAction::DEPOSIT_FEE_CHARGE,
+                )
+                .await?;
+            let fee_id = DepositFeeId::new();
+            let new_fee = NewDepositFee::builder()
+                .id(fee_id)
+                .deposit_account_id(account.id)
+                .fee_type(fee_type)
+                .amount(amount)
+                .reference(reference)
+                .audit_info(audit_info)
+                .build()
+                .expect("Could not build new deposit fee");
+            self.fees.create_in_op(&mut op, new_fee).await?;
+            self.ledger
+                .charge_fee(
+                    op,
+                    DepositFeeCharge {
+                        tx_id: fee_id.into(),
+                        amount,
+                    },
+                    account.id,
+                )
+                .await?;
+            available = available - amount;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Perms> JobRunner for MonthlyFeesJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let first_of_month = Utc::now()
+            .date_naive()
+            .with_day(1)
+            .expect("first day of month");
+        let period_end = first_of_month.pred_opt().expect("date out of range");
+        let period_start = period_end.with_day(1).expect("first day of month");
+
+        let mut fee_schedules = HashMap::new();
+        let mut has_next_page = true;
+        let mut after: Option<DepositAccountsByCreatedAtCursor> = None;
+        while has_next_page {
+            let accounts = self
+                .accounts
+                .list_by_created_at(
+                    es_entity::PaginatedQueryArgs::<DepositAccountsByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (accounts.end_cursor, accounts.has_next_page);
+
+            for account in accounts.entities.iter() {
+                let deposit_product_id = match account.deposit_product_id {
+                    Some(id) => id,
+                    None => continue,
+                };
+                if account.status != DepositAccountStatus::Active
+                    || account.currency != DepositAccountCurrency::Usd
+                    || account.is_time_deposit()
+                    || account.created_at().date_naive() > period_end
+                {
+                    continue;
+                }
+                let fee_schedule = match fee_schedules.get(&deposit_product_id) {
+                    Some(fee_schedule) => *fee_schedule,
+                    None => {
+                        let product = self.products.find_by_id(deposit_product_id).await?;
+                        fee_schedules.insert(deposit_product_id, product.fee_schedule);
+                        product.fee_schedule
+                    }
+                };
+                self.charge_fees(account, &fee_schedule, period_start, period_end)
+                    .await?;
+            }
+        }
+
+        let next_month = first_of_month
+            .checked_add_months(Months::new(1))
+            .expect("date out of range");
+        Ok(JobCompletion::RescheduleAt(
+            next_month
+                .and_hms_opt(0, 0, 0)
+                .expect("valid time")
+                .and_utc(),
+        ))
+    }
+}
@@ -0,0 +1,214 @@
+use async_trait::async_trait;
+use chrono::{Datelike, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+
+use crate::{
+    account::*,
+    error::CoreDepositError,
+    primitives::*,
+    statement::{statement_reference, DepositAccountStatementRepo, NewDepositAccountStatement},
+    DepositAccountsByCreatedAtCursor,
+};
+
+use super::generate_statement::GenerateStatementJobConfig;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MonthlyStatementsJobConfig<Perms> {
+    pub _phantom: std::marker::PhantomData<Perms>,
+}
+impl<Perms> MonthlyStatementsJobConfig<Perms> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms> JobConfig for MonthlyStatementsJobConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    type Initializer = MonthlyStatementsJobInitializer<Perms>;
+}
+
+pub struct MonthlyStatementsJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    accounts: DepositAccountRepo,
+    statements: DepositAccountStatementRepo,
+    jobs: Jobs,
+    audit: Perms::Audit,
+}
+
+impl<Perms> MonthlyStatementsJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub fn new(
+        accounts: &DepositAccountRepo,
+        statements: &DepositAccountStatementRepo,
+        jobs: &Jobs,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            accounts: accounts.clone(),
+            statements: statements.clone(),
+            jobs: jobs.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const DEPOSIT_MONTHLY_STATEMENTS_JOB: JobType = JobType::new("deposit-monthly-statements");
+impl<Perms> JobInitializer for MonthlyStatementsJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPOSIT_MONTHLY_STATEMENTS_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(MonthlyStatementsJobRunner::<Perms> {
+            accounts: self.accounts.clone(),
+            statements: self.statements.clone(),
+            jobs: self.jobs.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct MonthlyStatementsJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    accounts: DepositAccountRepo,
+    statements: DepositAccountStatementRepo,
+    jobs: Jobs,
+    audit: Perms::Audit,
+}
+
+impl<Perms> MonthlyStatementsJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    async fn create_statement(
+        &self,
+        account: &DepositAccount,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Result<(), CoreDepositError> {
+        match self
+            .statements
+            .find_by_reference(statement_reference(account.id, period_start, period_end))
+            .await
+        {
+            Ok(_) => return Ok(()),
+            Err(e) if e.was_not_found() => (),
+            Err(e) => return Err(e.into()),
+        }
+
+        let mut op = self.statements.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::all_statements(),
+                CoreDepositAction::STATEMENT_CREATE,
+            )
+            .await?;
+        let new_statement = NewDepositAccountStatement::builder()
+            .id(DepositAccountStatementId::new())
+            .deposit_account_id(account.id)
+            .account_holder_id(account.account_holder_id)
+            .period_start(period_start)
+            .period_end(period_end)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new statement");
+        let statement = self.statements.create_in_op(&mut op, new_statement).await?;
+        self.jobs
+            .create_and_spawn_in_op(
+                &mut op,
+                statement.id,
+                GenerateStatementJobConfig::<Perms> {
+                    deposit_account_statement_id: statement.id,
+                    _phantom: std::marker::PhantomData,
+                },
+            )
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Perms> JobRunner for MonthlyStatementsJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let first_of_month = Utc::now()
+            .date_naive()
+            .with_day(1)
+            .expect("first day of month");
+        let period_end = first_of_month.pred_opt().expect("date out of range");
+        let period_start = period_end.with_day(1).expect("first day of month");
+
+        let mut has_next_page = true;
+        let mut after: Option<DepositAccountsByCreatedAtCursor> = None;
+        while has_next_page {
+            let accounts = self
+                .accounts
+                .list_by_created_at(
+                    es_entity::PaginatedQueryArgs::<DepositAccountsByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (accounts.end_cursor, accounts.has_next_page);
+
+            for account in accounts.entities.iter() {
+                if account.currency != DepositAccountCurrency::Usd
+                    || account.created_at().date_naive() > period_end
+                {
+                    continue;
+                }
+                self.create_statement(account, period_start, period_end)
+                    .await?;
+            }
+        }
+
+        let next_month = first_of_month
+            .checked_add_months(Months::new(1))
+            .expect("date out of range");
+        Ok(JobCompletion::RescheduleAt(
+            next_month
+                .and_hms_opt(0, 0, 0)
+                .expect("valid time")
+                .and_utc(),
+        ))
+    }
+}
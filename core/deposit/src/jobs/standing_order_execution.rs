@@ -0,0 +1,273 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    account::*, config::StandingOrderRetryConfig, event::CoreDepositEvent, ledger::DepositLedger,
+    primitives::*, standing_order::*, transfer::*,
+};
+
+const INSUFFICIENT_AVAILABLE_BALANCE: &str = "insufficient available balance";
+const DEPOSIT_ACCOUNT_NOT_ACTIVE: &str = "deposit account is not active";
+const DESTINATION_DOES_NOT_ACCEPT_CREDITS: &str = "destination account does not accept credits";
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct StandingOrderExecutionJobConfig<Perms, E> {
+    pub standing_order_id: StandingOrderId,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for StandingOrderExecutionJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Initializer = StandingOrderExecutionJobInitializer<Perms, E>;
+}
+
+pub struct StandingOrderExecutionJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    standing_orders: StandingOrderRepo<E>,
+    accounts: DepositAccountRepo,
+    transfers: TransferRepo<E>,
+    ledger: DepositLedger,
+    retry: StandingOrderRetryConfig,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> StandingOrderExecutionJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        standing_orders: &StandingOrderRepo<E>,
+        accounts: &DepositAccountRepo,
+        transfers: &TransferRepo<E>,
+        ledger: &DepositLedger,
+        retry: &StandingOrderRetryConfig,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            standing_orders: standing_orders.clone(),
+            accounts: accounts.clone(),
+            transfers: transfers.clone(),
+            ledger: ledger.clone(),
+            retry: retry.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const STANDING_ORDER_EXECUTION_JOB: JobType = JobType::new("deposit-standing-order-execution");
+impl<Perms, E> JobInitializer for StandingOrderExecutionJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        STANDING_ORDER_EXECUTION_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(StandingOrderExecutionJobRunner::<Perms, E> {
+            config: job.config()?,
+            standing_orders: self.standing_orders.clone(),
+            accounts: self.accounts.clone(),
+            transfers: self.transfers.clone(),
+            ledger: self.ledger.clone(),
+            retry: self.retry.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct StandingOrderExecutionJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    config: StandingOrderExecutionJobConfig<Perms, E>,
+    standing_orders: StandingOrderRepo<E>,
+    accounts: DepositAccountRepo,
+    transfers: TransferRepo<E>,
+    ledger: DepositLedger,
+    retry: StandingOrderRetryConfig,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> StandingOrderExecutionJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    /// Returns the reason the pending execution cannot go through right now, if any.
+    async fn execution_blocker(
+        &self,
+        standing_order: &StandingOrder,
+    ) -> Result<Option<&'static str>, Box<dyn std::error::Error>> {
+        let account = self
+            .accounts
+            .find_by_id(standing_order.deposit_account_id)
+            .await?;
+        if account.status != DepositAccountStatus::Active {
+            return Ok(Some(DEPOSIT_ACCOUNT_NOT_ACTIVE));
+        }
+
+        if let StandingOrderDestination::DepositAccount { deposit_account_id } =
+            standing_order.destination
+        {
+            let destination = self.accounts.find_by_id(deposit_account_id).await?;
+            if destination.is_time_deposit()
+                || destination.currency != DepositAccountCurrency::Usd
+                || matches!(
+                    destination.status,
+                    DepositAccountStatus::Inactive | DepositAccountStatus::Closed
+                )
+            {
+                return Ok(Some(DESTINATION_DOES_NOT_ACCEPT_CREDITS));
+            }
+        }
+
+        let available = self
+            .ledger
+            .balance(standing_order.deposit_account_id)
+            .await?
+            .available();
+        if available < standing_order.amount {
+            return Ok(Some(INSUFFICIENT_AVAILABLE_BALANCE));
+        }
+
+        Ok(None)
+    }
+}
+
+fn next_completion(standing_order: &StandingOrder) -> JobCompletion {
+    match standing_order.next_run_at() {
+        Some(run_at) => JobCompletion::RescheduleAt(run_at),
+        None => JobCompletion::Complete,
+    }
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for StandingOrderExecutionJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut standing_order = self
+            .standing_orders
+            .find_by_id(self.config.standing_order_id)
+            .await?;
+
+        let now = Utc::now();
+        if standing_order.awaiting_repayment().is_some() {
+            return Ok(JobCompletion::RescheduleAt(
+                now + self.retry.retry_interval(),
+            ));
+        }
+        if !standing_order.is_due(now) {
+            return Ok(next_completion(&standing_order));
+        }
+
+        let mut op = self.standing_orders.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::standing_order(standing_order.id),
+                CoreDepositAction::STANDING_ORDER_EXECUTE,
+            )
+            .await?;
+
+        if let Some(reason) = self.execution_blocker(&standing_order).await? {
+            if standing_order
+                .record_execution_failed(reason.to_string(), &self.retry, now, audit_info)
+                .did_execute()
+            {
+                self.standing_orders
+                    .update_in_op(&mut op, &mut standing_order)
+                    .await?;
+                op.commit().await?;
+            }
+            return Ok(next_completion(&standing_order));
+        }
+
+        match standing_order.destination {
+            StandingOrderDestination::DepositAccount { deposit_account_id } => {
+                let new_transfer = NewTransfer::builder()
+                    .id(TransferId::new())
+                    .from_deposit_account_id(standing_order.deposit_account_id)
+                    .to_deposit_account_id(deposit_account_id)
+                    .amount(standing_order.amount)
+                    .reference(Some(standing_order.reference.clone()))
+                    .audit_info(audit_info.clone())
+                    .build()
+                    .expect("Could not build new transfer");
+                let mut transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+                let tx_id = transfer.execute(audit_info.clone())?;
+                self.transfers.update_in_op(&mut op, &mut transfer).await?;
+
+                if standing_order
+                    .record_executed(Some(transfer.id), audit_info)
+                    .was_ignored()
+                {
+                    return Ok(next_completion(&standing_order));
+                }
+                self.standing_orders
+                    .update_in_op(&mut op, &mut standing_order)
+                    .await?;
+
+                self.ledger
+                    .transfer(
+                        op,
+                        tx_id,
+                        standing_order.amount,
+                        standing_order.deposit_account_id,
+                        deposit_account_id,
+                    )
+                    .await?;
+            }
+            // The repayment itself is recorded by the credit module once it picks up
+            // the published `StandingOrderRepaymentDue` event, which then reports the
+            // outcome back. Until then the job keeps checking in at the retry interval.
+            StandingOrderDestination::CreditFacility { .. } => {
+                if standing_order.request_repayment(audit_info).did_execute() {
+                    self.standing_orders
+                        .update_in_op(&mut op, &mut standing_order)
+                        .await?;
+                    op.commit().await?;
+                }
+                return Ok(JobCompletion::RescheduleAt(
+                    now + self.retry.retry_interval(),
+                ));
+            }
+        }
+
+        Ok(next_completion(&standing_order))
+    }
+}
@@ -0,0 +1,194 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use es_entity::Idempotent;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    account::*,
+    event::CoreDepositEvent,
+    ledger::{DepositAccountSweep, DepositLedger},
+    primitives::*,
+    transfer::*,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TimeDepositMaturityJobConfig<Perms, E> {
+    pub deposit_account_id: DepositAccountId,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for TimeDepositMaturityJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Initializer = TimeDepositMaturityJobInitializer<Perms, E>;
+}
+
+pub struct TimeDepositMaturityJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    accounts: DepositAccountRepo,
+    transfers: TransferRepo<E>,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> TimeDepositMaturityJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        accounts: &DepositAccountRepo,
+        transfers: &TransferRepo<E>,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            accounts: accounts.clone(),
+            transfers: transfers.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const TIME_DEPOSIT_MATURITY_JOB: JobType = JobType::new("deposit-time-deposit-maturity");
+impl<Perms, E> JobInitializer for TimeDepositMaturityJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        TIME_DEPOSIT_MATURITY_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(TimeDepositMaturityJobRunner::<Perms, E> {
+            config: job.config()?,
+            accounts: self.accounts.clone(),
+            transfers: self.transfers.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct TimeDepositMaturityJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    config: TimeDepositMaturityJobConfig<Perms, E>,
+    accounts: DepositAccountRepo,
+    transfers: TransferRepo<E>,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for TimeDepositMaturityJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut account = self
+            .accounts
+            .find_by_id(self.config.deposit_account_id)
+            .await?;
+        let Some(terms) = account.time_deposit_terms else {
+            return Ok(JobCompletion::Complete);
+        };
+        if account.status.is_closed() {
+            return Ok(JobCompletion::Complete);
+        }
+
+        let now = Utc::now();
+        if terms.maturity_date > now.date_naive() {
+            return Ok(JobCompletion::RescheduleAt(
+                terms
+                    .maturity_date
+                    .and_hms_opt(0, 0, 0)
+                    .expect("valid time")
+                    .and_utc(),
+            ));
+        }
+        // Wait for the interest accrual job to catch up and for any freeze to be lifted
+        if !account.is_time_deposit_due_for_maturity(now.date_naive()) || account.status.is_frozen()
+        {
+            return Ok(JobCompletion::RescheduleAt(now + Duration::minutes(10)));
+        }
+        // Held funds stay in the time deposit until the holds are released or expire
+        if !self.ledger.balance(account.id).await?.encumbrance.is_zero() {
+            return Ok(JobCompletion::RescheduleAt(now + Duration::minutes(10)));
+        }
+
+        let mut op = self.accounts.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::deposit_account(account.id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_MATURE_TIME_DEPOSIT,
+            )
+            .await?;
+
+        let settlement = match account.mature_time_deposit(now.date_naive(), audit_info.clone())? {
+            Idempotent::Executed(settlement) => settlement,
+            Idempotent::Ignored => return Ok(JobCompletion::Complete),
+        };
+        self.accounts.update_in_op(&mut op, &mut account).await?;
+
+        let new_transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_deposit_account_id(account.id)
+            .to_deposit_account_id(settlement.payout_account_id)
+            .amount(settlement.payout_amount)
+            .reference(Some(format!("time-deposit-maturity-{}", account.id)))
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("Could not build new transfer");
+        let mut transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+        let tx_id = transfer.execute(audit_info)?;
+        self.transfers.update_in_op(&mut op, &mut transfer).await?;
+
+        self.ledger
+            .mature_time_deposit(
+                op,
+                account.id,
+                settlement.interest_tx_id,
+                settlement.interest,
+                terms.maturity_date,
+                DepositAccountSweep {
+                    tx_id,
+                    amount: settlement.payout_amount,
+                    to_account_id: settlement.payout_account_id.into(),
+                },
+            )
+            .await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use es_entity::Idempotent;
+use job::*;
+
+use crate::{
+    account::DepositAccountRepo, ledger::DepositLedger, primitives::*,
+    withdrawal_limit::WithdrawalLimitRepo, withdrawal_limit_override::WithdrawalLimitOverrideRepo,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct WithdrawalLimitOverrideExpiryJobConfig<Perms> {
+    pub withdrawal_limit_override_id: WithdrawalLimitOverrideId,
+    pub _phantom: std::marker::PhantomData<Perms>,
+}
+impl<Perms> JobConfig for WithdrawalLimitOverrideExpiryJobConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    type Initializer = WithdrawalLimitOverrideExpiryJobInitializer<Perms>;
+}
+
+pub struct WithdrawalLimitOverrideExpiryJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+{
+    overrides: WithdrawalLimitOverrideRepo,
+    withdrawal_limits: WithdrawalLimitRepo,
+    accounts: DepositAccountRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms> WithdrawalLimitOverrideExpiryJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub fn new(
+        overrides: &WithdrawalLimitOverrideRepo,
+        withdrawal_limits: &WithdrawalLimitRepo,
+        accounts: &DepositAccountRepo,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            overrides: overrides.clone(),
+            withdrawal_limits: withdrawal_limits.clone(),
+            accounts: accounts.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const WITHDRAWAL_LIMIT_OVERRIDE_EXPIRY_JOB: JobType =
+    JobType::new("deposit-withdrawal-limit-override-expiry");
+impl<Perms> JobInitializer for WithdrawalLimitOverrideExpiryJobInitializer<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        WITHDRAWAL_LIMIT_OVERRIDE_EXPIRY_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(WithdrawalLimitOverrideExpiryJobRunner::<Perms> {
+            config: job.config()?,
+            overrides: self.overrides.clone(),
+            withdrawal_limits: self.withdrawal_limits.clone(),
+            accounts: self.accounts.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct WithdrawalLimitOverrideExpiryJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    config: WithdrawalLimitOverrideExpiryJobConfig<Perms>,
+    overrides: WithdrawalLimitOverrideRepo,
+    withdrawal_limits: WithdrawalLimitRepo,
+    accounts: DepositAccountRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for WithdrawalLimitOverrideExpiryJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut limit_override = self
+            .overrides
+            .find_by_id(self.config.withdrawal_limit_override_id)
+            .await?;
+
+        if limit_override.expires_at > chrono::Utc::now() {
+            return Ok(JobCompletion::RescheduleAt(limit_override.expires_at));
+        }
+
+        let account = self
+            .accounts
+            .find_by_id(limit_override.deposit_account_id)
+            .await?;
+        let to = match account.withdrawal_limit_id {
+            Some(id) => Some(self.withdrawal_limits.find_by_id(id).await?.account_set_id),
+            None => None,
+        };
+
+        let mut op = self.overrides.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::all_withdrawal_limits(),
+                CoreDepositAction::WITHDRAWAL_LIMIT_EXPIRE_OVERRIDE,
+            )
+            .await?;
+
+        if limit_override.expire(audit_info).was_ignored() {
+            return Ok(JobCompletion::Complete);
+        }
+        self.overrides
+            .update_in_op(&mut op, &mut limit_override)
+            .await?;
+
+        self.ledger
+            .move_withdrawal_limit(op, account.id, None, to)
+            .await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
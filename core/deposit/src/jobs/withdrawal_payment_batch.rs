@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+use chrono::{Days, Utc};
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use cloud_storage::Storage;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    config::WithdrawalPaymentDebtorConfig,
+    event::CoreDepositEvent,
+    payment_batch::{create_payment_batch, PaymentBatchRepo},
+    primitives::*,
+    withdrawal::WithdrawalRepo,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct WithdrawalPaymentBatchJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> WithdrawalPaymentBatchJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms, E> JobConfig for WithdrawalPaymentBatchJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Initializer = WithdrawalPaymentBatchJobInitializer<Perms, E>;
+}
+
+pub struct WithdrawalPaymentBatchJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    withdrawals: WithdrawalRepo<E>,
+    payment_batches: PaymentBatchRepo,
+    storage: Storage,
+    debtor: WithdrawalPaymentDebtorConfig,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> WithdrawalPaymentBatchJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        withdrawals: &WithdrawalRepo<E>,
+        payment_batches: &PaymentBatchRepo,
+        storage: &Storage,
+        debtor: &WithdrawalPaymentDebtorConfig,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            withdrawals: withdrawals.clone(),
+            payment_batches: payment_batches.clone(),
+            storage: storage.clone(),
+            debtor: debtor.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const WITHDRAWAL_PAYMENT_BATCH_JOB: JobType = JobType::new("withdrawal-payment-batch");
+impl<Perms, E> JobInitializer for WithdrawalPaymentBatchJobInitializer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        WITHDRAWAL_PAYMENT_BATCH_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(WithdrawalPaymentBatchJobRunner::<Perms, E> {
+            withdrawals: self.withdrawals.clone(),
+            payment_batches: self.payment_batches.clone(),
+            storage: self.storage.clone(),
+            debtor: self.debtor.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct WithdrawalPaymentBatchJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    withdrawals: WithdrawalRepo<E>,
+    payment_batches: PaymentBatchRepo,
+    storage: Storage,
+    debtor: WithdrawalPaymentDebtorConfig,
+    audit: Perms::Audit,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for WithdrawalPaymentBatchJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let audit_info = self
+            .audit
+            .record_system_entry(
+                CoreDepositObject::all_payment_batches(),
+                CoreDepositAction::PAYMENT_BATCH_CREATE,
+            )
+            .await?;
+        create_payment_batch(
+            &self.withdrawals,
+            &self.payment_batches,
+            &self.storage,
+            &self.debtor,
+            audit_info,
+        )
+        .await?;
+
+        let tomorrow = Utc::now()
+            .date_naive()
+            .checked_add_days(Days::new(1))
+            .expect("date out of range");
+        Ok(JobCompletion::RescheduleAt(
+            tomorrow.and_hms_opt(0, 0, 0).expect("valid time").and_utc(),
+        ))
+    }
+}
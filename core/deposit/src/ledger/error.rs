@@ -28,6 +28,8 @@ pub enum DepositLedgerError {
     MissingTxMetadata,
     #[error("DepositLedgerError - ChartOfAccountsError: {0}")]
     ChartOfAccountsError(#[from] core_accounting::chart_of_accounts::error::ChartOfAccountsError),
+    #[error("DepositLedgerError - AccountingPeriodError: {0}")]
+    AccountingPeriodError(#[from] core_accounting::accounting_period::error::AccountingPeriodError),
     #[error("DepositLedgerError - MismatchedTxMetadata: {0}")]
     MismatchedTxMetadata(serde_json::Error),
     #[error("DepositLedgerError - NonAccountMemberFoundInAccountSet: Found non-Account typed member in account set {0}")]
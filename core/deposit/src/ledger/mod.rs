@@ -11,14 +11,19 @@ use cala_ledger::{
     account::*,
     account_set::{AccountSet, AccountSetMemberId, AccountSetUpdate, NewAccountSet},
     tx_template::Params,
-    velocity::{NewVelocityControl, VelocityControlId},
+    velocity::{NewVelocityControl, VelocityControlId, VelocityLimitId},
     CalaLedger, Currency, DebitOrCredit, JournalId, LedgerOperation, TransactionId,
 };
 
+use core_accounting::PeriodPostingLock;
+
 use crate::{
+    account::DepositInterestPosting,
     chart_of_accounts_integration::ChartOfAccountsIntegrationConfig,
-    primitives::{CalaAccountId, CalaAccountSetId, DepositAccountType, UsdCents},
-    DepositAccountBalance, LedgerOmnibusAccountIds,
+    primitives::{
+        CalaAccountId, CalaAccountSetId, DepositAccountType, DepositAmount, Satoshis, UsdCents,
+    },
+    BtcDepositAccountBalance, DepositAccountBalance, LedgerOmnibusAccountIds,
 };
 
 use error::*;
@@ -40,13 +45,58 @@ pub const DEPOSIT_NON_DOMICILED_INDIVIDUAL_ACCOUNT_SET_NAME: &str =
     "Deposit Non-Domiciled Company Account Set";
 pub const DEPOSIT_NON_DOMICILED_INDIVIDUAL_ACCOUNT_SET_REF: &str =
     "deposit-non-domiciled-company-account-set";
+pub const DEPOSIT_TIME_DEPOSIT_ACCOUNT_SET_NAME: &str = "Deposit Time Deposit Account Set";
+pub const DEPOSIT_TIME_DEPOSIT_ACCOUNT_SET_REF: &str = "deposit-time-deposit-account-set";
 
 pub const DEPOSIT_OMNIBUS_ACCOUNT_SET_NAME: &str = "Deposit Omnibus Account Set";
 pub const DEPOSIT_OMNIBUS_ACCOUNT_SET_REF: &str = "deposit-omnibus-account-set";
 pub const DEPOSIT_OMNIBUS_ACCOUNT_REF: &str = "deposit-omnibus-account";
 
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME: &str = "Deposit Interest Expense Account Set";
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF: &str = "deposit-interest-expense-account-set";
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF: &str = "deposit-interest-expense-account";
+
+pub const DEPOSIT_ACCRUED_INTEREST_ACCOUNT_SET_NAME: &str = "Deposit Accrued Interest Account Set";
+pub const DEPOSIT_ACCRUED_INTEREST_ACCOUNT_SET_REF: &str = "deposit-accrued-interest-account-set";
+pub const DEPOSIT_ACCRUED_INTEREST_ACCOUNT_REF: &str = "deposit-accrued-interest-account";
+
+pub const DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_SET_NAME: &str = "Deposit Unclaimed Funds Account Set";
+pub const DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_SET_REF: &str = "deposit-unclaimed-funds-account-set";
+pub const DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_REF: &str = "deposit-unclaimed-funds-account";
+
+pub const DEPOSIT_FEE_INCOME_ACCOUNT_SET_NAME: &str = "Deposit Fee Income Account Set";
+pub const DEPOSIT_FEE_INCOME_ACCOUNT_SET_REF: &str = "deposit-fee-income-account-set";
+pub const DEPOSIT_FEE_INCOME_ACCOUNT_REF: &str = "deposit-fee-income-account";
+
+pub const DEPOSIT_FROZEN_ACCOUNT_SET_NAME: &str = "Deposit Frozen Account Set";
+pub const DEPOSIT_FROZEN_ACCOUNT_SET_REF: &str = "deposit-frozen-account-set";
+pub const DEPOSIT_CLOSED_ACCOUNT_SET_NAME: &str = "Deposit Closed Account Set";
+pub const DEPOSIT_CLOSED_ACCOUNT_SET_REF: &str = "deposit-closed-account-set";
+pub const DEPOSIT_DORMANT_ACCOUNT_SET_NAME: &str = "Deposit Dormant Account Set";
+pub const DEPOSIT_DORMANT_ACCOUNT_SET_REF: &str = "deposit-dormant-account-set";
+pub const DEPOSIT_WITHDRAWAL_LIMIT_ACCOUNT_SET_REF: &str = "deposit-withdrawal-limit-account-set";
+
 pub const DEPOSITS_VELOCITY_CONTROL_ID: uuid::Uuid =
     uuid::uuid!("00000000-0000-0000-0000-000000000001");
+pub const FROZEN_DEPOSITS_VELOCITY_CONTROL_ID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000003");
+pub const CLOSED_DEPOSITS_VELOCITY_CONTROL_ID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000004");
+pub const DORMANT_DEPOSITS_VELOCITY_CONTROL_ID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000007");
+
+#[derive(Debug, Clone, Copy)]
+pub struct DepositAccountSweep {
+    pub tx_id: TransactionId,
+    pub amount: UsdCents,
+    pub to_account_id: CalaAccountId,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DepositFeeCharge {
+    pub tx_id: TransactionId,
+    pub amount: UsdCents,
+}
 
 #[derive(Clone, Copy)]
 pub struct InternalAccountSetDetails {
@@ -62,6 +112,7 @@ pub struct DepositAccountSets {
     bank: InternalAccountSetDetails,
     financial_institution: InternalAccountSetDetails,
     non_domiciled_individual: InternalAccountSetDetails,
+    time_deposit: InternalAccountSetDetails,
 }
 
 impl DepositAccountSets {
@@ -73,6 +124,7 @@ impl DepositAccountSets {
             self.bank.id,
             self.financial_institution.id,
             self.non_domiciled_individual.id,
+            self.time_deposit.id,
         ]
     }
 
@@ -85,21 +137,43 @@ impl DepositAccountSets {
 pub struct DepositLedger {
     cala: CalaLedger,
     journal_id: JournalId,
+    posting_lock: PeriodPostingLock,
     deposits_account_set: DepositAccountSets,
     deposit_omnibus_account_ids: LedgerOmnibusAccountIds,
+    interest_expense_account_ids: LedgerOmnibusAccountIds,
+    accrued_interest_account_ids: LedgerOmnibusAccountIds,
+    unclaimed_funds_account_ids: LedgerOmnibusAccountIds,
+    fee_income_account_ids: LedgerOmnibusAccountIds,
     usd: Currency,
+    btc: Currency,
     deposit_control_id: VelocityControlId,
+    frozen_account_set_id: CalaAccountSetId,
+    closed_account_set_id: CalaAccountSetId,
+    dormant_account_set_id: CalaAccountSetId,
+    daily_withdrawal_limit_id: VelocityLimitId,
+    monthly_withdrawal_limit_id: VelocityLimitId,
 }
 
 impl DepositLedger {
     pub async fn init(
+        pool: &sqlx::PgPool,
         cala: &CalaLedger,
         journal_id: JournalId,
     ) -> Result<Self, DepositLedgerError> {
         templates::RecordDeposit::init(cala).await?;
+        templates::RevertDeposit::init(cala).await?;
         templates::InitiateWithdraw::init(cala).await?;
         templates::CancelWithdraw::init(cala).await?;
         templates::ConfirmWithdraw::init(cala).await?;
+        templates::AccrueDepositInterest::init(cala).await?;
+        templates::CapitalizeDepositInterest::init(cala).await?;
+        templates::ForfeitDepositInterest::init(cala).await?;
+        templates::Transfer::init(cala).await?;
+        templates::PlaceHold::init(cala).await?;
+        templates::ReleaseHold::init(cala).await?;
+        templates::TransferUnclaimedFunds::init(cala).await?;
+        templates::ChargeDepositFee::init(cala).await?;
+        templates::WaiveDepositFee::init(cala).await?;
 
         let deposits_normal_balance_type = DebitOrCredit::Credit;
 
@@ -157,6 +231,15 @@ impl DepositLedger {
         )
         .await?;
 
+        let time_deposit_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_TIME_DEPOSIT_ACCOUNT_SET_REF}"),
+            DEPOSIT_TIME_DEPOSIT_ACCOUNT_SET_NAME.to_string(),
+            deposits_normal_balance_type,
+        )
+        .await?;
+
         let deposit_omnibus_account_ids = Self::find_or_create_omnibus_account(
             cala,
             journal_id,
@@ -167,6 +250,46 @@ impl DepositLedger {
         )
         .await?;
 
+        let interest_expense_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF}"),
+            DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Debit,
+        )
+        .await?;
+
+        let accrued_interest_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_ACCRUED_INTEREST_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{DEPOSIT_ACCRUED_INTEREST_ACCOUNT_REF}"),
+            DEPOSIT_ACCRUED_INTEREST_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Credit,
+        )
+        .await?;
+
+        let unclaimed_funds_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_REF}"),
+            DEPOSIT_UNCLAIMED_FUNDS_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Credit,
+        )
+        .await?;
+
+        let fee_income_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_FEE_INCOME_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{DEPOSIT_FEE_INCOME_ACCOUNT_REF}"),
+            DEPOSIT_FEE_INCOME_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Credit,
+        )
+        .await?;
+
         let overdraft_prevention_id = velocity::OverdraftPrevention::init(cala).await?;
 
         let deposit_control_id = Self::create_deposit_control(cala).await?;
@@ -181,9 +304,69 @@ impl DepositLedger {
             Err(e) => return Err(e.into()),
         }
 
+        let frozen_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_FROZEN_ACCOUNT_SET_REF}"),
+            DEPOSIT_FROZEN_ACCOUNT_SET_NAME.to_string(),
+            deposits_normal_balance_type,
+        )
+        .await?;
+        let frozen_account_prevention_id = velocity::FrozenAccountPrevention::init(cala).await?;
+        Self::create_account_set_control(
+            cala,
+            FROZEN_DEPOSITS_VELOCITY_CONTROL_ID,
+            "Frozen Deposit Control",
+            &[frozen_account_prevention_id],
+            Params::default(),
+            frozen_account_set_id,
+        )
+        .await?;
+
+        let dormant_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_DORMANT_ACCOUNT_SET_REF}"),
+            DEPOSIT_DORMANT_ACCOUNT_SET_NAME.to_string(),
+            deposits_normal_balance_type,
+        )
+        .await?;
+        Self::create_account_set_control(
+            cala,
+            DORMANT_DEPOSITS_VELOCITY_CONTROL_ID,
+            "Dormant Deposit Control",
+            &[frozen_account_prevention_id],
+            Params::default(),
+            dormant_account_set_id,
+        )
+        .await?;
+
+        let closed_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_CLOSED_ACCOUNT_SET_REF}"),
+            DEPOSIT_CLOSED_ACCOUNT_SET_NAME.to_string(),
+            deposits_normal_balance_type,
+        )
+        .await?;
+        let closed_account_prevention_id = velocity::ClosedAccountPrevention::init(cala).await?;
+        Self::create_account_set_control(
+            cala,
+            CLOSED_DEPOSITS_VELOCITY_CONTROL_ID,
+            "Closed Deposit Control",
+            &[closed_account_prevention_id],
+            Params::default(),
+            closed_account_set_id,
+        )
+        .await?;
+
+        let daily_withdrawal_limit_id = velocity::DailyWithdrawalLimit::init(cala).await?;
+        let monthly_withdrawal_limit_id = velocity::MonthlyWithdrawalLimit::init(cala).await?;
+
         Ok(Self {
             cala: cala.clone(),
             journal_id,
+            posting_lock: PeriodPostingLock::new(pool),
             deposits_account_set: DepositAccountSets {
                 individual: InternalAccountSetDetails {
                     id: individual_deposit_account_set_id,
@@ -209,10 +392,24 @@ impl DepositLedger {
                     id: non_domiciled_company_deposit_account_set_id,
                     normal_balance_type: deposits_normal_balance_type,
                 },
+                time_deposit: InternalAccountSetDetails {
+                    id: time_deposit_account_set_id,
+                    normal_balance_type: deposits_normal_balance_type,
+                },
             },
             deposit_omnibus_account_ids,
+            interest_expense_account_ids,
+            accrued_interest_account_ids,
+            unclaimed_funds_account_ids,
+            fee_income_account_ids,
             deposit_control_id,
+            frozen_account_set_id,
+            closed_account_set_id,
+            dormant_account_set_id,
+            daily_withdrawal_limit_id,
+            monthly_withdrawal_limit_id,
             usd: Currency::USD,
+            btc: Currency::BTC,
         })
     }
 
@@ -364,11 +561,46 @@ impl DepositLedger {
         })
     }
 
+    /// Returns all entries posted to the account at or after `since`, newest first.
+    pub async fn entries_since(
+        &self,
+        id: impl Into<AccountId>,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<cala_ledger::entry::Entry>, DepositLedgerError> {
+        let id = id.into();
+        let mut entries = Vec::new();
+        let mut after = None;
+        loop {
+            let ret = self
+                .cala
+                .entries()
+                .list_for_account_id(
+                    id,
+                    es_entity::PaginatedQueryArgs { first: 100, after },
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+            let mut reached_since = false;
+            for entry in ret.entities {
+                if entry.created_at() < since {
+                    reached_since = true;
+                    break;
+                }
+                entries.push(entry);
+            }
+            if reached_since || !ret.has_next_page {
+                break;
+            }
+            after = ret.end_cursor;
+        }
+        Ok(entries)
+    }
+
     pub async fn record_deposit(
         &self,
         op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
-        amount: UsdCents,
+        amount: DepositAmount,
         credit_account_id: impl Into<AccountId>,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
@@ -376,8 +608,8 @@ impl DepositLedger {
 
         let params = templates::RecordDepositParams {
             journal_id: self.journal_id,
-            currency: self.usd,
-            amount: amount.to_usd(),
+            currency: amount.currency().into(),
+            amount: amount.to_ledger_units(),
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             credit_account_id: credit_account_id.into(),
         };
@@ -389,38 +621,192 @@ impl DepositLedger {
         Ok(())
     }
 
-    pub async fn initiate_withdrawal(
+    pub async fn revert_deposit(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: DepositAmount,
+        debit_account_id: impl Into<AccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let params = templates::RevertDepositParams {
+            journal_id: self.journal_id,
+            currency: amount.currency().into(),
+            amount: amount.to_ledger_units(),
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            debit_account_id: debit_account_id.into(),
+        };
+        self.cala
+            .post_transaction_in_op(&mut op, tx_id, templates::REVERT_DEPOSIT_CODE, params)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn place_hold(
         &self,
         op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
         amount: UsdCents,
+        deposit_account_id: impl Into<AccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let params = templates::PlaceHoldParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            deposit_account_id: deposit_account_id.into(),
+        };
+        self.cala
+            .post_transaction_in_op(&mut op, tx_id, templates::PLACE_HOLD_CODE, params)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn release_hold(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: UsdCents,
+        deposit_account_id: impl Into<AccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let params = templates::ReleaseHoldParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            deposit_account_id: deposit_account_id.into(),
+        };
+        self.cala
+            .post_transaction_in_op(&mut op, tx_id, templates::RELEASE_HOLD_CODE, params)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn initiate_withdrawal(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: DepositAmount,
         credit_account_id: impl Into<AccountId>,
+        fee: Option<DepositFeeCharge>,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
+        let credit_account_id = credit_account_id.into();
         let mut op = self.cala.ledger_operation_from_db_op(op);
 
         let params = templates::InitiateWithdrawParams {
             journal_id: self.journal_id,
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
-            credit_account_id: credit_account_id.into(),
-            amount: amount.to_usd(),
-            currency: self.usd,
+            credit_account_id,
+            amount: amount.to_ledger_units(),
+            currency: amount.currency().into(),
         };
 
         self.cala
             .post_transaction_in_op(&mut op, tx_id, templates::INITIATE_WITHDRAW_CODE, params)
             .await?;
 
+        if let Some(fee) = fee {
+            self.charge_fee_in_op(&mut op, fee, credit_account_id)
+                .await?;
+        }
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn charge_fee(
+        &self,
+        op: es_entity::DbOp<'_>,
+        fee: DepositFeeCharge,
+        deposit_account_id: impl Into<CalaAccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.charge_fee_in_op(&mut op, fee, deposit_account_id.into())
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    async fn charge_fee_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        fee: DepositFeeCharge,
+        deposit_account_id: CalaAccountId,
+    ) -> Result<(), DepositLedgerError> {
+        let params = templates::ChargeDepositFeeParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: fee.amount.to_usd(),
+            deposit_account_id,
+            fee_income_account_id: self.fee_income_account_ids.account_id,
+        };
+        self.cala
+            .post_transaction_in_op(op, fee.tx_id, templates::CHARGE_DEPOSIT_FEE_CODE, params)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn waive_fee(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: UsdCents,
+        deposit_account_id: impl Into<CalaAccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.waive_fee_in_op(
+            &mut op,
+            DepositFeeCharge {
+                tx_id: tx_id.into(),
+                amount,
+            },
+            deposit_account_id.into(),
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
 
+    async fn waive_fee_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        fee: DepositFeeCharge,
+        deposit_account_id: CalaAccountId,
+    ) -> Result<(), DepositLedgerError> {
+        let params = templates::WaiveDepositFeeParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: fee.amount.to_usd(),
+            deposit_account_id,
+            fee_income_account_id: self.fee_income_account_ids.account_id,
+        };
+        self.cala
+            .post_transaction_in_op(op, fee.tx_id, templates::WAIVE_DEPOSIT_FEE_CODE, params)
+            .await?;
+        Ok(())
+    }
+
     pub async fn confirm_withdrawal(
         &self,
         op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
         correlation_id: String,
-        amount: UsdCents,
+        amount: DepositAmount,
         credit_account_id: impl Into<AccountId>,
         external_id: String,
     ) -> Result<(), DepositLedgerError> {
@@ -429,8 +815,8 @@ impl DepositLedger {
 
         let params = templates::ConfirmWithdrawParams {
             journal_id: self.journal_id,
-            currency: self.usd,
-            amount: amount.to_usd(),
+            currency: amount.currency().into(),
+            amount: amount.to_ledger_units(),
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             credit_account_id: credit_account_id.into(),
             correlation_id,
@@ -448,22 +834,128 @@ impl DepositLedger {
         &self,
         op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
-        amount: UsdCents,
+        amount: DepositAmount,
         credit_account_id: impl Into<AccountId>,
+        initiated_at: chrono::DateTime<chrono::Utc>,
+        waived_fee: Option<DepositFeeCharge>,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
+        let credit_account_id = credit_account_id.into();
         let mut op = self.cala.ledger_operation_from_db_op(op);
 
         let params = templates::CancelWithdrawParams {
+            journal_id: self.journal_id,
+            currency: amount.currency().into(),
+            amount: amount.to_ledger_units(),
+            credit_account_id,
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            initiated_at,
+        };
+
+        self.cala
+            .post_transaction_in_op(&mut op, tx_id, templates::CANCEL_WITHDRAW_CODE, params)
+            .await?;
+
+        if let Some(fee) = waived_fee {
+            self.waive_fee_in_op(&mut op, fee, credit_account_id)
+                .await?;
+        }
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn transfer(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: UsdCents,
+        debit_account_id: impl Into<AccountId>,
+        credit_account_id: impl Into<AccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let params = templates::TransferParams {
             journal_id: self.journal_id,
             currency: self.usd,
             amount: amount.to_usd(),
+            debit_account_id: debit_account_id.into(),
             credit_account_id: credit_account_id.into(),
-            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
         };
 
         self.cala
-            .post_transaction_in_op(&mut op, tx_id, templates::CANCEL_WITHDRAW_CODE, params)
+            .post_transaction_in_op(&mut op, tx_id, templates::TRANSFER_CODE, params)
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn accrue_interest(
+        &self,
+        mut op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        let effective = self
+            .posting_lock
+            .first_open_date_in_op(&mut op, effective)
+            .await?;
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let params = templates::AccrueDepositInterestParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            interest_expense_account_id: self.interest_expense_account_ids.account_id,
+            accrued_interest_account_id: self.accrued_interest_account_ids.account_id,
+            effective,
+        };
+
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::ACCRUE_DEPOSIT_INTEREST_CODE,
+                params,
+            )
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn capitalize_interest(
+        &self,
+        mut op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: UsdCents,
+        deposit_account_id: impl Into<AccountId>,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        let effective = self
+            .posting_lock
+            .first_open_date_in_op(&mut op, effective)
+            .await?;
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let params = templates::CapitalizeDepositInterestParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            accrued_interest_account_id: self.accrued_interest_account_ids.account_id,
+            deposit_account_id: deposit_account_id.into(),
+            effective,
+        };
+
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::CAPITALIZE_DEPOSIT_INTEREST_CODE,
+                params,
+            )
             .await?;
         op.commit().await?;
         Ok(())
@@ -482,6 +974,7 @@ impl DepositLedger {
             Ok(balances) => Ok(DepositAccountBalance {
                 settled: UsdCents::try_from_usd(balances.settled())?,
                 pending: UsdCents::try_from_usd(balances.pending())?,
+                encumbrance: UsdCents::try_from_usd(balances.encumbrance())?,
             }),
             Err(cala_ledger::balance::error::BalanceError::NotFound(..)) => {
                 Ok(DepositAccountBalance::ZERO)
@@ -490,9 +983,57 @@ impl DepositLedger {
         }
     }
 
-    pub async fn create_deposit_account(
+    /// Balance at the end of `date`, for catching up on work for days that have passed.
+    pub async fn balance_as_of(
         &self,
-        op: es_entity::DbOp<'_>,
+        account_id: impl Into<AccountId>,
+        date: chrono::NaiveDate,
+    ) -> Result<DepositAccountBalance, DepositLedgerError> {
+        let balance_id = (self.journal_id, account_id.into(), self.usd);
+        let until = date
+            .and_hms_opt(23, 59, 59)
+            .expect("valid end of day")
+            .and_utc();
+        let mut balances = self
+            .cala
+            .balances()
+            .find_all_in_range(&[balance_id], chrono::DateTime::UNIX_EPOCH, Some(until))
+            .await?;
+        match balances.remove(&balance_id) {
+            Some(range) => Ok(DepositAccountBalance {
+                settled: UsdCents::try_from_usd(range.end.settled())?,
+                pending: UsdCents::try_from_usd(range.end.pending())?,
+                encumbrance: UsdCents::try_from_usd(range.end.encumbrance())?,
+            }),
+            None => Ok(DepositAccountBalance::ZERO),
+        }
+    }
+
+    pub async fn btc_balance(
+        &self,
+        account_id: impl Into<AccountId>,
+    ) -> Result<BtcDepositAccountBalance, DepositLedgerError> {
+        match self
+            .cala
+            .balances()
+            .find(self.journal_id, account_id.into(), self.btc)
+            .await
+        {
+            Ok(balances) => Ok(BtcDepositAccountBalance {
+                settled: Satoshis::try_from_btc(balances.settled())?,
+                pending: Satoshis::try_from_btc(balances.pending())?,
+                encumbrance: Satoshis::try_from_btc(balances.encumbrance())?,
+            }),
+            Err(cala_ledger::balance::error::BalanceError::NotFound(..)) => {
+                Ok(BtcDepositAccountBalance::ZERO)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub async fn create_deposit_account(
+        &self,
+        op: es_entity::DbOp<'_>,
         id: impl Into<CalaAccountId>,
         deposit_account_reference: String,
         deposit_account_name: String,
@@ -519,6 +1060,180 @@ impl DepositLedger {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_time_deposit_account(
+        &self,
+        op: es_entity::DbOp<'_>,
+        id: impl Into<CalaAccountId>,
+        deposit_account_reference: String,
+        deposit_account_name: String,
+        funding_tx_id: impl Into<TransactionId>,
+        principal: UsdCents,
+        funding_account_id: impl Into<AccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let id = id.into();
+
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        self.create_account_in_op(
+            &mut op,
+            id,
+            self.deposits_account_set.time_deposit,
+            &deposit_account_reference,
+            &deposit_account_name,
+            &deposit_account_name,
+        )
+        .await?;
+
+        self.add_deposit_control_to_account(&mut op, id).await?;
+
+        let params = templates::TransferParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: principal.to_usd(),
+            debit_account_id: funding_account_id.into(),
+            credit_account_id: id,
+        };
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                funding_tx_id.into(),
+                templates::TRANSFER_CODE,
+                params,
+            )
+            .await?;
+
+        op.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn mature_time_deposit(
+        &self,
+        mut op: es_entity::DbOp<'_>,
+        account_id: impl Into<CalaAccountId>,
+        interest_tx_id: impl Into<TransactionId>,
+        interest: UsdCents,
+        effective: chrono::NaiveDate,
+        payout: DepositAccountSweep,
+    ) -> Result<(), DepositLedgerError> {
+        let account_id = account_id.into();
+        let effective = self
+            .posting_lock
+            .first_open_date_in_op(&mut op, effective)
+            .await?;
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        if !interest.is_zero() {
+            let params = templates::CapitalizeDepositInterestParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: interest.to_usd(),
+                accrued_interest_account_id: self.accrued_interest_account_ids.account_id,
+                deposit_account_id: account_id,
+                effective,
+            };
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    interest_tx_id.into(),
+                    templates::CAPITALIZE_DEPOSIT_INTEREST_CODE,
+                    params,
+                )
+                .await?;
+        }
+
+        self.pay_out_time_deposit_in_op(&mut op, account_id, payout)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn break_time_deposit(
+        &self,
+        op: es_entity::DbOp<'_>,
+        account_id: impl Into<CalaAccountId>,
+        interest_tx_id: impl Into<TransactionId>,
+        interest: UsdCents,
+        forfeit_tx_id: impl Into<TransactionId>,
+        forfeited_interest: UsdCents,
+        payout: DepositAccountSweep,
+    ) -> Result<(), DepositLedgerError> {
+        let account_id = account_id.into();
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        if !interest.is_zero() {
+            let params = templates::CapitalizeDepositInterestParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: interest.to_usd(),
+                accrued_interest_account_id: self.accrued_interest_account_ids.account_id,
+                deposit_account_id: account_id,
+                effective: chrono::Utc::now().date_naive(),
+            };
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    interest_tx_id.into(),
+                    templates::CAPITALIZE_DEPOSIT_INTEREST_CODE,
+                    params,
+                )
+                .await?;
+        }
+
+        if !forfeited_interest.is_zero() {
+            let params = templates::ForfeitDepositInterestParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: forfeited_interest.to_usd(),
+                interest_expense_account_id: self.interest_expense_account_ids.account_id,
+                accrued_interest_account_id: self.accrued_interest_account_ids.account_id,
+                effective: chrono::Utc::now().date_naive(),
+            };
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    forfeit_tx_id.into(),
+                    templates::FORFEIT_DEPOSIT_INTEREST_CODE,
+                    params,
+                )
+                .await?;
+        }
+
+        self.pay_out_time_deposit_in_op(&mut op, account_id, payout)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    async fn pay_out_time_deposit_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        account_id: CalaAccountId,
+        payout: DepositAccountSweep,
+    ) -> Result<(), DepositLedgerError> {
+        let params = templates::TransferParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: payout.amount.to_usd(),
+            debit_account_id: account_id,
+            credit_account_id: payout.to_account_id,
+        };
+        self.cala
+            .post_transaction_in_op(op, payout.tx_id, templates::TRANSFER_CODE, params)
+            .await?;
+
+        self.cala
+            .account_sets()
+            .add_member_in_op(op, self.closed_account_set_id, account_id)
+            .await?;
+
+        Ok(())
+    }
+
     fn deposit_internal_account_set_from_type(
         &self,
         deposit_account_type: DepositAccountType,
@@ -589,6 +1304,335 @@ impl DepositLedger {
         }
     }
 
+    async fn create_account_set_control(
+        cala: &CalaLedger,
+        id: uuid::Uuid,
+        name: &str,
+        limit_ids: &[VelocityLimitId],
+        params: Params,
+        account_set_id: CalaAccountSetId,
+    ) -> Result<(), DepositLedgerError> {
+        let control = NewVelocityControl::builder()
+            .id(id)
+            .name(name)
+            .description(name)
+            .build()
+            .expect("build control");
+
+        let control_id = match cala.velocities().create_control(control).await {
+            Err(cala_ledger::velocity::error::VelocityError::ControlIdAlreadyExists) => {
+                VelocityControlId::from(id)
+            }
+            Err(e) => return Err(e.into()),
+            Ok(control) => control.id(),
+        };
+
+        for limit_id in limit_ids {
+            match cala
+                .velocities()
+                .add_limit_to_control(control_id, *limit_id)
+                .await
+            {
+                Ok(_)
+                | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        match cala
+            .velocities()
+            .attach_control_to_account_set(control_id, account_set_id, params)
+            .await
+        {
+            Ok(_) | Err(cala_ledger::velocity::error::VelocityError::ControlAlreadyAttached) => {
+                Ok(())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Creates the account set carrying the velocity control for one version of a
+    /// withdrawal limit. Each update gets a fresh set as attached control params
+    /// cannot be changed in place.
+    pub async fn create_withdrawal_limit(
+        &self,
+        account_set_id: CalaAccountSetId,
+        name: &str,
+        daily_limit: UsdCents,
+        monthly_limit: UsdCents,
+    ) -> Result<(), DepositLedgerError> {
+        let new_account_set = NewAccountSet::builder()
+            .id(account_set_id)
+            .journal_id(self.journal_id)
+            .external_id(format!(
+                "{}:{DEPOSIT_WITHDRAWAL_LIMIT_ACCOUNT_SET_REF}:{account_set_id}",
+                self.journal_id
+            ))
+            .name(name.to_string())
+            .description(name.to_string())
+            .normal_balance_type(self.deposits_account_set.individual.normal_balance_type)
+            .build()
+            .expect("Could not build new account set");
+        match self.cala.account_sets().create(new_account_set).await {
+            Ok(_)
+            | Err(cala_ledger::account_set::error::AccountSetError::ExternalIdAlreadyExists) => (),
+            Err(e) => return Err(e.into()),
+        }
+
+        let mut params = Params::default();
+        params.insert(velocity::DAILY_WITHDRAWAL_LIMIT_PARAM, daily_limit.to_usd());
+        params.insert(
+            velocity::MONTHLY_WITHDRAWAL_LIMIT_PARAM,
+            monthly_limit.to_usd(),
+        );
+        Self::create_account_set_control(
+            &self.cala,
+            uuid::Uuid::from(account_set_id),
+            name,
+            &[
+                self.daily_withdrawal_limit_id,
+                self.monthly_withdrawal_limit_id,
+            ],
+            params,
+            account_set_id,
+        )
+        .await
+    }
+
+    /// Moves every account governed by the `from` withdrawal limit set into `to`.
+    pub async fn replace_withdrawal_limit(
+        &self,
+        op: es_entity::DbOp<'_>,
+        from: CalaAccountSetId,
+        to: CalaAccountSetId,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        let mut has_next_page = true;
+        let mut after = None;
+        while has_next_page {
+            let members = self
+                .cala
+                .account_sets()
+                .list_members_by_created_at(
+                    from,
+                    es_entity::PaginatedQueryArgs { first: 100, after },
+                )
+                .await?;
+            (after, has_next_page) = (members.end_cursor, members.has_next_page);
+            for member in members.entities {
+                if let AccountSetMemberId::Account(account_id) = member.id {
+                    self.cala
+                        .account_sets()
+                        .remove_member_in_op(&mut op, from, account_id)
+                        .await?;
+                    self.cala
+                        .account_sets()
+                        .add_member_in_op(&mut op, to, account_id)
+                        .await?;
+                }
+            }
+        }
+        op.commit().await?;
+        Ok(())
+    }
+
+    /// Moves the account between withdrawal limit account sets. Passing `None` on
+    /// either side lifts the limit, e.g. while a temporary override is in effect.
+    pub async fn move_withdrawal_limit(
+        &self,
+        op: es_entity::DbOp<'_>,
+        account_id: impl Into<CalaAccountId>,
+        from: Option<CalaAccountSetId>,
+        to: Option<CalaAccountSetId>,
+    ) -> Result<(), DepositLedgerError> {
+        let account_id = account_id.into();
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        if from == to {
+            op.commit().await?;
+            return Ok(());
+        }
+        if let Some(from) = from {
+            self.cala
+                .account_sets()
+                .remove_member_in_op(&mut op, from, account_id)
+                .await?;
+        }
+        if let Some(to) = to {
+            self.cala
+                .account_sets()
+                .add_member_in_op(&mut op, to, account_id)
+                .await?;
+        }
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn freeze_account(
+        &self,
+        op: es_entity::DbOp<'_>,
+        account_id: impl Into<CalaAccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .account_sets()
+            .add_member_in_op(&mut op, self.frozen_account_set_id, account_id.into())
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn unfreeze_account(
+        &self,
+        op: es_entity::DbOp<'_>,
+        account_id: impl Into<CalaAccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .account_sets()
+            .remove_member_in_op(&mut op, self.frozen_account_set_id, account_id.into())
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn mark_account_dormant(
+        &self,
+        op: es_entity::DbOp<'_>,
+        account_id: impl Into<CalaAccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .account_sets()
+            .add_member_in_op(&mut op, self.dormant_account_set_id, account_id.into())
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn reactivate_account(
+        &self,
+        op: es_entity::DbOp<'_>,
+        account_id: impl Into<CalaAccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .account_sets()
+            .remove_member_in_op(&mut op, self.dormant_account_set_id, account_id.into())
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    /// The account stays dormant; it only leaves the dormant set while the balance is moved out.
+    pub async fn transfer_unclaimed_funds(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: UsdCents,
+        account_id: impl Into<CalaAccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let account_id = account_id.into();
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        self.cala
+            .account_sets()
+            .remove_member_in_op(&mut op, self.dormant_account_set_id, account_id)
+            .await?;
+
+        let params = templates::TransferUnclaimedFundsParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_account_id: account_id,
+            unclaimed_funds_account_id: self.unclaimed_funds_account_ids.account_id,
+        };
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                tx_id.into(),
+                templates::TRANSFER_UNCLAIMED_FUNDS_CODE,
+                params,
+            )
+            .await?;
+
+        self.cala
+            .account_sets()
+            .add_member_in_op(&mut op, self.dormant_account_set_id, account_id)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn close_account(
+        &self,
+        mut op: es_entity::DbOp<'_>,
+        account_id: impl Into<CalaAccountId>,
+        was_frozen: bool,
+        interest: Option<DepositInterestPosting>,
+        sweep: Option<DepositAccountSweep>,
+    ) -> Result<(), DepositLedgerError> {
+        let account_id = account_id.into();
+        let interest = match interest {
+            Some(posting) => Some(DepositInterestPosting {
+                effective: self
+                    .posting_lock
+                    .first_open_date_in_op(&mut op, posting.effective)
+                    .await?,
+                ..posting
+            }),
+            None => None,
+        };
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        if was_frozen {
+            self.cala
+                .account_sets()
+                .remove_member_in_op(&mut op, self.frozen_account_set_id, account_id)
+                .await?;
+        }
+
+        if let Some(interest) = interest {
+            let params = templates::CapitalizeDepositInterestParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: interest.amount.to_usd(),
+                accrued_interest_account_id: self.accrued_interest_account_ids.account_id,
+                deposit_account_id: account_id,
+                effective: interest.effective,
+            };
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    interest.tx_id,
+                    templates::CAPITALIZE_DEPOSIT_INTEREST_CODE,
+                    params,
+                )
+                .await?;
+        }
+
+        if let Some(sweep) = sweep {
+            let params = templates::TransferParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: sweep.amount.to_usd(),
+                debit_account_id: account_id,
+                credit_account_id: sweep.to_account_id,
+            };
+            self.cala
+                .post_transaction_in_op(&mut op, sweep.tx_id, templates::TRANSFER_CODE, params)
+                .await?;
+        }
+
+        self.cala
+            .account_sets()
+            .add_member_in_op(&mut op, self.closed_account_set_id, account_id)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
     pub async fn add_deposit_control_to_account(
         &self,
         op: &mut cala_ledger::LedgerOperation<'_>,
@@ -675,7 +1719,13 @@ impl DepositLedger {
     ) -> Result<(), DepositLedgerError> {
         let mut op = self.cala.begin_operation().await?;
 
-        let mut account_set_ids = vec![self.deposit_omnibus_account_ids.account_set_id];
+        let mut account_set_ids = vec![
+            self.deposit_omnibus_account_ids.account_set_id,
+            self.interest_expense_account_ids.account_set_id,
+            self.accrued_interest_account_ids.account_set_id,
+            self.unclaimed_funds_account_ids.account_set_id,
+            self.fee_income_account_ids.account_set_id,
+        ];
         account_set_ids.extend(self.deposits_account_set.account_set_ids());
         let mut account_sets = self
             .cala
@@ -698,6 +1748,11 @@ impl DepositLedger {
                 financial_institution_deposit_parent_account_set_id,
             non_domiciled_individual_deposit_accounts_parent_account_set_id:
                 non_domiciled_company_deposit_parent_account_set_id,
+            time_deposit_accounts_parent_account_set_id: time_deposit_parent_account_set_id,
+            interest_expense_parent_account_set_id,
+            accrued_interest_parent_account_set_id,
+            unclaimed_funds_parent_account_set_id,
+            fee_income_parent_account_set_id,
         } = &charts_integration_meta;
 
         self.attach_charts_account_set(
@@ -770,6 +1825,56 @@ impl DepositLedger {
         )
         .await?;
 
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.deposits_account_set.time_deposit.id,
+            *time_deposit_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.time_deposit_accounts_parent_account_set_id,
+        )
+        .await?;
+
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.interest_expense_account_ids.account_set_id,
+            *interest_expense_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.interest_expense_parent_account_set_id,
+        )
+        .await?;
+
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.accrued_interest_account_ids.account_set_id,
+            *accrued_interest_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.accrued_interest_parent_account_set_id,
+        )
+        .await?;
+
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.unclaimed_funds_account_ids.account_set_id,
+            *unclaimed_funds_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.unclaimed_funds_parent_account_set_id,
+        )
+        .await?;
+
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.fee_income_account_ids.account_set_id,
+            *fee_income_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.fee_income_parent_account_set_id,
+        )
+        .await?;
+
         op.commit().await?;
 
         Ok(())
@@ -789,4 +1894,80 @@ pub struct ChartOfAccountsIntegrationMeta {
     pub bank_deposit_accounts_parent_account_set_id: CalaAccountSetId,
     pub financial_institution_deposit_accounts_parent_account_set_id: CalaAccountSetId,
     pub non_domiciled_individual_deposit_accounts_parent_account_set_id: CalaAccountSetId,
+    pub time_deposit_accounts_parent_account_set_id: CalaAccountSetId,
+
+    pub interest_expense_parent_account_set_id: CalaAccountSetId,
+    pub accrued_interest_parent_account_set_id: CalaAccountSetId,
+    pub unclaimed_funds_parent_account_set_id: CalaAccountSetId,
+    pub fee_income_parent_account_set_id: CalaAccountSetId,
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use cala_ledger::{journal::*, CalaLedgerConfig};
+
+    use super::*;
+
+    pub async fn init_pool() -> anyhow::Result<sqlx::PgPool> {
+        let pg_host = std::env::var("PG_HOST").unwrap_or("localhost".to_string());
+        let pg_con = format!("postgres://user:password@{pg_host}:5433/pg");
+        let pool = sqlx::PgPool::connect(&pg_con).await?;
+        Ok(pool)
+    }
+
+    #[tokio::test]
+    async fn velocity_control_rejects_overdrawn_withdrawal() -> anyhow::Result<()> {
+        let pool = init_pool().await?;
+        let cala_config = CalaLedgerConfig::builder()
+            .pool(pool.clone())
+            .exec_migrations(false)
+            .build()?;
+        let cala = CalaLedger::init(cala_config).await?;
+        let new_journal = NewJournal::builder()
+            .id(JournalId::new())
+            .name("Test journal")
+            .build()
+            .unwrap();
+        let journal = cala.journals().create(new_journal).await?;
+        let ledger = DepositLedger::init(&pool, &cala, journal.id).await?;
+
+        let account_id = CalaAccountId::new();
+        ledger
+            .create_deposit_account(
+                es_entity::DbOp::init(&pool).await?,
+                account_id,
+                account_id.to_string(),
+                "Overdraft test".to_string(),
+                DepositAccountType::Individual,
+            )
+            .await?;
+        let deposit_amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+        ledger
+            .record_deposit(
+                es_entity::DbOp::init(&pool).await?,
+                TransactionId::new(),
+                DepositAmount::Usd(deposit_amount),
+                account_id,
+            )
+            .await?;
+
+        let res = ledger
+            .initiate_withdrawal(
+                es_entity::DbOp::init(&pool).await?,
+                TransactionId::new(),
+                DepositAmount::Usd(UsdCents::try_from_usd(dec!(5000)).unwrap()),
+                account_id,
+                None,
+            )
+            .await;
+        assert!(matches!(res, Err(DepositLedgerError::CalaLedger(_))));
+
+        let balance = ledger.balance(account_id).await?;
+        assert_eq!(balance.settled, deposit_amount);
+        assert!(balance.pending.is_zero());
+
+        Ok(())
+    }
 }
@@ -6,6 +6,7 @@ use cala_ledger::{
     *,
 };
 
+use super::initiate_withdraw::withdrawal_window_meta;
 use crate::ledger::error::*;
 
 pub const CANCEL_WITHDRAW_CODE: &str = "CANCEL_WITHDRAW";
@@ -17,6 +18,7 @@ pub struct CancelWithdrawParams {
     pub amount: Decimal,
     pub deposit_omnibus_account_id: AccountId,
     pub credit_account_id: AccountId,
+    pub initiated_at: chrono::DateTime<chrono::Utc>,
 }
 
 impl CancelWithdrawParams {
@@ -52,6 +54,11 @@ impl CancelWithdrawParams {
                 .r#type(ParamDataType::Date)
                 .build()
                 .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
         ]
     }
 }
@@ -64,6 +71,7 @@ impl From<CancelWithdrawParams> for Params {
             amount,
             deposit_omnibus_account_id,
             credit_account_id,
+            initiated_at,
         }: CancelWithdrawParams,
     ) -> Self {
         let mut params = Self::default();
@@ -73,6 +81,7 @@ impl From<CancelWithdrawParams> for Params {
         params.insert("deposit_omnibus_account_id", deposit_omnibus_account_id);
         params.insert("credit_account_id", credit_account_id);
         params.insert("effective", chrono::Utc::now().date_naive());
+        params.insert("meta", withdrawal_window_meta(initiated_at));
 
         params
     }
@@ -86,6 +95,7 @@ impl CancelWithdraw {
         let tx_input = NewTxTemplateTransaction::builder()
             .journal_id("params.journal_id")
             .effective("params.effective")
+            .metadata("params.meta")
             .description("'Cancel a Withdraw'")
             .build()
             .expect("Couldn't build TxInput");
@@ -0,0 +1,132 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{error::TxTemplateError, Params, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const FORFEIT_DEPOSIT_INTEREST_CODE: &str = "FORFEIT_DEPOSIT_INTEREST";
+
+#[derive(Debug)]
+pub struct ForfeitDepositInterestParams {
+    pub journal_id: JournalId,
+    pub interest_expense_account_id: CalaAccountId,
+    pub accrued_interest_account_id: CalaAccountId,
+    pub amount: Decimal,
+    pub currency: Currency,
+    pub effective: NaiveDate,
+}
+
+impl ForfeitDepositInterestParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("interest_expense_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("accrued_interest_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<ForfeitDepositInterestParams> for Params {
+    fn from(
+        ForfeitDepositInterestParams {
+            journal_id,
+            interest_expense_account_id,
+            accrued_interest_account_id,
+            amount,
+            currency,
+            effective,
+        }: ForfeitDepositInterestParams,
+    ) -> Self {
+        let mut params = Self::default();
+
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("amount", amount);
+        params.insert("interest_expense_account_id", interest_expense_account_id);
+        params.insert("accrued_interest_account_id", accrued_interest_account_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct ForfeitDepositInterest;
+
+impl ForfeitDepositInterest {
+    #[instrument(name = "ledger.forfeit_deposit_interest.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Forfeit accrued interest on a deposit account'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'FORFEIT_DEPOSIT_INTEREST_DR'")
+                .currency("params.currency")
+                .account_id("params.accrued_interest_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'FORFEIT_DEPOSIT_INTEREST_CR'")
+                .currency("params.currency")
+                .account_id("params.interest_expense_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = ForfeitDepositInterestParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(FORFEIT_DEPOSIT_INTEREST_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
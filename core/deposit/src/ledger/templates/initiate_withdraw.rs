@@ -10,6 +10,15 @@ use crate::{ledger::error::*, primitives::CalaAccountId};
 
 pub const INITIATE_WITHDRAW_CODE: &str = "INITIATE_WITHDRAW";
 
+/// Windows the withdrawal velocity limits partition on. Cancellations carry the
+/// windows of the withdrawal they reverse so the refund nets out of the same window.
+pub fn withdrawal_window_meta(initiated_at: chrono::DateTime<chrono::Utc>) -> serde_json::Value {
+    serde_json::json!({
+        "withdrawal_day": initiated_at.format("%Y-%m-%d").to_string(),
+        "withdrawal_month": initiated_at.format("%Y-%m").to_string(),
+    })
+}
+
 #[derive(Debug)]
 pub struct InitiateWithdrawParams {
     pub journal_id: JournalId,
@@ -52,6 +61,11 @@ impl InitiateWithdrawParams {
                 .r#type(ParamDataType::Date)
                 .build()
                 .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
         ]
     }
 }
@@ -66,6 +80,7 @@ impl From<InitiateWithdrawParams> for Params {
             currency,
         }: InitiateWithdrawParams,
     ) -> Self {
+        let now = chrono::Utc::now();
         let mut params = Self::default();
 
         params.insert("journal_id", journal_id);
@@ -73,7 +88,8 @@ impl From<InitiateWithdrawParams> for Params {
         params.insert("amount", amount);
         params.insert("deposit_omnibus_account_id", deposit_omnibus_account_id);
         params.insert("credit_account_id", credit_account_id);
-        params.insert("effective", chrono::Utc::now().date_naive());
+        params.insert("effective", now.date_naive());
+        params.insert("meta", withdrawal_window_meta(now));
 
         params
     }
@@ -87,6 +103,7 @@ impl InitiateWithdraw {
         let tx_input = NewTxTemplateTransaction::builder()
             .journal_id("params.journal_id")
             .effective("params.effective")
+            .metadata("params.meta")
             .description("'Initiate a withdraw'")
             .build()
             .expect("Couldn't build TxInput");
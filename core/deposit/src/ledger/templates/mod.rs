@@ -1,9 +1,29 @@
+mod accrue_deposit_interest;
 mod cancel_withdraw;
+mod capitalize_deposit_interest;
+mod charge_deposit_fee;
 mod confirm_withdraw;
+mod forfeit_deposit_interest;
 mod initiate_withdraw;
+mod place_hold;
 mod record_deposit;
+mod release_hold;
+mod revert_deposit;
+mod transfer;
+mod transfer_unclaimed_funds;
+mod waive_deposit_fee;
 
+pub use accrue_deposit_interest::*;
 pub use cancel_withdraw::*;
+pub use capitalize_deposit_interest::*;
+pub use charge_deposit_fee::*;
 pub use confirm_withdraw::*;
+pub use forfeit_deposit_interest::*;
 pub use initiate_withdraw::*;
+pub use place_hold::*;
 pub use record_deposit::*;
+pub use release_hold::*;
+pub use revert_deposit::*;
+pub use transfer::*;
+pub use transfer_unclaimed_funds::*;
+pub use waive_deposit_fee::*;
@@ -0,0 +1,50 @@
+use tracing::instrument;
+
+use cala_ledger::{velocity::*, *};
+
+use crate::ledger::error::*;
+
+pub struct ClosedAccountPrevention;
+
+const CLOSED_ACCOUNT_PREVENTION_ID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000004");
+
+impl ClosedAccountPrevention {
+    #[instrument(name = "ledger.closed_account_prevention.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        let limit = NewVelocityLimit::builder()
+            .id(CLOSED_ACCOUNT_PREVENTION_ID)
+            .name("Closed Account Prevention")
+            .description("Prevent any movement on closed accounts")
+            .window(vec![])
+            .limit(
+                NewLimit::builder()
+                    .balance(vec![
+                        NewBalanceLimit::builder()
+                            .layer("SETTLED")
+                            .amount("decimal('0.0')")
+                            .enforcement_direction("DEBIT")
+                            .build()
+                            .expect("balance limit"),
+                        NewBalanceLimit::builder()
+                            .layer("SETTLED")
+                            .amount("decimal('0.0')")
+                            .enforcement_direction("CREDIT")
+                            .build()
+                            .expect("balance limit"),
+                    ])
+                    .build()
+                    .expect("limit"),
+            )
+            .build()
+            .expect("velocity limit");
+
+        match ledger.velocities().create_limit(limit).await {
+            Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => {
+                Ok(CLOSED_ACCOUNT_PREVENTION_ID.into())
+            }
+            Err(e) => Err(e.into()),
+            Ok(limit) => Ok(limit.id()),
+        }
+    }
+}
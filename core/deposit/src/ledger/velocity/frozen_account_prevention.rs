@@ -0,0 +1,43 @@
+use tracing::instrument;
+
+use cala_ledger::{velocity::*, *};
+
+use crate::ledger::error::*;
+
+pub struct FrozenAccountPrevention;
+
+const FROZEN_ACCOUNT_PREVENTION_ID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000003");
+
+impl FrozenAccountPrevention {
+    #[instrument(name = "ledger.frozen_account_prevention.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        let limit = NewVelocityLimit::builder()
+            .id(FROZEN_ACCOUNT_PREVENTION_ID)
+            .name("Frozen Account Prevention")
+            .description("Prevent debits from frozen accounts")
+            .window(vec![])
+            .condition("context.vars.entry.direction == DEBIT")
+            .limit(
+                NewLimit::builder()
+                    .balance(vec![NewBalanceLimit::builder()
+                        .layer("SETTLED")
+                        .amount("decimal('0.0')")
+                        .enforcement_direction("DEBIT")
+                        .build()
+                        .expect("balance limit")])
+                    .build()
+                    .expect("limit"),
+            )
+            .build()
+            .expect("velocity limit");
+
+        match ledger.velocities().create_limit(limit).await {
+            Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => {
+                Ok(FROZEN_ACCOUNT_PREVENTION_ID.into())
+            }
+            Err(e) => Err(e.into()),
+            Ok(limit) => Ok(limit.id()),
+        }
+    }
+}
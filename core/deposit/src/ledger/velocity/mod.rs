@@ -1,3 +1,9 @@
+mod closed_account_prevention;
+mod frozen_account_prevention;
 mod overdraft_prevention;
+mod withdrawal_limit;
 
+pub use closed_account_prevention::*;
+pub use frozen_account_prevention::*;
 pub use overdraft_prevention::*;
+pub use withdrawal_limit::*;
@@ -0,0 +1,96 @@
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{NewParamDefinition, ParamDataType},
+    velocity::*,
+    *,
+};
+
+use crate::ledger::error::*;
+
+pub struct DailyWithdrawalLimit;
+pub struct MonthlyWithdrawalLimit;
+
+const DAILY_WITHDRAWAL_LIMIT_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000005");
+const MONTHLY_WITHDRAWAL_LIMIT_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000006");
+
+pub const DAILY_WITHDRAWAL_LIMIT_PARAM: &str = "daily_limit";
+pub const MONTHLY_WITHDRAWAL_LIMIT_PARAM: &str = "monthly_limit";
+
+// The settled debit of an initiated USD withdrawal counts towards the window and the
+// refund of a cancelled or denied one is credited back to it
+const WITHDRAWAL_CONDITION: &str = "(context.vars.entry.entry_type == 'INITIATE_WITHDRAW_SETTLED_DR' || context.vars.entry.entry_type == 'CANCEL_WITHDRAW_SETTLED_CR') && context.vars.entry.currency == 'USD'";
+
+impl DailyWithdrawalLimit {
+    #[instrument(name = "ledger.daily_withdrawal_limit.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        init_windowed_limit(
+            ledger,
+            DAILY_WITHDRAWAL_LIMIT_ID,
+            "Daily Withdrawal Limit",
+            "Limit the amount withdrawn from an account per day",
+            "context.vars.transaction.metadata.withdrawal_day",
+            DAILY_WITHDRAWAL_LIMIT_PARAM,
+        )
+        .await
+    }
+}
+
+impl MonthlyWithdrawalLimit {
+    #[instrument(name = "ledger.monthly_withdrawal_limit.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        init_windowed_limit(
+            ledger,
+            MONTHLY_WITHDRAWAL_LIMIT_ID,
+            "Monthly Withdrawal Limit",
+            "Limit the amount withdrawn from an account per calendar month",
+            "context.vars.transaction.metadata.withdrawal_month",
+            MONTHLY_WITHDRAWAL_LIMIT_PARAM,
+        )
+        .await
+    }
+}
+
+async fn init_windowed_limit(
+    ledger: &CalaLedger,
+    id: uuid::Uuid,
+    name: &str,
+    description: &str,
+    window: &str,
+    param: &str,
+) -> Result<VelocityLimitId, DepositLedgerError> {
+    let limit = NewVelocityLimit::builder()
+        .id(id)
+        .name(name)
+        .description(description)
+        .window(vec![NewPartitionKey::builder()
+            .alias("window")
+            .value(window)
+            .build()
+            .expect("partition key")])
+        .condition(WITHDRAWAL_CONDITION)
+        .params(vec![NewParamDefinition::builder()
+            .name(param)
+            .r#type(ParamDataType::Decimal)
+            .build()
+            .expect("param definition")])
+        .limit(
+            NewLimit::builder()
+                .balance(vec![NewBalanceLimit::builder()
+                    .layer("SETTLED")
+                    .amount(format!("params.{param}"))
+                    .enforcement_direction("DEBIT")
+                    .build()
+                    .expect("balance limit")])
+                .build()
+                .expect("limit"),
+        )
+        .build()
+        .expect("velocity limit");
+
+    match ledger.velocities().create_limit(limit).await {
+        Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => Ok(id.into()),
+        Err(e) => Err(e.into()),
+        Ok(limit) => Ok(limit.id()),
+    }
+}
@@ -2,18 +2,31 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
 
 mod account;
+mod bank_statement;
 mod chart_of_accounts_integration;
+mod checks;
+mod config;
 mod deposit;
 mod deposit_account_balance;
 pub mod error;
 mod event;
+mod fee;
 mod for_subject;
 mod history;
+mod hold;
+mod jobs;
 mod ledger;
+mod payment_batch;
 mod primitives;
 mod processes;
+mod product;
 mod publisher;
+mod standing_order;
+mod statement;
+mod transfer;
 mod withdrawal;
+mod withdrawal_limit;
+mod withdrawal_limit_override;
 
 use deposit_account_cursor::DepositAccountsByCreatedAtCursor;
 use tracing::instrument;
@@ -21,30 +34,93 @@ use tracing::instrument;
 use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
+use cloud_storage::Storage;
 use core_accounting::Chart;
 use governance::{Governance, GovernanceEvent};
 use job::Jobs;
 use outbox::{Outbox, OutboxEventMarker};
 
-pub use account::DepositAccount;
 use account::*;
+pub use account::{DepositAccount, DormantAccountReportEntry, TimeDepositTerms, TimeDeposits};
+use bank_statement::*;
+pub use bank_statement::{
+    BankStatementFormat, BankStatementImport, BankStatementImportSummary, BankStatementImports,
+    BankStatementImportsByCreatedAtCursor, BankStatementLine, BankStatementLineStatus,
+    BankStatementLinesByCreatedAtCursor,
+};
 pub use chart_of_accounts_integration::ChartOfAccountsIntegrationConfig;
+use checks::*;
+pub use config::{DepositConfig, StandingOrderRetryConfig, WithdrawalPaymentDebtorConfig};
 use deposit::*;
-pub use deposit::{Deposit, DepositsByCreatedAtCursor};
-pub use deposit_account_balance::DepositAccountBalance;
+pub use deposit::{Deposit, DepositReversalReason, DepositStatus, DepositsByCreatedAtCursor};
+pub use deposit_account_balance::{BtcDepositAccountBalance, DepositAccountBalance};
 use error::*;
 pub use event::*;
+use fee::*;
+pub use fee::{DepositFee, DepositFeeStatus, DepositFees, DepositFeesByCreatedAtCursor};
 pub use for_subject::DepositsForSubject;
-pub use history::{DepositAccountHistoryCursor, DepositAccountHistoryEntry};
+pub use history::{
+    AccountStatusChangeEntry, DepositAccountHistoryCursor, DepositAccountHistoryEntry,
+};
+use hold::*;
+pub use hold::{Hold, HoldStatus, Holds, HoldsByCreatedAtCursor};
+use jobs::{
+    dormant_accounts::{DormantAccountsJobConfig, DormantAccountsJobInitializer},
+    generate_statement::GenerateStatementJobInitializer,
+    hold_expiry::HoldExpiryJobInitializer,
+    interest_accrual::{DepositInterestAccrualJobConfig, DepositInterestAccrualJobInitializer},
+    monthly_fees::{MonthlyFeesJobConfig, MonthlyFeesJobInitializer},
+    monthly_statements::{MonthlyStatementsJobConfig, MonthlyStatementsJobInitializer},
+    standing_order_execution::StandingOrderExecutionJobInitializer,
+    time_deposit_maturity::TimeDepositMaturityJobInitializer,
+    withdrawal_limit_override_expiry::WithdrawalLimitOverrideExpiryJobInitializer,
+    withdrawal_payment_batch::{
+        WithdrawalPaymentBatchJobConfig, WithdrawalPaymentBatchJobInitializer,
+    },
+};
 use ledger::*;
+use payment_batch::*;
+pub use payment_batch::{
+    GeneratedPaymentBatchDownloadLink, PaymentBatch, PaymentBatchStatus,
+    PaymentBatchesByCreatedAtCursor,
+};
 pub use primitives::*;
 pub use processes::approval::APPROVE_WITHDRAWAL_PROCESS;
 use processes::approval::{
     ApproveWithdrawal, WithdrawApprovalJobConfig, WithdrawApprovalJobInitializer,
 };
+pub use processes::approve_transfer::APPROVE_TRANSFER_PROCESS;
+use processes::approve_transfer::{
+    ApproveTransfer, TransferApprovalJobConfig, TransferApprovalJobInitializer,
+};
+use product::*;
+pub use product::{DepositFeeSchedule, DepositProduct, DepositProductsByCreatedAtCursor};
 use publisher::DepositPublisher;
+use standing_order::*;
+pub use standing_order::{
+    StandingOrder, StandingOrderDestination, StandingOrderFrequency, StandingOrderStatus,
+    StandingOrders, StandingOrdersByCreatedAtCursor,
+};
+use statement::*;
+pub use statement::{
+    DepositAccountStatement, DepositAccountStatementDownloadLinks, DepositAccountStatementStatus,
+    DepositAccountStatementsByCreatedAtCursor, Statements,
+};
+use transfer::*;
+pub use transfer::{Transfer, TransferStatus, TransfersByCreatedAtCursor};
 use withdrawal::*;
-pub use withdrawal::{Withdrawal, WithdrawalStatus, WithdrawalsByCreatedAtCursor};
+pub use withdrawal::{
+    Withdrawal, WithdrawalBeneficiary, WithdrawalStatus, WithdrawalsByCreatedAtCursor,
+};
+use withdrawal_limit::*;
+pub use withdrawal_limit::{WithdrawalLimit, WithdrawalLimits, WithdrawalLimitsByCreatedAtCursor};
+use withdrawal_limit_override::*;
+pub use withdrawal_limit_override::{
+    WithdrawalLimitOverride, WithdrawalLimitOverrideStatus,
+    WithdrawalLimitOverridesByCreatedAtCursor,
+};
+
+pub const BASIC_KYC_WITHDRAWAL_LIMIT_NAME: &str = "Basic KYC";
 
 pub struct CoreDeposit<Perms, E>
 where
@@ -52,14 +128,28 @@ where
     E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
 {
     accounts: DepositAccountRepo,
+    products: DepositProductRepo,
     deposits: DepositRepo<E>,
     withdrawals: WithdrawalRepo<E>,
+    transfers: TransferRepo<E>,
+    time_deposits: TimeDeposits<Perms, E>,
+    holds: Holds<Perms>,
+    standing_orders: StandingOrders<Perms, E>,
+    fees: DepositFees<Perms>,
+    withdrawal_limits: WithdrawalLimits<Perms, E>,
+    statements: Statements<Perms>,
+    payment_batches: PaymentBatchRepo,
+    bank_statement_imports: BankStatementImports<Perms, E>,
     approve_withdrawal: ApproveWithdrawal<Perms, E>,
+    approve_transfer: ApproveTransfer<Perms, E>,
     ledger: DepositLedger,
     cala: CalaLedger,
     authz: Perms,
     governance: Governance<Perms, E>,
     outbox: Outbox<E>,
+    jobs: Jobs,
+    storage: Storage,
+    config: DepositConfig,
 }
 
 impl<Perms, E> Clone for CoreDeposit<Perms, E>
@@ -70,14 +160,28 @@ where
     fn clone(&self) -> Self {
         Self {
             accounts: self.accounts.clone(),
+            products: self.products.clone(),
             deposits: self.deposits.clone(),
             withdrawals: self.withdrawals.clone(),
+            transfers: self.transfers.clone(),
+            time_deposits: self.time_deposits.clone(),
+            holds: self.holds.clone(),
+            standing_orders: self.standing_orders.clone(),
+            fees: self.fees.clone(),
+            withdrawal_limits: self.withdrawal_limits.clone(),
+            statements: self.statements.clone(),
+            payment_batches: self.payment_batches.clone(),
+            bank_statement_imports: self.bank_statement_imports.clone(),
             ledger: self.ledger.clone(),
             cala: self.cala.clone(),
             authz: self.authz.clone(),
             governance: self.governance.clone(),
             approve_withdrawal: self.approve_withdrawal.clone(),
+            approve_transfer: self.approve_transfer.clone(),
             outbox: self.outbox.clone(),
+            jobs: self.jobs.clone(),
+            storage: self.storage.clone(),
+            config: self.config.clone(),
         }
     }
 }
@@ -94,20 +198,36 @@ where
     #[allow(clippy::too_many_arguments)]
     pub async fn init(
         pool: &sqlx::PgPool,
+        config: DepositConfig,
         authz: &Perms,
         outbox: &Outbox<E>,
         governance: &Governance<Perms, E>,
         jobs: &Jobs,
+        storage: &Storage,
         cala: &CalaLedger,
         journal_id: CalaJournalId,
     ) -> Result<Self, CoreDepositError> {
         let publisher = DepositPublisher::new(outbox);
         let accounts = DepositAccountRepo::new(pool);
+        let products = DepositProductRepo::new(pool);
         let deposits = DepositRepo::new(pool, &publisher);
         let withdrawals = WithdrawalRepo::new(pool, &publisher);
-        let ledger = DepositLedger::init(cala, journal_id).await?;
+        let transfers = TransferRepo::new(pool, &publisher);
+        let holds = HoldRepo::new(pool);
+        let standing_orders = StandingOrderRepo::new(pool, &publisher);
+        let fees = DepositFeeRepo::new(pool);
+        let withdrawal_limit_repo = WithdrawalLimitRepo::new(pool);
+        let withdrawal_limit_overrides = WithdrawalLimitOverrideRepo::new(pool);
+        let statements = DepositAccountStatementRepo::new(pool);
+        let payment_batches = PaymentBatchRepo::new(pool);
+        let bank_statement_imports = BankStatementImportRepo::new(pool);
+        let bank_statement_lines = BankStatementLineRepo::new(pool);
+        let ledger = DepositLedger::init(pool, cala, journal_id).await?;
 
-        let approve_withdrawal = ApproveWithdrawal::new(&withdrawals, authz.audit(), governance);
+        let approve_withdrawal =
+            ApproveWithdrawal::new(&withdrawals, &fees, &ledger, authz.audit(), governance);
+        let approve_transfer =
+            ApproveTransfer::new(&transfers, &accounts, &ledger, authz.audit(), governance);
 
         jobs.add_initializer_and_spawn_unique(
             WithdrawApprovalJobInitializer::new(outbox, &approve_withdrawal),
@@ -115,6 +235,145 @@ where
         )
         .await?;
 
+        jobs.add_initializer_and_spawn_unique(
+            TransferApprovalJobInitializer::new(outbox, &approve_transfer),
+            TransferApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        jobs.add_initializer_and_spawn_unique(
+            DepositInterestAccrualJobInitializer::<Perms>::new(
+                &accounts,
+                &products,
+                &ledger,
+                authz.audit(),
+            ),
+            DepositInterestAccrualJobConfig::<Perms>::new(),
+        )
+        .await?;
+
+        jobs.add_initializer(HoldExpiryJobInitializer::<Perms>::new(
+            &holds,
+            &ledger,
+            authz.audit(),
+        ));
+
+        jobs.add_initializer(TimeDepositMaturityJobInitializer::<Perms, E>::new(
+            &accounts,
+            &transfers,
+            &ledger,
+            authz.audit(),
+        ));
+
+        jobs.add_initializer(StandingOrderExecutionJobInitializer::<Perms, E>::new(
+            &standing_orders,
+            &accounts,
+            &transfers,
+            &ledger,
+            &config.standing_order_retry,
+            authz.audit(),
+        ));
+
+        jobs.add_initializer(WithdrawalLimitOverrideExpiryJobInitializer::<Perms>::new(
+            &withdrawal_limit_overrides,
+            &withdrawal_limit_repo,
+            &accounts,
+            &ledger,
+            authz.audit(),
+        ));
+
+        jobs.add_initializer(GenerateStatementJobInitializer::<Perms>::new(
+            &statements,
+            &accounts,
+            &ledger,
+            storage,
+            authz.audit(),
+        ));
+
+        jobs.add_initializer_and_spawn_unique(
+            MonthlyStatementsJobInitializer::<Perms>::new(
+                &accounts,
+                &statements,
+                jobs,
+                authz.audit(),
+            ),
+            MonthlyStatementsJobConfig::<Perms>::new(),
+        )
+        .await?;
+
+        jobs.add_initializer_and_spawn_unique(
+            MonthlyFeesJobInitializer::<Perms>::new(
+                &accounts,
+                &products,
+                &fees,
+                &ledger,
+                authz.audit(),
+            ),
+            MonthlyFeesJobConfig::<Perms>::new(),
+        )
+        .await?;
+
+        jobs.add_initializer_and_spawn_unique(
+            WithdrawalPaymentBatchJobInitializer::<Perms, E>::new(
+                &withdrawals,
+                &payment_batches,
+                storage,
+                &config.withdrawal_payment_debtor,
+                authz.audit(),
+            ),
+            WithdrawalPaymentBatchJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        jobs.add_initializer_and_spawn_unique(
+            DormantAccountsJobInitializer::<Perms, E>::new(
+                &accounts,
+                &deposits,
+                &withdrawals,
+                &transfers,
+                &ledger,
+                config.dormancy_period(),
+                authz.audit(),
+            ),
+            DormantAccountsJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        let withdrawal_limits = WithdrawalLimits::new(
+            authz,
+            &withdrawal_limit_repo,
+            &withdrawal_limit_overrides,
+            &accounts,
+            &withdrawals,
+            &ledger,
+            jobs,
+        );
+        match withdrawal_limit_repo
+            .find_by_name(BASIC_KYC_WITHDRAWAL_LIMIT_NAME.to_string())
+            .await
+        {
+            Ok(_) => (),
+            Err(e) if e.was_not_found() => {
+                let audit_info = authz
+                    .audit()
+                    .record_system_entry(
+                        CoreDepositObject::all_withdrawal_limits(),
+                        CoreDepositAction::WITHDRAWAL_LIMIT_CREATE,
+                    )
+                    .await?;
+                withdrawal_limits
+                    .create_withdrawal_limit_with_audit(
+                        BASIC_KYC_WITHDRAWAL_LIMIT_NAME.to_string(),
+                        config.basic_kyc_daily_withdrawal_limit,
+                        config.basic_kyc_monthly_withdrawal_limit,
+                        Some(KycLevel::Basic),
+                        audit_info,
+                    )
+                    .await?;
+            }
+            Err(e) => return Err(e.into()),
+        }
+
         match governance.init_policy(APPROVE_WITHDRAWAL_PROCESS).await {
             Err(governance::error::GovernanceError::PolicyError(
                 governance::policy_error::PolicyError::DuplicateApprovalProcessType,
@@ -123,20 +382,91 @@ where
             _ => (),
         }
 
+        match governance.init_policy(APPROVE_TRANSFER_PROCESS).await {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+
+        let time_deposits =
+            TimeDeposits::new(authz, &accounts, &products, &transfers, &ledger, jobs);
+        let holds = Holds::new(authz, &holds, &accounts, &ledger, jobs);
+        let standing_orders = StandingOrders::new(
+            authz,
+            &standing_orders,
+            &accounts,
+            jobs,
+            &config.standing_order_retry,
+        );
+        let fees = DepositFees::new(authz, &fees, &accounts, &ledger);
+        let statements = Statements::new(authz, &statements, &accounts, jobs, storage);
+        let bank_statement_imports = BankStatementImports::new(
+            authz,
+            &bank_statement_imports,
+            &bank_statement_lines,
+            &accounts,
+            &deposits,
+            &ledger,
+        );
+
         let res = Self {
             accounts,
+            products,
             deposits,
             withdrawals,
+            transfers,
+            time_deposits,
+            holds,
+            standing_orders,
+            fees,
+            withdrawal_limits,
+            statements,
+            payment_batches,
+            bank_statement_imports,
             authz: authz.clone(),
             outbox: outbox.clone(),
             governance: governance.clone(),
             cala: cala.clone(),
             approve_withdrawal,
+            approve_transfer,
             ledger,
+            jobs: jobs.clone(),
+            storage: storage.clone(),
+            config,
         };
         Ok(res)
     }
 
+    pub fn time_deposits(&self) -> &TimeDeposits<Perms, E> {
+        &self.time_deposits
+    }
+
+    pub fn holds(&self) -> &Holds<Perms> {
+        &self.holds
+    }
+
+    pub fn fees(&self) -> &DepositFees<Perms> {
+        &self.fees
+    }
+
+    pub fn standing_orders(&self) -> &StandingOrders<Perms, E> {
+        &self.standing_orders
+    }
+
+    pub fn statements(&self) -> &Statements<Perms> {
+        &self.statements
+    }
+
+    pub fn bank_statement_imports(&self) -> &BankStatementImports<Perms, E> {
+        &self.bank_statement_imports
+    }
+
+    pub fn withdrawal_limits(&self) -> &WithdrawalLimits<Perms, E> {
+        &self.withdrawal_limits
+    }
+
     pub fn for_subject<'s>(
         &'s self,
         sub: &'s <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -153,7 +483,9 @@ where
             &self.accounts,
             &self.deposits,
             &self.withdrawals,
+            self.statements.repo(),
             &self.ledger,
+            &self.storage,
             &self.authz,
         ))
     }
@@ -167,9 +499,54 @@ where
         deposit_account_type: impl Into<DepositAccountType>,
     ) -> Result<DepositAccount, CoreDepositError> {
         let holder_id = holder_id.into();
+        self.create_account_in_currency(
+            sub,
+            holder_id,
+            active,
+            deposit_account_type,
+            DepositAccountCurrency::Usd,
+            format!("Deposit Account {}", holder_id),
+            format!("deposit-customer-account:{}", holder_id),
+        )
+        .await
+    }
+
+    #[instrument(
+        name = "deposit.create_btc_account",
+        skip(self, deposit_account_type),
+        err
+    )]
+    pub async fn create_btc_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+        active: bool,
+        deposit_account_type: impl Into<DepositAccountType>,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let holder_id = holder_id.into();
+        self.create_account_in_currency(
+            sub,
+            holder_id,
+            active,
+            deposit_account_type,
+            DepositAccountCurrency::Btc,
+            format!("BTC Deposit Account {}", holder_id),
+            format!("deposit-customer-btc-account:{}", holder_id),
+        )
+        .await
+    }
 
-        let name = &format!("Deposit Account {}", holder_id);
-        let reference = &format!("deposit-customer-account:{}", holder_id);
+    #[allow(clippy::too_many_arguments)]
+    async fn create_account_in_currency(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        holder_id: DepositAccountHolderId,
+        active: bool,
+        deposit_account_type: impl Into<DepositAccountType>,
+        currency: DepositAccountCurrency,
+        name: String,
+        reference: String,
+    ) -> Result<DepositAccount, CoreDepositError> {
         let audit_info = self
             .authz
             .enforce_permission(
@@ -183,10 +560,11 @@ where
         let new_account = NewDepositAccount::builder()
             .id(account_id)
             .account_holder_id(holder_id)
-            .reference(reference.to_string())
-            .name(name.to_string())
-            .description(name.to_string())
+            .reference(reference)
+            .name(name.clone())
+            .description(name)
             .active(active)
+            .currency(currency)
             .audit_info(audit_info.clone())
             .build()
             .expect("Could not build new account");
@@ -229,6 +607,36 @@ where
         }
     }
 
+    /// Checks that the account is an active USD demand account owned by the holder, so it
+    /// can be debited on their behalf, e.g. to repay their credit facility.
+    #[instrument(name = "deposit.check_auto_debit_account", skip(self), err)]
+    pub async fn check_auto_debit_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(deposit_account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ,
+            )
+            .await?;
+        let account = self.accounts.find_by_id(deposit_account_id).await?;
+        if account.account_holder_id != holder_id.into() {
+            return Err(CoreDepositError::DepositAccountHolderMismatch);
+        }
+        check_account_active(
+            &self.accounts,
+            deposit_account_id,
+            DepositAccountCurrency::Usd,
+        )
+        .await?;
+        Ok(account)
+    }
+
     #[instrument(name = "deposit.update_account_status_for_holder", skip(self), err)]
     pub async fn update_account_status_for_holder(
         &self,
@@ -263,78 +671,1044 @@ where
         Ok(())
     }
 
-    #[instrument(name = "deposit.account_history", skip(self), err)]
-    pub async fn account_history(
+    #[instrument(name = "deposit.freeze_account", skip(self), err)]
+    pub async fn freeze_account(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         account_id: impl Into<DepositAccountId> + std::fmt::Debug,
-        query: es_entity::PaginatedQueryArgs<DepositAccountHistoryCursor>,
-    ) -> Result<
-        es_entity::PaginatedQueryRet<DepositAccountHistoryEntry, DepositAccountHistoryCursor>,
-        CoreDepositError,
-    > {
+        reason: String,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_FREEZE,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        if account.freeze(reason, audit_info)?.was_ignored() {
+            return Ok(account);
+        }
+
+        let mut op = self.accounts.begin_op().await?;
+        self.accounts.update_in_op(&mut op, &mut account).await?;
+        self.ledger.freeze_account(op, account_id).await?;
+        Ok(account)
+    }
+
+    #[instrument(name = "deposit.unfreeze_account", skip(self), err)]
+    pub async fn unfreeze_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_UNFREEZE,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        account.unfreeze(reason, audit_info)?;
+
+        let mut op = self.accounts.begin_op().await?;
+        self.accounts.update_in_op(&mut op, &mut account).await?;
+        self.ledger.unfreeze_account(op, account_id).await?;
+        Ok(account)
+    }
+
+    #[instrument(name = "deposit.close_account", skip(self), err)]
+    pub async fn close_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        reason: String,
+        sweep_to_account_id: Option<DepositAccountId>,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_CLOSE,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        if account.is_time_deposit() {
+            return Err(CoreDepositError::TimeDepositLocked);
+        }
+        if account.status.is_dormant() {
+            return Err(CoreDepositError::DepositAccountDormant);
+        }
+        if account.status.is_closed() {
+            return Ok(account);
+        }
+        let was_frozen = account.status.is_frozen();
+
+        if account.currency == DepositAccountCurrency::Btc {
+            let balance = self.ledger.btc_balance(account_id).await?;
+            if !balance.settled.is_zero() || !balance.pending.is_zero() {
+                return Err(CoreDepositError::DepositAccountBalanceNotZero);
+            }
+        }
+        let balance = self.ledger.balance(account_id).await?;
+        if !balance.encumbrance.is_zero() {
+            return Err(CoreDepositError::DepositAccountHasActiveHolds);
+        }
+        if !balance.pending.is_zero() {
+            return Err(CoreDepositError::DepositAccountBalanceNotZero);
+        }
+
+        let interest = account
+            .capitalize_interest_on_close(chrono::Utc::now().date_naive(), audit_info.clone());
+        let sweep_amount = balance.settled
+            + interest
+                .as_ref()
+                .map(|posting| posting.amount)
+                .unwrap_or(UsdCents::ZERO);
+        let _ = account.close(reason, audit_info.clone());
+
+        let mut op = self.accounts.begin_op().await?;
+        let sweep = match sweep_to_account_id {
+            _ if sweep_amount.is_zero() => None,
+            None => return Err(CoreDepositError::DepositAccountBalanceNotZero),
+            Some(to_account_id) if to_account_id == account_id => {
+                return Err(TransferError::SameAccount.into())
+            }
+            Some(to_account_id) => {
+                check_account_accepts_credits(
+                    &self.accounts,
+                    to_account_id,
+                    DepositAccountCurrency::Usd,
+                )
+                .await?;
+                let new_transfer = NewTransfer::builder()
+                    .id(TransferId::new())
+                    .from_deposit_account_id(account_id)
+                    .to_deposit_account_id(to_account_id)
+                    .amount(sweep_amount)
+                    .reference(Some(format!("closure-sweep-{account_id}")))
+                    .audit_info(audit_info.clone())
+                    .build()
+                    .expect("Could not build new transfer");
+                let mut transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+                let tx_id = transfer.execute(audit_info)?;
+                self.transfers.update_in_op(&mut op, &mut transfer).await?;
+                Some(DepositAccountSweep {
+                    tx_id,
+                    amount: transfer.amount,
+                    to_account_id: to_account_id.into(),
+                })
+            }
+        };
+
+        self.accounts.update_in_op(&mut op, &mut account).await?;
+        self.ledger
+            .close_account(op, account_id, was_frozen, interest, sweep)
+            .await?;
+        Ok(account)
+    }
+
+    #[instrument(name = "deposit.reactivate_dormant_account", skip(self), err)]
+    pub async fn reactivate_dormant_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_REACTIVATE,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        account.reactivate(reason, audit_info)?;
+
+        let mut op = self.accounts.begin_op().await?;
+        self.accounts.update_in_op(&mut op, &mut account).await?;
+        self.ledger.reactivate_account(op, account_id).await?;
+        Ok(account)
+    }
+
+    #[instrument(name = "deposit.transfer_unclaimed_funds", skip(self), err)]
+    pub async fn transfer_unclaimed_funds(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
         let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_TRANSFER_UNCLAIMED_FUNDS,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        let balance = self.ledger.balance(account_id).await?;
+        if !balance.encumbrance.is_zero() {
+            return Err(CoreDepositError::DepositAccountHasActiveHolds);
+        }
+
+        let tx_id = match account.transfer_unclaimed_funds(
+            balance.settled,
+            chrono::Utc::now(),
+            self.config.escheatment_period(),
+            audit_info,
+        )? {
+            es_entity::Idempotent::Executed(tx_id) => tx_id,
+            es_entity::Idempotent::Ignored => return Ok(account),
+        };
+
+        let mut op = self.accounts.begin_op().await?;
+        self.accounts.update_in_op(&mut op, &mut account).await?;
+        self.ledger
+            .transfer_unclaimed_funds(op, tx_id, balance.settled, account_id)
+            .await?;
+        Ok(account)
+    }
+
+    #[instrument(name = "deposit.dormant_accounts_report", skip(self), err)]
+    pub async fn dormant_accounts_report(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<Vec<DormantAccountReportEntry>, CoreDepositError> {
         self.authz
             .enforce_permission(
                 sub,
-                CoreDepositObject::deposit_account(account_id),
-                CoreDepositAction::DEPOSIT_ACCOUNT_READ,
+                CoreDepositObject::all_deposit_accounts(),
+                CoreDepositAction::DEPOSIT_ACCOUNT_LIST,
+            )
+            .await?;
+
+        let mut report = Vec::new();
+        let mut has_next_page = true;
+        let mut after: Option<DepositAccountsByCreatedAtCursor> = None;
+        while has_next_page {
+            let accounts = self
+                .accounts
+                .list_by_created_at(
+                    es_entity::PaginatedQueryArgs::<DepositAccountsByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (accounts.end_cursor, accounts.has_next_page);
+
+            for account in accounts.entities.into_iter() {
+                let (Some(dormant_since), Some(last_activity_at)) =
+                    (account.dormant_since(), account.last_activity_at())
+                else {
+                    continue;
+                };
+                let balance = self.ledger.balance(account.id).await?;
+                report.push(DormantAccountReportEntry {
+                    account,
+                    dormant_since,
+                    last_activity_at,
+                    balance: balance.settled,
+                });
+            }
+        }
+        report.sort_by_key(|entry| entry.dormant_since);
+        Ok(report)
+    }
+
+    #[instrument(name = "deposit.create_payment_batch", skip(self), err)]
+    pub async fn create_payment_batch(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<Option<PaymentBatch>, CoreDepositError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_batches(),
+                CoreDepositAction::PAYMENT_BATCH_CREATE,
+            )
+            .await?;
+
+        create_payment_batch(
+            &self.withdrawals,
+            &self.payment_batches,
+            &self.storage,
+            &self.config.withdrawal_payment_debtor,
+            audit_info,
+        )
+        .await
+    }
+
+    #[instrument(name = "deposit.find_payment_batch_by_id", skip(self), err)]
+    pub async fn find_payment_batch_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<PaymentBatchId> + std::fmt::Debug,
+    ) -> Result<Option<PaymentBatch>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::payment_batch(id),
+                CoreDepositAction::PAYMENT_BATCH_READ,
+            )
+            .await?;
+
+        match self.payment_batches.find_by_id(id).await {
+            Ok(batch) => Ok(Some(batch)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_payment_batches", skip(self), err)]
+    pub async fn list_payment_batches(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<PaymentBatchesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<PaymentBatch, PaymentBatchesByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_batches(),
+                CoreDepositAction::PAYMENT_BATCH_LIST,
+            )
+            .await?;
+        Ok(self
+            .payment_batches
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.mark_payment_batch_sent", skip(self), err)]
+    pub async fn mark_payment_batch_sent(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<PaymentBatchId> + std::fmt::Debug,
+    ) -> Result<PaymentBatch, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::payment_batch(id),
+                CoreDepositAction::PAYMENT_BATCH_MARK_SENT,
+            )
+            .await?;
+
+        let mut batch = self.payment_batches.find_by_id(id).await?;
+        if batch.mark_sent(audit_info.clone())?.was_ignored() {
+            return Ok(batch);
+        }
+
+        let mut op = self.payment_batches.begin_op().await?;
+        self.payment_batches
+            .update_in_op(&mut op, &mut batch)
+            .await?;
+        let mut withdrawals: std::collections::HashMap<WithdrawalId, Withdrawal> =
+            self.withdrawals.find_all(&batch.withdrawal_ids).await?;
+        for withdrawal in withdrawals.values_mut() {
+            if withdrawal.payment_sent(audit_info.clone())?.did_execute() {
+                self.withdrawals.update_in_op(&mut op, withdrawal).await?;
+            }
+        }
+        op.commit().await?;
+
+        Ok(batch)
+    }
+
+    #[instrument(name = "deposit.mark_payment_batch_settled", skip(self), err)]
+    pub async fn mark_payment_batch_settled(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<PaymentBatchId> + std::fmt::Debug,
+    ) -> Result<PaymentBatch, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::payment_batch(id),
+                CoreDepositAction::PAYMENT_BATCH_MARK_SETTLED,
+            )
+            .await?;
+
+        let mut batch = self.payment_batches.find_by_id(id).await?;
+        if batch.mark_settled(audit_info.clone())?.was_ignored() {
+            return Ok(batch);
+        }
+
+        let mut op = self.payment_batches.begin_op().await?;
+        self.payment_batches
+            .update_in_op(&mut op, &mut batch)
+            .await?;
+        let mut withdrawals: std::collections::HashMap<WithdrawalId, Withdrawal> =
+            self.withdrawals.find_all(&batch.withdrawal_ids).await?;
+        for withdrawal in withdrawals.values_mut() {
+            if withdrawal
+                .payment_settled(audit_info.clone())?
+                .did_execute()
+            {
+                self.withdrawals.update_in_op(&mut op, withdrawal).await?;
+            }
+        }
+        op.commit().await?;
+
+        Ok(batch)
+    }
+
+    #[instrument(name = "deposit.generate_payment_batch_download_link", skip(self), err)]
+    pub async fn generate_payment_batch_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<PaymentBatchId> + std::fmt::Debug,
+    ) -> Result<GeneratedPaymentBatchDownloadLink, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::payment_batch(id),
+                CoreDepositAction::PAYMENT_BATCH_GENERATE_DOWNLOAD_LINK,
+            )
+            .await?;
+
+        let mut batch = self.payment_batches.find_by_id(id).await?;
+        let location = batch.download_link_generated(audit_info);
+        let link = self.storage.generate_download_link(location).await?;
+        self.payment_batches.update(&mut batch).await?;
+
+        Ok(GeneratedPaymentBatchDownloadLink {
+            payment_batch_id: id,
+            link,
+        })
+    }
+
+    #[instrument(name = "deposit.create_deposit_product", skip(self), err)]
+    pub async fn create_deposit_product(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        name: impl Into<String> + std::fmt::Debug,
+        annual_rate: InterestRatePct,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_products(),
+                CoreDepositAction::DEPOSIT_PRODUCT_CREATE,
+            )
+            .await?;
+
+        let new_product = NewDepositProduct::builder()
+            .id(DepositProductId::new())
+            .name(name)
+            .annual_rate(annual_rate)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new deposit product");
+
+        Ok(self.products.create(new_product).await?)
+    }
+
+    #[instrument(name = "deposit.update_deposit_product_rate", skip(self), err)]
+    pub async fn update_deposit_product_rate(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+        annual_rate: InterestRatePct,
+        effective_from: chrono::NaiveDate,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_UPDATE_RATE,
+            )
+            .await?;
+
+        let mut product = self.products.find_by_id(id).await?;
+        if product
+            .update_annual_rate(annual_rate, effective_from, audit_info)
+            .did_execute()
+        {
+            self.products.update(&mut product).await?;
+        }
+        Ok(product)
+    }
+
+    #[instrument(name = "deposit.update_deposit_product_fee_schedule", skip(self), err)]
+    pub async fn update_deposit_product_fee_schedule(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+        fee_schedule: DepositFeeSchedule,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_UPDATE_FEE_SCHEDULE,
+            )
+            .await?;
+
+        let mut product = self.products.find_by_id(id).await?;
+        if product
+            .update_fee_schedule(fee_schedule, audit_info)
+            .did_execute()
+        {
+            self.products.update(&mut product).await?;
+        }
+        Ok(product)
+    }
+
+    #[instrument(
+        name = "deposit.update_deposit_product_early_break_penalty",
+        skip(self),
+        err
+    )]
+    pub async fn update_deposit_product_early_break_penalty(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+        early_break_penalty: EarlyBreakPenaltyPct,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_UPDATE_EARLY_BREAK_PENALTY,
+            )
+            .await?;
+
+        let mut product = self.products.find_by_id(id).await?;
+        if product
+            .update_early_break_penalty(early_break_penalty, audit_info)?
+            .did_execute()
+        {
+            self.products.update(&mut product).await?;
+        }
+        Ok(product)
+    }
+
+    #[instrument(name = "deposit.find_deposit_product_by_id", skip(self), err)]
+    pub async fn find_deposit_product_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+    ) -> Result<Option<DepositProduct>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_READ,
+            )
+            .await?;
+
+        match self.products.find_by_id(id).await {
+            Ok(product) => Ok(Some(product)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_deposit_products", skip(self), err)]
+    pub async fn list_deposit_products(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<DepositProductsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<DepositProduct, DepositProductsByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_products(),
+                CoreDepositAction::DEPOSIT_PRODUCT_LIST,
+            )
+            .await?;
+        Ok(self
+            .products
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.assign_deposit_product", skip(self), err)]
+    pub async fn assign_deposit_product(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        deposit_product_id: impl Into<DepositProductId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let deposit_product_id = deposit_product_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_ASSIGN_PRODUCT,
+            )
+            .await?;
+
+        self.products.find_by_id(deposit_product_id).await?;
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        if account.is_time_deposit() {
+            return Err(CoreDepositError::TimeDepositLocked);
+        }
+        if account.currency != DepositAccountCurrency::Usd {
+            return Err(CoreDepositError::DepositAccountCurrencyMismatch(
+                account.currency,
+                DepositAccountCurrency::Usd,
+            ));
+        }
+        if account
+            .assign_deposit_product(
+                deposit_product_id,
+                chrono::Utc::now().date_naive(),
+                audit_info,
+            )
+            .did_execute()
+        {
+            self.accounts.update(&mut account).await?;
+        }
+        Ok(account)
+    }
+
+    #[instrument(name = "deposit.account_history", skip(self), err)]
+    pub async fn account_history(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        query: es_entity::PaginatedQueryArgs<DepositAccountHistoryCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<DepositAccountHistoryEntry, DepositAccountHistoryCursor>,
+        CoreDepositError,
+    > {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ,
+            )
+            .await?;
+
+        let account = self.accounts.find_by_id(account_id).await?;
+        let after = query.after.clone();
+        let history = self
+            .ledger
+            .account_history::<DepositAccountHistoryEntry, DepositAccountHistoryCursor>(
+                account_id, query,
+            )
+            .await?;
+        Ok(history::with_status_changes(
+            history,
+            after.as_ref(),
+            account.status_changes(),
+        ))
+    }
+
+    #[instrument(name = "deposit.record_deposit", skip(self), err)]
+    pub async fn record_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<DepositAmount> + std::fmt::Debug,
+        reference: Option<String>,
+    ) -> Result<Deposit, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let amount = amount.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposits(),
+                CoreDepositAction::DEPOSIT_CREATE,
+            )
+            .await?;
+        check_account_accepts_credits(&self.accounts, deposit_account_id, amount.currency())
+            .await?;
+        let deposit_id = DepositId::new();
+        let new_deposit = NewDeposit::builder()
+            .id(deposit_id)
+            .ledger_transaction_id(deposit_id)
+            .deposit_account_id(deposit_account_id)
+            .amount(amount)
+            .reference(reference)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new account");
+
+        let mut op = self.deposits.begin_op().await?;
+        let deposit = self.deposits.create_in_op(&mut op, new_deposit).await?;
+        self.ledger
+            .record_deposit(op, deposit_id, amount, deposit_account_id)
+            .await?;
+        Ok(deposit)
+    }
+
+    #[instrument(name = "deposit.revert_deposit", skip(self), err)]
+    pub async fn revert_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_id: impl Into<DepositId> + std::fmt::Debug,
+        reason: DepositReversalReason,
+    ) -> Result<Deposit, CoreDepositError> {
+        let id = deposit_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit(id),
+                CoreDepositAction::DEPOSIT_REVERT,
+            )
+            .await?;
+        let mut deposit = self.deposits.find_by_id(id).await?;
+        check_account_active(
+            &self.accounts,
+            deposit.deposit_account_id,
+            deposit.amount.currency(),
+        )
+        .await?;
+        let tx_id = deposit.revert(reason, audit_info)?;
+        check_available_balance(&self.ledger, deposit.deposit_account_id, deposit.amount)
+            .await
+            .map_err(|e| match e {
+                CoreDepositError::InsufficientAvailableBalance => {
+                    CoreDepositError::InsufficientBalanceForReversal
+                }
+                e => e,
+            })?;
+
+        let mut op = self.deposits.begin_op().await?;
+        self.deposits.update_in_op(&mut op, &mut deposit).await?;
+        self.ledger
+            .revert_deposit(op, tx_id, deposit.amount, deposit.deposit_account_id)
+            .await?;
+        Ok(deposit)
+    }
+
+    #[instrument(name = "deposit.initiate_withdrawal", skip(self), err)]
+    pub async fn initiate_withdrawal(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<DepositAmount> + std::fmt::Debug,
+        reference: Option<String>,
+        beneficiary: Option<WithdrawalBeneficiary>,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let amount = amount.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_withdrawals(),
+                CoreDepositAction::WITHDRAWAL_INITIATE,
+            )
+            .await?;
+        self.create_withdrawal(
+            audit_info,
+            deposit_account_id,
+            amount,
+            reference,
+            beneficiary,
+            None,
+        )
+        .await
+    }
+
+    #[instrument(
+        name = "deposit.initiate_withdrawal_as_signatory",
+        skip(self, signatory),
+        err
+    )]
+    pub async fn initiate_withdrawal_as_signatory(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        signatory: &core_customer::Signatory,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<DepositAmount> + std::fmt::Debug,
+        reference: Option<String>,
+        beneficiary: Option<WithdrawalBeneficiary>,
+    ) -> Result<Withdrawal, CoreDepositError>
+    where
+        DepositAccountHolderId:
+            for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
+    {
+        let deposit_account_id = deposit_account_id.into();
+        let audit_info = self
+            .ensure_signatory_authorized(
+                sub,
+                signatory,
+                deposit_account_id,
+                signatory.role.can_initiate_withdrawals(),
+                CoreDepositObject::all_withdrawals(),
+                CoreDepositAction::WITHDRAWAL_INITIATE,
+            )
+            .await?;
+        self.create_withdrawal(
+            audit_info,
+            deposit_account_id,
+            amount.into(),
+            reference,
+            beneficiary,
+            Some(signatory.id),
+        )
+        .await
+    }
+
+    #[instrument(
+        name = "deposit.approve_withdrawal_as_signatory",
+        skip(self, signatory),
+        err
+    )]
+    pub async fn approve_withdrawal_as_signatory(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        signatory: &core_customer::Signatory,
+        withdrawal_id: impl Into<WithdrawalId> + std::fmt::Debug,
+    ) -> Result<Withdrawal, CoreDepositError>
+    where
+        DepositAccountHolderId:
+            for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
+    {
+        let id = withdrawal_id.into();
+        let mut withdrawal = self.withdrawals.find_by_id(id).await?;
+        let audit_info = self
+            .ensure_signatory_authorized(
+                sub,
+                signatory,
+                withdrawal.deposit_account_id,
+                signatory.role.can_approve_withdrawals(),
+                CoreDepositObject::withdrawal(id),
+                CoreDepositAction::WITHDRAWAL_SIGNATORY_APPROVE,
+            )
+            .await?;
+        withdrawal.approve_as_signatory(signatory.id, audit_info)?;
+
+        let mut op = self.withdrawals.begin_op().await?;
+        self.governance
+            .start_process(
+                &mut op,
+                withdrawal.approval_process_id,
+                withdrawal.id.to_string(),
+                APPROVE_WITHDRAWAL_PROCESS,
+            )
+            .await?;
+        self.withdrawals
+            .update_in_op(&mut op, &mut withdrawal)
+            .await?;
+        op.commit().await?;
+
+        Ok(withdrawal)
+    }
+
+    #[instrument(
+        name = "deposit.reject_withdrawal_as_signatory",
+        skip(self, signatory),
+        err
+    )]
+    pub async fn reject_withdrawal_as_signatory(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        signatory: &core_customer::Signatory,
+        withdrawal_id: impl Into<WithdrawalId> + std::fmt::Debug,
+    ) -> Result<Withdrawal, CoreDepositError>
+    where
+        DepositAccountHolderId:
+            for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
+    {
+        let id = withdrawal_id.into();
+        let mut withdrawal = self.withdrawals.find_by_id(id).await?;
+        let audit_info = self
+            .ensure_signatory_authorized(
+                sub,
+                signatory,
+                withdrawal.deposit_account_id,
+                signatory.role.can_approve_withdrawals(),
+                CoreDepositObject::withdrawal(id),
+                CoreDepositAction::WITHDRAWAL_SIGNATORY_REJECT,
             )
             .await?;
+        let tx_id = withdrawal.reject_as_signatory(signatory.id, audit_info.clone())?;
 
-        let history = self
-            .ledger
-            .account_history::<DepositAccountHistoryEntry, DepositAccountHistoryCursor>(
-                account_id, query,
+        let mut op = self.withdrawals.begin_op().await?;
+        self.withdrawals
+            .update_in_op(&mut op, &mut withdrawal)
+            .await?;
+        let waived_fee = self
+            .fees
+            .waive_withdrawal_fee_in_op(
+                &mut op,
+                withdrawal.id,
+                "Withdrawal rejected by signatory",
+                audit_info,
+            )
+            .await?;
+        self.ledger
+            .cancel_withdrawal(
+                op,
+                tx_id,
+                withdrawal.amount,
+                withdrawal.deposit_account_id,
+                withdrawal.created_at(),
+                waived_fee,
             )
             .await?;
-        Ok(history)
+        Ok(withdrawal)
     }
 
-    #[instrument(name = "deposit.record_deposit", skip(self), err)]
-    pub async fn record_deposit(
+    /// Checks that the signatory belongs to the authenticated customer, holds the account
+    /// and has a role that permits the action. The outcome is recorded in the audit log.
+    async fn ensure_signatory_authorized(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
-        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
-        amount: UsdCents,
-        reference: Option<String>,
-    ) -> Result<Deposit, CoreDepositError> {
-        let deposit_account_id = deposit_account_id.into();
-        let audit_info = self
+        signatory: &core_customer::Signatory,
+        deposit_account_id: DepositAccountId,
+        role_permits: bool,
+        object: CoreDepositObject,
+        action: CoreDepositAction,
+    ) -> Result<audit::AuditInfo, CoreDepositError>
+    where
+        DepositAccountHolderId:
+            for<'a> TryFrom<&'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject>,
+    {
+        let holder_id = DepositAccountHolderId::try_from(sub)
+            .map_err(|_| CoreDepositError::SubjectIsNotDepositAccountHolder)?;
+        let account = self.accounts.find_by_id(deposit_account_id).await?;
+
+        if account.account_holder_id != holder_id
+            || DepositAccountHolderId::from(signatory.customer_id) != holder_id
+            || !signatory.is_active()
+            || !role_permits
+        {
+            self.authz
+                .audit()
+                .record_entry(sub, object, action, false)
+                .await?;
+            return Err(CoreDepositError::SignatoryNotAuthorized);
+        }
+
+        Ok(self
             .authz
-            .enforce_permission(
-                sub,
-                CoreDepositObject::all_deposits(),
-                CoreDepositAction::DEPOSIT_CREATE,
-            )
-            .await?;
-        self.check_account_active(deposit_account_id).await?;
-        let deposit_id = DepositId::new();
-        let new_deposit = NewDeposit::builder()
-            .id(deposit_id)
-            .ledger_transaction_id(deposit_id)
+            .audit()
+            .record_entry(sub, object, action, true)
+            .await?)
+    }
+
+    async fn create_withdrawal(
+        &self,
+        audit_info: audit::AuditInfo,
+        deposit_account_id: DepositAccountId,
+        amount: DepositAmount,
+        reference: Option<String>,
+        beneficiary: Option<WithdrawalBeneficiary>,
+        initiated_by: Option<SignatoryId>,
+    ) -> Result<Withdrawal, CoreDepositError> {
+        check_account_active(&self.accounts, deposit_account_id, amount.currency()).await?;
+        let withdrawal_fee = self.withdrawal_fee(deposit_account_id, amount).await?;
+        check_available_balance(
+            &self.ledger,
+            deposit_account_id,
+            match amount {
+                DepositAmount::Usd(amount) => DepositAmount::Usd(amount + withdrawal_fee),
+                DepositAmount::Btc(_) => amount,
+            },
+        )
+        .await?;
+        if let DepositAmount::Usd(amount) = amount {
+            self.withdrawal_limits
+                .check_withdrawal_limits(deposit_account_id, amount)
+                .await?;
+        }
+        let withdrawal_id = WithdrawalId::new();
+        let new_withdrawal = NewWithdrawal::builder()
+            .id(withdrawal_id)
             .deposit_account_id(deposit_account_id)
             .amount(amount)
+            .approval_process_id(withdrawal_id)
             .reference(reference)
-            .audit_info(audit_info)
+            .beneficiary(beneficiary)
+            .initiated_by(initiated_by)
+            .audit_info(audit_info.clone())
             .build()
-            .expect("Could not build new account");
+            .expect("Could not build new withdrawal");
+
+        let mut op = self.withdrawals.begin_op().await?;
+        if initiated_by.is_none() {
+            self.governance
+                .start_process(
+                    &mut op,
+                    withdrawal_id,
+                    withdrawal_id.to_string(),
+                    APPROVE_WITHDRAWAL_PROCESS,
+                )
+                .await?;
+        }
+        let withdrawal = self
+            .withdrawals
+            .create_in_op(&mut op, new_withdrawal)
+            .await?;
+
+        let fee = self
+            .fees
+            .charge_withdrawal_fee_in_op(
+                &mut op,
+                withdrawal_id,
+                deposit_account_id,
+                withdrawal_fee,
+                audit_info,
+            )
+            .await?;
 
-        let mut op = self.deposits.begin_op().await?;
-        let deposit = self.deposits.create_in_op(&mut op, new_deposit).await?;
         self.ledger
-            .record_deposit(op, deposit_id, amount, deposit_account_id)
+            .initiate_withdrawal(op, withdrawal_id, amount, deposit_account_id, fee)
             .await?;
-        Ok(deposit)
+        Ok(withdrawal)
     }
 
-    #[instrument(name = "deposit.initiate_withdrawal", skip(self), err)]
-    pub async fn initiate_withdrawal(
+    /// Moves BTC out of a holder's deposit account to fund credit facility collateral.
+    /// The withdrawal needs no approval process; it is confirmed once the collateral has
+    /// been recorded, or cancelled if that fails.
+    #[instrument(name = "deposit.initiate_collateral_withdrawal", skip(self), err)]
+    pub async fn initiate_collateral_withdrawal(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
-        amount: UsdCents,
-        reference: Option<String>,
+        holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+        amount: Satoshis,
+        reference: String,
     ) -> Result<Withdrawal, CoreDepositError> {
         let deposit_account_id = deposit_account_id.into();
         let audit_info = self
@@ -345,34 +1719,45 @@ where
                 CoreDepositAction::WITHDRAWAL_INITIATE,
             )
             .await?;
-        self.check_account_active(deposit_account_id).await?;
+        let account = self.accounts.find_by_id(deposit_account_id).await?;
+        if account.account_holder_id != holder_id.into() {
+            return Err(CoreDepositError::DepositAccountHolderMismatch);
+        }
+        check_account_active(
+            &self.accounts,
+            deposit_account_id,
+            DepositAccountCurrency::Btc,
+        )
+        .await?;
+        check_available_balance(&self.ledger, deposit_account_id, amount.into()).await?;
+
         let withdrawal_id = WithdrawalId::new();
         let new_withdrawal = NewWithdrawal::builder()
             .id(withdrawal_id)
             .deposit_account_id(deposit_account_id)
             .amount(amount)
             .approval_process_id(withdrawal_id)
-            .reference(reference)
-            .audit_info(audit_info)
+            .reference(Some(reference))
+            .audit_info(audit_info.clone())
             .build()
             .expect("Could not build new withdrawal");
 
         let mut op = self.withdrawals.begin_op().await?;
-        self.governance
-            .start_process(
-                &mut op,
-                withdrawal_id,
-                withdrawal_id.to_string(),
-                APPROVE_WITHDRAWAL_PROCESS,
-            )
-            .await?;
-        let withdrawal = self
+        let mut withdrawal = self
             .withdrawals
             .create_in_op(&mut op, new_withdrawal)
             .await?;
+        if withdrawal
+            .approval_process_concluded(true, audit_info)
+            .did_execute()
+        {
+            self.withdrawals
+                .update_in_op(&mut op, &mut withdrawal)
+                .await?;
+        }
 
         self.ledger
-            .initiate_withdrawal(op, withdrawal_id, amount, deposit_account_id)
+            .initiate_withdrawal(op, withdrawal_id, amount.into(), deposit_account_id, None)
             .await?;
         Ok(withdrawal)
     }
@@ -393,8 +1778,12 @@ where
             )
             .await?;
         let mut withdrawal = self.withdrawals.find_by_id(id).await?;
-        self.check_account_active(withdrawal.deposit_account_id)
-            .await?;
+        check_account_active(
+            &self.accounts,
+            withdrawal.deposit_account_id,
+            withdrawal.amount.currency(),
+        )
+        .await?;
         let mut op = self.withdrawals.begin_op().await?;
         let tx_id = withdrawal.confirm(audit_info)?;
         self.withdrawals
@@ -431,19 +1820,115 @@ where
             )
             .await?;
         let mut withdrawal = self.withdrawals.find_by_id(id).await?;
-        self.check_account_active(withdrawal.deposit_account_id)
-            .await?;
+        check_account_accepts_credits(
+            &self.accounts,
+            withdrawal.deposit_account_id,
+            withdrawal.amount.currency(),
+        )
+        .await?;
         let mut op = self.withdrawals.begin_op().await?;
-        let tx_id = withdrawal.cancel(audit_info)?;
+        let tx_id = withdrawal.cancel(audit_info.clone())?;
         self.withdrawals
             .update_in_op(&mut op, &mut withdrawal)
             .await?;
+        let waived_fee = self
+            .fees
+            .waive_withdrawal_fee_in_op(&mut op, withdrawal.id, "Withdrawal cancelled", audit_info)
+            .await?;
         self.ledger
-            .cancel_withdrawal(op, tx_id, withdrawal.amount, withdrawal.deposit_account_id)
+            .cancel_withdrawal(
+                op,
+                tx_id,
+                withdrawal.amount,
+                withdrawal.deposit_account_id,
+                withdrawal.created_at(),
+                waived_fee,
+            )
             .await?;
         Ok(withdrawal)
     }
 
+    #[instrument(name = "deposit.initiate_transfer", skip(self), err)]
+    pub async fn initiate_transfer(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        from_deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        to_deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        reference: Option<String>,
+    ) -> Result<Transfer, CoreDepositError> {
+        let from_deposit_account_id = from_deposit_account_id.into();
+        let to_deposit_account_id = to_deposit_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_INITIATE,
+            )
+            .await?;
+        if from_deposit_account_id == to_deposit_account_id {
+            return Err(TransferError::SameAccount.into());
+        }
+        check_account_active(
+            &self.accounts,
+            from_deposit_account_id,
+            DepositAccountCurrency::Usd,
+        )
+        .await?;
+        check_account_accepts_credits(
+            &self.accounts,
+            to_deposit_account_id,
+            DepositAccountCurrency::Usd,
+        )
+        .await?;
+        check_available_balance(&self.ledger, from_deposit_account_id, amount.into()).await?;
+
+        let transfer_id = TransferId::new();
+        let requires_approval = self.config.transfer_requires_approval(amount);
+        let mut builder = NewTransfer::builder();
+        builder
+            .id(transfer_id)
+            .from_deposit_account_id(from_deposit_account_id)
+            .to_deposit_account_id(to_deposit_account_id)
+            .amount(amount)
+            .reference(reference)
+            .audit_info(audit_info.clone());
+        if requires_approval {
+            builder.approval_process_id(transfer_id);
+        }
+        let new_transfer = builder.build().expect("Could not build new transfer");
+
+        let mut op = self.transfers.begin_op().await?;
+        if requires_approval {
+            self.governance
+                .start_process(
+                    &mut op,
+                    transfer_id,
+                    transfer_id.to_string(),
+                    APPROVE_TRANSFER_PROCESS,
+                )
+                .await?;
+            let transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+            op.commit().await?;
+            return Ok(transfer);
+        }
+
+        let mut transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+        let tx_id = transfer.execute(audit_info)?;
+        self.transfers.update_in_op(&mut op, &mut transfer).await?;
+        self.ledger
+            .transfer(
+                op,
+                tx_id,
+                amount,
+                from_deposit_account_id,
+                to_deposit_account_id,
+            )
+            .await?;
+        Ok(transfer)
+    }
+
     #[instrument(name = "deposit.account_balance", skip(self), err)]
     pub async fn account_balance(
         &self,
@@ -464,6 +1949,26 @@ where
         Ok(balance)
     }
 
+    #[instrument(name = "deposit.account_btc_balance", skip(self), err)]
+    pub async fn account_btc_balance(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<BtcDepositAccountBalance, CoreDepositError> {
+        let account_id = account_id.into();
+        let _ = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ_BALANCE,
+            )
+            .await?;
+
+        let balance = self.ledger.btc_balance(account_id).await?;
+        Ok(balance)
+    }
+
     #[instrument(name = "deposit.find_deposit_by_id", skip(self), err)]
     pub async fn find_deposit_by_id(
         &self,
@@ -486,6 +1991,27 @@ where
         }
     }
 
+    pub async fn find_deposit_by_reverted_tx_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reverted_tx_id: impl Into<CalaTransactionId> + std::fmt::Debug,
+    ) -> Result<Deposit, CoreDepositError> {
+        let reverted_tx_id = reverted_tx_id.into();
+        let deposit = self
+            .deposits
+            .find_by_reverted_tx_id(Some(reverted_tx_id))
+            .await?;
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit(deposit.id),
+                CoreDepositAction::DEPOSIT_READ,
+            )
+            .await?;
+
+        Ok(deposit)
+    }
+
     #[instrument(name = "deposit.find_withdrawal_by_id", skip(self), err)]
     pub async fn find_withdrawal_by_id(
         &self,
@@ -508,6 +2034,28 @@ where
         }
     }
 
+    #[instrument(name = "deposit.find_transfer_by_id", skip(self), err)]
+    pub async fn find_transfer_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<TransferId> + std::fmt::Debug,
+    ) -> Result<Option<Transfer>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::TRANSFER_READ,
+            )
+            .await?;
+
+        match self.transfers.find_by_id(id).await {
+            Ok(transfer) => Ok(Some(transfer)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     pub async fn find_withdrawal_by_cancelled_tx_id(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -537,6 +2085,22 @@ where
         Ok(self.withdrawals.find_all(ids).await?)
     }
 
+    #[instrument(name = "deposit.find_all_payment_batches", skip(self), err)]
+    pub async fn find_all_payment_batches<T: From<PaymentBatch>>(
+        &self,
+        ids: &[PaymentBatchId],
+    ) -> Result<std::collections::HashMap<PaymentBatchId, T>, CoreDepositError> {
+        Ok(self.payment_batches.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.find_all_transfers", skip(self), err)]
+    pub async fn find_all_transfers<T: From<Transfer>>(
+        &self,
+        ids: &[TransferId],
+    ) -> Result<std::collections::HashMap<TransferId, T>, CoreDepositError> {
+        Ok(self.transfers.find_all(ids).await?)
+    }
+
     #[instrument(name = "deposit.find_all_deposits", skip(self), err)]
     pub async fn find_all_deposits<T: From<Deposit>>(
         &self,
@@ -545,6 +2109,14 @@ where
         Ok(self.deposits.find_all(ids).await?)
     }
 
+    #[instrument(name = "deposit.find_all_deposit_products", skip(self), err)]
+    pub async fn find_all_deposit_products<T: From<DepositProduct>>(
+        &self,
+        ids: &[DepositProductId],
+    ) -> Result<std::collections::HashMap<DepositProductId, T>, CoreDepositError> {
+        Ok(self.products.find_all(ids).await?)
+    }
+
     #[instrument(name = "deposit.find_all_deposit_accounts", skip(self), err)]
     pub async fn find_all_deposit_accounts<T: From<DepositAccount>>(
         &self,
@@ -575,6 +2147,26 @@ where
             .await?)
     }
 
+    #[instrument(name = "deposit.list_transfers", skip(self), err)]
+    pub async fn list_transfers(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<TransfersByCreatedAtCursor>,
+    ) -> Result<es_entity::PaginatedQueryRet<Transfer, TransfersByCreatedAtCursor>, CoreDepositError>
+    {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_LIST,
+            )
+            .await?;
+        Ok(self
+            .transfers
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
     #[instrument(name = "deposit.list_deposits", skip(self), err)]
     pub async fn list_deposits(
         &self,
@@ -603,6 +2195,18 @@ where
         Ok(self.approve_withdrawal.execute_from_svc(withdraw).await?)
     }
 
+    #[instrument(
+        name = "deposit.ensure_up_to_date_transfer_status",
+        skip(self, transfer),
+        err
+    )]
+    pub async fn ensure_up_to_date_transfer_status(
+        &self,
+        transfer: &Transfer,
+    ) -> Result<Option<Transfer>, CoreDepositError> {
+        Ok(self.approve_transfer.execute_from_svc(transfer).await?)
+    }
+
     #[instrument(name = "deposit.list_deposits_for_account", skip(self), err)]
     pub async fn list_deposits_for_account(
         &self,
@@ -653,6 +2257,43 @@ where
             .entities)
     }
 
+    #[instrument(name = "deposit.list_transfers_for_account", skip(self), err)]
+    pub async fn list_transfers_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<Transfer>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_LIST,
+            )
+            .await?;
+        let outgoing = self
+            .transfers
+            .list_for_from_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities;
+        let incoming = self
+            .transfers
+            .list_for_to_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities;
+        let mut transfers: Vec<_> = outgoing.into_iter().chain(incoming).collect();
+        transfers.sort_by_key(|t| std::cmp::Reverse(t.created_at()));
+        Ok(transfers)
+    }
+
     #[instrument(
         name = "deposit.list_accounts_by_created_at_for_account_holder",
         skip(self),
@@ -740,9 +2381,20 @@ where
             .account_set_id_from_code(
                 &config.chart_of_account_non_domiciled_individual_deposit_accounts_parent_code,
             )?;
+        let time_deposit_accounts_parent_account_set_id = chart.account_set_id_from_code(
+            &config.chart_of_accounts_time_deposit_accounts_parent_code,
+        )?;
 
         let omnibus_parent_account_set_id =
             chart.account_set_id_from_code(&config.chart_of_accounts_omnibus_parent_code)?;
+        let interest_expense_parent_account_set_id = chart
+            .account_set_id_from_code(&config.chart_of_accounts_interest_expense_parent_code)?;
+        let accrued_interest_parent_account_set_id = chart
+            .account_set_id_from_code(&config.chart_of_accounts_accrued_interest_parent_code)?;
+        let unclaimed_funds_parent_account_set_id = chart
+            .account_set_id_from_code(&config.chart_of_accounts_unclaimed_funds_parent_code)?;
+        let fee_income_parent_account_set_id =
+            chart.account_set_id_from_code(&config.chart_of_accounts_fee_income_parent_code)?;
 
         let audit_info = self
             .authz
@@ -763,6 +2415,11 @@ where
             bank_deposit_accounts_parent_account_set_id,
             financial_institution_deposit_accounts_parent_account_set_id,
             non_domiciled_individual_deposit_accounts_parent_account_set_id,
+            time_deposit_accounts_parent_account_set_id,
+            interest_expense_parent_account_set_id,
+            accrued_interest_parent_account_set_id,
+            unclaimed_funds_parent_account_set_id,
+            fee_income_parent_account_set_id,
         };
 
         self.ledger
@@ -772,14 +2429,23 @@ where
         Ok(config)
     }
 
-    async fn check_account_active(
+    async fn withdrawal_fee(
         &self,
         deposit_account_id: DepositAccountId,
-    ) -> Result<(), CoreDepositError> {
+        amount: DepositAmount,
+    ) -> Result<UsdCents, CoreDepositError> {
+        if amount.usd().is_none() {
+            return Ok(UsdCents::ZERO);
+        }
         let account = self.accounts.find_by_id(deposit_account_id).await?;
-        if account.status.is_inactive() {
-            return Err(CoreDepositError::DepositAccountNotActive);
+        match account.deposit_product_id {
+            Some(deposit_product_id) => Ok(self
+                .products
+                .find_by_id(deposit_product_id)
+                .await?
+                .fee_schedule
+                .withdrawal_fee),
+            None => Ok(UsdCents::ZERO),
         }
-        Ok(())
     }
 }
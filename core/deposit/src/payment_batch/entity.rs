@@ -0,0 +1,215 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{PaymentBatchId, UsdCents, WithdrawalId};
+use audit::AuditInfo;
+
+use super::error::PaymentBatchError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum PaymentBatchStatus {
+    Generated,
+    Sent,
+    Settled,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "PaymentBatchId")]
+pub enum PaymentBatchEvent {
+    Initialized {
+        id: PaymentBatchId,
+        message_id: String,
+        withdrawal_ids: Vec<WithdrawalId>,
+        total_amount: UsdCents,
+        requested_execution_date: NaiveDate,
+        bucket: String,
+        path_in_bucket: String,
+        audit_info: AuditInfo,
+    },
+    MarkedSent {
+        audit_info: AuditInfo,
+        recorded_at: DateTime<Utc>,
+    },
+    MarkedSettled {
+        audit_info: AuditInfo,
+        recorded_at: DateTime<Utc>,
+    },
+    DownloadLinkGenerated {
+        audit_info: AuditInfo,
+        recorded_at: DateTime<Utc>,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct PaymentBatch {
+    pub id: PaymentBatchId,
+    pub message_id: String,
+    pub withdrawal_ids: Vec<WithdrawalId>,
+    pub total_amount: UsdCents,
+    pub requested_execution_date: NaiveDate,
+    bucket: String,
+    path_in_bucket: String,
+
+    pub(super) events: EntityEvents<PaymentBatchEvent>,
+}
+
+impl PaymentBatch {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for payment batch")
+    }
+
+    pub fn status(&self) -> PaymentBatchStatus {
+        if self.settled_at().is_some() {
+            PaymentBatchStatus::Settled
+        } else if self.sent_at().is_some() {
+            PaymentBatchStatus::Sent
+        } else {
+            PaymentBatchStatus::Generated
+        }
+    }
+
+    pub fn sent_at(&self) -> Option<DateTime<Utc>> {
+        self.events.iter_all().find_map(|e| match e {
+            PaymentBatchEvent::MarkedSent { recorded_at, .. } => Some(*recorded_at),
+            _ => None,
+        })
+    }
+
+    pub fn settled_at(&self) -> Option<DateTime<Utc>> {
+        self.events.iter_all().find_map(|e| match e {
+            PaymentBatchEvent::MarkedSettled { recorded_at, .. } => Some(*recorded_at),
+            _ => None,
+        })
+    }
+
+    pub fn mark_sent(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, PaymentBatchError> {
+        idempotency_guard!(self.events.iter_all(), PaymentBatchEvent::MarkedSent { .. });
+
+        self.events.push(PaymentBatchEvent::MarkedSent {
+            audit_info,
+            recorded_at: Utc::now(),
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn mark_settled(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, PaymentBatchError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            PaymentBatchEvent::MarkedSettled { .. }
+        );
+
+        if self.status() != PaymentBatchStatus::Sent {
+            return Err(PaymentBatchError::NotSent(self.id));
+        }
+
+        self.events.push(PaymentBatchEvent::MarkedSettled {
+            audit_info,
+            recorded_at: Utc::now(),
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn download_link_generated(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> cloud_storage::LocationInCloud<'_> {
+        self.events.push(PaymentBatchEvent::DownloadLinkGenerated {
+            audit_info,
+            recorded_at: Utc::now(),
+        });
+        cloud_storage::LocationInCloud {
+            bucket: &self.bucket,
+            path_in_bucket: &self.path_in_bucket,
+        }
+    }
+}
+
+impl TryFromEvents<PaymentBatchEvent> for PaymentBatch {
+    fn try_from_events(events: EntityEvents<PaymentBatchEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = PaymentBatchBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                PaymentBatchEvent::Initialized {
+                    id,
+                    message_id,
+                    withdrawal_ids,
+                    total_amount,
+                    requested_execution_date,
+                    bucket,
+                    path_in_bucket,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .message_id(message_id.clone())
+                        .withdrawal_ids(withdrawal_ids.clone())
+                        .total_amount(*total_amount)
+                        .requested_execution_date(*requested_execution_date)
+                        .bucket(bucket.clone())
+                        .path_in_bucket(path_in_bucket.clone());
+                }
+                PaymentBatchEvent::MarkedSent { .. } => {}
+                PaymentBatchEvent::MarkedSettled { .. } => {}
+                PaymentBatchEvent::DownloadLinkGenerated { .. } => {}
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewPaymentBatch {
+    #[builder(setter(into))]
+    pub(super) id: PaymentBatchId,
+    #[builder(setter(into))]
+    pub(super) message_id: String,
+    pub(super) withdrawal_ids: Vec<WithdrawalId>,
+    pub(super) total_amount: UsdCents,
+    pub(super) requested_execution_date: NaiveDate,
+    #[builder(setter(into))]
+    pub(super) bucket: String,
+    #[builder(setter(into))]
+    pub(super) path_in_bucket: String,
+    #[builder(setter(into))]
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewPaymentBatch {
+    pub fn builder() -> NewPaymentBatchBuilder {
+        NewPaymentBatchBuilder::default()
+    }
+}
+
+impl IntoEvents<PaymentBatchEvent> for NewPaymentBatch {
+    fn into_events(self) -> EntityEvents<PaymentBatchEvent> {
+        EntityEvents::init(
+            self.id,
+            [PaymentBatchEvent::Initialized {
+                id: self.id,
+                message_id: self.message_id,
+                withdrawal_ids: self.withdrawal_ids,
+                total_amount: self.total_amount,
+                requested_execution_date: self.requested_execution_date,
+                bucket: self.bucket,
+                path_in_bucket: self.path_in_bucket,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+use crate::primitives::PaymentBatchId;
+
+#[derive(Error, Debug)]
+pub enum PaymentBatchError {
+    #[error("PaymentBatchError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("PaymentBatchError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("PaymentBatchError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("PaymentBatchError - NotSent: {0}")]
+    NotSent(PaymentBatchId),
+}
+
+es_entity::from_es_entity_error!(PaymentBatchError);
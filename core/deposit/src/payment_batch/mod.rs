@@ -0,0 +1,123 @@
+mod entity;
+pub mod error;
+mod pain001;
+mod repo;
+
+pub(super) use entity::*;
+pub use entity::{PaymentBatch, PaymentBatchStatus};
+pub use repo::payment_batch_cursor::PaymentBatchesByCreatedAtCursor;
+pub(super) use repo::*;
+
+use chrono::Utc;
+
+use audit::AuditInfo;
+use cloud_storage::Storage;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    config::WithdrawalPaymentDebtorConfig,
+    error::CoreDepositError,
+    event::CoreDepositEvent,
+    primitives::{PaymentBatchId, UsdCents},
+    withdrawal::{Withdrawal, WithdrawalRepo, WithdrawalStatus, WithdrawalsByCreatedAtCursor},
+};
+
+use pain001::CreditTransfer;
+
+#[derive(Debug, Clone)]
+pub struct GeneratedPaymentBatchDownloadLink {
+    pub payment_batch_id: PaymentBatchId,
+    pub link: String,
+}
+
+/// Collects all confirmed USD withdrawals with beneficiary details into a new
+/// batch and uploads the corresponding pain.001 file.
+pub(crate) async fn create_payment_batch<E>(
+    withdrawals: &WithdrawalRepo<E>,
+    payment_batches: &PaymentBatchRepo,
+    storage: &Storage,
+    debtor: &WithdrawalPaymentDebtorConfig,
+    audit_info: AuditInfo,
+) -> Result<Option<PaymentBatch>, CoreDepositError>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    let mut payable: Vec<Withdrawal> = Vec::new();
+    let mut has_next_page = true;
+    let mut after: Option<WithdrawalsByCreatedAtCursor> = None;
+    while has_next_page {
+        let res = withdrawals
+            .list_for_status_by_created_at(
+                WithdrawalStatus::Confirmed,
+                es_entity::PaginatedQueryArgs::<WithdrawalsByCreatedAtCursor> { first: 100, after },
+                es_entity::ListDirection::Ascending,
+            )
+            .await?;
+        (after, has_next_page) = (res.end_cursor, res.has_next_page);
+        payable.extend(
+            res.entities
+                .into_iter()
+                .filter(|w| w.amount.usd().is_some() && w.beneficiary.is_some()),
+        );
+    }
+
+    if payable.is_empty() {
+        return Ok(None);
+    }
+
+    let id = PaymentBatchId::new();
+    let message_id = id.to_string().replace('-', "");
+    let now = Utc::now();
+    let requested_execution_date = now.date_naive();
+
+    let transfers = payable
+        .iter()
+        .map(|w| CreditTransfer {
+            end_to_end_id: w.id.to_string().replace('-', ""),
+            amount: w.amount.usd().expect("filtered to usd withdrawals"),
+            creditor: w.beneficiary.as_ref().expect("filtered to beneficiaries"),
+            remittance_information: &w.reference,
+        })
+        .collect::<Vec<_>>();
+    let total_amount = transfers
+        .iter()
+        .fold(UsdCents::ZERO, |acc, t| acc + t.amount);
+    let xml = pain001::render(
+        &message_id,
+        now,
+        requested_execution_date,
+        debtor,
+        &transfers,
+    );
+
+    let path_in_bucket = format!("withdrawal_payment_batches/{id}.xml");
+    storage
+        .upload(xml.into_bytes(), &path_in_bucket, "application/xml")
+        .await?;
+
+    let new_batch = NewPaymentBatch::builder()
+        .id(id)
+        .message_id(message_id)
+        .withdrawal_ids(payable.iter().map(|w| w.id).collect())
+        .total_amount(total_amount)
+        .requested_execution_date(requested_execution_date)
+        .bucket(storage.bucket_name())
+        .path_in_bucket(path_in_bucket)
+        .audit_info(audit_info.clone())
+        .build()
+        .expect("Could not build new payment batch");
+
+    let mut op = payment_batches.begin_op().await?;
+    let batch = payment_batches.create_in_op(&mut op, new_batch).await?;
+    for withdrawal in payable.iter_mut() {
+        if withdrawal
+            .add_to_payment_batch(batch.id, audit_info.clone())?
+            .did_execute()
+        {
+            withdrawals.update_in_op(&mut op, withdrawal).await?;
+        }
+    }
+    op.commit().await?;
+
+    Ok(Some(batch))
+}
@@ -0,0 +1,254 @@
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+
+use std::fmt::Write;
+
+use crate::{
+    config::WithdrawalPaymentDebtorConfig, primitives::UsdCents, withdrawal::WithdrawalBeneficiary,
+};
+
+const PAIN_001_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:pain.001.001.03";
+const MAX_ID_LENGTH: usize = 35;
+const MAX_REMITTANCE_LENGTH: usize = 140;
+
+pub(crate) struct CreditTransfer<'a> {
+    pub end_to_end_id: String,
+    pub amount: UsdCents,
+    pub creditor: &'a WithdrawalBeneficiary,
+    pub remittance_information: &'a str,
+}
+
+/// Renders a pain.001.001.03 customer credit transfer initiation with a single
+/// payment information block debiting the configured bank account.
+pub(crate) fn render(
+    message_id: &str,
+    created_at: DateTime<Utc>,
+    requested_execution_date: NaiveDate,
+    debtor: &WithdrawalPaymentDebtorConfig,
+    transfers: &[CreditTransfer<'_>],
+) -> String {
+    let number_of_transactions = transfers.len();
+    let control_sum = transfers
+        .iter()
+        .fold(UsdCents::ZERO, |acc, t| acc + t.amount)
+        .formatted_usd();
+
+    let mut xml = String::new();
+    writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+    writeln!(xml, r#"<Document xmlns="{PAIN_001_NAMESPACE}">"#).unwrap();
+    writeln!(xml, "  <CstmrCdtTrfInitn>").unwrap();
+    writeln!(xml, "    <GrpHdr>").unwrap();
+    writeln!(xml, "      <MsgId>{}</MsgId>", max_id(message_id)).unwrap();
+    writeln!(
+        xml,
+        "      <CreDtTm>{}</CreDtTm>",
+        created_at.to_rfc3339_opts(SecondsFormat::Secs, true)
+    )
+    .unwrap();
+    writeln!(xml, "      <NbOfTxs>{number_of_transactions}</NbOfTxs>").unwrap();
+    writeln!(xml, "      <CtrlSum>{control_sum}</CtrlSum>").unwrap();
+    writeln!(xml, "      <InitgPty>").unwrap();
+    writeln!(xml, "        <Nm>{}</Nm>", escape(&debtor.name)).unwrap();
+    writeln!(xml, "      </InitgPty>").unwrap();
+    writeln!(xml, "    </GrpHdr>").unwrap();
+    writeln!(xml, "    <PmtInf>").unwrap();
+    writeln!(xml, "      <PmtInfId>{}</PmtInfId>", max_id(message_id)).unwrap();
+    writeln!(xml, "      <PmtMtd>TRF</PmtMtd>").unwrap();
+    writeln!(xml, "      <NbOfTxs>{number_of_transactions}</NbOfTxs>").unwrap();
+    writeln!(xml, "      <CtrlSum>{control_sum}</CtrlSum>").unwrap();
+    writeln!(
+        xml,
+        "      <ReqdExctnDt>{}</ReqdExctnDt>",
+        requested_execution_date.format("%Y-%m-%d")
+    )
+    .unwrap();
+    writeln!(xml, "      <Dbtr>").unwrap();
+    writeln!(xml, "        <Nm>{}</Nm>", escape(&debtor.name)).unwrap();
+    writeln!(xml, "      </Dbtr>").unwrap();
+    write_account(&mut xml, "DbtrAcct", &debtor.account_number);
+    write_agent(&mut xml, "DbtrAgt", debtor.bic.as_deref());
+    for transfer in transfers {
+        writeln!(xml, "      <CdtTrfTxInf>").unwrap();
+        writeln!(xml, "        <PmtId>").unwrap();
+        writeln!(
+            xml,
+            "          <EndToEndId>{}</EndToEndId>",
+            max_id(&transfer.end_to_end_id)
+        )
+        .unwrap();
+        writeln!(xml, "        </PmtId>").unwrap();
+        writeln!(xml, "        <Amt>").unwrap();
+        writeln!(
+            xml,
+            r#"          <InstdAmt Ccy="USD">{}</InstdAmt>"#,
+            transfer.amount.formatted_usd()
+        )
+        .unwrap();
+        writeln!(xml, "        </Amt>").unwrap();
+        write_agent(&mut xml, "CdtrAgt", transfer.creditor.bic.as_deref());
+        writeln!(xml, "        <Cdtr>").unwrap();
+        writeln!(
+            xml,
+            "          <Nm>{}</Nm>",
+            escape(&transfer.creditor.name)
+        )
+        .unwrap();
+        writeln!(xml, "        </Cdtr>").unwrap();
+        write_account(&mut xml, "CdtrAcct", &transfer.creditor.account_number);
+        writeln!(xml, "        <RmtInf>").unwrap();
+        writeln!(
+            xml,
+            "          <Ustrd>{}</Ustrd>",
+            escape(&truncate(
+                transfer.remittance_information,
+                MAX_REMITTANCE_LENGTH
+            ))
+        )
+        .unwrap();
+        writeln!(xml, "        </RmtInf>").unwrap();
+        writeln!(xml, "      </CdtTrfTxInf>").unwrap();
+    }
+    writeln!(xml, "    </PmtInf>").unwrap();
+    writeln!(xml, "  </CstmrCdtTrfInitn>").unwrap();
+    writeln!(xml, "</Document>").unwrap();
+    xml
+}
+
+fn write_account(xml: &mut String, tag: &str, account_number: &str) {
+    let account_number: String = account_number
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    writeln!(xml, "      <{tag}>").unwrap();
+    writeln!(xml, "        <Id>").unwrap();
+    if is_iban(&account_number) {
+        writeln!(
+            xml,
+            "          <IBAN>{}</IBAN>",
+            account_number.to_uppercase()
+        )
+        .unwrap();
+    } else {
+        writeln!(xml, "          <Othr>").unwrap();
+        writeln!(xml, "            <Id>{}</Id>", escape(&account_number)).unwrap();
+        writeln!(xml, "          </Othr>").unwrap();
+    }
+    writeln!(xml, "        </Id>").unwrap();
+    writeln!(xml, "      </{tag}>").unwrap();
+}
+
+fn write_agent(xml: &mut String, tag: &str, bic: Option<&str>) {
+    writeln!(xml, "      <{tag}>").unwrap();
+    writeln!(xml, "        <FinInstnId>").unwrap();
+    match bic {
+        Some(bic) if !bic.trim().is_empty() => {
+            writeln!(xml, "          <BIC>{}</BIC>", escape(bic.trim())).unwrap();
+        }
+        _ => {
+            writeln!(xml, "          <Othr>").unwrap();
+            writeln!(xml, "            <Id>NOTPROVIDED</Id>").unwrap();
+            writeln!(xml, "          </Othr>").unwrap();
+        }
+    }
+    writeln!(xml, "        </FinInstnId>").unwrap();
+    writeln!(xml, "      </{tag}>").unwrap();
+}
+
+fn is_iban(account_number: &str) -> bool {
+    let bytes = account_number.as_bytes();
+    (15..=34).contains(&bytes.len())
+        && bytes[..2].iter().all(|b| b.is_ascii_alphabetic())
+        && bytes[2..4].iter().all(|b| b.is_ascii_digit())
+        && bytes[4..].iter().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn max_id(id: &str) -> String {
+    escape(&truncate(id, MAX_ID_LENGTH))
+}
+
+fn truncate(value: &str, max: usize) -> String {
+    value.chars().take(max).collect()
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn debtor() -> WithdrawalPaymentDebtorConfig {
+        WithdrawalPaymentDebtorConfig {
+            name: "Lana Bank".to_string(),
+            account_number: "DE89 3704 0044 0532 0130 00".to_string(),
+            bic: Some("COBADEFFXXX".to_string()),
+        }
+    }
+
+    #[test]
+    fn renders_group_header_totals() {
+        let creditor = WithdrawalBeneficiary {
+            name: "Jane & Co".to_string(),
+            account_number: "123456789".to_string(),
+            bic: None,
+        };
+        let transfers = vec![
+            CreditTransfer {
+                end_to_end_id: "first".to_string(),
+                amount: UsdCents::from(12_345),
+                creditor: &creditor,
+                remittance_information: "ref-1",
+            },
+            CreditTransfer {
+                end_to_end_id: "second".to_string(),
+                amount: UsdCents::from(55),
+                creditor: &creditor,
+                remittance_information: "ref-2",
+            },
+        ];
+        let xml = render(
+            "msg-1",
+            Utc::now(),
+            NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(),
+            &debtor(),
+            &transfers,
+        );
+
+        assert!(xml.contains("<NbOfTxs>2</NbOfTxs>"));
+        assert!(xml.contains("<CtrlSum>124.00</CtrlSum>"));
+        assert!(xml.contains(r#"<InstdAmt Ccy="USD">123.45</InstdAmt>"#));
+        assert!(xml.contains("<ReqdExctnDt>2025-01-02</ReqdExctnDt>"));
+        assert!(xml.contains("<Nm>Jane &amp; Co</Nm>"));
+    }
+
+    #[test]
+    fn detects_iban_accounts() {
+        let xml = render(
+            "msg-1",
+            Utc::now(),
+            NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(),
+            &debtor(),
+            &[],
+        );
+        assert!(xml.contains("<IBAN>DE89370400440532013000</IBAN>"));
+        assert!(is_iban("GB29NWBK60161331926819"));
+        assert!(!is_iban("123456789"));
+    }
+
+    #[test]
+    fn truncates_identifiers() {
+        let id = "x".repeat(50);
+        assert_eq!(max_id(&id).len(), MAX_ID_LENGTH);
+    }
+}
@@ -1,9 +1,11 @@
 use std::{fmt::Display, str::FromStr};
 
 use authz::AllOrOne;
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
 
 pub use core_accounting::ChartId;
-pub use core_customer::CustomerType;
+pub use core_customer::{CustomerType, SignatoryId};
 pub use governance::{ApprovalProcessId, GovernanceAction, GovernanceObject};
 
 pub use cala_ledger::primitives::{
@@ -16,23 +18,249 @@ es_entity::entity_id! {
     DepositAccountId,
     WithdrawalId,
     ChartOfAccountsIntegrationConfigId,
+    DepositProductId,
+    TransferId,
+    HoldId,
+    WithdrawalLimitId,
+    WithdrawalLimitOverrideId,
+    DepositAccountStatementId,
+    PaymentBatchId,
+    BankStatementImportId,
+    BankStatementLineId,
+    DepositFeeId,
+    StandingOrderId,
     DepositId;
 
     DepositAccountHolderId => core_customer::CustomerId,
     DepositAccountId => CalaAccountId,
+    DepositAccountId => job::JobId,
     DepositId => CalaTransactionId,
+    DepositFeeId => CalaTransactionId,
     WithdrawalId => CalaTransactionId,
-    WithdrawalId => ApprovalProcessId
+    WithdrawalId => ApprovalProcessId,
+    TransferId => CalaTransactionId,
+    TransferId => ApprovalProcessId,
+    HoldId => CalaTransactionId,
+    HoldId => job::JobId,
+    WithdrawalLimitId => CalaAccountSetId,
+    WithdrawalLimitOverrideId => job::JobId,
+    DepositAccountStatementId => job::JobId,
+    PaymentBatchId => job::JobId,
+    StandingOrderId => job::JobId
 }
 
-pub use core_customer::AccountStatus;
-pub use core_money::UsdCents;
+pub use core_customer::{AccountStatus, KycLevel};
+pub use core_money::{Satoshis, UsdCents};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum DepositAccountStatus {
+    Inactive,
+    Active,
+    Frozen,
+    Dormant,
+    Closed,
+}
+
+impl DepositAccountStatus {
+    pub fn is_inactive(&self) -> bool {
+        matches!(self, DepositAccountStatus::Inactive)
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        matches!(self, DepositAccountStatus::Frozen)
+    }
+
+    pub fn is_dormant(&self) -> bool {
+        matches!(self, DepositAccountStatus::Dormant)
+    }
+
+    pub fn is_closed(&self) -> bool {
+        matches!(self, DepositAccountStatus::Closed)
+    }
+}
+
+impl From<AccountStatus> for DepositAccountStatus {
+    fn from(status: AccountStatus) -> Self {
+        match status {
+            AccountStatus::Inactive => DepositAccountStatus::Inactive,
+            AccountStatus::Active => DepositAccountStatus::Active,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum DepositAccountCurrency {
+    #[default]
+    Usd,
+    Btc,
+}
+
+impl From<DepositAccountCurrency> for cala_ledger::Currency {
+    fn from(currency: DepositAccountCurrency) -> Self {
+        match currency {
+            DepositAccountCurrency::Usd => cala_ledger::Currency::USD,
+            DepositAccountCurrency::Btc => cala_ledger::Currency::BTC,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DepositAmount {
+    Usd(UsdCents),
+    Btc(Satoshis),
+}
+
+impl DepositAmount {
+    pub fn currency(&self) -> DepositAccountCurrency {
+        match self {
+            DepositAmount::Usd(_) => DepositAccountCurrency::Usd,
+            DepositAmount::Btc(_) => DepositAccountCurrency::Btc,
+        }
+    }
+
+    pub fn usd(&self) -> Option<UsdCents> {
+        match self {
+            DepositAmount::Usd(amount) => Some(*amount),
+            DepositAmount::Btc(_) => None,
+        }
+    }
+
+    pub fn btc(&self) -> Option<Satoshis> {
+        match self {
+            DepositAmount::Usd(_) => None,
+            DepositAmount::Btc(amount) => Some(*amount),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        match self {
+            DepositAmount::Usd(amount) => amount.is_zero(),
+            DepositAmount::Btc(amount) => amount.is_zero(),
+        }
+    }
+
+    pub(crate) fn to_ledger_units(self) -> Decimal {
+        match self {
+            DepositAmount::Usd(amount) => amount.to_usd(),
+            DepositAmount::Btc(amount) => amount.to_btc(),
+        }
+    }
+}
+
+impl From<UsdCents> for DepositAmount {
+    fn from(amount: UsdCents) -> Self {
+        DepositAmount::Usd(amount)
+    }
+}
+
+impl From<Satoshis> for DepositAmount {
+    fn from(amount: Satoshis) -> Self {
+        DepositAmount::Btc(amount)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[strum(serialize_all = "kebab-case")]
+pub enum DepositFeeType {
+    MonthlyMaintenance,
+    Withdrawal,
+    BelowMinimumBalance,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum WithdrawalLimitSource {
+    Account,
+    Segment,
+}
 
 pub type DepositAccountAllOrOne = AllOrOne<DepositAccountId>;
 pub type DepositAccountByHolderAllOrOne = AllOrOne<DepositAccountHolderId>;
 pub type DepositAllOrOne = AllOrOne<DepositId>;
+pub type DepositFeeAllOrOne = AllOrOne<DepositFeeId>;
+pub type DepositProductAllOrOne = AllOrOne<DepositProductId>;
 pub type ChartOfAccountsIntegrationConfigAllOrOne = AllOrOne<ChartOfAccountsIntegrationConfigId>;
 pub type WithdrawalAllOrOne = AllOrOne<WithdrawalId>;
+pub type TransferAllOrOne = AllOrOne<TransferId>;
+pub type HoldAllOrOne = AllOrOne<HoldId>;
+pub type WithdrawalLimitAllOrOne = AllOrOne<WithdrawalLimitId>;
+pub type DepositAccountStatementAllOrOne = AllOrOne<DepositAccountStatementId>;
+pub type PaymentBatchAllOrOne = AllOrOne<PaymentBatchId>;
+pub type BankStatementImportAllOrOne = AllOrOne<BankStatementImportId>;
+pub type StandingOrderAllOrOne = AllOrOne<StandingOrderId>;
+
+const NUMBER_OF_DAYS_IN_YEAR: u64 = 365;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct InterestRatePct(Decimal);
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(InterestRatePct);
+
+impl InterestRatePct {
+    pub const ZERO: Self = Self(Decimal::ZERO);
+
+    pub fn into_inner(self) -> Decimal {
+        self.0
+    }
+
+    pub fn daily_interest_cents(&self, balance: UsdCents) -> Decimal {
+        balance.to_usd() * self.0 / Decimal::from(NUMBER_OF_DAYS_IN_YEAR)
+    }
+}
+
+impl From<Decimal> for InterestRatePct {
+    fn from(value: Decimal) -> Self {
+        InterestRatePct(value)
+    }
+}
+
+/// Share of the accrued interest a time deposit forfeits when broken before maturity.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct EarlyBreakPenaltyPct(Decimal);
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(EarlyBreakPenaltyPct);
+
+impl EarlyBreakPenaltyPct {
+    pub const FULL_INTEREST: Self = Self(Decimal::ONE_HUNDRED);
+
+    pub fn into_inner(self) -> Decimal {
+        self.0
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.0 >= Decimal::ZERO && self.0 <= Decimal::ONE_HUNDRED
+    }
+
+    pub fn forfeited_interest(&self, interest: UsdCents) -> UsdCents {
+        whole_cents(Decimal::from(interest.into_inner()) * self.0 / Decimal::ONE_HUNDRED)
+    }
+}
+
+impl Default for EarlyBreakPenaltyPct {
+    fn default() -> Self {
+        Self::FULL_INTEREST
+    }
+}
+
+impl From<Decimal> for EarlyBreakPenaltyPct {
+    fn from(value: Decimal) -> Self {
+        EarlyBreakPenaltyPct(value)
+    }
+}
+
+pub(crate) fn whole_cents(cents: Decimal) -> UsdCents {
+    UsdCents::from(
+        cents
+            .round_dp_with_strategy(0, RoundingStrategy::ToZero)
+            .to_u64()
+            .expect("should return a valid integer"),
+    )
+}
 
 #[derive(Debug, Clone)]
 pub struct LedgerOmnibusAccountIds {
@@ -48,6 +276,15 @@ pub enum CoreDepositObject {
     Deposit(DepositAllOrOne),
     ChartOfAccountsIntegration(ChartOfAccountsIntegrationConfigAllOrOne),
     Withdrawal(WithdrawalAllOrOne),
+    DepositProduct(DepositProductAllOrOne),
+    Transfer(TransferAllOrOne),
+    Hold(HoldAllOrOne),
+    WithdrawalLimit(WithdrawalLimitAllOrOne),
+    Statement(DepositAccountStatementAllOrOne),
+    PaymentBatch(PaymentBatchAllOrOne),
+    BankStatement(BankStatementImportAllOrOne),
+    DepositFee(DepositFeeAllOrOne),
+    StandingOrder(StandingOrderAllOrOne),
 }
 
 impl CoreDepositObject {
@@ -78,6 +315,78 @@ impl CoreDepositObject {
     pub fn chart_of_accounts_integration() -> Self {
         CoreDepositObject::ChartOfAccountsIntegration(AllOrOne::All)
     }
+
+    pub fn all_deposit_products() -> Self {
+        CoreDepositObject::DepositProduct(AllOrOne::All)
+    }
+
+    pub fn deposit_product(id: DepositProductId) -> Self {
+        CoreDepositObject::DepositProduct(AllOrOne::ById(id))
+    }
+
+    pub fn all_transfers() -> Self {
+        CoreDepositObject::Transfer(AllOrOne::All)
+    }
+
+    pub fn transfer(id: TransferId) -> Self {
+        CoreDepositObject::Transfer(AllOrOne::ById(id))
+    }
+
+    pub fn all_holds() -> Self {
+        CoreDepositObject::Hold(AllOrOne::All)
+    }
+
+    pub fn hold(id: HoldId) -> Self {
+        CoreDepositObject::Hold(AllOrOne::ById(id))
+    }
+
+    pub fn all_withdrawal_limits() -> Self {
+        CoreDepositObject::WithdrawalLimit(AllOrOne::All)
+    }
+
+    pub fn withdrawal_limit(id: WithdrawalLimitId) -> Self {
+        CoreDepositObject::WithdrawalLimit(AllOrOne::ById(id))
+    }
+
+    pub fn all_statements() -> Self {
+        CoreDepositObject::Statement(AllOrOne::All)
+    }
+
+    pub fn statement(id: DepositAccountStatementId) -> Self {
+        CoreDepositObject::Statement(AllOrOne::ById(id))
+    }
+
+    pub fn all_payment_batches() -> Self {
+        CoreDepositObject::PaymentBatch(AllOrOne::All)
+    }
+
+    pub fn payment_batch(id: PaymentBatchId) -> Self {
+        CoreDepositObject::PaymentBatch(AllOrOne::ById(id))
+    }
+
+    pub fn all_bank_statements() -> Self {
+        CoreDepositObject::BankStatement(AllOrOne::All)
+    }
+
+    pub fn bank_statement(id: BankStatementImportId) -> Self {
+        CoreDepositObject::BankStatement(AllOrOne::ById(id))
+    }
+
+    pub fn all_deposit_fees() -> Self {
+        CoreDepositObject::DepositFee(AllOrOne::All)
+    }
+
+    pub fn deposit_fee(id: DepositFeeId) -> Self {
+        CoreDepositObject::DepositFee(AllOrOne::ById(id))
+    }
+
+    pub fn all_standing_orders() -> Self {
+        CoreDepositObject::StandingOrder(AllOrOne::All)
+    }
+
+    pub fn standing_order(id: StandingOrderId) -> Self {
+        CoreDepositObject::StandingOrder(AllOrOne::ById(id))
+    }
 }
 
 impl Display for CoreDepositObject {
@@ -89,6 +398,15 @@ impl Display for CoreDepositObject {
             Deposit(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             Withdrawal(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
             ChartOfAccountsIntegration(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            DepositProduct(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            Transfer(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            Hold(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            WithdrawalLimit(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            Statement(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            PaymentBatch(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            BankStatement(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            DepositFee(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
+            StandingOrder(obj_ref) => write!(f, "{}/{}", discriminant, obj_ref),
         }
     }
 }
@@ -124,6 +442,60 @@ impl FromStr for CoreDepositObject {
                     .map_err(|_| "could not parse CoreDepositObject")?;
                 CoreDepositObject::ChartOfAccountsIntegration(obj_ref)
             }
+            DepositProduct => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::DepositProduct(obj_ref)
+            }
+            Transfer => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Transfer(obj_ref)
+            }
+            Hold => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Hold(obj_ref)
+            }
+            WithdrawalLimit => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::WithdrawalLimit(obj_ref)
+            }
+            Statement => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Statement(obj_ref)
+            }
+            PaymentBatch => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::PaymentBatch(obj_ref)
+            }
+            BankStatement => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::BankStatement(obj_ref)
+            }
+            DepositFee => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::DepositFee(obj_ref)
+            }
+            StandingOrder => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::StandingOrder(obj_ref)
+            }
         };
         Ok(res)
     }
@@ -137,6 +509,15 @@ pub enum CoreDepositAction {
     Deposit(DepositAction),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAction),
     Withdrawal(WithdrawalAction),
+    DepositProduct(DepositProductAction),
+    Transfer(TransferAction),
+    Hold(HoldAction),
+    WithdrawalLimit(WithdrawalLimitAction),
+    Statement(StatementAction),
+    PaymentBatch(PaymentBatchAction),
+    BankStatement(BankStatementAction),
+    DepositFee(DepositFeeAction),
+    StandingOrder(StandingOrderAction),
 }
 
 impl CoreDepositAction {
@@ -150,8 +531,33 @@ impl CoreDepositAction {
         CoreDepositAction::DepositAccount(DepositAccountAction::Read);
     pub const DEPOSIT_ACCOUNT_LIST: Self =
         CoreDepositAction::DepositAccount(DepositAccountAction::List);
+    pub const DEPOSIT_ACCOUNT_ASSIGN_PRODUCT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::AssignProduct);
+    pub const DEPOSIT_ACCOUNT_ACCRUE_INTEREST: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::AccrueInterest);
+    pub const DEPOSIT_ACCOUNT_FREEZE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::Freeze);
+    pub const DEPOSIT_ACCOUNT_UNFREEZE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::Unfreeze);
+    pub const DEPOSIT_ACCOUNT_CLOSE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::Close);
+    pub const DEPOSIT_ACCOUNT_OPEN_TIME_DEPOSIT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::OpenTimeDeposit);
+    pub const DEPOSIT_ACCOUNT_BREAK_TIME_DEPOSIT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::BreakTimeDeposit);
+    pub const DEPOSIT_ACCOUNT_MATURE_TIME_DEPOSIT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::MatureTimeDeposit);
+    pub const DEPOSIT_ACCOUNT_ASSIGN_WITHDRAWAL_LIMIT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::AssignWithdrawalLimit);
+    pub const DEPOSIT_ACCOUNT_MARK_DORMANT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::MarkDormant);
+    pub const DEPOSIT_ACCOUNT_REACTIVATE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::Reactivate);
+    pub const DEPOSIT_ACCOUNT_TRANSFER_UNCLAIMED_FUNDS: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::TransferUnclaimedFunds);
 
     pub const DEPOSIT_CREATE: Self = CoreDepositAction::Deposit(DepositAction::Create);
+    pub const DEPOSIT_REVERT: Self = CoreDepositAction::Deposit(DepositAction::Revert);
     pub const DEPOSIT_READ: Self = CoreDepositAction::Deposit(DepositAction::Read);
     pub const DEPOSIT_LIST: Self = CoreDepositAction::Deposit(DepositAction::List);
 
@@ -169,8 +575,97 @@ impl CoreDepositAction {
         CoreDepositAction::Withdrawal(WithdrawalAction::ConcludeApprovalProcess);
     pub const WITHDRAWAL_CANCEL: Self = CoreDepositAction::Withdrawal(WithdrawalAction::Cancel);
     pub const WITHDRAWAL_CONFIRM: Self = CoreDepositAction::Withdrawal(WithdrawalAction::Confirm);
+    pub const WITHDRAWAL_SIGNATORY_APPROVE: Self =
+        CoreDepositAction::Withdrawal(WithdrawalAction::SignatoryApprove);
+    pub const WITHDRAWAL_SIGNATORY_REJECT: Self =
+        CoreDepositAction::Withdrawal(WithdrawalAction::SignatoryReject);
     pub const WITHDRAWAL_READ: Self = CoreDepositAction::Withdrawal(WithdrawalAction::Read);
     pub const WITHDRAWAL_LIST: Self = CoreDepositAction::Withdrawal(WithdrawalAction::List);
+
+    pub const DEPOSIT_PRODUCT_CREATE: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Create);
+    pub const DEPOSIT_PRODUCT_UPDATE_RATE: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::UpdateRate);
+    pub const DEPOSIT_PRODUCT_UPDATE_FEE_SCHEDULE: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::UpdateFeeSchedule);
+    pub const DEPOSIT_PRODUCT_UPDATE_EARLY_BREAK_PENALTY: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::UpdateEarlyBreakPenalty);
+    pub const DEPOSIT_PRODUCT_READ: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Read);
+    pub const DEPOSIT_PRODUCT_LIST: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::List);
+
+    pub const TRANSFER_INITIATE: Self = CoreDepositAction::Transfer(TransferAction::Initiate);
+    pub const TRANSFER_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreDepositAction::Transfer(TransferAction::ConcludeApprovalProcess);
+    pub const TRANSFER_READ: Self = CoreDepositAction::Transfer(TransferAction::Read);
+    pub const TRANSFER_LIST: Self = CoreDepositAction::Transfer(TransferAction::List);
+
+    pub const HOLD_PLACE: Self = CoreDepositAction::Hold(HoldAction::Place);
+    pub const HOLD_RELEASE: Self = CoreDepositAction::Hold(HoldAction::Release);
+    pub const HOLD_EXPIRE: Self = CoreDepositAction::Hold(HoldAction::Expire);
+    pub const HOLD_READ: Self = CoreDepositAction::Hold(HoldAction::Read);
+    pub const HOLD_LIST: Self = CoreDepositAction::Hold(HoldAction::List);
+
+    pub const WITHDRAWAL_LIMIT_CREATE: Self =
+        CoreDepositAction::WithdrawalLimit(WithdrawalLimitAction::Create);
+    pub const WITHDRAWAL_LIMIT_UPDATE: Self =
+        CoreDepositAction::WithdrawalLimit(WithdrawalLimitAction::Update);
+    pub const WITHDRAWAL_LIMIT_READ: Self =
+        CoreDepositAction::WithdrawalLimit(WithdrawalLimitAction::Read);
+    pub const WITHDRAWAL_LIMIT_LIST: Self =
+        CoreDepositAction::WithdrawalLimit(WithdrawalLimitAction::List);
+    pub const WITHDRAWAL_LIMIT_GRANT_OVERRIDE: Self =
+        CoreDepositAction::WithdrawalLimit(WithdrawalLimitAction::GrantOverride);
+    pub const WITHDRAWAL_LIMIT_REVOKE_OVERRIDE: Self =
+        CoreDepositAction::WithdrawalLimit(WithdrawalLimitAction::RevokeOverride);
+    pub const WITHDRAWAL_LIMIT_EXPIRE_OVERRIDE: Self =
+        CoreDepositAction::WithdrawalLimit(WithdrawalLimitAction::ExpireOverride);
+
+    pub const STATEMENT_CREATE: Self = CoreDepositAction::Statement(StatementAction::Create);
+    pub const STATEMENT_GENERATE: Self = CoreDepositAction::Statement(StatementAction::Generate);
+    pub const STATEMENT_READ: Self = CoreDepositAction::Statement(StatementAction::Read);
+    pub const STATEMENT_LIST: Self = CoreDepositAction::Statement(StatementAction::List);
+    pub const STATEMENT_GENERATE_DOWNLOAD_LINK: Self =
+        CoreDepositAction::Statement(StatementAction::GenerateDownloadLink);
+
+    pub const PAYMENT_BATCH_CREATE: Self =
+        CoreDepositAction::PaymentBatch(PaymentBatchAction::Create);
+    pub const PAYMENT_BATCH_READ: Self = CoreDepositAction::PaymentBatch(PaymentBatchAction::Read);
+    pub const PAYMENT_BATCH_LIST: Self = CoreDepositAction::PaymentBatch(PaymentBatchAction::List);
+    pub const PAYMENT_BATCH_MARK_SENT: Self =
+        CoreDepositAction::PaymentBatch(PaymentBatchAction::MarkSent);
+    pub const PAYMENT_BATCH_MARK_SETTLED: Self =
+        CoreDepositAction::PaymentBatch(PaymentBatchAction::MarkSettled);
+    pub const PAYMENT_BATCH_GENERATE_DOWNLOAD_LINK: Self =
+        CoreDepositAction::PaymentBatch(PaymentBatchAction::GenerateDownloadLink);
+
+    pub const BANK_STATEMENT_IMPORT: Self =
+        CoreDepositAction::BankStatement(BankStatementAction::Import);
+    pub const BANK_STATEMENT_READ: Self =
+        CoreDepositAction::BankStatement(BankStatementAction::Read);
+    pub const BANK_STATEMENT_LIST: Self =
+        CoreDepositAction::BankStatement(BankStatementAction::List);
+    pub const BANK_STATEMENT_RESOLVE_LINE: Self =
+        CoreDepositAction::BankStatement(BankStatementAction::ResolveLine);
+    pub const BANK_STATEMENT_DISMISS_LINE: Self =
+        CoreDepositAction::BankStatement(BankStatementAction::DismissLine);
+
+    pub const DEPOSIT_FEE_CHARGE: Self = CoreDepositAction::DepositFee(DepositFeeAction::Charge);
+    pub const DEPOSIT_FEE_WAIVE: Self = CoreDepositAction::DepositFee(DepositFeeAction::Waive);
+    pub const DEPOSIT_FEE_READ: Self = CoreDepositAction::DepositFee(DepositFeeAction::Read);
+    pub const DEPOSIT_FEE_LIST: Self = CoreDepositAction::DepositFee(DepositFeeAction::List);
+
+    pub const STANDING_ORDER_CREATE: Self =
+        CoreDepositAction::StandingOrder(StandingOrderAction::Create);
+    pub const STANDING_ORDER_CANCEL: Self =
+        CoreDepositAction::StandingOrder(StandingOrderAction::Cancel);
+    pub const STANDING_ORDER_EXECUTE: Self =
+        CoreDepositAction::StandingOrder(StandingOrderAction::Execute);
+    pub const STANDING_ORDER_READ: Self =
+        CoreDepositAction::StandingOrder(StandingOrderAction::Read);
+    pub const STANDING_ORDER_LIST: Self =
+        CoreDepositAction::StandingOrder(StandingOrderAction::List);
 }
 
 impl Display for CoreDepositAction {
@@ -182,6 +677,15 @@ impl Display for CoreDepositAction {
             Deposit(action) => action.fmt(f),
             ChartOfAccountsIntegrationConfig(action) => action.fmt(f),
             Withdrawal(action) => action.fmt(f),
+            DepositProduct(action) => action.fmt(f),
+            Transfer(action) => action.fmt(f),
+            Hold(action) => action.fmt(f),
+            WithdrawalLimit(action) => action.fmt(f),
+            Statement(action) => action.fmt(f),
+            PaymentBatch(action) => action.fmt(f),
+            BankStatement(action) => action.fmt(f),
+            DepositFee(action) => action.fmt(f),
+            StandingOrder(action) => action.fmt(f),
         }
     }
 }
@@ -199,6 +703,15 @@ impl FromStr for CoreDepositAction {
                 CoreDepositAction::from(action.parse::<ChartOfAccountsIntegrationConfigAction>()?)
             }
             Withdrawal => CoreDepositAction::from(action.parse::<WithdrawalAction>()?),
+            DepositProduct => CoreDepositAction::from(action.parse::<DepositProductAction>()?),
+            Transfer => CoreDepositAction::from(action.parse::<TransferAction>()?),
+            Hold => CoreDepositAction::from(action.parse::<HoldAction>()?),
+            WithdrawalLimit => CoreDepositAction::from(action.parse::<WithdrawalLimitAction>()?),
+            Statement => CoreDepositAction::from(action.parse::<StatementAction>()?),
+            PaymentBatch => CoreDepositAction::from(action.parse::<PaymentBatchAction>()?),
+            BankStatement => CoreDepositAction::from(action.parse::<BankStatementAction>()?),
+            DepositFee => CoreDepositAction::from(action.parse::<DepositFeeAction>()?),
+            StandingOrder => CoreDepositAction::from(action.parse::<StandingOrderAction>()?),
         };
 
         Ok(res)
@@ -214,6 +727,18 @@ pub enum DepositAccountAction {
     ReadTxHistory,
     Read,
     List,
+    AssignProduct,
+    AccrueInterest,
+    Freeze,
+    Unfreeze,
+    Close,
+    OpenTimeDeposit,
+    BreakTimeDeposit,
+    MatureTimeDeposit,
+    AssignWithdrawalLimit,
+    MarkDormant,
+    Reactivate,
+    TransferUnclaimedFunds,
 }
 
 impl From<DepositAccountAction> for CoreDepositAction {
@@ -226,6 +751,7 @@ impl From<DepositAccountAction> for CoreDepositAction {
 #[strum(serialize_all = "kebab-case")]
 pub enum DepositAction {
     Create,
+    Revert,
     Read,
     List,
 }
@@ -243,6 +769,8 @@ pub enum WithdrawalAction {
     Cancel,
     Confirm,
     ConcludeApprovalProcess,
+    SignatoryApprove,
+    SignatoryReject,
     Read,
     List,
 }
@@ -266,6 +794,152 @@ impl From<ChartOfAccountsIntegrationConfigAction> for CoreDepositAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DepositProductAction {
+    Create,
+    UpdateRate,
+    UpdateFeeSchedule,
+    UpdateEarlyBreakPenalty,
+    Read,
+    List,
+}
+
+impl From<DepositProductAction> for CoreDepositAction {
+    fn from(action: DepositProductAction) -> Self {
+        CoreDepositAction::DepositProduct(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum TransferAction {
+    Initiate,
+    ConcludeApprovalProcess,
+    Read,
+    List,
+}
+
+impl From<TransferAction> for CoreDepositAction {
+    fn from(action: TransferAction) -> Self {
+        CoreDepositAction::Transfer(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum HoldAction {
+    Place,
+    Release,
+    Expire,
+    Read,
+    List,
+}
+
+impl From<HoldAction> for CoreDepositAction {
+    fn from(action: HoldAction) -> Self {
+        CoreDepositAction::Hold(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum WithdrawalLimitAction {
+    Create,
+    Update,
+    Read,
+    List,
+    GrantOverride,
+    RevokeOverride,
+    ExpireOverride,
+}
+
+impl From<WithdrawalLimitAction> for CoreDepositAction {
+    fn from(action: WithdrawalLimitAction) -> Self {
+        CoreDepositAction::WithdrawalLimit(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum StatementAction {
+    Create,
+    Generate,
+    Read,
+    List,
+    GenerateDownloadLink,
+}
+
+impl From<StatementAction> for CoreDepositAction {
+    fn from(action: StatementAction) -> Self {
+        CoreDepositAction::Statement(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PaymentBatchAction {
+    Create,
+    Read,
+    List,
+    MarkSent,
+    MarkSettled,
+    GenerateDownloadLink,
+}
+
+impl From<PaymentBatchAction> for CoreDepositAction {
+    fn from(action: PaymentBatchAction) -> Self {
+        CoreDepositAction::PaymentBatch(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum BankStatementAction {
+    Import,
+    Read,
+    List,
+    ResolveLine,
+    DismissLine,
+}
+
+impl From<BankStatementAction> for CoreDepositAction {
+    fn from(action: BankStatementAction) -> Self {
+        CoreDepositAction::BankStatement(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DepositFeeAction {
+    Charge,
+    Waive,
+    Read,
+    List,
+}
+
+impl From<DepositFeeAction> for CoreDepositAction {
+    fn from(action: DepositFeeAction) -> Self {
+        CoreDepositAction::DepositFee(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum StandingOrderAction {
+    Create,
+    Cancel,
+    Execute,
+    Read,
+    List,
+}
+
+impl From<StandingOrderAction> for CoreDepositAction {
+    fn from(action: StandingOrderAction) -> Self {
+        CoreDepositAction::StandingOrder(action)
+    }
+}
+
 pub enum DepositAccountType {
     Individual,
     GovernmentEntity,
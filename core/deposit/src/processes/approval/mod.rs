@@ -12,8 +12,10 @@ use outbox::OutboxEventMarker;
 
 use crate::{
     event::CoreDepositEvent,
+    fee::DepositFeeRepo,
+    ledger::DepositLedger,
     primitives::WithdrawalId,
-    withdrawal::{error::WithdrawalError, repo::WithdrawalRepo, Withdrawal},
+    withdrawal::{repo::WithdrawalRepo, Withdrawal},
     CoreDepositAction, CoreDepositObject, WithdrawalAction,
 };
 
@@ -29,6 +31,8 @@ where
     E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
 {
     repo: WithdrawalRepo<E>,
+    fees: DepositFeeRepo,
+    ledger: DepositLedger,
     audit: Perms::Audit,
     governance: Governance<Perms, E>,
 }
@@ -40,6 +44,8 @@ where
     fn clone(&self) -> Self {
         Self {
             repo: self.repo.clone(),
+            fees: self.fees.clone(),
+            ledger: self.ledger.clone(),
             audit: self.audit.clone(),
             governance: self.governance.clone(),
         }
@@ -57,11 +63,15 @@ where
 {
     pub fn new(
         repo: &WithdrawalRepo<E>,
+        fees: &DepositFeeRepo,
+        ledger: &DepositLedger,
         audit: &Perms::Audit,
         governance: &Governance<Perms, E>,
     ) -> Self {
         Self {
             repo: repo.clone(),
+            fees: fees.clone(),
+            ledger: ledger.clone(),
             audit: audit.clone(),
             governance: governance.clone(),
         }
@@ -71,7 +81,7 @@ where
         &self,
         withdraw: &Withdrawal,
     ) -> Result<Option<Withdrawal>, ProcessError> {
-        if withdraw.is_approved_or_denied().is_some() {
+        if withdraw.is_approved_or_denied().is_some() || !withdraw.has_approval_process() {
             return Ok(None);
         }
 
@@ -95,7 +105,7 @@ where
         &self,
         id: impl es_entity::RetryableInto<WithdrawalId>,
         approved: bool,
-    ) -> Result<Withdrawal, WithdrawalError> {
+    ) -> Result<Withdrawal, ProcessError> {
         let id = id.into();
         let mut withdraw = self.repo.find_by_id(id).await?;
         if withdraw.is_approved_or_denied().is_some() {
@@ -111,11 +121,34 @@ where
             )
             .await?;
         if withdraw
-            .approval_process_concluded(approved, audit_info)
-            .did_execute()
+            .approval_process_concluded(approved, audit_info.clone())
+            .was_ignored()
         {
-            self.repo.update_in_op(&mut db, &mut withdraw).await?;
-            db.commit().await?;
+            return Ok(withdraw);
+        }
+        self.repo.update_in_op(&mut db, &mut withdraw).await?;
+
+        // A denied withdrawal is not charged for; the funds themselves are released
+        // once the withdrawal is cancelled.
+        let waived_fee = if approved {
+            None
+        } else {
+            self.fees
+                .waive_withdrawal_fee_in_op(&mut db, id, "Withdrawal denied", audit_info)
+                .await?
+        };
+        match waived_fee {
+            Some(fee) => {
+                self.ledger
+                    .waive_fee(
+                        db,
+                        fee.waived_tx_id.expect("fee was just waived"),
+                        fee.amount,
+                        fee.deposit_account_id,
+                    )
+                    .await?
+            }
+            None => db.commit().await?,
         }
         Ok(withdraw)
     }
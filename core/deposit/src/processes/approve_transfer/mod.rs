@@ -0,0 +1,174 @@
+mod job;
+
+use authz::PermissionCheck;
+use governance::{
+    ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, GovernanceAction, GovernanceEvent,
+    GovernanceObject,
+};
+
+use audit::AuditSvc;
+use governance::Governance;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    account::DepositAccountRepo,
+    event::CoreDepositEvent,
+    ledger::DepositLedger,
+    primitives::{DepositAccountCurrency, TransferId},
+    transfer::{repo::TransferRepo, Transfer},
+    CoreDepositAction, CoreDepositObject, TransferAction,
+};
+
+use super::error::ProcessError;
+
+pub use job::*;
+
+pub const APPROVE_TRANSFER_PROCESS: ApprovalProcessType = ApprovalProcessType::new("transfer");
+
+pub struct ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    repo: TransferRepo<E>,
+    accounts: DepositAccountRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+    governance: Governance<Perms, E>,
+}
+impl<Perms, E> Clone for ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            accounts: self.accounts.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+            governance: self.governance.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        repo: &TransferRepo<E>,
+        accounts: &DepositAccountRepo,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+        governance: &Governance<Perms, E>,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            accounts: accounts.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+            governance: governance.clone(),
+        }
+    }
+
+    pub async fn execute_from_svc(
+        &self,
+        transfer: &Transfer,
+    ) -> Result<Option<Transfer>, ProcessError> {
+        let approval_process_id = match transfer.approval_process_id {
+            Some(id) if transfer.is_approved_or_denied().is_none() => id,
+            _ => return Ok(None),
+        };
+
+        let process: ApprovalProcess = self
+            .governance
+            .find_all_approval_processes(&[approval_process_id])
+            .await?
+            .remove(&approval_process_id)
+            .expect("approval process not found");
+
+        let res = match process.status() {
+            ApprovalProcessStatus::Approved => Some(self.execute(transfer.id, true).await?),
+            ApprovalProcessStatus::Denied => Some(self.execute(transfer.id, false).await?),
+            _ => None,
+        };
+        Ok(res)
+    }
+
+    #[es_entity::retry_on_concurrent_modification]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<TransferId>,
+        approved: bool,
+    ) -> Result<Transfer, ProcessError> {
+        let id = id.into();
+        let mut transfer = self.repo.find_by_id(id).await?;
+        if transfer.is_approved_or_denied().is_some() {
+            return Ok(transfer);
+        }
+        let mut db = self.repo.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::Transfer(TransferAction::ConcludeApprovalProcess),
+            )
+            .await?;
+        if transfer
+            .approval_process_concluded(approved, audit_info.clone())
+            .was_ignored()
+        {
+            return Ok(transfer);
+        }
+
+        if !approved {
+            self.repo.update_in_op(&mut db, &mut transfer).await?;
+            db.commit().await?;
+            return Ok(transfer);
+        }
+
+        // Accounts may have been frozen, closed or gone dormant while the transfer was
+        // awaiting approval.
+        let from_account = self
+            .accounts
+            .find_by_id(transfer.from_deposit_account_id)
+            .await?;
+        let to_account = self
+            .accounts
+            .find_by_id(transfer.to_deposit_account_id)
+            .await?;
+        let balance = self
+            .ledger
+            .balance(transfer.from_deposit_account_id)
+            .await?;
+        if !from_account.accepts_debits(DepositAccountCurrency::Usd)
+            || !to_account.accepts_credits(DepositAccountCurrency::Usd)
+            || balance.available() < transfer.amount
+        {
+            transfer.cancel(audit_info)?;
+            self.repo.update_in_op(&mut db, &mut transfer).await?;
+            db.commit().await?;
+            return Ok(transfer);
+        }
+
+        let tx_id = transfer.execute(audit_info)?;
+        self.repo.update_in_op(&mut db, &mut transfer).await?;
+        self.ledger
+            .transfer(
+                db,
+                tx_id,
+                transfer.amount,
+                transfer.from_deposit_account_id,
+                transfer.to_deposit_account_id,
+            )
+            .await?;
+        Ok(transfer)
+    }
+}
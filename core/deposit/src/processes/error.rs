@@ -10,6 +10,14 @@ pub enum ProcessError {
     Sqlx(#[from] sqlx::Error),
     #[error("ProcessError - WithdrawalError: {0}")]
     WithdrawalError(#[from] crate::withdrawal::error::WithdrawalError),
+    #[error("ProcessError - DepositAccountError: {0}")]
+    DepositAccountError(#[from] crate::account::error::DepositAccountError),
+    #[error("ProcessError - TransferError: {0}")]
+    TransferError(#[from] crate::transfer::error::TransferError),
+    #[error("ProcessError - DepositFeeError: {0}")]
+    DepositFeeError(#[from] crate::fee::error::DepositFeeError),
+    #[error("ProcessError - DepositLedgerError: {0}")]
+    DepositLedgerError(#[from] crate::ledger::error::DepositLedgerError),
     #[error("ProcessError - AuditError: {0}")]
     AuditError(#[from] audit::error::AuditError),
 }
@@ -1,2 +1,3 @@
 pub mod approval;
+pub mod approve_transfer;
 pub mod error;
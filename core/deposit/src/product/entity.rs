@@ -0,0 +1,238 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use audit::AuditInfo;
+
+use crate::primitives::*;
+
+use super::error::DepositProductError;
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DepositFeeSchedule {
+    pub monthly_maintenance_fee: UsdCents,
+    pub withdrawal_fee: UsdCents,
+    pub minimum_balance: UsdCents,
+    pub below_minimum_balance_fee: UsdCents,
+}
+
+impl DepositFeeSchedule {
+    /// Fees due at the end of a month for an account whose lowest end-of-day balance over
+    /// the month was `minimum_balance`.
+    pub fn monthly_fees(&self, minimum_balance: UsdCents) -> Vec<(DepositFeeType, UsdCents)> {
+        let mut fees = Vec::new();
+        if !self.monthly_maintenance_fee.is_zero() {
+            fees.push((
+                DepositFeeType::MonthlyMaintenance,
+                self.monthly_maintenance_fee,
+            ));
+        }
+        if !self.below_minimum_balance_fee.is_zero() && minimum_balance < self.minimum_balance {
+            fees.push((
+                DepositFeeType::BelowMinimumBalance,
+                self.below_minimum_balance_fee,
+            ));
+        }
+        fees
+    }
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "DepositProductId")]
+pub enum DepositProductEvent {
+    Initialized {
+        id: DepositProductId,
+        name: String,
+        annual_rate: InterestRatePct,
+        audit_info: AuditInfo,
+    },
+    AnnualRateUpdated {
+        annual_rate: InterestRatePct,
+        effective_from: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    FeeScheduleUpdated {
+        fee_schedule: DepositFeeSchedule,
+        audit_info: AuditInfo,
+    },
+    EarlyBreakPenaltyUpdated {
+        early_break_penalty: EarlyBreakPenaltyPct,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct DepositProduct {
+    pub id: DepositProductId,
+    pub name: String,
+    pub annual_rate: InterestRatePct,
+    #[builder(default)]
+    pub fee_schedule: DepositFeeSchedule,
+    #[builder(default)]
+    pub early_break_penalty: EarlyBreakPenaltyPct,
+    pub(super) events: EntityEvents<DepositProductEvent>,
+}
+
+impl DepositProduct {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("Deposit Product has never been persisted")
+    }
+
+    pub fn rate_at(&self, date: NaiveDate) -> InterestRatePct {
+        let mut rate = None;
+        let mut rate_effective_from = None;
+        for event in self.events.iter_all() {
+            match event {
+                DepositProductEvent::Initialized { annual_rate, .. } => rate = Some(*annual_rate),
+                DepositProductEvent::AnnualRateUpdated {
+                    annual_rate,
+                    effective_from,
+                    ..
+                } if *effective_from <= date
+                    && rate_effective_from.is_none_or(|from| from <= *effective_from) =>
+                {
+                    rate = Some(*annual_rate);
+                    rate_effective_from = Some(*effective_from);
+                }
+                _ => (),
+            }
+        }
+        rate.expect("Initialized event not found")
+    }
+
+    pub fn update_annual_rate(
+        &mut self,
+        annual_rate: InterestRatePct,
+        effective_from: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if let Some(DepositProductEvent::AnnualRateUpdated {
+            annual_rate: existing_rate,
+            effective_from: existing_effective_from,
+            ..
+        }) = self
+            .events
+            .iter_all()
+            .rev()
+            .find(|e| matches!(e, DepositProductEvent::AnnualRateUpdated { .. }))
+        {
+            if existing_rate == &annual_rate && existing_effective_from == &effective_from {
+                return Idempotent::Ignored;
+            }
+        }
+        self.events.push(DepositProductEvent::AnnualRateUpdated {
+            annual_rate,
+            effective_from,
+            audit_info,
+        });
+        self.annual_rate = self.rate_at(chrono::Utc::now().date_naive());
+        Idempotent::Executed(())
+    }
+
+    pub fn update_fee_schedule(
+        &mut self,
+        fee_schedule: DepositFeeSchedule,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.fee_schedule == fee_schedule {
+            return Idempotent::Ignored;
+        }
+        self.events.push(DepositProductEvent::FeeScheduleUpdated {
+            fee_schedule,
+            audit_info,
+        });
+        self.fee_schedule = fee_schedule;
+        Idempotent::Executed(())
+    }
+
+    pub fn update_early_break_penalty(
+        &mut self,
+        early_break_penalty: EarlyBreakPenaltyPct,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, DepositProductError> {
+        if !early_break_penalty.is_valid() {
+            return Err(DepositProductError::InvalidEarlyBreakPenalty(
+                early_break_penalty,
+            ));
+        }
+        if self.early_break_penalty == early_break_penalty {
+            return Ok(Idempotent::Ignored);
+        }
+        self.events
+            .push(DepositProductEvent::EarlyBreakPenaltyUpdated {
+                early_break_penalty,
+                audit_info,
+            });
+        self.early_break_penalty = early_break_penalty;
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<DepositProductEvent> for DepositProduct {
+    fn try_from_events(events: EntityEvents<DepositProductEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = DepositProductBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                DepositProductEvent::Initialized {
+                    id,
+                    name,
+                    annual_rate,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .name(name.to_string())
+                        .annual_rate(*annual_rate)
+                }
+                DepositProductEvent::AnnualRateUpdated { .. } => (),
+                DepositProductEvent::FeeScheduleUpdated { fee_schedule, .. } => {
+                    builder = builder.fee_schedule(*fee_schedule)
+                }
+                DepositProductEvent::EarlyBreakPenaltyUpdated {
+                    early_break_penalty,
+                    ..
+                } => builder = builder.early_break_penalty(*early_break_penalty),
+            }
+        }
+        let mut product = builder.events(events).build()?;
+        product.annual_rate = product.rate_at(chrono::Utc::now().date_naive());
+        Ok(product)
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewDepositProduct {
+    #[builder(setter(into))]
+    pub(super) id: DepositProductId,
+    #[builder(setter(into))]
+    pub(super) name: String,
+    pub(super) annual_rate: InterestRatePct,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewDepositProduct {
+    pub fn builder() -> NewDepositProductBuilder {
+        NewDepositProductBuilder::default()
+    }
+}
+
+impl IntoEvents<DepositProductEvent> for NewDepositProduct {
+    fn into_events(self) -> EntityEvents<DepositProductEvent> {
+        EntityEvents::init(
+            self.id,
+            [DepositProductEvent::Initialized {
+                id: self.id,
+                name: self.name,
+                annual_rate: self.annual_rate,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
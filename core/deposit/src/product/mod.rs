@@ -0,0 +1,8 @@
+mod entity;
+pub mod error;
+mod repo;
+
+pub(crate) use entity::*;
+pub use entity::{DepositFeeSchedule, DepositProduct};
+pub use repo::deposit_product_cursor::DepositProductsByCreatedAtCursor;
+pub(crate) use repo::*;
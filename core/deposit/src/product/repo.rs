@@ -0,0 +1,25 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::DepositProductId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "DepositProduct",
+    err = "DepositProductError",
+    columns(name(ty = "String")),
+    tbl_prefix = "core"
+)]
+pub struct DepositProductRepo {
+    #[allow(dead_code)]
+    pool: PgPool,
+}
+
+impl DepositProductRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
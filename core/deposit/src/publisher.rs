@@ -3,6 +3,10 @@ use outbox::{Outbox, OutboxEventMarker};
 use super::event::CoreDepositEvent;
 use crate::{
     deposit::{error::DepositError, Deposit, DepositEvent},
+    standing_order::{
+        error::StandingOrderError, StandingOrder, StandingOrderDestination, StandingOrderEvent,
+    },
+    transfer::{error::TransferError, Transfer, TransferEvent},
     withdrawal::{error::WithdrawalError, Withdrawal, WithdrawalEvent},
 };
 
@@ -71,6 +75,66 @@ where
                     deposit_account_id: entity.deposit_account_id,
                     amount: entity.amount,
                 },
+                Reverted { .. } => CoreDepositEvent::DepositReverted {
+                    id: entity.id,
+                    deposit_account_id: entity.deposit_account_id,
+                    amount: entity.amount,
+                },
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(db.tx(), publish_events)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn publish_transfer(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        entity: &Transfer,
+        new_events: es_entity::LastPersisted<'_, TransferEvent>,
+    ) -> Result<(), TransferError> {
+        use TransferEvent::*;
+        let publish_events = new_events
+            .filter_map(|event| match &event.event {
+                Executed { .. } => Some(CoreDepositEvent::TransferExecuted {
+                    id: entity.id,
+                    from_deposit_account_id: entity.from_deposit_account_id,
+                    to_deposit_account_id: entity.to_deposit_account_id,
+                    amount: entity.amount,
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(db.tx(), publish_events)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn publish_standing_order(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        entity: &StandingOrder,
+        new_events: es_entity::LastPersisted<'_, StandingOrderEvent>,
+    ) -> Result<(), StandingOrderError> {
+        use StandingOrderEvent::*;
+        let StandingOrderDestination::CreditFacility { credit_facility_id } = entity.destination
+        else {
+            return Ok(());
+        };
+        let publish_events = new_events
+            .filter_map(|event| match &event.event {
+                RepaymentRequested { execution_date, .. } => {
+                    Some(CoreDepositEvent::StandingOrderRepaymentDue {
+                        id: entity.id,
+                        deposit_account_id: entity.deposit_account_id,
+                        credit_facility_id,
+                        amount: entity.amount,
+                        execution_date: *execution_date,
+                    })
+                }
+                _ => None,
             })
             .collect::<Vec<_>>();
         self.outbox
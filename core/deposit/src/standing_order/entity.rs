@@ -0,0 +1,454 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::{
+    config::StandingOrderRetryConfig,
+    primitives::{DepositAccountId, StandingOrderId, TransferId, UsdCents},
+};
+use audit::AuditInfo;
+
+use super::{error::StandingOrderError, schedule::StandingOrderFrequency};
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum StandingOrderStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StandingOrderDestination {
+    DepositAccount {
+        deposit_account_id: DepositAccountId,
+    },
+    CreditFacility {
+        credit_facility_id: uuid::Uuid,
+    },
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "StandingOrderId")]
+pub enum StandingOrderEvent {
+    Initialized {
+        id: StandingOrderId,
+        deposit_account_id: DepositAccountId,
+        destination: StandingOrderDestination,
+        amount: UsdCents,
+        frequency: StandingOrderFrequency,
+        start_date: NaiveDate,
+        end_date: Option<NaiveDate>,
+        reference: String,
+        audit_info: AuditInfo,
+    },
+    Executed {
+        execution_date: NaiveDate,
+        transfer_id: Option<TransferId>,
+        next_execution_date: Option<NaiveDate>,
+        audit_info: AuditInfo,
+    },
+    RepaymentRequested {
+        execution_date: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    ExecutionFailed {
+        execution_date: NaiveDate,
+        attempt: u32,
+        reason: String,
+        retry_at: Option<DateTime<Utc>>,
+        next_execution_date: Option<NaiveDate>,
+        audit_info: AuditInfo,
+    },
+    Cancelled {
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct StandingOrder {
+    pub id: StandingOrderId,
+    pub deposit_account_id: DepositAccountId,
+    pub destination: StandingOrderDestination,
+    pub amount: UsdCents,
+    pub frequency: StandingOrderFrequency,
+    pub start_date: NaiveDate,
+    #[builder(default)]
+    pub end_date: Option<NaiveDate>,
+    pub reference: String,
+    #[builder(default)]
+    pub next_execution_date: Option<NaiveDate>,
+
+    pub(super) events: EntityEvents<StandingOrderEvent>,
+}
+
+impl StandingOrder {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for standing order")
+    }
+
+    pub fn status(&self) -> StandingOrderStatus {
+        if self
+            .events
+            .iter_all()
+            .any(|e| matches!(e, StandingOrderEvent::Cancelled { .. }))
+        {
+            StandingOrderStatus::Cancelled
+        } else if self.next_execution_date.is_none() {
+            StandingOrderStatus::Completed
+        } else {
+            StandingOrderStatus::Active
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status() == StandingOrderStatus::Active
+    }
+
+    /// Failed attempts recorded against the pending execution date.
+    pub fn failed_attempts(&self) -> u32 {
+        let Some(next_execution_date) = self.next_execution_date else {
+            return 0;
+        };
+        self.events
+            .iter_all()
+            .filter(|e| {
+                matches!(
+                    e,
+                    StandingOrderEvent::ExecutionFailed { execution_date, .. }
+                        if *execution_date == next_execution_date
+                )
+            })
+            .count() as u32
+    }
+
+    pub fn next_run_at(&self) -> Option<DateTime<Utc>> {
+        if !self.is_active() {
+            return None;
+        }
+        if let Some(StandingOrderEvent::ExecutionFailed {
+            retry_at: Some(retry_at),
+            ..
+        }) = self.events.iter_all().next_back()
+        {
+            return Some(*retry_at);
+        }
+        self.next_execution_date
+            .map(|date| date.and_hms_opt(0, 0, 0).expect("valid time").and_utc())
+    }
+
+    pub fn last_failure_reason(&self) -> Option<&str> {
+        match self.events.iter_all().next_back() {
+            Some(StandingOrderEvent::ExecutionFailed { reason, .. }) => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// Execution date of a credit facility repayment that has been requested but whose
+    /// outcome has not been recorded yet.
+    pub fn awaiting_repayment(&self) -> Option<NaiveDate> {
+        let pending_execution_date = self.pending_execution_date()?;
+        match self.events.iter_all().next_back() {
+            Some(StandingOrderEvent::RepaymentRequested { execution_date, .. })
+                if *execution_date == pending_execution_date =>
+            {
+                Some(*execution_date)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.next_run_at().is_some_and(|run_at| run_at <= now)
+    }
+
+    pub fn record_executed(
+        &mut self,
+        transfer_id: Option<TransferId>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        let Some(execution_date) = self.pending_execution_date() else {
+            return Idempotent::Ignored;
+        };
+
+        let next_execution_date = self.following_execution_date(execution_date);
+        self.events.push(StandingOrderEvent::Executed {
+            execution_date,
+            transfer_id,
+            next_execution_date,
+            audit_info,
+        });
+        self.next_execution_date = next_execution_date;
+
+        Idempotent::Executed(())
+    }
+
+    /// Hands the pending execution over to the credit module. It only counts as executed
+    /// once the repayment has been recorded there.
+    pub fn request_repayment(&mut self, audit_info: AuditInfo) -> Idempotent<NaiveDate> {
+        let Some(execution_date) = self.pending_execution_date() else {
+            return Idempotent::Ignored;
+        };
+        if self.awaiting_repayment().is_some() {
+            return Idempotent::Ignored;
+        }
+
+        self.events.push(StandingOrderEvent::RepaymentRequested {
+            execution_date,
+            audit_info,
+        });
+
+        Idempotent::Executed(execution_date)
+    }
+
+    /// Schedules a retry of the pending execution or, once the retry policy is
+    /// exhausted, skips it and moves on to the next one.
+    pub fn record_execution_failed(
+        &mut self,
+        reason: String,
+        retry: &StandingOrderRetryConfig,
+        now: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        let Some(execution_date) = self.pending_execution_date() else {
+            return Idempotent::Ignored;
+        };
+
+        let attempt = self.failed_attempts() + 1;
+        let (retry_at, next_execution_date) = if attempt < retry.max_attempts {
+            (Some(now + retry.retry_interval()), Some(execution_date))
+        } else {
+            (None, self.following_execution_date(execution_date))
+        };
+        self.events.push(StandingOrderEvent::ExecutionFailed {
+            execution_date,
+            attempt,
+            reason,
+            retry_at,
+            next_execution_date,
+            audit_info,
+        });
+        self.next_execution_date = next_execution_date;
+
+        Idempotent::Executed(())
+    }
+
+    pub fn cancel(&mut self, audit_info: AuditInfo) -> Result<(), StandingOrderError> {
+        match self.status() {
+            StandingOrderStatus::Cancelled => {
+                return Err(StandingOrderError::AlreadyCancelled(self.id))
+            }
+            StandingOrderStatus::Completed => {
+                return Err(StandingOrderError::AlreadyCompleted(self.id))
+            }
+            StandingOrderStatus::Active => (),
+        }
+
+        self.events
+            .push(StandingOrderEvent::Cancelled { audit_info });
+
+        Ok(())
+    }
+
+    fn pending_execution_date(&self) -> Option<NaiveDate> {
+        if self.is_active() {
+            self.next_execution_date
+        } else {
+            None
+        }
+    }
+
+    fn following_execution_date(&self, execution_date: NaiveDate) -> Option<NaiveDate> {
+        let next = self
+            .frequency
+            .next_execution_date(self.start_date, execution_date);
+        if self.end_date.is_some_and(|end_date| next > end_date) {
+            None
+        } else {
+            Some(next)
+        }
+    }
+}
+
+impl TryFromEvents<StandingOrderEvent> for StandingOrder {
+    fn try_from_events(events: EntityEvents<StandingOrderEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = StandingOrderBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                StandingOrderEvent::Initialized {
+                    id,
+                    deposit_account_id,
+                    destination,
+                    amount,
+                    frequency,
+                    start_date,
+                    end_date,
+                    reference,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .deposit_account_id(*deposit_account_id)
+                        .destination(*destination)
+                        .amount(*amount)
+                        .frequency(*frequency)
+                        .start_date(*start_date)
+                        .end_date(*end_date)
+                        .reference(reference.clone())
+                        .next_execution_date(Some(frequency.first_execution_date(*start_date)))
+                }
+                StandingOrderEvent::Executed {
+                    next_execution_date,
+                    ..
+                }
+                | StandingOrderEvent::ExecutionFailed {
+                    next_execution_date,
+                    ..
+                } => builder = builder.next_execution_date(*next_execution_date),
+                StandingOrderEvent::RepaymentRequested { .. }
+                | StandingOrderEvent::Cancelled { .. } => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewStandingOrder {
+    #[builder(setter(into))]
+    pub(super) id: StandingOrderId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    pub(super) destination: StandingOrderDestination,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    pub(super) frequency: StandingOrderFrequency,
+    pub(super) start_date: NaiveDate,
+    #[builder(default)]
+    pub(super) end_date: Option<NaiveDate>,
+    reference: Option<String>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewStandingOrder {
+    pub fn builder() -> NewStandingOrderBuilder {
+        NewStandingOrderBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None => self.id.to_string(),
+            Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl IntoEvents<StandingOrderEvent> for NewStandingOrder {
+    fn into_events(self) -> EntityEvents<StandingOrderEvent> {
+        EntityEvents::init(
+            self.id,
+            [StandingOrderEvent::Initialized {
+                reference: self.reference(),
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                destination: self.destination,
+                amount: self.amount,
+                frequency: self.frequency,
+                start_date: self.start_date,
+                end_date: self.end_date,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn repayment_standing_order() -> StandingOrder {
+        let new_standing_order = NewStandingOrder::builder()
+            .id(StandingOrderId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .destination(StandingOrderDestination::CreditFacility {
+                credit_facility_id: uuid::Uuid::new_v4(),
+            })
+            .amount(UsdCents::ONE)
+            .frequency(StandingOrderFrequency::Monthly)
+            .start_date("2025-01-15".parse().unwrap())
+            .reference(None)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        StandingOrder::try_from_events(new_standing_order.into_events()).unwrap()
+    }
+
+    #[test]
+    fn requested_repayment_is_not_executed_until_recorded() {
+        let mut standing_order = repayment_standing_order();
+        let execution_date = standing_order.next_execution_date.unwrap();
+
+        assert!(standing_order
+            .request_repayment(dummy_audit_info())
+            .did_execute());
+        assert_eq!(standing_order.awaiting_repayment(), Some(execution_date));
+        assert_eq!(standing_order.next_execution_date, Some(execution_date));
+        assert!(standing_order
+            .request_repayment(dummy_audit_info())
+            .was_ignored());
+
+        assert!(standing_order
+            .record_executed(None, dummy_audit_info())
+            .did_execute());
+        assert_eq!(standing_order.awaiting_repayment(), None);
+        assert!(standing_order.next_execution_date > Some(execution_date));
+    }
+
+    #[test]
+    fn failed_repayment_is_retried() {
+        let mut standing_order = repayment_standing_order();
+        let execution_date = standing_order.next_execution_date.unwrap();
+        let retry = StandingOrderRetryConfig::default();
+        let now = Utc::now();
+
+        assert!(standing_order
+            .request_repayment(dummy_audit_info())
+            .did_execute());
+        assert!(standing_order
+            .record_execution_failed(
+                "insufficient funds".to_string(),
+                &retry,
+                now,
+                dummy_audit_info()
+            )
+            .did_execute());
+
+        assert_eq!(standing_order.awaiting_repayment(), None);
+        assert_eq!(standing_order.next_execution_date, Some(execution_date));
+        assert_eq!(
+            standing_order.next_run_at(),
+            Some(now + retry.retry_interval())
+        );
+        assert!(standing_order
+            .request_repayment(dummy_audit_info())
+            .did_execute());
+    }
+}
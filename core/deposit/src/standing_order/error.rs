@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+use crate::primitives::StandingOrderId;
+
+#[derive(Error, Debug)]
+pub enum StandingOrderError {
+    #[error("StandingOrderError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("StandingOrderError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("StandingOrderError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("StandingOrderError - AlreadyCancelled: {0}")]
+    AlreadyCancelled(StandingOrderId),
+    #[error("StandingOrderError - AlreadyCompleted: {0}")]
+    AlreadyCompleted(StandingOrderId),
+    #[error("StandingOrderError - StartDateInPast")]
+    StartDateInPast,
+    #[error("StandingOrderError - EndDateBeforeFirstExecution")]
+    EndDateBeforeFirstExecution,
+    #[error("StandingOrderError - SameAccount")]
+    SameAccount,
+    #[error("StandingOrderError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+}
+
+es_entity::from_es_entity_error!(StandingOrderError);
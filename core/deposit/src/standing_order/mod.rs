@@ -0,0 +1,279 @@
+mod entity;
+pub mod error;
+pub mod repo;
+mod schedule;
+
+pub(super) use entity::*;
+pub use entity::{StandingOrder, StandingOrderDestination, StandingOrderStatus};
+pub use repo::standing_order_cursor::StandingOrdersByCreatedAtCursor;
+pub(super) use repo::*;
+pub use schedule::StandingOrderFrequency;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::Jobs;
+use outbox::OutboxEventMarker;
+use tracing::instrument;
+
+use crate::{
+    account::DepositAccountRepo,
+    checks::{check_account_accepts_credits, check_account_active},
+    config::StandingOrderRetryConfig,
+    error::CoreDepositError,
+    event::CoreDepositEvent,
+    jobs::standing_order_execution::StandingOrderExecutionJobConfig,
+    primitives::*,
+};
+use error::StandingOrderError;
+
+pub struct StandingOrders<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    authz: Perms,
+    repo: StandingOrderRepo<E>,
+    accounts: DepositAccountRepo,
+    jobs: Jobs,
+    retry: StandingOrderRetryConfig,
+}
+
+impl<Perms, E> Clone for StandingOrders<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            repo: self.repo.clone(),
+            accounts: self.accounts.clone(),
+            jobs: self.jobs.clone(),
+            retry: self.retry.clone(),
+        }
+    }
+}
+
+impl<Perms, E> StandingOrders<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub(crate) fn new(
+        authz: &Perms,
+        repo: &StandingOrderRepo<E>,
+        accounts: &DepositAccountRepo,
+        jobs: &Jobs,
+        retry: &StandingOrderRetryConfig,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            repo: repo.clone(),
+            accounts: accounts.clone(),
+            jobs: jobs.clone(),
+            retry: retry.clone(),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(name = "deposit.create_standing_order", skip(self), err)]
+    pub async fn create_standing_order(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        destination: StandingOrderDestination,
+        amount: UsdCents,
+        frequency: StandingOrderFrequency,
+        start_date: chrono::NaiveDate,
+        end_date: Option<chrono::NaiveDate>,
+        reference: Option<String>,
+    ) -> Result<StandingOrder, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_standing_orders(),
+                CoreDepositAction::STANDING_ORDER_CREATE,
+            )
+            .await?;
+        if start_date < chrono::Utc::now().date_naive() {
+            return Err(StandingOrderError::StartDateInPast.into());
+        }
+        if end_date.is_some_and(|end_date| frequency.first_execution_date(start_date) > end_date) {
+            return Err(StandingOrderError::EndDateBeforeFirstExecution.into());
+        }
+        check_account_active(
+            &self.accounts,
+            deposit_account_id,
+            DepositAccountCurrency::Usd,
+        )
+        .await?;
+        if let StandingOrderDestination::DepositAccount {
+            deposit_account_id: to_deposit_account_id,
+        } = destination
+        {
+            if to_deposit_account_id == deposit_account_id {
+                return Err(StandingOrderError::SameAccount.into());
+            }
+            check_account_accepts_credits(
+                &self.accounts,
+                to_deposit_account_id,
+                DepositAccountCurrency::Usd,
+            )
+            .await?;
+        }
+
+        let new_standing_order = NewStandingOrder::builder()
+            .id(StandingOrderId::new())
+            .deposit_account_id(deposit_account_id)
+            .destination(destination)
+            .amount(amount)
+            .frequency(frequency)
+            .start_date(start_date)
+            .end_date(end_date)
+            .reference(reference)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new standing order");
+
+        let mut op = self.repo.begin_op().await?;
+        let standing_order = self.repo.create_in_op(&mut op, new_standing_order).await?;
+        let first_run_at = standing_order
+            .next_run_at()
+            .expect("new standing order has a first execution");
+        self.jobs
+            .create_and_spawn_at_in_op(
+                &mut op,
+                standing_order.id,
+                StandingOrderExecutionJobConfig::<Perms, E> {
+                    standing_order_id: standing_order.id,
+                    _phantom: std::marker::PhantomData,
+                },
+                first_run_at,
+            )
+            .await?;
+        op.commit().await?;
+        Ok(standing_order)
+    }
+
+    #[instrument(name = "deposit.cancel_standing_order", skip(self), err)]
+    pub async fn cancel_standing_order(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        standing_order_id: impl Into<StandingOrderId> + std::fmt::Debug,
+    ) -> Result<StandingOrder, CoreDepositError> {
+        let standing_order_id = standing_order_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::standing_order(standing_order_id),
+                CoreDepositAction::STANDING_ORDER_CANCEL,
+            )
+            .await?;
+
+        let mut standing_order = self.repo.find_by_id(standing_order_id).await?;
+        standing_order.cancel(audit_info)?;
+        self.repo.update(&mut standing_order).await?;
+        Ok(standing_order)
+    }
+
+    /// Records the outcome of a credit facility repayment requested by a standing order.
+    /// A failed repayment is retried or skipped like any other failed execution.
+    #[instrument(name = "deposit.record_standing_order_repayment", skip(self), err)]
+    pub async fn record_standing_order_repayment(
+        &self,
+        standing_order_id: impl Into<StandingOrderId> + std::fmt::Debug,
+        execution_date: chrono::NaiveDate,
+        failure_reason: Option<String>,
+    ) -> Result<StandingOrder, CoreDepositError> {
+        let mut standing_order = self.repo.find_by_id(standing_order_id.into()).await?;
+        if standing_order.awaiting_repayment() != Some(execution_date) {
+            return Ok(standing_order);
+        }
+
+        let mut op = self.repo.begin_op().await?;
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::standing_order(standing_order.id),
+                CoreDepositAction::STANDING_ORDER_EXECUTE,
+            )
+            .await?;
+        let recorded = match failure_reason {
+            None => standing_order.record_executed(None, audit_info),
+            Some(reason) => standing_order.record_execution_failed(
+                reason,
+                &self.retry,
+                chrono::Utc::now(),
+                audit_info,
+            ),
+        };
+        if recorded.did_execute() {
+            self.repo.update_in_op(&mut op, &mut standing_order).await?;
+            op.commit().await?;
+        }
+
+        Ok(standing_order)
+    }
+
+    #[instrument(name = "deposit.find_standing_order_by_id", skip(self), err)]
+    pub async fn find_standing_order_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<StandingOrderId> + std::fmt::Debug,
+    ) -> Result<Option<StandingOrder>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::standing_order(id),
+                CoreDepositAction::STANDING_ORDER_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(standing_order) => Ok(Some(standing_order)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_standing_orders_for_account", skip(self), err)]
+    pub async fn list_standing_orders_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<StandingOrder>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_standing_orders(),
+                CoreDepositAction::STANDING_ORDER_LIST,
+            )
+            .await?;
+        Ok(self
+            .repo
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[instrument(name = "deposit.find_all_standing_orders", skip(self), err)]
+    pub async fn find_all_standing_orders<T: From<StandingOrder>>(
+        &self,
+        ids: &[StandingOrderId],
+    ) -> Result<std::collections::HashMap<StandingOrderId, T>, CoreDepositError> {
+        Ok(self.repo.find_all(ids).await?)
+    }
+}
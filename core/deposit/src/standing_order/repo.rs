@@ -0,0 +1,64 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    event::CoreDepositEvent,
+    primitives::{DepositAccountId, StandingOrderId},
+    publisher::DepositPublisher,
+};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "StandingOrder",
+    err = "StandingOrderError",
+    columns(deposit_account_id(ty = "DepositAccountId", list_for, update(persist = false))),
+    tbl_prefix = "core",
+    post_persist_hook = "publish"
+)]
+pub struct StandingOrderRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    publisher: DepositPublisher<E>,
+
+    pool: PgPool,
+}
+
+impl<E> Clone for StandingOrderRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            publisher: self.publisher.clone(),
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+impl<E> StandingOrderRepo<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(pool: &PgPool, publisher: &DepositPublisher<E>) -> Self {
+        Self {
+            pool: pool.clone(),
+            publisher: publisher.clone(),
+        }
+    }
+
+    async fn publish(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        entity: &StandingOrder,
+        new_events: es_entity::LastPersisted<'_, StandingOrderEvent>,
+    ) -> Result<(), StandingOrderError> {
+        self.publisher
+            .publish_standing_order(db, entity, new_events)
+            .await
+    }
+}
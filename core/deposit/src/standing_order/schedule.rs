@@ -0,0 +1,94 @@
+use chrono::{Datelike, Days, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[serde(rename_all = "snake_case")]
+pub enum StandingOrderFrequency {
+    Weekly,
+    Monthly,
+    EndOfMonth,
+}
+
+impl StandingOrderFrequency {
+    pub fn first_execution_date(&self, start_date: NaiveDate) -> NaiveDate {
+        match self {
+            StandingOrderFrequency::Weekly | StandingOrderFrequency::Monthly => start_date,
+            StandingOrderFrequency::EndOfMonth => end_of_month(start_date),
+        }
+    }
+
+    /// Monthly orders stay anchored to the day of month of `start_date`, so an
+    /// execution clamped to the end of a short month does not shift the ones after it.
+    pub fn next_execution_date(&self, start_date: NaiveDate, previous: NaiveDate) -> NaiveDate {
+        match self {
+            StandingOrderFrequency::Weekly => previous + Days::new(7),
+            StandingOrderFrequency::Monthly => {
+                let months_since_start = (previous.year() - start_date.year()) * 12
+                    + previous.month() as i32
+                    - start_date.month() as i32;
+                start_date
+                    .checked_add_months(Months::new(months_since_start as u32 + 1))
+                    .expect("should return a valid date")
+            }
+            StandingOrderFrequency::EndOfMonth => end_of_month(previous + Days::new(1)),
+        }
+    }
+}
+
+fn end_of_month(date: NaiveDate) -> NaiveDate {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+
+    NaiveDate::from_ymd_opt(year, month, 1).expect("should return a valid date") - Days::new(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn weekly_advances_by_seven_days() {
+        let frequency = StandingOrderFrequency::Weekly;
+        let start = date("2025-12-29");
+
+        assert_eq!(frequency.first_execution_date(start), start);
+        assert_eq!(
+            frequency.next_execution_date(start, start),
+            date("2026-01-05")
+        );
+    }
+
+    #[test]
+    fn monthly_stays_anchored_to_start_day() {
+        let frequency = StandingOrderFrequency::Monthly;
+        let start = date("2025-01-31");
+
+        let february = frequency.next_execution_date(start, start);
+        assert_eq!(february, date("2025-02-28"));
+        let march = frequency.next_execution_date(start, february);
+        assert_eq!(march, date("2025-03-31"));
+    }
+
+    #[test]
+    fn end_of_month_executes_on_last_day() {
+        let frequency = StandingOrderFrequency::EndOfMonth;
+        let start = date("2025-11-15");
+
+        let first = frequency.first_execution_date(start);
+        assert_eq!(first, date("2025-11-30"));
+        let second = frequency.next_execution_date(start, first);
+        assert_eq!(second, date("2025-12-31"));
+        assert_eq!(
+            frequency.next_execution_date(start, second),
+            date("2026-01-31")
+        );
+    }
+}
@@ -0,0 +1,287 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{
+    DepositAccountHolderId, DepositAccountId, DepositAccountStatementId, UsdCents,
+};
+use audit::AuditInfo;
+
+use super::error::DepositAccountStatementError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum DepositAccountStatementStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "DepositAccountStatementId")]
+pub enum DepositAccountStatementEvent {
+    Initialized {
+        id: DepositAccountStatementId,
+        deposit_account_id: DepositAccountId,
+        account_holder_id: DepositAccountHolderId,
+        reference: String,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    FilesUploaded {
+        bucket: String,
+        pdf_path_in_bucket: String,
+        csv_path_in_bucket: String,
+        opening_balance: UsdCents,
+        closing_balance: UsdCents,
+        audit_info: AuditInfo,
+        recorded_at: DateTime<Utc>,
+    },
+    GenerationFailed {
+        error: String,
+        audit_info: AuditInfo,
+        recorded_at: DateTime<Utc>,
+    },
+    DownloadLinksGenerated {
+        audit_info: AuditInfo,
+        recorded_at: DateTime<Utc>,
+    },
+}
+
+#[derive(Debug)]
+pub struct DepositAccountStatementLocationInCloud<'a> {
+    pub bucket: &'a str,
+    pub pdf_path_in_bucket: &'a str,
+    pub csv_path_in_bucket: &'a str,
+}
+
+impl<'a> DepositAccountStatementLocationInCloud<'a> {
+    pub fn pdf(&self) -> cloud_storage::LocationInCloud<'a> {
+        cloud_storage::LocationInCloud {
+            bucket: self.bucket,
+            path_in_bucket: self.pdf_path_in_bucket,
+        }
+    }
+
+    pub fn csv(&self) -> cloud_storage::LocationInCloud<'a> {
+        cloud_storage::LocationInCloud {
+            bucket: self.bucket,
+            path_in_bucket: self.csv_path_in_bucket,
+        }
+    }
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct DepositAccountStatement {
+    pub id: DepositAccountStatementId,
+    pub deposit_account_id: DepositAccountId,
+    pub account_holder_id: DepositAccountHolderId,
+    pub reference: String,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    #[builder(setter(strip_option), default)]
+    pub opening_balance: Option<UsdCents>,
+    #[builder(setter(strip_option), default)]
+    pub closing_balance: Option<UsdCents>,
+
+    pub(super) events: EntityEvents<DepositAccountStatementEvent>,
+}
+
+impl DepositAccountStatement {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for deposit account statement")
+    }
+
+    pub fn status(&self) -> DepositAccountStatementStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                DepositAccountStatementEvent::FilesUploaded { .. } => {
+                    Some(DepositAccountStatementStatus::Completed)
+                }
+                DepositAccountStatementEvent::GenerationFailed { .. } => {
+                    Some(DepositAccountStatementStatus::Failed)
+                }
+                _ => None,
+            })
+            .unwrap_or(DepositAccountStatementStatus::Pending)
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            DepositAccountStatementEvent::GenerationFailed { error, .. } => Some(error.as_str()),
+            _ => None,
+        })
+    }
+
+    fn location(&self) -> Option<DepositAccountStatementLocationInCloud<'_>> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            DepositAccountStatementEvent::FilesUploaded {
+                bucket,
+                pdf_path_in_bucket,
+                csv_path_in_bucket,
+                ..
+            } => Some(DepositAccountStatementLocationInCloud {
+                bucket,
+                pdf_path_in_bucket,
+                csv_path_in_bucket,
+            }),
+            _ => None,
+        })
+    }
+
+    pub fn files_uploaded(
+        &mut self,
+        bucket: String,
+        pdf_path_in_bucket: String,
+        csv_path_in_bucket: String,
+        opening_balance: UsdCents,
+        closing_balance: UsdCents,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            DepositAccountStatementEvent::FilesUploaded { .. }
+        );
+
+        self.events
+            .push(DepositAccountStatementEvent::FilesUploaded {
+                bucket,
+                pdf_path_in_bucket,
+                csv_path_in_bucket,
+                opening_balance,
+                closing_balance,
+                audit_info,
+                recorded_at: Utc::now(),
+            });
+        self.opening_balance = Some(opening_balance);
+        self.closing_balance = Some(closing_balance);
+        Idempotent::Executed(())
+    }
+
+    pub fn generation_failed(&mut self, error: String, audit_info: AuditInfo) {
+        self.events
+            .push(DepositAccountStatementEvent::GenerationFailed {
+                error,
+                audit_info,
+                recorded_at: Utc::now(),
+            });
+    }
+
+    pub fn download_links_generated(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<DepositAccountStatementLocationInCloud<'_>, DepositAccountStatementError> {
+        if self.status() != DepositAccountStatementStatus::Completed {
+            return Err(DepositAccountStatementError::StatementNotReady);
+        }
+        if self.location().is_none() {
+            return Err(DepositAccountStatementError::StatementFilesNotFound);
+        }
+        self.events
+            .push(DepositAccountStatementEvent::DownloadLinksGenerated {
+                audit_info,
+                recorded_at: Utc::now(),
+            });
+        Ok(self.location().expect("location exists"))
+    }
+}
+
+impl TryFromEvents<DepositAccountStatementEvent> for DepositAccountStatement {
+    fn try_from_events(
+        events: EntityEvents<DepositAccountStatementEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = DepositAccountStatementBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                DepositAccountStatementEvent::Initialized {
+                    id,
+                    deposit_account_id,
+                    account_holder_id,
+                    reference,
+                    period_start,
+                    period_end,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .deposit_account_id(*deposit_account_id)
+                        .account_holder_id(*account_holder_id)
+                        .reference(reference.clone())
+                        .period_start(*period_start)
+                        .period_end(*period_end);
+                }
+                DepositAccountStatementEvent::FilesUploaded {
+                    opening_balance,
+                    closing_balance,
+                    ..
+                } => {
+                    builder = builder
+                        .opening_balance(*opening_balance)
+                        .closing_balance(*closing_balance);
+                }
+                DepositAccountStatementEvent::GenerationFailed { .. } => {}
+                DepositAccountStatementEvent::DownloadLinksGenerated { .. } => {}
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewDepositAccountStatement {
+    #[builder(setter(into))]
+    pub(super) id: DepositAccountStatementId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) account_holder_id: DepositAccountHolderId,
+    pub(super) period_start: NaiveDate,
+    pub(super) period_end: NaiveDate,
+    #[builder(setter(into))]
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewDepositAccountStatement {
+    pub fn builder() -> NewDepositAccountStatementBuilder {
+        NewDepositAccountStatementBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        statement_reference(self.deposit_account_id, self.period_start, self.period_end)
+    }
+}
+
+/// Statements are unique per account and period.
+pub(crate) fn statement_reference(
+    deposit_account_id: DepositAccountId,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+) -> String {
+    format!("{deposit_account_id}:{period_start}:{period_end}")
+}
+
+impl IntoEvents<DepositAccountStatementEvent> for NewDepositAccountStatement {
+    fn into_events(self) -> EntityEvents<DepositAccountStatementEvent> {
+        EntityEvents::init(
+            self.id,
+            [DepositAccountStatementEvent::Initialized {
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                account_holder_id: self.account_holder_id,
+                reference: self.reference(),
+                period_start: self.period_start,
+                period_end: self.period_end,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
@@ -0,0 +1,368 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use csv::Writer;
+use rust_decimal::Decimal;
+
+use cala_ledger::{Currency, DebitOrCredit, Layer};
+
+use crate::primitives::UsdCents;
+
+use super::{error::DepositAccountStatementError, pdf};
+
+const RECORD_DEPOSIT: &str = "RECORD_DEPOSIT_CR";
+const REVERT_DEPOSIT: &str = "REVERT_DEPOSIT_DR";
+const INITIATE_WITHDRAW: &str = "INITIATE_WITHDRAW_SETTLED_DR";
+const CANCEL_WITHDRAW: &str = "CANCEL_WITHDRAW_SETTLED_CR";
+const TRANSFER_IN: &str = "TRANSFER_SETTLED_CR";
+const TRANSFER_OUT: &str = "TRANSFER_SETTLED_DR";
+const CAPITALIZE_INTEREST: &str = "CAPITALIZE_DEPOSIT_INTEREST_CR";
+const DEPOSIT_FEE_PREFIX: &str = "CHARGE_DEPOSIT_FEE";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+pub enum StatementLineKind {
+    Deposit,
+    Withdrawal,
+    #[strum(serialize = "Transfer In")]
+    TransferIn,
+    #[strum(serialize = "Transfer Out")]
+    TransferOut,
+    Interest,
+    Fee,
+    Other,
+}
+
+impl StatementLineKind {
+    fn from_entry_type(entry_type: &str) -> Self {
+        match entry_type {
+            RECORD_DEPOSIT | REVERT_DEPOSIT => Self::Deposit,
+            INITIATE_WITHDRAW | CANCEL_WITHDRAW => Self::Withdrawal,
+            TRANSFER_IN => Self::TransferIn,
+            TRANSFER_OUT => Self::TransferOut,
+            CAPITALIZE_INTEREST => Self::Interest,
+            t if t.starts_with(DEPOSIT_FEE_PREFIX) => Self::Fee,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// The settled USD movement of a single ledger entry on the deposit account.
+#[derive(Debug, Clone)]
+pub(crate) struct StatementEntry {
+    pub recorded_at: DateTime<Utc>,
+    pub entry_type: String,
+    pub description: Option<String>,
+    pub direction: DebitOrCredit,
+    pub units: Decimal,
+}
+
+impl StatementEntry {
+    pub fn from_ledger_entry(entry: &cala_ledger::entry::Entry) -> Option<Self> {
+        let values = entry.values();
+        if !matches!(values.layer, Layer::Settled) || values.currency != Currency::USD {
+            return None;
+        }
+        Some(Self {
+            recorded_at: entry.created_at(),
+            entry_type: values.entry_type.clone(),
+            description: values.description.clone(),
+            direction: values.direction,
+            units: values.units,
+        })
+    }
+
+    /// Deposit accounts are credit normal so credits increase the balance.
+    fn signed_units(&self) -> Decimal {
+        match self.direction {
+            DebitOrCredit::Credit => self.units,
+            DebitOrCredit::Debit => -self.units,
+        }
+    }
+}
+
+pub struct StatementLine {
+    pub recorded_at: DateTime<Utc>,
+    pub kind: StatementLineKind,
+    pub description: String,
+    pub credit: Decimal,
+    pub debit: Decimal,
+    pub balance: Decimal,
+}
+
+pub struct StatementContent {
+    pub account_reference: String,
+    pub account_name: String,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub opening_balance: UsdCents,
+    pub closing_balance: UsdCents,
+    pub lines: Vec<StatementLine>,
+}
+
+impl StatementContent {
+    /// Reconstructs the statement from the current settled balance and every
+    /// entry posted since the start of the period (newest first), walking the
+    /// balance back to the end and the start of the period.
+    pub(crate) fn build(
+        account_reference: String,
+        account_name: String,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        settled_balance: UsdCents,
+        entries: Vec<StatementEntry>,
+    ) -> Result<Self, DepositAccountStatementError> {
+        let period_end_exclusive = period_end
+            .succ_opt()
+            .expect("date out of range")
+            .and_hms_opt(0, 0, 0)
+            .expect("valid time")
+            .and_utc();
+
+        let (after_period, mut in_period): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .partition(|entry| entry.recorded_at >= period_end_exclusive);
+
+        let closing = settled_balance.to_usd()
+            - after_period
+                .iter()
+                .map(StatementEntry::signed_units)
+                .sum::<Decimal>();
+        let opening = closing
+            - in_period
+                .iter()
+                .map(StatementEntry::signed_units)
+                .sum::<Decimal>();
+
+        in_period.sort_by_key(|entry| entry.recorded_at);
+        let mut balance = opening;
+        let lines = in_period
+            .into_iter()
+            .map(|entry| {
+                balance += entry.signed_units();
+                let kind = StatementLineKind::from_entry_type(&entry.entry_type);
+                let (credit, debit) = match entry.direction {
+                    DebitOrCredit::Credit => (entry.units, Decimal::ZERO),
+                    DebitOrCredit::Debit => (Decimal::ZERO, entry.units),
+                };
+                StatementLine {
+                    recorded_at: entry.recorded_at,
+                    kind,
+                    description: entry.description.unwrap_or_else(|| kind.to_string()),
+                    credit,
+                    debit,
+                    balance,
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            account_reference,
+            account_name,
+            period_start,
+            period_end,
+            opening_balance: UsdCents::try_from_usd(opening)?,
+            closing_balance: UsdCents::try_from_usd(closing)?,
+            lines,
+        })
+    }
+
+    pub fn total_for(&self, kind: StatementLineKind) -> Decimal {
+        self.lines
+            .iter()
+            .filter(|line| line.kind == kind)
+            .map(|line| line.credit - line.debit)
+            .sum()
+    }
+
+    pub fn to_csv(&self) -> Result<Vec<u8>, DepositAccountStatementError> {
+        let mut wtr = Writer::from_writer(vec![]);
+        wtr.write_record([
+            "Recorded At",
+            "Type",
+            "Description",
+            "Credit Amount",
+            "Debit Amount",
+            "Balance",
+        ])
+        .map_err(|e| DepositAccountStatementError::CsvError(e.to_string()))?;
+
+        wtr.write_record([
+            self.period_start.to_string(),
+            "Opening Balance".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            self.opening_balance.to_usd().to_string(),
+        ])
+        .map_err(|e| DepositAccountStatementError::CsvError(e.to_string()))?;
+
+        for line in self.lines.iter() {
+            wtr.write_record([
+                line.recorded_at.to_rfc3339(),
+                line.kind.to_string(),
+                line.description.clone(),
+                line.credit.to_string(),
+                line.debit.to_string(),
+                line.balance.to_string(),
+            ])
+            .map_err(|e| DepositAccountStatementError::CsvError(e.to_string()))?;
+        }
+
+        wtr.write_record([
+            self.period_end.to_string(),
+            "Closing Balance".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            self.closing_balance.to_usd().to_string(),
+        ])
+        .map_err(|e| DepositAccountStatementError::CsvError(e.to_string()))?;
+
+        wtr.into_inner()
+            .map_err(|e| DepositAccountStatementError::CsvError(e.to_string()))
+    }
+
+    pub fn to_pdf(&self) -> Vec<u8> {
+        let mut lines = vec![
+            "DEPOSIT ACCOUNT STATEMENT".to_string(),
+            String::new(),
+            format!(
+                "Account:  {} ({})",
+                self.account_name, self.account_reference
+            ),
+            format!("Period:   {} to {}", self.period_start, self.period_end),
+            String::new(),
+            format!(
+                "Opening balance:  {:>16}",
+                self.opening_balance.to_usd().to_string()
+            ),
+        ];
+        for kind in [
+            StatementLineKind::Deposit,
+            StatementLineKind::Withdrawal,
+            StatementLineKind::TransferIn,
+            StatementLineKind::TransferOut,
+            StatementLineKind::Interest,
+            StatementLineKind::Fee,
+            StatementLineKind::Other,
+        ] {
+            let total = self.total_for(kind);
+            if !total.is_zero() {
+                lines.push(format!(
+                    "{:<18}{:>16}",
+                    format!("{kind}:"),
+                    total.to_string()
+                ));
+            }
+        }
+        lines.push(format!(
+            "Closing balance:  {:>16}",
+            self.closing_balance.to_usd().to_string()
+        ));
+        lines.push(String::new());
+        lines.push(format!(
+            "{:<12}{:<14}{:<30}{:>14}{:>14}{:>16}",
+            "Date", "Type", "Description", "Credit", "Debit", "Balance"
+        ));
+        for line in self.lines.iter() {
+            let description: String = line.description.chars().take(28).collect();
+            lines.push(format!(
+                "{:<12}{:<14}{:<30}{:>14}{:>14}{:>16}",
+                line.recorded_at.date_naive().to_string(),
+                line.kind.to_string(),
+                description,
+                line.credit.to_string(),
+                line.debit.to_string(),
+                line.balance.to_string()
+            ));
+        }
+        pdf::render(&lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn entry(
+        recorded_at: &str,
+        entry_type: &str,
+        direction: DebitOrCredit,
+        units: Decimal,
+    ) -> StatementEntry {
+        StatementEntry {
+            recorded_at: recorded_at.parse().unwrap(),
+            entry_type: entry_type.to_string(),
+            description: None,
+            direction,
+            units,
+        }
+    }
+
+    #[test]
+    fn reconstructs_opening_and_closing_balances() {
+        let entries = vec![
+            entry(
+                "2025-02-03T00:00:00Z",
+                RECORD_DEPOSIT,
+                DebitOrCredit::Credit,
+                dec!(50),
+            ),
+            entry(
+                "2025-01-20T00:00:00Z",
+                INITIATE_WITHDRAW,
+                DebitOrCredit::Debit,
+                dec!(30),
+            ),
+            entry(
+                "2025-01-05T00:00:00Z",
+                RECORD_DEPOSIT,
+                DebitOrCredit::Credit,
+                dec!(100),
+            ),
+        ];
+        let statement = StatementContent::build(
+            "ref".to_string(),
+            "name".to_string(),
+            "2025-01-01".parse().unwrap(),
+            "2025-01-31".parse().unwrap(),
+            UsdCents::try_from_usd(dec!(170)).unwrap(),
+            entries,
+        )
+        .unwrap();
+
+        assert_eq!(
+            statement.opening_balance,
+            UsdCents::try_from_usd(dec!(50)).unwrap()
+        );
+        assert_eq!(
+            statement.closing_balance,
+            UsdCents::try_from_usd(dec!(120)).unwrap()
+        );
+        assert_eq!(statement.lines.len(), 2);
+        assert_eq!(statement.lines[0].kind, StatementLineKind::Deposit);
+        assert_eq!(statement.lines[1].balance, dec!(120));
+        assert_eq!(
+            statement.total_for(StatementLineKind::Withdrawal),
+            dec!(-30)
+        );
+    }
+
+    #[test]
+    fn renders_pdf_and_csv() {
+        let statement = StatementContent::build(
+            "ref".to_string(),
+            "name".to_string(),
+            "2025-01-01".parse().unwrap(),
+            "2025-01-31".parse().unwrap(),
+            UsdCents::ZERO,
+            vec![],
+        )
+        .unwrap();
+
+        assert!(statement.to_pdf().starts_with(b"%PDF-1.4"));
+        let csv = String::from_utf8(statement.to_csv().unwrap()).unwrap();
+        assert!(csv.contains("Opening Balance"));
+        assert!(csv.contains("Closing Balance"));
+    }
+}
@@ -0,0 +1,246 @@
+mod entity;
+pub mod error;
+mod generate;
+mod pdf;
+mod repo;
+
+pub(super) use entity::*;
+pub use entity::{DepositAccountStatement, DepositAccountStatementStatus};
+pub use generate::StatementContent;
+pub(super) use generate::StatementEntry;
+pub use repo::deposit_account_statement_cursor::DepositAccountStatementsByCreatedAtCursor;
+pub(super) use repo::*;
+
+use audit::{AuditInfo, AuditSvc};
+use authz::PermissionCheck;
+use cloud_storage::Storage;
+use job::Jobs;
+use tracing::instrument;
+
+use crate::{
+    account::DepositAccountRepo, error::CoreDepositError,
+    jobs::generate_statement::GenerateStatementJobConfig, primitives::*,
+};
+
+#[derive(Debug, Clone)]
+pub struct DepositAccountStatementDownloadLinks {
+    pub deposit_account_statement_id: DepositAccountStatementId,
+    pub pdf_url: String,
+    pub csv_url: String,
+}
+
+pub(crate) async fn statement_download_links(
+    storage: &Storage,
+    statement: &mut DepositAccountStatement,
+    audit_info: AuditInfo,
+) -> Result<DepositAccountStatementDownloadLinks, CoreDepositError> {
+    let deposit_account_statement_id = statement.id;
+    let location = statement.download_links_generated(audit_info)?;
+    let pdf_url = storage.generate_download_link(location.pdf()).await?;
+    let csv_url = storage.generate_download_link(location.csv()).await?;
+    Ok(DepositAccountStatementDownloadLinks {
+        deposit_account_statement_id,
+        pdf_url,
+        csv_url,
+    })
+}
+
+pub struct Statements<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Perms,
+    repo: DepositAccountStatementRepo,
+    accounts: DepositAccountRepo,
+    jobs: Jobs,
+    storage: Storage,
+}
+
+impl<Perms> Clone for Statements<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            repo: self.repo.clone(),
+            accounts: self.accounts.clone(),
+            jobs: self.jobs.clone(),
+            storage: self.storage.clone(),
+        }
+    }
+}
+
+impl<Perms> Statements<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub(crate) fn new(
+        authz: &Perms,
+        repo: &DepositAccountStatementRepo,
+        accounts: &DepositAccountRepo,
+        jobs: &Jobs,
+        storage: &Storage,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            repo: repo.clone(),
+            accounts: accounts.clone(),
+            jobs: jobs.clone(),
+            storage: storage.clone(),
+        }
+    }
+
+    pub(crate) fn repo(&self) -> &DepositAccountStatementRepo {
+        &self.repo
+    }
+
+    #[instrument(name = "deposit.generate_statement", skip(self), err)]
+    pub async fn generate_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        period_start: chrono::NaiveDate,
+        period_end: chrono::NaiveDate,
+    ) -> Result<DepositAccountStatement, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_statements(),
+                CoreDepositAction::STATEMENT_CREATE,
+            )
+            .await?;
+
+        if period_start > period_end || period_end >= chrono::Utc::now().date_naive() {
+            return Err(error::DepositAccountStatementError::InvalidPeriod(
+                period_start,
+                period_end,
+            )
+            .into());
+        }
+
+        let account = self.accounts.find_by_id(account_id).await?;
+        if account.currency != DepositAccountCurrency::Usd {
+            return Err(
+                error::DepositAccountStatementError::UnsupportedCurrency(account.currency).into(),
+            );
+        }
+
+        match self
+            .repo
+            .find_by_reference(statement_reference(account_id, period_start, period_end))
+            .await
+        {
+            Ok(statement) => return Ok(statement),
+            Err(e) if e.was_not_found() => (),
+            Err(e) => return Err(e.into()),
+        }
+
+        let new_statement = NewDepositAccountStatement::builder()
+            .id(DepositAccountStatementId::new())
+            .deposit_account_id(account_id)
+            .account_holder_id(account.account_holder_id)
+            .period_start(period_start)
+            .period_end(period_end)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new statement");
+
+        let mut op = self.repo.begin_op().await?;
+        let statement = self.repo.create_in_op(&mut op, new_statement).await?;
+        self.jobs
+            .create_and_spawn_in_op(
+                &mut op,
+                statement.id,
+                GenerateStatementJobConfig::<Perms> {
+                    deposit_account_statement_id: statement.id,
+                    _phantom: std::marker::PhantomData,
+                },
+            )
+            .await?;
+        op.commit().await?;
+
+        Ok(statement)
+    }
+
+    #[instrument(name = "deposit.find_statement_by_id", skip(self), err)]
+    pub async fn find_statement_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositAccountStatementId> + std::fmt::Debug,
+    ) -> Result<Option<DepositAccountStatement>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::statement(id),
+                CoreDepositAction::STATEMENT_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(statement) => Ok(Some(statement)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_statements_for_account", skip(self), err)]
+    pub async fn list_statements_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<DepositAccountStatement>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_statements(),
+                CoreDepositAction::STATEMENT_LIST,
+            )
+            .await?;
+        Ok(self
+            .repo
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[instrument(name = "deposit.generate_statement_download_links", skip(self), err)]
+    pub async fn generate_statement_download_links(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositAccountStatementId> + std::fmt::Debug,
+    ) -> Result<DepositAccountStatementDownloadLinks, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::statement(id),
+                CoreDepositAction::STATEMENT_GENERATE_DOWNLOAD_LINK,
+            )
+            .await?;
+
+        let mut statement = self.repo.find_by_id(id).await?;
+        let links = statement_download_links(&self.storage, &mut statement, audit_info).await?;
+        self.repo.update(&mut statement).await?;
+        Ok(links)
+    }
+
+    #[instrument(name = "deposit.find_all_statements", skip(self), err)]
+    pub async fn find_all_statements<T: From<DepositAccountStatement>>(
+        &self,
+        ids: &[DepositAccountStatementId],
+    ) -> Result<std::collections::HashMap<DepositAccountStatementId, T>, CoreDepositError> {
+        Ok(self.repo.find_all(ids).await?)
+    }
+}
@@ -0,0 +1,89 @@
+//! Minimal single-font PDF writer used to render statements without pulling in
+//! a full PDF toolkit. Each page holds a fixed number of monospaced text lines.
+
+const LINES_PER_PAGE: usize = 60;
+const FONT_SIZE: u32 = 9;
+const LEADING: u32 = 12;
+const PAGE_WIDTH: u32 = 612;
+const PAGE_HEIGHT: u32 = 792;
+const MARGIN: u32 = 40;
+
+pub(super) fn render(lines: &[String]) -> Vec<u8> {
+    let pages: Vec<&[String]> = if lines.is_empty() {
+        vec![&[]]
+    } else {
+        lines.chunks(LINES_PER_PAGE).collect()
+    };
+
+    // Object layout: 1 catalog, 2 page tree, 3 font, then a page and a
+    // content stream object for every page.
+    let mut objects: Vec<Vec<u8>> = Vec::new();
+    let page_ids: Vec<usize> = (0..pages.len()).map(|i| 4 + i * 2).collect();
+    let kids = page_ids
+        .iter()
+        .map(|id| format!("{id} 0 R"))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+    objects.push(format!("<< /Type /Pages /Kids [{kids}] /Count {} >>", pages.len()).into_bytes());
+    objects.push(b"<< /Type /Font /Subtype /Type1 /BaseFont /Courier >>".to_vec());
+
+    for (page_id, page) in page_ids.iter().zip(pages) {
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                page_id + 1
+            )
+            .into_bytes(),
+        );
+        let mut content = format!(
+            "BT /F1 {FONT_SIZE} Tf {LEADING} TL {MARGIN} {} Td\n",
+            PAGE_HEIGHT - MARGIN
+        );
+        for line in page {
+            content.push_str(&format!("({}) '\n", escape(line)));
+        }
+        content.push_str("ET");
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend_from_slice(content.as_bytes());
+        stream.extend_from_slice(b"\nendstream");
+        objects.push(stream);
+    }
+
+    let mut out = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (idx, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend_from_slice(format!("{} 0 obj\n", idx + 1).as_bytes());
+        out.extend_from_slice(object);
+        out.extend_from_slice(b"\nendobj\n");
+    }
+    let xref_offset = out.len();
+    out.extend_from_slice(format!("xref\n0 {}\n", objects.len() + 1).as_bytes());
+    out.extend_from_slice(b"0000000000 65535 f \n");
+    for offset in offsets {
+        out.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    out.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .as_bytes(),
+    );
+    out
+}
+
+fn escape(line: &str) -> String {
+    line.chars()
+        .filter(|c| c.is_ascii() && !c.is_ascii_control())
+        .fold(String::with_capacity(line.len()), |mut acc, c| {
+            if matches!(c, '(' | ')' | '\\') {
+                acc.push('\\');
+            }
+            acc.push(c);
+            acc
+        })
+}
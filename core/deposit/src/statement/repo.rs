@@ -0,0 +1,33 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{DepositAccountHolderId, DepositAccountId, DepositAccountStatementId};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "DepositAccountStatement",
+    err = "DepositAccountStatementError",
+    columns(
+        deposit_account_id(ty = "DepositAccountId", list_for, update(persist = false)),
+        account_holder_id(ty = "DepositAccountHolderId", list_for, update(persist = false)),
+        reference(
+            ty = "String",
+            create(accessor = "reference()"),
+            update(persist = false)
+        )
+    ),
+    tbl_prefix = "core"
+)]
+pub struct DepositAccountStatementRepo {
+    #[allow(dead_code)]
+    pool: PgPool,
+}
+
+impl DepositAccountStatementRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
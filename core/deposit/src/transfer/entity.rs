@@ -0,0 +1,244 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{
+    ApprovalProcessId, CalaTransactionId, DepositAccountId, TransferId, UsdCents,
+};
+use audit::AuditInfo;
+
+use super::error::TransferError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum TransferStatus {
+    PendingApproval,
+    Executed,
+    Denied,
+    Cancelled,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "TransferId")]
+pub enum TransferEvent {
+    Initialized {
+        id: TransferId,
+        from_deposit_account_id: DepositAccountId,
+        to_deposit_account_id: DepositAccountId,
+        amount: UsdCents,
+        reference: String,
+        approval_process_id: Option<ApprovalProcessId>,
+        audit_info: AuditInfo,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    },
+    Executed {
+        ledger_tx_id: CalaTransactionId,
+        audit_info: AuditInfo,
+    },
+    Cancelled {
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Transfer {
+    pub id: TransferId,
+    pub from_deposit_account_id: DepositAccountId,
+    pub to_deposit_account_id: DepositAccountId,
+    pub reference: String,
+    pub amount: UsdCents,
+    #[builder(default)]
+    pub approval_process_id: Option<ApprovalProcessId>,
+    #[builder(setter(strip_option), default)]
+    pub ledger_tx_id: Option<CalaTransactionId>,
+
+    pub(super) events: EntityEvents<TransferEvent>,
+}
+
+impl Transfer {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for transfer")
+    }
+
+    pub fn requires_approval(&self) -> bool {
+        self.approval_process_id.is_some()
+    }
+
+    pub fn execute(&mut self, audit_info: AuditInfo) -> Result<CalaTransactionId, TransferError> {
+        if self.requires_approval() && self.is_approved_or_denied() != Some(true) {
+            return Err(TransferError::NotApproved(self.id));
+        }
+
+        if self.is_executed() {
+            return Err(TransferError::AlreadyExecuted(self.id));
+        }
+
+        if self.is_cancelled() {
+            return Err(TransferError::AlreadyCancelled(self.id));
+        }
+
+        let ledger_tx_id = CalaTransactionId::from(self.id);
+        self.events.push(TransferEvent::Executed {
+            ledger_tx_id,
+            audit_info,
+        });
+        self.ledger_tx_id = Some(ledger_tx_id);
+
+        Ok(ledger_tx_id)
+    }
+
+    pub fn cancel(&mut self, audit_info: AuditInfo) -> Result<(), TransferError> {
+        if self.is_executed() {
+            return Err(TransferError::AlreadyExecuted(self.id));
+        }
+
+        if self.is_cancelled() {
+            return Err(TransferError::AlreadyCancelled(self.id));
+        }
+
+        self.events.push(TransferEvent::Cancelled { audit_info });
+
+        Ok(())
+    }
+
+    fn is_executed(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, TransferEvent::Executed { .. }))
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, TransferEvent::Cancelled { .. }))
+    }
+
+    pub fn is_approved_or_denied(&self) -> Option<bool> {
+        self.events.iter_all().find_map(|e| {
+            if let TransferEvent::ApprovalProcessConcluded { approved, .. } = e {
+                Some(*approved)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn status(&self) -> TransferStatus {
+        if self.is_executed() {
+            TransferStatus::Executed
+        } else if self.is_cancelled() {
+            TransferStatus::Cancelled
+        } else {
+            match self.is_approved_or_denied() {
+                Some(false) => TransferStatus::Denied,
+                _ => TransferStatus::PendingApproval,
+            }
+        }
+    }
+
+    pub fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            TransferEvent::ApprovalProcessConcluded { .. }
+        );
+        self.events.push(TransferEvent::ApprovalProcessConcluded {
+            approval_process_id: self.id.into(),
+            approved,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<TransferEvent> for Transfer {
+    fn try_from_events(events: EntityEvents<TransferEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = TransferBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                TransferEvent::Initialized {
+                    id,
+                    from_deposit_account_id,
+                    to_deposit_account_id,
+                    amount,
+                    reference,
+                    approval_process_id,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .from_deposit_account_id(*from_deposit_account_id)
+                        .to_deposit_account_id(*to_deposit_account_id)
+                        .amount(*amount)
+                        .reference(reference.clone())
+                        .approval_process_id(*approval_process_id)
+                }
+                TransferEvent::Executed { ledger_tx_id, .. } => {
+                    builder = builder.ledger_tx_id(*ledger_tx_id)
+                }
+                _ => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewTransfer {
+    #[builder(setter(into))]
+    pub(super) id: TransferId,
+    #[builder(setter(into))]
+    pub(super) from_deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) to_deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    #[builder(setter(into, strip_option), default)]
+    pub(super) approval_process_id: Option<ApprovalProcessId>,
+    reference: Option<String>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewTransfer {
+    pub fn builder() -> NewTransferBuilder {
+        NewTransferBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None => self.id.to_string(),
+            Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl IntoEvents<TransferEvent> for NewTransfer {
+    fn into_events(self) -> EntityEvents<TransferEvent> {
+        EntityEvents::init(
+            self.id,
+            [TransferEvent::Initialized {
+                reference: self.reference(),
+                id: self.id,
+                from_deposit_account_id: self.from_deposit_account_id,
+                to_deposit_account_id: self.to_deposit_account_id,
+                amount: self.amount,
+                approval_process_id: self.approval_process_id,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
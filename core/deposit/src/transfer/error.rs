@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+use crate::primitives::TransferId;
+
+#[derive(Error, Debug)]
+pub enum TransferError {
+    #[error("TransferError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("TransferError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("TransferError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("TransferError - AlreadyExecuted: {0}")]
+    AlreadyExecuted(TransferId),
+    #[error("TransferError - AlreadyCancelled: {0}")]
+    AlreadyCancelled(TransferId),
+    #[error("TransferError - NotApproved: {0}")]
+    NotApproved(TransferId),
+    #[error("TransferError - SameAccount")]
+    SameAccount,
+    #[error("TransferError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+}
+
+es_entity::from_es_entity_error!(TransferError);
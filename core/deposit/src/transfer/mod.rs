@@ -0,0 +1,8 @@
+mod entity;
+pub mod error;
+pub mod repo;
+
+pub(super) use entity::*;
+pub use entity::{Transfer, TransferStatus};
+pub use repo::transfer_cursor::TransfersByCreatedAtCursor;
+pub(super) use repo::*;
@@ -4,20 +4,34 @@ use serde::{Deserialize, Serialize};
 use es_entity::*;
 
 use crate::primitives::{
-    ApprovalProcessId, CalaTransactionId, DepositAccountId, UsdCents, WithdrawalId,
+    ApprovalProcessId, CalaTransactionId, DepositAccountId, DepositAmount, PaymentBatchId,
+    SignatoryId, WithdrawalId,
 };
 use audit::AuditInfo;
 
 use super::error::WithdrawalError;
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display, strum::EnumString,
+)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 pub enum WithdrawalStatus {
+    PendingSignatoryApproval,
     PendingApproval,
     PendingConfirmation,
     Confirmed,
     Denied,
     Cancelled,
+    PaymentBatched,
+    PaymentSent,
+    PaymentSettled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WithdrawalBeneficiary {
+    pub name: String,
+    pub account_number: String,
+    pub bic: Option<String>,
 }
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
@@ -27,9 +41,21 @@ pub enum WithdrawalEvent {
     Initialized {
         id: WithdrawalId,
         deposit_account_id: DepositAccountId,
-        amount: UsdCents,
+        amount: DepositAmount,
         reference: String,
         approval_process_id: ApprovalProcessId,
+        #[serde(default)]
+        beneficiary: Option<WithdrawalBeneficiary>,
+        #[serde(default)]
+        initiated_by: Option<SignatoryId>,
+        audit_info: AuditInfo,
+    },
+    SignatoryApproved {
+        signatory_id: SignatoryId,
+        audit_info: AuditInfo,
+    },
+    SignatoryRejected {
+        signatory_id: SignatoryId,
         audit_info: AuditInfo,
     },
     ApprovalProcessConcluded {
@@ -45,6 +71,16 @@ pub enum WithdrawalEvent {
         ledger_tx_id: CalaTransactionId,
         audit_info: AuditInfo,
     },
+    PaymentBatched {
+        payment_batch_id: PaymentBatchId,
+        audit_info: AuditInfo,
+    },
+    PaymentSent {
+        audit_info: AuditInfo,
+    },
+    PaymentSettled {
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -53,10 +89,18 @@ pub struct Withdrawal {
     pub id: WithdrawalId,
     pub deposit_account_id: DepositAccountId,
     pub reference: String,
-    pub amount: UsdCents,
+    pub amount: DepositAmount,
     pub approval_process_id: ApprovalProcessId,
     #[builder(setter(strip_option), default)]
     pub cancelled_tx_id: Option<CalaTransactionId>,
+    #[builder(default)]
+    pub beneficiary: Option<WithdrawalBeneficiary>,
+    #[builder(setter(strip_option), default)]
+    pub payment_batch_id: Option<PaymentBatchId>,
+    #[builder(default)]
+    pub initiated_by: Option<SignatoryId>,
+    #[builder(setter(strip_option), default)]
+    pub approved_by: Option<SignatoryId>,
 
     pub(super) events: EntityEvents<WithdrawalEvent>,
 }
@@ -111,6 +155,54 @@ impl Withdrawal {
         Ok(ledger_tx_id)
     }
 
+    /// Withdrawals initiated by a customer's signatory only enter the bank's approval
+    /// process once a second signatory of that customer has approved them.
+    pub fn is_pending_signatory_approval(&self) -> bool {
+        self.initiated_by.is_some() && self.approved_by.is_none() && !self.is_cancelled()
+    }
+
+    /// The bank's approval process is only started once signatory approval has been granted.
+    pub fn has_approval_process(&self) -> bool {
+        self.initiated_by.is_none() || self.approved_by.is_some()
+    }
+
+    pub fn approve_as_signatory(
+        &mut self,
+        signatory_id: SignatoryId,
+        audit_info: AuditInfo,
+    ) -> Result<(), WithdrawalError> {
+        if !self.is_pending_signatory_approval() {
+            return Err(WithdrawalError::NotPendingSignatoryApproval(self.id));
+        }
+        if self.initiated_by == Some(signatory_id) {
+            return Err(WithdrawalError::SelfApprovalNotAllowed(self.id));
+        }
+
+        self.events.push(WithdrawalEvent::SignatoryApproved {
+            signatory_id,
+            audit_info,
+        });
+        self.approved_by = Some(signatory_id);
+
+        Ok(())
+    }
+
+    pub fn reject_as_signatory(
+        &mut self,
+        signatory_id: SignatoryId,
+        audit_info: AuditInfo,
+    ) -> Result<CalaTransactionId, WithdrawalError> {
+        if !self.is_pending_signatory_approval() {
+            return Err(WithdrawalError::NotPendingSignatoryApproval(self.id));
+        }
+
+        self.events.push(WithdrawalEvent::SignatoryRejected {
+            signatory_id,
+            audit_info: audit_info.clone(),
+        });
+        self.cancel(audit_info)
+    }
+
     fn is_confirmed(&self) -> bool {
         self.events
             .iter_all()
@@ -133,11 +225,82 @@ impl Withdrawal {
             .any(|e| matches!(e, WithdrawalEvent::Cancelled { .. }))
     }
 
+    pub fn add_to_payment_batch(
+        &mut self,
+        payment_batch_id: PaymentBatchId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, WithdrawalError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            WithdrawalEvent::PaymentBatched { .. }
+        );
+
+        if !self.is_confirmed() {
+            return Err(WithdrawalError::NotConfirmed(self.id));
+        }
+
+        self.events.push(WithdrawalEvent::PaymentBatched {
+            payment_batch_id,
+            audit_info,
+        });
+        self.payment_batch_id = Some(payment_batch_id);
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn payment_sent(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, WithdrawalError> {
+        idempotency_guard!(self.events.iter_all(), WithdrawalEvent::PaymentSent { .. });
+
+        if self.payment_batch_id.is_none() {
+            return Err(WithdrawalError::NotInPaymentBatch(self.id));
+        }
+
+        self.events
+            .push(WithdrawalEvent::PaymentSent { audit_info });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn payment_settled(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, WithdrawalError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            WithdrawalEvent::PaymentSettled { .. }
+        );
+
+        if self.payment_batch_id.is_none() {
+            return Err(WithdrawalError::NotInPaymentBatch(self.id));
+        }
+
+        self.events
+            .push(WithdrawalEvent::PaymentSettled { audit_info });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    fn last_payment_status(&self) -> Option<WithdrawalStatus> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            WithdrawalEvent::PaymentBatched { .. } => Some(WithdrawalStatus::PaymentBatched),
+            WithdrawalEvent::PaymentSent { .. } => Some(WithdrawalStatus::PaymentSent),
+            WithdrawalEvent::PaymentSettled { .. } => Some(WithdrawalStatus::PaymentSettled),
+            _ => None,
+        })
+    }
+
     pub fn status(&self) -> WithdrawalStatus {
-        if self.is_confirmed() {
+        if let Some(status) = self.last_payment_status() {
+            status
+        } else if self.is_confirmed() {
             WithdrawalStatus::Confirmed
         } else if self.is_cancelled() {
             WithdrawalStatus::Cancelled
+        } else if self.is_pending_signatory_approval() {
+            WithdrawalStatus::PendingSignatoryApproval
         } else {
             match self.is_approved_or_denied() {
                 Some(true) => WithdrawalStatus::PendingConfirmation,
@@ -176,6 +339,8 @@ impl TryFromEvents<WithdrawalEvent> for Withdrawal {
                     deposit_account_id,
                     amount,
                     approval_process_id,
+                    beneficiary,
+                    initiated_by,
                     ..
                 } => {
                     builder = builder
@@ -184,10 +349,18 @@ impl TryFromEvents<WithdrawalEvent> for Withdrawal {
                         .amount(*amount)
                         .reference(reference.clone())
                         .approval_process_id(*approval_process_id)
+                        .beneficiary(beneficiary.clone())
+                        .initiated_by(*initiated_by)
+                }
+                WithdrawalEvent::SignatoryApproved { signatory_id, .. } => {
+                    builder = builder.approved_by(*signatory_id)
                 }
                 WithdrawalEvent::Cancelled { ledger_tx_id, .. } => {
                     builder = builder.cancelled_tx_id(*ledger_tx_id)
                 }
+                WithdrawalEvent::PaymentBatched {
+                    payment_batch_id, ..
+                } => builder = builder.payment_batch_id(*payment_batch_id),
                 _ => (),
             }
         }
@@ -202,10 +375,14 @@ pub struct NewWithdrawal {
     #[builder(setter(into))]
     pub(super) deposit_account_id: DepositAccountId,
     #[builder(setter(into))]
-    pub(super) amount: UsdCents,
+    pub(super) amount: DepositAmount,
     #[builder(setter(into))]
     pub(super) approval_process_id: ApprovalProcessId,
     reference: Option<String>,
+    #[builder(default)]
+    beneficiary: Option<WithdrawalBeneficiary>,
+    #[builder(default)]
+    initiated_by: Option<SignatoryId>,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
 }
@@ -222,6 +399,14 @@ impl NewWithdrawal {
             Some(reference) => reference.to_string(),
         }
     }
+
+    pub(super) fn status(&self) -> WithdrawalStatus {
+        if self.initiated_by.is_some() {
+            WithdrawalStatus::PendingSignatoryApproval
+        } else {
+            WithdrawalStatus::PendingApproval
+        }
+    }
 }
 
 impl IntoEvents<WithdrawalEvent> for NewWithdrawal {
@@ -234,8 +419,104 @@ impl IntoEvents<WithdrawalEvent> for NewWithdrawal {
                 deposit_account_id: self.deposit_account_id,
                 amount: self.amount,
                 approval_process_id: self.approval_process_id,
+                beneficiary: self.beneficiary,
+                initiated_by: self.initiated_by,
                 audit_info: self.audit_info,
             }],
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use crate::primitives::UsdCents;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn withdrawal_initiated_by(initiated_by: Option<SignatoryId>) -> Withdrawal {
+        let id = WithdrawalId::new();
+        let new_withdrawal = NewWithdrawal::builder()
+            .id(id)
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .approval_process_id(id)
+            .reference(None)
+            .initiated_by(initiated_by)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        Withdrawal::try_from_events(new_withdrawal.into_events()).unwrap()
+    }
+
+    #[test]
+    fn withdrawal_initiated_by_signatory_awaits_signatory_approval() {
+        let withdrawal = withdrawal_initiated_by(Some(SignatoryId::new()));
+        assert_eq!(
+            withdrawal.status(),
+            WithdrawalStatus::PendingSignatoryApproval
+        );
+        assert!(!withdrawal.has_approval_process());
+    }
+
+    #[test]
+    fn withdrawal_initiated_by_bank_skips_signatory_approval() {
+        let mut withdrawal = withdrawal_initiated_by(None);
+        assert_eq!(withdrawal.status(), WithdrawalStatus::PendingApproval);
+        assert!(withdrawal.has_approval_process());
+        assert!(matches!(
+            withdrawal.approve_as_signatory(SignatoryId::new(), dummy_audit_info()),
+            Err(WithdrawalError::NotPendingSignatoryApproval(_))
+        ));
+    }
+
+    #[test]
+    fn initiator_cannot_approve_own_withdrawal() {
+        let initiator = SignatoryId::new();
+        let mut withdrawal = withdrawal_initiated_by(Some(initiator));
+        assert!(matches!(
+            withdrawal.approve_as_signatory(initiator, dummy_audit_info()),
+            Err(WithdrawalError::SelfApprovalNotAllowed(_))
+        ));
+        assert_eq!(
+            withdrawal.status(),
+            WithdrawalStatus::PendingSignatoryApproval
+        );
+    }
+
+    #[test]
+    fn second_signatory_approval_starts_approval_process() {
+        let mut withdrawal = withdrawal_initiated_by(Some(SignatoryId::new()));
+        withdrawal
+            .approve_as_signatory(SignatoryId::new(), dummy_audit_info())
+            .unwrap();
+        assert_eq!(withdrawal.status(), WithdrawalStatus::PendingApproval);
+        assert!(withdrawal.has_approval_process());
+        assert!(matches!(
+            withdrawal.approve_as_signatory(SignatoryId::new(), dummy_audit_info()),
+            Err(WithdrawalError::NotPendingSignatoryApproval(_))
+        ));
+    }
+
+    #[test]
+    fn signatory_rejection_cancels_withdrawal() {
+        let mut withdrawal = withdrawal_initiated_by(Some(SignatoryId::new()));
+        withdrawal
+            .reject_as_signatory(SignatoryId::new(), dummy_audit_info())
+            .unwrap();
+        assert_eq!(withdrawal.status(), WithdrawalStatus::Cancelled);
+        assert!(withdrawal.cancelled_tx_id.is_some());
+        assert!(matches!(
+            withdrawal.reject_as_signatory(SignatoryId::new(), dummy_audit_info()),
+            Err(WithdrawalError::NotPendingSignatoryApproval(_))
+        ));
+    }
+}
@@ -16,6 +16,14 @@ pub enum WithdrawalError {
     AlreadyCancelled(WithdrawalId),
     #[error("WithdrawalError - NotApproved: {0}")]
     NotApproved(WithdrawalId),
+    #[error("WithdrawalError - NotConfirmed: {0}")]
+    NotConfirmed(WithdrawalId),
+    #[error("WithdrawalError - NotInPaymentBatch: {0}")]
+    NotInPaymentBatch(WithdrawalId),
+    #[error("WithdrawalError - NotPendingSignatoryApproval: {0}")]
+    NotPendingSignatoryApproval(WithdrawalId),
+    #[error("WithdrawalError - SelfApprovalNotAllowed: {0}")]
+    SelfApprovalNotAllowed(WithdrawalId),
     #[error("WithdrawalError - AuditError: {0}")]
     AuditError(#[from] audit::error::AuditError),
 }
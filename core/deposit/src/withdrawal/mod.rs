@@ -3,6 +3,6 @@ pub mod error;
 pub mod repo;
 
 pub(super) use entity::*;
-pub use entity::{Withdrawal, WithdrawalStatus};
+pub use entity::{Withdrawal, WithdrawalBeneficiary, WithdrawalStatus};
 pub use repo::withdrawal_cursor::WithdrawalsByCreatedAtCursor;
 pub(super) use repo::*;
@@ -0,0 +1,151 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use audit::AuditInfo;
+
+use crate::primitives::*;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "WithdrawalLimitId")]
+pub enum WithdrawalLimitEvent {
+    Initialized {
+        id: WithdrawalLimitId,
+        name: String,
+        daily_limit: UsdCents,
+        monthly_limit: UsdCents,
+        kyc_level: Option<KycLevel>,
+        audit_info: AuditInfo,
+    },
+    LimitsUpdated {
+        daily_limit: UsdCents,
+        monthly_limit: UsdCents,
+        account_set_id: CalaAccountSetId,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct WithdrawalLimit {
+    pub id: WithdrawalLimitId,
+    pub name: String,
+    pub daily_limit: UsdCents,
+    pub monthly_limit: UsdCents,
+    pub kyc_level: Option<KycLevel>,
+    pub(crate) account_set_id: CalaAccountSetId,
+    pub(super) events: EntityEvents<WithdrawalLimitEvent>,
+}
+
+impl WithdrawalLimit {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("Withdrawal Limit has never been persisted")
+    }
+
+    pub fn applies_to_segment(&self, kyc_level: KycLevel) -> bool {
+        self.kyc_level == Some(kyc_level)
+    }
+
+    /// Returns the ledger account set that has to carry the new limits, the
+    /// previous set keeps its control and is drained by the caller.
+    pub(crate) fn update_limits(
+        &mut self,
+        daily_limit: UsdCents,
+        monthly_limit: UsdCents,
+        audit_info: AuditInfo,
+    ) -> Idempotent<CalaAccountSetId> {
+        if self.daily_limit == daily_limit && self.monthly_limit == monthly_limit {
+            return Idempotent::Ignored;
+        }
+
+        let account_set_id = CalaAccountSetId::new();
+        self.events.push(WithdrawalLimitEvent::LimitsUpdated {
+            daily_limit,
+            monthly_limit,
+            account_set_id,
+            audit_info,
+        });
+        self.daily_limit = daily_limit;
+        self.monthly_limit = monthly_limit;
+        self.account_set_id = account_set_id;
+        Idempotent::Executed(account_set_id)
+    }
+}
+
+impl TryFromEvents<WithdrawalLimitEvent> for WithdrawalLimit {
+    fn try_from_events(events: EntityEvents<WithdrawalLimitEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = WithdrawalLimitBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                WithdrawalLimitEvent::Initialized {
+                    id,
+                    name,
+                    daily_limit,
+                    monthly_limit,
+                    kyc_level,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .name(name.to_string())
+                        .daily_limit(*daily_limit)
+                        .monthly_limit(*monthly_limit)
+                        .kyc_level(*kyc_level)
+                        .account_set_id((*id).into())
+                }
+                WithdrawalLimitEvent::LimitsUpdated {
+                    daily_limit,
+                    monthly_limit,
+                    account_set_id,
+                    ..
+                } => {
+                    builder = builder
+                        .daily_limit(*daily_limit)
+                        .monthly_limit(*monthly_limit)
+                        .account_set_id(*account_set_id)
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewWithdrawalLimit {
+    #[builder(setter(into))]
+    pub(super) id: WithdrawalLimitId,
+    #[builder(setter(into))]
+    pub(super) name: String,
+    pub(super) daily_limit: UsdCents,
+    pub(super) monthly_limit: UsdCents,
+    #[builder(default)]
+    pub(super) kyc_level: Option<KycLevel>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewWithdrawalLimit {
+    pub fn builder() -> NewWithdrawalLimitBuilder {
+        NewWithdrawalLimitBuilder::default()
+    }
+}
+
+impl IntoEvents<WithdrawalLimitEvent> for NewWithdrawalLimit {
+    fn into_events(self) -> EntityEvents<WithdrawalLimitEvent> {
+        EntityEvents::init(
+            self.id,
+            [WithdrawalLimitEvent::Initialized {
+                id: self.id,
+                name: self.name,
+                daily_limit: self.daily_limit,
+                monthly_limit: self.monthly_limit,
+                kyc_level: self.kyc_level,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
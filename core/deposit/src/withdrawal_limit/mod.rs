@@ -0,0 +1,619 @@
+mod entity;
+pub mod error;
+mod repo;
+
+pub use entity::WithdrawalLimit;
+pub(crate) use entity::*;
+pub use repo::withdrawal_limit_cursor::WithdrawalLimitsByCreatedAtCursor;
+pub(crate) use repo::*;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use chrono::Datelike;
+use job::Jobs;
+use outbox::OutboxEventMarker;
+use tracing::instrument;
+
+use crate::{
+    account::{DepositAccount, DepositAccountRepo},
+    error::CoreDepositError,
+    event::CoreDepositEvent,
+    jobs::withdrawal_limit_override_expiry::WithdrawalLimitOverrideExpiryJobConfig,
+    ledger::DepositLedger,
+    primitives::*,
+    withdrawal::{WithdrawalRepo, WithdrawalStatus, WithdrawalsByCreatedAtCursor},
+    withdrawal_limit_override::{
+        error::WithdrawalLimitOverrideError, NewWithdrawalLimitOverride, WithdrawalLimitOverride,
+        WithdrawalLimitOverrideRepo,
+    },
+};
+
+pub struct WithdrawalLimits<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    authz: Perms,
+    repo: WithdrawalLimitRepo,
+    overrides: WithdrawalLimitOverrideRepo,
+    accounts: DepositAccountRepo,
+    withdrawals: WithdrawalRepo<E>,
+    ledger: DepositLedger,
+    jobs: Jobs,
+}
+
+impl<Perms, E> Clone for WithdrawalLimits<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            repo: self.repo.clone(),
+            overrides: self.overrides.clone(),
+            accounts: self.accounts.clone(),
+            withdrawals: self.withdrawals.clone(),
+            ledger: self.ledger.clone(),
+            jobs: self.jobs.clone(),
+        }
+    }
+}
+
+impl<Perms, E> WithdrawalLimits<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub(crate) fn new(
+        authz: &Perms,
+        repo: &WithdrawalLimitRepo,
+        overrides: &WithdrawalLimitOverrideRepo,
+        accounts: &DepositAccountRepo,
+        withdrawals: &WithdrawalRepo<E>,
+        ledger: &DepositLedger,
+        jobs: &Jobs,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            repo: repo.clone(),
+            overrides: overrides.clone(),
+            accounts: accounts.clone(),
+            withdrawals: withdrawals.clone(),
+            ledger: ledger.clone(),
+            jobs: jobs.clone(),
+        }
+    }
+
+    #[instrument(name = "deposit.create_withdrawal_limit", skip(self), err)]
+    pub async fn create_withdrawal_limit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        name: impl Into<String> + std::fmt::Debug,
+        daily_limit: UsdCents,
+        monthly_limit: UsdCents,
+        kyc_level: Option<KycLevel>,
+    ) -> Result<WithdrawalLimit, CoreDepositError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_withdrawal_limits(),
+                CoreDepositAction::WITHDRAWAL_LIMIT_CREATE,
+            )
+            .await?;
+
+        self.create_withdrawal_limit_with_audit(
+            name.into(),
+            daily_limit,
+            monthly_limit,
+            kyc_level,
+            audit_info,
+        )
+        .await
+    }
+
+    pub(crate) async fn create_withdrawal_limit_with_audit(
+        &self,
+        name: String,
+        daily_limit: UsdCents,
+        monthly_limit: UsdCents,
+        kyc_level: Option<KycLevel>,
+        audit_info: audit::AuditInfo,
+    ) -> Result<WithdrawalLimit, CoreDepositError> {
+        let id = WithdrawalLimitId::new();
+        self.ledger
+            .create_withdrawal_limit(id.into(), &name, daily_limit, monthly_limit)
+            .await?;
+
+        let new_withdrawal_limit = NewWithdrawalLimit::builder()
+            .id(id)
+            .name(name)
+            .daily_limit(daily_limit)
+            .monthly_limit(monthly_limit)
+            .kyc_level(kyc_level)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new withdrawal limit");
+
+        Ok(self.repo.create(new_withdrawal_limit).await?)
+    }
+
+    /// Changes the limit amounts for every account governed by the withdrawal limit.
+    #[instrument(name = "deposit.update_withdrawal_limit", skip(self), err)]
+    pub async fn update_withdrawal_limit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<WithdrawalLimitId> + std::fmt::Debug,
+        daily_limit: UsdCents,
+        monthly_limit: UsdCents,
+    ) -> Result<WithdrawalLimit, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::withdrawal_limit(id),
+                CoreDepositAction::WITHDRAWAL_LIMIT_UPDATE,
+            )
+            .await?;
+
+        let mut withdrawal_limit = self.repo.find_by_id(id).await?;
+        let previous = withdrawal_limit.account_set_id;
+        let account_set_id =
+            match withdrawal_limit.update_limits(daily_limit, monthly_limit, audit_info) {
+                es_entity::Idempotent::Executed(account_set_id) => account_set_id,
+                es_entity::Idempotent::Ignored => return Ok(withdrawal_limit),
+            };
+        self.ledger
+            .create_withdrawal_limit(
+                account_set_id,
+                &withdrawal_limit.name,
+                daily_limit,
+                monthly_limit,
+            )
+            .await?;
+
+        let mut op = self.repo.begin_op().await?;
+        self.repo
+            .update_in_op(&mut op, &mut withdrawal_limit)
+            .await?;
+        self.ledger
+            .replace_withdrawal_limit(op, previous, account_set_id)
+            .await?;
+        Ok(withdrawal_limit)
+    }
+
+    async fn withdrawal_limit_account_set(
+        &self,
+        withdrawal_limit_id: Option<WithdrawalLimitId>,
+    ) -> Result<Option<CalaAccountSetId>, CoreDepositError> {
+        match withdrawal_limit_id {
+            Some(id) => Ok(Some(self.repo.find_by_id(id).await?.account_set_id)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(name = "deposit.find_withdrawal_limit_by_id", skip(self), err)]
+    pub async fn find_withdrawal_limit_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<WithdrawalLimitId> + std::fmt::Debug,
+    ) -> Result<Option<WithdrawalLimit>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::withdrawal_limit(id),
+                CoreDepositAction::WITHDRAWAL_LIMIT_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(withdrawal_limit) => Ok(Some(withdrawal_limit)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_withdrawal_limits", skip(self), err)]
+    pub async fn list_withdrawal_limits(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<WithdrawalLimitsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<WithdrawalLimit, WithdrawalLimitsByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_withdrawal_limits(),
+                CoreDepositAction::WITHDRAWAL_LIMIT_LIST,
+            )
+            .await?;
+        Ok(self
+            .repo
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.assign_withdrawal_limit", skip(self), err)]
+    pub async fn assign_withdrawal_limit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        withdrawal_limit_id: Option<WithdrawalLimitId>,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_ASSIGN_WITHDRAWAL_LIMIT,
+            )
+            .await?;
+
+        if let Some(withdrawal_limit_id) = withdrawal_limit_id {
+            self.repo.find_by_id(withdrawal_limit_id).await?;
+        }
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        if account.currency != DepositAccountCurrency::Usd {
+            return Err(CoreDepositError::DepositAccountCurrencyMismatch(
+                account.currency,
+                DepositAccountCurrency::Usd,
+            ));
+        }
+        let previous = match account.assign_withdrawal_limit(
+            withdrawal_limit_id,
+            WithdrawalLimitSource::Account,
+            audit_info,
+        ) {
+            es_entity::Idempotent::Executed(previous) => previous,
+            es_entity::Idempotent::Ignored => return Ok(account),
+        };
+
+        let override_in_effect = self.find_override_in_effect(account_id).await?.is_some();
+        let mut op = self.accounts.begin_op().await?;
+        self.accounts.update_in_op(&mut op, &mut account).await?;
+        if override_in_effect {
+            op.commit().await?;
+        } else {
+            let from = self.withdrawal_limit_account_set(previous).await?;
+            let to = self
+                .withdrawal_limit_account_set(withdrawal_limit_id)
+                .await?;
+            self.ledger
+                .move_withdrawal_limit(op, account_id, from, to)
+                .await?;
+        }
+        Ok(account)
+    }
+
+    /// Applies the most recently created withdrawal limit for the holder's KYC level to
+    /// their USD accounts. Limits assigned directly to an account are left untouched.
+    #[instrument(name = "deposit.update_withdrawal_limit_for_holder", skip(self), err)]
+    pub async fn update_withdrawal_limit_for_holder(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+        kyc_level: KycLevel,
+    ) -> Result<(), CoreDepositError> {
+        let holder_id = holder_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_accounts(),
+                CoreDepositAction::DEPOSIT_ACCOUNT_ASSIGN_WITHDRAWAL_LIMIT,
+            )
+            .await?;
+
+        let mut withdrawal_limit_id = None;
+        let mut has_next_page = true;
+        let mut after: Option<WithdrawalLimitsByCreatedAtCursor> = None;
+        while has_next_page && withdrawal_limit_id.is_none() {
+            let withdrawal_limits = self
+                .repo
+                .list_by_created_at(
+                    es_entity::PaginatedQueryArgs::<WithdrawalLimitsByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+            withdrawal_limit_id = withdrawal_limits
+                .entities
+                .iter()
+                .find(|l| l.applies_to_segment(kyc_level))
+                .map(|l| l.id);
+            (after, has_next_page) = (
+                withdrawal_limits.end_cursor,
+                withdrawal_limits.has_next_page,
+            );
+        }
+
+        let accounts = self
+            .accounts
+            .list_for_account_holder_id_by_id(holder_id, Default::default(), Default::default())
+            .await?;
+        for mut account in accounts.entities.into_iter() {
+            if account.currency != DepositAccountCurrency::Usd {
+                continue;
+            }
+            let previous = match account.assign_withdrawal_limit(
+                withdrawal_limit_id,
+                WithdrawalLimitSource::Segment,
+                audit_info.clone(),
+            ) {
+                es_entity::Idempotent::Executed(previous) => previous,
+                es_entity::Idempotent::Ignored => continue,
+            };
+            let override_in_effect = self.find_override_in_effect(account.id).await?.is_some();
+            let mut op = self.accounts.begin_op().await?;
+            self.accounts.update_in_op(&mut op, &mut account).await?;
+            if override_in_effect {
+                op.commit().await?;
+            } else {
+                let from = self.withdrawal_limit_account_set(previous).await?;
+                let to = self
+                    .withdrawal_limit_account_set(withdrawal_limit_id)
+                    .await?;
+                self.ledger
+                    .move_withdrawal_limit(op, account.id, from, to)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Temporarily replaces the account's withdrawal limits. The ledger velocity control is
+    /// lifted while the override is in effect and the override amounts are enforced when the
+    /// withdrawal is initiated.
+    #[instrument(name = "deposit.grant_withdrawal_limit_override", skip(self), err)]
+    pub async fn grant_withdrawal_limit_override(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        daily_limit: UsdCents,
+        monthly_limit: UsdCents,
+        reason: String,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<WithdrawalLimitOverride, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_withdrawal_limits(),
+                CoreDepositAction::WITHDRAWAL_LIMIT_GRANT_OVERRIDE,
+            )
+            .await?;
+        if expires_at <= chrono::Utc::now() {
+            return Err(WithdrawalLimitOverrideError::ExpiryInPast.into());
+        }
+        let account = self.accounts.find_by_id(account_id).await?;
+        if account.currency != DepositAccountCurrency::Usd {
+            return Err(CoreDepositError::DepositAccountCurrencyMismatch(
+                account.currency,
+                DepositAccountCurrency::Usd,
+            ));
+        }
+        if self.find_override_in_effect(account_id).await?.is_some() {
+            return Err(WithdrawalLimitOverrideError::OverrideAlreadyActive.into());
+        }
+
+        let new_override = NewWithdrawalLimitOverride::builder()
+            .id(WithdrawalLimitOverrideId::new())
+            .deposit_account_id(account_id)
+            .daily_limit(daily_limit)
+            .monthly_limit(monthly_limit)
+            .reason(reason)
+            .expires_at(expires_at)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new withdrawal limit override");
+
+        let from = self
+            .withdrawal_limit_account_set(account.withdrawal_limit_id)
+            .await?;
+        let mut op = self.overrides.begin_op().await?;
+        let limit_override = self.overrides.create_in_op(&mut op, new_override).await?;
+        self.jobs
+            .create_and_spawn_at_in_op(
+                &mut op,
+                limit_override.id,
+                WithdrawalLimitOverrideExpiryJobConfig::<Perms> {
+                    withdrawal_limit_override_id: limit_override.id,
+                    _phantom: std::marker::PhantomData,
+                },
+                limit_override.expires_at,
+            )
+            .await?;
+        self.ledger
+            .move_withdrawal_limit(op, account_id, from, None)
+            .await?;
+        Ok(limit_override)
+    }
+
+    #[instrument(name = "deposit.revoke_withdrawal_limit_override", skip(self), err)]
+    pub async fn revoke_withdrawal_limit_override(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        withdrawal_limit_override_id: impl Into<WithdrawalLimitOverrideId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<WithdrawalLimitOverride, CoreDepositError> {
+        let id = withdrawal_limit_override_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_withdrawal_limits(),
+                CoreDepositAction::WITHDRAWAL_LIMIT_REVOKE_OVERRIDE,
+            )
+            .await?;
+
+        let mut limit_override = self.overrides.find_by_id(id).await?;
+        limit_override.revoke(reason, audit_info)?;
+        let account = self
+            .accounts
+            .find_by_id(limit_override.deposit_account_id)
+            .await?;
+        let to = self
+            .withdrawal_limit_account_set(account.withdrawal_limit_id)
+            .await?;
+
+        let mut op = self.overrides.begin_op().await?;
+        self.overrides
+            .update_in_op(&mut op, &mut limit_override)
+            .await?;
+        self.ledger
+            .move_withdrawal_limit(op, account.id, None, to)
+            .await?;
+        Ok(limit_override)
+    }
+
+    #[instrument(
+        name = "deposit.list_withdrawal_limit_overrides_for_account",
+        skip(self),
+        err
+    )]
+    pub async fn list_withdrawal_limit_overrides_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<WithdrawalLimitOverride>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_withdrawal_limits(),
+                CoreDepositAction::WITHDRAWAL_LIMIT_LIST,
+            )
+            .await?;
+        Ok(self
+            .overrides
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[instrument(name = "deposit.find_all_withdrawal_limits", skip(self), err)]
+    pub async fn find_all_withdrawal_limits<T: From<WithdrawalLimit>>(
+        &self,
+        ids: &[WithdrawalLimitId],
+    ) -> Result<std::collections::HashMap<WithdrawalLimitId, T>, CoreDepositError> {
+        Ok(self.repo.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.find_all_withdrawal_limit_overrides", skip(self), err)]
+    pub async fn find_all_withdrawal_limit_overrides<T: From<WithdrawalLimitOverride>>(
+        &self,
+        ids: &[WithdrawalLimitOverrideId],
+    ) -> Result<std::collections::HashMap<WithdrawalLimitOverrideId, T>, CoreDepositError> {
+        Ok(self.overrides.find_all(ids).await?)
+    }
+
+    async fn find_override_in_effect(
+        &self,
+        deposit_account_id: DepositAccountId,
+    ) -> Result<Option<WithdrawalLimitOverride>, CoreDepositError> {
+        let now = chrono::Utc::now();
+        Ok(self
+            .overrides
+            .list_for_deposit_account_id_by_created_at(
+                deposit_account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities
+            .into_iter()
+            .find(|o| o.is_in_effect(now)))
+    }
+
+    /// Mirrors the ledger velocity windows (UTC day and month) so a breach surfaces as a
+    /// descriptive error instead of a velocity violation from the ledger.
+    pub(crate) async fn check_withdrawal_limits(
+        &self,
+        deposit_account_id: DepositAccountId,
+        amount: UsdCents,
+    ) -> Result<(), CoreDepositError> {
+        let (daily_limit, monthly_limit) =
+            match self.find_override_in_effect(deposit_account_id).await? {
+                Some(limit_override) => (limit_override.daily_limit, limit_override.monthly_limit),
+                None => {
+                    let account = self.accounts.find_by_id(deposit_account_id).await?;
+                    let Some(withdrawal_limit_id) = account.withdrawal_limit_id else {
+                        return Ok(());
+                    };
+                    let withdrawal_limit = self.repo.find_by_id(withdrawal_limit_id).await?;
+                    (withdrawal_limit.daily_limit, withdrawal_limit.monthly_limit)
+                }
+            };
+
+        let now = chrono::Utc::now();
+        let today = now.date_naive();
+        let month_start = today.with_day(1).expect("first day of month");
+        let mut withdrawn_today = UsdCents::ZERO;
+        let mut withdrawn_this_month = UsdCents::ZERO;
+        let mut has_next_page = true;
+        let mut after: Option<WithdrawalsByCreatedAtCursor> = None;
+        while has_next_page {
+            let withdrawals = self
+                .withdrawals
+                .list_for_deposit_account_id_by_created_at(
+                    deposit_account_id,
+                    es_entity::PaginatedQueryArgs::<WithdrawalsByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+            (after, has_next_page) = (withdrawals.end_cursor, withdrawals.has_next_page);
+            for withdrawal in withdrawals.entities {
+                let created_on = withdrawal.created_at().date_naive();
+                if created_on < month_start {
+                    has_next_page = false;
+                    break;
+                }
+                if matches!(
+                    withdrawal.status(),
+                    WithdrawalStatus::Cancelled | WithdrawalStatus::Denied
+                ) {
+                    continue;
+                }
+                let DepositAmount::Usd(withdrawn) = withdrawal.amount else {
+                    continue;
+                };
+                withdrawn_this_month += withdrawn;
+                if created_on == today {
+                    withdrawn_today += withdrawn;
+                }
+            }
+        }
+
+        if withdrawn_today + amount > daily_limit {
+            return Err(CoreDepositError::DailyWithdrawalLimitExceeded {
+                limit: daily_limit,
+                withdrawn: withdrawn_today,
+            });
+        }
+        if withdrawn_this_month + amount > monthly_limit {
+            return Err(CoreDepositError::MonthlyWithdrawalLimitExceeded {
+                limit: monthly_limit,
+                withdrawn: withdrawn_this_month,
+            });
+        }
+        Ok(())
+    }
+}
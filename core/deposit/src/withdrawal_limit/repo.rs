@@ -0,0 +1,25 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::WithdrawalLimitId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "WithdrawalLimit",
+    err = "WithdrawalLimitError",
+    columns(name(ty = "String")),
+    tbl_prefix = "core"
+)]
+pub struct WithdrawalLimitRepo {
+    #[allow(dead_code)]
+    pool: PgPool,
+}
+
+impl WithdrawalLimitRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
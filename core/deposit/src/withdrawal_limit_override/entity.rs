@@ -0,0 +1,183 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{DepositAccountId, UsdCents, WithdrawalLimitOverrideId};
+use audit::AuditInfo;
+
+use super::error::WithdrawalLimitOverrideError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum WithdrawalLimitOverrideStatus {
+    Active,
+    Revoked,
+    Expired,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "WithdrawalLimitOverrideId")]
+pub enum WithdrawalLimitOverrideEvent {
+    Initialized {
+        id: WithdrawalLimitOverrideId,
+        deposit_account_id: DepositAccountId,
+        daily_limit: UsdCents,
+        monthly_limit: UsdCents,
+        reason: String,
+        expires_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    },
+    Revoked {
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    Expired {
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct WithdrawalLimitOverride {
+    pub id: WithdrawalLimitOverrideId,
+    pub deposit_account_id: DepositAccountId,
+    pub daily_limit: UsdCents,
+    pub monthly_limit: UsdCents,
+    pub reason: String,
+    pub expires_at: DateTime<Utc>,
+
+    pub(super) events: EntityEvents<WithdrawalLimitOverrideEvent>,
+}
+
+impl WithdrawalLimitOverride {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for withdrawal limit override")
+    }
+
+    pub fn status(&self) -> WithdrawalLimitOverrideStatus {
+        self.events
+            .iter_all()
+            .find_map(|e| match e {
+                WithdrawalLimitOverrideEvent::Revoked { .. } => {
+                    Some(WithdrawalLimitOverrideStatus::Revoked)
+                }
+                WithdrawalLimitOverrideEvent::Expired { .. } => {
+                    Some(WithdrawalLimitOverrideStatus::Expired)
+                }
+                _ => None,
+            })
+            .unwrap_or(WithdrawalLimitOverrideStatus::Active)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status() == WithdrawalLimitOverrideStatus::Active
+    }
+
+    pub fn is_in_effect(&self, now: DateTime<Utc>) -> bool {
+        self.is_active() && self.expires_at > now
+    }
+
+    pub fn revoke(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<(), WithdrawalLimitOverrideError> {
+        match self.status() {
+            WithdrawalLimitOverrideStatus::Revoked => {
+                return Err(WithdrawalLimitOverrideError::AlreadyRevoked(self.id))
+            }
+            WithdrawalLimitOverrideStatus::Expired => {
+                return Err(WithdrawalLimitOverrideError::AlreadyExpired(self.id))
+            }
+            WithdrawalLimitOverrideStatus::Active => (),
+        }
+
+        self.events
+            .push(WithdrawalLimitOverrideEvent::Revoked { reason, audit_info });
+
+        Ok(())
+    }
+
+    pub fn expire(&mut self, audit_info: AuditInfo) -> Idempotent<()> {
+        if !self.is_active() {
+            return Idempotent::Ignored;
+        }
+
+        self.events
+            .push(WithdrawalLimitOverrideEvent::Expired { audit_info });
+
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<WithdrawalLimitOverrideEvent> for WithdrawalLimitOverride {
+    fn try_from_events(
+        events: EntityEvents<WithdrawalLimitOverrideEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = WithdrawalLimitOverrideBuilder::default();
+        for event in events.iter_all() {
+            if let WithdrawalLimitOverrideEvent::Initialized {
+                id,
+                deposit_account_id,
+                daily_limit,
+                monthly_limit,
+                reason,
+                expires_at,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .deposit_account_id(*deposit_account_id)
+                    .daily_limit(*daily_limit)
+                    .monthly_limit(*monthly_limit)
+                    .reason(reason.clone())
+                    .expires_at(*expires_at)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewWithdrawalLimitOverride {
+    #[builder(setter(into))]
+    pub(super) id: WithdrawalLimitOverrideId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    pub(super) daily_limit: UsdCents,
+    pub(super) monthly_limit: UsdCents,
+    #[builder(setter(into))]
+    pub(super) reason: String,
+    pub(super) expires_at: DateTime<Utc>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewWithdrawalLimitOverride {
+    pub fn builder() -> NewWithdrawalLimitOverrideBuilder {
+        NewWithdrawalLimitOverrideBuilder::default()
+    }
+}
+
+impl IntoEvents<WithdrawalLimitOverrideEvent> for NewWithdrawalLimitOverride {
+    fn into_events(self) -> EntityEvents<WithdrawalLimitOverrideEvent> {
+        EntityEvents::init(
+            self.id,
+            [WithdrawalLimitOverrideEvent::Initialized {
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                daily_limit: self.daily_limit,
+                monthly_limit: self.monthly_limit,
+                reason: self.reason,
+                expires_at: self.expires_at,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+use crate::primitives::WithdrawalLimitOverrideId;
+
+#[derive(Error, Debug)]
+pub enum WithdrawalLimitOverrideError {
+    #[error("WithdrawalLimitOverrideError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("WithdrawalLimitOverrideError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("WithdrawalLimitOverrideError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("WithdrawalLimitOverrideError - AlreadyRevoked: {0}")]
+    AlreadyRevoked(WithdrawalLimitOverrideId),
+    #[error("WithdrawalLimitOverrideError - AlreadyExpired: {0}")]
+    AlreadyExpired(WithdrawalLimitOverrideId),
+    #[error("WithdrawalLimitOverrideError - ExpiryInPast")]
+    ExpiryInPast,
+    #[error("WithdrawalLimitOverrideError - OverrideAlreadyActive")]
+    OverrideAlreadyActive,
+}
+
+es_entity::from_es_entity_error!(WithdrawalLimitOverrideError);
@@ -0,0 +1,8 @@
+mod entity;
+pub mod error;
+pub mod repo;
+
+pub(super) use entity::*;
+pub use entity::{WithdrawalLimitOverride, WithdrawalLimitOverrideStatus};
+pub use repo::withdrawal_limit_override_cursor::WithdrawalLimitOverridesByCreatedAtCursor;
+pub(super) use repo::*;
@@ -0,0 +1,25 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{DepositAccountId, WithdrawalLimitOverrideId};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "WithdrawalLimitOverride",
+    err = "WithdrawalLimitOverrideError",
+    columns(deposit_account_id(ty = "DepositAccountId", list_for, update(persist = false))),
+    tbl_prefix = "core"
+)]
+pub struct WithdrawalLimitOverrideRepo {
+    #[allow(dead_code)]
+    pool: PgPool,
+}
+
+impl WithdrawalLimitOverrideRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
@@ -0,0 +1,423 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
+use deposit::*;
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn freeze_and_close_account() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    let sweep_account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, account.id, amount, None)
+        .await?;
+
+    let frozen = deposit
+        .freeze_account(&DummySubject, account.id, "compliance review".to_string())
+        .await?;
+    assert_eq!(frozen.status, DepositAccountStatus::Frozen);
+
+    // credits are still allowed on a frozen account
+    deposit
+        .record_deposit(&DummySubject, account.id, amount, None)
+        .await?;
+
+    let res = deposit
+        .initiate_withdrawal(&DummySubject, account.id, amount, None, None)
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositAccountFrozen)
+    ));
+
+    let res = deposit
+        .close_account(
+            &DummySubject,
+            account.id,
+            "customer request".to_string(),
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositAccountBalanceNotZero)
+    ));
+
+    let closed = deposit
+        .close_account(
+            &DummySubject,
+            account.id,
+            "customer request".to_string(),
+            Some(sweep_account.id),
+        )
+        .await?;
+    assert_eq!(closed.status, DepositAccountStatus::Closed);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert!(balance.settled.is_zero());
+    let balance = deposit
+        .account_balance(&DummySubject, sweep_account.id)
+        .await?;
+    assert_eq!(balance.settled, amount + amount);
+
+    let res = deposit
+        .record_deposit(&DummySubject, account.id, amount, None)
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositAccountClosed)
+    ));
+
+    let history = deposit
+        .account_history(&DummySubject, account.id, Default::default())
+        .await?;
+    let status_changes = history
+        .entities
+        .iter()
+        .filter(|entry| matches!(entry, DepositAccountHistoryEntry::StatusChanged(_)))
+        .count();
+    assert_eq!(status_changes, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn close_account_rejects_pending_withdrawal() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    let sweep_account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, account.id, amount, None)
+        .await?;
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(400)).unwrap(),
+            None,
+            None,
+        )
+        .await?;
+
+    let res = deposit
+        .close_account(
+            &DummySubject,
+            account.id,
+            "customer request".to_string(),
+            Some(sweep_account.id),
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositAccountBalanceNotZero)
+    ));
+
+    let account = deposit
+        .find_account_by_id(&DummySubject, account.id)
+        .await?
+        .expect("account exists");
+    assert_eq!(account.status, DepositAccountStatus::Active);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn frozen_account_rejects_ledger_debits() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    let recipient = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, account.id, amount, None)
+        .await?;
+    deposit
+        .freeze_account(&DummySubject, account.id, "compliance review".to_string())
+        .await?;
+
+    // post straight to the ledger, bypassing the status checks in CoreDeposit
+    let mut params = cala_ledger::tx_template::Params::default();
+    params.insert("journal_id", journal_id);
+    params.insert("currency", cala_ledger::Currency::USD);
+    params.insert("amount", dec!(100));
+    params.insert("debit_account_id", cala_ledger::AccountId::from(account.id));
+    params.insert(
+        "credit_account_id",
+        cala_ledger::AccountId::from(recipient.id),
+    );
+    params.insert("effective", chrono::Utc::now().date_naive());
+    let res = cala
+        .post_transaction(cala_ledger::TransactionId::new(), "TRANSFER", params)
+        .await;
+    assert!(res.is_err());
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, amount);
+    let balance = deposit.account_balance(&DummySubject, recipient.id).await?;
+    assert!(balance.settled.is_zero());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dormancy_actions_require_dormant_account() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let res = deposit
+        .reactivate_dormant_account(&DummySubject, account.id, "customer contact".to_string())
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositAccountError(_))
+    ));
+
+    let res = deposit
+        .transfer_unclaimed_funds(&DummySubject, account.id)
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositAccountError(_))
+    ));
+
+    let report = deposit.dormant_accounts_report(&DummySubject).await?;
+    assert!(report.iter().all(|entry| entry.account.id != account.id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn auto_debit_account_must_be_active_and_owned_by_holder() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let holder_id = DepositAccountHolderId::new();
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            holder_id,
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    deposit
+        .check_auto_debit_account(&DummySubject, account.id, holder_id)
+        .await?;
+
+    let res = deposit
+        .check_auto_debit_account(&DummySubject, account.id, DepositAccountHolderId::new())
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositAccountHolderMismatch)
+    ));
+
+    deposit
+        .freeze_account(&DummySubject, account.id, "compliance review".to_string())
+        .await?;
+    let res = deposit
+        .check_auto_debit_account(&DummySubject, account.id, holder_id)
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositAccountFrozen)
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
+use deposit::*;
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn bank_statement_lines_are_matched_once() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let matched_tx = format!("tx-{}", uuid::Uuid::new_v4());
+    let unmatched_tx = format!("tx-{}", uuid::Uuid::new_v4());
+    let data = format!(
+        "transaction_id,booking_date,amount,currency,remittance_information,counterparty_name,counterparty_account
+{matched_tx},2026-03-02,250.00,USD,Deposit {},Jane Doe,
+{unmatched_tx},2026-03-02,75.00,USD,unknown reference,,
+",
+        account.id
+    );
+
+    let import = deposit
+        .bank_statement_imports()
+        .import_bank_statement(
+            &DummySubject,
+            "statement.csv".to_string(),
+            BankStatementFormat::Csv,
+            data.clone(),
+        )
+        .await?;
+    let summary = import.summary.expect("import is processed");
+    assert_eq!(summary.total_lines, 2);
+    assert_eq!(summary.matched_lines, 1);
+    assert_eq!(summary.unmatched_lines, 1);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, UsdCents::try_from_usd(dec!(250)).unwrap());
+
+    let reimport = deposit
+        .bank_statement_imports()
+        .import_bank_statement(
+            &DummySubject,
+            "statement.csv".to_string(),
+            BankStatementFormat::Csv,
+            data,
+        )
+        .await?;
+    let summary = reimport.summary.expect("import is processed");
+    assert_eq!(summary.duplicate_lines, 2);
+    assert_eq!(summary.matched_lines, 0);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, UsdCents::try_from_usd(dec!(250)).unwrap());
+
+    let lines = deposit
+        .bank_statement_imports()
+        .list_bank_statement_lines_for_import(&DummySubject, import.id, Default::default())
+        .await?
+        .entities;
+    let unmatched = lines
+        .into_iter()
+        .find(|l| l.bank_transaction_id == unmatched_tx)
+        .expect("unmatched line exists");
+    assert_eq!(unmatched.status(), BankStatementLineStatus::Unmatched);
+
+    let resolved = deposit
+        .bank_statement_imports()
+        .resolve_bank_statement_line(&DummySubject, unmatched.id, account.id)
+        .await?;
+    assert_eq!(resolved.status(), BankStatementLineStatus::Resolved);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, UsdCents::try_from_usd(dec!(325)).unwrap());
+
+    let res = deposit
+        .bank_statement_imports()
+        .dismiss_bank_statement_line(&DummySubject, unmatched.id, "duplicate".to_string())
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
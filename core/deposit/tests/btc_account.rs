@@ -0,0 +1,213 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
+use deposit::*;
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn btc_account_records_deposits_and_withdrawals_in_satoshis() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_btc_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    assert_eq!(account.currency, DepositAccountCurrency::Btc);
+
+    let amount = Satoshis::try_from_btc(dec!(1.5)).unwrap();
+    let recorded = deposit
+        .record_deposit(&DummySubject, account.id, amount, None)
+        .await?;
+    assert_eq!(recorded.amount, DepositAmount::Btc(amount));
+
+    let balance = deposit
+        .account_btc_balance(&DummySubject, account.id)
+        .await?;
+    assert_eq!(balance.settled, amount);
+
+    let res = deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(
+            deposit::error::CoreDepositError::DepositAccountCurrencyMismatch(
+                DepositAccountCurrency::Btc,
+                DepositAccountCurrency::Usd
+            )
+        )
+    ));
+
+    let res = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            Satoshis::try_from_btc(dec!(2)).unwrap(),
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::InsufficientAvailableBalance)
+    ));
+
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            Satoshis::try_from_btc(dec!(0.5)).unwrap(),
+            None,
+            None,
+        )
+        .await?;
+
+    let balance = deposit
+        .account_btc_balance(&DummySubject, account.id)
+        .await?;
+    assert_eq!(balance.settled, Satoshis::try_from_btc(dec!(1)).unwrap());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn collateral_withdrawal_checks_holder_and_balance() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let holder_id = DepositAccountHolderId::new();
+    let account = deposit
+        .create_btc_account(
+            &DummySubject,
+            holder_id,
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            Satoshis::try_from_btc(dec!(1)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let amount = Satoshis::try_from_btc(dec!(0.4)).unwrap();
+    let res = deposit
+        .initiate_collateral_withdrawal(
+            &DummySubject,
+            account.id,
+            DepositAccountHolderId::new(),
+            amount,
+            "collateral-funding".to_string(),
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositAccountHolderMismatch)
+    ));
+
+    let res = deposit
+        .initiate_collateral_withdrawal(
+            &DummySubject,
+            account.id,
+            holder_id,
+            Satoshis::try_from_btc(dec!(2)).unwrap(),
+            "collateral-funding".to_string(),
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::InsufficientAvailableBalance)
+    ));
+
+    let withdrawal = deposit
+        .initiate_collateral_withdrawal(
+            &DummySubject,
+            account.id,
+            holder_id,
+            amount,
+            "collateral-funding".to_string(),
+        )
+        .await?;
+    assert_eq!(withdrawal.status(), WithdrawalStatus::PendingConfirmation);
+
+    let withdrawal = deposit
+        .confirm_withdrawal(&DummySubject, withdrawal.id)
+        .await?;
+    assert_eq!(withdrawal.status(), WithdrawalStatus::Confirmed);
+
+    let balance = deposit
+        .account_btc_balance(&DummySubject, account.id)
+        .await?;
+    assert_eq!(balance.settled, Satoshis::try_from_btc(dec!(0.6)).unwrap());
+
+    Ok(())
+}
@@ -26,19 +26,32 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
 
     let journal_id = helpers::init_journal(&cala).await?;
 
+    let storage = Storage::new(&StorageConfig::default());
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
         &jobs,
+        &storage,
         &cala,
         journal_id,
     )
     .await?;
 
-    let storage = Storage::new(&StorageConfig::default());
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, &storage, &jobs);
+    let accounting = CoreAccounting::init(
+        &pool,
+        Default::default(),
+        &authz,
+        &cala,
+        journal_id,
+        &storage,
+        &jobs,
+        &governance,
+        &outbox,
+    )
+    .await?;
     let chart_ref = format!("ref-{:08}", rand::thread_rng().gen_range(0..10000));
     let chart = accounting
         .chart_of_accounts()
@@ -52,6 +65,11 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         4,Bank Deposit Accounts
         5,Financial Institution Deposit Accounts
         6,Non Domiciled Individual Deposit Accounts
+        8,Deposit Interest Expense
+        9,Accrued Deposit Interest
+        10,Time Deposit Accounts
+        11,Unclaimed Funds
+        12,Deposit Fee Income
         "#
     .to_string();
     let chart_id = chart.id;
@@ -86,6 +104,11 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
                 .chart_of_account_non_domiciled_individual_deposit_accounts_parent_code(
                     "6".parse().unwrap(),
                 )
+                .chart_of_accounts_interest_expense_parent_code("8".parse().unwrap())
+                .chart_of_accounts_accrued_interest_parent_code("9".parse().unwrap())
+                .chart_of_accounts_time_deposit_accounts_parent_code("10".parse().unwrap())
+                .chart_of_accounts_unclaimed_funds_parent_code("11".parse().unwrap())
+                .chart_of_accounts_fee_income_parent_code("12".parse().unwrap())
                 .build()
                 .unwrap(),
         )
@@ -112,6 +135,11 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         4,Other Bank Deposit Accounts
         5,Other Financial Institution Deposit Accounts
         6,Other Non Domiciled Individual Deposit Accounts
+        8,Other Deposit Interest Expense
+        9,Other Accrued Deposit Interest
+        10,Other Time Deposit Accounts
+        11,Other Unclaimed Funds
+        12,Other Deposit Fee Income
         "#
     .to_string();
     let chart_id = chart.id;
@@ -139,6 +167,11 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
                 .chart_of_account_non_domiciled_individual_deposit_accounts_parent_code(
                     "6".parse().unwrap(),
                 )
+                .chart_of_accounts_interest_expense_parent_code("8".parse().unwrap())
+                .chart_of_accounts_accrued_interest_parent_code("9".parse().unwrap())
+                .chart_of_accounts_time_deposit_accounts_parent_code("10".parse().unwrap())
+                .chart_of_accounts_unclaimed_funds_parent_code("11".parse().unwrap())
+                .chart_of_accounts_fee_income_parent_code("12".parse().unwrap())
                 .build()
                 .unwrap(),
         )
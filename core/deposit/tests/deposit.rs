@@ -4,6 +4,7 @@ use rust_decimal_macros::dec;
 
 use authz::dummy::DummySubject;
 use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
 use deposit::*;
 use helpers::{action, event, object};
 
@@ -24,12 +25,15 @@ async fn deposit() -> anyhow::Result<()> {
 
     let journal_id = helpers::init_journal(&cala).await?;
 
+    let storage = Storage::new(&StorageConfig::default());
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
         &jobs,
+        &storage,
         &cala,
         journal_id,
     )
@@ -63,3 +67,187 @@ async fn deposit() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn revert_deposit() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let first = deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1000)).unwrap(),
+            None,
+        )
+        .await?;
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(500)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let reverted = deposit
+        .revert_deposit(&DummySubject, first.id, DepositReversalReason::WrongAmount)
+        .await?;
+    assert_eq!(reverted.status(), DepositStatus::Reverted);
+    assert_eq!(
+        reverted.reversal_reason(),
+        Some(DepositReversalReason::WrongAmount)
+    );
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, UsdCents::try_from_usd(dec!(500)).unwrap());
+
+    let res = deposit
+        .revert_deposit(&DummySubject, first.id, DepositReversalReason::WrongAmount)
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositError(_))
+    ));
+
+    let history = deposit
+        .account_history(&DummySubject, account.id, Default::default())
+        .await?;
+    assert!(history
+        .entities
+        .iter()
+        .any(|entry| matches!(entry, DepositAccountHistoryEntry::RevertedDeposit(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn revert_deposit_with_pending_withdrawal() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let first = deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1000)).unwrap(),
+            None,
+        )
+        .await?;
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(400)).unwrap(),
+            None,
+            None,
+        )
+        .await?;
+
+    let res = deposit
+        .revert_deposit(&DummySubject, first.id, DepositReversalReason::WrongAmount)
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::InsufficientBalanceForReversal)
+    ));
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, UsdCents::try_from_usd(dec!(600)).unwrap());
+    assert_eq!(balance.pending, UsdCents::try_from_usd(dec!(400)).unwrap());
+
+    let first = deposit
+        .find_deposit_by_id(&DummySubject, first.id)
+        .await?
+        .expect("deposit exists");
+    assert_eq!(first.status(), DepositStatus::Confirmed);
+
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(500)).unwrap(),
+            None,
+        )
+        .await?;
+    let reverted = deposit
+        .revert_deposit(&DummySubject, first.id, DepositReversalReason::WrongAmount)
+        .await?;
+    assert_eq!(reverted.status(), DepositStatus::Reverted);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, UsdCents::try_from_usd(dec!(100)).unwrap());
+    assert_eq!(balance.pending, UsdCents::try_from_usd(dec!(400)).unwrap());
+
+    Ok(())
+}
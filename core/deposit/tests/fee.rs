@@ -0,0 +1,220 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
+use deposit::*;
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn withdrawal_fee_is_charged_and_can_be_waived() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let product = deposit
+        .create_deposit_product(
+            &DummySubject,
+            "Basic Savings",
+            InterestRatePct::from(dec!(0.01)),
+        )
+        .await?;
+    let withdrawal_fee = UsdCents::try_from_usd(dec!(2)).unwrap();
+    deposit
+        .update_deposit_product_fee_schedule(
+            &DummySubject,
+            product.id,
+            DepositFeeSchedule {
+                withdrawal_fee,
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    deposit
+        .assign_deposit_product(&DummySubject, account.id, product.id)
+        .await?;
+
+    let deposit_amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, account.id, deposit_amount, None)
+        .await?;
+
+    let res = deposit
+        .initiate_withdrawal(&DummySubject, account.id, deposit_amount, None, None)
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::InsufficientAvailableBalance)
+    ));
+
+    let withdrawal_amount = UsdCents::try_from_usd(dec!(100)).unwrap();
+    deposit
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None, None)
+        .await?;
+
+    let fees = deposit
+        .fees()
+        .list_fees_for_account(&DummySubject, account.id)
+        .await?;
+    assert_eq!(fees.len(), 1);
+    let fee = &fees[0];
+    assert_eq!(fee.fee_type, DepositFeeType::Withdrawal);
+    assert_eq!(fee.amount, withdrawal_fee);
+    assert_eq!(fee.status(), DepositFeeStatus::Charged);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(
+        balance.settled,
+        deposit_amount - withdrawal_amount - withdrawal_fee
+    );
+
+    let fee = deposit
+        .fees()
+        .waive_fee(&DummySubject, fee.id, "goodwill".to_string())
+        .await?;
+    assert_eq!(fee.status(), DepositFeeStatus::Waived);
+    assert_eq!(fee.waiver_reason(), Some("goodwill"));
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, deposit_amount - withdrawal_amount);
+
+    let res = deposit
+        .fees()
+        .waive_fee(&DummySubject, fee.id, "goodwill".to_string())
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositFeeError(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn withdrawal_fee_is_waived_when_withdrawal_is_cancelled() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let product = deposit
+        .create_deposit_product(
+            &DummySubject,
+            "Basic Savings",
+            InterestRatePct::from(dec!(0.01)),
+        )
+        .await?;
+    deposit
+        .update_deposit_product_fee_schedule(
+            &DummySubject,
+            product.id,
+            DepositFeeSchedule {
+                withdrawal_fee: UsdCents::try_from_usd(dec!(2)).unwrap(),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    deposit
+        .assign_deposit_product(&DummySubject, account.id, product.id)
+        .await?;
+
+    let deposit_amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, account.id, deposit_amount, None)
+        .await?;
+
+    let withdrawal = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            None,
+            None,
+        )
+        .await?;
+    deposit
+        .cancel_withdrawal(&DummySubject, withdrawal.id)
+        .await?;
+
+    let fees = deposit
+        .fees()
+        .list_fees_for_account(&DummySubject, account.id)
+        .await?;
+    assert_eq!(fees.len(), 1);
+    assert_eq!(fees[0].status(), DepositFeeStatus::Waived);
+    assert_eq!(fees[0].waiver_reason(), Some("Withdrawal cancelled"));
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, deposit_amount);
+
+    Ok(())
+}
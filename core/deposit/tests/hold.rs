@@ -0,0 +1,112 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
+use deposit::*;
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn hold_reduces_available_balance() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, account.id, amount, None)
+        .await?;
+
+    let hold = deposit
+        .holds()
+        .place_hold(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(600)).unwrap(),
+            "court order".to_string(),
+            None,
+        )
+        .await?;
+    assert_eq!(hold.status(), HoldStatus::Active);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, amount);
+    assert_eq!(
+        balance.available(),
+        UsdCents::try_from_usd(dec!(400)).unwrap()
+    );
+
+    let res = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(500)).unwrap(),
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::InsufficientAvailableBalance)
+    ));
+
+    let hold = deposit
+        .holds()
+        .release_hold(&DummySubject, hold.id, "order lifted".to_string())
+        .await?;
+    assert_eq!(hold.status(), HoldStatus::Released);
+
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(500)).unwrap(),
+            None,
+            None,
+        )
+        .await?;
+
+    let holds = deposit
+        .holds()
+        .list_holds_for_account(&DummySubject, account.id)
+        .await?;
+    assert_eq!(holds.len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
+use deposit::*;
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn withdrawal_beneficiary_is_captured() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1000)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let beneficiary = WithdrawalBeneficiary {
+        name: "Jane Doe".to_string(),
+        account_number: "GB29NWBK60161331926819".to_string(),
+        bic: Some("NWBKGB2L".to_string()),
+    };
+    let withdrawal = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            None,
+            Some(beneficiary.clone()),
+        )
+        .await?;
+    assert_eq!(withdrawal.status(), WithdrawalStatus::PendingApproval);
+    assert_eq!(withdrawal.payment_batch_id, None);
+
+    let withdrawal = deposit
+        .find_withdrawal_by_id(&DummySubject, withdrawal.id)
+        .await?
+        .expect("withdrawal exists");
+    assert_eq!(withdrawal.beneficiary, Some(beneficiary));
+
+    Ok(())
+}
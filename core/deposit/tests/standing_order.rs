@@ -0,0 +1,113 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
+use deposit::*;
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn standing_order_can_be_created_and_cancelled() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let from = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    let to = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let start_date = chrono::Utc::now().date_naive() + chrono::Days::new(7);
+    let res = deposit
+        .standing_orders()
+        .create_standing_order(
+            &DummySubject,
+            from.id,
+            StandingOrderDestination::DepositAccount {
+                deposit_account_id: from.id,
+            },
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            StandingOrderFrequency::Monthly,
+            start_date,
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::StandingOrderError(_))
+    ));
+
+    let standing_order = deposit
+        .standing_orders()
+        .create_standing_order(
+            &DummySubject,
+            from.id,
+            StandingOrderDestination::DepositAccount {
+                deposit_account_id: to.id,
+            },
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            StandingOrderFrequency::Monthly,
+            start_date,
+            None,
+            Some("rent".to_string()),
+        )
+        .await?;
+    assert_eq!(standing_order.status(), StandingOrderStatus::Active);
+    assert_eq!(standing_order.next_execution_date, Some(start_date));
+    assert_eq!(standing_order.reference, "rent");
+
+    let standing_order = deposit
+        .standing_orders()
+        .cancel_standing_order(&DummySubject, standing_order.id)
+        .await?;
+    assert_eq!(standing_order.status(), StandingOrderStatus::Cancelled);
+    assert_eq!(standing_order.next_run_at(), None);
+
+    let standing_orders = deposit
+        .standing_orders()
+        .list_standing_orders_for_account(&DummySubject, from.id)
+        .await?;
+    assert_eq!(standing_orders.len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
+use deposit::*;
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn generate_statement() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(500)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let today = chrono::Utc::now().date_naive();
+    let period_end = today.pred_opt().unwrap();
+    let period_start = period_end - chrono::Days::new(30);
+
+    let res = deposit
+        .statements()
+        .generate_statement(&DummySubject, account.id, period_start, today)
+        .await;
+    assert!(matches!(
+        res,
+        Err(error::CoreDepositError::DepositAccountStatementError(_))
+    ));
+
+    let statement = deposit
+        .statements()
+        .generate_statement(&DummySubject, account.id, period_start, period_end)
+        .await?;
+    assert_eq!(statement.deposit_account_id, account.id);
+    assert_eq!(statement.account_holder_id, account.account_holder_id);
+    assert_eq!(statement.status(), DepositAccountStatementStatus::Pending);
+
+    let again = deposit
+        .statements()
+        .generate_statement(&DummySubject, account.id, period_start, period_end)
+        .await?;
+    assert_eq!(again.id, statement.id);
+
+    let statements = deposit
+        .statements()
+        .list_statements_for_account(&DummySubject, account.id)
+        .await?;
+    assert_eq!(statements.len(), 1);
+
+    let res = deposit
+        .statements()
+        .generate_statement_download_links(&DummySubject, statement.id)
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
+use deposit::*;
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn open_and_break_time_deposit() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1000)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let principal = UsdCents::try_from_usd(dec!(600)).unwrap();
+    let maturity_date = chrono::Utc::now().date_naive() + chrono::Duration::days(90);
+    let time_deposit = deposit
+        .time_deposits()
+        .open_time_deposit(
+            &DummySubject,
+            account.id,
+            principal,
+            InterestRatePct::from(dec!(0.05)),
+            maturity_date,
+        )
+        .await?;
+    assert!(time_deposit.is_time_deposit());
+    assert_eq!(time_deposit.account_holder_id, account.account_holder_id);
+
+    let balance = deposit
+        .account_balance(&DummySubject, time_deposit.id)
+        .await?;
+    assert_eq!(balance.settled, principal);
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, UsdCents::try_from_usd(dec!(400)).unwrap());
+
+    let res = deposit
+        .initiate_withdrawal(&DummySubject, time_deposit.id, principal, None, None)
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::TimeDepositLocked)
+    ));
+
+    let time_deposit = deposit
+        .time_deposits()
+        .break_time_deposit(&DummySubject, time_deposit.id, "early break".to_string())
+        .await?;
+    assert_eq!(time_deposit.status, DepositAccountStatus::Closed);
+
+    let balance = deposit
+        .account_balance(&DummySubject, time_deposit.id)
+        .await?;
+    assert_eq!(balance.settled, UsdCents::ZERO);
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, UsdCents::try_from_usd(dec!(1000)).unwrap());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn time_deposit_takes_early_break_penalty_from_product() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let product = deposit
+        .create_deposit_product(
+            &DummySubject,
+            "Term Savings",
+            InterestRatePct::from(dec!(0.01)),
+        )
+        .await?;
+    assert_eq!(
+        product.early_break_penalty,
+        EarlyBreakPenaltyPct::FULL_INTEREST
+    );
+
+    let res = deposit
+        .update_deposit_product_early_break_penalty(
+            &DummySubject,
+            product.id,
+            EarlyBreakPenaltyPct::from(dec!(150)),
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DepositProductError(_))
+    ));
+
+    let early_break_penalty = EarlyBreakPenaltyPct::from(dec!(25));
+    deposit
+        .update_deposit_product_early_break_penalty(&DummySubject, product.id, early_break_penalty)
+        .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    deposit
+        .assign_deposit_product(&DummySubject, account.id, product.id)
+        .await?;
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1000)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let time_deposit = deposit
+        .time_deposits()
+        .open_time_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(600)).unwrap(),
+            InterestRatePct::from(dec!(0.05)),
+            chrono::Utc::now().date_naive() + chrono::Duration::days(90),
+        )
+        .await?;
+    let terms = time_deposit
+        .time_deposit_terms
+        .expect("time deposit has terms");
+    assert_eq!(terms.early_break_penalty, early_break_penalty);
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
+use deposit::*;
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn transfer_between_accounts() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let sender = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    let recipient = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let deposit_amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, sender.id, deposit_amount, None)
+        .await?;
+
+    // overdraw
+    let transfer_amount = UsdCents::try_from_usd(dec!(5000)).unwrap();
+    let transfer = deposit
+        .initiate_transfer(
+            &DummySubject,
+            sender.id,
+            recipient.id,
+            transfer_amount,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        transfer,
+        Err(deposit::error::CoreDepositError::InsufficientAvailableBalance)
+    ));
+
+    let transfer_amount = UsdCents::try_from_usd(dec!(400)).unwrap();
+    let transfer = deposit
+        .initiate_transfer(
+            &DummySubject,
+            sender.id,
+            recipient.id,
+            transfer_amount,
+            None,
+        )
+        .await?;
+    assert_eq!(transfer.status(), TransferStatus::Executed);
+
+    let balance = deposit.account_balance(&DummySubject, sender.id).await?;
+    assert_eq!(balance.settled, deposit_amount - transfer_amount);
+    let balance = deposit.account_balance(&DummySubject, recipient.id).await?;
+    assert_eq!(balance.settled, transfer_amount);
+
+    let history = deposit
+        .account_history(&DummySubject, recipient.id, Default::default())
+        .await?;
+    assert!(history
+        .entities
+        .iter()
+        .any(|entry| matches!(entry, DepositAccountHistoryEntry::TransferIn(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn approved_transfer_is_cancelled_when_source_account_frozen() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig {
+            transfer_approval_threshold: Some(UsdCents::try_from_usd(dec!(100)).unwrap()),
+            ..Default::default()
+        },
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let sender = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    let recipient = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let deposit_amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, sender.id, deposit_amount, None)
+        .await?;
+
+    let transfer_amount = UsdCents::try_from_usd(dec!(400)).unwrap();
+    let transfer = deposit
+        .initiate_transfer(
+            &DummySubject,
+            sender.id,
+            recipient.id,
+            transfer_amount,
+            None,
+        )
+        .await?;
+    assert_eq!(transfer.status(), TransferStatus::PendingApproval);
+
+    deposit
+        .freeze_account(&DummySubject, sender.id, "investigation".to_string())
+        .await?;
+
+    let transfer = deposit
+        .ensure_up_to_date_transfer_status(&transfer)
+        .await?
+        .expect("transfer should conclude");
+    assert_eq!(transfer.status(), TransferStatus::Cancelled);
+
+    let balance = deposit.account_balance(&DummySubject, sender.id).await?;
+    assert_eq!(balance.settled, deposit_amount);
+    let balance = deposit.account_balance(&DummySubject, recipient.id).await?;
+    assert_eq!(balance.settled, UsdCents::ZERO);
+
+    Ok(())
+}
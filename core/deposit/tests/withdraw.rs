@@ -4,12 +4,13 @@ use rust_decimal_macros::dec;
 
 use authz::dummy::DummySubject;
 use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
 use deposit::*;
 
 use helpers::{action, event, object};
 
 #[tokio::test]
-async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
+async fn overdraw_withdrawal() -> anyhow::Result<()> {
     let pool = helpers::init_pool().await?;
 
     let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
@@ -25,12 +26,15 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
 
     let journal_id = helpers::init_journal(&cala).await?;
 
+    let storage = Storage::new(&StorageConfig::default());
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
         &jobs,
+        &storage,
         &cala,
         journal_id,
     )
@@ -52,20 +56,73 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
         .record_deposit(&DummySubject, account.id, deposit_amount, None)
         .await?;
 
-    // overdraw
     let withdrawal_amount = UsdCents::try_from_usd(dec!(5000000)).unwrap();
     let withdrawal = deposit
-        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None, None)
         .await;
     assert!(matches!(
         withdrawal,
-        Err(deposit::error::CoreDepositError::DepositLedgerError(_))
+        Err(deposit::error::CoreDepositError::InsufficientAvailableBalance)
     ));
 
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, deposit_amount);
+    assert!(balance.pending.is_zero());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn initiate_and_cancel_withdrawal() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account_holder_id = DepositAccountHolderId::new();
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            account_holder_id,
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let deposit_amount = UsdCents::try_from_usd(dec!(1000000)).unwrap();
+
+    deposit
+        .record_deposit(&DummySubject, account.id, deposit_amount, None)
+        .await?;
+
     let withdrawal_amount = UsdCents::try_from_usd(dec!(500000)).unwrap();
 
     let withdrawal = deposit
-        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None, None)
         .await?;
 
     let balance = deposit.account_balance(&DummySubject, account.id).await?;
@@ -0,0 +1,280 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{config::StorageConfig, Storage};
+use deposit::*;
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn withdrawal_limit_breach_and_override() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(5000)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let withdrawal_limit = deposit
+        .withdrawal_limits()
+        .create_withdrawal_limit(
+            &DummySubject,
+            format!("Test limit {}", account.id),
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            UsdCents::try_from_usd(dec!(150)).unwrap(),
+            None,
+        )
+        .await?;
+    let account = deposit
+        .withdrawal_limits()
+        .assign_withdrawal_limit(&DummySubject, account.id, Some(withdrawal_limit.id))
+        .await?;
+    assert_eq!(account.withdrawal_limit_id, Some(withdrawal_limit.id));
+
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(80)).unwrap(),
+            None,
+            None,
+        )
+        .await?;
+
+    let res = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(30)).unwrap(),
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DailyWithdrawalLimitExceeded { .. })
+    ));
+
+    let limit_override = deposit
+        .withdrawal_limits()
+        .grant_withdrawal_limit_override(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1000)).unwrap(),
+            UsdCents::try_from_usd(dec!(1000)).unwrap(),
+            "Customer relocating".to_string(),
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        )
+        .await?;
+    assert_eq!(
+        limit_override.status(),
+        WithdrawalLimitOverrideStatus::Active
+    );
+
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(30)).unwrap(),
+            None,
+            None,
+        )
+        .await?;
+
+    let limit_override = deposit
+        .withdrawal_limits()
+        .revoke_withdrawal_limit_override(
+            &DummySubject,
+            limit_override.id,
+            "Relocation done".to_string(),
+        )
+        .await?;
+    assert_eq!(
+        limit_override.status(),
+        WithdrawalLimitOverrideStatus::Revoked
+    );
+
+    let res = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(10)).unwrap(),
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DailyWithdrawalLimitExceeded { .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn withdrawal_limit_ignores_cancelled_and_applies_updates() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let storage = Storage::new(&StorageConfig::default());
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &storage,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(5000)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let withdrawal_limit = deposit
+        .withdrawal_limits()
+        .create_withdrawal_limit(
+            &DummySubject,
+            format!("Test limit {}", account.id),
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            UsdCents::try_from_usd(dec!(150)).unwrap(),
+            None,
+        )
+        .await?;
+    deposit
+        .withdrawal_limits()
+        .assign_withdrawal_limit(&DummySubject, account.id, Some(withdrawal_limit.id))
+        .await?;
+
+    let withdrawal = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(80)).unwrap(),
+            None,
+            None,
+        )
+        .await?;
+    deposit
+        .cancel_withdrawal(&DummySubject, withdrawal.id)
+        .await?;
+
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(80)).unwrap(),
+            None,
+            None,
+        )
+        .await?;
+
+    let withdrawal_limit = deposit
+        .withdrawal_limits()
+        .update_withdrawal_limit(
+            &DummySubject,
+            withdrawal_limit.id,
+            UsdCents::try_from_usd(dec!(200)).unwrap(),
+            UsdCents::try_from_usd(dec!(300)).unwrap(),
+        )
+        .await?;
+    assert_eq!(
+        withdrawal_limit.daily_limit,
+        UsdCents::try_from_usd(dec!(200)).unwrap()
+    );
+
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            None,
+            None,
+        )
+        .await?;
+
+    let res = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(30)).unwrap(),
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(deposit::error::CoreDepositError::DailyWithdrawalLimitExceeded { .. })
+    ));
+
+    Ok(())
+}
@@ -147,6 +147,10 @@ impl Satoshis {
         self.0
     }
 
+    pub fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
     pub fn formatted_btc(self) -> String {
         format!("{:.8}", self.to_btc())
     }
@@ -0,0 +1,96 @@
+use async_graphql::*;
+
+use crate::{
+    graphql::{approval_process::ApprovalProcess, loader::LanaDataLoader},
+    primitives::*,
+};
+
+use super::ledger_transaction::LedgerTransaction;
+
+pub use lana_app::accounting::{
+    AccountingPeriod as DomainAccountingPeriod, AccountingPeriodStatus,
+    AccountingPeriodsByCreatedAtCursor,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct AccountingPeriod {
+    id: ID,
+    accounting_period_id: UUID,
+    start_date: Date,
+    end_date: Date,
+    status: AccountingPeriodStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainAccountingPeriod>,
+}
+
+impl From<DomainAccountingPeriod> for AccountingPeriod {
+    fn from(period: DomainAccountingPeriod) -> Self {
+        Self {
+            id: period.id.to_global_id(),
+            accounting_period_id: UUID::from(period.id),
+            start_date: period.start_date.into(),
+            end_date: period.end_date.into(),
+            status: period.status,
+            created_at: period.created_at().into(),
+            entity: Arc::new(period),
+        }
+    }
+}
+
+#[ComplexObject]
+impl AccountingPeriod {
+    async fn closing_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        let Some(ledger_transaction_id) = self.entity.closing_ledger_transaction_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(ledger_transaction_id).await?)
+    }
+
+    async fn reopen_approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.reopen_approval_process_id() else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(approval_process_id)
+            .await?
+            .expect("process not found");
+        Ok(Some(process))
+    }
+}
+
+#[derive(InputObject)]
+pub struct AccountingPeriodCreateInput {
+    pub start_date: Date,
+    pub end_date: Date,
+}
+crate::mutation_payload! { AccountingPeriodCreatePayload, accounting_period: AccountingPeriod }
+
+#[derive(InputObject)]
+pub struct AccountingPeriodSoftCloseInput {
+    pub accounting_period_id: UUID,
+}
+crate::mutation_payload! { AccountingPeriodSoftClosePayload, accounting_period: AccountingPeriod }
+
+#[derive(InputObject)]
+pub struct AccountingPeriodCloseInput {
+    pub accounting_period_id: UUID,
+    pub retained_earnings_account_ref: String,
+}
+crate::mutation_payload! { AccountingPeriodClosePayload, accounting_period: AccountingPeriod }
+
+#[derive(InputObject)]
+pub struct AccountingPeriodRequestReopenInput {
+    pub accounting_period_id: UUID,
+}
+crate::mutation_payload! { AccountingPeriodRequestReopenPayload, accounting_period: AccountingPeriod }
@@ -1,14 +1,102 @@
 use async_graphql::*;
 
-pub use lana_app::accounting::manual_transaction::ManualEntryInput;
+pub use lana_app::accounting::{
+    manual_transaction::ManualEntryInput, ManualTransaction as DomainManualTransaction,
+    ManualTransactionStatus, ManualTransactionsByCreatedAtCursor,
+};
 
-use crate::graphql::primitives::*;
-use crate::primitives::Date;
+use crate::graphql::{approval_process::ApprovalProcess, loader::LanaDataLoader, primitives::*};
+use crate::primitives::*;
 
 use cala_ledger::DebitOrCredit;
 
 use super::ledger_transaction::LedgerTransaction;
 
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct ManualTransaction {
+    id: ID,
+    manual_transaction_id: UUID,
+    ledger_transaction_id: UUID,
+    reference: String,
+    description: String,
+    effective: Date,
+    approval_process_id: Option<UUID>,
+    reversal_of_id: Option<UUID>,
+    reversed_by_id: Option<UUID>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainManualTransaction>,
+}
+
+impl From<DomainManualTransaction> for ManualTransaction {
+    fn from(tx: DomainManualTransaction) -> Self {
+        Self {
+            id: tx.id.to_global_id(),
+            manual_transaction_id: UUID::from(tx.id),
+            ledger_transaction_id: tx.ledger_transaction_id.into(),
+            reference: tx.reference.clone(),
+            description: tx.description.clone(),
+            effective: tx.effective.into(),
+            approval_process_id: tx.approval_process_id.map(UUID::from),
+            reversal_of_id: tx.reversal_of.map(UUID::from),
+            reversed_by_id: tx.reversed_by.map(UUID::from),
+            created_at: tx.created_at().into(),
+            entity: Arc::new(tx),
+        }
+    }
+}
+
+#[ComplexObject]
+impl ManualTransaction {
+    async fn status(&self) -> ManualTransactionStatus {
+        self.entity.status()
+    }
+
+    async fn ledger_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        if !self.entity.is_posted() {
+            return Ok(None);
+        }
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(self.entity.ledger_transaction_id).await?)
+    }
+
+    async fn approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.approval_process_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(approval_process_id)
+            .await?
+            .expect("process not found");
+        Ok(Some(process))
+    }
+
+    async fn reversal_of(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Self>> {
+        let Some(id) = self.entity.reversal_of else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(id).await?)
+    }
+
+    async fn reversed_by(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Self>> {
+        let Some(id) = self.entity.reversed_by else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(id).await?)
+    }
+}
+
 #[derive(InputObject)]
 pub struct ManualTransactionExecuteInput {
     pub description: String,
@@ -16,7 +104,7 @@ pub struct ManualTransactionExecuteInput {
     pub effective: Option<Date>,
     pub entries: Vec<ManualTransactionEntryInput>,
 }
-crate::mutation_payload! { ManualTransactionExecutePayload, transaction: LedgerTransaction }
+crate::mutation_payload! { ManualTransactionExecutePayload, transaction: ManualTransaction }
 
 #[derive(InputObject)]
 pub struct ManualTransactionEntryInput {
@@ -43,3 +131,9 @@ impl TryFrom<ManualTransactionEntryInput> for ManualEntryInput {
         Ok(builder.build().expect("all fields provided"))
     }
 }
+
+#[derive(InputObject)]
+pub struct ManualTransactionReverseInput {
+    pub manual_transaction_id: UUID,
+}
+crate::mutation_payload! { ManualTransactionReversePayload, reversal: ManualTransaction }
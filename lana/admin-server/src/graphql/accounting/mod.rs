@@ -1,3 +1,4 @@
+mod accounting_period;
 mod balance_sheet;
 mod journal_entry;
 mod ledger_account;
@@ -7,6 +8,7 @@ mod profit_and_loss;
 mod transaction_templates;
 mod trial_balance;
 
+pub use accounting_period::*;
 pub use balance_sheet::*;
 pub use journal_entry::*;
 pub use ledger_account::*;
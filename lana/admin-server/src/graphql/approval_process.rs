@@ -3,7 +3,13 @@ use async_graphql::*;
 use crate::primitives::*;
 
 use super::{
-    approval_rules::*, credit_facility::*, loader::LanaDataLoader, policy::*, user::User,
+    accounting::{AccountingPeriod, ManualTransaction},
+    approval_rules::*,
+    credit_facility::*,
+    loader::LanaDataLoader,
+    policy::*,
+    transfer::*,
+    user::User,
     withdrawal::*,
 };
 
@@ -164,6 +170,56 @@ impl ApprovalProcess {
                     .expect("disbursal not found");
                 Ok(ApprovalProcessTarget::CreditFacilityDisbursal(disbursal))
             }
+            ApprovalProcessType::ForbearanceApproval => {
+                let forbearance = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<ForbearanceId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("forbearance not found");
+                Ok(ApprovalProcessTarget::CreditFacilityForbearance(
+                    forbearance,
+                ))
+            }
+            ApprovalProcessType::TransferApproval => {
+                let transfer = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<TransferId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("transfer not found");
+                Ok(ApprovalProcessTarget::Transfer(transfer))
+            }
+            ApprovalProcessType::AccountingPeriodReopenApproval => {
+                let period = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<AccountingPeriodId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("accounting period not found");
+                Ok(ApprovalProcessTarget::AccountingPeriod(period))
+            }
+            ApprovalProcessType::ManualTransactionApproval => {
+                let manual_transaction = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<ManualTransactionId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("manual transaction not found");
+                Ok(ApprovalProcessTarget::ManualTransaction(manual_transaction))
+            }
         }
     }
 }
@@ -174,6 +230,10 @@ pub enum ApprovalProcessType {
     WithdrawalApproval,
     CreditFacilityApproval,
     DisbursalApproval,
+    ForbearanceApproval,
+    TransferApproval,
+    AccountingPeriodReopenApproval,
+    ManualTransactionApproval,
 }
 
 impl From<&governance::ApprovalProcessType> for ApprovalProcessType {
@@ -184,6 +244,14 @@ impl From<&governance::ApprovalProcessType> for ApprovalProcessType {
             Self::CreditFacilityApproval
         } else if process_type == &lana_app::governance::APPROVE_DISBURSAL_PROCESS {
             Self::DisbursalApproval
+        } else if process_type == &lana_app::governance::APPROVE_FORBEARANCE_PROCESS {
+            Self::ForbearanceApproval
+        } else if process_type == &lana_app::governance::APPROVE_TRANSFER_PROCESS {
+            Self::TransferApproval
+        } else if process_type == &lana_app::governance::APPROVE_PERIOD_REOPEN_PROCESS {
+            Self::AccountingPeriodReopenApproval
+        } else if process_type == &lana_app::governance::APPROVE_MANUAL_TRANSACTION_PROCESS {
+            Self::ManualTransactionApproval
         } else {
             panic!("Unknown approval process type: {:?}", process_type);
         }
@@ -221,6 +289,10 @@ pub(super) enum ApprovalProcessTarget {
     Withdrawal(Withdrawal),
     CreditFacility(CreditFacility),
     CreditFacilityDisbursal(CreditFacilityDisbursal),
+    CreditFacilityForbearance(CreditFacilityForbearance),
+    Transfer(Transfer),
+    AccountingPeriod(AccountingPeriod),
+    ManualTransaction(ManualTransaction),
 }
 
 #[derive(InputObject)]
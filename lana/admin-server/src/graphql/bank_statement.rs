@@ -0,0 +1,170 @@
+use async_graphql::{connection::*, *};
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{
+    BankStatementFormat, BankStatementImport as DomainBankStatementImport,
+    BankStatementImportsByCreatedAtCursor, BankStatementLine as DomainBankStatementLine,
+    BankStatementLineStatus, BankStatementLinesByCreatedAtCursor,
+};
+
+use super::{deposit::Deposit, deposit_account::DepositAccount, loader::LanaDataLoader};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct BankStatementImport {
+    id: ID,
+    bank_statement_import_id: UUID,
+    file_name: String,
+    format: BankStatementFormat,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainBankStatementImport>,
+}
+
+impl From<DomainBankStatementImport> for BankStatementImport {
+    fn from(import: DomainBankStatementImport) -> Self {
+        BankStatementImport {
+            id: import.id.to_global_id(),
+            bank_statement_import_id: UUID::from(import.id),
+            file_name: import.file_name.clone(),
+            format: import.format,
+            created_at: import.created_at().into(),
+            entity: Arc::new(import),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct BankStatementImportSummary {
+    total_lines: u32,
+    matched_lines: u32,
+    unmatched_lines: u32,
+    duplicate_lines: u32,
+}
+
+#[ComplexObject]
+impl BankStatementImport {
+    async fn summary(&self) -> Option<BankStatementImportSummary> {
+        self.entity
+            .summary
+            .map(|summary| BankStatementImportSummary {
+                total_lines: summary.total_lines,
+                matched_lines: summary.matched_lines,
+                unmatched_lines: summary.unmatched_lines,
+                duplicate_lines: summary.duplicate_lines,
+            })
+    }
+
+    async fn lines(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            BankStatementLinesByCreatedAtCursor,
+            BankStatementLine,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        crate::list_with_cursor!(
+            BankStatementLinesByCreatedAtCursor,
+            BankStatementLine,
+            ctx,
+            after,
+            first,
+            |query| app
+                .deposits()
+                .bank_statement_imports()
+                .list_bank_statement_lines_for_import(sub, self.entity.id, query)
+        )
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct BankStatementLine {
+    id: ID,
+    bank_statement_line_id: UUID,
+    bank_statement_import_id: UUID,
+    bank_transaction_id: String,
+    amount: UsdCents,
+    booking_date: Date,
+    remittance_information: String,
+    counterparty_name: Option<String>,
+    counterparty_account: Option<String>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainBankStatementLine>,
+}
+
+impl From<DomainBankStatementLine> for BankStatementLine {
+    fn from(line: DomainBankStatementLine) -> Self {
+        BankStatementLine {
+            id: line.id.to_global_id(),
+            bank_statement_line_id: UUID::from(line.id),
+            bank_statement_import_id: UUID::from(line.import_id),
+            bank_transaction_id: line.bank_transaction_id.clone(),
+            amount: line.amount,
+            booking_date: line.booking_date.into(),
+            remittance_information: line.remittance_information.clone(),
+            counterparty_name: line.counterparty_name.clone(),
+            counterparty_account: line.counterparty_account.clone(),
+            created_at: line.created_at().into(),
+            entity: Arc::new(line),
+        }
+    }
+}
+
+#[ComplexObject]
+impl BankStatementLine {
+    async fn status(&self) -> BankStatementLineStatus {
+        self.entity.status()
+    }
+
+    async fn dismissal_reason(&self) -> Option<&str> {
+        self.entity.dismissal_reason()
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<DepositAccount>> {
+        let Some(deposit_account_id) = self.entity.deposit_account_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(deposit_account_id).await?)
+    }
+
+    async fn deposit(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Deposit>> {
+        let Some(deposit_id) = self.entity.deposit_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(deposit_id).await?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct BankStatementImportInput {
+    pub file: Upload,
+    pub format: BankStatementFormat,
+}
+crate::mutation_payload! { BankStatementImportPayload, bank_statement_import: BankStatementImport }
+
+#[derive(InputObject)]
+pub struct BankStatementLineResolveInput {
+    pub bank_statement_line_id: UUID,
+    pub deposit_account_id: UUID,
+}
+crate::mutation_payload! { BankStatementLineResolvePayload, bank_statement_line: BankStatementLine }
+
+#[derive(InputObject)]
+pub struct BankStatementLineDismissInput {
+    pub bank_statement_line_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { BankStatementLineDismissPayload, bank_statement_line: BankStatementLine }
@@ -13,6 +13,7 @@ pub struct CreditModuleConfig {
     chart_of_account_collateral_parent_code: Option<String>,
     chart_of_account_interest_income_parent_code: Option<String>,
     chart_of_account_fee_income_parent_code: Option<String>,
+    chart_of_account_deferred_fee_income_parent_code: Option<String>,
 
     chart_of_account_short_term_individual_disbursed_receivable_parent_code: Option<String>,
     chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: Option<String>,
@@ -99,6 +100,11 @@ impl From<DomainChartOfAccountsIntegrationConfig> for CreditModuleConfig {
             chart_of_account_fee_income_parent_code: Some(
                 values.chart_of_account_fee_income_parent_code.to_string(),
             ),
+            chart_of_account_deferred_fee_income_parent_code: Some(
+                values
+                    .chart_of_account_deferred_fee_income_parent_code
+                    .to_string(),
+            ),
 
             chart_of_account_short_term_individual_disbursed_receivable_parent_code: Some(
                 values
@@ -293,6 +299,7 @@ pub struct CreditModuleConfigureInput {
     pub chart_of_account_collateral_parent_code: String,
     pub chart_of_account_interest_income_parent_code: String,
     pub chart_of_account_fee_income_parent_code: String,
+    pub chart_of_account_deferred_fee_income_parent_code: String,
 
     pub chart_of_account_short_term_individual_disbursed_receivable_parent_code: String,
     pub chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: String,
@@ -0,0 +1,95 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+pub use lana_app::{
+    credit::{
+        CustomerTypeExposureLimit, ExposureLimitPolicy as DomainExposureLimitPolicy,
+        ExposureLimitScope, ExposureLimitUtilization as DomainExposureLimitUtilization,
+        ExposureLimitsConfig,
+    },
+    customer::CustomerType,
+};
+
+#[derive(SimpleObject, Clone)]
+pub struct CreditExposureLimit {
+    scope: ExposureLimitScope,
+    customer_type: Option<CustomerType>,
+    limit: UsdCents,
+    exposure: UsdCents,
+    remaining: UsdCents,
+}
+
+impl From<DomainExposureLimitUtilization> for CreditExposureLimit {
+    fn from(utilization: DomainExposureLimitUtilization) -> Self {
+        Self {
+            scope: utilization.scope,
+            customer_type: utilization.customer_type,
+            limit: utilization.limit,
+            exposure: utilization.exposure,
+            remaining: utilization.remaining(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct CreditCustomerTypeExposureLimit {
+    customer_type: CustomerType,
+    limit: UsdCents,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct CreditExposureLimitPolicy {
+    customer_limit: Option<UsdCents>,
+    customer_type_limits: Vec<CreditCustomerTypeExposureLimit>,
+    portfolio_limit: Option<UsdCents>,
+}
+
+impl From<DomainExposureLimitPolicy> for CreditExposureLimitPolicy {
+    fn from(policy: DomainExposureLimitPolicy) -> Self {
+        Self {
+            customer_limit: policy.limits.customer,
+            customer_type_limits: policy
+                .limits
+                .customer_types
+                .into_iter()
+                .map(|l| CreditCustomerTypeExposureLimit {
+                    customer_type: l.customer_type,
+                    limit: l.limit,
+                })
+                .collect(),
+            portfolio_limit: policy.limits.portfolio,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreditCustomerTypeExposureLimitInput {
+    pub customer_type: CustomerType,
+    pub limit: UsdCents,
+}
+
+#[derive(InputObject)]
+pub struct CreditExposureLimitPolicyUpdateInput {
+    pub customer_limit: Option<UsdCents>,
+    pub customer_type_limits: Vec<CreditCustomerTypeExposureLimitInput>,
+    pub portfolio_limit: Option<UsdCents>,
+}
+
+impl From<CreditExposureLimitPolicyUpdateInput> for ExposureLimitsConfig {
+    fn from(input: CreditExposureLimitPolicyUpdateInput) -> Self {
+        Self {
+            customer: input.customer_limit,
+            customer_types: input
+                .customer_type_limits
+                .into_iter()
+                .map(|l| CustomerTypeExposureLimit {
+                    customer_type: l.customer_type,
+                    limit: l.limit,
+                })
+                .collect(),
+            portfolio: input.portfolio_limit,
+        }
+    }
+}
+
+crate::mutation_payload! { CreditExposureLimitPolicyUpdatePayload, exposure_limit_policy: CreditExposureLimitPolicy }
@@ -0,0 +1,76 @@
+use async_graphql::*;
+
+use super::CreditFacility;
+use crate::{
+    graphql::{approval_process::*, loader::LanaDataLoader},
+    primitives::*,
+};
+pub use lana_app::credit::Forbearance as DomainForbearance;
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct CreditFacilityForbearance {
+    id: ID,
+    forbearance_id: UUID,
+    starts_at: Timestamp,
+    ends_at: Timestamp,
+    capitalize_interest: bool,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainForbearance>,
+}
+
+impl From<DomainForbearance> for CreditFacilityForbearance {
+    fn from(forbearance: DomainForbearance) -> Self {
+        Self {
+            id: forbearance.id.to_global_id(),
+            forbearance_id: UUID::from(forbearance.id),
+            starts_at: forbearance.starts_at.into(),
+            ends_at: forbearance.ends_at.into(),
+            capitalize_interest: forbearance.capitalize_interest,
+            created_at: forbearance.created_at().into(),
+            entity: Arc::new(forbearance),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CreditFacilityForbearance {
+    async fn credit_facility(&self, ctx: &Context<'_>) -> async_graphql::Result<CreditFacility> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let facility = loader
+            .load_one(self.entity.credit_facility_id)
+            .await?
+            .expect("credit facility not found");
+        Ok(facility)
+    }
+
+    async fn status(&self, ctx: &Context<'_>) -> async_graphql::Result<ForbearanceStatus> {
+        let (app, _) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .credit()
+            .ensure_up_to_date_forbearance_status(&self.entity)
+            .await?
+            .map(|f| f.status())
+            .unwrap_or_else(|| self.entity.status()))
+    }
+
+    async fn approval_process(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcess> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(self.entity.approval_process_id)
+            .await?
+            .expect("process not found");
+        Ok(process)
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreditFacilityForbearanceInitiateInput {
+    pub credit_facility_id: UUID,
+    pub starts_at: Timestamp,
+    pub ends_at: Timestamp,
+    pub capitalize_interest: bool,
+}
+crate::mutation_payload! { CreditFacilityForbearanceInitiatePayload, forbearance: CreditFacilityForbearance }
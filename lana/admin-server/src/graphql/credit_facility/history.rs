@@ -1,7 +1,8 @@
 use async_graphql::*;
 
 use crate::primitives::*;
-pub use lana_app::primitives::CollateralAction;
+pub use lana_app::primitives::{AutoDebitOutcome, CollateralAction};
+use lana_app::terms::{AnnualRatePct, CVLPct};
 
 #[derive(async_graphql::Union)]
 pub enum CreditFacilityHistoryEntry {
@@ -11,6 +12,8 @@ pub enum CreditFacilityHistoryEntry {
     Collateralization(CreditFacilityCollateralizationUpdated),
     Disbursal(CreditFacilityDisbursalExecuted),
     Interest(CreditFacilityInterestAccrued),
+    InterestRate(CreditFacilityInterestRateAdjusted),
+    AutoDebit(CreditFacilityAutoDebitAttempted),
 }
 
 #[derive(SimpleObject)]
@@ -60,6 +63,24 @@ pub struct CreditFacilityInterestAccrued {
     pub days: i64,
 }
 
+#[derive(SimpleObject)]
+pub struct CreditFacilityInterestRateAdjusted {
+    pub annual_rate: AnnualRatePct,
+    pub previous_annual_rate: AnnualRatePct,
+    pub cvl: CVLPct,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct CreditFacilityAutoDebitAttempted {
+    pub obligation_id: UUID,
+    pub requested: UsdCents,
+    pub collected: UsdCents,
+    pub outcome: AutoDebitOutcome,
+    pub failure_reason: Option<String>,
+    pub recorded_at: Timestamp,
+}
+
 impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistoryEntry {
     fn from(transaction: lana_app::credit::CreditFacilityHistoryEntry) -> Self {
         match transaction {
@@ -81,6 +102,12 @@ impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistor
             lana_app::credit::CreditFacilityHistoryEntry::Interest(interest) => {
                 CreditFacilityHistoryEntry::Interest(interest.into())
             }
+            lana_app::credit::CreditFacilityHistoryEntry::InterestRate(rate) => {
+                CreditFacilityHistoryEntry::InterestRate(rate.into())
+            }
+            lana_app::credit::CreditFacilityHistoryEntry::AutoDebit(attempt) => {
+                CreditFacilityHistoryEntry::AutoDebit(attempt.into())
+            }
         }
     }
 }
@@ -149,3 +176,27 @@ impl From<lana_app::credit::InterestAccrualsPosted> for CreditFacilityInterestAc
         }
     }
 }
+
+impl From<lana_app::credit::InterestRateAdjusted> for CreditFacilityInterestRateAdjusted {
+    fn from(rate: lana_app::credit::InterestRateAdjusted) -> Self {
+        Self {
+            annual_rate: rate.annual_rate,
+            previous_annual_rate: rate.previous_annual_rate,
+            cvl: rate.cvl,
+            recorded_at: rate.recorded_at.into(),
+        }
+    }
+}
+
+impl From<lana_app::credit::AutoDebitAttempt> for CreditFacilityAutoDebitAttempted {
+    fn from(attempt: lana_app::credit::AutoDebitAttempt) -> Self {
+        Self {
+            obligation_id: UUID::from(attempt.obligation_id),
+            requested: attempt.requested,
+            collected: attempt.collected,
+            outcome: attempt.outcome,
+            failure_reason: attempt.failure_reason,
+            recorded_at: attempt.recorded_at.into(),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 mod balance;
 pub(super) mod disbursal;
 mod error;
+mod exposure_limit;
+pub(super) mod forbearance;
 mod history;
 pub(super) mod payment;
 mod repayment;
@@ -24,6 +26,8 @@ pub use lana_app::{
 pub use balance::*;
 pub use disbursal::*;
 pub use error::*;
+pub use exposure_limit::*;
+pub use forbearance::*;
 pub use history::*;
 pub use repayment::*;
 
@@ -76,6 +80,10 @@ impl CreditFacility {
         self.entity.terms.into()
     }
 
+    async fn auto_debit_deposit_account_id(&self) -> Option<UUID> {
+        self.entity.auto_debit_account_id().map(UUID::from)
+    }
+
     async fn status(&self, ctx: &Context<'_>) -> async_graphql::Result<CreditFacilityStatus> {
         let (app, _) = crate::app_and_sub_from_ctx!(ctx);
         Ok(app
@@ -135,6 +143,23 @@ impl CreditFacility {
             .collect())
     }
 
+    async fn forbearances(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<CreditFacilityForbearance>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let forbearances = app
+            .credit()
+            .list_forbearances_for_credit_facility(sub, self.entity.id)
+            .await?;
+
+        Ok(forbearances
+            .into_iter()
+            .map(CreditFacilityForbearance::from)
+            .collect())
+    }
+
     async fn approval_process(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcess> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let process = loader
@@ -168,6 +193,18 @@ impl CreditFacility {
             .is_ok())
     }
 
+    async fn subject_can_initiate_forbearance(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<bool> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .credit()
+            .subject_can_initiate_forbearance(sub, false)
+            .await
+            .is_ok())
+    }
+
     async fn subject_can_record_payment(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
         Ok(app
@@ -229,6 +266,27 @@ pub struct CreditFacilityCollateralUpdateInput {
 }
 crate::mutation_payload! { CreditFacilityCollateralUpdatePayload, credit_facility: CreditFacility }
 
+#[derive(InputObject)]
+pub struct CreditFacilityCollateralFundInput {
+    pub credit_facility_id: UUID,
+    pub deposit_account_id: UUID,
+    pub amount: Satoshis,
+}
+crate::mutation_payload! { CreditFacilityCollateralFundPayload, credit_facility: CreditFacility }
+
+#[derive(InputObject)]
+pub struct CreditFacilityAutoDebitEnableInput {
+    pub credit_facility_id: UUID,
+    pub deposit_account_id: UUID,
+}
+crate::mutation_payload! { CreditFacilityAutoDebitEnablePayload, credit_facility: CreditFacility }
+
+#[derive(InputObject)]
+pub struct CreditFacilityAutoDebitDisableInput {
+    pub credit_facility_id: UUID,
+}
+crate::mutation_payload! { CreditFacilityAutoDebitDisablePayload, credit_facility: CreditFacility }
+
 #[derive(InputObject)]
 pub struct CreditFacilityPartialPaymentInput {
     pub credit_facility_id: UUID,
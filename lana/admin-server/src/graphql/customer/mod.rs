@@ -1,4 +1,5 @@
 mod error;
+mod signatory;
 
 use async_graphql::*;
 
@@ -17,6 +18,7 @@ pub use lana_app::{
 };
 
 pub use error::*;
+pub use signatory::*;
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -107,6 +109,21 @@ impl Customer {
         Ok(credit_facilities)
     }
 
+    async fn credit_exposure_limits(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<CreditExposureLimit>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let exposure_limits = app
+            .credit()
+            .customer_exposure_limits(sub, self.entity.id)
+            .await?;
+        Ok(exposure_limits
+            .into_iter()
+            .map(CreditExposureLimit::from)
+            .collect())
+    }
+
     async fn documents(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Document>> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
         let documents = app
@@ -116,6 +133,15 @@ impl Customer {
         Ok(documents.into_iter().map(Document::from).collect())
     }
 
+    async fn signatories(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Signatory>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let signatories = app
+            .customers()
+            .list_signatories_for_customer(sub, self.entity.id)
+            .await?;
+        Ok(signatories.into_iter().map(Signatory::from).collect())
+    }
+
     async fn subject_can_create_credit_facility(
         &self,
         ctx: &Context<'_>,
@@ -0,0 +1,51 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::customer::{Signatory as DomainSignatory, SignatoryRole, SignatoryStatus};
+
+#[derive(SimpleObject, Clone)]
+pub struct Signatory {
+    id: ID,
+    signatory_id: UUID,
+    customer_id: UUID,
+    email: String,
+    role: SignatoryRole,
+    status: SignatoryStatus,
+    created_at: Timestamp,
+}
+
+impl From<DomainSignatory> for Signatory {
+    fn from(signatory: DomainSignatory) -> Self {
+        Signatory {
+            created_at: signatory.created_at().into(),
+            id: signatory.id.to_global_id(),
+            signatory_id: UUID::from(signatory.id),
+            customer_id: UUID::from(signatory.customer_id),
+            email: signatory.email,
+            role: signatory.role,
+            status: signatory.status,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CustomerSignatoryAddInput {
+    pub customer_id: UUID,
+    pub email: String,
+    pub role: SignatoryRole,
+}
+crate::mutation_payload! { CustomerSignatoryAddPayload, signatory: Signatory }
+
+#[derive(InputObject)]
+pub struct CustomerSignatoryRoleUpdateInput {
+    pub signatory_id: UUID,
+    pub role: SignatoryRole,
+}
+crate::mutation_payload! { CustomerSignatoryRoleUpdatePayload, signatory: Signatory }
+
+#[derive(InputObject)]
+pub struct CustomerSignatoryRemoveInput {
+    pub signatory_id: UUID,
+}
+crate::mutation_payload! { CustomerSignatoryRemovePayload, signatory: Signatory }
@@ -6,7 +6,10 @@ use super::loader::LanaDataLoader;
 
 pub use super::deposit_account::DepositAccount;
 
-pub use lana_app::deposit::{Deposit as DomainDeposit, DepositsByCreatedAtCursor};
+pub use lana_app::deposit::{
+    Deposit as DomainDeposit, DepositAccountCurrency, DepositReversalReason, DepositStatus,
+    DepositsByCreatedAtCursor,
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -14,7 +17,11 @@ pub struct Deposit {
     id: ID,
     deposit_id: UUID,
     account_id: UUID,
+    currency: DepositAccountCurrency,
     amount: UsdCents,
+    btc_amount: Option<Satoshis>,
+    status: DepositStatus,
+    reversal_reason: Option<DepositReversalReason>,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -27,7 +34,11 @@ impl From<DomainDeposit> for Deposit {
             id: deposit.id.to_global_id(),
             deposit_id: UUID::from(deposit.id),
             account_id: UUID::from(deposit.deposit_account_id),
-            amount: deposit.amount,
+            currency: deposit.amount.currency(),
+            amount: deposit.amount.usd().unwrap_or(UsdCents::ZERO),
+            btc_amount: deposit.amount.btc(),
+            status: deposit.status(),
+            reversal_reason: deposit.reversal_reason(),
             created_at: deposit.created_at().into(),
 
             entity: Arc::new(deposit),
@@ -58,3 +69,18 @@ pub struct DepositRecordInput {
     pub reference: Option<String>,
 }
 crate::mutation_payload! { DepositRecordPayload, deposit: Deposit }
+
+#[derive(InputObject)]
+pub struct BtcDepositRecordInput {
+    pub deposit_account_id: UUID,
+    pub amount: Satoshis,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { BtcDepositRecordPayload, deposit: Deposit }
+
+#[derive(InputObject)]
+pub struct DepositRevertInput {
+    pub deposit_id: UUID,
+    pub reason: DepositReversalReason,
+}
+crate::mutation_payload! { DepositRevertPayload, deposit: Deposit }
@@ -3,11 +3,16 @@ use async_graphql::{connection::*, *};
 use crate::primitives::*;
 
 pub use lana_app::deposit::{
-    DepositAccount as DomainDepositAccount, DepositAccountHistoryCursor,
-    DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
+    DepositAccount as DomainDepositAccount, DepositAccountCurrency, DepositAccountHistoryCursor,
+    DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry, DepositAccountStatus,
+    DormantAccountReportEntry as DomainDormantAccountReportEntry, TimeDepositTerms,
 };
 
-use super::{customer::Customer, deposit::*, deposit_account_history::*, withdrawal::*};
+use super::{
+    customer::Customer, deposit::*, deposit_account_history::*, deposit_account_statement::*,
+    deposit_fee::*, deposit_product::*, hold::*, loader::LanaDataLoader, standing_order::*,
+    withdrawal::*, withdrawal_limit::*,
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -15,6 +20,9 @@ pub struct DepositAccount {
     id: ID,
     deposit_account_id: UUID,
     customer_id: UUID,
+    status: DepositAccountStatus,
+    currency: DepositAccountCurrency,
+    time_deposit: Option<TimeDeposit>,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -27,6 +35,9 @@ impl From<DomainDepositAccount> for DepositAccount {
             id: account.id.to_global_id(),
             deposit_account_id: account.id.into(),
             customer_id: account.account_holder_id.into(),
+            status: account.status,
+            currency: account.currency,
+            time_deposit: account.time_deposit_terms.map(TimeDeposit::from),
             created_at: account.created_at().into(),
 
             entity: Arc::new(account),
@@ -34,10 +45,33 @@ impl From<DomainDepositAccount> for DepositAccount {
     }
 }
 
+#[derive(SimpleObject, Clone)]
+pub struct TimeDeposit {
+    principal: UsdCents,
+    annual_rate: InterestRatePct,
+    start_date: Date,
+    maturity_date: Date,
+    payout_deposit_account_id: UUID,
+}
+
+impl From<TimeDepositTerms> for TimeDeposit {
+    fn from(terms: TimeDepositTerms) -> Self {
+        Self {
+            principal: terms.principal,
+            annual_rate: terms.annual_rate,
+            start_date: terms.start_date.into(),
+            maturity_date: terms.maturity_date.into(),
+            payout_deposit_account_id: terms.payout_account_id.into(),
+        }
+    }
+}
+
 #[derive(SimpleObject)]
 pub struct DepositAccountBalance {
     settled: UsdCents,
     pending: UsdCents,
+    encumbrance: UsdCents,
+    available: UsdCents,
 }
 
 impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
@@ -45,10 +79,107 @@ impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
         Self {
             settled: balance.settled,
             pending: balance.pending,
+            encumbrance: balance.encumbrance,
+            available: balance.available(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct BtcDepositAccountBalance {
+    settled: Satoshis,
+    pending: Satoshis,
+    encumbrance: Satoshis,
+    available: Satoshis,
+}
+
+impl From<lana_app::deposit::BtcDepositAccountBalance> for BtcDepositAccountBalance {
+    fn from(balance: lana_app::deposit::BtcDepositAccountBalance) -> Self {
+        Self {
+            settled: balance.settled,
+            pending: balance.pending,
+            encumbrance: balance.encumbrance,
+            available: balance.available(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositAccountBtcCreateInput {
+    pub customer_id: UUID,
+}
+crate::mutation_payload! { DepositAccountBtcCreatePayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountFreezeInput {
+    pub deposit_account_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { DepositAccountFreezePayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountUnfreezeInput {
+    pub deposit_account_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { DepositAccountUnfreezePayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountReactivateInput {
+    pub deposit_account_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { DepositAccountReactivatePayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountUnclaimedFundsTransferInput {
+    pub deposit_account_id: UUID,
+}
+crate::mutation_payload! { DepositAccountUnclaimedFundsTransferPayload, account: DepositAccount }
+
+#[derive(SimpleObject)]
+pub struct DormantDepositAccount {
+    account: DepositAccount,
+    dormant_since: Timestamp,
+    last_activity_at: Timestamp,
+    balance: UsdCents,
+}
+
+impl From<DomainDormantAccountReportEntry> for DormantDepositAccount {
+    fn from(entry: DomainDormantAccountReportEntry) -> Self {
+        Self {
+            account: DepositAccount::from(entry.account),
+            dormant_since: entry.dormant_since.into(),
+            last_activity_at: entry.last_activity_at.into(),
+            balance: entry.balance,
         }
     }
 }
 
+#[derive(InputObject)]
+pub struct DepositAccountCloseInput {
+    pub deposit_account_id: UUID,
+    pub reason: String,
+    pub sweep_to_deposit_account_id: Option<UUID>,
+}
+crate::mutation_payload! { DepositAccountClosePayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct TimeDepositOpenInput {
+    pub funding_deposit_account_id: UUID,
+    pub principal: UsdCents,
+    pub annual_rate: InterestRatePct,
+    pub maturity_date: Date,
+}
+crate::mutation_payload! { TimeDepositOpenPayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct TimeDepositBreakInput {
+    pub deposit_account_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { TimeDepositBreakPayload, account: DepositAccount }
+
 #[ComplexObject]
 impl DepositAccount {
     async fn deposits(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Deposit>> {
@@ -69,6 +200,42 @@ impl DepositAccount {
         Ok(withdrawals.into_iter().map(Withdrawal::from).collect())
     }
 
+    async fn holds(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Hold>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let holds = app
+            .deposits()
+            .holds()
+            .list_holds_for_account(sub, self.entity.id)
+            .await?;
+        Ok(holds.into_iter().map(Hold::from).collect())
+    }
+
+    async fn standing_orders(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<StandingOrder>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let standing_orders = app
+            .deposits()
+            .standing_orders()
+            .list_standing_orders_for_account(sub, self.entity.id)
+            .await?;
+        Ok(standing_orders
+            .into_iter()
+            .map(StandingOrder::from)
+            .collect())
+    }
+
+    async fn fees(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<DepositFee>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let fees = app
+            .deposits()
+            .fees()
+            .list_fees_for_account(sub, self.entity.id)
+            .await?;
+        Ok(fees.into_iter().map(DepositFee::from).collect())
+    }
+
     async fn history(
         &self,
         ctx: &Context<'_>,
@@ -119,6 +286,74 @@ impl DepositAccount {
         Ok(DepositAccountBalance::from(balance))
     }
 
+    async fn btc_balance(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<BtcDepositAccountBalance> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let balance = app
+            .deposits()
+            .account_btc_balance(sub, self.entity.id)
+            .await?;
+        Ok(BtcDepositAccountBalance::from(balance))
+    }
+
+    async fn deposit_product(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<DepositProduct>> {
+        if let Some(deposit_product_id) = self.entity.deposit_product_id {
+            let loader = ctx.data_unchecked::<LanaDataLoader>();
+            Ok(loader.load_one(deposit_product_id).await?)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn withdrawal_limit(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<WithdrawalLimit>> {
+        if let Some(withdrawal_limit_id) = self.entity.withdrawal_limit_id {
+            let loader = ctx.data_unchecked::<LanaDataLoader>();
+            Ok(loader.load_one(withdrawal_limit_id).await?)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn withdrawal_limit_overrides(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<WithdrawalLimitOverride>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let overrides = app
+            .deposits()
+            .withdrawal_limits()
+            .list_withdrawal_limit_overrides_for_account(sub, self.entity.id)
+            .await?;
+        Ok(overrides
+            .into_iter()
+            .map(WithdrawalLimitOverride::from)
+            .collect())
+    }
+
+    async fn statements(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DepositAccountStatement>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let statements = app
+            .deposits()
+            .statements()
+            .list_statements_for_account(sub, self.entity.id)
+            .await?;
+        Ok(statements
+            .into_iter()
+            .map(DepositAccountStatement::from)
+            .collect())
+    }
+
     async fn customer(&self, ctx: &Context<'_>) -> async_graphql::Result<Customer> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
         let customer = app
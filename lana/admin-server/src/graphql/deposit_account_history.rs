@@ -2,18 +2,28 @@ use async_graphql::*;
 
 use crate::primitives::*;
 
+use lana_app::deposit::DepositAccountStatus;
+
 use super::{
     credit_facility::disbursal::CreditFacilityDisbursal,
-    credit_facility::payment::CreditFacilityPayment, deposit::Deposit, withdrawal::Withdrawal,
+    credit_facility::payment::CreditFacilityPayment, deposit::Deposit, deposit_fee::DepositFee,
+    transfer::Transfer, withdrawal::Withdrawal,
 };
 
 #[derive(Union)]
 pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
+    RevertedDeposit(RevertedDepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(CancelledWithdrawalEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
+    TransferIn(TransferInEntry),
+    TransferOut(TransferOutEntry),
+    UnclaimedFundsTransfer(UnclaimedFundsTransferEntry),
+    Fee(FeeEntry),
+    WaivedFee(WaivedFeeEntry),
+    StatusChanged(AccountStatusChangedEntry),
     Unknown(UnknownEntry),
 }
 
@@ -25,6 +35,14 @@ pub struct DepositEntry {
     pub recorded_at: Timestamp,
 }
 
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct RevertedDepositEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
 #[derive(SimpleObject)]
 #[graphql(complex)]
 pub struct WithdrawalEntry {
@@ -56,6 +74,51 @@ pub struct PaymentEntry {
     pub recorded_at: Timestamp,
 }
 
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct TransferInEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct TransferOutEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct UnclaimedFundsTransferEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct FeeEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct WaivedFeeEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct AccountStatusChangedEntry {
+    pub status: DepositAccountStatus,
+    pub reason: String,
+    pub recorded_at: Timestamp,
+}
+
 #[derive(SimpleObject)]
 pub struct UnknownEntry {
     pub tx_id: UUID,
@@ -77,6 +140,20 @@ impl DepositEntry {
     }
 }
 
+#[ComplexObject]
+impl RevertedDepositEntry {
+    async fn deposit(&self, ctx: &Context<'_>) -> async_graphql::Result<Deposit> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let deposit = app
+            .deposits()
+            .find_deposit_by_reverted_tx_id(sub, self.tx_id)
+            .await?;
+
+        Ok(Deposit::from(deposit))
+    }
+}
+
 #[ComplexObject]
 impl WithdrawalEntry {
     async fn withdrawal(&self, ctx: &Context<'_>) -> async_graphql::Result<Withdrawal> {
@@ -131,6 +208,67 @@ impl PaymentEntry {
     }
 }
 
+#[ComplexObject]
+impl TransferInEntry {
+    async fn transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Transfer> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let transfer = app
+            .deposits()
+            .find_transfer_by_id(sub, self.tx_id)
+            .await?
+            .expect("transfer should exist");
+
+        Ok(Transfer::from(transfer))
+    }
+}
+
+#[ComplexObject]
+impl TransferOutEntry {
+    async fn transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Transfer> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let transfer = app
+            .deposits()
+            .find_transfer_by_id(sub, self.tx_id)
+            .await?
+            .expect("transfer should exist");
+
+        Ok(Transfer::from(transfer))
+    }
+}
+
+#[ComplexObject]
+impl FeeEntry {
+    async fn fee(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositFee> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let fee = app
+            .deposits()
+            .fees()
+            .find_deposit_fee_by_id(sub, self.tx_id)
+            .await?
+            .expect("fee should exist");
+
+        Ok(DepositFee::from(fee))
+    }
+}
+
+#[ComplexObject]
+impl WaivedFeeEntry {
+    async fn fee(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositFee> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let fee = app
+            .deposits()
+            .fees()
+            .find_deposit_fee_by_waived_tx_id(sub, self.tx_id)
+            .await?;
+
+        Ok(DepositFee::from(fee))
+    }
+}
+
 impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHistoryEntry {
     fn from(entry: lana_app::deposit::DepositAccountHistoryEntry) -> Self {
         match entry {
@@ -140,6 +278,12 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::RevertedDeposit(entry) => {
+                Self::RevertedDeposit(RevertedDepositEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Withdrawal(entry) => {
                 Self::Withdrawal(WithdrawalEntry {
                     tx_id: UUID::from(entry.tx_id),
@@ -164,6 +308,41 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferIn(entry) => {
+                Self::TransferIn(TransferInEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferOut(entry) => {
+                Self::TransferOut(TransferOutEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::UnclaimedFundsTransfer(entry) => {
+                Self::UnclaimedFundsTransfer(UnclaimedFundsTransferEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::Fee(entry) => Self::Fee(FeeEntry {
+                tx_id: UUID::from(entry.tx_id),
+                recorded_at: entry.recorded_at.into(),
+            }),
+            lana_app::deposit::DepositAccountHistoryEntry::WaivedFee(entry) => {
+                Self::WaivedFee(WaivedFeeEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::StatusChanged(entry) => {
+                Self::StatusChanged(AccountStatusChangedEntry {
+                    status: entry.status,
+                    reason: entry.reason,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Unknown(entry) => {
                 Self::Unknown(UnknownEntry {
                     tx_id: UUID::from(entry.tx_id),
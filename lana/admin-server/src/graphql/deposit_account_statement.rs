@@ -0,0 +1,94 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{
+    DepositAccountStatement as DomainDepositAccountStatement, DepositAccountStatementDownloadLinks,
+    DepositAccountStatementStatus,
+};
+
+use super::{deposit_account::DepositAccount, loader::LanaDataLoader};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct DepositAccountStatement {
+    id: ID,
+    deposit_account_statement_id: UUID,
+    account_id: UUID,
+    period_start: Date,
+    period_end: Date,
+    opening_balance: Option<UsdCents>,
+    closing_balance: Option<UsdCents>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainDepositAccountStatement>,
+}
+
+impl From<DomainDepositAccountStatement> for DepositAccountStatement {
+    fn from(statement: DomainDepositAccountStatement) -> Self {
+        DepositAccountStatement {
+            id: statement.id.to_global_id(),
+            deposit_account_statement_id: UUID::from(statement.id),
+            account_id: statement.deposit_account_id.into(),
+            period_start: statement.period_start.into(),
+            period_end: statement.period_end.into(),
+            opening_balance: statement.opening_balance,
+            closing_balance: statement.closing_balance,
+            created_at: statement.created_at().into(),
+            entity: Arc::new(statement),
+        }
+    }
+}
+
+#[ComplexObject]
+impl DepositAccountStatement {
+    async fn status(&self) -> DepositAccountStatementStatus {
+        self.entity.status()
+    }
+
+    async fn error(&self) -> Option<&str> {
+        self.entity.last_error()
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositAccountStatementGenerateInput {
+    pub deposit_account_id: UUID,
+    pub period_start: Date,
+    pub period_end: Date,
+}
+crate::mutation_payload! { DepositAccountStatementGeneratePayload, statement: DepositAccountStatement }
+
+#[derive(InputObject)]
+pub struct DepositAccountStatementDownloadLinksGenerateInput {
+    pub deposit_account_statement_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct DepositAccountStatementDownloadLinksGeneratePayload {
+    deposit_account_statement_id: UUID,
+    pdf_link: String,
+    csv_link: String,
+}
+
+impl From<DepositAccountStatementDownloadLinks>
+    for DepositAccountStatementDownloadLinksGeneratePayload
+{
+    fn from(links: DepositAccountStatementDownloadLinks) -> Self {
+        Self {
+            deposit_account_statement_id: UUID::from(links.deposit_account_statement_id),
+            pdf_link: links.pdf_url,
+            csv_link: links.csv_url,
+        }
+    }
+}
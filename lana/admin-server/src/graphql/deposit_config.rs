@@ -14,6 +14,11 @@ pub struct DepositModuleConfig {
     chart_of_account_bank_deposit_accounts_parent_code: Option<String>,
     chart_of_account_financial_institution_deposit_accounts_parent_code: Option<String>,
     chart_of_account_non_domiciled_company_deposit_accounts_parent_code: Option<String>,
+    chart_of_accounts_time_deposit_accounts_parent_code: Option<String>,
+    chart_of_accounts_interest_expense_parent_code: Option<String>,
+    chart_of_accounts_accrued_interest_parent_code: Option<String>,
+    chart_of_accounts_unclaimed_funds_parent_code: Option<String>,
+    chart_of_accounts_fee_income_parent_code: Option<String>,
 
     #[graphql(skip)]
     pub(super) _entity: Arc<DomainChartOfAccountsIntegrationConfig>,
@@ -56,6 +61,29 @@ impl From<DomainChartOfAccountsIntegrationConfig> for DepositModuleConfig {
                     .chart_of_account_non_domiciled_individual_deposit_accounts_parent_code
                     .to_string(),
             ),
+            chart_of_accounts_time_deposit_accounts_parent_code: Some(
+                values
+                    .chart_of_accounts_time_deposit_accounts_parent_code
+                    .to_string(),
+            ),
+            chart_of_accounts_interest_expense_parent_code: Some(
+                values
+                    .chart_of_accounts_interest_expense_parent_code
+                    .to_string(),
+            ),
+            chart_of_accounts_accrued_interest_parent_code: Some(
+                values
+                    .chart_of_accounts_accrued_interest_parent_code
+                    .to_string(),
+            ),
+            chart_of_accounts_unclaimed_funds_parent_code: Some(
+                values
+                    .chart_of_accounts_unclaimed_funds_parent_code
+                    .to_string(),
+            ),
+            chart_of_accounts_fee_income_parent_code: Some(
+                values.chart_of_accounts_fee_income_parent_code.to_string(),
+            ),
 
             _entity: Arc::new(values),
         }
@@ -71,5 +99,10 @@ pub struct DepositModuleConfigureInput {
     pub chart_of_account_bank_deposit_accounts_parent_code: String,
     pub chart_of_account_financial_institution_deposit_accounts_parent_code: String,
     pub chart_of_account_non_domiciled_individual_deposit_accounts_parent_code: String,
+    pub chart_of_accounts_time_deposit_accounts_parent_code: String,
+    pub chart_of_accounts_interest_expense_parent_code: String,
+    pub chart_of_accounts_accrued_interest_parent_code: String,
+    pub chart_of_accounts_unclaimed_funds_parent_code: String,
+    pub chart_of_accounts_fee_income_parent_code: String,
 }
 crate::mutation_payload! { DepositModuleConfigurePayload, deposit_config: DepositModuleConfig }
@@ -0,0 +1,61 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{deposit_account::DepositAccount, loader::LanaDataLoader};
+
+pub use lana_app::deposit::{DepositFee as DomainDepositFee, DepositFeeStatus, DepositFeeType};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct DepositFee {
+    id: ID,
+    deposit_fee_id: UUID,
+    account_id: UUID,
+    fee_type: DepositFeeType,
+    amount: UsdCents,
+    status: DepositFeeStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainDepositFee>,
+}
+
+impl From<DomainDepositFee> for DepositFee {
+    fn from(fee: DomainDepositFee) -> Self {
+        DepositFee {
+            id: fee.id.to_global_id(),
+            deposit_fee_id: UUID::from(fee.id),
+            account_id: UUID::from(fee.deposit_account_id),
+            fee_type: fee.fee_type,
+            amount: fee.amount,
+            status: fee.status(),
+            created_at: fee.created_at().into(),
+
+            entity: Arc::new(fee),
+        }
+    }
+}
+
+#[ComplexObject]
+impl DepositFee {
+    async fn waiver_reason(&self) -> Option<&str> {
+        self.entity.waiver_reason()
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositFeeWaiveInput {
+    pub deposit_fee_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { DepositFeeWaivePayload, deposit_fee: DepositFee }
@@ -0,0 +1,98 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{
+    DepositFeeSchedule as DomainDepositFeeSchedule, DepositProduct as DomainDepositProduct,
+    DepositProductsByCreatedAtCursor,
+};
+
+use super::deposit_account::DepositAccount;
+
+#[derive(SimpleObject, Clone)]
+pub struct DepositProduct {
+    id: ID,
+    deposit_product_id: UUID,
+    name: String,
+    annual_rate: InterestRatePct,
+    fee_schedule: DepositFeeSchedule,
+    early_break_penalty: EarlyBreakPenaltyPct,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainDepositProduct>,
+}
+
+impl From<DomainDepositProduct> for DepositProduct {
+    fn from(product: DomainDepositProduct) -> Self {
+        DepositProduct {
+            id: product.id.to_global_id(),
+            deposit_product_id: UUID::from(product.id),
+            name: product.name.to_string(),
+            annual_rate: product.annual_rate,
+            fee_schedule: product.fee_schedule.into(),
+            early_break_penalty: product.early_break_penalty,
+            created_at: product.created_at().into(),
+
+            entity: Arc::new(product),
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct DepositFeeSchedule {
+    monthly_maintenance_fee: UsdCents,
+    withdrawal_fee: UsdCents,
+    minimum_balance: UsdCents,
+    below_minimum_balance_fee: UsdCents,
+}
+
+impl From<DomainDepositFeeSchedule> for DepositFeeSchedule {
+    fn from(fee_schedule: DomainDepositFeeSchedule) -> Self {
+        DepositFeeSchedule {
+            monthly_maintenance_fee: fee_schedule.monthly_maintenance_fee,
+            withdrawal_fee: fee_schedule.withdrawal_fee,
+            minimum_balance: fee_schedule.minimum_balance,
+            below_minimum_balance_fee: fee_schedule.below_minimum_balance_fee,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositProductCreateInput {
+    pub name: String,
+    pub annual_rate: InterestRatePct,
+}
+crate::mutation_payload! { DepositProductCreatePayload, deposit_product: DepositProduct }
+
+#[derive(InputObject)]
+pub struct DepositProductRateUpdateInput {
+    pub deposit_product_id: UUID,
+    pub annual_rate: InterestRatePct,
+    pub effective_from: Date,
+}
+crate::mutation_payload! { DepositProductRateUpdatePayload, deposit_product: DepositProduct }
+
+#[derive(InputObject)]
+pub struct DepositProductFeeScheduleUpdateInput {
+    pub deposit_product_id: UUID,
+    pub monthly_maintenance_fee: UsdCents,
+    pub withdrawal_fee: UsdCents,
+    pub minimum_balance: UsdCents,
+    pub below_minimum_balance_fee: UsdCents,
+}
+crate::mutation_payload! { DepositProductFeeScheduleUpdatePayload, deposit_product: DepositProduct }
+
+#[derive(InputObject)]
+pub struct DepositProductEarlyBreakPenaltyUpdateInput {
+    pub deposit_product_id: UUID,
+    pub early_break_penalty: EarlyBreakPenaltyPct,
+}
+crate::mutation_payload! { DepositProductEarlyBreakPenaltyUpdatePayload, deposit_product: DepositProduct }
+
+#[derive(InputObject)]
+pub struct DepositAccountProductAssignInput {
+    pub deposit_account_id: UUID,
+    pub deposit_product_id: UUID,
+}
+crate::mutation_payload! { DepositAccountProductAssignPayload, account: DepositAccount }
@@ -4,8 +4,10 @@ use std::collections::HashMap;
 
 use lana_app::{
     accounting::{
+        accounting_period::error::AccountingPeriodError,
         chart_of_accounts::error::ChartOfAccountsError,
         ledger_transaction::error::LedgerTransactionError,
+        manual_transaction::error::ManualTransactionError,
         transaction_templates::error::TransactionTemplateError, Chart, LedgerAccountId,
         TransactionTemplateId,
     },
@@ -17,9 +19,10 @@ use lana_app::{
 use crate::primitives::*;
 
 use super::{
-    accounting::*, approval_process::*, chart_of_accounts::*, committee::*, credit_facility::*,
-    customer::*, deposit::*, deposit_account::*, document::*, policy::*, terms_template::*,
-    user::*, withdrawal::*,
+    accounting::*, approval_process::*, bank_statement::*, chart_of_accounts::*, committee::*,
+    credit_facility::*, customer::*, deposit::*, deposit_account::*, deposit_account_statement::*,
+    deposit_fee::*, deposit_product::*, document::*, hold::*, payment_batch::*, policy::*,
+    standing_order::*, terms_template::*, transfer::*, user::*, withdrawal::*, withdrawal_limit::*,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -171,6 +174,53 @@ impl Loader<WithdrawalId> for LanaLoader {
     }
 }
 
+impl Loader<TransferId> for LanaLoader {
+    type Value = Transfer;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[TransferId],
+    ) -> Result<HashMap<TransferId, Transfer>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_transfers(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<HoldId> for LanaLoader {
+    type Value = Hold;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(&self, keys: &[HoldId]) -> Result<HashMap<HoldId, Hold>, Self::Error> {
+        self.app
+            .deposits()
+            .holds()
+            .find_all_holds(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<StandingOrderId> for LanaLoader {
+    type Value = StandingOrder;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[StandingOrderId],
+    ) -> Result<HashMap<StandingOrderId, StandingOrder>, Self::Error> {
+        self.app
+            .deposits()
+            .standing_orders()
+            .find_all_standing_orders(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<DepositId> for LanaLoader {
     type Value = Deposit;
     type Error = Arc<CoreDepositError>;
@@ -184,6 +234,23 @@ impl Loader<DepositId> for LanaLoader {
     }
 }
 
+impl Loader<DepositFeeId> for LanaLoader {
+    type Value = DepositFee;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[DepositFeeId],
+    ) -> Result<HashMap<DepositFeeId, DepositFee>, Self::Error> {
+        self.app
+            .deposits()
+            .fees()
+            .find_all_deposit_fees(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<DepositAccountId> for LanaLoader {
     type Value = DepositAccount;
     type Error = Arc<CoreDepositError>;
@@ -200,6 +267,123 @@ impl Loader<DepositAccountId> for LanaLoader {
     }
 }
 
+impl Loader<WithdrawalLimitId> for LanaLoader {
+    type Value = WithdrawalLimit;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[WithdrawalLimitId],
+    ) -> Result<HashMap<WithdrawalLimitId, WithdrawalLimit>, Self::Error> {
+        self.app
+            .deposits()
+            .withdrawal_limits()
+            .find_all_withdrawal_limits(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<WithdrawalLimitOverrideId> for LanaLoader {
+    type Value = WithdrawalLimitOverride;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[WithdrawalLimitOverrideId],
+    ) -> Result<HashMap<WithdrawalLimitOverrideId, WithdrawalLimitOverride>, Self::Error> {
+        self.app
+            .deposits()
+            .withdrawal_limits()
+            .find_all_withdrawal_limit_overrides(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<DepositAccountStatementId> for LanaLoader {
+    type Value = DepositAccountStatement;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[DepositAccountStatementId],
+    ) -> Result<HashMap<DepositAccountStatementId, DepositAccountStatement>, Self::Error> {
+        self.app
+            .deposits()
+            .statements()
+            .find_all_statements(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<PaymentBatchId> for LanaLoader {
+    type Value = PaymentBatch;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[PaymentBatchId],
+    ) -> Result<HashMap<PaymentBatchId, PaymentBatch>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_payment_batches(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<BankStatementImportId> for LanaLoader {
+    type Value = BankStatementImport;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[BankStatementImportId],
+    ) -> Result<HashMap<BankStatementImportId, BankStatementImport>, Self::Error> {
+        self.app
+            .deposits()
+            .bank_statement_imports()
+            .find_all_bank_statement_imports(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<BankStatementLineId> for LanaLoader {
+    type Value = BankStatementLine;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[BankStatementLineId],
+    ) -> Result<HashMap<BankStatementLineId, BankStatementLine>, Self::Error> {
+        self.app
+            .deposits()
+            .bank_statement_imports()
+            .find_all_bank_statement_lines(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<DepositProductId> for LanaLoader {
+    type Value = DepositProduct;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[DepositProductId],
+    ) -> Result<HashMap<DepositProductId, DepositProduct>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_deposit_products(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<LedgerTransactionId> for LanaLoader {
     type Value = LedgerTransaction;
     type Error = Arc<LedgerTransactionError>;
@@ -217,6 +401,40 @@ impl Loader<LedgerTransactionId> for LanaLoader {
     }
 }
 
+impl Loader<AccountingPeriodId> for LanaLoader {
+    type Value = AccountingPeriod;
+    type Error = Arc<AccountingPeriodError>;
+
+    async fn load(
+        &self,
+        keys: &[AccountingPeriodId],
+    ) -> Result<HashMap<AccountingPeriodId, AccountingPeriod>, Self::Error> {
+        self.app
+            .accounting()
+            .accounting_periods()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<ManualTransactionId> for LanaLoader {
+    type Value = ManualTransaction;
+    type Error = Arc<ManualTransactionError>;
+
+    async fn load(
+        &self,
+        keys: &[ManualTransactionId],
+    ) -> Result<HashMap<ManualTransactionId, ManualTransaction>, Self::Error> {
+        self.app
+            .accounting()
+            .manual_transactions()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<TransactionTemplateId> for LanaLoader {
     type Value = TransactionTemplate;
     type Error = Arc<TransactionTemplateError>;
@@ -278,6 +496,22 @@ impl Loader<DisbursalId> for LanaLoader {
     }
 }
 
+impl Loader<ForbearanceId> for LanaLoader {
+    type Value = CreditFacilityForbearance;
+    type Error = Arc<lana_app::credit::error::CoreCreditError>;
+
+    async fn load(
+        &self,
+        keys: &[ForbearanceId],
+    ) -> Result<HashMap<ForbearanceId, CreditFacilityForbearance>, Self::Error> {
+        self.app
+            .credit()
+            .find_all_forbearances(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<LedgerAccountId> for LanaLoader {
     type Value = LedgerAccount;
     type Error = Arc<lana_app::accounting::error::CoreAccountingError>;
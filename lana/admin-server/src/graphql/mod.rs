@@ -5,6 +5,7 @@ mod approval_rules;
 mod audit;
 mod authenticated_subject;
 mod balance_sheet_config;
+mod bank_statement;
 mod chart_of_accounts;
 mod committee;
 mod credit_config;
@@ -14,19 +15,27 @@ mod dashboard;
 mod deposit;
 mod deposit_account;
 mod deposit_account_history;
+mod deposit_account_statement;
 mod deposit_config;
+mod deposit_fee;
+mod deposit_product;
 mod document;
 mod financials;
 mod general_ledger;
+mod hold;
 mod loader;
+mod payment_batch;
 mod price;
 mod primitives;
 mod profit_and_loss_config;
 mod report;
+mod standing_order;
 mod sumsub;
 mod terms;
 mod terms_template;
+mod transfer;
 mod withdrawal;
+mod withdrawal_limit;
 #[macro_use]
 pub mod macros;
 mod policy;
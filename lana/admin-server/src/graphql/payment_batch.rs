@@ -0,0 +1,111 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{
+    GeneratedPaymentBatchDownloadLink, PaymentBatch as DomainPaymentBatch, PaymentBatchStatus,
+    PaymentBatchesByCreatedAtCursor,
+};
+
+use super::{loader::LanaDataLoader, withdrawal::Withdrawal};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct PaymentBatch {
+    id: ID,
+    payment_batch_id: UUID,
+    message_id: String,
+    total_amount: UsdCents,
+    number_of_withdrawals: u32,
+    requested_execution_date: Date,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainPaymentBatch>,
+}
+
+impl From<DomainPaymentBatch> for PaymentBatch {
+    fn from(batch: DomainPaymentBatch) -> Self {
+        PaymentBatch {
+            id: batch.id.to_global_id(),
+            payment_batch_id: UUID::from(batch.id),
+            message_id: batch.message_id.clone(),
+            total_amount: batch.total_amount,
+            number_of_withdrawals: batch.withdrawal_ids.len() as u32,
+            requested_execution_date: batch.requested_execution_date.into(),
+            created_at: batch.created_at().into(),
+            entity: Arc::new(batch),
+        }
+    }
+}
+
+#[ComplexObject]
+impl PaymentBatch {
+    async fn status(&self) -> PaymentBatchStatus {
+        self.entity.status()
+    }
+
+    async fn sent_at(&self) -> Option<Timestamp> {
+        self.entity.sent_at().map(|t| t.into())
+    }
+
+    async fn settled_at(&self) -> Option<Timestamp> {
+        self.entity.settled_at().map(|t| t.into())
+    }
+
+    async fn withdrawals(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Withdrawal>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let withdrawals = loader
+            .load_many(self.entity.withdrawal_ids.iter().copied())
+            .await?;
+        Ok(self
+            .entity
+            .withdrawal_ids
+            .iter()
+            .filter_map(|id| withdrawals.get(id).cloned())
+            .collect())
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PaymentBatchCreatePayload {
+    payment_batch: Option<PaymentBatch>,
+}
+
+impl From<Option<PaymentBatch>> for PaymentBatchCreatePayload {
+    fn from(payment_batch: Option<PaymentBatch>) -> Self {
+        Self { payment_batch }
+    }
+}
+
+#[derive(InputObject)]
+pub struct PaymentBatchMarkSentInput {
+    pub payment_batch_id: UUID,
+}
+crate::mutation_payload! { PaymentBatchMarkSentPayload, payment_batch: PaymentBatch }
+
+#[derive(InputObject)]
+pub struct PaymentBatchMarkSettledInput {
+    pub payment_batch_id: UUID,
+}
+crate::mutation_payload! { PaymentBatchMarkSettledPayload, payment_batch: PaymentBatch }
+
+#[derive(InputObject)]
+pub struct PaymentBatchDownloadLinkGenerateInput {
+    pub payment_batch_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct PaymentBatchDownloadLinkGeneratePayload {
+    payment_batch_id: UUID,
+    link: String,
+}
+
+impl From<GeneratedPaymentBatchDownloadLink> for PaymentBatchDownloadLinkGeneratePayload {
+    fn from(value: GeneratedPaymentBatchDownloadLink) -> Self {
+        Self {
+            payment_batch_id: UUID::from(value.payment_batch_id),
+            link: value.link,
+        }
+    }
+}
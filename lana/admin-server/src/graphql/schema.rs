@@ -14,10 +14,13 @@ use crate::primitives::*;
 
 use super::{
     accounting::*, approval_process::*, audit::*, authenticated_subject::*,
-    balance_sheet_config::*, chart_of_accounts::*, committee::*, credit_config::*,
-    credit_facility::*, customer::*, dashboard::*, deposit::*, deposit_config::*, document::*,
-    financials::*, general_ledger::*, loader::*, policy::*, price::*, profit_and_loss_config::*,
-    report::*, sumsub::*, terms_template::*, user::*, withdrawal::*,
+    balance_sheet_config::*, bank_statement::*, chart_of_accounts::*, committee::*,
+    credit_config::*, credit_facility::*, customer::*, dashboard::*, deposit::*,
+    deposit_account::*, deposit_account_statement::*, deposit_config::*, deposit_fee::*,
+    deposit_product::*, document::*, financials::*, general_ledger::*, hold::*, loader::*,
+    payment_batch::*, policy::*, price::*, profit_and_loss_config::*, report::*, standing_order::*,
+    sumsub::*, terms::pricing_grid_id_from_input, terms_template::*, transfer::*, user::*,
+    withdrawal::*, withdrawal_limit::*,
 };
 
 pub struct Query;
@@ -145,6 +148,168 @@ impl Query {
         )
     }
 
+    async fn payment_batch(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<PaymentBatch>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            PaymentBatch,
+            ctx,
+            app.deposits().find_payment_batch_by_id(sub, id)
+        )
+    }
+
+    async fn payment_batches(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<PaymentBatchesByCreatedAtCursor, PaymentBatch, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            PaymentBatchesByCreatedAtCursor,
+            PaymentBatch,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_payment_batches(sub, query)
+        )
+    }
+
+    async fn bank_statement_import(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<BankStatementImport>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            BankStatementImport,
+            ctx,
+            app.deposits()
+                .bank_statement_imports()
+                .find_bank_statement_import_by_id(sub, id)
+        )
+    }
+
+    async fn bank_statement_imports(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            BankStatementImportsByCreatedAtCursor,
+            BankStatementImport,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            BankStatementImportsByCreatedAtCursor,
+            BankStatementImport,
+            ctx,
+            after,
+            first,
+            |query| app
+                .deposits()
+                .bank_statement_imports()
+                .list_bank_statement_imports(sub, query)
+        )
+    }
+
+    async fn unmatched_bank_statement_lines(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            BankStatementLinesByCreatedAtCursor,
+            BankStatementLine,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            BankStatementLinesByCreatedAtCursor,
+            BankStatementLine,
+            ctx,
+            after,
+            first,
+            |query| app
+                .deposits()
+                .bank_statement_imports()
+                .list_unmatched_bank_statement_lines(sub, query)
+        )
+    }
+
+    async fn transfer(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<Transfer>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(Transfer, ctx, app.deposits().find_transfer_by_id(sub, id))
+    }
+
+    async fn hold(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Hold>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(Hold, ctx, app.deposits().holds().find_hold_by_id(sub, id))
+    }
+
+    async fn standing_order(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<StandingOrder>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            StandingOrder,
+            ctx,
+            app.deposits()
+                .standing_orders()
+                .find_standing_order_by_id(sub, id)
+        )
+    }
+
+    async fn deposit_fee(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<DepositFee>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            DepositFee,
+            ctx,
+            app.deposits().fees().find_deposit_fee_by_id(sub, id)
+        )
+    }
+
+    async fn transfers(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransfersByCreatedAtCursor, Transfer, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            TransfersByCreatedAtCursor,
+            Transfer,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_transfers(sub, query)
+        )
+    }
+
     async fn deposit(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Deposit>> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         maybe_fetch_one!(Deposit, ctx, app.deposits().find_deposit_by_id(sub, id))
@@ -168,6 +333,72 @@ impl Query {
         )
     }
 
+    async fn dormant_deposit_accounts_report(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DormantDepositAccount>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let report = app.deposits().dormant_accounts_report(sub).await?;
+        Ok(report
+            .into_iter()
+            .map(DormantDepositAccount::from)
+            .collect())
+    }
+
+    async fn deposit_product(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<DepositProduct>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            DepositProduct,
+            ctx,
+            app.deposits().find_deposit_product_by_id(sub, id)
+        )
+    }
+
+    async fn deposit_products(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<DepositProductsByCreatedAtCursor, DepositProduct, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            DepositProductsByCreatedAtCursor,
+            DepositProduct,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_deposit_products(sub, query)
+        )
+    }
+
+    async fn withdrawal_limits(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<WithdrawalLimitsByCreatedAtCursor, WithdrawalLimit, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            WithdrawalLimitsByCreatedAtCursor,
+            WithdrawalLimit,
+            ctx,
+            after,
+            first,
+            |query| app
+                .deposits()
+                .withdrawal_limits()
+                .list_withdrawal_limits(sub, query)
+        )
+    }
+
     async fn terms_template(
         &self,
         ctx: &Context<'_>,
@@ -193,6 +424,27 @@ impl Query {
             .collect())
     }
 
+    async fn credit_exposure_limits(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<CreditExposureLimit>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let exposure_limits = app.credit().portfolio_exposure_limits(sub).await?;
+        Ok(exposure_limits
+            .into_iter()
+            .map(CreditExposureLimit::from)
+            .collect())
+    }
+
+    async fn credit_exposure_limit_policy(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<CreditExposureLimitPolicy> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let policy = app.credit().exposure_limits().find_policy(sub).await?;
+        Ok(CreditExposureLimitPolicy::from(policy))
+    }
+
     async fn credit_facility(
         &self,
         ctx: &Context<'_>,
@@ -436,6 +688,80 @@ impl Query {
         )
     }
 
+    async fn manual_transaction(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<ManualTransaction>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            ManualTransaction,
+            ctx,
+            app.accounting()
+                .manual_transactions()
+                .find_manual_transaction_by_id(sub, id)
+        )
+    }
+
+    async fn manual_transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            ManualTransactionsByCreatedAtCursor,
+            ManualTransaction,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            ManualTransactionsByCreatedAtCursor,
+            ManualTransaction,
+            ctx,
+            after,
+            first,
+            |query| app
+                .accounting()
+                .manual_transactions()
+                .list_manual_transactions(sub, query)
+        )
+    }
+
+    async fn accounting_period(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<AccountingPeriod>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            AccountingPeriod,
+            ctx,
+            app.accounting().accounting_periods().find_by_id(sub, id)
+        )
+    }
+
+    async fn accounting_periods(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<AccountingPeriodsByCreatedAtCursor, AccountingPeriod, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            AccountingPeriodsByCreatedAtCursor,
+            AccountingPeriod,
+            ctx,
+            after,
+            first,
+            |query| app.accounting().accounting_periods().list(sub, query)
+        )
+    }
+
     async fn ledger_transaction(
         &self,
         ctx: &Context<'_>,
@@ -874,6 +1200,51 @@ impl Mutation {
         )
     }
 
+    async fn customer_signatory_add(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerSignatoryAddInput,
+    ) -> async_graphql::Result<CustomerSignatoryAddPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let signatory = app
+            .customers()
+            .add_signatory(sub, input.customer_id, input.email, input.role)
+            .await?;
+        Ok(CustomerSignatoryAddPayload::from(Signatory::from(
+            signatory,
+        )))
+    }
+
+    async fn customer_signatory_role_update(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerSignatoryRoleUpdateInput,
+    ) -> async_graphql::Result<CustomerSignatoryRoleUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let signatory = app
+            .customers()
+            .update_signatory_role(sub, input.signatory_id, input.role)
+            .await?;
+        Ok(CustomerSignatoryRoleUpdatePayload::from(Signatory::from(
+            signatory,
+        )))
+    }
+
+    async fn customer_signatory_remove(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerSignatoryRemoveInput,
+    ) -> async_graphql::Result<CustomerSignatoryRemovePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let signatory = app
+            .customers()
+            .remove_signatory(sub, input.signatory_id)
+            .await?;
+        Ok(CustomerSignatoryRemovePayload::from(Signatory::from(
+            signatory,
+        )))
+    }
+
     async fn deposit_module_configure(
         &self,
         ctx: &Context<'_>,
@@ -919,9 +1290,32 @@ impl Mutation {
                     .chart_of_account_non_domiciled_individual_deposit_accounts_parent_code
                     .parse()?,
             )
+            .chart_of_accounts_time_deposit_accounts_parent_code(
+                input
+                    .chart_of_accounts_time_deposit_accounts_parent_code
+                    .parse()?,
+            )
             .chart_of_accounts_omnibus_parent_code(
                 input.chart_of_accounts_omnibus_parent_code.parse()?,
             )
+            .chart_of_accounts_interest_expense_parent_code(
+                input
+                    .chart_of_accounts_interest_expense_parent_code
+                    .parse()?,
+            )
+            .chart_of_accounts_accrued_interest_parent_code(
+                input
+                    .chart_of_accounts_accrued_interest_parent_code
+                    .parse()?,
+            )
+            .chart_of_accounts_unclaimed_funds_parent_code(
+                input
+                    .chart_of_accounts_unclaimed_funds_parent_code
+                    .parse()?,
+            )
+            .chart_of_accounts_fee_income_parent_code(
+                input.chart_of_accounts_fee_income_parent_code.parse()?,
+            )
             .build()?;
         let config = app
             .deposits()
@@ -946,7 +1340,7 @@ impl Mutation {
 
         exec_mutation!(
             ManualTransactionExecutePayload,
-            LedgerTransaction,
+            ManualTransaction,
             ctx,
             app.accounting().execute_manual_transaction(
                 sub,
@@ -959,23 +1353,532 @@ impl Mutation {
         )
     }
 
-    pub async fn deposit_record(
+    pub async fn manual_transaction_reverse(
         &self,
         ctx: &Context<'_>,
-        input: DepositRecordInput,
-    ) -> async_graphql::Result<DepositRecordPayload> {
+        input: ManualTransactionReverseInput,
+    ) -> async_graphql::Result<ManualTransactionReversePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
-
         exec_mutation!(
-            DepositRecordPayload,
-            Deposit,
+            ManualTransactionReversePayload,
+            ManualTransaction,
             ctx,
-            app.deposits().record_deposit(
-                sub,
-                input.deposit_account_id,
-                input.amount,
-                input.reference
-            )
+            app.accounting()
+                .manual_transactions()
+                .reverse(sub, input.manual_transaction_id)
+        )
+    }
+
+    pub async fn accounting_period_create(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountingPeriodCreateInput,
+    ) -> async_graphql::Result<AccountingPeriodCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            AccountingPeriodCreatePayload,
+            AccountingPeriod,
+            ctx,
+            app.accounting().accounting_periods().create(
+                sub,
+                input.start_date.into_inner(),
+                input.end_date.into_inner()
+            )
+        )
+    }
+
+    pub async fn accounting_period_soft_close(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountingPeriodSoftCloseInput,
+    ) -> async_graphql::Result<AccountingPeriodSoftClosePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            AccountingPeriodSoftClosePayload,
+            AccountingPeriod,
+            ctx,
+            app.accounting()
+                .accounting_periods()
+                .soft_close(sub, input.accounting_period_id)
+        )
+    }
+
+    pub async fn accounting_period_close(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountingPeriodCloseInput,
+    ) -> async_graphql::Result<AccountingPeriodClosePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let retained_earnings = input.retained_earnings_account_ref.parse()?;
+        exec_mutation!(
+            AccountingPeriodClosePayload,
+            AccountingPeriod,
+            ctx,
+            app.accounting().close_accounting_period(
+                sub,
+                CHART_REF.0,
+                PROFIT_AND_LOSS_STATEMENT_NAME.to_string(),
+                input.accounting_period_id,
+                retained_earnings
+            )
+        )
+    }
+
+    pub async fn accounting_period_request_reopen(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountingPeriodRequestReopenInput,
+    ) -> async_graphql::Result<AccountingPeriodRequestReopenPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            AccountingPeriodRequestReopenPayload,
+            AccountingPeriod,
+            ctx,
+            app.accounting()
+                .accounting_periods()
+                .request_reopen(sub, input.accounting_period_id)
+        )
+    }
+
+    pub async fn deposit_record(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositRecordInput,
+    ) -> async_graphql::Result<DepositRecordPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        exec_mutation!(
+            DepositRecordPayload,
+            Deposit,
+            ctx,
+            app.deposits().record_deposit(
+                sub,
+                input.deposit_account_id,
+                input.amount,
+                input.reference
+            )
+        )
+    }
+
+    pub async fn btc_deposit_record(
+        &self,
+        ctx: &Context<'_>,
+        input: BtcDepositRecordInput,
+    ) -> async_graphql::Result<BtcDepositRecordPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        exec_mutation!(
+            BtcDepositRecordPayload,
+            Deposit,
+            ctx,
+            app.deposits().record_deposit(
+                sub,
+                input.deposit_account_id,
+                input.amount,
+                input.reference
+            )
+        )
+    }
+
+    pub async fn deposit_revert(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositRevertInput,
+    ) -> async_graphql::Result<DepositRevertPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        exec_mutation!(
+            DepositRevertPayload,
+            Deposit,
+            ctx,
+            app.deposits()
+                .revert_deposit(sub, input.deposit_id, input.reason)
+        )
+    }
+
+    pub async fn deposit_product_create(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositProductCreateInput,
+    ) -> async_graphql::Result<DepositProductCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositProductCreatePayload,
+            DepositProduct,
+            ctx,
+            app.deposits()
+                .create_deposit_product(sub, input.name, input.annual_rate)
+        )
+    }
+
+    pub async fn deposit_product_rate_update(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositProductRateUpdateInput,
+    ) -> async_graphql::Result<DepositProductRateUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositProductRateUpdatePayload,
+            DepositProduct,
+            ctx,
+            app.deposits().update_deposit_product_rate(
+                sub,
+                input.deposit_product_id,
+                input.annual_rate,
+                input.effective_from.into_inner()
+            )
+        )
+    }
+
+    pub async fn deposit_product_fee_schedule_update(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositProductFeeScheduleUpdateInput,
+    ) -> async_graphql::Result<DepositProductFeeScheduleUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositProductFeeScheduleUpdatePayload,
+            DepositProduct,
+            ctx,
+            app.deposits().update_deposit_product_fee_schedule(
+                sub,
+                input.deposit_product_id,
+                DomainDepositFeeSchedule {
+                    monthly_maintenance_fee: input.monthly_maintenance_fee,
+                    withdrawal_fee: input.withdrawal_fee,
+                    minimum_balance: input.minimum_balance,
+                    below_minimum_balance_fee: input.below_minimum_balance_fee,
+                }
+            )
+        )
+    }
+
+    pub async fn deposit_product_early_break_penalty_update(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositProductEarlyBreakPenaltyUpdateInput,
+    ) -> async_graphql::Result<DepositProductEarlyBreakPenaltyUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositProductEarlyBreakPenaltyUpdatePayload,
+            DepositProduct,
+            ctx,
+            app.deposits().update_deposit_product_early_break_penalty(
+                sub,
+                input.deposit_product_id,
+                input.early_break_penalty
+            )
+        )
+    }
+
+    pub async fn deposit_fee_waive(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositFeeWaiveInput,
+    ) -> async_graphql::Result<DepositFeeWaivePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositFeeWaivePayload,
+            DepositFee,
+            ctx,
+            app.deposits()
+                .fees()
+                .waive_fee(sub, input.deposit_fee_id, input.reason)
+        )
+    }
+
+    pub async fn deposit_account_product_assign(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountProductAssignInput,
+    ) -> async_graphql::Result<DepositAccountProductAssignPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountProductAssignPayload,
+            DepositAccount,
+            ctx,
+            app.deposits().assign_deposit_product(
+                sub,
+                input.deposit_account_id,
+                input.deposit_product_id
+            )
+        )
+    }
+
+    pub async fn withdrawal_limit_create(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalLimitCreateInput,
+    ) -> async_graphql::Result<WithdrawalLimitCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            WithdrawalLimitCreatePayload,
+            WithdrawalLimit,
+            ctx,
+            app.deposits().withdrawal_limits().create_withdrawal_limit(
+                sub,
+                input.name,
+                input.daily_limit,
+                input.monthly_limit,
+                input.kyc_level
+            )
+        )
+    }
+
+    pub async fn withdrawal_limit_update(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalLimitUpdateInput,
+    ) -> async_graphql::Result<WithdrawalLimitUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            WithdrawalLimitUpdatePayload,
+            WithdrawalLimit,
+            ctx,
+            app.deposits().withdrawal_limits().update_withdrawal_limit(
+                sub,
+                input.withdrawal_limit_id,
+                input.daily_limit,
+                input.monthly_limit
+            )
+        )
+    }
+
+    pub async fn deposit_account_withdrawal_limit_assign(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountWithdrawalLimitAssignInput,
+    ) -> async_graphql::Result<DepositAccountWithdrawalLimitAssignPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountWithdrawalLimitAssignPayload,
+            DepositAccount,
+            ctx,
+            app.deposits().withdrawal_limits().assign_withdrawal_limit(
+                sub,
+                input.deposit_account_id,
+                input.withdrawal_limit_id.map(Into::into)
+            )
+        )
+    }
+
+    pub async fn withdrawal_limit_override_grant(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalLimitOverrideGrantInput,
+    ) -> async_graphql::Result<WithdrawalLimitOverrideGrantPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            WithdrawalLimitOverrideGrantPayload,
+            WithdrawalLimitOverride,
+            ctx,
+            app.deposits()
+                .withdrawal_limits()
+                .grant_withdrawal_limit_override(
+                    sub,
+                    input.deposit_account_id,
+                    input.daily_limit,
+                    input.monthly_limit,
+                    input.reason,
+                    input.expires_at.into_inner()
+                )
+        )
+    }
+
+    pub async fn withdrawal_limit_override_revoke(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalLimitOverrideRevokeInput,
+    ) -> async_graphql::Result<WithdrawalLimitOverrideRevokePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            WithdrawalLimitOverrideRevokePayload,
+            WithdrawalLimitOverride,
+            ctx,
+            app.deposits()
+                .withdrawal_limits()
+                .revoke_withdrawal_limit_override(
+                    sub,
+                    input.withdrawal_limit_override_id,
+                    input.reason
+                )
+        )
+    }
+
+    pub async fn deposit_account_statement_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountStatementGenerateInput,
+    ) -> async_graphql::Result<DepositAccountStatementGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountStatementGeneratePayload,
+            DepositAccountStatement,
+            ctx,
+            app.deposits().statements().generate_statement(
+                sub,
+                input.deposit_account_id,
+                input.period_start.into_inner(),
+                input.period_end.into_inner()
+            )
+        )
+    }
+
+    async fn deposit_account_statement_download_links_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountStatementDownloadLinksGenerateInput,
+    ) -> async_graphql::Result<DepositAccountStatementDownloadLinksGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        // not using macro here because DepositAccountStatementDownloadLinksGeneratePayload is non standard
+        let links = app
+            .deposits()
+            .statements()
+            .generate_statement_download_links(sub, input.deposit_account_statement_id)
+            .await?;
+        Ok(DepositAccountStatementDownloadLinksGeneratePayload::from(
+            links,
+        ))
+    }
+
+    pub async fn deposit_account_btc_create(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountBtcCreateInput,
+    ) -> async_graphql::Result<DepositAccountBtcCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let customer = app
+            .customers()
+            .find_by_id(sub, input.customer_id)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("customer not found"))?;
+        exec_mutation!(
+            DepositAccountBtcCreatePayload,
+            DepositAccount,
+            ctx,
+            app.deposits().create_btc_account(
+                sub,
+                customer.id,
+                customer.status == AccountStatus::Active,
+                customer.customer_type
+            )
+        )
+    }
+
+    pub async fn deposit_account_freeze(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountFreezeInput,
+    ) -> async_graphql::Result<DepositAccountFreezePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountFreezePayload,
+            DepositAccount,
+            ctx,
+            app.deposits()
+                .freeze_account(sub, input.deposit_account_id, input.reason)
+        )
+    }
+
+    pub async fn deposit_account_unfreeze(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountUnfreezeInput,
+    ) -> async_graphql::Result<DepositAccountUnfreezePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountUnfreezePayload,
+            DepositAccount,
+            ctx,
+            app.deposits()
+                .unfreeze_account(sub, input.deposit_account_id, input.reason)
+        )
+    }
+
+    pub async fn deposit_account_reactivate(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountReactivateInput,
+    ) -> async_graphql::Result<DepositAccountReactivatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountReactivatePayload,
+            DepositAccount,
+            ctx,
+            app.deposits()
+                .reactivate_dormant_account(sub, input.deposit_account_id, input.reason)
+        )
+    }
+
+    pub async fn deposit_account_unclaimed_funds_transfer(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountUnclaimedFundsTransferInput,
+    ) -> async_graphql::Result<DepositAccountUnclaimedFundsTransferPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountUnclaimedFundsTransferPayload,
+            DepositAccount,
+            ctx,
+            app.deposits()
+                .transfer_unclaimed_funds(sub, input.deposit_account_id)
+        )
+    }
+
+    pub async fn deposit_account_close(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountCloseInput,
+    ) -> async_graphql::Result<DepositAccountClosePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountClosePayload,
+            DepositAccount,
+            ctx,
+            app.deposits().close_account(
+                sub,
+                input.deposit_account_id,
+                input.reason,
+                input.sweep_to_deposit_account_id.map(Into::into)
+            )
+        )
+    }
+
+    pub async fn time_deposit_open(
+        &self,
+        ctx: &Context<'_>,
+        input: TimeDepositOpenInput,
+    ) -> async_graphql::Result<TimeDepositOpenPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TimeDepositOpenPayload,
+            DepositAccount,
+            ctx,
+            app.deposits().time_deposits().open_time_deposit(
+                sub,
+                input.funding_deposit_account_id,
+                input.principal,
+                input.annual_rate,
+                input.maturity_date.into_inner()
+            )
+        )
+    }
+
+    pub async fn time_deposit_break(
+        &self,
+        ctx: &Context<'_>,
+        input: TimeDepositBreakInput,
+    ) -> async_graphql::Result<TimeDepositBreakPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TimeDepositBreakPayload,
+            DepositAccount,
+            ctx,
+            app.deposits().time_deposits().break_time_deposit(
+                sub,
+                input.deposit_account_id,
+                input.reason
+            )
         )
     }
 
@@ -989,11 +1892,32 @@ impl Mutation {
             WithdrawalInitiatePayload,
             Withdrawal,
             ctx,
-            app.deposits().initiate_withdrawal(
+            app.initiate_withdrawal(
                 sub,
                 input.deposit_account_id,
                 input.amount,
-                input.reference
+                input.reference,
+                input.beneficiary.map(Into::into)
+            )
+        )
+    }
+
+    pub async fn btc_withdrawal_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: BtcWithdrawalInitiateInput,
+    ) -> async_graphql::Result<BtcWithdrawalInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            BtcWithdrawalInitiatePayload,
+            Withdrawal,
+            ctx,
+            app.initiate_withdrawal(
+                sub,
+                input.deposit_account_id,
+                input.amount,
+                input.reference,
+                None
             )
         )
     }
@@ -1027,6 +1951,251 @@ impl Mutation {
         )
     }
 
+    async fn payment_batch_create(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<PaymentBatchCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        // not using macro here because the batch is only created when withdrawals are pending payment
+        let payment_batch = app
+            .deposits()
+            .create_payment_batch(sub)
+            .await?
+            .map(PaymentBatch::from);
+        if let Some(payment_batch) = payment_batch.as_ref() {
+            let loader = ctx.data_unchecked::<LanaDataLoader>();
+            loader
+                .feed_one(payment_batch.entity.id, payment_batch.clone())
+                .await;
+        }
+        Ok(PaymentBatchCreatePayload::from(payment_batch))
+    }
+
+    async fn payment_batch_mark_sent(
+        &self,
+        ctx: &Context<'_>,
+        input: PaymentBatchMarkSentInput,
+    ) -> async_graphql::Result<PaymentBatchMarkSentPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            PaymentBatchMarkSentPayload,
+            PaymentBatch,
+            ctx,
+            app.deposits()
+                .mark_payment_batch_sent(sub, input.payment_batch_id)
+        )
+    }
+
+    async fn payment_batch_mark_settled(
+        &self,
+        ctx: &Context<'_>,
+        input: PaymentBatchMarkSettledInput,
+    ) -> async_graphql::Result<PaymentBatchMarkSettledPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            PaymentBatchMarkSettledPayload,
+            PaymentBatch,
+            ctx,
+            app.deposits()
+                .mark_payment_batch_settled(sub, input.payment_batch_id)
+        )
+    }
+
+    async fn payment_batch_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: PaymentBatchDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<PaymentBatchDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        // not using macro here because PaymentBatchDownloadLinkGeneratePayload is non standard
+        let link = app
+            .deposits()
+            .generate_payment_batch_download_link(sub, input.payment_batch_id)
+            .await?;
+        Ok(PaymentBatchDownloadLinkGeneratePayload::from(link))
+    }
+
+    async fn bank_statement_import(
+        &self,
+        ctx: &Context<'_>,
+        input: BankStatementImportInput,
+    ) -> async_graphql::Result<BankStatementImportPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let BankStatementImportInput { file, format } = input;
+
+        let file = file.value(ctx)?;
+        let file_name = file.filename;
+        let mut content = file.content;
+
+        let mut data = String::new();
+        content.read_to_string(&mut data)?;
+
+        exec_mutation!(
+            BankStatementImportPayload,
+            BankStatementImport,
+            ctx,
+            app.deposits()
+                .bank_statement_imports()
+                .import_bank_statement(sub, file_name, format, data)
+        )
+    }
+
+    async fn bank_statement_line_resolve(
+        &self,
+        ctx: &Context<'_>,
+        input: BankStatementLineResolveInput,
+    ) -> async_graphql::Result<BankStatementLineResolvePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            BankStatementLineResolvePayload,
+            BankStatementLine,
+            ctx,
+            app.deposits()
+                .bank_statement_imports()
+                .resolve_bank_statement_line(
+                    sub,
+                    input.bank_statement_line_id,
+                    input.deposit_account_id
+                )
+        )
+    }
+
+    async fn bank_statement_line_dismiss(
+        &self,
+        ctx: &Context<'_>,
+        input: BankStatementLineDismissInput,
+    ) -> async_graphql::Result<BankStatementLineDismissPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            BankStatementLineDismissPayload,
+            BankStatementLine,
+            ctx,
+            app.deposits()
+                .bank_statement_imports()
+                .dismiss_bank_statement_line(sub, input.bank_statement_line_id, input.reason)
+        )
+    }
+
+    pub async fn transfer_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: TransferInitiateInput,
+    ) -> async_graphql::Result<TransferInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TransferInitiatePayload,
+            Transfer,
+            ctx,
+            app.deposits().initiate_transfer(
+                sub,
+                input.from_deposit_account_id,
+                input.to_deposit_account_id,
+                input.amount,
+                input.reference
+            )
+        )
+    }
+
+    pub async fn hold_place(
+        &self,
+        ctx: &Context<'_>,
+        input: HoldPlaceInput,
+    ) -> async_graphql::Result<HoldPlacePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            HoldPlacePayload,
+            Hold,
+            ctx,
+            app.deposits().holds().place_hold(
+                sub,
+                input.deposit_account_id,
+                input.amount,
+                input.reason,
+                input.expires_at.map(|t| t.into_inner())
+            )
+        )
+    }
+
+    pub async fn hold_release(
+        &self,
+        ctx: &Context<'_>,
+        input: HoldReleaseInput,
+    ) -> async_graphql::Result<HoldReleasePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            HoldReleasePayload,
+            Hold,
+            ctx,
+            app.deposits()
+                .holds()
+                .release_hold(sub, input.hold_id, input.reason)
+        )
+    }
+
+    pub async fn standing_order_transfer_create(
+        &self,
+        ctx: &Context<'_>,
+        input: StandingOrderTransferCreateInput,
+    ) -> async_graphql::Result<StandingOrderTransferCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            StandingOrderTransferCreatePayload,
+            StandingOrder,
+            ctx,
+            app.deposits().standing_orders().create_standing_order(
+                sub,
+                input.deposit_account_id,
+                StandingOrderDestination::DepositAccount {
+                    deposit_account_id: input.to_deposit_account_id.into(),
+                },
+                input.amount,
+                input.frequency,
+                input.start_date.into_inner(),
+                input.end_date.map(|d| d.into_inner()),
+                input.reference
+            )
+        )
+    }
+
+    pub async fn standing_order_repayment_create(
+        &self,
+        ctx: &Context<'_>,
+        input: StandingOrderRepaymentCreateInput,
+    ) -> async_graphql::Result<StandingOrderRepaymentCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            StandingOrderRepaymentCreatePayload,
+            StandingOrder,
+            ctx,
+            app.create_credit_facility_repayment_standing_order(
+                sub,
+                input.credit_facility_id,
+                input.deposit_account_id,
+                input.amount,
+                input.frequency,
+                input.start_date.into_inner(),
+                input.end_date.map(|d| d.into_inner()),
+                input.reference
+            )
+        )
+    }
+
+    pub async fn standing_order_cancel(
+        &self,
+        ctx: &Context<'_>,
+        input: StandingOrderCancelInput,
+    ) -> async_graphql::Result<StandingOrderCancelPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            StandingOrderCancelPayload,
+            StandingOrder,
+            ctx,
+            app.deposits()
+                .standing_orders()
+                .cancel_standing_order(sub, input.standing_order_id)
+        )
+    }
+
     async fn terms_template_create(
         &self,
         ctx: &Context<'_>,
@@ -1038,11 +2207,14 @@ impl Mutation {
             .accrual_interval(input.accrual_interval)
             .accrual_cycle_interval(input.accrual_cycle_interval)
             .one_time_fee_rate(input.one_time_fee_rate)
+            .structuring_fee_recognition(input.structuring_fee_recognition.unwrap_or_default())
+            .interest_capitalization(input.interest_capitalization.unwrap_or_default())
             .duration(input.duration)
             .interest_due_duration(input.interest_due_duration)
             .liquidation_cvl(input.liquidation_cvl)
             .margin_call_cvl(input.margin_call_cvl)
             .initial_cvl(input.initial_cvl)
+            .pricing_grid_id(pricing_grid_id_from_input(app, sub, input.pricing_grid).await?)
             .build()?;
 
         exec_mutation!(
@@ -1066,11 +2238,14 @@ impl Mutation {
             .accrual_interval(input.accrual_interval)
             .accrual_cycle_interval(input.accrual_cycle_interval)
             .one_time_fee_rate(input.one_time_fee_rate)
+            .structuring_fee_recognition(input.structuring_fee_recognition.unwrap_or_default())
+            .interest_capitalization(input.interest_capitalization.unwrap_or_default())
             .duration(input.duration)
             .interest_due_duration(input.interest_due_duration)
             .liquidation_cvl(input.liquidation_cvl)
             .margin_call_cvl(input.margin_call_cvl)
             .initial_cvl(input.initial_cvl)
+            .pricing_grid_id(pricing_grid_id_from_input(app, sub, input.pricing_grid).await?)
             .build()?;
         exec_mutation!(
             TermsTemplateUpdatePayload,
@@ -1084,6 +2259,22 @@ impl Mutation {
         )
     }
 
+    async fn credit_exposure_limit_policy_update(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditExposureLimitPolicyUpdateInput,
+    ) -> async_graphql::Result<CreditExposureLimitPolicyUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let policy = app
+            .credit()
+            .exposure_limits()
+            .update_limits(sub, input.into())
+            .await?;
+        Ok(CreditExposureLimitPolicyUpdatePayload::from(
+            CreditExposureLimitPolicy::from(policy),
+        ))
+    }
+
     async fn credit_module_configure(
         &self,
         ctx: &Context<'_>,
@@ -1104,6 +2295,7 @@ impl Mutation {
             chart_of_account_collateral_parent_code,
             chart_of_account_interest_income_parent_code,
             chart_of_account_fee_income_parent_code,
+            chart_of_account_deferred_fee_income_parent_code,
 
             chart_of_account_short_term_individual_disbursed_receivable_parent_code,
             chart_of_account_short_term_government_entity_disbursed_receivable_parent_code,
@@ -1168,6 +2360,9 @@ impl Mutation {
             .chart_of_account_fee_income_parent_code(
                 chart_of_account_fee_income_parent_code.parse()?,
             )
+            .chart_of_account_deferred_fee_income_parent_code(
+                chart_of_account_deferred_fee_income_parent_code.parse()?,
+            )
             .chart_of_account_short_term_individual_disbursed_receivable_parent_code(chart_of_account_short_term_individual_disbursed_receivable_parent_code.parse()?)
             .chart_of_account_short_term_government_entity_disbursed_receivable_parent_code(chart_of_account_short_term_government_entity_disbursed_receivable_parent_code.parse()?)
             .chart_of_account_short_term_private_company_disbursed_receivable_parent_code(chart_of_account_short_term_private_company_disbursed_receivable_parent_code.parse()?)
@@ -1234,11 +2429,14 @@ impl Mutation {
             .accrual_interval(terms.accrual_interval)
             .accrual_cycle_interval(terms.accrual_cycle_interval)
             .one_time_fee_rate(terms.one_time_fee_rate)
+            .structuring_fee_recognition(terms.structuring_fee_recognition.unwrap_or_default())
+            .interest_capitalization(terms.interest_capitalization.unwrap_or_default())
             .duration(terms.duration)
             .interest_due_duration(terms.interest_due_duration)
             .liquidation_cvl(terms.liquidation_cvl)
             .margin_call_cvl(terms.margin_call_cvl)
             .initial_cvl(terms.initial_cvl)
+            .pricing_grid_id(pricing_grid_id_from_input(app, sub, terms.pricing_grid).await?)
             .build()?;
 
         exec_mutation!(
@@ -1274,6 +2472,58 @@ impl Mutation {
         )
     }
 
+    pub async fn credit_facility_collateral_fund(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityCollateralFundInput,
+    ) -> async_graphql::Result<CreditFacilityCollateralFundPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityCollateralFundInput {
+            credit_facility_id,
+            deposit_account_id,
+            amount,
+        } = input;
+        exec_mutation!(
+            CreditFacilityCollateralFundPayload,
+            CreditFacility,
+            ctx,
+            app.fund_collateral_from_deposit(sub, credit_facility_id, deposit_account_id, amount)
+        )
+    }
+
+    pub async fn credit_facility_auto_debit_enable(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityAutoDebitEnableInput,
+    ) -> async_graphql::Result<CreditFacilityAutoDebitEnablePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityAutoDebitEnableInput {
+            credit_facility_id,
+            deposit_account_id,
+        } = input;
+        exec_mutation!(
+            CreditFacilityAutoDebitEnablePayload,
+            CreditFacility,
+            ctx,
+            app.enable_credit_facility_auto_debit(sub, credit_facility_id, deposit_account_id)
+        )
+    }
+
+    pub async fn credit_facility_auto_debit_disable(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityAutoDebitDisableInput,
+    ) -> async_graphql::Result<CreditFacilityAutoDebitDisablePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityAutoDebitDisablePayload,
+            CreditFacility,
+            ctx,
+            app.credit()
+                .disable_auto_debit(sub, input.credit_facility_id.into())
+        )
+    }
+
     pub async fn credit_facility_partial_payment(
         &self,
         ctx: &Context<'_>,
@@ -1304,6 +2554,26 @@ impl Mutation {
         )
     }
 
+    pub async fn credit_facility_forbearance_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityForbearanceInitiateInput,
+    ) -> async_graphql::Result<CreditFacilityForbearanceInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityForbearanceInitiatePayload,
+            CreditFacilityForbearance,
+            ctx,
+            app.credit().initiate_forbearance(
+                sub,
+                input.credit_facility_id,
+                input.starts_at.into_inner(),
+                input.ends_at.into_inner(),
+                input.capitalize_interest
+            )
+        )
+    }
+
     async fn credit_facility_complete(
         &self,
         ctx: &Context<'_>,
@@ -1388,11 +2658,16 @@ impl Mutation {
         input: ApprovalProcessApproveInput,
     ) -> async_graphql::Result<ApprovalProcessApprovePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let process_id = governance::ApprovalProcessId::from(input.process_id);
+        app.accounting()
+            .manual_transactions()
+            .check_approver(sub, process_id)
+            .await?;
         exec_mutation!(
             ApprovalProcessApprovePayload,
             ApprovalProcess,
             ctx,
-            app.governance().approve_process(sub, input.process_id)
+            app.governance().approve_process(sub, process_id)
         )
     }
 
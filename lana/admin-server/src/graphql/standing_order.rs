@@ -0,0 +1,115 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{deposit_account::DepositAccount, loader::LanaDataLoader};
+
+pub use lana_app::deposit::{
+    StandingOrder as DomainStandingOrder, StandingOrderDestination, StandingOrderFrequency,
+    StandingOrderStatus,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct StandingOrder {
+    id: ID,
+    standing_order_id: UUID,
+    account_id: UUID,
+    destination_account_id: Option<UUID>,
+    credit_facility_id: Option<UUID>,
+    amount: UsdCents,
+    frequency: StandingOrderFrequency,
+    start_date: Date,
+    end_date: Option<Date>,
+    reference: String,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainStandingOrder>,
+}
+
+impl From<DomainStandingOrder> for StandingOrder {
+    fn from(standing_order: DomainStandingOrder) -> Self {
+        let (destination_account_id, credit_facility_id) = match standing_order.destination {
+            StandingOrderDestination::DepositAccount { deposit_account_id } => {
+                (Some(UUID::from(deposit_account_id)), None)
+            }
+            StandingOrderDestination::CreditFacility { credit_facility_id } => (
+                None,
+                Some(UUID::from(CreditFacilityId::from(credit_facility_id))),
+            ),
+        };
+        StandingOrder {
+            id: standing_order.id.to_global_id(),
+            created_at: standing_order.created_at().into(),
+            standing_order_id: UUID::from(standing_order.id),
+            account_id: standing_order.deposit_account_id.into(),
+            destination_account_id,
+            credit_facility_id,
+            amount: standing_order.amount,
+            frequency: standing_order.frequency,
+            start_date: standing_order.start_date.into(),
+            end_date: standing_order.end_date.map(Date::from),
+            reference: standing_order.reference.clone(),
+            entity: Arc::new(standing_order),
+        }
+    }
+}
+
+#[ComplexObject]
+impl StandingOrder {
+    async fn status(&self) -> StandingOrderStatus {
+        self.entity.status()
+    }
+
+    async fn next_execution_date(&self) -> Option<Date> {
+        self.entity.next_execution_date.map(Date::from)
+    }
+
+    async fn failed_attempts(&self) -> u32 {
+        self.entity.failed_attempts()
+    }
+
+    async fn last_failure_reason(&self) -> Option<&str> {
+        self.entity.last_failure_reason()
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct StandingOrderTransferCreateInput {
+    pub deposit_account_id: UUID,
+    pub to_deposit_account_id: UUID,
+    pub amount: UsdCents,
+    pub frequency: StandingOrderFrequency,
+    pub start_date: Date,
+    pub end_date: Option<Date>,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { StandingOrderTransferCreatePayload, standing_order: StandingOrder }
+
+#[derive(InputObject)]
+pub struct StandingOrderRepaymentCreateInput {
+    pub deposit_account_id: UUID,
+    pub credit_facility_id: UUID,
+    pub amount: UsdCents,
+    pub frequency: StandingOrderFrequency,
+    pub start_date: Date,
+    pub end_date: Option<Date>,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { StandingOrderRepaymentCreatePayload, standing_order: StandingOrder }
+
+#[derive(InputObject)]
+pub struct StandingOrderCancelInput {
+    pub standing_order_id: UUID,
+}
+crate::mutation_payload! { StandingOrderCancelPayload, standing_order: StandingOrder }
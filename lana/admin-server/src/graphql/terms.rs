@@ -1,20 +1,59 @@
 use async_graphql::*;
 
 pub use lana_app::terms::{
-    AnnualRatePct, CVLPct, Duration as DomainDuration, InterestDuration as DomainInterestDuration,
-    InterestInterval, OneTimeFeeRatePct, TermValues as DomainTermValues,
+    AnnualRatePct, CVLPct, Duration as DomainDuration, InterestCapitalization,
+    InterestDuration as DomainInterestDuration, InterestInterval, OneTimeFeeRatePct,
+    PricingGridBand as DomainPricingGridBand, PricingGridId, StructuringFeeRecognition,
+    TermValues as DomainTermValues,
 };
 
 #[derive(SimpleObject, Clone)]
+#[graphql(complex)]
 pub struct TermValues {
     annual_rate: AnnualRatePct,
     accrual_interval: InterestInterval,
     accrual_cycle_interval: InterestInterval,
     one_time_fee_rate: OneTimeFeeRatePct,
+    structuring_fee_recognition: StructuringFeeRecognition,
+    interest_capitalization: InterestCapitalization,
     duration: Duration,
     liquidation_cvl: CVLPct,
     margin_call_cvl: CVLPct,
     initial_cvl: CVLPct,
+    #[graphql(skip)]
+    pricing_grid_id: Option<PricingGridId>,
+}
+
+#[ComplexObject]
+impl TermValues {
+    async fn pricing_grid(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<PricingGridBand>> {
+        let Some(id) = self.pricing_grid_id else {
+            return Ok(Vec::new());
+        };
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .credit()
+            .pricing_grids()
+            .find_by_id(sub, id)
+            .await?
+            .map(|grid| grid.bands().iter().map(PricingGridBand::from).collect())
+            .unwrap_or_default())
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct PricingGridBand {
+    min_cvl: CVLPct,
+    rate_adjustment: AnnualRatePct,
+}
+
+impl From<&DomainPricingGridBand> for PricingGridBand {
+    fn from(band: &DomainPricingGridBand) -> Self {
+        Self {
+            min_cvl: band.min_cvl,
+            rate_adjustment: band.rate_adjustment,
+        }
+    }
 }
 
 impl From<DomainTermValues> for TermValues {
@@ -24,10 +63,13 @@ impl From<DomainTermValues> for TermValues {
             accrual_interval: values.accrual_interval,
             accrual_cycle_interval: values.accrual_cycle_interval,
             one_time_fee_rate: values.one_time_fee_rate,
+            structuring_fee_recognition: values.structuring_fee_recognition,
+            interest_capitalization: values.interest_capitalization,
             duration: values.duration.into(),
             liquidation_cvl: values.liquidation_cvl,
             margin_call_cvl: values.margin_call_cvl,
             initial_cvl: values.initial_cvl,
+            pricing_grid_id: values.pricing_grid_id,
         }
     }
 }
@@ -39,10 +81,45 @@ pub struct TermsInput {
     pub accrual_cycle_interval: InterestInterval,
     pub liquidation_cvl: CVLPct,
     pub one_time_fee_rate: OneTimeFeeRatePct,
+    pub structuring_fee_recognition: Option<StructuringFeeRecognition>,
+    pub interest_capitalization: Option<InterestCapitalization>,
     pub duration: DurationInput,
     pub interest_due_duration: DurationInput,
     pub margin_call_cvl: CVLPct,
     pub initial_cvl: CVLPct,
+    pub pricing_grid: Option<Vec<PricingGridBandInput>>,
+}
+
+#[derive(InputObject)]
+pub struct PricingGridBandInput {
+    pub min_cvl: CVLPct,
+    pub rate_adjustment: AnnualRatePct,
+}
+
+impl From<PricingGridBandInput> for DomainPricingGridBand {
+    fn from(band: PricingGridBandInput) -> Self {
+        Self {
+            min_cvl: band.min_cvl,
+            rate_adjustment: band.rate_adjustment,
+        }
+    }
+}
+
+pub(super) async fn pricing_grid_id_from_input(
+    app: &lana_app::app::LanaApp,
+    sub: &lana_app::primitives::Subject,
+    bands: Option<Vec<PricingGridBandInput>>,
+) -> async_graphql::Result<Option<PricingGridId>> {
+    let bands: Vec<DomainPricingGridBand> = bands
+        .into_iter()
+        .flatten()
+        .map(DomainPricingGridBand::from)
+        .collect();
+    if bands.is_empty() {
+        return Ok(None);
+    }
+    let grid = app.credit().pricing_grids().create(sub, bands).await?;
+    Ok(Some(grid.id))
 }
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
@@ -56,11 +56,14 @@ pub(super) struct TermsTemplateCreateInput {
     pub accrual_interval: InterestInterval,
     pub accrual_cycle_interval: InterestInterval,
     pub one_time_fee_rate: OneTimeFeeRatePct,
+    pub structuring_fee_recognition: Option<StructuringFeeRecognition>,
+    pub interest_capitalization: Option<InterestCapitalization>,
     pub duration: DurationInput,
     pub interest_due_duration: DurationInput,
     pub liquidation_cvl: CVLPct,
     pub margin_call_cvl: CVLPct,
     pub initial_cvl: CVLPct,
+    pub pricing_grid: Option<Vec<PricingGridBandInput>>,
 }
 crate::mutation_payload! { TermsTemplateCreatePayload, terms_template: TermsTemplate }
 
@@ -71,10 +74,13 @@ pub(super) struct TermsTemplateUpdateInput {
     pub accrual_interval: InterestInterval,
     pub accrual_cycle_interval: InterestInterval,
     pub one_time_fee_rate: OneTimeFeeRatePct,
+    pub structuring_fee_recognition: Option<StructuringFeeRecognition>,
+    pub interest_capitalization: Option<InterestCapitalization>,
     pub liquidation_cvl: CVLPct,
     pub duration: DurationInput,
     pub interest_due_duration: DurationInput,
     pub margin_call_cvl: CVLPct,
     pub initial_cvl: CVLPct,
+    pub pricing_grid: Option<Vec<PricingGridBandInput>>,
 }
 crate::mutation_payload! { TermsTemplateUpdatePayload, terms_template: TermsTemplate }
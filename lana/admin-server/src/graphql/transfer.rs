@@ -0,0 +1,100 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{
+    approval_process::ApprovalProcess, deposit_account::DepositAccount, loader::LanaDataLoader,
+};
+
+pub use lana_app::deposit::{
+    Transfer as DomainTransfer, TransferStatus, TransfersByCreatedAtCursor,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Transfer {
+    id: ID,
+    transfer_id: UUID,
+    from_account_id: UUID,
+    to_account_id: UUID,
+    approval_process_id: Option<UUID>,
+    amount: UsdCents,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainTransfer>,
+}
+
+impl From<DomainTransfer> for Transfer {
+    fn from(transfer: DomainTransfer) -> Self {
+        Transfer {
+            id: transfer.id.to_global_id(),
+            created_at: transfer.created_at().into(),
+            transfer_id: UUID::from(transfer.id),
+            from_account_id: transfer.from_deposit_account_id.into(),
+            to_account_id: transfer.to_deposit_account_id.into(),
+            approval_process_id: transfer.approval_process_id.map(UUID::from),
+            amount: transfer.amount,
+            entity: Arc::new(transfer),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Transfer {
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+
+    async fn status(&self, ctx: &Context<'_>) -> async_graphql::Result<TransferStatus> {
+        let (app, _) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .deposits()
+            .ensure_up_to_date_transfer_status(&self.entity)
+            .await?
+            .map(|t| t.status())
+            .unwrap_or_else(|| self.entity.status()))
+    }
+
+    async fn approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.approval_process_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(approval_process_id)
+            .await?
+            .expect("process not found");
+        Ok(Some(process))
+    }
+
+    async fn from_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.from_deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+
+    async fn to_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.to_deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct TransferInitiateInput {
+    pub from_deposit_account_id: UUID,
+    pub to_deposit_account_id: UUID,
+    pub amount: UsdCents,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { TransferInitiatePayload, transfer: Transfer }
@@ -4,10 +4,13 @@ use crate::primitives::*;
 
 use super::{
     approval_process::ApprovalProcess, deposit_account::DepositAccount, loader::LanaDataLoader,
+    payment_batch::PaymentBatch,
 };
 
 pub use lana_app::deposit::{
-    Withdrawal as DomainWithdrawal, WithdrawalStatus, WithdrawalsByCreatedAtCursor,
+    DepositAccountCurrency, Withdrawal as DomainWithdrawal,
+    WithdrawalBeneficiary as DomainWithdrawalBeneficiary, WithdrawalStatus,
+    WithdrawalsByCreatedAtCursor,
 };
 
 #[derive(SimpleObject, Clone)]
@@ -17,13 +20,33 @@ pub struct Withdrawal {
     withdrawal_id: UUID,
     account_id: UUID,
     approval_process_id: UUID,
+    currency: DepositAccountCurrency,
     amount: UsdCents,
+    btc_amount: Option<Satoshis>,
+    beneficiary: Option<WithdrawalBeneficiary>,
     created_at: Timestamp,
 
     #[graphql(skip)]
     pub(super) entity: Arc<DomainWithdrawal>,
 }
 
+#[derive(SimpleObject, Clone)]
+pub struct WithdrawalBeneficiary {
+    name: String,
+    account_number: String,
+    bic: Option<String>,
+}
+
+impl From<DomainWithdrawalBeneficiary> for WithdrawalBeneficiary {
+    fn from(beneficiary: DomainWithdrawalBeneficiary) -> Self {
+        WithdrawalBeneficiary {
+            name: beneficiary.name,
+            account_number: beneficiary.account_number,
+            bic: beneficiary.bic,
+        }
+    }
+}
+
 impl From<lana_app::deposit::Withdrawal> for Withdrawal {
     fn from(withdraw: lana_app::deposit::Withdrawal) -> Self {
         Withdrawal {
@@ -32,7 +55,13 @@ impl From<lana_app::deposit::Withdrawal> for Withdrawal {
             account_id: withdraw.deposit_account_id.into(),
             withdrawal_id: UUID::from(withdraw.id),
             approval_process_id: UUID::from(withdraw.approval_process_id),
-            amount: withdraw.amount,
+            currency: withdraw.amount.currency(),
+            amount: withdraw.amount.usd().unwrap_or(UsdCents::ZERO),
+            btc_amount: withdraw.amount.btc(),
+            beneficiary: withdraw
+                .beneficiary
+                .clone()
+                .map(WithdrawalBeneficiary::from),
             entity: Arc::new(withdraw),
         }
     }
@@ -54,13 +83,19 @@ impl Withdrawal {
             .unwrap_or_else(|| self.entity.status()))
     }
 
-    async fn approval_process(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcess> {
+    async fn approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        if !self.entity.has_approval_process() {
+            return Ok(None);
+        }
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let process = loader
             .load_one(self.entity.approval_process_id)
             .await?
             .expect("process not found");
-        Ok(process)
+        Ok(Some(process))
     }
 
     async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
@@ -72,6 +107,17 @@ impl Withdrawal {
         Ok(account)
     }
 
+    async fn payment_batch(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<PaymentBatch>> {
+        let Some(payment_batch_id) = self.entity.payment_batch_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(payment_batch_id).await?)
+    }
+
     // async fn subject_can_confirm(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
     //     let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
     //     Ok(app
@@ -91,14 +137,40 @@ impl Withdrawal {
     // }
 }
 
+#[derive(InputObject)]
+pub struct WithdrawalBeneficiaryInput {
+    pub name: String,
+    pub account_number: String,
+    pub bic: Option<String>,
+}
+
+impl From<WithdrawalBeneficiaryInput> for DomainWithdrawalBeneficiary {
+    fn from(input: WithdrawalBeneficiaryInput) -> Self {
+        DomainWithdrawalBeneficiary {
+            name: input.name,
+            account_number: input.account_number,
+            bic: input.bic,
+        }
+    }
+}
+
 #[derive(InputObject)]
 pub struct WithdrawalInitiateInput {
     pub deposit_account_id: UUID,
     pub amount: UsdCents,
     pub reference: Option<String>,
+    pub beneficiary: Option<WithdrawalBeneficiaryInput>,
 }
 crate::mutation_payload! { WithdrawalInitiatePayload, withdrawal: Withdrawal }
 
+#[derive(InputObject)]
+pub struct BtcWithdrawalInitiateInput {
+    pub deposit_account_id: UUID,
+    pub amount: Satoshis,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { BtcWithdrawalInitiatePayload, withdrawal: Withdrawal }
+
 #[derive(InputObject)]
 pub struct WithdrawalConfirmInput {
     pub withdrawal_id: UUID,
@@ -0,0 +1,133 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::{
+    customer::KycLevel,
+    deposit::{
+        WithdrawalLimit as DomainWithdrawalLimit,
+        WithdrawalLimitOverride as DomainWithdrawalLimitOverride, WithdrawalLimitOverrideStatus,
+        WithdrawalLimitsByCreatedAtCursor,
+    },
+};
+
+use super::{deposit_account::DepositAccount, loader::LanaDataLoader};
+
+#[derive(SimpleObject, Clone)]
+pub struct WithdrawalLimit {
+    id: ID,
+    withdrawal_limit_id: UUID,
+    name: String,
+    daily_limit: UsdCents,
+    monthly_limit: UsdCents,
+    kyc_level: Option<KycLevel>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainWithdrawalLimit>,
+}
+
+impl From<DomainWithdrawalLimit> for WithdrawalLimit {
+    fn from(withdrawal_limit: DomainWithdrawalLimit) -> Self {
+        WithdrawalLimit {
+            id: withdrawal_limit.id.to_global_id(),
+            withdrawal_limit_id: UUID::from(withdrawal_limit.id),
+            name: withdrawal_limit.name.to_string(),
+            daily_limit: withdrawal_limit.daily_limit,
+            monthly_limit: withdrawal_limit.monthly_limit,
+            kyc_level: withdrawal_limit.kyc_level,
+            created_at: withdrawal_limit.created_at().into(),
+
+            entity: Arc::new(withdrawal_limit),
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct WithdrawalLimitOverride {
+    id: ID,
+    withdrawal_limit_override_id: UUID,
+    account_id: UUID,
+    daily_limit: UsdCents,
+    monthly_limit: UsdCents,
+    reason: String,
+    expires_at: Timestamp,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainWithdrawalLimitOverride>,
+}
+
+impl From<DomainWithdrawalLimitOverride> for WithdrawalLimitOverride {
+    fn from(limit_override: DomainWithdrawalLimitOverride) -> Self {
+        WithdrawalLimitOverride {
+            id: limit_override.id.to_global_id(),
+            withdrawal_limit_override_id: UUID::from(limit_override.id),
+            account_id: limit_override.deposit_account_id.into(),
+            daily_limit: limit_override.daily_limit,
+            monthly_limit: limit_override.monthly_limit,
+            reason: limit_override.reason.clone(),
+            expires_at: limit_override.expires_at.into(),
+            created_at: limit_override.created_at().into(),
+            entity: Arc::new(limit_override),
+        }
+    }
+}
+
+#[ComplexObject]
+impl WithdrawalLimitOverride {
+    async fn status(&self) -> WithdrawalLimitOverrideStatus {
+        self.entity.status()
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct WithdrawalLimitCreateInput {
+    pub name: String,
+    pub daily_limit: UsdCents,
+    pub monthly_limit: UsdCents,
+    pub kyc_level: Option<KycLevel>,
+}
+crate::mutation_payload! { WithdrawalLimitCreatePayload, withdrawal_limit: WithdrawalLimit }
+
+#[derive(InputObject)]
+pub struct WithdrawalLimitUpdateInput {
+    pub withdrawal_limit_id: UUID,
+    pub daily_limit: UsdCents,
+    pub monthly_limit: UsdCents,
+}
+crate::mutation_payload! { WithdrawalLimitUpdatePayload, withdrawal_limit: WithdrawalLimit }
+
+#[derive(InputObject)]
+pub struct DepositAccountWithdrawalLimitAssignInput {
+    pub deposit_account_id: UUID,
+    pub withdrawal_limit_id: Option<UUID>,
+}
+crate::mutation_payload! { DepositAccountWithdrawalLimitAssignPayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct WithdrawalLimitOverrideGrantInput {
+    pub deposit_account_id: UUID,
+    pub daily_limit: UsdCents,
+    pub monthly_limit: UsdCents,
+    pub reason: String,
+    pub expires_at: Timestamp,
+}
+crate::mutation_payload! { WithdrawalLimitOverrideGrantPayload, withdrawal_limit_override: WithdrawalLimitOverride }
+
+#[derive(InputObject)]
+pub struct WithdrawalLimitOverrideRevokeInput {
+    pub withdrawal_limit_override_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { WithdrawalLimitOverrideRevokePayload, withdrawal_limit_override: WithdrawalLimitOverride }
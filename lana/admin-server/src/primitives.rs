@@ -5,10 +5,14 @@ use serde::{Deserialize, Serialize};
 
 pub use lana_app::{
     primitives::{
-        ApprovalProcessId, ChartId, CommitteeId, CreditFacilityId, CustomerId, DepositAccountId,
-        DepositId, DisbursalId, DisbursalStatus, DocumentId, LanaRole, LedgerTransactionId,
-        ManualTransactionId, PaymentId, PolicyId, ReportId, ReportProgress, Satoshis,
-        SignedSatoshis, SignedUsdCents, Subject, TermsTemplateId, UsdCents, UserId, WithdrawalId,
+        AccountingPeriodId, ApprovalProcessId, BankStatementImportId, BankStatementLineId, ChartId,
+        CommitteeId, CreditFacilityId, CustomerId, DepositAccountId, DepositAccountStatementId,
+        DepositFeeId, DepositId, DepositProductId, DisbursalId, DisbursalStatus, DocumentId,
+        EarlyBreakPenaltyPct, ForbearanceId, ForbearanceStatus, HoldId, InterestRatePct, LanaRole,
+        LedgerTransactionId, ManualTransactionId, PaymentBatchId, PaymentId, PolicyId, ReportId,
+        ReportProgress, Satoshis, SignatoryId, SignedSatoshis, SignedUsdCents, StandingOrderId,
+        Subject, TermsTemplateId, TransferId, UsdCents, UserId, WithdrawalId, WithdrawalLimitId,
+        WithdrawalLimitOverrideId,
     },
     terms::CollateralizationState,
 };
@@ -92,10 +96,12 @@ macro_rules! impl_to_global_id {
 impl_to_global_id! {
     UserId,
     CustomerId,
+    SignatoryId,
     ChartId,
     TermsTemplateId,
     CreditFacilityId,
     DisbursalId,
+    ForbearanceId,
     PaymentId,
     audit::AuditEntryId,
     ReportId,
@@ -104,9 +110,21 @@ impl_to_global_id! {
     CommitteeId,
     WithdrawalId,
     DepositId,
+    DepositFeeId,
     ManualTransactionId,
+    AccountingPeriodId,
     ApprovalProcessId,
     DepositAccountId,
+    DepositProductId,
+    TransferId,
+    HoldId,
+    StandingOrderId,
+    WithdrawalLimitId,
+    WithdrawalLimitOverrideId,
+    DepositAccountStatementId,
+    PaymentBatchId,
+    BankStatementImportId,
+    BankStatementLineId,
     LedgerTransactionId
 }
 
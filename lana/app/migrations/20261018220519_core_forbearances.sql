@@ -0,0 +1,15 @@
+CREATE TABLE core_forbearances (
+  id UUID PRIMARY KEY,
+  credit_facility_id UUID NOT NULL REFERENCES core_credit_facilities(id),
+  approval_process_id UUID NOT NULL REFERENCES approval_processes(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_forbearance_events (
+  id UUID NOT NULL REFERENCES core_forbearances(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
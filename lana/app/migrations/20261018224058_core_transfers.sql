@@ -0,0 +1,17 @@
+CREATE TABLE core_transfers (
+  id UUID PRIMARY KEY,
+  from_deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  to_deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  approval_process_id UUID REFERENCES approval_processes(id),
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_transfer_events (
+  id UUID NOT NULL REFERENCES core_transfers(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
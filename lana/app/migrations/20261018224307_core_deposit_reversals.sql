@@ -0,0 +1 @@
+ALTER TABLE core_deposits ADD COLUMN reverted_tx_id UUID DEFAULT NULL;
@@ -0,0 +1,14 @@
+CREATE TABLE core_holds (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_hold_events (
+  id UUID NOT NULL REFERENCES core_holds(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
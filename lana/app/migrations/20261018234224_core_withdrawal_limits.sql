@@ -0,0 +1,29 @@
+CREATE TABLE core_withdrawal_limits (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_withdrawal_limit_events (
+  id UUID NOT NULL REFERENCES core_withdrawal_limits(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_withdrawal_limit_overrides (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_withdrawal_limit_override_events (
+  id UUID NOT NULL REFERENCES core_withdrawal_limit_overrides(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
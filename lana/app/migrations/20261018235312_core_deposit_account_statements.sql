@@ -0,0 +1,16 @@
+CREATE TABLE core_deposit_account_statements (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  account_holder_id UUID NOT NULL,
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_deposit_account_statement_events (
+  id UUID NOT NULL REFERENCES core_deposit_account_statements(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
@@ -0,0 +1,39 @@
+ALTER TABLE core_withdrawals ADD COLUMN status VARCHAR;
+
+UPDATE core_withdrawals w
+SET status = CASE
+  WHEN EXISTS (
+    SELECT 1 FROM core_withdrawal_events e
+    WHERE e.id = w.id AND e.event->>'type' = 'confirmed'
+  ) THEN 'Confirmed'
+  WHEN EXISTS (
+    SELECT 1 FROM core_withdrawal_events e
+    WHERE e.id = w.id AND e.event->>'type' = 'cancelled'
+  ) THEN 'Cancelled'
+  WHEN EXISTS (
+    SELECT 1 FROM core_withdrawal_events e
+    WHERE e.id = w.id AND e.event->>'type' = 'approval_process_concluded'
+      AND (e.event->>'approved')::BOOLEAN
+  ) THEN 'PendingConfirmation'
+  WHEN EXISTS (
+    SELECT 1 FROM core_withdrawal_events e
+    WHERE e.id = w.id AND e.event->>'type' = 'approval_process_concluded'
+  ) THEN 'Denied'
+  ELSE 'PendingApproval'
+END;
+
+ALTER TABLE core_withdrawals ALTER COLUMN status SET NOT NULL;
+
+CREATE TABLE core_payment_batches (
+  id UUID PRIMARY KEY,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_payment_batch_events (
+  id UUID NOT NULL REFERENCES core_payment_batches(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
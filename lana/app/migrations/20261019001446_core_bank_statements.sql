@@ -0,0 +1,30 @@
+CREATE TABLE core_bank_statement_imports (
+  id UUID PRIMARY KEY,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_bank_statement_import_events (
+  id UUID NOT NULL REFERENCES core_bank_statement_imports(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_bank_statement_lines (
+  id UUID PRIMARY KEY,
+  import_id UUID NOT NULL REFERENCES core_bank_statement_imports(id),
+  bank_transaction_id VARCHAR NOT NULL UNIQUE,
+  status VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_bank_statement_line_events (
+  id UUID NOT NULL REFERENCES core_bank_statement_lines(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
@@ -0,0 +1,16 @@
+CREATE TABLE core_deposit_fees (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  waived_tx_id UUID DEFAULT NULL,
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_deposit_fee_events (
+  id UUID NOT NULL REFERENCES core_deposit_fees(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
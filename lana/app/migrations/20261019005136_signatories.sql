@@ -0,0 +1,16 @@
+CREATE TABLE signatories (
+  id UUID PRIMARY KEY,
+  customer_id UUID NOT NULL REFERENCES customers(id),
+  email VARCHAR NOT NULL UNIQUE,
+  authentication_id UUID UNIQUE DEFAULT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE signatory_events (
+  id UUID NOT NULL REFERENCES signatories(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
@@ -0,0 +1,13 @@
+CREATE TABLE core_accounting_periods (
+  id UUID PRIMARY KEY,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_accounting_period_events (
+  id UUID NOT NULL REFERENCES core_accounting_periods(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
@@ -0,0 +1,14 @@
+CREATE TABLE core_exposure_limit_policies (
+  id UUID PRIMARY KEY,
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_exposure_limit_policy_events (
+  id UUID NOT NULL REFERENCES core_exposure_limit_policies(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
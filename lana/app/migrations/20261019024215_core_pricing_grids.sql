@@ -0,0 +1,13 @@
+CREATE TABLE core_pricing_grids (
+  id UUID PRIMARY KEY,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_pricing_grid_events (
+  id UUID NOT NULL REFERENCES core_pricing_grids(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
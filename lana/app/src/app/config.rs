@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    applicant::SumsubConfig, credit::CreditConfig, customer_onboarding::CustomerOnboardingConfig,
-    job::JobExecutorConfig, report::ReportConfig, service_account::ServiceAccountConfig,
-    storage::config::StorageConfig, user_onboarding::UserOnboardingConfig,
+    accounting::AccountingConfig, applicant::SumsubConfig, credit::CreditConfig,
+    customer_onboarding::CustomerOnboardingConfig, deposit::DepositConfig, job::JobExecutorConfig,
+    report::ReportConfig, service_account::ServiceAccountConfig, storage::config::StorageConfig,
+    user_onboarding::UserOnboardingConfig,
 };
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -17,6 +18,10 @@ pub struct AppConfig {
     #[serde(default)]
     pub credit: CreditConfig,
     #[serde(default)]
+    pub deposit: DepositConfig,
+    #[serde(default)]
+    pub accounting: AccountingConfig,
+    #[serde(default)]
     pub service_account: ServiceAccountConfig,
     #[serde(default)]
     pub report: ReportConfig,
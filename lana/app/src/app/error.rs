@@ -44,10 +44,18 @@ pub enum ApplicationError {
     CalaError(#[from] cala_ledger::error::LedgerError),
     #[error("ApplicationError - ChartOfAccountsError: {0}")]
     ChartOfAccountsError(#[from] core_accounting::chart_of_accounts::error::ChartOfAccountsError),
+    #[error("ApplicationError - AccountingError: {0}")]
+    AccountingError(#[from] core_accounting::error::CoreAccountingError),
     #[error("ApplicationError - DepositError: {0}")]
     DepositError(#[from] crate::deposit::error::CoreDepositError),
     #[error("ApplicationError - StorageError: {0}")]
     StorageError(#[from] crate::storage::error::StorageError),
     #[error("ApplicationError - ApplicantError: {0}")]
     ApplicantError(#[from] crate::applicant::error::ApplicantError),
+    #[error("ApplicationError - CreditFacilityNotFound: {0}")]
+    CreditFacilityNotFound(crate::primitives::CreditFacilityId),
+    #[error("ApplicationError - DepositAccountNotFound: {0}")]
+    DepositAccountNotFound(crate::primitives::DepositAccountId),
+    #[error("ApplicationError - WithdrawalRequiresSignatoryApproval: {0}")]
+    WithdrawalRequiresSignatoryApproval(crate::primitives::DepositAccountId),
 }
@@ -13,19 +13,23 @@ use crate::{
     audit::{Audit, AuditCursor, AuditEntry},
     authorization::{init as init_authz, AppAction, AppObject, AuditAction, Authorization},
     cash_flow::CashFlowStatements,
-    credit::Credit,
+    credit::{Credit, CreditFacility},
     customer::Customers,
     customer_onboarding::CustomerOnboarding,
     dashboard::Dashboard,
-    deposit::Deposits,
+    deposit::{
+        DepositAmount, Deposits, StandingOrder, StandingOrderDestination, StandingOrderFrequency,
+        Withdrawal, WithdrawalBeneficiary,
+    },
     document::Documents,
     general_ledger::GeneralLedger,
     governance::Governance,
     job::Jobs,
     outbox::Outbox,
     price::Price,
-    primitives::Subject,
+    primitives::{CreditFacilityId, DepositAccountId, Satoshis, Subject, UsdCents},
     report::Reports,
+    standing_order,
     storage::Storage,
     terms_template::TermsTemplates,
     user::Users,
@@ -87,14 +91,18 @@ impl LanaApp {
         let journal_init = JournalInit::journal(&cala).await?;
         let cash_flow_statements =
             CashFlowStatements::init(&pool, &authz, &cala, journal_init.journal_id).await?;
-        let accounting = Accounting::new(
+        let accounting = Accounting::init(
             &pool,
+            config.accounting,
             &authz,
             &cala,
             journal_init.journal_id,
             &storage,
             &jobs,
-        );
+            &governance,
+            &outbox,
+        )
+        .await?;
 
         StatementsInit::statements(
             accounting.trial_balances(),
@@ -109,10 +117,12 @@ impl LanaApp {
         let customers = Customers::new(&pool, &authz, &outbox);
         let deposits = Deposits::init(
             &pool,
+            config.deposit,
             &authz,
             &outbox,
             &governance,
             &jobs,
+            &storage,
             &cala,
             journal_init.journal_id,
         )
@@ -141,6 +151,7 @@ impl LanaApp {
             journal_init.journal_id,
         )
         .await?;
+        standing_order::init(&jobs, &outbox, &credit, &deposits).await?;
         let terms_templates = TermsTemplates::new(&pool, &authz);
         jobs.start_poll().await?;
 
@@ -228,6 +239,161 @@ impl LanaApp {
         &self.credit
     }
 
+    /// Funds a credit facility's collateral from the customer's BTC deposit account. The
+    /// outflow is a deposit withdrawal that is confirmed once the collateral is recorded
+    /// and cancelled if recording it fails.
+    #[instrument(name = "lana.fund_collateral_from_deposit", skip(self), err)]
+    pub async fn fund_collateral_from_deposit(
+        &self,
+        sub: &Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: Satoshis,
+    ) -> Result<CreditFacility, ApplicationError> {
+        let credit_facility_id = credit_facility_id.into();
+        let credit_facility = self
+            .credit
+            .find_by_id(sub, credit_facility_id)
+            .await?
+            .ok_or(ApplicationError::CreditFacilityNotFound(credit_facility_id))?;
+
+        let withdrawal = self
+            .deposits
+            .initiate_collateral_withdrawal(
+                sub,
+                deposit_account_id,
+                credit_facility.customer_id,
+                amount,
+                format!("collateral-funding:{credit_facility_id}"),
+            )
+            .await?;
+
+        match self
+            .credit
+            .add_collateral(sub, credit_facility_id, amount)
+            .await
+        {
+            Ok(credit_facility) => {
+                self.deposits.confirm_withdrawal(sub, withdrawal.id).await?;
+                Ok(credit_facility)
+            }
+            Err(e) => {
+                self.deposits.cancel_withdrawal(sub, withdrawal.id).await?;
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Links a deposit account of the facility's customer for repaying due obligations.
+    #[instrument(name = "lana.enable_credit_facility_auto_debit", skip(self), err)]
+    pub async fn enable_credit_facility_auto_debit(
+        &self,
+        sub: &Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<CreditFacility, ApplicationError> {
+        let credit_facility_id = credit_facility_id.into();
+        let credit_facility = self
+            .credit
+            .find_by_id(sub, credit_facility_id)
+            .await?
+            .ok_or(ApplicationError::CreditFacilityNotFound(credit_facility_id))?;
+
+        let account = self
+            .deposits
+            .check_auto_debit_account(sub, deposit_account_id, credit_facility.customer_id)
+            .await?;
+
+        Ok(self
+            .credit
+            .enable_auto_debit(sub, credit_facility_id, account.id)
+            .await?)
+    }
+
+    /// Sets up a standing order repaying a credit facility out of a deposit account of the
+    /// facility's customer.
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(
+        name = "lana.create_credit_facility_repayment_standing_order",
+        skip(self),
+        err
+    )]
+    pub async fn create_credit_facility_repayment_standing_order(
+        &self,
+        sub: &Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        frequency: StandingOrderFrequency,
+        start_date: chrono::NaiveDate,
+        end_date: Option<chrono::NaiveDate>,
+        reference: Option<String>,
+    ) -> Result<StandingOrder, ApplicationError> {
+        let credit_facility_id = credit_facility_id.into();
+        let credit_facility = self
+            .credit
+            .find_by_id(sub, credit_facility_id)
+            .await?
+            .ok_or(ApplicationError::CreditFacilityNotFound(credit_facility_id))?;
+
+        let account = self
+            .deposits
+            .check_auto_debit_account(sub, deposit_account_id, credit_facility.customer_id)
+            .await?;
+
+        Ok(self
+            .deposits
+            .standing_orders()
+            .create_standing_order(
+                sub,
+                account.id,
+                StandingOrderDestination::CreditFacility {
+                    credit_facility_id: credit_facility_id.into(),
+                },
+                amount,
+                frequency,
+                start_date,
+                end_date,
+                reference,
+            )
+            .await?)
+    }
+
+    /// Initiates a withdrawal on behalf of the account holder. Accounts of customers with
+    /// active signatories only accept withdrawals initiated by a signatory, so that they
+    /// go through signatory approval.
+    #[instrument(name = "lana.initiate_withdrawal", skip(self), err)]
+    pub async fn initiate_withdrawal(
+        &self,
+        sub: &Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<DepositAmount> + std::fmt::Debug,
+        reference: Option<String>,
+        beneficiary: Option<WithdrawalBeneficiary>,
+    ) -> Result<Withdrawal, ApplicationError> {
+        let deposit_account_id = deposit_account_id.into();
+        let account = self
+            .deposits
+            .find_account_by_id(sub, deposit_account_id)
+            .await?
+            .ok_or(ApplicationError::DepositAccountNotFound(deposit_account_id))?;
+
+        let signatories = self
+            .customers
+            .list_signatories_for_customer(sub, account.account_holder_id)
+            .await?;
+        if signatories.iter().any(|signatory| signatory.is_active()) {
+            return Err(ApplicationError::WithdrawalRequiresSignatoryApproval(
+                deposit_account_id,
+            ));
+        }
+
+        Ok(self
+            .deposits
+            .initiate_withdrawal(sub, deposit_account_id, amount, reference, beneficiary)
+            .await?)
+    }
+
     pub fn cash_flow_statements(&self) -> &CashFlowStatements {
         &self.cash_flow_statements
     }
@@ -10,7 +10,7 @@ use lana_events::LanaEvent;
 use super::{error::ApplicantError, sumsub_auth::*};
 use crate::{
     customer::CustomerId,
-    deposit::{CoreDepositEvent, DepositId, Deposits, WithdrawalId},
+    deposit::{CoreDepositEvent, DepositAmount, DepositId, Deposits, WithdrawalId},
     job::*,
     outbox::Outbox,
 };
@@ -84,7 +84,7 @@ impl JobRunner for SumsubExportJobRunner {
                 Some(LanaEvent::Deposit(CoreDepositEvent::DepositInitialized {
                     id,
                     deposit_account_id,
-                    amount,
+                    amount: DepositAmount::Usd(amount),
                 })) => {
                     let account = self
                         .deposits
@@ -102,7 +102,7 @@ impl JobRunner for SumsubExportJobRunner {
                 Some(LanaEvent::Deposit(CoreDepositEvent::WithdrawalConfirmed {
                     id,
                     deposit_account_id,
-                    amount,
+                    amount: DepositAmount::Usd(amount),
                 })) => {
                     let account = self
                         .deposits
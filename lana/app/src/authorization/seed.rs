@@ -271,6 +271,41 @@ async fn add_permissions_for_bank_manager(authz: &Authorization) -> Result<(), A
             CoreCustomerAction::CUSTOMER_UPDATE,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CustomerObject::all_signatories(),
+            CoreCustomerAction::SIGNATORY_CREATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CustomerObject::all_signatories(),
+            CoreCustomerAction::SIGNATORY_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CustomerObject::all_signatories(),
+            CoreCustomerAction::SIGNATORY_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CustomerObject::all_signatories(),
+            CoreCustomerAction::SIGNATORY_UPDATE_ROLE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CustomerObject::all_signatories(),
+            CoreCustomerAction::SIGNATORY_REMOVE,
+        )
+        .await?;
     authz
         .add_permission_to_role(&role, Object::Document, DocumentAction::Create)
         .await?;
@@ -335,6 +370,13 @@ async fn add_permissions_for_bank_manager(authz: &Authorization) -> Result<(), A
             CoreCreditAction::CREDIT_FACILITY_RECORD_INTEREST,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreCreditObject::all_credit_facilities(),
+            CoreCreditAction::CREDIT_FACILITY_AMORTIZE_STRUCTURING_FEE,
+        )
+        .await?;
     authz
         .add_permission_to_role(
             &role,
@@ -349,6 +391,20 @@ async fn add_permissions_for_bank_manager(authz: &Authorization) -> Result<(), A
             CoreCreditAction::CREDIT_FACILITY_UPDATE_COLLATERALIZATION_STATE,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreCreditObject::all_credit_facilities(),
+            CoreCreditAction::CREDIT_FACILITY_UPDATE_AUTO_DEBIT_MANDATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreCreditObject::all_credit_facilities(),
+            CoreCreditAction::CREDIT_FACILITY_RECORD_AUTO_DEBIT,
+        )
+        .await?;
     authz
         .add_permission_to_role(
             &role,
@@ -370,6 +426,34 @@ async fn add_permissions_for_bank_manager(authz: &Authorization) -> Result<(), A
             CoreCreditAction::CHART_OF_ACCOUNTS_INTEGRATION_CONFIG_UPDATE,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreCreditObject::exposure_limit_policy(),
+            CoreCreditAction::EXPOSURE_LIMIT_POLICY_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreCreditObject::exposure_limit_policy(),
+            CoreCreditAction::EXPOSURE_LIMIT_POLICY_UPDATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreCreditObject::all_pricing_grids(),
+            CoreCreditAction::PRICING_GRID_CREATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreCreditObject::all_pricing_grids(),
+            CoreCreditAction::PRICING_GRID_READ,
+        )
+        .await?;
 
     authz
         .add_permission_to_role(
@@ -416,6 +500,27 @@ async fn add_permissions_for_bank_manager(authz: &Authorization) -> Result<(), A
             CoreCreditAction::DISBURSAL_INITIATE,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreCreditObject::all_credit_facilities(),
+            CoreCreditAction::FORBEARANCE_INITIATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreCreditObject::all_credit_facilities(),
+            CoreCreditAction::FORBEARANCE_CONCLUDE_APPROVAL_PROCESS,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreCreditObject::all_credit_facilities(),
+            CoreCreditAction::FORBEARANCE_LIST,
+        )
+        .await?;
     authz
         .add_permission_to_role(
             &role,
@@ -538,6 +643,55 @@ async fn add_permissions_for_bank_manager(authz: &Authorization) -> Result<(), A
             CoreAccountingAction::MANUAL_TRANSACTION_CREATE,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreAccountingObject::all_manual_transactions(),
+            CoreAccountingAction::MANUAL_TRANSACTION_REVERSE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreAccountingObject::all_accounting_periods(),
+            CoreAccountingAction::ACCOUNTING_PERIOD_CREATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreAccountingObject::all_accounting_periods(),
+            CoreAccountingAction::ACCOUNTING_PERIOD_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreAccountingObject::all_accounting_periods(),
+            CoreAccountingAction::ACCOUNTING_PERIOD_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreAccountingObject::all_accounting_periods(),
+            CoreAccountingAction::ACCOUNTING_PERIOD_SOFT_CLOSE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreAccountingObject::all_accounting_periods(),
+            CoreAccountingAction::ACCOUNTING_PERIOD_CLOSE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreAccountingObject::all_accounting_periods(),
+            CoreAccountingAction::ACCOUNTING_PERIOD_REQUEST_REOPEN,
+        )
+        .await?;
     authz
         .add_permission_to_role(
             &role,
@@ -601,6 +755,13 @@ async fn add_permissions_for_bank_manager(authz: &Authorization) -> Result<(), A
             CoreDepositAction::DEPOSIT_CREATE,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposits(),
+            CoreDepositAction::DEPOSIT_REVERT,
+        )
+        .await?;
     authz
         .add_permission_to_role(
             &role,
@@ -645,6 +806,55 @@ async fn add_permissions_for_bank_manager(authz: &Authorization) -> Result<(), A
             CoreDepositAction::DEPOSIT_ACCOUNT_UPDATE_STATUS,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_accounts(),
+            CoreDepositAction::DEPOSIT_ACCOUNT_FREEZE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_accounts(),
+            CoreDepositAction::DEPOSIT_ACCOUNT_UNFREEZE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_accounts(),
+            CoreDepositAction::DEPOSIT_ACCOUNT_CLOSE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_accounts(),
+            CoreDepositAction::DEPOSIT_ACCOUNT_REACTIVATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_accounts(),
+            CoreDepositAction::DEPOSIT_ACCOUNT_TRANSFER_UNCLAIMED_FUNDS,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_accounts(),
+            CoreDepositAction::DEPOSIT_ACCOUNT_OPEN_TIME_DEPOSIT,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_accounts(),
+            CoreDepositAction::DEPOSIT_ACCOUNT_BREAK_TIME_DEPOSIT,
+        )
+        .await?;
     authz
         .add_permission_to_role(
             &role,
@@ -666,6 +876,76 @@ async fn add_permissions_for_bank_manager(authz: &Authorization) -> Result<(), A
             CoreDepositAction::DEPOSIT_ACCOUNT_READ_BALANCE,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_accounts(),
+            CoreDepositAction::DEPOSIT_ACCOUNT_ASSIGN_PRODUCT,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_products(),
+            CoreDepositAction::DEPOSIT_PRODUCT_CREATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_products(),
+            CoreDepositAction::DEPOSIT_PRODUCT_UPDATE_RATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_products(),
+            CoreDepositAction::DEPOSIT_PRODUCT_UPDATE_FEE_SCHEDULE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_products(),
+            CoreDepositAction::DEPOSIT_PRODUCT_UPDATE_EARLY_BREAK_PENALTY,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_fees(),
+            CoreDepositAction::DEPOSIT_FEE_WAIVE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_fees(),
+            CoreDepositAction::DEPOSIT_FEE_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_fees(),
+            CoreDepositAction::DEPOSIT_FEE_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_products(),
+            CoreDepositAction::DEPOSIT_PRODUCT_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_products(),
+            CoreDepositAction::DEPOSIT_PRODUCT_LIST,
+        )
+        .await?;
     authz
         .add_permission_to_role(
             &role,
@@ -701,6 +981,237 @@ async fn add_permissions_for_bank_manager(authz: &Authorization) -> Result<(), A
             CoreDepositAction::WITHDRAWAL_LIST,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_transfers(),
+            CoreDepositAction::TRANSFER_INITIATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_transfers(),
+            CoreDepositAction::TRANSFER_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_transfers(),
+            CoreDepositAction::TRANSFER_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_holds(),
+            CoreDepositAction::HOLD_PLACE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_holds(),
+            CoreDepositAction::HOLD_RELEASE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_holds(),
+            CoreDepositAction::HOLD_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_holds(),
+            CoreDepositAction::HOLD_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_standing_orders(),
+            CoreDepositAction::STANDING_ORDER_CREATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_standing_orders(),
+            CoreDepositAction::STANDING_ORDER_CANCEL,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_standing_orders(),
+            CoreDepositAction::STANDING_ORDER_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_standing_orders(),
+            CoreDepositAction::STANDING_ORDER_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_accounts(),
+            CoreDepositAction::DEPOSIT_ACCOUNT_ASSIGN_WITHDRAWAL_LIMIT,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_withdrawal_limits(),
+            CoreDepositAction::WITHDRAWAL_LIMIT_CREATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_withdrawal_limits(),
+            CoreDepositAction::WITHDRAWAL_LIMIT_UPDATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_withdrawal_limits(),
+            CoreDepositAction::WITHDRAWAL_LIMIT_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_withdrawal_limits(),
+            CoreDepositAction::WITHDRAWAL_LIMIT_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_withdrawal_limits(),
+            CoreDepositAction::WITHDRAWAL_LIMIT_GRANT_OVERRIDE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_withdrawal_limits(),
+            CoreDepositAction::WITHDRAWAL_LIMIT_REVOKE_OVERRIDE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_statements(),
+            CoreDepositAction::STATEMENT_CREATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_statements(),
+            CoreDepositAction::STATEMENT_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_statements(),
+            CoreDepositAction::STATEMENT_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_statements(),
+            CoreDepositAction::STATEMENT_GENERATE_DOWNLOAD_LINK,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_payment_batches(),
+            CoreDepositAction::PAYMENT_BATCH_CREATE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_payment_batches(),
+            CoreDepositAction::PAYMENT_BATCH_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_payment_batches(),
+            CoreDepositAction::PAYMENT_BATCH_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_payment_batches(),
+            CoreDepositAction::PAYMENT_BATCH_MARK_SENT,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_payment_batches(),
+            CoreDepositAction::PAYMENT_BATCH_MARK_SETTLED,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_payment_batches(),
+            CoreDepositAction::PAYMENT_BATCH_GENERATE_DOWNLOAD_LINK,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_bank_statements(),
+            CoreDepositAction::BANK_STATEMENT_IMPORT,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_bank_statements(),
+            CoreDepositAction::BANK_STATEMENT_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_bank_statements(),
+            CoreDepositAction::BANK_STATEMENT_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_bank_statements(),
+            CoreDepositAction::BANK_STATEMENT_RESOLVE_LINE,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_bank_statements(),
+            CoreDepositAction::BANK_STATEMENT_DISMISS_LINE,
+        )
+        .await?;
 
     Ok(())
 }
@@ -711,6 +1222,13 @@ async fn add_permissions_for_accountant(authz: &Authorization) -> Result<(), Aut
     authz
         .add_permission_to_role(&role, Object::TermsTemplate, TermsTemplateAction::Read)
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreCreditObject::all_pricing_grids(),
+            CoreCreditAction::PRICING_GRID_READ,
+        )
+        .await?;
     authz
         .add_permission_to_role(
             &role,
@@ -725,6 +1243,20 @@ async fn add_permissions_for_accountant(authz: &Authorization) -> Result<(), Aut
             CoreCustomerAction::CUSTOMER_LIST,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CustomerObject::all_signatories(),
+            CoreCustomerAction::SIGNATORY_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CustomerObject::all_signatories(),
+            CoreCustomerAction::SIGNATORY_LIST,
+        )
+        .await?;
     authz
         .add_permission_to_role(
             &role,
@@ -739,6 +1271,20 @@ async fn add_permissions_for_accountant(authz: &Authorization) -> Result<(), Aut
             CoreDepositAction::DEPOSIT_LIST,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_fees(),
+            CoreDepositAction::DEPOSIT_FEE_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_deposit_fees(),
+            CoreDepositAction::DEPOSIT_FEE_LIST,
+        )
+        .await?;
     authz
         .add_permission_to_role(
             &role,
@@ -753,6 +1299,118 @@ async fn add_permissions_for_accountant(authz: &Authorization) -> Result<(), Aut
             CoreDepositAction::WITHDRAWAL_LIST,
         )
         .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_transfers(),
+            CoreDepositAction::TRANSFER_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_transfers(),
+            CoreDepositAction::TRANSFER_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_holds(),
+            CoreDepositAction::HOLD_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_holds(),
+            CoreDepositAction::HOLD_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_standing_orders(),
+            CoreDepositAction::STANDING_ORDER_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_standing_orders(),
+            CoreDepositAction::STANDING_ORDER_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_withdrawal_limits(),
+            CoreDepositAction::WITHDRAWAL_LIMIT_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_withdrawal_limits(),
+            CoreDepositAction::WITHDRAWAL_LIMIT_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_statements(),
+            CoreDepositAction::STATEMENT_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_statements(),
+            CoreDepositAction::STATEMENT_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_statements(),
+            CoreDepositAction::STATEMENT_GENERATE_DOWNLOAD_LINK,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_payment_batches(),
+            CoreDepositAction::PAYMENT_BATCH_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_payment_batches(),
+            CoreDepositAction::PAYMENT_BATCH_LIST,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_payment_batches(),
+            CoreDepositAction::PAYMENT_BATCH_GENERATE_DOWNLOAD_LINK,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_bank_statements(),
+            CoreDepositAction::BANK_STATEMENT_READ,
+        )
+        .await?;
+    authz
+        .add_permission_to_role(
+            &role,
+            CoreDepositObject::all_bank_statements(),
+            CoreDepositAction::BANK_STATEMENT_LIST,
+        )
+        .await?;
     authz
         .add_permission_to_role(&role, Object::Document, DocumentAction::Read)
         .await?;
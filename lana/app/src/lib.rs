@@ -11,6 +11,7 @@ pub mod general_ledger;
 pub mod primitives;
 pub mod report;
 pub mod service_account;
+pub mod standing_order;
 pub mod statement;
 pub mod terms_template;
 
@@ -40,7 +41,7 @@ pub mod user {
 pub mod customer {
     pub use core_customer::{
         error, AccountStatus, Customer, CustomerId, CustomerType, CustomersCursor, CustomersSortBy,
-        FindManyCustomers, KycLevel, Sort,
+        FindManyCustomers, KycLevel, Signatory, SignatoryId, SignatoryRole, SignatoryStatus, Sort,
     };
     pub type Customers =
         core_customer::Customers<crate::authorization::Authorization, lana_events::LanaEvent>;
@@ -68,6 +69,10 @@ pub mod governance {
     pub type Governance = governance::Governance<Authorization, LanaEvent>;
     pub use crate::credit::APPROVE_CREDIT_FACILITY_PROCESS;
     pub use crate::credit::APPROVE_DISBURSAL_PROCESS;
+    pub use crate::credit::APPROVE_FORBEARANCE_PROCESS;
+    pub use core_accounting::APPROVE_MANUAL_TRANSACTION_PROCESS;
+    pub use core_accounting::APPROVE_PERIOD_REOPEN_PROCESS;
+    pub use deposit::APPROVE_TRANSFER_PROCESS;
     pub use deposit::APPROVE_WITHDRAWAL_PROCESS;
 }
 
@@ -84,9 +89,23 @@ pub mod audit {
 
 pub mod deposit {
     pub use deposit::{
-        error, ChartOfAccountsIntegrationConfig, CoreDepositEvent, Deposit, DepositAccount,
-        DepositAccountBalance, DepositAccountHistoryCursor, DepositAccountHistoryEntry, DepositId,
-        DepositsByCreatedAtCursor, Withdrawal, WithdrawalId, WithdrawalStatus,
+        error, BankStatementFormat, BankStatementImport, BankStatementImportSummary,
+        BankStatementImportsByCreatedAtCursor, BankStatementLine, BankStatementLineStatus,
+        BankStatementLinesByCreatedAtCursor, BtcDepositAccountBalance,
+        ChartOfAccountsIntegrationConfig, CoreDepositEvent, Deposit, DepositAccount,
+        DepositAccountBalance, DepositAccountCurrency, DepositAccountHistoryCursor,
+        DepositAccountHistoryEntry, DepositAccountStatement, DepositAccountStatementDownloadLinks,
+        DepositAccountStatementStatus, DepositAccountStatus, DepositAmount, DepositConfig,
+        DepositFee, DepositFeeSchedule, DepositFeeStatus, DepositFeeType,
+        DepositFeesByCreatedAtCursor, DepositId, DepositProduct, DepositProductsByCreatedAtCursor,
+        DepositReversalReason, DepositStatus, DepositsByCreatedAtCursor, DormantAccountReportEntry,
+        GeneratedPaymentBatchDownloadLink, Hold, HoldStatus, HoldsByCreatedAtCursor, PaymentBatch,
+        PaymentBatchStatus, PaymentBatchesByCreatedAtCursor, StandingOrder,
+        StandingOrderDestination, StandingOrderFrequency, StandingOrderStatus,
+        StandingOrdersByCreatedAtCursor, TimeDepositTerms, Transfer, TransferStatus,
+        TransfersByCreatedAtCursor, Withdrawal, WithdrawalBeneficiary, WithdrawalId,
+        WithdrawalLimit, WithdrawalLimitOverride, WithdrawalLimitOverrideStatus,
+        WithdrawalLimitSource, WithdrawalLimitsByCreatedAtCursor, WithdrawalStatus,
         WithdrawalsByCreatedAtCursor,
     };
 
@@ -96,12 +115,17 @@ pub mod deposit {
 
 pub mod accounting {
     pub use core_accounting::{
-        chart_of_accounts, error, journal, ledger_account, ledger_transaction, manual_transaction,
-        transaction_templates, AccountCode, CalaAccountId, LedgerAccountId, TransactionTemplateId,
-        {tree, Chart},
+        accounting_period, chart_of_accounts, error, journal, ledger_account, ledger_transaction,
+        manual_transaction, transaction_templates, AccountCode, AccountingConfig, AccountingPeriod,
+        AccountingPeriodId, AccountingPeriodStatus, AccountingPeriodsByCreatedAtCursor,
+        CalaAccountId, LedgerAccountId, ManualTransaction, ManualTransactionStatus,
+        ManualTransactionsByCreatedAtCursor, TransactionTemplateId, {tree, Chart},
     };
 
-    pub type Accounting = core_accounting::CoreAccounting<crate::authorization::Authorization>;
+    pub type Accounting = core_accounting::CoreAccounting<
+        crate::authorization::Authorization,
+        lana_events::LanaEvent,
+    >;
     pub type ChartOfAccounts =
         core_accounting::ChartOfAccounts<crate::authorization::Authorization>;
 }
@@ -124,14 +148,17 @@ pub mod trial_balance {
 
 pub mod credit {
     pub use core_credit::{
-        error, ChartOfAccountsIntegrationConfig, CollateralUpdated, CollateralizationUpdated,
-        CoreCreditEvent, CreditConfig, CreditFacilitiesCursor, CreditFacilitiesSortBy,
-        CreditFacility, CreditFacilityBalanceSummary, CreditFacilityHistoryEntry,
-        CreditFacilityOrigination, CreditFacilityRepaymentInPlan, CreditFacilityStatus, Disbursal,
-        DisbursalExecuted, DisbursalStatus, DisbursalsCursor, DisbursalsSortBy, FacilityCVL,
-        FindManyCreditFacilities, FindManyDisbursals, IncrementalPayment, InterestAccrualsPosted,
-        ListDirection, Payment, RepaymentStatus, Sort, APPROVE_CREDIT_FACILITY_PROCESS,
-        APPROVE_DISBURSAL_PROCESS,
+        error, AutoDebitAttempt, ChartOfAccountsIntegrationConfig, CollateralUpdated,
+        CollateralizationUpdated, CoreCreditEvent, CreditConfig, CreditFacilitiesCursor,
+        CreditFacilitiesSortBy, CreditFacility, CreditFacilityBalanceSummary,
+        CreditFacilityHistoryEntry, CreditFacilityOrigination, CreditFacilityRepaymentInPlan,
+        CreditFacilityStatus, CustomerTypeExposureLimit, Disbursal, DisbursalExecuted,
+        DisbursalStatus, DisbursalsCursor, DisbursalsSortBy, ExposureLimitPolicy,
+        ExposureLimitScope, ExposureLimitUtilization, ExposureLimitsConfig, FacilityCVL,
+        FindManyCreditFacilities, FindManyDisbursals, Forbearance, ForbearanceStatus,
+        IncrementalPayment, InterestAccrualsPosted, InterestRateAdjusted, ListDirection, Payment,
+        PricingGrid, RepaymentStatus, Sort, APPROVE_CREDIT_FACILITY_PROCESS,
+        APPROVE_DISBURSAL_PROCESS, APPROVE_FORBEARANCE_PROCESS,
     };
 
     pub type Credit =
@@ -140,7 +167,8 @@ pub mod credit {
 
 pub mod terms {
     pub use core_credit::{
-        AnnualRatePct, CVLPct, CollateralizationState, Duration, InterestDuration,
-        InterestInterval, OneTimeFeeRatePct, TermValues,
+        AnnualRatePct, CVLPct, CollateralizationState, Duration, InterestCapitalization,
+        InterestDuration, InterestInterval, OneTimeFeeRatePct, PricingGridBand, PricingGridId,
+        StructuringFeeRecognition, TermValues,
     };
 }
@@ -2,16 +2,23 @@ use serde::{Deserialize, Serialize};
 
 use std::fmt;
 
-pub use core_accounting::{BalanceRange, Chart, ChartId, LedgerTransactionId, ManualTransactionId};
+pub use core_accounting::{
+    AccountingPeriodId, BalanceRange, Chart, ChartId, LedgerTransactionId, ManualTransactionId,
+};
 pub use core_credit::{
-    CollateralAction, CreditFacilityId, CreditFacilityStatus, DisbursalId, DisbursalStatus,
-    PaymentId,
+    AutoDebitOutcome, CollateralAction, CreditFacilityId, CreditFacilityStatus, DisbursalId,
+    DisbursalStatus, ForbearanceId, ForbearanceStatus, PaymentId,
 };
-pub use core_customer::CustomerId;
+pub use core_customer::{CustomerId, SignatoryId};
 pub use core_money::*;
 pub use core_price::PriceOfOneBTC;
 pub use core_user::UserId;
-pub use deposit::{DepositAccountHolderId, DepositAccountId, DepositId, WithdrawalId};
+pub use deposit::{
+    BankStatementImportId, BankStatementLineId, DepositAccountHolderId, DepositAccountId,
+    DepositAccountStatementId, DepositFeeId, DepositId, DepositProductId, EarlyBreakPenaltyPct,
+    HoldId, InterestRatePct, PaymentBatchId, StandingOrderId, TransferId, WithdrawalId,
+    WithdrawalLimitId, WithdrawalLimitOverrideId,
+};
 pub use governance::{ApprovalProcessId, CommitteeId, CommitteeMemberId, PolicyId};
 pub use job::JobId;
 pub use lana_ids::*;
@@ -0,0 +1,26 @@
+mod repayment;
+
+use crate::{
+    credit::Credit,
+    deposit::Deposits,
+    job::{error::JobError, Jobs},
+    outbox::Outbox,
+};
+
+use repayment::*;
+
+/// Standing orders live in the deposit module, which has no access to credit facilities,
+/// so repayments they trigger are recorded here from the published deposit events.
+pub async fn init(
+    jobs: &Jobs,
+    outbox: &Outbox,
+    credit: &Credit,
+    deposits: &Deposits,
+) -> Result<(), JobError> {
+    jobs.add_initializer_and_spawn_unique(
+        StandingOrderRepaymentInitializer::new(outbox, credit, deposits),
+        StandingOrderRepaymentJobConfig,
+    )
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use audit::SystemSubject;
+use lana_events::LanaEvent;
+
+use crate::{
+    credit::Credit,
+    deposit::{CoreDepositEvent, Deposits},
+    job::*,
+    outbox::Outbox,
+    primitives::CreditFacilityId,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct StandingOrderRepaymentJobConfig;
+
+impl JobConfig for StandingOrderRepaymentJobConfig {
+    type Initializer = StandingOrderRepaymentInitializer;
+}
+
+pub struct StandingOrderRepaymentInitializer {
+    outbox: Outbox,
+    credit: Credit,
+    deposits: Deposits,
+}
+
+impl StandingOrderRepaymentInitializer {
+    pub fn new(outbox: &Outbox, credit: &Credit, deposits: &Deposits) -> Self {
+        Self {
+            outbox: outbox.clone(),
+            credit: credit.clone(),
+            deposits: deposits.clone(),
+        }
+    }
+}
+
+const STANDING_ORDER_REPAYMENT_JOB: JobType = JobType::new("standing-order-repayment");
+impl JobInitializer for StandingOrderRepaymentInitializer {
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        STANDING_ORDER_REPAYMENT_JOB
+    }
+
+    fn init(&self, _job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(StandingOrderRepaymentJobRunner {
+            outbox: self.outbox.clone(),
+            credit: self.credit.clone(),
+            deposits: self.deposits.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Deserialize, Serialize)]
+struct StandingOrderRepaymentJobData {
+    sequence: outbox::EventSequence,
+}
+
+pub struct StandingOrderRepaymentJobRunner {
+    outbox: Outbox,
+    credit: Credit,
+    deposits: Deposits,
+}
+
+#[async_trait]
+impl JobRunner for StandingOrderRepaymentJobRunner {
+    #[tracing::instrument(name = "standing_order.repayment", skip_all, err)]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<StandingOrderRepaymentJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            if let Some(LanaEvent::Deposit(CoreDepositEvent::StandingOrderRepaymentDue {
+                id,
+                deposit_account_id,
+                credit_facility_id,
+                amount,
+                execution_date,
+            })) = &message.payload
+            {
+                // A repayment that cannot be recorded fails the standing order execution
+                // rather than the job, so later events are not held up behind it.
+                let failure_reason = self
+                    .credit
+                    .record_payment_from_deposit(
+                        &rbac_types::Subject::system(),
+                        CreditFacilityId::from(*credit_facility_id),
+                        *deposit_account_id,
+                        *amount,
+                    )
+                    .await
+                    .err()
+                    .map(|e| e.to_string());
+                self.deposits
+                    .standing_orders()
+                    .record_standing_order_repayment(*id, *execution_date, failure_reason)
+                    .await?;
+            }
+            state.sequence = message.sequence;
+            current_job.update_execution_state(&state).await?;
+        }
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
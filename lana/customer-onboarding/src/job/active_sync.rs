@@ -146,13 +146,17 @@ where
         let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
 
         while let Some(message) = stream.next().await {
-            if let Some(CoreCustomerEvent::CustomerAccountStatusUpdated { .. }) =
-                &message.as_ref().as_event()
-            {
-                self.handle_status_updated(message.as_ref()).await?;
-                state.sequence = message.sequence;
-                current_job.update_execution_state(&state).await?;
+            match &message.as_ref().as_event() {
+                Some(CoreCustomerEvent::CustomerAccountStatusUpdated { .. }) => {
+                    self.handle_status_updated(message.as_ref()).await?;
+                }
+                Some(CoreCustomerEvent::CustomerKycLevelUpdated { .. }) => {
+                    self.handle_kyc_level_updated(message.as_ref()).await?;
+                }
+                _ => continue,
             }
+            state.sequence = message.sequence;
+            current_job.update_execution_state(&state).await?;
         }
 
         Ok(JobCompletion::RescheduleNow)
@@ -199,4 +203,31 @@ where
         }
         Ok(())
     }
+
+    #[instrument(
+        name = "customer_onboarding.handle_kyc_level_update",
+        skip(self, message),
+        err
+    )]
+    async fn handle_kyc_level_updated(
+        &self,
+        message: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error>>
+    where
+        E: OutboxEventMarker<CoreCustomerEvent>,
+    {
+        if let Some(CoreCustomerEvent::CustomerKycLevelUpdated { id, level }) = message.as_event() {
+            message.inject_trace_parent();
+
+            self.deposit
+                .withdrawal_limits()
+                .update_withdrawal_limit_for_holder(
+                    &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject::system(),
+                    *id,
+                    *level,
+                )
+                .await?;
+        }
+        Ok(())
+    }
 }
@@ -138,8 +138,15 @@ where
         let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
 
         while let Some(message) = stream.next().await {
-            if let Some(CoreCustomerEvent::CustomerCreated { .. }) = &message.as_ref().as_event() {
-                self.handle_create_kratos_user(message.as_ref()).await?;
+            match &message.as_ref().as_event() {
+                Some(CoreCustomerEvent::CustomerCreated { .. }) => {
+                    self.handle_create_kratos_user(message.as_ref()).await?;
+                }
+                Some(CoreCustomerEvent::SignatoryCreated { .. }) => {
+                    self.handle_create_kratos_user_for_signatory(message.as_ref())
+                        .await?;
+                }
+                _ => {}
             }
         }
 
@@ -178,4 +185,29 @@ where
         }
         Ok(())
     }
+
+    #[instrument(
+        name = "customer_onboarding.create_kratos_user_for_signatory",
+        skip(self, message)
+    )]
+    async fn handle_create_kratos_user_for_signatory(
+        &self,
+        message: &PersistentOutboxEvent<E>,
+    ) -> Result<(), Box<dyn std::error::Error>>
+    where
+        E: OutboxEventMarker<CoreCustomerEvent>,
+    {
+        if let Some(CoreCustomerEvent::SignatoryCreated { id, email, .. }) = message.as_event() {
+            message.inject_trace_parent();
+
+            let authentication_id = self
+                .kratos_admin
+                .create_user::<AuthenticationId>(email.clone())
+                .await?;
+            self.customers
+                .update_authentication_id_for_signatory(*id, authentication_id)
+                .await?;
+        }
+        Ok(())
+    }
 }
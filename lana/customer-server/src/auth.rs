@@ -30,32 +30,130 @@ pub async fn customer_id_from_authentication_id(
         .await
     {
         Ok(customer) => {
-            if let serde_json::Value::Object(ref mut extra) = payload.extra {
-                extra.insert(
-                    "subject".to_string(),
-                    serde_json::Value::String(customer.id.to_string()),
-                );
-            } else {
-                payload.extra = serde_json::json!({
-                    "subject": customer.id.to_string()
-                });
-            }
+            add_claims(&mut payload.extra, customer_claims(customer.id, None));
             Json(payload).into_response()
         }
         Err(e) if e.was_not_found() => {
+            signatory_id_from_authentication_id(app, payload, authentication_id).await
+        }
+        Err(error) => {
+            println!("Error finding customer: {:?}", error);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+async fn signatory_id_from_authentication_id(
+    app: LanaApp,
+    mut payload: AuthenticationPayload,
+    authentication_id: core_customer::AuthenticationId,
+) -> axum::response::Response {
+    match app
+        .customers()
+        .find_signatory_by_authentication_id(authentication_id)
+        .await
+    {
+        Ok(Some(signatory)) if signatory.is_active() => {
+            add_claims(
+                &mut payload.extra,
+                customer_claims(signatory.customer_id, Some(signatory.id)),
+            );
+            Json(payload).into_response()
+        }
+        Ok(_) => {
             println!("Customer not found: {:?}", authentication_id);
             StatusCode::NOT_FOUND.into_response()
         }
         Err(error) => {
-            println!("Error finding customer: {:?}", error);
+            println!("Error finding signatory: {:?}", error);
             StatusCode::INTERNAL_SERVER_ERROR.into_response()
         }
     }
 }
 
+/// Signatories act on behalf of their customer, so they carry the customer as `subject`
+/// and are told apart by the additional `signatory_id` claim.
+fn customer_claims(
+    customer_id: core_customer::CustomerId,
+    signatory_id: Option<core_customer::SignatoryId>,
+) -> Vec<(String, serde_json::Value)> {
+    let mut claims = vec![(
+        "subject".to_string(),
+        serde_json::Value::String(customer_id.to_string()),
+    )];
+    if let Some(signatory_id) = signatory_id {
+        claims.push((
+            "signatory_id".to_string(),
+            serde_json::Value::String(signatory_id.to_string()),
+        ));
+    }
+    claims
+}
+
+fn add_claims(extra: &mut serde_json::Value, claims: Vec<(String, serde_json::Value)>) {
+    if let serde_json::Value::Object(ref mut extra) = extra {
+        extra.extend(claims);
+    } else {
+        *extra = serde_json::Value::Object(claims.into_iter().collect());
+    }
+}
+
 pub fn auth_routes() -> Router<JwtDecoderState> {
     Router::new().route(
         "/customer/customer-id-from-authentication-id",
         post(customer_id_from_authentication_id),
     )
 }
+
+#[cfg(test)]
+mod test {
+    use core_customer::{CustomerId, SignatoryId};
+
+    use super::*;
+
+    #[test]
+    fn customer_claims_only_carry_subject() {
+        let customer_id = CustomerId::new();
+        let mut extra = serde_json::json!({ "existing": "claim" });
+
+        add_claims(&mut extra, customer_claims(customer_id, None));
+
+        assert_eq!(
+            extra,
+            serde_json::json!({
+                "existing": "claim",
+                "subject": customer_id.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn signatory_claims_carry_customer_as_subject() {
+        let customer_id = CustomerId::new();
+        let signatory_id = SignatoryId::new();
+        let mut extra = serde_json::Value::Null;
+
+        add_claims(&mut extra, customer_claims(customer_id, Some(signatory_id)));
+
+        assert_eq!(
+            extra,
+            serde_json::json!({
+                "subject": customer_id.to_string(),
+                "signatory_id": signatory_id.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn signatory_claims_map_to_jwt_claims() {
+        let customer_id = CustomerId::new();
+        let signatory_id = SignatoryId::new();
+        let mut extra = serde_json::Value::Null;
+
+        add_claims(&mut extra, customer_claims(customer_id, Some(signatory_id)));
+        let claims: crate::CustomerJwtClaims = serde_json::from_value(extra).unwrap();
+
+        assert_eq!(claims.subject, customer_id.to_string());
+        assert_eq!(claims.signatory_id, Some(signatory_id.to_string()));
+    }
+}
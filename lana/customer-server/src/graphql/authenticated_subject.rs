@@ -1,19 +1,30 @@
 use async_graphql::*;
 
-use core_customer::Customer as DomainCustomer;
+use core_customer::{Customer as DomainCustomer, Signatory as DomainSignatory};
 
-use super::customer::*;
+use super::{customer::*, signatory::*};
 
 #[derive(SimpleObject)]
 #[graphql(name = "Subject")]
 pub struct AuthenticatedSubject {
     customer: Customer,
+    signatory: Option<Signatory>,
 }
 
 impl From<DomainCustomer> for AuthenticatedSubject {
     fn from(entity: DomainCustomer) -> Self {
         Self {
             customer: Customer::from(entity),
+            signatory: None,
+        }
+    }
+}
+
+impl From<(DomainCustomer, DomainSignatory)> for AuthenticatedSubject {
+    fn from((customer, signatory): (DomainCustomer, DomainSignatory)) -> Self {
+        Self {
+            customer: Customer::from(customer),
+            signatory: Some(Signatory::from(signatory)),
         }
     }
 }
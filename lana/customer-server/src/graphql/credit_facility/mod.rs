@@ -60,8 +60,20 @@ impl From<DomainCreditFacility> for CreditFacility {
 
 #[ComplexObject]
 impl CreditFacility {
-    async fn credit_facility_terms(&self) -> TermValues {
-        self.entity.terms.into()
+    async fn credit_facility_terms(&self, ctx: &Context<'_>) -> async_graphql::Result<TermValues> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let pricing_grid = app
+            .credit()
+            .for_subject(sub)?
+            .pricing_grid(self.entity.id)
+            .await?;
+        Ok(TermValues::new(
+            self.entity.terms,
+            pricing_grid
+                .as_ref()
+                .map(|grid| grid.bands())
+                .unwrap_or_default(),
+        ))
     }
 
     async fn balance(&self, ctx: &Context<'_>) -> async_graphql::Result<CreditFacilityBalance> {
@@ -2,7 +2,7 @@ use async_graphql::*;
 
 use crate::primitives::*;
 
-pub use lana_app::deposit::Deposit as DomainDeposit;
+pub use lana_app::deposit::{Deposit as DomainDeposit, DepositAccountCurrency};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -10,7 +10,9 @@ pub struct Deposit {
     id: ID,
     deposit_id: UUID,
     account_id: UUID,
+    currency: DepositAccountCurrency,
     amount: UsdCents,
+    btc_amount: Option<Satoshis>,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -23,7 +25,9 @@ impl From<DomainDeposit> for Deposit {
             id: deposit.id.to_global_id(),
             deposit_id: UUID::from(deposit.id),
             account_id: UUID::from(deposit.deposit_account_id),
-            amount: deposit.amount,
+            currency: deposit.amount.currency(),
+            amount: deposit.amount.usd().unwrap_or(UsdCents::ZERO),
+            btc_amount: deposit.amount.btc(),
             created_at: deposit.created_at().into(),
 
             entity: Arc::new(deposit),
@@ -3,11 +3,11 @@ use async_graphql::{connection::*, *};
 use crate::primitives::*;
 
 pub use lana_app::deposit::{
-    DepositAccount as DomainDepositAccount, DepositAccountHistoryCursor,
-    DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
+    DepositAccount as DomainDepositAccount, DepositAccountCurrency, DepositAccountHistoryCursor,
+    DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry, DepositAccountStatus,
 };
 
-use super::{deposit::*, deposit_account_history::*, withdrawal::*};
+use super::{deposit::*, deposit_account_history::*, deposit_account_statement::*, withdrawal::*};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -15,6 +15,8 @@ pub struct DepositAccount {
     id: ID,
     deposit_account_id: UUID,
     customer_id: UUID,
+    status: DepositAccountStatus,
+    currency: DepositAccountCurrency,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -27,6 +29,8 @@ impl From<DomainDepositAccount> for DepositAccount {
             id: account.id.to_global_id(),
             deposit_account_id: account.id.into(),
             customer_id: account.account_holder_id.into(),
+            status: account.status,
+            currency: account.currency,
             created_at: account.created_at().into(),
 
             entity: Arc::new(account),
@@ -38,6 +42,8 @@ impl From<DomainDepositAccount> for DepositAccount {
 pub struct DepositAccountBalance {
     settled: UsdCents,
     pending: UsdCents,
+    encumbrance: UsdCents,
+    available: UsdCents,
 }
 
 impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
@@ -45,6 +51,8 @@ impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
         Self {
             settled: balance.settled,
             pending: balance.pending,
+            encumbrance: balance.encumbrance,
+            available: balance.available(),
         }
     }
 }
@@ -81,6 +89,22 @@ impl DepositAccount {
         Ok(withdrawals.into_iter().map(Withdrawal::from).collect())
     }
 
+    async fn statements(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DepositAccountStatement>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let statements = app
+            .deposits()
+            .for_subject(sub)?
+            .list_statements_for_account(self.entity.id)
+            .await?;
+        Ok(statements
+            .into_iter()
+            .map(DepositAccountStatement::from)
+            .collect())
+    }
+
     async fn history(
         &self,
         ctx: &Context<'_>,
@@ -2,6 +2,8 @@ use async_graphql::*;
 
 use crate::primitives::*;
 
+use lana_app::deposit::DepositAccountStatus;
+
 use super::{
     credit_facility::disbursal::CreditFacilityDisbursal,
     credit_facility::payment::CreditFacilityPayment, deposit::Deposit, withdrawal::Withdrawal,
@@ -10,10 +12,17 @@ use super::{
 #[derive(Union)]
 pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
+    RevertedDeposit(RevertedDepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(CancelledWithdrawalEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
+    TransferIn(TransferInEntry),
+    TransferOut(TransferOutEntry),
+    UnclaimedFundsTransfer(UnclaimedFundsTransferEntry),
+    Fee(FeeEntry),
+    WaivedFee(WaivedFeeEntry),
+    StatusChanged(AccountStatusChangedEntry),
     Unknown(UnknownEntry),
 }
 
@@ -25,6 +34,14 @@ pub struct DepositEntry {
     pub recorded_at: Timestamp,
 }
 
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct RevertedDepositEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
 #[derive(SimpleObject)]
 #[graphql(complex)]
 pub struct WithdrawalEntry {
@@ -56,6 +73,43 @@ pub struct PaymentEntry {
     pub recorded_at: Timestamp,
 }
 
+#[derive(SimpleObject)]
+pub struct TransferInEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct TransferOutEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct UnclaimedFundsTransferEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct FeeEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct WaivedFeeEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct AccountStatusChangedEntry {
+    pub status: DepositAccountStatus,
+    pub reason: String,
+    pub recorded_at: Timestamp,
+}
+
 #[derive(SimpleObject)]
 pub struct UnknownEntry {
     pub tx_id: UUID,
@@ -77,6 +131,21 @@ impl DepositEntry {
     }
 }
 
+#[ComplexObject]
+impl RevertedDepositEntry {
+    async fn deposit(&self, ctx: &Context<'_>) -> async_graphql::Result<Deposit> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let deposit = app
+            .deposits()
+            .for_subject(sub)?
+            .find_deposit_by_reverted_tx_id(self.tx_id)
+            .await?;
+
+        Ok(Deposit::from(deposit))
+    }
+}
+
 #[ComplexObject]
 impl WithdrawalEntry {
     async fn withdrawal(&self, ctx: &Context<'_>) -> async_graphql::Result<Withdrawal> {
@@ -146,6 +215,12 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::RevertedDeposit(entry) => {
+                Self::RevertedDeposit(RevertedDepositEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Withdrawal(entry) => {
                 Self::Withdrawal(WithdrawalEntry {
                     tx_id: UUID::from(entry.tx_id),
@@ -170,6 +245,41 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferIn(entry) => {
+                Self::TransferIn(TransferInEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferOut(entry) => {
+                Self::TransferOut(TransferOutEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::UnclaimedFundsTransfer(entry) => {
+                Self::UnclaimedFundsTransfer(UnclaimedFundsTransferEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::Fee(entry) => Self::Fee(FeeEntry {
+                tx_id: UUID::from(entry.tx_id),
+                recorded_at: entry.recorded_at.into(),
+            }),
+            lana_app::deposit::DepositAccountHistoryEntry::WaivedFee(entry) => {
+                Self::WaivedFee(WaivedFeeEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::StatusChanged(entry) => {
+                Self::StatusChanged(AccountStatusChangedEntry {
+                    status: entry.status,
+                    reason: entry.reason,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Unknown(entry) => {
                 Self::Unknown(UnknownEntry {
                     tx_id: UUID::from(entry.tx_id),
@@ -0,0 +1,75 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{
+    DepositAccountStatement as DomainDepositAccountStatement, DepositAccountStatementDownloadLinks,
+    DepositAccountStatementStatus,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct DepositAccountStatement {
+    id: ID,
+    deposit_account_statement_id: UUID,
+    account_id: UUID,
+    period_start: Date,
+    period_end: Date,
+    opening_balance: Option<UsdCents>,
+    closing_balance: Option<UsdCents>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainDepositAccountStatement>,
+}
+
+impl From<DomainDepositAccountStatement> for DepositAccountStatement {
+    fn from(statement: DomainDepositAccountStatement) -> Self {
+        DepositAccountStatement {
+            id: statement.id.to_global_id(),
+            deposit_account_statement_id: UUID::from(statement.id),
+            account_id: statement.deposit_account_id.into(),
+            period_start: statement.period_start.into(),
+            period_end: statement.period_end.into(),
+            opening_balance: statement.opening_balance,
+            closing_balance: statement.closing_balance,
+            created_at: statement.created_at().into(),
+            entity: Arc::new(statement),
+        }
+    }
+}
+
+#[ComplexObject]
+impl DepositAccountStatement {
+    async fn status(&self) -> DepositAccountStatementStatus {
+        self.entity.status()
+    }
+
+    async fn download_links(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<DepositAccountStatementDownloadLinksGenerated> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let links = app
+            .deposits()
+            .for_subject(sub)?
+            .generate_statement_download_links(self.entity.id)
+            .await?;
+        Ok(DepositAccountStatementDownloadLinksGenerated::from(links))
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DepositAccountStatementDownloadLinksGenerated {
+    pdf_link: String,
+    csv_link: String,
+}
+
+impl From<DepositAccountStatementDownloadLinks> for DepositAccountStatementDownloadLinksGenerated {
+    fn from(links: DepositAccountStatementDownloadLinks) -> Self {
+        Self {
+            pdf_link: links.pdf_url,
+            csv_link: links.csv_url,
+        }
+    }
+}
@@ -14,7 +14,30 @@
 macro_rules! app_and_sub_from_ctx {
     ($ctx:expr) => {{
         let app = $ctx.data_unchecked::<lana_app::app::LanaApp>();
-        let $crate::primitives::CustomerAuthContext { sub } = $ctx.data()?;
+        let $crate::primitives::CustomerAuthContext { sub, .. } = $ctx.data()?;
         (app, sub)
     }};
 }
+
+// Helper to create a 'standard' payload of the form
+// pub struct SomeMutationPayload {
+//   entity: Entity
+// }
+//
+// eg:
+// mutation_payload! { WithdrawalInitiatePayload, withdrawal: Withdrawal }
+#[macro_export]
+macro_rules! mutation_payload {
+    ($payload:ident, $name:ident: $gql_type:ty) => {
+        #[derive(SimpleObject)]
+        pub struct $payload {
+            $name: $gql_type,
+        }
+
+        impl From<$gql_type> for $payload {
+            fn from($name: $gql_type) -> Self {
+                Self { $name }
+            }
+        }
+    };
+}
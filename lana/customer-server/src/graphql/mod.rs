@@ -6,8 +6,10 @@ mod customer;
 mod deposit;
 mod deposit_account;
 mod deposit_account_history;
+mod deposit_account_statement;
 mod price;
 mod schema;
+mod signatory;
 mod terms;
 mod withdrawal;
 
@@ -17,8 +19,8 @@ pub use schema::*;
 
 use lana_app::app::LanaApp;
 
-pub fn schema(app: Option<LanaApp>) -> Schema<Query, EmptyMutation, EmptySubscription> {
-    let mut schema_builder = Schema::build(Query, EmptyMutation, EmptySubscription);
+pub fn schema(app: Option<LanaApp>) -> Schema<Query, Mutation, EmptySubscription> {
+    let mut schema_builder = Schema::build(Query, Mutation, EmptySubscription);
 
     if let Some(app) = app {
         schema_builder = schema_builder.data(app);
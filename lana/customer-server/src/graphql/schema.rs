@@ -2,7 +2,7 @@ use async_graphql::{Context, Object};
 
 use crate::{primitives::*, LanaApp};
 
-use super::{authenticated_subject::*, credit_facility::*, price::*};
+use super::{authenticated_subject::*, credit_facility::*, price::*, withdrawal::*};
 
 pub struct Query;
 
@@ -11,7 +11,17 @@ impl Query {
     async fn me(&self, ctx: &Context<'_>) -> async_graphql::Result<AuthenticatedSubject> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         let customer = app.customers().find_for_subject(sub).await?;
-        Ok(AuthenticatedSubject::from(customer))
+        let CustomerAuthContext { signatory_id, .. } = ctx.data()?;
+        match signatory_id {
+            Some(signatory_id) => {
+                let signatory = app
+                    .customers()
+                    .find_signatory_for_subject(sub, *signatory_id)
+                    .await?;
+                Ok(AuthenticatedSubject::from((customer, signatory)))
+            }
+            None => Ok(AuthenticatedSubject::from(customer)),
+        }
     }
 
     async fn credit_facility(
@@ -35,3 +45,75 @@ impl Query {
         Ok(usd_cents_per_btc.into())
     }
 }
+
+pub struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn withdrawal_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalInitiateInput,
+    ) -> async_graphql::Result<WithdrawalInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let signatory = signatory_from_ctx(ctx).await?;
+        let withdrawal = app
+            .deposits()
+            .initiate_withdrawal_as_signatory(
+                sub,
+                &signatory,
+                input.deposit_account_id,
+                input.amount,
+                input.reference,
+                None,
+            )
+            .await?;
+        Ok(WithdrawalInitiatePayload::from(Withdrawal::from(
+            withdrawal,
+        )))
+    }
+
+    async fn withdrawal_signatory_approve(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalSignatoryApproveInput,
+    ) -> async_graphql::Result<WithdrawalSignatoryApprovePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let signatory = signatory_from_ctx(ctx).await?;
+        let withdrawal = app
+            .deposits()
+            .approve_withdrawal_as_signatory(sub, &signatory, input.withdrawal_id)
+            .await?;
+        Ok(WithdrawalSignatoryApprovePayload::from(Withdrawal::from(
+            withdrawal,
+        )))
+    }
+
+    async fn withdrawal_signatory_reject(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalSignatoryRejectInput,
+    ) -> async_graphql::Result<WithdrawalSignatoryRejectPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let signatory = signatory_from_ctx(ctx).await?;
+        let withdrawal = app
+            .deposits()
+            .reject_withdrawal_as_signatory(sub, &signatory, input.withdrawal_id)
+            .await?;
+        Ok(WithdrawalSignatoryRejectPayload::from(Withdrawal::from(
+            withdrawal,
+        )))
+    }
+}
+
+async fn signatory_from_ctx(
+    ctx: &Context<'_>,
+) -> async_graphql::Result<lana_app::customer::Signatory> {
+    let (app, sub) = app_and_sub_from_ctx!(ctx);
+    let CustomerAuthContext { signatory_id, .. } = ctx.data()?;
+    let signatory_id = signatory_id.ok_or("Only signatories can manage withdrawals")?;
+    Ok(app
+        .customers()
+        .find_signatory_for_subject(sub, signatory_id)
+        .await?)
+}
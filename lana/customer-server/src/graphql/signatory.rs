@@ -0,0 +1,26 @@
+use async_graphql::*;
+
+use core_customer::{Signatory as DomainSignatory, SignatoryRole};
+
+use crate::primitives::*;
+
+#[derive(SimpleObject, Clone)]
+pub struct Signatory {
+    id: ID,
+    signatory_id: UUID,
+    customer_id: UUID,
+    email: String,
+    role: SignatoryRole,
+}
+
+impl From<DomainSignatory> for Signatory {
+    fn from(signatory: DomainSignatory) -> Self {
+        Signatory {
+            id: signatory.id.to_global_id(),
+            signatory_id: UUID::from(signatory.id),
+            customer_id: UUID::from(signatory.customer_id),
+            email: signatory.email,
+            role: signatory.role,
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use async_graphql::*;
 
 pub use lana_app::terms::{
-    AnnualRatePct, CVLPct, Duration as DomainDuration, InterestInterval, OneTimeFeeRatePct,
+    AnnualRatePct, CVLPct, Duration as DomainDuration, InterestCapitalization, InterestInterval,
+    OneTimeFeeRatePct, PricingGridBand as DomainPricingGridBand, StructuringFeeRecognition,
     TermValues as DomainTermValues,
 };
 
@@ -11,23 +12,44 @@ pub struct TermValues {
     accrual_interval: InterestInterval,
     accrual_cycle_interval: InterestInterval,
     one_time_fee_rate: OneTimeFeeRatePct,
+    structuring_fee_recognition: StructuringFeeRecognition,
+    interest_capitalization: InterestCapitalization,
     duration: Duration,
     liquidation_cvl: CVLPct,
     margin_call_cvl: CVLPct,
     initial_cvl: CVLPct,
+    pricing_grid: Vec<PricingGridBand>,
 }
 
-impl From<DomainTermValues> for TermValues {
-    fn from(values: DomainTermValues) -> Self {
+#[derive(SimpleObject, Clone)]
+pub struct PricingGridBand {
+    min_cvl: CVLPct,
+    rate_adjustment: AnnualRatePct,
+}
+
+impl From<&DomainPricingGridBand> for PricingGridBand {
+    fn from(band: &DomainPricingGridBand) -> Self {
+        Self {
+            min_cvl: band.min_cvl,
+            rate_adjustment: band.rate_adjustment,
+        }
+    }
+}
+
+impl TermValues {
+    pub(super) fn new(values: DomainTermValues, bands: &[DomainPricingGridBand]) -> Self {
         Self {
             annual_rate: values.annual_rate,
             accrual_interval: values.accrual_interval,
             accrual_cycle_interval: values.accrual_cycle_interval,
             one_time_fee_rate: values.one_time_fee_rate,
+            structuring_fee_recognition: values.structuring_fee_recognition,
+            interest_capitalization: values.interest_capitalization,
             duration: values.duration.into(),
             liquidation_cvl: values.liquidation_cvl,
             margin_call_cvl: values.margin_call_cvl,
             initial_cvl: values.initial_cvl,
+            pricing_grid: bands.iter().map(PricingGridBand::from).collect(),
         }
     }
 }
@@ -2,7 +2,9 @@ use async_graphql::*;
 
 use crate::primitives::*;
 
-pub use lana_app::deposit::{Withdrawal as DomainWithdrawal, WithdrawalStatus};
+pub use lana_app::deposit::{
+    DepositAccountCurrency, Withdrawal as DomainWithdrawal, WithdrawalStatus,
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -10,7 +12,9 @@ pub struct Withdrawal {
     id: ID,
     withdrawal_id: UUID,
     account_id: UUID,
+    currency: DepositAccountCurrency,
     amount: UsdCents,
+    btc_amount: Option<Satoshis>,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -24,7 +28,9 @@ impl From<lana_app::deposit::Withdrawal> for Withdrawal {
             created_at: withdraw.created_at().into(),
             account_id: withdraw.deposit_account_id.into(),
             withdrawal_id: UUID::from(withdraw.id),
-            amount: withdraw.amount,
+            currency: withdraw.amount.currency(),
+            amount: withdraw.amount.usd().unwrap_or(UsdCents::ZERO),
+            btc_amount: withdraw.amount.btc(),
             entity: Arc::new(withdraw),
         }
     }
@@ -46,3 +52,23 @@ impl Withdrawal {
             .unwrap_or_else(|| self.entity.status()))
     }
 }
+
+#[derive(InputObject)]
+pub struct WithdrawalInitiateInput {
+    pub deposit_account_id: UUID,
+    pub amount: UsdCents,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { WithdrawalInitiatePayload, withdrawal: Withdrawal }
+
+#[derive(InputObject)]
+pub struct WithdrawalSignatoryApproveInput {
+    pub withdrawal_id: UUID,
+}
+crate::mutation_payload! { WithdrawalSignatoryApprovePayload, withdrawal: Withdrawal }
+
+#[derive(InputObject)]
+pub struct WithdrawalSignatoryRejectInput {
+    pub withdrawal_id: UUID,
+}
+crate::mutation_payload! { WithdrawalSignatoryRejectPayload, withdrawal: Withdrawal }
@@ -60,21 +60,31 @@ pub async fn run(config: CustomerServerConfig, app: LanaApp) -> anyhow::Result<(
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CustomerJwtClaims {
     pub subject: String,
+    #[serde(default)]
+    pub signatory_id: Option<String>,
 }
 
 #[instrument(name = "customer_server.graphql", skip_all, fields(error, error.level, error.message))]
 pub async fn graphql_handler(
     headers: HeaderMap,
-    schema: Extension<Schema<graphql::Query, EmptyMutation, EmptySubscription>>,
+    schema: Extension<Schema<graphql::Query, graphql::Mutation, EmptySubscription>>,
     Claims(jwt_claims): Claims<CustomerJwtClaims>,
     req: GraphQLRequest,
 ) -> GraphQLResponse {
     tracing_utils::http::extract_tracing(&headers);
     let mut req = req.into_inner();
 
-    match uuid::Uuid::parse_str(&jwt_claims.subject) {
-        Ok(id) => {
-            let auth_context = CustomerAuthContext::new(id);
+    let ids = uuid::Uuid::parse_str(&jwt_claims.subject).and_then(|id| {
+        let signatory_id = jwt_claims
+            .signatory_id
+            .as_deref()
+            .map(uuid::Uuid::parse_str)
+            .transpose()?;
+        Ok((id, signatory_id))
+    });
+    match ids {
+        Ok((id, signatory_id)) => {
+            let auth_context = CustomerAuthContext::new(id, signatory_id.map(SignatoryId::from));
             req = req.data(auth_context);
             schema.execute(req).await.into()
         }
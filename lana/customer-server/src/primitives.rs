@@ -5,8 +5,9 @@ pub use std::sync::Arc;
 
 pub use lana_app::{
     primitives::{
-        CreditFacilityId, CreditFacilityStatus, CustomerId, DepositAccountId, DepositId,
-        DisbursalId, DisbursalStatus, PaymentId, Satoshis, Subject, UsdCents, WithdrawalId,
+        CreditFacilityId, CreditFacilityStatus, CustomerId, DepositAccountId,
+        DepositAccountStatementId, DepositId, DisbursalId, DisbursalStatus, PaymentId, Satoshis,
+        SignatoryId, Subject, UsdCents, WithdrawalId,
     },
     terms::CollateralizationState,
 };
@@ -16,12 +17,14 @@ pub use es_entity::graphql::UUID;
 #[derive(Debug, Clone)]
 pub struct CustomerAuthContext {
     pub sub: Subject,
+    pub signatory_id: Option<SignatoryId>,
 }
 
 impl CustomerAuthContext {
-    pub fn new(sub: impl Into<CustomerId>) -> Self {
+    pub fn new(sub: impl Into<CustomerId>, signatory_id: Option<SignatoryId>) -> Self {
         Self {
             sub: Subject::Customer(sub.into()),
+            signatory_id,
         }
     }
 }
@@ -36,6 +39,16 @@ impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
     }
 }
 
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Date(chrono::NaiveDate);
+scalar!(Date);
+impl From<chrono::NaiveDate> for Date {
+    fn from(value: chrono::NaiveDate) -> Self {
+        Self(value)
+    }
+}
+
 pub trait ToGlobalId {
     fn to_global_id(&self) -> async_graphql::types::ID;
 }
@@ -54,7 +67,9 @@ macro_rules! impl_to_global_id {
 
 impl_to_global_id! {
     CustomerId,
+    SignatoryId,
     DepositAccountId,
+    DepositAccountStatementId,
     DepositId,
     WithdrawalId,
     CreditFacilityId,
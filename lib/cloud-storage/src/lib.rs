@@ -52,6 +52,26 @@ impl Storage {
         file: Vec<u8>,
         path_in_bucket: &str,
         mime_type: &str,
+    ) -> Result<(), StorageError> {
+        self.upload_body(file, path_in_bucket, mime_type).await
+    }
+
+    /// Streams a file from local disk so large exports are never held in memory.
+    pub async fn upload_file(
+        &self,
+        path: &std::path::Path,
+        path_in_bucket: &str,
+        mime_type: &str,
+    ) -> Result<(), StorageError> {
+        let file = tokio::fs::File::open(path).await?;
+        self.upload_body(file, path_in_bucket, mime_type).await
+    }
+
+    async fn upload_body(
+        &self,
+        body: impl Into<reqwest::Body>,
+        path_in_bucket: &str,
+        mime_type: &str,
     ) -> Result<(), StorageError> {
         let bucket = self.bucket_name();
         let object_name = self.path_with_prefix(path_in_bucket);
@@ -66,7 +86,7 @@ impl Storage {
         };
         self.client()
             .await?
-            .upload_object(&req, file, &upload_type)
+            .upload_object(&req, body, &upload_type)
             .await?;
 
         Ok(())